@@ -0,0 +1,66 @@
+//! Throughput of the big-endian encoding and decoding of large variables.
+//!
+//! Run with `cargo bench --bench byte_order`.
+use bencher::{benchmark_group, benchmark_main, Bencher};
+use tempdir::TempDir;
+
+use netcdf3::{DataSet, FileReader, FileWriter, Version};
+
+const NUM_VALUES: usize = 1_000_000;
+
+fn define_data_set() -> DataSet {
+    let mut data_set: DataSet = DataSet::new();
+    data_set.add_fixed_dim("x", NUM_VALUES).unwrap();
+    data_set.add_var_f32("var_f32", &["x"]).unwrap();
+    data_set.add_var_f64("var_f64", &["x"]).unwrap();
+    return data_set;
+}
+
+fn write_f32(bench: &mut Bencher) {
+    let tmp_dir: TempDir = TempDir::new("netcdf3_bench_").unwrap();
+    let file_path = tmp_dir.path().join("write_f32.nc");
+    let data_set: DataSet = define_data_set();
+    let data: Vec<f32> = (0..NUM_VALUES).map(|i: usize| i as f32).collect();
+    let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+    file_writer.set_def(&data_set, Version::Offset64Bit, 0).unwrap();
+    bench.bytes = (NUM_VALUES * std::mem::size_of::<f32>()) as u64;
+    bench.iter(|| {
+        file_writer.write_var_f32("var_f32", &data).unwrap();
+    });
+}
+
+fn write_f64(bench: &mut Bencher) {
+    let tmp_dir: TempDir = TempDir::new("netcdf3_bench_").unwrap();
+    let file_path = tmp_dir.path().join("write_f64.nc");
+    let data_set: DataSet = define_data_set();
+    let data: Vec<f64> = (0..NUM_VALUES).map(|i: usize| i as f64).collect();
+    let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+    file_writer.set_def(&data_set, Version::Offset64Bit, 0).unwrap();
+    bench.bytes = (NUM_VALUES * std::mem::size_of::<f64>()) as u64;
+    bench.iter(|| {
+        file_writer.write_var_f64("var_f64", &data).unwrap();
+    });
+}
+
+fn read_f32_and_f64(bench: &mut Bencher) {
+    let tmp_dir: TempDir = TempDir::new("netcdf3_bench_").unwrap();
+    let file_path = tmp_dir.path().join("read.nc");
+    let data_set: DataSet = define_data_set();
+    {
+        let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Offset64Bit, 0).unwrap();
+        file_writer.write_var_f32("var_f32", &vec![1.0; NUM_VALUES]).unwrap();
+        file_writer.write_var_f64("var_f64", &vec![1.0; NUM_VALUES]).unwrap();
+        file_writer.close().unwrap();
+    }
+    let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    bench.bytes = (NUM_VALUES * (std::mem::size_of::<f32>() + std::mem::size_of::<f64>())) as u64;
+    bench.iter(|| {
+        let data_f32: Vec<f32> = file_reader.read_var_f32("var_f32").unwrap();
+        let data_f64: Vec<f64> = file_reader.read_var_f64("var_f64").unwrap();
+        (data_f32, data_f64)
+    });
+}
+
+benchmark_group!(benches, write_f32, write_f64, read_f32_and_f64);
+benchmark_main!(benches);
@@ -0,0 +1,465 @@
+//! Helpers for the [CF conventions](http://cfconventions.org), built on top of [`DataSet`](../struct.DataSet.html).
+//!
+//! # Example
+//!
+//! ```
+//! use netcdf3::{DataSet, DataType, Variable};
+//! use netcdf3::cf::{self, Axis};
+//!
+//! let mut data_set: DataSet = DataSet::new();
+//! data_set.set_unlimited_dim("time", 2).unwrap();
+//! data_set.add_coord_var("latitude", 3, DataType::F32, Some("degrees_north"), None).unwrap();
+//! data_set.add_var_f64("time", &["time"]).unwrap();
+//! data_set.add_var_attr_string("time", "units", "hours since 2020-01-01").unwrap();
+//! data_set.add_var_f32("sst", &["time", "latitude"]).unwrap();
+//! data_set.add_var_attr_string("sst", "standard_name", "sea_surface_temperature").unwrap();
+//! data_set.add_var_attr_string("sst", "units", "K").unwrap();
+//!
+//! let sst: &Variable = cf::find_vars_by_standard_name(&data_set, "sea_surface_temperature")[0];
+//! assert_eq!("sst",                           sst.name());
+//! assert_eq!(Some(String::from("K")),         cf::units(&data_set, "sst"));
+//! assert_eq!(None,                            cf::long_name(&data_set, "sst"));
+//!
+//! assert_eq!(Some(Axis::Y),                   cf::var_axis(&data_set, "latitude"));
+//! assert_eq!(Some("time"),                    cf::find_axis_var(&data_set, "sst", Axis::T).map(|var: &Variable| var.name()));
+//! assert_eq!(None,                            cf::find_axis_var(&data_set, "sst", Axis::X));
+//! ```
+mod tests;
+
+use std::rc::Rc;
+
+use crate::{Attribute, DataSet, DataType, DataVector, Dimension, InvalidDataSet, Variable};
+use crate::error::{CastError, GridMappingError, QuantizationError};
+
+/// Units of the latitudes (see the section *Latitude Coordinate* of the CF conventions)
+const LATITUDE_UNITS: [&str; 6] = ["degrees_north", "degree_north", "degree_N", "degrees_N", "degreeN", "degreesN"];
+/// Units of the longitudes (see the section *Longitude Coordinate* of the CF conventions)
+const LONGITUDE_UNITS: [&str; 6] = ["degrees_east", "degree_east", "degree_E", "degrees_E", "degreeE", "degreesE"];
+
+/// Spatiotemporal axis of a coordinate variable (values of the CF attribute `axis`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+    T,
+}
+
+impl Axis {
+
+    /// Parses the value of the attribute `axis`.
+    pub fn parse(axis: &str) -> Option<Axis> {
+        return match axis.trim() {
+            "X" | "x" => Some(Axis::X),
+            "Y" | "y" => Some(Axis::Y),
+            "Z" | "z" => Some(Axis::Z),
+            "T" | "t" => Some(Axis::T),
+            _ => None,
+        };
+    }
+
+    /// Returns the value of the attribute `axis`.
+    pub fn as_str(&self) -> &'static str {
+        return match self {
+            Axis::X => "X",
+            Axis::Y => "Y",
+            Axis::Z => "Z",
+            Axis::T => "T",
+        };
+    }
+}
+
+/// Returns the attribute `standard_name` of the variable.
+pub fn standard_name(data_set: &DataSet, var_name: &str) -> Option<String> {
+    return data_set.get_var_attr_as_string(var_name, "standard_name");
+}
+
+/// Returns the attribute `long_name` of the variable.
+pub fn long_name(data_set: &DataSet, var_name: &str) -> Option<String> {
+    return data_set.get_var_attr_as_string(var_name, "long_name");
+}
+
+/// Returns the attribute `units` of the variable.
+pub fn units(data_set: &DataSet, var_name: &str) -> Option<String> {
+    return data_set.get_var_attr_as_string(var_name, "units");
+}
+
+/// Returns the variables having the attribute `standard_name`, in their definition order.
+pub fn find_vars_by_standard_name<'a>(data_set: &'a DataSet, standard_name: &str) -> Vec<&'a Variable> {
+    return data_set.vars_with_attr("standard_name", standard_name);
+}
+
+/// Returns the coordinate variables of a variable, namely :
+///
+/// 1. the *coordinate variables* of its dimensions (the 1-D variables having the same name as their dimension), in the order of the dimensions,
+/// 2. then the *auxiliary coordinate variables* listed in its attribute `coordinates`.
+///
+/// Returns an empty list if the variable is not defined.
+pub fn coordinate_vars<'a>(data_set: &'a DataSet, var_name: &str) -> Vec<&'a Variable> {
+    let var: &Variable = match data_set.get_var(var_name) {
+        None => return vec![],
+        Some(var) => var,
+    };
+    let mut coord_vars: Vec<&Variable> = var.dims().filter_map(|dim: &Rc<Dimension>| {
+        let dim_name: String = dim.name();
+        data_set.get_var(&dim_name).filter(|coord_var: &&Variable| coord_var.dim_names() == [dim_name.as_str()])
+    }).collect();
+    if let Some(coordinates) = var.get_attr_as_string("coordinates") {
+        for coord_var_name in coordinates.split_whitespace() {
+            if let Some(coord_var) = data_set.get_var(coord_var_name) {
+                if !coord_vars.iter().any(|var_2: &&Variable| var_2.name() == coord_var.name()) {
+                    coord_vars.push(coord_var);
+                }
+            }
+        }
+    }
+    return coord_vars;
+}
+
+/// Returns the axis of a coordinate variable.
+///
+/// The attribute `axis` is used first, otherwise the axis is deduced (in this order) from :
+///
+/// - the `units` of the latitudes (`Y`) and the longitudes (`X`),
+/// - the `units` of the times (`T`), containing the word `since`,
+/// - the attribute `positive` of the vertical coordinates (`Z`),
+/// - the `standard_name`s `latitude`, `longitude` and `time`.
+pub fn var_axis(data_set: &DataSet, var_name: &str) -> Option<Axis> {
+    let var: &Variable = data_set.get_var(var_name)?;
+    if let Some(axis) = var.get_attr_as_string("axis").as_deref().and_then(Axis::parse) {
+        return Some(axis);
+    }
+    if let Some(units) = var.get_attr_as_string("units") {
+        let units: &str = units.trim();
+        if LATITUDE_UNITS.contains(&units) {
+            return Some(Axis::Y);
+        }
+        if LONGITUDE_UNITS.contains(&units) {
+            return Some(Axis::X);
+        }
+        if units.split_whitespace().any(|word: &str| word == "since") {
+            return Some(Axis::T);
+        }
+    }
+    if let Some(positive) = var.get_attr_as_string("positive") {
+        if positive.trim().eq_ignore_ascii_case("up") || positive.trim().eq_ignore_ascii_case("down") {
+            return Some(Axis::Z);
+        }
+    }
+    return match var.get_attr_as_string("standard_name").as_deref() {
+        Some("latitude") => Some(Axis::Y),
+        Some("longitude") => Some(Axis::X),
+        Some("time") => Some(Axis::T),
+        _ => None,
+    };
+}
+
+/// Returns the first coordinate variable of a variable (see the function [`coordinate_vars`](fn.coordinate_vars.html)) along the `axis`.
+pub fn find_axis_var<'a>(data_set: &'a DataSet, var_name: &str, axis: Axis) -> Option<&'a Variable> {
+    return coordinate_vars(data_set, var_name).into_iter()
+        .find(|coord_var: &&Variable| var_axis(data_set, coord_var.name()) == Some(axis));
+}
+
+/// Figure of the Earth of a grid mapping, the lengths are in meters.
+///
+/// Either `earth_radius` for a sphere, or `semi_major_axis` with `semi_minor_axis` or `inverse_flattening` for an
+/// ellipsoid. All the parameters are `None` if the grid mapping does not define the figure of the Earth.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EarthShape {
+    pub earth_radius: Option<f64>,
+    pub semi_major_axis: Option<f64>,
+    pub semi_minor_axis: Option<f64>,
+    pub inverse_flattening: Option<f64>,
+}
+
+/// Projection of a grid mapping, with its parameters (see the appendix *Grid Mappings* of the CF conventions).
+///
+/// The angles are in degrees. The optional parameters are the ones which can be replaced by an other parameter
+/// (e.g. `standard_parallel` or `scale_factor_at_projection_origin` for the Mercator projection).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Projection {
+    LatitudeLongitude,
+    RotatedLatitudeLongitude{
+        grid_north_pole_latitude: f64,
+        grid_north_pole_longitude: f64,
+        north_pole_grid_longitude: f64,
+    },
+    /// One or two standard parallels
+    LambertConformalConic{
+        standard_parallel: Vec<f64>,
+        longitude_of_central_meridian: f64,
+        latitude_of_projection_origin: f64,
+    },
+    /// One or two standard parallels
+    AlbersConicalEqualArea{
+        standard_parallel: Vec<f64>,
+        longitude_of_central_meridian: f64,
+        latitude_of_projection_origin: f64,
+    },
+    LambertAzimuthalEqualArea{
+        longitude_of_projection_origin: f64,
+        latitude_of_projection_origin: f64,
+    },
+    Mercator{
+        longitude_of_projection_origin: f64,
+        standard_parallel: Option<f64>,
+        scale_factor_at_projection_origin: Option<f64>,
+    },
+    PolarStereographic{
+        straight_vertical_longitude_from_pole: f64,
+        latitude_of_projection_origin: f64,
+        standard_parallel: Option<f64>,
+        scale_factor_at_projection_origin: Option<f64>,
+    },
+    TransverseMercator{
+        scale_factor_at_central_meridian: f64,
+        longitude_of_central_meridian: f64,
+        latitude_of_projection_origin: f64,
+    },
+    /// The other projections, named by their `grid_mapping_name` (their parameters can be read from the container variable)
+    Other(String),
+}
+
+/// Grid mapping of a variable, parsed from its *grid mapping variable* (see the function [`grid_mapping`](fn.grid_mapping.html)).
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridMapping {
+    /// Name of the grid mapping variable, containing the attributes
+    pub container_name: String,
+    pub projection: Projection,
+    pub earth_shape: EarthShape,
+    /// Value added to the `x` coordinates (`0.0` by default)
+    pub false_easting: f64,
+    /// Value added to the `y` coordinates (`0.0` by default)
+    pub false_northing: f64,
+}
+
+/// Returns the grid mapping of a variable, parsed from the *grid mapping variable* named by its attribute `grid_mapping`.
+///
+/// Returns `Ok(None)` if the variable has no attribute `grid_mapping`. Only the first grid mapping of the extended form
+/// of the attribute (e.g. `"crs: x y"`) is parsed.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::DataSet;
+/// use netcdf3::cf::{self, GridMapping, Projection};
+///
+/// let mut data_set: DataSet = DataSet::new();
+/// data_set.add_fixed_dim("y", 2).unwrap();
+/// data_set.add_fixed_dim("x", 3).unwrap();
+/// data_set.add_var_f32("temperature", &["y", "x"]).unwrap();
+/// data_set.add_var_attr_string("temperature", "grid_mapping", "lambert").unwrap();
+/// data_set.add_var_i32("lambert", &[] as &[&str]).unwrap();
+/// data_set.add_var_attr_string("lambert", "grid_mapping_name", "lambert_conformal_conic").unwrap();
+/// data_set.add_var_attr_f64("lambert", "standard_parallel", vec![25.0, 25.0]).unwrap();
+/// data_set.add_var_attr_f64("lambert", "longitude_of_central_meridian", vec![265.0]).unwrap();
+/// data_set.add_var_attr_f64("lambert", "latitude_of_projection_origin", vec![25.0]).unwrap();
+/// data_set.add_var_attr_f64("lambert", "earth_radius", vec![6_371_229.0]).unwrap();
+///
+/// let grid_mapping: GridMapping = cf::grid_mapping(&data_set, "temperature").unwrap().unwrap();
+/// assert_eq!(
+///     Projection::LambertConformalConic{
+///         standard_parallel: vec![25.0, 25.0],
+///         longitude_of_central_meridian: 265.0,
+///         latitude_of_projection_origin: 25.0,
+///     },
+///     grid_mapping.projection
+/// );
+/// assert_eq!(Some(6_371_229.0),               grid_mapping.earth_shape.earth_radius);
+/// assert_eq!(0.0,                             grid_mapping.false_easting);
+/// ```
+pub fn grid_mapping(data_set: &DataSet, var_name: &str) -> Result<Option<GridMapping>, GridMappingError> {
+    let var: &Variable = data_set.get_var(var_name).ok_or_else(|| GridMappingError::VariableNotDefined(var_name.to_owned()))?;
+    let container_name: String = match var.get_attr_as_string("grid_mapping") {
+        None => return Ok(None),
+        Some(grid_mapping) => {
+            let first_word: &str = grid_mapping.split_whitespace().next().unwrap_or("");
+            String::from(first_word.trim_end_matches(':'))
+        },
+    };
+    let container: &Variable = data_set.get_var(&container_name).ok_or_else(|| GridMappingError::ContainerNotDefined{
+        var_name: var_name.to_owned(),
+        container_name: container_name.clone(),
+    })?;
+    let grid_mapping_name: String = container.get_attr_as_string("grid_mapping_name")
+        .ok_or_else(|| GridMappingError::NameNotDefined(container_name.clone()))?;
+
+    // The numeric attributes, the `char` attributes are ignored
+    let numbers = |param_name: &str| -> Vec<f64> {
+        return container.get_attr(param_name)
+            .filter(|attr: &&Attribute| attr.data.data_type() != DataType::U8)
+            .map(|attr: &Attribute| attr.data.iter_as_f64().collect())
+            .unwrap_or_default();
+    };
+    let optional = |param_name: &str| -> Option<f64> {
+        return numbers(param_name).first().copied();
+    };
+    let required = |param_name: &str| -> Result<f64, GridMappingError> {
+        return optional(param_name).ok_or_else(|| GridMappingError::ParameterNotDefined{
+            container_name: container_name.clone(),
+            param_name: param_name.to_owned(),
+        });
+    };
+    let standard_parallels = || -> Result<Vec<f64>, GridMappingError> {
+        let standard_parallel: Vec<f64> = numbers("standard_parallel").into_iter().take(2).collect();
+        return match standard_parallel.is_empty() {
+            true => Err(GridMappingError::ParameterNotDefined{container_name: container_name.clone(), param_name: String::from("standard_parallel")}),
+            false => Ok(standard_parallel),
+        };
+    };
+    let projection: Projection = match grid_mapping_name.trim() {
+        "latitude_longitude" => Projection::LatitudeLongitude,
+        "rotated_latitude_longitude" => Projection::RotatedLatitudeLongitude{
+            grid_north_pole_latitude: required("grid_north_pole_latitude")?,
+            grid_north_pole_longitude: required("grid_north_pole_longitude")?,
+            north_pole_grid_longitude: optional("north_pole_grid_longitude").unwrap_or(0.0),
+        },
+        "lambert_conformal_conic" => Projection::LambertConformalConic{
+            standard_parallel: standard_parallels()?,
+            longitude_of_central_meridian: required("longitude_of_central_meridian")?,
+            latitude_of_projection_origin: required("latitude_of_projection_origin")?,
+        },
+        "albers_conical_equal_area" => Projection::AlbersConicalEqualArea{
+            standard_parallel: standard_parallels()?,
+            longitude_of_central_meridian: required("longitude_of_central_meridian")?,
+            latitude_of_projection_origin: required("latitude_of_projection_origin")?,
+        },
+        "lambert_azimuthal_equal_area" => Projection::LambertAzimuthalEqualArea{
+            longitude_of_projection_origin: required("longitude_of_projection_origin")?,
+            latitude_of_projection_origin: required("latitude_of_projection_origin")?,
+        },
+        "mercator" => Projection::Mercator{
+            longitude_of_projection_origin: required("longitude_of_projection_origin")?,
+            standard_parallel: optional("standard_parallel"),
+            scale_factor_at_projection_origin: optional("scale_factor_at_projection_origin"),
+        },
+        "polar_stereographic" => Projection::PolarStereographic{
+            straight_vertical_longitude_from_pole: required("straight_vertical_longitude_from_pole")?,
+            latitude_of_projection_origin: required("latitude_of_projection_origin")?,
+            standard_parallel: optional("standard_parallel"),
+            scale_factor_at_projection_origin: optional("scale_factor_at_projection_origin"),
+        },
+        "transverse_mercator" => Projection::TransverseMercator{
+            scale_factor_at_central_meridian: required("scale_factor_at_central_meridian")?,
+            longitude_of_central_meridian: required("longitude_of_central_meridian")?,
+            latitude_of_projection_origin: required("latitude_of_projection_origin")?,
+        },
+        other => Projection::Other(String::from(other)),
+    };
+    return Ok(Some(GridMapping{
+        container_name: container_name.clone(),
+        projection: projection,
+        earth_shape: EarthShape{
+            earth_radius: optional("earth_radius"),
+            semi_major_axis: optional("semi_major_axis"),
+            semi_minor_axis: optional("semi_minor_axis"),
+            inverse_flattening: optional("inverse_flattening"),
+        },
+        false_easting: optional("false_easting").unwrap_or(0.0),
+        false_northing: optional("false_northing").unwrap_or(0.0),
+    }));
+}
+
+/// Computes the CF attributes `scale_factor` and `add_offset` packing the `values` into the data type of the variable,
+/// sets them on the variable (replacing the previous ones) and returns the packed values.
+///
+/// - The `values` must be `f32` or `f64` values, the attributes have the same data type.
+/// - The variable must be of type `i8`, `i16`, `i32`, `u16` or `u32`, the whole range of the type is used except its fill value.
+/// - The `NaN` values are packed as the fill value.
+/// - If a `precision` is given, an error is returned (and the data set is unchanged) if the quantization error (half of `scale_factor`) exceeds it.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{DataSet, DataVector};
+/// use netcdf3::cf;
+///
+/// let mut data_set: DataSet = DataSet::new();
+/// data_set.add_fixed_dim("x", 3).unwrap();
+/// data_set.add_var_i16("temperature", &["x"]).unwrap();
+///
+/// let packed: DataVector = cf::quantize(&mut data_set, "temperature", &DataVector::F64(vec![-10.0, std::f64::NAN, 30.0]), Some(0.001)).unwrap();
+/// assert_eq!(DataVector::I16(vec![-32766, -32767, 32767]),    packed);
+///
+/// let scale_factor: f64 = data_set.get_var_attr_f64("temperature", "scale_factor").unwrap()[0];
+/// assert_eq!(40.0 / 65533.0,                                  scale_factor);
+/// ```
+pub fn quantize(data_set: &mut DataSet, var_name: &str, values: &DataVector, precision: Option<f64>) -> Result<DataVector, QuantizationError> {
+    let var: &Variable = data_set.get_var(var_name).ok_or_else(|| InvalidDataSet::VariableNotDefined(var_name.to_owned()))?;
+    let (packed_min, packed_max): (f64, f64) = match var.data_type() {
+        DataType::I8 => packed_range(f64::from(i8::MIN), f64::from(i8::MAX), var.fill_value::<i8>().map(f64::from)),
+        DataType::I16 => packed_range(f64::from(i16::MIN), f64::from(i16::MAX), var.fill_value::<i16>().map(f64::from)),
+        DataType::I32 => packed_range(f64::from(i32::MIN), f64::from(i32::MAX), var.fill_value::<i32>().map(f64::from)),
+        DataType::U16 => packed_range(f64::from(u16::MIN), f64::from(u16::MAX), var.fill_value::<u16>().map(f64::from)),
+        DataType::U32 => packed_range(f64::from(u32::MIN), f64::from(u32::MAX), var.fill_value::<u32>().map(f64::from)),
+        data_type => return Err(QuantizationError::DataTypeNotSupported{var_name: var_name.to_owned(), data_type: data_type}),
+    };
+    // relative rounding error of the attributes
+    let (unpacked_values, epsilon): (Vec<f64>, f64) = match values {
+        DataVector::F32(values) => (values.iter().map(|value: &f32| f64::from(*value)).collect(), f64::from(std::f32::EPSILON)),
+        DataVector::F64(values) => (values.clone(), 0.0),
+        _ => return Err(QuantizationError::ValuesNotFloat{var_name: var_name.to_owned(), data_type: values.data_type()}),
+    };
+    if let Some(index) = unpacked_values.iter().position(|value: &f64| value.is_infinite()) {
+        return Err(QuantizationError::ValueNotFinite{var_name: var_name.to_owned(), index: index});
+    }
+    let (min, max): (f64, f64) = unpacked_values.iter()
+        .filter(|value: &&f64| !value.is_nan())
+        .fold((std::f64::INFINITY, std::f64::NEG_INFINITY), |(min, max): (f64, f64), value: &f64| (min.min(*value), max.max(*value)));
+
+    let (scale_factor, add_offset): (f64, f64) = if min > max {
+        (1.0, 0.0)
+    } else if min == max {
+        (1.0, min - 0.0_f64.max(packed_min).min(packed_max))
+    } else {
+        // the rounding of the attributes shifts the packed values, the packed range is reduced accordingly
+        let scale_factor: f64 = (max - min) / (packed_max - packed_min);
+        let margin: f64 = (epsilon * (min.abs().max(max.abs()) + (min - packed_min * scale_factor).abs()) / scale_factor).ceil();
+        let margin: f64 = margin.min(((packed_max - packed_min) / 2.0 - 1.0).floor());
+        let (packed_min, packed_max): (f64, f64) = (packed_min + margin, packed_max - margin);
+        let scale_factor: f64 = (max - min) / (packed_max - packed_min);
+        (scale_factor, min - packed_min * scale_factor)
+    };
+    if let Some(precision) = precision {
+        if scale_factor / 2.0 > precision {
+            return Err(QuantizationError::PrecisionNotReached{var_name: var_name.to_owned(), precision: precision, scale_factor: scale_factor});
+        }
+    }
+
+    for attr_name in ["scale_factor", "add_offset"].iter() {
+        if data_set.get_var_attr(var_name, attr_name).is_some() {
+            data_set.remove_var_attr(var_name, attr_name)?;
+        }
+    }
+    match values {
+        DataVector::F32(_) => {
+            data_set.add_var_attr_f32(var_name, "scale_factor", vec![scale_factor as f32])?;
+            data_set.add_var_attr_f32(var_name, "add_offset", vec![add_offset as f32])?;
+        },
+        _ => {
+            data_set.add_var_attr_f64(var_name, "scale_factor", vec![scale_factor])?;
+            data_set.add_var_attr_f64(var_name, "add_offset", vec![add_offset])?;
+        },
+    }
+    let var: &Variable = data_set.get_var(var_name).ok_or_else(|| InvalidDataSet::VariableNotDefined(var_name.to_owned()))?;
+    return var.pack_values(&unpacked_values).map_err(|err: CastError| {
+        match err {
+            CastError::ValueOutOfRange{index, data_type: _} => QuantizationError::ValueOutOfRange{var_name: var_name.to_owned(), index: index},
+        }
+    });
+}
+
+/// Returns the largest contiguous range of the packed values `[min, max]` excluding the fill value.
+fn packed_range(min: f64, max: f64, fill_value: Option<f64>) -> (f64, f64) {
+    return match fill_value {
+        Some(fill_value) if fill_value >= min && fill_value <= max => {
+            if fill_value - min > max - fill_value {
+                (min, fill_value - 1.0)
+            } else {
+                (fill_value + 1.0, max)
+            }
+        },
+        _ => (min, max),
+    };
+}
+
@@ -0,0 +1,232 @@
+#![cfg(test)]
+
+use crate::{DataSet, DataType, DataVector, Variable};
+use crate::error::{GridMappingError, QuantizationError};
+
+use super::{
+    Axis,
+    standard_name, long_name, units,
+    find_vars_by_standard_name, coordinate_vars, var_axis, find_axis_var,
+    quantize,
+    EarthShape, GridMapping, Projection, grid_mapping,
+};
+
+fn var_names(vars: Vec<&Variable>) -> Vec<&str> {
+    return vars.into_iter().map(|var: &Variable| var.name()).collect();
+}
+
+#[test]
+fn test_attrs() {
+    let mut data_set = DataSet::new();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_var_f32("sst", &["latitude"]).unwrap();
+    data_set.add_var_attr_string("sst", "standard_name", "sea_surface_temperature").unwrap();
+    data_set.add_var_attr_string("sst", "long_name", "Sea surface temperature").unwrap();
+    data_set.add_var_attr_string("sst", "units", "K").unwrap();
+    data_set.add_var_f32("sst_error", &["latitude"]).unwrap();
+    data_set.add_var_attr_string("sst_error", "standard_name", "sea_surface_temperature standard_error").unwrap();
+    data_set.add_var_f32("sst_2", &["latitude"]).unwrap();
+    data_set.add_var_attr_string("sst_2", "standard_name", "sea_surface_temperature").unwrap();
+
+    assert_eq!(Some(String::from("sea_surface_temperature")),   standard_name(&data_set, "sst"));
+    assert_eq!(Some(String::from("Sea surface temperature")),   long_name(&data_set, "sst"));
+    assert_eq!(Some(String::from("K")),                         units(&data_set, "sst"));
+    assert_eq!(None,                                            units(&data_set, "sst_2"));
+    assert_eq!(None,                                            units(&data_set, "undef_var"));
+
+    assert_eq!(vec!["sst", "sst_2"],                            var_names(find_vars_by_standard_name(&data_set, "sea_surface_temperature")));
+    assert_eq!(Vec::<&str>::new(),                              var_names(find_vars_by_standard_name(&data_set, "air_temperature")));
+}
+
+#[test]
+fn test_axis_parse() {
+    assert_eq!(Some(Axis::X),               Axis::parse("X"));
+    assert_eq!(Some(Axis::Y),               Axis::parse("y"));
+    assert_eq!(Some(Axis::Z),               Axis::parse(" Z "));
+    assert_eq!(Some(Axis::T),               Axis::parse("T"));
+    assert_eq!(None,                        Axis::parse("W"));
+    assert_eq!("T",                         Axis::T.as_str());
+}
+
+#[test]
+fn test_coordinate_vars_and_axes() {
+    let mut data_set = DataSet::new();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("depth", 4).unwrap();
+    data_set.add_fixed_dim("y", 3).unwrap();
+    data_set.add_fixed_dim("x", 5).unwrap();
+    data_set.add_var_f64("time", &["time"]).unwrap();
+    data_set.add_var_attr_string("time", "units", "days since 1970-01-01").unwrap();
+    data_set.add_var_f32("depth", &["depth"]).unwrap();
+    data_set.add_var_attr_string("depth", "positive", "down").unwrap();
+    data_set.add_var_f32("y", &["y"]).unwrap();
+    data_set.add_var_attr_string("y", "axis", "Y").unwrap();
+    // Not a coordinate variable, it is not 1-D
+    data_set.add_var_f32("x", &["y", "x"]).unwrap();
+    data_set.add_var_f32("lat", &["y", "x"]).unwrap();
+    data_set.add_var_attr_string("lat", "units", "degrees_north").unwrap();
+    data_set.add_var_f32("lon", &["y", "x"]).unwrap();
+    data_set.add_var_attr_string("lon", "standard_name", "longitude").unwrap();
+    data_set.add_var_f32("temp", &["time", "depth", "y", "x"]).unwrap();
+    data_set.add_var_attr_string("temp", "coordinates", "lon lat undef_var y").unwrap();
+
+    assert_eq!(vec!["time", "depth", "y", "lon", "lat"],    var_names(coordinate_vars(&data_set, "temp")));
+    assert_eq!(Vec::<&str>::new(),                          var_names(coordinate_vars(&data_set, "undef_var")));
+
+    assert_eq!(Some(Axis::T),                               var_axis(&data_set, "time"));
+    assert_eq!(Some(Axis::Z),                               var_axis(&data_set, "depth"));
+    assert_eq!(Some(Axis::Y),                               var_axis(&data_set, "y"));
+    assert_eq!(Some(Axis::Y),                               var_axis(&data_set, "lat"));
+    assert_eq!(Some(Axis::X),                               var_axis(&data_set, "lon"));
+    assert_eq!(None,                                        var_axis(&data_set, "temp"));
+    assert_eq!(None,                                        var_axis(&data_set, "undef_var"));
+
+    assert_eq!(Some("time"),                                find_axis_var(&data_set, "temp", Axis::T).map(|var: &Variable| var.name()));
+    assert_eq!(Some("depth"),                               find_axis_var(&data_set, "temp", Axis::Z).map(|var: &Variable| var.name()));
+    assert_eq!(Some("y"),                                   find_axis_var(&data_set, "temp", Axis::Y).map(|var: &Variable| var.name()));
+    assert_eq!(Some("lon"),                                 find_axis_var(&data_set, "temp", Axis::X).map(|var: &Variable| var.name()));
+    assert_eq!(None,                                        find_axis_var(&data_set, "lat", Axis::T).map(|var: &Variable| var.name()));
+
+}
+
+#[test]
+fn test_quantize() {
+    let mut data_set = DataSet::new();
+    data_set.add_fixed_dim("x", 5).unwrap();
+    data_set.add_var_u16("temperature", &["x"]).unwrap();
+    data_set.add_var_attr_f32("temperature", "scale_factor", vec![10.0]).unwrap();
+    data_set.add_var_i8("humidity", &["x"]).unwrap();
+    data_set.add_var_attr_i8("humidity", "_FillValue", vec![0]).unwrap();
+    data_set.add_var_f32("pressure", &["x"]).unwrap();
+
+    // `f32` values packed into the range `[0, 65534]` (the fill value is 65535), minus a margin absorbing the rounding of the `f32` attributes
+    // the previous attributes are replaced
+    let values: Vec<f32> = vec![270.0, 280.5, std::f32::NAN, 290.25, 300.125];
+    let packed: Vec<u16> = quantize(&mut data_set, "temperature", &DataVector::F32(values.clone()), Some(0.001)).unwrap().get_u16_into().unwrap();
+    assert_eq!(1,                                               packed[0]);
+    assert_eq!(65535,                                           packed[2]);
+    assert_eq!(65533,                                           packed[4]);
+    assert_eq!(1,                                               data_set.get_var_attr_f32("temperature", "scale_factor").unwrap().len());
+    assert_eq!(1,                                               data_set.get_var_attr_f32("temperature", "add_offset").unwrap().len());
+    let var: &Variable = data_set.get_var("temperature").unwrap();
+    let (scale_factor, add_offset): (f64, f64) = var.packing_attrs();
+    for (value, packed_value) in values.iter().zip(packed.iter()).filter(|(value, _)| !value.is_nan()) {
+        assert!((f64::from(*value) - (f64::from(*packed_value) * scale_factor + add_offset)).abs() <= 0.001);
+    }
+
+    // the fill value in the middle of the range, the largest side is used
+    let packed: DataVector = quantize(&mut data_set, "humidity", &DataVector::F64(vec![0.0, 0.5, 1.0]), None).unwrap();
+    assert_eq!(DataVector::I8(vec![-128, -65, -1]),             packed);
+    let packed: DataVector = quantize(&mut data_set, "humidity", &DataVector::F64(vec![0.5, 0.5]), None).unwrap();
+    assert_eq!(DataVector::I8(vec![-1, -1]),                    packed);
+    assert_eq!(Some(&[1.0][..]),                                data_set.get_var_attr_f64("humidity", "scale_factor"));
+    assert_eq!(Some(&[1.5][..]),                                data_set.get_var_attr_f64("humidity", "add_offset"));
+
+    // errors, the data set is unchanged
+    assert_eq!(
+        QuantizationError::PrecisionNotReached{var_name: String::from("humidity"), precision: 0.001, scale_factor: 1.0 / 127.0},
+        quantize(&mut data_set, "humidity", &DataVector::F64(vec![0.0, 1.0]), Some(0.001)).unwrap_err()
+    );
+    assert_eq!(Some(&[1.5][..]),                                data_set.get_var_attr_f64("humidity", "add_offset"));
+    assert_eq!(
+        QuantizationError::ValueNotFinite{var_name: String::from("humidity"), index: 1},
+        quantize(&mut data_set, "humidity", &DataVector::F64(vec![0.0, std::f64::INFINITY]), None).unwrap_err()
+    );
+    assert_eq!(
+        QuantizationError::ValuesNotFloat{var_name: String::from("humidity"), data_type: DataType::I32},
+        quantize(&mut data_set, "humidity", &DataVector::I32(vec![0, 1]), None).unwrap_err()
+    );
+    assert_eq!(
+        QuantizationError::DataTypeNotSupported{var_name: String::from("pressure"), data_type: DataType::F32},
+        quantize(&mut data_set, "pressure", &DataVector::F64(vec![0.0, 1.0]), None).unwrap_err()
+    );
+    assert_eq!(
+        QuantizationError::DataSet(crate::InvalidDataSet::VariableNotDefined(String::from("undef_var"))),
+        quantize(&mut data_set, "undef_var", &DataVector::F64(vec![0.0, 1.0]), None).unwrap_err()
+    );
+}
+
+#[test]
+fn test_grid_mapping() {
+    let mut data_set = DataSet::new();
+    data_set.add_fixed_dim("y", 2).unwrap();
+    data_set.add_fixed_dim("x", 3).unwrap();
+    data_set.add_var_f32("temperature", &["y", "x"]).unwrap();
+    data_set.add_var_attr_string("temperature", "grid_mapping", "polar_stereo: x y").unwrap();
+    data_set.add_var_f32("pressure", &["y", "x"]).unwrap();
+    data_set.add_var_attr_string("pressure", "grid_mapping", "undef_container").unwrap();
+    data_set.add_var_f32("no_grid_mapping", &["y", "x"]).unwrap();
+    data_set.add_var_f32("humidity", &["y", "x"]).unwrap();
+    data_set.add_var_attr_string("humidity", "grid_mapping", "crs").unwrap();
+    data_set.add_var_f32("wind", &["y", "x"]).unwrap();
+    data_set.add_var_attr_string("wind", "grid_mapping", "other").unwrap();
+    data_set.add_var_f32("rain", &["y", "x"]).unwrap();
+    data_set.add_var_attr_string("rain", "grid_mapping", "geostationary").unwrap();
+
+    data_set.add_var_i32("polar_stereo", &[] as &[&str]).unwrap();
+    data_set.add_var_attr_string("polar_stereo", "grid_mapping_name", "polar_stereographic").unwrap();
+    data_set.add_var_attr_f32("polar_stereo", "straight_vertical_longitude_from_pole", vec![-45.0]).unwrap();
+    data_set.add_var_attr_i32("polar_stereo", "latitude_of_projection_origin", vec![90]).unwrap();
+    data_set.add_var_attr_f64("polar_stereo", "standard_parallel", vec![70.0]).unwrap();
+    data_set.add_var_attr_f64("polar_stereo", "semi_major_axis", vec![6_378_273.0]).unwrap();
+    data_set.add_var_attr_f64("polar_stereo", "inverse_flattening", vec![298.279_411_123_064]).unwrap();
+    data_set.add_var_attr_f64("polar_stereo", "false_easting", vec![100.0]).unwrap();
+    // The `char` attributes are not numbers
+    data_set.add_var_attr_string("polar_stereo", "false_northing", "200").unwrap();
+
+    // Missing parameter
+    data_set.add_var_i32("crs", &[] as &[&str]).unwrap();
+    data_set.add_var_attr_string("crs", "grid_mapping_name", "lambert_conformal_conic").unwrap();
+    data_set.add_var_attr_f64("crs", "longitude_of_central_meridian", vec![265.0]).unwrap();
+    data_set.add_var_attr_f64("crs", "latitude_of_projection_origin", vec![25.0]).unwrap();
+
+    // Missing `grid_mapping_name`
+    data_set.add_var_i32("other", &[] as &[&str]).unwrap();
+
+    data_set.add_var_i32("geostationary", &[] as &[&str]).unwrap();
+    data_set.add_var_attr_string("geostationary", "grid_mapping_name", "geostationary").unwrap();
+    data_set.add_var_attr_f64("geostationary", "earth_radius", vec![6_371_000.0]).unwrap();
+
+    assert_eq!(
+        Ok(Some(GridMapping{
+            container_name: String::from("polar_stereo"),
+            projection: Projection::PolarStereographic{
+                straight_vertical_longitude_from_pole: -45.0,
+                latitude_of_projection_origin: 90.0,
+                standard_parallel: Some(70.0),
+                scale_factor_at_projection_origin: None,
+            },
+            earth_shape: EarthShape{
+                earth_radius: None,
+                semi_major_axis: Some(6_378_273.0),
+                semi_minor_axis: None,
+                inverse_flattening: Some(298.279_411_123_064),
+            },
+            false_easting: 100.0,
+            false_northing: 0.0,
+        })),
+        grid_mapping(&data_set, "temperature")
+    );
+    let rain_grid_mapping: GridMapping = grid_mapping(&data_set, "rain").unwrap().unwrap();
+    assert_eq!(Projection::Other(String::from("geostationary")),   rain_grid_mapping.projection);
+    assert_eq!(Some(6_371_000.0),                                   rain_grid_mapping.earth_shape.earth_radius);
+    assert_eq!(Ok(None),                                            grid_mapping(&data_set, "no_grid_mapping"));
+
+    // Errors
+    assert_eq!(
+        Err(GridMappingError::VariableNotDefined(String::from("undef_var"))),
+        grid_mapping(&data_set, "undef_var")
+    );
+    assert_eq!(
+        Err(GridMappingError::ContainerNotDefined{var_name: String::from("pressure"), container_name: String::from("undef_container")}),
+        grid_mapping(&data_set, "pressure")
+    );
+    assert_eq!(
+        Err(GridMappingError::ParameterNotDefined{container_name: String::from("crs"), param_name: String::from("standard_parallel")}),
+        grid_mapping(&data_set, "humidity")
+    );
+    assert_eq!(
+        Err(GridMappingError::NameNotDefined(String::from("other"))),
+        grid_mapping(&data_set, "wind")
+    );
+}
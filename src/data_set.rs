@@ -8,11 +8,30 @@ pub use attribute::Attribute;
 mod variable;
 pub use variable::Variable;
 
+mod cdl;
+mod json;
+pub(crate) use json::{json_numbers, json_string};
+
+mod diff;
+pub use diff::{DataSetDiff, DimensionDiff, VariableDiff, AttributeDiff, DataDiff, Tolerance, compare_vars};
+
+mod merge;
+pub use merge::{MergePolicy, MergeReport};
+
+mod builder;
+pub use builder::{DataSetBuilder, VariableBuilder};
+
+mod in_memory;
+pub use in_memory::InMemoryDataSet;
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+
 mod tests;
 
-use std::{cell::RefMut, ops::Deref, rc::Rc};
+use std::{cell::RefMut, collections::HashMap, ops::Deref, rc::Rc};
 
-use crate::{DataType, InvalidDataSet};
+use crate::{DataType, DateTime, InvalidDataSet, NcType, NC_MAX_NAME_SIZE};
 use crate::data_vector::DataVector;
 
 /// Default fill value for the `i8` elements (same value as `NC_FILL_BYTE` defined in the header file [netcdf.h](https://www.unidata.ucar.edu/software/netcdf/docs/netcdf_8h.html))
@@ -63,6 +82,38 @@ pub const NC_FILL_F32: f32 = 9.9692099683868690e+36;
 /// assert_eq!([0x47, 0x9e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], NC_FILL_F64.to_be_bytes());
 /// ```
 pub const NC_FILL_F64: f64 = 9.9692099683868690e+36;
+/// Default fill value for the `u16` elements (same value as `NC_FILL_USHORT` defined in the header file [netcdf.h](https://www.unidata.ucar.edu/software/netcdf/docs/netcdf_8h.html))
+///
+/// ```
+/// # use netcdf3::NC_FILL_U16;
+/// // Written bytes in the NetCDF-3 files
+/// assert_eq!([0xff, 0xff], NC_FILL_U16.to_be_bytes());
+/// ```
+pub const NC_FILL_U16: u16 = 65535;
+/// Default fill value for the `u32` elements (same value as `NC_FILL_UINT` defined in the header file [netcdf.h](https://www.unidata.ucar.edu/software/netcdf/docs/netcdf_8h.html))
+///
+/// ```
+/// # use netcdf3::NC_FILL_U32;
+/// // Written bytes in the NetCDF-3 files
+/// assert_eq!([0xff, 0xff, 0xff, 0xff], NC_FILL_U32.to_be_bytes());
+/// ```
+pub const NC_FILL_U32: u32 = 4294967295;
+/// Default fill value for the `i64` elements (same value as `NC_FILL_INT64` defined in the header file [netcdf.h](https://www.unidata.ucar.edu/software/netcdf/docs/netcdf_8h.html))
+///
+/// ```
+/// # use netcdf3::NC_FILL_I64;
+/// // Written bytes in the NetCDF-3 files
+/// assert_eq!([0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02], NC_FILL_I64.to_be_bytes());
+/// ```
+pub const NC_FILL_I64: i64 = -9223372036854775806;
+/// Default fill value for the `u64` elements (same value as `NC_FILL_UINT64` defined in the header file [netcdf.h](https://www.unidata.ucar.edu/software/netcdf/docs/netcdf_8h.html))
+///
+/// ```
+/// # use netcdf3::NC_FILL_U64;
+/// // Written bytes in the NetCDF-3 files
+/// assert_eq!([0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe], NC_FILL_U64.to_be_bytes());
+/// ```
+pub const NC_FILL_U64: u64 = 18446744073709551614;
 
 /// Maximum length of the *fixed-size* dimensions
 ///
@@ -195,6 +246,29 @@ pub const NC_MAX_DIM_SIZE: usize = (std::i32::MAX - 3) as usize;
 /// ```
 pub const NC_MAX_VAR_DIMS: usize = 1024;
 
+/// Implementation limits of a [`DataSet`](struct.DataSet.html), checked while its definition (see the method [`DataSet::with_limits`](struct.DataSet.html#method.with_limits)).
+///
+/// The default limits are those of the NetCDF-3 format. Larger limits have no effect, the limits of the format are always checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Limits {
+    /// Maximum size of the *fixed-size* dimensions (default: [`NC_MAX_DIM_SIZE`](constant.NC_MAX_DIM_SIZE.html))
+    pub max_dim_size: usize,
+    /// Maximum number of dimensions per variable (default: [`NC_MAX_VAR_DIMS`](constant.NC_MAX_VAR_DIMS.html))
+    pub max_var_dims: usize,
+    /// Maximum size (number of bytes) of the names of the dimensions, the variables and the attributes (default: [`NC_MAX_NAME_SIZE`](constant.NC_MAX_NAME_SIZE.html))
+    pub max_name_size: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        return Limits{
+            max_dim_size: NC_MAX_DIM_SIZE,
+            max_var_dims: NC_MAX_VAR_DIMS,
+            max_name_size: NC_MAX_NAME_SIZE,
+        };
+    }
+}
+
 /// Allows to define the NetCDF-3 data sets
 ///
 /// # Examples
@@ -253,25 +327,133 @@ pub const NC_MAX_VAR_DIMS: usize = 1024;
 /// data_set.add_var_attr_u8("air_temperature", "units", String::from("Celsius").into_bytes()).unwrap();
 ///
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 pub struct DataSet {
     pub(crate) unlimited_dim: Option<Rc<Dimension>>,
     pub(crate) dims: Vec<Rc<Dimension>>,
     pub(crate) attrs: Vec<Attribute>,
     pub(crate) vars: Vec<Variable>,
+    /// Positions of the dimensions in `dims`, indexed by their names
+    dim_indices: HashMap<String, usize>,
+    /// Positions of the variables in `vars`, indexed by their names
+    var_indices: HashMap<String, usize>,
+    /// Implementation limits checked while the definition
+    limits: Limits,
+}
+
+/// Deep copy of the data set.
+///
+/// The dimensions are copied once, and shared by the copied variables. So renaming a dimension or appending a record
+/// to the copy does not modify the original data set.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::DataSet;
+///
+/// let mut template: DataSet = DataSet::new();
+/// template.add_fixed_dim("latitude", 3).unwrap();
+/// template.add_var_f32("latitude", &["latitude"]).unwrap();
+///
+/// let mut data_set: DataSet = template.clone();
+/// assert_eq!(template,                                    data_set);
+///
+/// data_set.rename_dim("latitude", "lat").unwrap();
+/// assert_eq!(vec!["lat"],                                 data_set.get_var("latitude").unwrap().dim_names());
+/// assert_eq!(vec!["latitude"],                            template.get_var("latitude").unwrap().dim_names());
+/// ```
+impl Clone for DataSet {
+    fn clone(&self) -> Self {
+        let dims: Vec<Rc<Dimension>> = self.dims.iter().map(|dim: &Rc<Dimension>| Rc::new(dim.deref().clone())).collect();
+        // Returns the copy of a dimension of the data set
+        let copy_dim = |dim: &Rc<Dimension>| -> Rc<Dimension> {
+            return match self.dims.iter().position(|other_dim: &Rc<Dimension>| Rc::ptr_eq(dim, other_dim)) {
+                Some(index) => Rc::clone(&dims[index]),
+                None => Rc::new(dim.deref().clone()),  // not expected
+            };
+        };
+        let vars: Vec<Variable> = self.vars.iter().map(|var: &Variable| {
+            Variable{
+                name: var.name.clone(),
+                unlimited_dim: var.unlimited_dim.as_ref().map(copy_dim),
+                dims: var.dims.iter().map(copy_dim).collect(),
+                attrs: var.attrs.clone(),
+                data_type: var.data_type.clone(),
+            }
+        }).collect();
+        return DataSet{
+            unlimited_dim: self.unlimited_dim.as_ref().map(copy_dim),
+            dims: dims,
+            attrs: self.attrs.clone(),
+            vars: vars,
+            dim_indices: self.dim_indices.clone(),
+            var_indices: self.var_indices.clone(),
+            limits: self.limits.clone(),
+        };
+    }
+}
+
+/// Compares the definitions of the data sets (the dimensions, the attributes and the variables), their implementation
+/// limits are not compared.
+impl PartialEq for DataSet {
+    fn eq(&self, other: &Self) -> bool {
+        return self.unlimited_dim == other.unlimited_dim
+            && self.dims == other.dims
+            && self.attrs == other.attrs
+            && self.vars == other.vars;
+    }
 }
 
 impl DataSet {
     // Creates an new empty NetCDF-3 dataset.
     pub fn new() -> DataSet {
+        return DataSet::with_limits(Limits::default());
+    }
+
+    /// Creates an empty data set, whose definition is checked with stricter implementation limits than those of the NetCDF-3 format.
+    ///
+    /// The variables edited directly (see the method `get_var_mut`) are not checked, the method [`validate`](struct.DataSet.html#method.validate) checks all of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, Limits, InvalidDataSet};
+    ///
+    /// let mut data_set: DataSet = DataSet::with_limits(Limits{max_dim_size: 1_000, max_name_size: 8, ..Limits::default()});
+    ///
+    /// data_set.add_fixed_dim("latitude", 180).unwrap();
+    /// assert_eq!(
+    ///     InvalidDataSet::MaximumFixedDimensionSizeExceeded{dim_name: String::from("depth"), get: 5_000},
+    ///     data_set.add_fixed_dim("depth", 5_000).unwrap_err()
+    /// );
+    /// assert_eq!(
+    ///     InvalidDataSet::DimensionNameNotValid(String::from("longitude")),
+    ///     data_set.add_fixed_dim("longitude", 360).unwrap_err()
+    /// );
+    /// assert_eq!(8,                           data_set.limits().max_name_size);
+    /// ```
+    pub fn with_limits(limits: Limits) -> DataSet {
         DataSet {
             unlimited_dim: None,
             dims: vec![],
             attrs: vec![],
             vars: vec![],
+            dim_indices: HashMap::new(),
+            var_indices: HashMap::new(),
+            limits: limits,
         }
     }
 
+    /// Returns the implementation limits of the data set.
+    pub fn limits(&self) -> &Limits {
+        return &self.limits;
+    }
+
+    /// Returns `true` if the size of the name does not exceed the limit of the data set.
+    pub(crate) fn check_name_size(&self, name: &str) -> bool {
+        return name.len() <= self.limits.max_name_size;
+    }
+
     // ----------------------------------------------------------------
     //
     //                          Dimensions
@@ -282,10 +464,17 @@ impl DataSet {
     /// Returns a error if an other dimension with the same name is already defined.
     pub fn add_fixed_dim<T: std::convert::AsRef<str>>(&mut self, dim_name: T, dim_size: usize) -> Result<(), InvalidDataSet> {
         let dim_name: &str = dim_name.as_ref();
-        if self.dims.iter().position(|dim| *dim.name.borrow() == dim_name).is_some() {
+        if self.dim_indices.contains_key(dim_name) {
             return Err(InvalidDataSet::DimensionAlreadyExists(dim_name.to_string()));
         }
+        if !self.check_name_size(dim_name) {
+            return Err(InvalidDataSet::DimensionNameNotValid(dim_name.to_string()));
+        }
+        if dim_size > self.limits.max_dim_size {
+            return Err(InvalidDataSet::MaximumFixedDimensionSizeExceeded{dim_name: dim_name.to_string(), get: dim_size});
+        }
         let new_fixed_size_dim = Rc::new(Dimension::new_fixed_size(dim_name, dim_size)?);
+        self.dim_indices.insert(dim_name.to_string(), self.dims.len());
         self.dims.push(new_fixed_size_dim);
         return Ok(());
     }
@@ -300,10 +489,14 @@ impl DataSet {
         if let Some(unlimited_dim) = &self.unlimited_dim {
             return Err(InvalidDataSet::UnlimitedDimensionAlreadyExists(unlimited_dim.name()));
         }
-        if self.dims.iter().position(|dim| *dim.name.borrow() == dim_name).is_some() {
+        if self.dim_indices.contains_key(dim_name) {
             return Err(InvalidDataSet::DimensionAlreadyExists(dim_name.to_string()));
         }
+        if !self.check_name_size(dim_name) {
+            return Err(InvalidDataSet::DimensionNameNotValid(dim_name.to_string()));
+        }
         let new_unlimited_dim = Rc::new(Dimension::new_unlimited_size(dim_name, dim_size)?);
+        self.dim_indices.insert(dim_name.to_string(), self.dims.len());
         self.dims.push(Rc::clone(&new_unlimited_dim));
         self.unlimited_dim = Some(new_unlimited_dim);
         return Ok(());
@@ -335,6 +528,11 @@ impl DataSet {
         return self.dims.iter().map(|dim: &Rc<Dimension>| Rc::clone(dim)).collect();
     }
 
+    /// Returns an iterator over the dimensions, without allocating a `Vec` like the method `get_dims`.
+    pub fn dims(&self) -> std::slice::Iter<'_, Rc<Dimension>> {
+        return self.dims.iter();
+    }
+
     /// Returns the names all the dimensions defined in the data set.
     pub fn dim_names(&self) -> Vec<String>
     {
@@ -394,6 +592,7 @@ impl DataSet {
         }
 
         let removed_dim: Rc<Dimension> = self.dims.remove(removed_dim_index);
+        self.dim_indices = self.dims.iter().enumerate().map(|(index, dim): (usize, &Rc<Dimension>)| (dim.name(), index)).collect();
 
         // Remove the *unlimited-size* dimension if necessary
         if removed_dim.is_unlimited() {
@@ -414,7 +613,7 @@ impl DataSet {
             return Ok(());
         }
 
-        let (_dim_position, renamed_dim): (usize, &Rc<Dimension>) = match self.find_dim_from_name(old_dim_name) {
+        let (dim_position, renamed_dim): (usize, &Rc<Dimension>) = match self.find_dim_from_name(old_dim_name) {
             None => {
                 return Err(InvalidDataSet::DimensionNotDefined(old_dim_name.to_string()));
             }
@@ -426,23 +625,103 @@ impl DataSet {
         }
 
         Dimension::check_dim_name(new_dim_name)?;
+        if !self.check_name_size(new_dim_name) {
+            return Err(InvalidDataSet::DimensionNameNotValid(new_dim_name.to_string()));
+        }
+
+        {
+            let mut dim_name: RefMut<String> = renamed_dim.name.borrow_mut();
+            *dim_name = new_dim_name.to_string();
+        }
+        self.dim_indices.remove(old_dim_name);
+        self.dim_indices.insert(new_dim_name.to_string(), dim_position);
+        return Ok(());
+    }
 
-        let mut dim_name: RefMut<String> = renamed_dim.name.borrow_mut();
-        *dim_name = new_dim_name.to_string();
+    /// Changes the size of a *fixed-size* dimension, the variables defined over it are resized too.
+    ///
+    /// The data set only contains definitions, so it can be resized freely before being written
+    /// (see [`InMemoryDataSet::resize_fixed_dim`](struct.InMemoryDataSet.html#method.resize_fixed_dim) for the data sets coupled with their data).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, InvalidDataSet};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.set_unlimited_dim("time", 2).unwrap();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("sst", &["time", "latitude"]).unwrap();
+    /// assert_eq!(Some(6),                             data_set.var_len("sst"));
+    ///
+    /// data_set.resize_fixed_dim("latitude", 5).unwrap();
+    /// assert_eq!(Some(5),                             data_set.dim_size("latitude"));
+    /// assert_eq!(Some(10),                            data_set.var_len("sst"));
+    ///
+    /// assert_eq!(
+    ///     InvalidDataSet::DimensionNotFixedSize(String::from("time")),
+    ///     data_set.resize_fixed_dim("time", 4).unwrap_err()
+    /// );
+    /// ```
+    pub fn resize_fixed_dim(&mut self, dim_name: &str, new_dim_size: usize) -> Result<(), InvalidDataSet> {
+        let (dim_position, old_dim): (usize, Rc<Dimension>) = match self.find_dim_from_name(dim_name) {
+            None => return Err(InvalidDataSet::DimensionNotDefined(dim_name.to_string())),
+            Some((dim_position, old_dim)) => (dim_position, Rc::clone(old_dim)),
+        };
+        if old_dim.is_unlimited() {
+            return Err(InvalidDataSet::DimensionNotFixedSize(dim_name.to_string()));
+        }
+        if new_dim_size > self.limits.max_dim_size {
+            return Err(InvalidDataSet::MaximumFixedDimensionSizeExceeded{dim_name: dim_name.to_string(), get: new_dim_size});
+        }
+        // The fixed sizes are not mutable, so the dimension is replaced in the data set and in its variables
+        let new_dim: Rc<Dimension> = Rc::new(Dimension::new_fixed_size(dim_name, new_dim_size)?);
+        for var in self.vars.iter_mut() {
+            for var_dim in var.dims.iter_mut() {
+                if Rc::ptr_eq(var_dim, &old_dim) {
+                    *var_dim = Rc::clone(&new_dim);
+                }
+            }
+        }
+        self.dims[dim_position] = new_dim;
         return Ok(());
     }
 
     /// Find a dataset's dimension from is name.
     fn find_dim_from_name(&self, dim_name: &str) -> Option<(usize, &Rc<Dimension>)> {
-        return self
-            .dims
-            .iter()
-            .position(|dim| {
-                return dim.name.borrow().deref() == dim_name;
-            })
-            .map(|index| {
-                return (index, &self.dims[index]);
-            });
+        return self.dim_indices.get(dim_name).map(|index: &usize| {
+            return (*index, &self.dims[*index]);
+        });
+    }
+
+    /// Returns the dimension at the position `dim_id` (the dimension ID used by the NetCDF C library).
+    ///
+    /// Returns `None` if the dimension ID is not defined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::DataSet;
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.set_unlimited_dim("time", 2).unwrap();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("sst", &["time", "latitude"]).unwrap();
+    ///
+    /// assert_eq!(Some(1),                                 data_set.dim_index("latitude"));
+    /// assert_eq!(Some(String::from("latitude")),          data_set.get_dim_by_index(1).map(|dim| dim.name()));
+    /// assert_eq!(None,                                    data_set.get_dim_by_index(2));
+    /// assert_eq!(Some(vec![0, 1]),                        data_set.get_var_dim_ids("sst"));
+    /// ```
+    pub fn get_dim_by_index(&self, dim_id: usize) -> Option<Rc<Dimension>> {
+        return self.dims.get(dim_id).map(Rc::clone);
+    }
+
+    /// Returns the position of the dimension (the dimension ID used by the NetCDF C library).
+    ///
+    /// Returns `None` if the dimension is not defined.
+    pub fn dim_index(&self, dim_name: &str) -> Option<usize> {
+        return self.find_dim_from_name(dim_name).map(|(dim_index, _dim): (usize, &Rc<Dimension>)| dim_index);
     }
 
     pub fn get_dims_from_dim_ids(&self, dim_ids: &[usize]) -> Result<Vec<Rc<Dimension>>, InvalidDataSet> {
@@ -462,12 +741,14 @@ impl DataSet {
         Ok(dim_ids.iter().map(|dim_id: &usize| Rc::clone(&self.dims[*dim_id])).collect())
     }
 
-    pub(crate) fn get_var_dim_ids(&self, var_name: &str) -> Option<Vec<usize>> {
+    /// Returns the positions of the dimensions of the variable (the dimension IDs used by the NetCDF C library).
+    ///
+    /// Returns `None` if the variable is not defined.
+    pub fn get_var_dim_ids(&self, var_name: &str) -> Option<Vec<usize>> {
         let var: &Variable = self.find_var_from_name(var_name).ok()?.1;
         let var_dims: &[Rc<Dimension>] = &var.dims;
         let var_dim_ids: Vec<usize> = var_dims.iter().map(|var_dim: &Rc<Dimension>| {
-            self.dims.iter()
-                .position(|data_set_dim: &Rc<Dimension>| Rc::ptr_eq(data_set_dim, var_dim))
+            self.dim_index(&var_dim.name())
                 .expect("Shouldn't have occurred! All variable dimensions are defined in the data set, their positions should have been found.")
             // Can't panic :all dimensions
         }).collect();
@@ -549,10 +830,56 @@ impl DataSet {
     }
 
     pub(crate) fn add_var_using_dim_refs(&mut self, var_name: &str, var_dims: Vec<Rc<Dimension>>, data_type: DataType) -> Result<&Variable, InvalidDataSet> {
-        let _ = self.vars.push(Variable::new(var_name, var_dims, data_type)?);
+        let new_var: Variable = Variable::new(var_name, var_dims, data_type)?;
+        if !self.check_name_size(var_name) {
+            return Err(InvalidDataSet::VariableNameNotValid(var_name.to_string()));
+        }
+        if new_var.dims.len() > self.limits.max_var_dims {
+            return Err(InvalidDataSet::MaximumDimensionsPerVariableExceeded{var_name: var_name.to_string(), num_dims: new_var.dims.len()});
+        }
+        let _ = self.vars.push(new_var);
+        self.var_indices.insert(var_name.to_string(), self.vars.len() - 1);
         Ok(self.vars.last().unwrap())
     }
 
+    /// Adds a *fixed-size* dimension and its coordinate variable, a 1-D variable having the same name.
+    ///
+    /// The optional attributes `units` and `axis` of the variable are also added.
+    ///
+    /// Returns an error if the dimension or the variable cannot be added, and then nothing is added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, DataType};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_coord_var("latitude", 180, DataType::F32, Some("degrees_north"), Some("Y")).unwrap();
+    /// data_set.add_coord_var("depth", 10, DataType::F64, None, None).unwrap();
+    ///
+    /// assert_eq!(vec!["latitude", "depth"],               data_set.dim_names());
+    /// assert_eq!(Some(180),                               data_set.dim_size("latitude"));
+    /// assert_eq!(vec!["latitude"],                        data_set.get_var("latitude").unwrap().dim_names());
+    /// assert_eq!(Some(DataType::F32),                     data_set.var_data_type("latitude"));
+    /// assert_eq!(Some(String::from("degrees_north")),     data_set.get_var_attr_as_string("latitude", "units"));
+    /// assert_eq!(Some(String::from("Y")),                 data_set.get_var_attr_as_string("latitude", "axis"));
+    /// assert_eq!(Some(0),                                 data_set.num_var_attrs("depth"));
+    /// ```
+    pub fn add_coord_var(&mut self, name: &str, size: usize, data_type: DataType, units: Option<&str>, axis: Option<&str>) -> Result<(), InvalidDataSet> {
+        if self.find_var_from_name(name).is_ok() {
+            return Err(InvalidDataSet::VariableAlreadyExists(name.to_string()));
+        }
+        self.add_fixed_dim(name, size)?;
+        self.add_var(name, &[name], data_type)?;
+        if let Some(units) = units {
+            self.add_var_attr_string(name, "units", units)?;
+        }
+        if let Some(axis) = axis {
+            self.add_var_attr_string(name, "axis", axis)?;
+        }
+        return Ok(());
+    }
+
     /// Add a new `i8` type variable  defined over named dimensions (see the [add_var](struct.DataSet.html#method.add_var) method).
     pub fn add_var_i8<T: std::convert::AsRef<str>>(&mut self, var_name: &str, dims_name: &[T]) -> Result<(), InvalidDataSet> {
         self.add_var(var_name, dims_name, DataType::I8)
@@ -583,6 +910,67 @@ impl DataSet {
         self.add_var(var_name, dims_name, DataType::F64)
     }
 
+    /// Add a new `u16` type variable  defined over named dimensions (see the [add_var](struct.DataSet.html#method.add_var) method).
+    ///
+    /// Only supported by the CDF-5 version.
+    pub fn add_var_u16<T: std::convert::AsRef<str>>(&mut self, var_name: &str, dims_name: &[T]) -> Result<(), InvalidDataSet> {
+        self.add_var(var_name, dims_name, DataType::U16)
+    }
+
+    /// Add a new `u32` type variable  defined over named dimensions (see the [add_var](struct.DataSet.html#method.add_var) method).
+    ///
+    /// Only supported by the CDF-5 version.
+    pub fn add_var_u32<T: std::convert::AsRef<str>>(&mut self, var_name: &str, dims_name: &[T]) -> Result<(), InvalidDataSet> {
+        self.add_var(var_name, dims_name, DataType::U32)
+    }
+
+    /// Add a new `i64` type variable  defined over named dimensions (see the [add_var](struct.DataSet.html#method.add_var) method).
+    ///
+    /// Only supported by the CDF-5 version.
+    pub fn add_var_i64<T: std::convert::AsRef<str>>(&mut self, var_name: &str, dims_name: &[T]) -> Result<(), InvalidDataSet> {
+        self.add_var(var_name, dims_name, DataType::I64)
+    }
+
+    /// Add a new `u64` type variable  defined over named dimensions (see the [add_var](struct.DataSet.html#method.add_var) method).
+    ///
+    /// Only supported by the CDF-5 version.
+    pub fn add_var_u64<T: std::convert::AsRef<str>>(&mut self, var_name: &str, dims_name: &[T]) -> Result<(), InvalidDataSet> {
+        self.add_var(var_name, dims_name, DataType::U64)
+    }
+
+    /// Add a new `u8` type variable storing strings of at most `max_len` bytes (see the [add_var](struct.DataSet.html#method.add_var) method).
+    ///
+    /// The auxiliary *fixed-size* dimension `<var_name>_strlen` is appended to the dataset and used as the last dimension of the variable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, DataType};
+    ///
+    /// let mut data_set = DataSet::new();
+    /// data_set.add_fixed_dim("station", 3).unwrap();
+    /// data_set.add_string_var("station_name", &["station"], 16).unwrap();
+    ///
+    /// assert_eq!(Some(16),                                data_set.dim_size("station_name_strlen"));
+    /// assert_eq!(Some(DataType::U8),                      data_set.var_data_type("station_name"));
+    /// assert_eq!(Some(vec![String::from("station"), String::from("station_name_strlen")]), data_set.get_var("station_name").map(|var| var.dim_names()));
+    /// ```
+    pub fn add_string_var<T: std::convert::AsRef<str>>(&mut self, var_name: &str, dims_name: &[T], max_len: usize) -> Result<(), InvalidDataSet> {
+        if self.has_var(var_name) {
+            return Err(InvalidDataSet::VariableAlreadyExists(var_name.to_string()));
+        }
+        let strlen_dim_name: String = format!("{}_strlen", var_name);
+        self.add_fixed_dim(&strlen_dim_name, max_len)?;
+        let mut var_dims_name: Vec<&str> = dims_name.iter().map(|dim_name: &T| dim_name.as_ref()).collect();
+        var_dims_name.push(&strlen_dim_name);
+        if let Err(err) = self.add_var(var_name, &var_dims_name, DataType::U8) {
+            // Remove the auxiliary dimension, it is not used
+            let _strlen_dim: Rc<Dimension> = self.remove_dim(&strlen_dim_name)?;
+            return Err(err);
+        }
+        return Ok(());
+    }
+
     /// Returns the number of defined variables.
     pub fn num_vars(&self) -> usize {
         self.vars.len()
@@ -645,6 +1033,79 @@ impl DataSet {
         return self.vars.iter().collect();
     }
 
+    /// Returns an iterator over the variables, without allocating a `Vec` like the method `get_vars`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, Variable};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    /// data_set.add_var_attr_string("latitude", "units", "degrees_north").unwrap();
+    /// data_set.add_var_f32("sst", &["latitude"]).unwrap();
+    ///
+    /// let var_names: Vec<&str> = data_set.vars().map(|var: &Variable| var.name()).collect();
+    /// assert_eq!(vec!["latitude", "sst"],         var_names);
+    ///
+    /// let num_attrs: usize = data_set.vars().map(|var: &Variable| var.attrs().len()).sum();
+    /// assert_eq!(1,                               num_attrs);
+    /// ```
+    pub fn vars(&self) -> std::slice::Iter<'_, Variable> {
+        return self.vars.iter();
+    }
+
+    /// Returns the variables matching the `predicate`, in their definition order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, Variable};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.set_unlimited_dim("time", 2).unwrap();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f64("time", &["time"]).unwrap();
+    /// data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    /// data_set.add_var_f32("sst", &["time", "latitude"]).unwrap();
+    ///
+    /// let record_vars: Vec<&Variable> = data_set.find_vars_where(|var: &Variable| var.is_record_var());
+    /// let record_var_names: Vec<&str> = record_vars.iter().map(|var: &&Variable| var.name()).collect();
+    /// assert_eq!(vec!["time", "sst"],             record_var_names);
+    /// ```
+    pub fn find_vars_where<P>(&self, mut predicate: P) -> Vec<&Variable>
+    where
+        P: FnMut(&Variable) -> bool,
+    {
+        return self.vars.iter().filter(|var: &&Variable| predicate(var)).collect();
+    }
+
+    /// Returns the variables having the text attribute `attr_name` equal to `attr_value`, in their definition order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, Variable};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("station", 3).unwrap();
+    /// data_set.add_var_f32("temp", &["station"]).unwrap();
+    /// data_set.add_var_attr_string("temp", "standard_name", "air_temperature").unwrap();
+    /// data_set.add_var_f32("temp_min", &["station"]).unwrap();
+    /// data_set.add_var_attr_string("temp_min", "standard_name", "air_temperature").unwrap();
+    /// data_set.add_var_f32("pressure", &["station"]).unwrap();
+    /// data_set.add_var_attr_string("pressure", "standard_name", "air_pressure").unwrap();
+    ///
+    /// let vars: Vec<&Variable> = data_set.vars_with_attr("standard_name", "air_temperature");
+    /// assert_eq!(2,                               vars.len());
+    /// assert_eq!("temp",                          vars[0].name());
+    /// assert_eq!("temp_min",                      vars[1].name());
+    /// ```
+    pub fn vars_with_attr(&self, attr_name: &str, attr_value: &str) -> Vec<&Variable> {
+        return self.find_vars_where(|var: &Variable| var.get_attr_as_string(attr_name).as_deref() == Some(attr_value));
+    }
+
     /// Returns the names all the variables defined in the dataset.
     pub fn get_var_names(&self) -> Vec<String>
     {
@@ -675,52 +1136,303 @@ impl DataSet {
         }
         // Check the validity of the new name
         let _ = Variable::check_var_name(new_var_name)?;
+        if !self.check_name_size(new_var_name) {
+            return Err(InvalidDataSet::VariableNameNotValid(new_var_name.to_string()));
+        }
 
         // Then rename the variable
         self.vars[renamed_var_index].name = new_var_name.to_string();
+        self.var_indices.remove(old_var_name);
+        self.var_indices.insert(new_var_name.to_string(), renamed_var_index);
 
         return Ok(());
     }
 
+    /// Changes the data type of a variable, and returns its previous data type.
+    ///
+    /// The attributes `_FillValue`, `missing_value`, `valid_min`, `valid_max` and `valid_range`, which have the same data type as the variable,
+    /// are converted too (see the method [`DataVector::cast`](enum.DataVector.html#method.cast)). The data of the variable can be converted with the same method.
+    ///
+    /// Returns an error if the variable is not defined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, DataType, DataVector};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f64("sst", &["latitude"]).unwrap();
+    /// data_set.add_var_attr_f64("sst", "_FillValue", vec![-999.0]).unwrap();
+    /// data_set.add_var_attr_f64("sst", "scale_factor", vec![0.01]).unwrap();
+    /// let sst_data = DataVector::F64(vec![290.15, 291.0, -999.0]);
+    ///
+    /// assert_eq!(Ok(DataType::F64),                   data_set.cast_var("sst", DataType::F32));
+    /// assert_eq!(Some(DataType::F32),                 data_set.var_data_type("sst"));
+    /// assert_eq!(Some(&[-999.0_f32][..]),             data_set.get_var_attr_f32("sst", "_FillValue"));
+    /// assert_eq!(Some(&[0.01_f64][..]),               data_set.get_var_attr_f64("sst", "scale_factor"));
+    ///
+    /// let sst_data: DataVector = sst_data.cast(DataType::F32);
+    /// assert_eq!(DataVector::F32(vec![290.15, 291.0, -999.0]),    sst_data);
+    /// ```
+    pub fn cast_var(&mut self, var_name: &str, data_type: DataType) -> Result<DataType, InvalidDataSet> {
+        const CAST_ATTR_NAMES: [&str; 5] = ["_FillValue", "missing_value", "valid_min", "valid_max", "valid_range"];
+
+        let var_index: usize = self.find_var_from_name(var_name)?.0;
+        let var: &mut Variable = &mut self.vars[var_index];
+        let prev_data_type: DataType = std::mem::replace(&mut var.data_type, data_type.clone());
+        for attr in var.attrs.iter_mut() {
+            if CAST_ATTR_NAMES.contains(&attr.name.as_str()) && attr.data_type() == prev_data_type {
+                attr.data = attr.data.cast(data_type.clone());
+            }
+        }
+        return Ok(prev_data_type);
+    }
+
     /// Remove the variable.
     pub fn remove_var(&mut self, var_name: &str) -> Result<Variable, InvalidDataSet> {
         let var_index: usize = self.find_var_from_name(var_name)?.0;
         let removed_var: Variable = self.vars.remove(var_index);
+        self.var_indices = self.vars.iter().enumerate().map(|(index, var): (usize, &Variable)| (var.name.clone(), index)).collect();
         return Ok(removed_var);
     }
 
-    /// Finds the dataset's variable from his name, and returns a tuple containing :
+    /// Removes the variable, and the dimensions which were only used by it.
     ///
-    /// - 0 : the index of the variable
-    /// - 1 : a reference to the variable
-    pub(crate) fn find_var_from_name(&self, var_name: &str) -> Result<(usize, &Variable), InvalidDataSet> {
-        return self
-            .vars
-            .iter()
-            .position(|var: &Variable| var.name == var_name)
-            .map(|var_index| (var_index, &self.vars[var_index]))
-            .ok_or(InvalidDataSet::VariableNotDefined(var_name.to_string()));
+    /// The other unused dimensions are kept (see the method [remove_unused_dims](struct.DataSet.html#method.remove_unused_dims)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::DataSet;
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_fixed_dim("longitude", 4).unwrap();
+    /// data_set.add_fixed_dim("depth", 5).unwrap();
+    /// data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    /// data_set.add_var_f32("sst", &["latitude", "longitude"]).unwrap();
+    ///
+    /// data_set.remove_var_and_unused_dims("sst").unwrap();
+    /// assert_eq!(vec!["latitude", "depth"],       data_set.dim_names());
+    /// assert_eq!(vec!["latitude"],                data_set.get_var_names());
+    /// ```
+    pub fn remove_var_and_unused_dims(&mut self, var_name: &str) -> Result<Variable, InvalidDataSet> {
+        let removed_var: Variable = self.remove_var(var_name)?;
+        for dim in removed_var.dims.iter() {
+            if !self.vars.iter().any(|var: &Variable| var.use_dim(&dim.name())) && self.has_dim(&dim.name()) {
+                self.remove_dim(&dim.name())?;
+            }
+        }
+        return Ok(removed_var);
     }
 
-    // ----------------------------------------------------------------
-    //
-    //                  Variable attributes
-    //
-    // ----------------------------------------------------------------
-    // Add a `i8` attribute in the variable.
-    pub fn add_var_attr_i8(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<i8>) -> Result<(), InvalidDataSet> {
-        // Check that the variable is defined
-        let var_index: usize = self.find_var_from_name(var_name)?.0;
-        // Append the new attribute
-        let var: &mut Variable = &mut self.vars[var_index];
-        var.add_attr_i8(attr_name, var_attr_value)?;
-        Ok(())
+    /// Removes and returns the dimensions which are not used by any variable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use netcdf3::{DataSet, Dimension};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.set_unlimited_dim("time", 2).unwrap();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_fixed_dim("longitude", 4).unwrap();
+    /// data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    ///
+    /// let removed_dims: Vec<Rc<Dimension>> = data_set.remove_unused_dims();
+    /// assert_eq!(vec!["time", "longitude"],       removed_dims.iter().map(|dim| dim.name()).collect::<Vec<String>>());
+    /// assert_eq!(vec!["latitude"],                data_set.dim_names());
+    /// assert_eq!(false,                           data_set.has_unlimited_dim());
+    /// ```
+    pub fn remove_unused_dims(&mut self) -> Vec<Rc<Dimension>> {
+        let unused_dim_names: Vec<String> = self.dims.iter()
+            .filter(|dim: &&Rc<Dimension>| !self.vars.iter().any(|var: &Variable| var.use_dim(&dim.name())))
+            .map(|dim: &Rc<Dimension>| dim.name())
+            .collect();
+        return unused_dim_names.iter().filter_map(|dim_name: &String| self.remove_dim(dim_name).ok()).collect();
     }
 
-    // Add a `u8` attribute in the variable.
-    pub fn add_var_attr_u8(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<u8>) -> Result<(), InvalidDataSet> {
-        // Check that the variable is defined
-        let var_index: usize = self.find_var_from_name(var_name)?.0;
+    /// Returns a new data set containing only the selected variables (with their attributes), the dimensions used by them, and all the global attributes.
+    ///
+    /// The original order of the dimensions and of the variables is kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::DataSet;
+    ///
+    /// let mut data_set = DataSet::new();
+    /// data_set.set_unlimited_dim("time", 2).unwrap();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_fixed_dim("longitude", 4).unwrap();
+    /// data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    /// data_set.add_var_f32("longitude", &["longitude"]).unwrap();
+    /// data_set.add_var_f64("temperature", &["time", "latitude"]).unwrap();
+    /// data_set.add_var_attr_string("temperature", "units", "K").unwrap();
+    /// data_set.add_global_attr_string("title", "example").unwrap();
+    ///
+    /// let sub_data_set: DataSet = data_set.extract_vars(&["temperature", "latitude"]).unwrap();
+    /// assert_eq!(vec!["time", "latitude"],            sub_data_set.dim_names());
+    /// assert_eq!(vec!["latitude", "temperature"],     sub_data_set.get_var_names());
+    /// assert_eq!(Some(2),                             sub_data_set.num_records());
+    /// assert_eq!(Some(String::from("K")),             sub_data_set.get_var_attr_as_string("temperature", "units"));
+    /// assert_eq!(Some(String::from("example")),       sub_data_set.get_global_attr_as_string("title"));
+    /// ```
+    pub fn extract_vars<T: std::convert::AsRef<str>>(&self, var_names: &[T]) -> Result<DataSet, InvalidDataSet> {
+        return self.extract_resized_vars(var_names, &[]);
+    }
+
+    /// Same as [`extract_vars`](struct.DataSet.html#method.extract_vars), but the *fixed-size* dimensions listed in `resized_dims` get new sizes.
+    pub(crate) fn extract_resized_vars<T: std::convert::AsRef<str>>(&self, var_names: &[T], resized_dims: &[(&str, usize)]) -> Result<DataSet, InvalidDataSet> {
+        // Check that all the variables are defined
+        for var_name in var_names.iter() {
+            let _ = self.find_var_from_name(var_name.as_ref())?;
+        }
+        let is_selected = |var: &Variable| -> bool {
+            var_names.iter().any(|var_name: &T| var_name.as_ref() == var.name)
+        };
+        let mut data_set = DataSet::new();
+        // Copy the used dimensions
+        for dim in self.dims.iter() {
+            let is_used: bool = self.vars.iter()
+                .filter(|var: &&Variable| is_selected(var))
+                .any(|var: &Variable| var.dims.iter().any(|var_dim: &Rc<Dimension>| Rc::ptr_eq(var_dim, dim)));
+            if !is_used {
+                continue;
+            }
+            match dim.is_unlimited() {
+                true => data_set.set_unlimited_dim(dim.name(), dim.size())?,
+                false => {
+                    let dim_name: String = dim.name();
+                    let dim_size: usize = resized_dims.iter()
+                        .find(|(resized_dim_name, _size): &&(&str, usize)| *resized_dim_name == dim_name)
+                        .map(|(_resized_dim_name, size): &(&str, usize)| *size)
+                        .unwrap_or_else(|| dim.size());
+                    data_set.add_fixed_dim(dim_name, dim_size)?
+                },
+            }
+        }
+        // Copy the global attributes
+        data_set.attrs = self.attrs.clone();
+        // Copy the variables and their attributes
+        for var in self.vars.iter().filter(|var: &&Variable| is_selected(var)) {
+            data_set.add_var(&var.name, &var.dim_names(), var.data_type())?;
+            let new_var: &mut Variable = data_set.get_var_mut(&var.name).ok_or_else(|| InvalidDataSet::VariableNotDefined(var.name.clone()))?;
+            new_var.attrs = var.attrs.clone();
+        }
+        return Ok(data_set);
+    }
+
+    /// Copies the definition of a variable of an other data set (its data type, its dimensions and its attributes) into the data set.
+    ///
+    /// The missing dimensions are created with the same sizes, the existing dimensions must have the same sizes and types
+    /// (otherwise the error `DimensionAlreadyExists` is returned).
+    ///
+    /// The data set is not modified if an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, DataType};
+    ///
+    /// let mut source: DataSet = DataSet::new();
+    /// source.set_unlimited_dim("time", 2).unwrap();
+    /// source.add_fixed_dim("latitude", 3).unwrap();
+    /// source.add_var_f32("sst", &["time", "latitude"]).unwrap();
+    /// source.add_var_attr_string("sst", "units", "K").unwrap();
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.copy_var_def_from(&source, "sst").unwrap();
+    ///
+    /// assert_eq!(vec!["latitude", "time"],            data_set.dim_names());
+    /// assert_eq!(Some(2),                             data_set.num_records());
+    /// assert_eq!(Some(DataType::F32),                 data_set.var_data_type("sst"));
+    /// assert_eq!(Some(String::from("K")),             data_set.get_var_attr_as_string("sst", "units"));
+    /// ```
+    pub fn copy_var_def_from(&mut self, other: &DataSet, var_name: &str) -> Result<(), InvalidDataSet> {
+        let other_var: &Variable = other.find_var_from_name(var_name)?.1;
+        if self.has_var(var_name) {
+            return Err(InvalidDataSet::VariableAlreadyExists(var_name.to_string()));
+        }
+        // The changes are made on a copy, the data set is only replaced if they are all valid
+        let mut data_set: DataSet = self.clone();
+        for other_dim in other_var.dims.iter() {
+            let dim_name: String = other_dim.name();
+            match data_set.get_dim(&dim_name) {
+                Some(dim) if dim.dim_type() == other_dim.dim_type() && dim.size() == other_dim.size() => {},
+                Some(_dim) => return Err(InvalidDataSet::DimensionAlreadyExists(dim_name)),
+                None => match other_dim.is_unlimited() {
+                    true => data_set.set_unlimited_dim(dim_name, other_dim.size())?,
+                    false => data_set.add_fixed_dim(dim_name, other_dim.size())?,
+                },
+            }
+        }
+        data_set.add_var(var_name, &other_var.dim_names(), other_var.data_type())?;
+        for attr in other_var.attrs.iter() {
+            data_set.add_var_attr_data(var_name, &attr.name, attr.data.clone())?;
+        }
+        *self = data_set;
+        return Ok(());
+    }
+
+    /// Finds the dataset's variable from his name, and returns a tuple containing :
+    ///
+    /// - 0 : the index of the variable
+    /// - 1 : a reference to the variable
+    pub(crate) fn find_var_from_name(&self, var_name: &str) -> Result<(usize, &Variable), InvalidDataSet> {
+        return self.var_indices.get(var_name)
+            .map(|var_index: &usize| (*var_index, &self.vars[*var_index]))
+            .ok_or(InvalidDataSet::VariableNotDefined(var_name.to_string()));
+    }
+
+    // ----------------------------------------------------------------
+    //
+    //                  Variable attributes
+    //
+    // ----------------------------------------------------------------
+    /// Adds an attribute in the variable, the element type is generic (see the trait [NcType](trait.NcType.html)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, DataType};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("sst", &["latitude"]).unwrap();
+    ///
+    /// data_set.add_var_attr("sst", "valid_range", vec![-5_i16, 40]).unwrap();
+    /// data_set.add_var_attr("sst", "scale_factor", vec![0.01_f64]).unwrap();
+    /// assert_eq!(Some(DataType::I16),                 data_set.get_var_attr_data_type("sst", "valid_range"));
+    /// assert_eq!(Some(&[0.01][..]),                   data_set.get_var_attr_f64("sst", "scale_factor"));
+    /// ```
+    pub fn add_var_attr<T: NcType>(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<T>) -> Result<(), InvalidDataSet> {
+        self.add_var_attr_data(var_name, attr_name, T::into_data_vector(var_attr_value))
+    }
+
+    // Add a `i8` attribute in the variable.
+    pub fn add_var_attr_i8(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<i8>) -> Result<(), InvalidDataSet> {
+        // Check that the variable is defined
+        let var_index: usize = self.find_var_from_name(var_name)?.0;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::VariableAttributeNameNotValid{var_name: var_name.to_string(), attr_name: attr_name.to_string()});
+        }
+        // Append the new attribute
+        let var: &mut Variable = &mut self.vars[var_index];
+        var.add_attr_i8(attr_name, var_attr_value)?;
+        Ok(())
+    }
+
+    // Add a `u8` attribute in the variable.
+    pub fn add_var_attr_u8(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<u8>) -> Result<(), InvalidDataSet> {
+        // Check that the variable is defined
+        let var_index: usize = self.find_var_from_name(var_name)?.0;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::VariableAttributeNameNotValid{var_name: var_name.to_string(), attr_name: attr_name.to_string()});
+        }
         // Append the new attribute
         let var: &mut Variable = &mut self.vars[var_index];
         var.add_attr_u8(attr_name, var_attr_value)?;
@@ -736,6 +1448,9 @@ impl DataSet {
     pub fn add_var_attr_i16(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<i16>) -> Result<(), InvalidDataSet> {
         // Check that the variable is defined
         let var_index: usize = self.find_var_from_name(var_name)?.0;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::VariableAttributeNameNotValid{var_name: var_name.to_string(), attr_name: attr_name.to_string()});
+        }
         // Append the new attribute
         let var: &mut Variable = &mut self.vars[var_index];
         var.add_attr_i16(attr_name, var_attr_value)?;
@@ -746,6 +1461,9 @@ impl DataSet {
     pub fn add_var_attr_i32(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<i32>) -> Result<(), InvalidDataSet> {
         // Check that the variable is defined
         let var_index: usize = self.find_var_from_name(var_name)?.0;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::VariableAttributeNameNotValid{var_name: var_name.to_string(), attr_name: attr_name.to_string()});
+        }
         // Append the new attribute
         let var: &mut Variable = &mut self.vars[var_index];
         var.add_attr_i32(attr_name, var_attr_value)?;
@@ -757,6 +1475,9 @@ impl DataSet {
     pub fn add_var_attr_f32(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<f32>) -> Result<(), InvalidDataSet> {
         // Check that the variable is defined
         let var_index: usize = self.find_var_from_name(var_name)?.0;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::VariableAttributeNameNotValid{var_name: var_name.to_string(), attr_name: attr_name.to_string()});
+        }
         // Append the new attribute
         let var: &mut Variable = &mut self.vars[var_index];
         var.add_attr_f32(attr_name, var_attr_value)?;
@@ -767,12 +1488,158 @@ impl DataSet {
     pub fn add_var_attr_f64(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<f64>) -> Result<(), InvalidDataSet> {
         // Check that the variable is defined
         let var_index: usize = self.find_var_from_name(var_name)?.0;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::VariableAttributeNameNotValid{var_name: var_name.to_string(), attr_name: attr_name.to_string()});
+        }
         // Append the new attribute
         let var: &mut Variable = &mut self.vars[var_index];
         var.add_attr_f64(attr_name, var_attr_value)?;
         Ok(())
     }
 
+    // Add a `u16` attribute in the variable.
+    pub fn add_var_attr_u16(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<u16>) -> Result<(), InvalidDataSet> {
+        // Check that the variable is defined
+        let var_index: usize = self.find_var_from_name(var_name)?.0;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::VariableAttributeNameNotValid{var_name: var_name.to_string(), attr_name: attr_name.to_string()});
+        }
+        // Append the new attribute
+        let var: &mut Variable = &mut self.vars[var_index];
+        var.add_attr_u16(attr_name, var_attr_value)?;
+        Ok(())
+    }
+
+    // Add a `u32` attribute in the variable.
+    pub fn add_var_attr_u32(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<u32>) -> Result<(), InvalidDataSet> {
+        // Check that the variable is defined
+        let var_index: usize = self.find_var_from_name(var_name)?.0;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::VariableAttributeNameNotValid{var_name: var_name.to_string(), attr_name: attr_name.to_string()});
+        }
+        // Append the new attribute
+        let var: &mut Variable = &mut self.vars[var_index];
+        var.add_attr_u32(attr_name, var_attr_value)?;
+        Ok(())
+    }
+
+    // Add a `i64` attribute in the variable.
+    pub fn add_var_attr_i64(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<i64>) -> Result<(), InvalidDataSet> {
+        // Check that the variable is defined
+        let var_index: usize = self.find_var_from_name(var_name)?.0;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::VariableAttributeNameNotValid{var_name: var_name.to_string(), attr_name: attr_name.to_string()});
+        }
+        // Append the new attribute
+        let var: &mut Variable = &mut self.vars[var_index];
+        var.add_attr_i64(attr_name, var_attr_value)?;
+        Ok(())
+    }
+
+    // Add a `u64` attribute in the variable.
+    pub fn add_var_attr_u64(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<u64>) -> Result<(), InvalidDataSet> {
+        // Check that the variable is defined
+        let var_index: usize = self.find_var_from_name(var_name)?.0;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::VariableAttributeNameNotValid{var_name: var_name.to_string(), attr_name: attr_name.to_string()});
+        }
+        // Append the new attribute
+        let var: &mut Variable = &mut self.vars[var_index];
+        var.add_attr_u64(attr_name, var_attr_value)?;
+        Ok(())
+    }
+
+    /// Sets an attribute of the variable, the element type is generic (see the trait [NcType](trait.NcType.html)).
+    ///
+    /// Its value and its data type are overwritten if the attribute already exists.
+    pub fn set_var_attr<T: NcType>(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<T>) -> Result<(), InvalidDataSet> {
+        self.set_var_attr_data(var_name, attr_name, T::into_data_vector(var_attr_value))
+    }
+
+    /// Sets a `i8` attribute of the variable, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_var_attr_i8(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<i8>) -> Result<(), InvalidDataSet> {
+        self.set_var_attr_data(var_name, attr_name, DataVector::I8(var_attr_value))
+    }
+
+    /// Sets a `u8` attribute of the variable, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_var_attr_u8(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<u8>) -> Result<(), InvalidDataSet> {
+        self.set_var_attr_data(var_name, attr_name, DataVector::U8(var_attr_value))
+    }
+
+    /// Sets a string attribute of the variable (see the method [set_var_attr_u8](struct.DataSet.html#method.set_var_attr_u8)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, DataType};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    /// data_set.add_var_attr_f32("latitude", "units", vec![0.0]).unwrap();
+    ///
+    /// // Overwrite the value and the data type of the attribute
+    /// data_set.set_var_attr_string("latitude", "units", "degrees_north").unwrap();
+    /// assert_eq!(Some(DataType::U8),                          data_set.get_var_attr_data_type("latitude", "units"));
+    /// assert_eq!(Some(String::from("degrees_north")),         data_set.get_var_attr_as_string("latitude", "units"));
+    ///
+    /// // Insert a new attribute
+    /// data_set.set_var_attr_string("latitude", "long_name", "Latitude").unwrap();
+    /// assert_eq!(vec!["units", "long_name"],                  data_set.get_var("latitude").unwrap().get_attr_names());
+    /// ```
+    pub fn set_var_attr_string<T: AsRef<str>>(&mut self, var_name: &str, attr_name: &str, var_attr_value: T) -> Result<(), InvalidDataSet> {
+        self.set_var_attr_u8(var_name, attr_name, String::from(var_attr_value.as_ref()).into_bytes())
+    }
+
+    /// Sets a `i16` attribute of the variable, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_var_attr_i16(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<i16>) -> Result<(), InvalidDataSet> {
+        self.set_var_attr_data(var_name, attr_name, DataVector::I16(var_attr_value))
+    }
+
+    /// Sets a `i32` attribute of the variable, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_var_attr_i32(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<i32>) -> Result<(), InvalidDataSet> {
+        self.set_var_attr_data(var_name, attr_name, DataVector::I32(var_attr_value))
+    }
+
+    /// Sets a `f32` attribute of the variable, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_var_attr_f32(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<f32>) -> Result<(), InvalidDataSet> {
+        self.set_var_attr_data(var_name, attr_name, DataVector::F32(var_attr_value))
+    }
+
+    /// Sets a `f64` attribute of the variable, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_var_attr_f64(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<f64>) -> Result<(), InvalidDataSet> {
+        self.set_var_attr_data(var_name, attr_name, DataVector::F64(var_attr_value))
+    }
+
+    /// Sets a `u16` attribute of the variable, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_var_attr_u16(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<u16>) -> Result<(), InvalidDataSet> {
+        self.set_var_attr_data(var_name, attr_name, DataVector::U16(var_attr_value))
+    }
+
+    /// Sets a `u32` attribute of the variable, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_var_attr_u32(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<u32>) -> Result<(), InvalidDataSet> {
+        self.set_var_attr_data(var_name, attr_name, DataVector::U32(var_attr_value))
+    }
+
+    /// Sets a `i64` attribute of the variable, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_var_attr_i64(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<i64>) -> Result<(), InvalidDataSet> {
+        self.set_var_attr_data(var_name, attr_name, DataVector::I64(var_attr_value))
+    }
+
+    /// Sets a `u64` attribute of the variable, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_var_attr_u64(&mut self, var_name: &str, attr_name: &str, var_attr_value: Vec<u64>) -> Result<(), InvalidDataSet> {
+        self.set_var_attr_data(var_name, attr_name, DataVector::U64(var_attr_value))
+    }
+
+    /// Overwrites the value of the variable attribute, or appends it if it is not defined.
+    fn set_var_attr_data(&mut self, var_name: &str, attr_name: &str, attr_data: DataVector) -> Result<(), InvalidDataSet> {
+        let var_index: usize = self.find_var_from_name(var_name)?.0;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::VariableAttributeNameNotValid{var_name: var_name.to_string(), attr_name: attr_name.to_string()});
+        }
+        return self.vars[var_index].set_attr_data(attr_name, attr_data);
+    }
+
     /// Returns a reference of variable attribute.
     pub fn get_var_attr(&self, var_name: &str, attr_name: &str) -> Option<&Attribute> {
         return self.find_var_attr_from_name(var_name, attr_name).map(
@@ -812,6 +1679,14 @@ impl DataSet {
         })
     }
 
+    /// Returns an iterator over the attributes of the variable, without allocating a `Vec` like the method `get_var_attrs`.
+    ///
+    /// Returns `None` if the variable is not defined.
+    pub fn var_attrs(&self, var_name: &str) -> Option<std::slice::Iter<'_, Attribute>> {
+        let var: &Variable = self.find_var_from_name(var_name).ok()?.1;
+        return Some(var.attrs());
+    }
+
     /// Returns :
     ///
     ///  - `true` if the variable attribute is defined.
@@ -834,6 +1709,9 @@ impl DataSet {
     /// Rename the variable attribute.
     pub fn rename_var_attr(&mut self, var_name: &str, old_attr_name: &str, new_attr_name: &str) -> Result<(), InvalidDataSet> {
         let var_index = self.find_var_from_name(var_name)?.0;
+        if !self.check_name_size(new_attr_name) {
+            return Err(InvalidDataSet::VariableAttributeNameNotValid{var_name: var_name.to_string(), attr_name: new_attr_name.to_string()});
+        }
         let var: &mut Variable = &mut self.vars[var_index];
         var.rename_attr(old_attr_name, new_attr_name)?;
         Ok(())
@@ -909,6 +1787,91 @@ impl DataSet {
         attr.get_f64()
     }
 
+    /// Returns the attribute value as a `&[u16]`.
+    ///
+    /// Also see the method [Attribute::get_u16](struct.Attribute.html#method.get_u16).
+    pub fn get_var_attr_u16(&self, var_name: &str, attr_name: &str) -> Option<&[u16]> {
+        let attr: &Attribute = (self.find_var_attr_from_name(var_name, attr_name).ok()?.1).1;
+        attr.get_u16()
+    }
+
+    /// Returns the attribute value as a `&[u32]`.
+    ///
+    /// Also see the method [Attribute::get_u32](struct.Attribute.html#method.get_u32).
+    pub fn get_var_attr_u32(&self, var_name: &str, attr_name: &str) -> Option<&[u32]> {
+        let attr: &Attribute = (self.find_var_attr_from_name(var_name, attr_name).ok()?.1).1;
+        attr.get_u32()
+    }
+
+    /// Returns the attribute value as a `&[i64]`.
+    ///
+    /// Also see the method [Attribute::get_i64](struct.Attribute.html#method.get_i64).
+    pub fn get_var_attr_i64(&self, var_name: &str, attr_name: &str) -> Option<&[i64]> {
+        let attr: &Attribute = (self.find_var_attr_from_name(var_name, attr_name).ok()?.1).1;
+        attr.get_i64()
+    }
+
+    /// Returns the attribute value as a `&[u64]`.
+    ///
+    /// Also see the method [Attribute::get_u64](struct.Attribute.html#method.get_u64).
+    pub fn get_var_attr_u64(&self, var_name: &str, attr_name: &str) -> Option<&[u64]> {
+        let attr: &Attribute = (self.find_var_attr_from_name(var_name, attr_name).ok()?.1).1;
+        attr.get_u64()
+    }
+
+    /// Returns the single value of the variable attribute, the element type is generic (see the trait [NcType](trait.NcType.html)).
+    ///
+    /// Returns an error if the variable or the attribute is not defined, if the data type of the attribute is not `T`,
+    /// or if the attribute does not contain exactly one element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, DataType, InvalidDataSet};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("sst", &["latitude"]).unwrap();
+    /// data_set.add_var_attr_f64("sst", "scale_factor", vec![0.01]).unwrap();
+    /// data_set.add_var_attr_i16("sst", "valid_range", vec![-5, 40]).unwrap();
+    ///
+    /// assert_eq!(Ok(0.01),                        data_set.get_var_attr_scalar::<f64>("sst", "scale_factor"));
+    /// assert_eq!(
+    ///     Err(InvalidDataSet::VariableAttributeMismatchDataType{
+    ///         var_name: String::from("sst"),
+    ///         attr_name: String::from("scale_factor"),
+    ///         req: DataType::F32,
+    ///         get: DataType::F64,
+    ///     }),
+    ///     data_set.get_var_attr_scalar::<f32>("sst", "scale_factor")
+    /// );
+    /// assert_eq!(
+    ///     Err(InvalidDataSet::VariableAttributeNotScalar{
+    ///         var_name: String::from("sst"),
+    ///         attr_name: String::from("valid_range"),
+    ///         len: 2,
+    ///     }),
+    ///     data_set.get_var_attr_scalar::<i16>("sst", "valid_range")
+    /// );
+    /// ```
+    pub fn get_var_attr_scalar<T: NcType>(&self, var_name: &str, attr_name: &str) -> Result<T, InvalidDataSet> {
+        let attr: &Attribute = (self.find_var_attr_from_name(var_name, attr_name)?.1).1;
+        let values: &[T] = T::from_data_vector(&attr.data).ok_or_else(|| InvalidDataSet::VariableAttributeMismatchDataType{
+            var_name: var_name.to_owned(),
+            attr_name: attr_name.to_owned(),
+            req: T::DATA_TYPE,
+            get: attr.data_type(),
+        })?;
+        return match values {
+            [value] => Ok(*value),
+            _ => Err(InvalidDataSet::VariableAttributeNotScalar{
+                var_name: var_name.to_owned(),
+                attr_name: attr_name.to_owned(),
+                len: values.len(),
+            }),
+        };
+    }
+
     // ----------------------------------------------------------------
     //
     //                  Global attributes
@@ -934,6 +1897,11 @@ impl DataSet {
         self.attrs.iter().collect()
     }
 
+    /// Returns an iterator over the global attributes, without allocating a `Vec` like the method `get_global_attrs`.
+    pub fn attrs(&self) -> std::slice::Iter<'_, Attribute> {
+        return self.attrs.iter();
+    }
+
     /// Returns the length (number of elements) of the global attribute.
     pub fn get_global_attr_len(&self, attr_name: &str) -> Option<usize> {
         self.find_global_attr_from_name(attr_name)
@@ -971,6 +1939,22 @@ impl DataSet {
         }).collect()
     }
 
+    /// Adds a global attribute in the data set, the element type is generic (see the trait [NcType](trait.NcType.html)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, DataType};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_global_attr("range", vec![0_i16, 100]).unwrap();
+    /// assert_eq!(Some(DataType::I16),                 data_set.get_global_attr_data_type("range"));
+    /// assert_eq!(Some(&[0, 100][..]),                 data_set.get_global_attr_i16("range"));
+    /// ```
+    pub fn add_global_attr<T: NcType>(&mut self, attr_name: &str, attr_data: Vec<T>) -> Result<(), InvalidDataSet> {
+        self.add_global_attr_data(attr_name, T::into_data_vector(attr_data))
+    }
+
     /// Adds a global `i8` type attribute in the data set.
     pub fn add_global_attr_i8(&mut self, attr_name: &str, attr_data: Vec<i8>) -> Result<(), InvalidDataSet> {
         if self.find_global_attr_from_name(attr_name).is_ok() {
@@ -978,6 +1962,9 @@ impl DataSet {
         }
         let _ = Attribute::check_attr_name(attr_name)
             .map_err(|invalid_attr_name: String| InvalidDataSet::GlobalAttributeNameNotValid(invalid_attr_name))?;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::GlobalAttributeNameNotValid(attr_name.to_string()));
+        }
         self.attrs.push(Attribute {
             name: attr_name.to_string(),
             data: DataVector::I8(attr_data),
@@ -992,6 +1979,9 @@ impl DataSet {
         }
         let _ = Attribute::check_attr_name(attr_name)
             .map_err(|invalid_attr_name: String| InvalidDataSet::GlobalAttributeNameNotValid(invalid_attr_name))?;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::GlobalAttributeNameNotValid(attr_name.to_string()));
+        }
         self.attrs.push(Attribute {
             name: attr_name.to_string(),
             data: DataVector::U8(attr_data),
@@ -1011,6 +2001,9 @@ impl DataSet {
         }
         let _ = Attribute::check_attr_name(attr_name)
             .map_err(|invalid_attr_name: String| InvalidDataSet::GlobalAttributeNameNotValid(invalid_attr_name))?;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::GlobalAttributeNameNotValid(attr_name.to_string()));
+        }
         self.attrs.push(Attribute {
             name: attr_name.to_string(),
             data: DataVector::I16(attr_data),
@@ -1025,6 +2018,9 @@ impl DataSet {
         }
         let _ = Attribute::check_attr_name(attr_name)
             .map_err(|invalid_attr_name: String| InvalidDataSet::GlobalAttributeNameNotValid(invalid_attr_name))?;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::GlobalAttributeNameNotValid(attr_name.to_string()));
+        }
         self.attrs.push(Attribute {
             name: attr_name.to_string(),
             data: DataVector::I32(attr_data),
@@ -1039,6 +2035,9 @@ impl DataSet {
         }
         let _ = Attribute::check_attr_name(attr_name)
             .map_err(|invalid_attr_name: String| InvalidDataSet::GlobalAttributeNameNotValid(invalid_attr_name))?;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::GlobalAttributeNameNotValid(attr_name.to_string()));
+        }
         self.attrs.push(Attribute {
             name: attr_name.to_string(),
             data: DataVector::F32(attr_data),
@@ -1053,6 +2052,9 @@ impl DataSet {
         }
         let _ = Attribute::check_attr_name(attr_name)
             .map_err(|invalid_attr_name: String| InvalidDataSet::GlobalAttributeNameNotValid(invalid_attr_name))?;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::GlobalAttributeNameNotValid(attr_name.to_string()));
+        }
         self.attrs.push(Attribute {
             name: attr_name.to_string(),
             data: DataVector::F64(attr_data),
@@ -1060,6 +2062,208 @@ impl DataSet {
         Ok(())
     }
 
+    /// Add a global `u16` type attribute in the data set.
+    pub fn add_global_attr_u16(&mut self, attr_name: &str, attr_data: Vec<u16>) -> Result<(), InvalidDataSet> {
+        if self.find_global_attr_from_name(attr_name).is_ok() {
+            return Err(InvalidDataSet::GlobalAttributeAlreadyExists(attr_name.to_string()));
+        }
+        let _ = Attribute::check_attr_name(attr_name)
+            .map_err(|invalid_attr_name: String| InvalidDataSet::GlobalAttributeNameNotValid(invalid_attr_name))?;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::GlobalAttributeNameNotValid(attr_name.to_string()));
+        }
+        self.attrs.push(Attribute {
+            name: attr_name.to_string(),
+            data: DataVector::U16(attr_data),
+        });
+        Ok(())
+    }
+
+    /// Add a global `u32` type attribute in the data set.
+    pub fn add_global_attr_u32(&mut self, attr_name: &str, attr_data: Vec<u32>) -> Result<(), InvalidDataSet> {
+        if self.find_global_attr_from_name(attr_name).is_ok() {
+            return Err(InvalidDataSet::GlobalAttributeAlreadyExists(attr_name.to_string()));
+        }
+        let _ = Attribute::check_attr_name(attr_name)
+            .map_err(|invalid_attr_name: String| InvalidDataSet::GlobalAttributeNameNotValid(invalid_attr_name))?;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::GlobalAttributeNameNotValid(attr_name.to_string()));
+        }
+        self.attrs.push(Attribute {
+            name: attr_name.to_string(),
+            data: DataVector::U32(attr_data),
+        });
+        Ok(())
+    }
+
+    /// Add a global `i64` type attribute in the data set.
+    pub fn add_global_attr_i64(&mut self, attr_name: &str, attr_data: Vec<i64>) -> Result<(), InvalidDataSet> {
+        if self.find_global_attr_from_name(attr_name).is_ok() {
+            return Err(InvalidDataSet::GlobalAttributeAlreadyExists(attr_name.to_string()));
+        }
+        let _ = Attribute::check_attr_name(attr_name)
+            .map_err(|invalid_attr_name: String| InvalidDataSet::GlobalAttributeNameNotValid(invalid_attr_name))?;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::GlobalAttributeNameNotValid(attr_name.to_string()));
+        }
+        self.attrs.push(Attribute {
+            name: attr_name.to_string(),
+            data: DataVector::I64(attr_data),
+        });
+        Ok(())
+    }
+
+    /// Add a global `u64` type attribute in the data set.
+    pub fn add_global_attr_u64(&mut self, attr_name: &str, attr_data: Vec<u64>) -> Result<(), InvalidDataSet> {
+        if self.find_global_attr_from_name(attr_name).is_ok() {
+            return Err(InvalidDataSet::GlobalAttributeAlreadyExists(attr_name.to_string()));
+        }
+        let _ = Attribute::check_attr_name(attr_name)
+            .map_err(|invalid_attr_name: String| InvalidDataSet::GlobalAttributeNameNotValid(invalid_attr_name))?;
+        if !self.check_name_size(attr_name) {
+            return Err(InvalidDataSet::GlobalAttributeNameNotValid(attr_name.to_string()));
+        }
+        self.attrs.push(Attribute {
+            name: attr_name.to_string(),
+            data: DataVector::U64(attr_data),
+        });
+        Ok(())
+    }
+
+    /// Sets a global attribute, the element type is generic (see the trait [NcType](trait.NcType.html)).
+    ///
+    /// Its value and its data type are overwritten if the attribute already exists.
+    pub fn set_global_attr<T: NcType>(&mut self, attr_name: &str, attr_data: Vec<T>) -> Result<(), InvalidDataSet> {
+        self.set_global_attr_data(attr_name, T::into_data_vector(attr_data))
+    }
+
+    /// Sets a global `i8` type attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_global_attr_i8(&mut self, attr_name: &str, attr_data: Vec<i8>) -> Result<(), InvalidDataSet> {
+        self.set_global_attr_data(attr_name, DataVector::I8(attr_data))
+    }
+
+    /// Sets a global `u8` type attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_global_attr_u8(&mut self, attr_name: &str, attr_data: Vec<u8>) -> Result<(), InvalidDataSet> {
+        self.set_global_attr_data(attr_name, DataVector::U8(attr_data))
+    }
+
+    /// Sets a global string attribute (see the method [set_global_attr_u8](struct.DataSet.html#method.set_global_attr_u8)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::DataSet;
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_global_attr_string("history", "created").unwrap();
+    ///
+    /// data_set.set_global_attr_string("history", "created, regridded").unwrap();
+    /// data_set.set_global_attr_string("title", "Sea surface temperature").unwrap();
+    /// assert_eq!(Some(String::from("created, regridded")),    data_set.get_global_attr_as_string("history"));
+    /// assert_eq!(vec!["history", "title"],                    data_set.get_global_attr_names());
+    /// ```
+    pub fn set_global_attr_string<T: AsRef<str>>(&mut self, attr_name: &str, attr_data: T) -> Result<(), InvalidDataSet> {
+        self.set_global_attr_u8(attr_name, String::from(attr_data.as_ref()).into_bytes())
+    }
+
+    /// Sets a global `i16` type attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_global_attr_i16(&mut self, attr_name: &str, attr_data: Vec<i16>) -> Result<(), InvalidDataSet> {
+        self.set_global_attr_data(attr_name, DataVector::I16(attr_data))
+    }
+
+    /// Sets a global `i32` type attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_global_attr_i32(&mut self, attr_name: &str, attr_data: Vec<i32>) -> Result<(), InvalidDataSet> {
+        self.set_global_attr_data(attr_name, DataVector::I32(attr_data))
+    }
+
+    /// Sets a global `f32` type attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_global_attr_f32(&mut self, attr_name: &str, attr_data: Vec<f32>) -> Result<(), InvalidDataSet> {
+        self.set_global_attr_data(attr_name, DataVector::F32(attr_data))
+    }
+
+    /// Sets a global `f64` type attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_global_attr_f64(&mut self, attr_name: &str, attr_data: Vec<f64>) -> Result<(), InvalidDataSet> {
+        self.set_global_attr_data(attr_name, DataVector::F64(attr_data))
+    }
+
+    /// Sets a global `u16` type attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_global_attr_u16(&mut self, attr_name: &str, attr_data: Vec<u16>) -> Result<(), InvalidDataSet> {
+        self.set_global_attr_data(attr_name, DataVector::U16(attr_data))
+    }
+
+    /// Sets a global `u32` type attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_global_attr_u32(&mut self, attr_name: &str, attr_data: Vec<u32>) -> Result<(), InvalidDataSet> {
+        self.set_global_attr_data(attr_name, DataVector::U32(attr_data))
+    }
+
+    /// Sets a global `i64` type attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_global_attr_i64(&mut self, attr_name: &str, attr_data: Vec<i64>) -> Result<(), InvalidDataSet> {
+        self.set_global_attr_data(attr_name, DataVector::I64(attr_data))
+    }
+
+    /// Sets a global `u64` type attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_global_attr_u64(&mut self, attr_name: &str, attr_data: Vec<u64>) -> Result<(), InvalidDataSet> {
+        self.set_global_attr_data(attr_name, DataVector::U64(attr_data))
+    }
+
+    /// Prepends a line `<date time>: <entry>` to the global attribute `history`, the date time is the current
+    /// UTC time formatted as ISO 8601 (see the method `append_history_at`).
+    pub fn append_history(&mut self, entry: &str) -> Result<(), InvalidDataSet> {
+        return self.append_history_at(entry, &DateTime::now());
+    }
+
+    /// Prepends a line `<date time>: <entry>` to the global attribute `history`, the most recent entry coming first
+    /// as done by the [NCO](http://nco.sourceforge.net) tools.
+    ///
+    /// The attribute `history` is added if it is not defined.
+    ///
+    /// Returns an error if the attribute `history` is not a text (a `u8` attribute).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, DateTime};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.append_history_at("created", &DateTime::parse("2020-01-01 00:00:00").unwrap()).unwrap();
+    /// data_set.append_history_at("regridded to 0.25 degree", &DateTime::parse("2020-01-02 12:30:00").unwrap()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Some(String::from("2020-01-02T12:30:00Z: regridded to 0.25 degree\n2020-01-01T00:00:00Z: created")),
+    ///     data_set.get_global_attr_as_string("history")
+    /// );
+    /// ```
+    pub fn append_history_at(&mut self, entry: &str, date_time: &DateTime) -> Result<(), InvalidDataSet> {
+        let line: String = format!("{}: {}", date_time, entry);
+        let history: String = match self.get_global_attr("history") {
+            None => line,
+            Some(attr) => {
+                let prev_history: &[u8] = attr.get_u8().ok_or_else(|| InvalidDataSet::GlobalAttributeMismatchDataType{
+                    attr_name: String::from("history"),
+                    req: DataType::U8,
+                    get: attr.data_type(),
+                })?;
+                let prev_history: String = String::from_utf8_lossy(prev_history).trim_end_matches('\0').to_string();
+                match prev_history.is_empty() {
+                    true => line,
+                    false => format!("{}\n{}", line, prev_history),
+                }
+            },
+        };
+        return self.set_global_attr_string("history", history);
+    }
+
+    /// Overwrites the value of the global attribute, or appends it if it is not defined.
+    fn set_global_attr_data(&mut self, attr_name: &str, attr_data: DataVector) -> Result<(), InvalidDataSet> {
+        return match self.attrs.iter_mut().find(|attr: &&mut Attribute| attr.name == attr_name) {
+            Some(attr) => {
+                attr.data = attr_data;
+                Ok(())
+            },
+            None => self.add_global_attr_data(attr_name, attr_data),
+        };
+    }
+
     pub fn rename_global_attr(&mut self, old_attr_name: &str, new_attr_name: &str) -> Result<(), InvalidDataSet> {
         // Check that both names are different
         if old_attr_name == new_attr_name {
@@ -1077,6 +2281,9 @@ impl DataSet {
         // Check that the new name is a NetCDF-3 valid name
         let _ = Attribute::check_attr_name(new_attr_name)
             .map_err(|invalid_attr_name: String| InvalidDataSet::GlobalAttributeNameNotValid(invalid_attr_name))?;
+        if !self.check_name_size(new_attr_name) {
+            return Err(InvalidDataSet::GlobalAttributeNameNotValid(new_attr_name.to_string()));
+        }
 
         // Update the attribute name
         self.attrs[renamed_attr_index].name = new_attr_name.to_string();
@@ -1147,6 +2354,73 @@ impl DataSet {
         attr.get_f64()
     }
 
+    /// Returns the attribute value as a `&[u16]`.
+    ///
+    /// Also see the method [Attribute::get_u16](struct.Attribute.html#method.get_u16).
+    pub fn get_global_attr_u16(&self, attr_name: &str) -> Option<&[u16]> {
+        let attr: &Attribute = self.find_global_attr_from_name(attr_name).ok()?.1;
+        attr.get_u16()
+    }
+
+    /// Returns the attribute value as a `&[u32]`.
+    ///
+    /// Also see the method [Attribute::get_u32](struct.Attribute.html#method.get_u32).
+    pub fn get_global_attr_u32(&self, attr_name: &str) -> Option<&[u32]> {
+        let attr: &Attribute = self.find_global_attr_from_name(attr_name).ok()?.1;
+        attr.get_u32()
+    }
+
+    /// Returns the attribute value as a `&[i64]`.
+    ///
+    /// Also see the method [Attribute::get_i64](struct.Attribute.html#method.get_i64).
+    pub fn get_global_attr_i64(&self, attr_name: &str) -> Option<&[i64]> {
+        let attr: &Attribute = self.find_global_attr_from_name(attr_name).ok()?.1;
+        attr.get_i64()
+    }
+
+    /// Returns the attribute value as a `&[u64]`.
+    ///
+    /// Also see the method [Attribute::get_u64](struct.Attribute.html#method.get_u64).
+    pub fn get_global_attr_u64(&self, attr_name: &str) -> Option<&[u64]> {
+        let attr: &Attribute = self.find_global_attr_from_name(attr_name).ok()?.1;
+        attr.get_u64()
+    }
+
+    /// Returns the single value of the global attribute, the element type is generic (see the trait [NcType](trait.NcType.html)).
+    ///
+    /// Returns an error if the attribute is not defined, if its data type is not `T`, or if it does not contain exactly one element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, DataType, InvalidDataSet};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_global_attr_i32("version", vec![2]).unwrap();
+    ///
+    /// assert_eq!(Ok(2),                           data_set.get_global_attr_scalar::<i32>("version"));
+    /// assert_eq!(
+    ///     Err(InvalidDataSet::GlobalAttributeMismatchDataType{attr_name: String::from("version"), req: DataType::I64, get: DataType::I32}),
+    ///     data_set.get_global_attr_scalar::<i64>("version")
+    /// );
+    /// assert_eq!(
+    ///     Err(InvalidDataSet::GlobalAttributeNotDefined(String::from("title"))),
+    ///     data_set.get_global_attr_scalar::<u8>("title")
+    /// );
+    /// ```
+    pub fn get_global_attr_scalar<T: NcType>(&self, attr_name: &str) -> Result<T, InvalidDataSet> {
+        let attr: &Attribute = self.find_global_attr_from_name(attr_name)?.1;
+        let values: &[T] = T::from_data_vector(&attr.data).ok_or_else(|| InvalidDataSet::GlobalAttributeMismatchDataType{
+            attr_name: attr_name.to_owned(),
+            req: T::DATA_TYPE,
+            get: attr.data_type(),
+        })?;
+        return match values {
+            [value] => Ok(*value),
+            _ => Err(InvalidDataSet::GlobalAttributeNotScalar{attr_name: attr_name.to_owned(), len: values.len()}),
+        };
+    }
+
     /// Returns the size (number of bytes) required by each record stored in the data file.
     ///
     /// Returns `None` if the data set has not a *unlimited-size* dimension.
@@ -1231,4 +2505,19 @@ impl DataSet {
             Some(dim) => Some(dim.size())
         }
     }
+
+    /// Appends one record to the *unlimited-size* dimension, and returns the new number of records.
+    ///
+    /// Returns `None` if no *unlimited-size* dimension is defined.
+    pub(crate) fn push_record(&self) -> Option<usize> {
+        let unlimited_dim: &Rc<Dimension> = self.unlimited_dim.as_ref()?;
+        match &unlimited_dim.size {
+            DimensionSize::Unlimited(size) => {
+                let mut size: RefMut<usize> = size.borrow_mut();
+                *size += 1;
+                return Some(*size);
+            },
+            DimensionSize::Fixed(_) => return None,
+        }
+    }
 }
\ No newline at end of file
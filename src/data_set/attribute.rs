@@ -2,7 +2,7 @@ mod tests;
 
 use crate::name_string::is_valid_name;
 use crate::data_vector::DataVector;
-use crate::DataType;
+use crate::{DataType, TextEncoding};
 
 /// NetCDF-3 attribute
 ///
@@ -306,6 +306,30 @@ impl Attribute {
         Attribute::new(name, data)
     }
 
+    /// Creates a new attribute containing *u16* data.
+    pub(crate) fn new_u16(name: &str, data: Vec<u16>) -> Result<Attribute, String> {
+        let data = DataVector::U16(data);
+        Attribute::new(name, data)
+    }
+
+    /// Creates a new attribute containing *u32* data.
+    pub(crate) fn new_u32(name: &str, data: Vec<u32>) -> Result<Attribute, String> {
+        let data = DataVector::U32(data);
+        Attribute::new(name, data)
+    }
+
+    /// Creates a new attribute containing *i64* data.
+    pub(crate) fn new_i64(name: &str, data: Vec<i64>) -> Result<Attribute, String> {
+        let data = DataVector::I64(data);
+        Attribute::new(name, data)
+    }
+
+    /// Creates a new attribute containing *u64* data.
+    pub(crate) fn new_u64(name: &str, data: Vec<u64>) -> Result<Attribute, String> {
+        let data = DataVector::U64(data);
+        Attribute::new(name, data)
+    }
+
     /// Returns the name of the attribute.
     pub fn name(&self) -> &str {
         &self.name
@@ -416,6 +440,28 @@ impl Attribute {
         self.data.get_as_string()
     }
 
+    /// Returns the attribute data as a `String` decoded with the `encoding`, or `None` if the attribute is not a `u8` attribute.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, Attribute, TextEncoding};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_global_attr_u8("latin1_attr", vec![b'c', b'a', b'f', b'\xe9']).unwrap();
+    /// data_set.add_global_attr_i32("i32_attr", vec![1, 2]).unwrap();
+    ///
+    /// let attr: &Attribute = data_set.get_global_attr("latin1_attr").unwrap();
+    /// assert_eq!(None,                                attr.get_as_string());
+    /// assert_eq!(Some(String::from("café")),          attr.get_as_string_with_encoding(TextEncoding::Utf8OrLatin1));
+    ///
+    /// let attr: &Attribute = data_set.get_global_attr("i32_attr").unwrap();
+    /// assert_eq!(None,                                attr.get_as_string_with_encoding(TextEncoding::Utf8OrLatin1));
+    /// ```
+    pub fn get_as_string_with_encoding(&self, encoding: TextEncoding) -> Option<String> {
+        return self.data.get_u8().and_then(|bytes: &[u8]| encoding.decode(bytes));
+    }
+
     /// Returns a reference of the `i16` data or `None` if the attribute has not `i16` data (also see the method [get_i8](struct.Attribute.html#method.get_i8)).
     pub fn get_i16(&self) -> Option<&[i16]> {
         self.data.get_i16()
@@ -436,6 +482,26 @@ impl Attribute {
         self.data.get_f64()
     }
 
+    /// Returns a reference of the `u16` data or `None` if the attribute has not `u16` data (also see the method [get_i8](struct.Attribute.html#method.get_i8)).
+    pub fn get_u16(&self) -> Option<&[u16]> {
+        self.data.get_u16()
+    }
+
+    /// Returns a reference of the `u32` data or `None` if the attribute has not `u32` data (also see the method [get_i8](struct.Attribute.html#method.get_i8)).
+    pub fn get_u32(&self) -> Option<&[u32]> {
+        self.data.get_u32()
+    }
+
+    /// Returns a reference of the `i64` data or `None` if the attribute has not `i64` data (also see the method [get_i8](struct.Attribute.html#method.get_i8)).
+    pub fn get_i64(&self) -> Option<&[i64]> {
+        self.data.get_i64()
+    }
+
+    /// Returns a reference of the `u64` data or `None` if the attribute has not `u64` data (also see the method [get_i8](struct.Attribute.html#method.get_i8)).
+    pub fn get_u64(&self) -> Option<&[u64]> {
+        self.data.get_u64()
+    }
+
     pub(crate) fn check_attr_name(attr_name: &str) -> Result<(), String> {
         match is_valid_name(attr_name) {
             true => Ok(()),
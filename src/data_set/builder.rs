@@ -0,0 +1,187 @@
+use crate::{DataSet, DataType, DataVector, InvalidDataSet};
+
+macro_rules! impl_typed_attr {
+    ($func_name:ident, $attr_func_name:ident, $prim_type:ty, $data_vector:path) => {
+        #[doc = concat!("Appends a `", stringify!($prim_type), "` attribute (see the method `", stringify!($attr_func_name), "`).")]
+        pub fn $func_name(self, attr_name: &str, attr_data: Vec<$prim_type>) -> Self {
+            return self.$attr_func_name(attr_name, $data_vector(attr_data));
+        }
+    }
+}
+
+/// Definition step recorded by a [`DataSetBuilder`](struct.DataSetBuilder.html)
+#[derive(Debug, Clone, PartialEq)]
+enum BuildStep {
+    FixedDim{name: String, size: usize},
+    UnlimitedDim{name: String, size: usize},
+    Var{name: String, dim_names: Vec<String>, data_type: DataType, attrs: Vec<(String, DataVector)>},
+    GlobalAttr{name: String, data: DataVector},
+}
+
+/// Fluent builder of a [`DataSet`](struct.DataSet.html).
+///
+/// The definitions are recorded, and they are all applied by the method `build`, which returns all the errors at once.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{DataSet, DataSetBuilder, DataType, InvalidDataSet};
+///
+/// let data_set: DataSet = DataSetBuilder::new()
+///     .unlimited_dim("time", 0)
+///     .fixed_dim("latitude", 3)
+///     .var("time", &["time"], DataType::F64, |v| v.attr_str("units", "days since 1970-01-01"))
+///     .var("sst", &["time", "latitude"], DataType::F32, |v| v.attr_str("units", "K").attr_f32("_FillValue", vec![-999.0]))
+///     .global_attr_str("title", "Sea surface temperature")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(vec!["time", "latitude"],                data_set.dim_names());
+/// assert_eq!(vec!["time", "sst"],                     data_set.get_var_names());
+/// assert_eq!(Some(String::from("K")),                 data_set.get_var_attr_as_string("sst", "units"));
+///
+/// // All the errors are returned
+/// let errors: Vec<InvalidDataSet> = DataSetBuilder::new()
+///     .fixed_dim("latitude", 0)
+///     .var("sst", &["time"], DataType::F32, |v| v)
+///     .build()
+///     .unwrap_err();
+///
+/// assert_eq!(2,                                       errors.len());
+/// assert_eq!(InvalidDataSet::FixedDimensionWithZeroSize(String::from("latitude")),   errors[0]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DataSetBuilder {
+    steps: Vec<BuildStep>,
+}
+
+/// Builder of the attributes of a variable, used by the method [`DataSetBuilder::var`](struct.DataSetBuilder.html#method.var).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct VariableBuilder {
+    attrs: Vec<(String, DataVector)>,
+}
+
+impl DataSetBuilder {
+
+    /// Creates an empty builder.
+    pub fn new() -> DataSetBuilder {
+        return DataSetBuilder::default();
+    }
+
+    /// Appends a *fixed-size* dimension (see the method [`DataSet::add_fixed_dim`](struct.DataSet.html#method.add_fixed_dim)).
+    pub fn fixed_dim(mut self, dim_name: &str, dim_size: usize) -> Self {
+        self.steps.push(BuildStep::FixedDim{name: dim_name.to_owned(), size: dim_size});
+        return self;
+    }
+
+    /// Appends the *unlimited-size* dimension (see the method [`DataSet::set_unlimited_dim`](struct.DataSet.html#method.set_unlimited_dim)).
+    pub fn unlimited_dim(mut self, dim_name: &str, dim_size: usize) -> Self {
+        self.steps.push(BuildStep::UnlimitedDim{name: dim_name.to_owned(), size: dim_size});
+        return self;
+    }
+
+    /// Appends a variable (see the method [`DataSet::add_var`](struct.DataSet.html#method.add_var)), its attributes are defined by the closure `attrs`.
+    pub fn var<T, F>(mut self, var_name: &str, dim_names: &[T], data_type: DataType, attrs: F) -> Self
+    where
+        T: AsRef<str>,
+        F: FnOnce(VariableBuilder) -> VariableBuilder,
+    {
+        self.steps.push(BuildStep::Var{
+            name: var_name.to_owned(),
+            dim_names: dim_names.iter().map(|dim_name: &T| dim_name.as_ref().to_owned()).collect(),
+            data_type: data_type,
+            attrs: attrs(VariableBuilder::default()).attrs,
+        });
+        return self;
+    }
+
+    /// Appends a global attribute.
+    pub fn global_attr(mut self, attr_name: &str, attr_data: DataVector) -> Self {
+        self.steps.push(BuildStep::GlobalAttr{name: attr_name.to_owned(), data: attr_data});
+        return self;
+    }
+
+    /// Appends a global attribute from a string (see the method `global_attr`).
+    pub fn global_attr_str(self, attr_name: &str, attr_data: &str) -> Self {
+        return self.global_attr(attr_name, DataVector::U8(attr_data.as_bytes().to_vec()));
+    }
+
+    impl_typed_attr!(global_attr_i8, global_attr, i8, DataVector::I8);
+    impl_typed_attr!(global_attr_u8, global_attr, u8, DataVector::U8);
+    impl_typed_attr!(global_attr_i16, global_attr, i16, DataVector::I16);
+    impl_typed_attr!(global_attr_i32, global_attr, i32, DataVector::I32);
+    impl_typed_attr!(global_attr_f32, global_attr, f32, DataVector::F32);
+    impl_typed_attr!(global_attr_f64, global_attr, f64, DataVector::F64);
+    impl_typed_attr!(global_attr_u16, global_attr, u16, DataVector::U16);
+    impl_typed_attr!(global_attr_u32, global_attr, u32, DataVector::U32);
+    impl_typed_attr!(global_attr_i64, global_attr, i64, DataVector::I64);
+    impl_typed_attr!(global_attr_u64, global_attr, u64, DataVector::U64);
+
+    /// Builds the data set.
+    ///
+    /// All the definitions are applied in order, and all the errors are returned. The attributes of a variable
+    /// which cannot be defined are ignored.
+    pub fn build(self) -> Result<DataSet, Vec<InvalidDataSet>> {
+        let mut data_set: DataSet = DataSet::new();
+        let mut errors: Vec<InvalidDataSet> = vec![];
+        for step in self.steps.into_iter() {
+            match step {
+                BuildStep::FixedDim{name, size} => {
+                    if let Err(err) = data_set.add_fixed_dim(&name, size) {
+                        errors.push(err);
+                    }
+                },
+                BuildStep::UnlimitedDim{name, size} => {
+                    if let Err(err) = data_set.set_unlimited_dim(&name, size) {
+                        errors.push(err);
+                    }
+                },
+                BuildStep::Var{name, dim_names, data_type, attrs} => {
+                    if let Err(err) = data_set.add_var(&name, &dim_names, data_type) {
+                        errors.push(err);
+                        continue;
+                    }
+                    for (attr_name, attr_data) in attrs.into_iter() {
+                        if let Err(err) = data_set.add_var_attr_data(&name, &attr_name, attr_data) {
+                            errors.push(err);
+                        }
+                    }
+                },
+                BuildStep::GlobalAttr{name, data} => {
+                    if let Err(err) = data_set.add_global_attr_data(&name, data) {
+                        errors.push(err);
+                    }
+                },
+            }
+        }
+        return match errors.is_empty() {
+            true => Ok(data_set),
+            false => Err(errors),
+        };
+    }
+}
+
+impl VariableBuilder {
+
+    /// Appends an attribute.
+    pub fn attr(mut self, attr_name: &str, attr_data: DataVector) -> Self {
+        self.attrs.push((attr_name.to_owned(), attr_data));
+        return self;
+    }
+
+    /// Appends an attribute from a string (see the method `attr`).
+    pub fn attr_str(self, attr_name: &str, attr_data: &str) -> Self {
+        return self.attr(attr_name, DataVector::U8(attr_data.as_bytes().to_vec()));
+    }
+
+    impl_typed_attr!(attr_i8, attr, i8, DataVector::I8);
+    impl_typed_attr!(attr_u8, attr, u8, DataVector::U8);
+    impl_typed_attr!(attr_i16, attr, i16, DataVector::I16);
+    impl_typed_attr!(attr_i32, attr, i32, DataVector::I32);
+    impl_typed_attr!(attr_f32, attr, f32, DataVector::F32);
+    impl_typed_attr!(attr_f64, attr, f64, DataVector::F64);
+    impl_typed_attr!(attr_u16, attr, u16, DataVector::U16);
+    impl_typed_attr!(attr_u32, attr, u32, DataVector::U32);
+    impl_typed_attr!(attr_i64, attr, i64, DataVector::I64);
+    impl_typed_attr!(attr_u64, attr, u64, DataVector::U64);
+}
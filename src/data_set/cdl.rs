@@ -0,0 +1,881 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use crate::{DataSet, DataType, DataVector, Variable, Attribute};
+use crate::error::CdlError;
+use crate::{NC_FILL_I8, NC_FILL_U8, NC_FILL_I16, NC_FILL_I32, NC_FILL_F32, NC_FILL_F64, NC_FILL_U16, NC_FILL_U32, NC_FILL_I64, NC_FILL_U64};
+
+/// Token of the CDL text
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Number(String),
+    Str(String),
+    Punct(char),
+}
+
+/// Value of an attribute or of a variable, the numbers keep the data type given by their suffix
+#[derive(Debug, Clone, PartialEq)]
+enum CdlValue {
+    Int(i128, Option<DataType>),
+    Float(f64, Option<DataType>),
+    Str(String),
+    Fill,
+}
+
+/// Attribute of the CDL text : the variable name (`None` for the global attributes), the attribute name, the optional data type and the values
+type CdlAttr = (Option<String>, String, Option<DataType>, Vec<CdlValue>);
+
+/// Definitions parsed from the CDL text, before building the data set
+#[derive(Debug, Default)]
+struct CdlDefinition {
+    /// Name and size of the dimensions (`None` for the *unlimited-size* dimension)
+    dims: Vec<(String, Option<usize>)>,
+    vars: Vec<(String, DataType, Vec<String>)>,
+    attrs: Vec<(CdlAttr, usize)>,
+    data: Vec<(String, Vec<CdlValue>, usize)>,
+}
+
+impl DataSet {
+
+    /// Builds a data set from a text written in the CDL language (Common Data form Language), as done by the command `ncgen`.
+    ///
+    /// The dimensions, the variables and the attributes are parsed, the section `data:` is checked but ignored (see [`from_cdl_with_data`](struct.DataSet.html#method.from_cdl_with_data)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, DataType};
+    ///
+    /// let data_set: DataSet = DataSet::from_cdl("
+    /// netcdf example {
+    /// dimensions:
+    ///     time = UNLIMITED ; // (0 currently)
+    ///     latitude = 3 ;
+    /// variables:
+    ///     float latitude(latitude) ;
+    ///         latitude:units = \"degrees_north\" ;
+    ///     short temperature(time, latitude) ;
+    ///         temperature:valid_range = 0s, 50s ;
+    ///
+    /// // global attributes:
+    ///     :title = \"Example\" ;
+    /// }").unwrap();
+    ///
+    /// assert_eq!(Some(3),                                 data_set.dim_size("latitude"));
+    /// assert_eq!(Some(0),                                 data_set.num_records());
+    /// assert_eq!(Some(DataType::I16),                     data_set.var_data_type("temperature"));
+    /// assert_eq!(Some(&[0_i16, 50][..]),                  data_set.get_var_attr_i16("temperature", "valid_range"));
+    /// assert_eq!(Some(String::from("Example")),           data_set.get_global_attr_as_string("title"));
+    /// ```
+    pub fn from_cdl(cdl: &str) -> Result<DataSet, CdlError> {
+        let (data_set, _vars_data): (DataSet, HashMap<String, DataVector>) = DataSet::from_cdl_with_data(cdl)?;
+        return Ok(data_set);
+    }
+
+    /// Builds a data set and the data of its variables from a text written in the CDL language, as done by the command `ncgen`.
+    ///
+    /// The following rules of `ncgen` are applied to the section `data:` :
+    /// - The number of records is given by the longest *record* variable.
+    /// - The missing values (and the values `_`) are replaced by the fill values.
+    /// - Each string of a `char` variable fills the last dimension of the variable, padded with NUL characters.
+    ///
+    /// The variables without values are not returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use netcdf3::{DataSet, DataVector, NC_FILL_I16};
+    ///
+    /// let (data_set, vars_data): (DataSet, HashMap<String, DataVector>) = DataSet::from_cdl_with_data("
+    /// netcdf example {
+    /// dimensions:
+    ///     time = UNLIMITED ;
+    ///     latitude = 2 ;
+    ///     name_len = 4 ;
+    /// variables:
+    ///     short temperature(time, latitude) ;
+    ///     char station(latitude, name_len) ;
+    /// data:
+    ///     temperature = 1, 2, 3, _, 5 ;
+    ///     station = \"ab\", \"cd\" ;
+    /// }").unwrap();
+    ///
+    /// assert_eq!(Some(3),                                 data_set.num_records());
+    /// assert_eq!(Some(&[1, 2, 3, NC_FILL_I16, 5, NC_FILL_I16][..]), vars_data["temperature"].get_i16());
+    /// assert_eq!(Some(&b"ab\0\0cd\0\0"[..]),              vars_data["station"].get_u8());
+    /// ```
+    pub fn from_cdl_with_data(cdl: &str) -> Result<(DataSet, HashMap<String, DataVector>), CdlError> {
+        let definition: CdlDefinition = CdlParser::new(tokenize(cdl)?).parse()?;
+
+        // Convert the values of the variables, the *record* variables are filled to the same number of records
+        let mut vars_data: Vec<(String, DataVector, usize)> = vec![];
+        let unlim_dim_name: Option<&str> = definition.dims.iter()
+            .find(|(_dim_name, dim_size): &&(String, Option<usize>)| dim_size.is_none())
+            .map(|(dim_name, _dim_size): &(String, Option<usize>)| dim_name.as_str());
+        let dim_size = |dim_name: &str| -> Option<usize> {
+            return definition.dims.iter()
+                .find(|(other_name, _dim_size): &&(String, Option<usize>)| other_name == dim_name)
+                .and_then(|(_dim_name, dim_size): &(String, Option<usize>)| *dim_size);
+        };
+        let mut num_records: usize = 0;
+        for (var_name, values, line) in definition.data.iter() {
+            let (_var_name, data_type, dim_names): &(String, DataType, Vec<String>) = definition.vars.iter()
+                .find(|(other_name, _data_type, _dim_names): &&(String, DataType, Vec<String>)| other_name == var_name)
+                .ok_or_else(|| CdlError::Syntax{line: *line, message: format!("variable '{}' not defined", var_name)})?;
+            let is_record_var: bool = dim_names.first().map(|dim_name: &String| Some(dim_name.as_str()) == unlim_dim_name).unwrap_or(false);
+            let chunk_len: usize = dim_names.iter().skip(if is_record_var { 1 } else { 0 })
+                .map(|dim_name: &String| dim_size(dim_name).unwrap_or(1))
+                .product();
+            // Each string fills the last *fixed-size* dimension
+            let string_len: Option<usize> = match dim_names.last() {
+                Some(dim_name) => dim_size(dim_name),
+                None => None,
+            };
+            let data: DataVector = build_data_vector(data_type.clone(), values, string_len).map_err(|message: String| CdlError::Syntax{line: *line, message: message})?;
+            if is_record_var {
+                if chunk_len > 0 {
+                    num_records = num_records.max(data.len().div_ceil(chunk_len));
+                }
+            }
+            else if data.len() > chunk_len {
+                return Err(CdlError::Syntax{line: *line, message: format!("too many values for the variable '{}' ({} > {})", var_name, data.len(), chunk_len)});
+            }
+            vars_data.push((var_name.clone(), data, if is_record_var { chunk_len } else { 0 }));
+        }
+
+        // Build the data set
+        let mut data_set: DataSet = DataSet::new();
+        for (dim_name, dim_size) in definition.dims.iter() {
+            match dim_size {
+                Some(dim_size) => data_set.add_fixed_dim(dim_name, *dim_size)?,
+                None => data_set.set_unlimited_dim(dim_name, num_records)?,
+            }
+        }
+        for (var_name, data_type, dim_names) in definition.vars.iter() {
+            data_set.add_var(var_name, dim_names, data_type.clone())?;
+        }
+        for ((var_name, attr_name, data_type, values), line) in definition.attrs.into_iter() {
+            let data_type: DataType = match data_type {
+                Some(data_type) => data_type,
+                None => infer_data_type(&values),
+            };
+            if values.contains(&CdlValue::Fill) {
+                return Err(CdlError::Syntax{line: line, message: format!("fill value not allowed in the attribute '{}'", attr_name)});
+            }
+            let attr_data: DataVector = build_data_vector(data_type, &values, None).map_err(|message: String| CdlError::Syntax{line: line, message: message})?;
+            match var_name {
+                None => data_set.add_global_attr_data(&attr_name, attr_data)?,
+                Some(var_name) => data_set.add_var_attr_data(&var_name, &attr_name, attr_data)?,
+            }
+        }
+
+        let vars_data: HashMap<String, DataVector> = vars_data.into_iter().map(|(var_name, mut data, record_chunk_len): (String, DataVector, usize)| {
+            let len: usize = match record_chunk_len {
+                0 => data_set.var_len(&var_name).unwrap_or(0),
+                _ => num_records * record_chunk_len,
+            };
+            fill_data_vector(&mut data, len);
+            (var_name, data)
+        }).collect();
+        return Ok((data_set, vars_data));
+    }
+
+    /// Returns the CDL text describing the data set, in the format of the command `ncdump -h`.
+    ///
+    /// The text can be parsed back with [`from_cdl`](struct.DataSet.html#method.from_cdl).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::DataSet;
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.set_unlimited_dim("time", 2).unwrap();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    /// data_set.add_var_attr_string("latitude", "units", "degrees_north").unwrap();
+    /// data_set.add_var_i16("temperature", &["time", "latitude"]).unwrap();
+    /// data_set.add_var_attr_i16("temperature", "valid_range", vec![0, 50]).unwrap();
+    /// data_set.add_global_attr_string("title", "Example").unwrap();
+    ///
+    /// assert_eq!(
+    ///     "netcdf example {\n\
+    ///     dimensions:\n\
+    ///     \ttime = UNLIMITED ; // (2 currently)\n\
+    ///     \tlatitude = 3 ;\n\
+    ///     variables:\n\
+    ///     \tfloat latitude(latitude) ;\n\
+    ///     \t\tlatitude:units = \"degrees_north\" ;\n\
+    ///     \tshort temperature(time, latitude) ;\n\
+    ///     \t\ttemperature:valid_range = 0s, 50s ;\n\
+    ///     \n\
+    ///     // global attributes:\n\
+    ///     \t\t:title = \"Example\" ;\n\
+    ///     }\n",
+    ///     data_set.to_cdl("example")
+    /// );
+    /// ```
+    pub fn to_cdl(&self, data_set_name: &str) -> String {
+        return self.to_cdl_with_data(data_set_name, &HashMap::new());
+    }
+
+    /// Returns the CDL text describing the data set and the data of its variables, in the format of the command `ncdump`.
+    ///
+    /// The fill values are written as `_`, and the values of the `char` variables are written as strings splitted on the last dimension.
+    /// The variables missing from `vars_data` (or whose the number of values is not valid) are not written in the section `data:`.
+    ///
+    /// The text can be parsed back with [`from_cdl_with_data`](struct.DataSet.html#method.from_cdl_with_data).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use netcdf3::{DataSet, DataVector};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    ///
+    /// let mut vars_data: HashMap<String, DataVector> = HashMap::new();
+    /// vars_data.insert(String::from("latitude"), DataVector::F32(vec![0.0, 0.5, netcdf3::NC_FILL_F32]));
+    ///
+    /// let cdl: String = data_set.to_cdl_with_data("example", &vars_data);
+    /// assert_eq!(true,                                    cdl.ends_with("data:\n\n latitude = 0.0, 0.5, _ ;\n}\n"));
+    /// assert_eq!((data_set, vars_data),                   DataSet::from_cdl_with_data(&cdl).unwrap());
+    /// ```
+    pub fn to_cdl_with_data(&self, data_set_name: &str, vars_data: &HashMap<String, DataVector>) -> String {
+        let mut cdl: String = format!("netcdf {} {{\n", data_set_name);
+        if !self.dims.is_empty() {
+            cdl.push_str("dimensions:\n");
+            for dim in self.dims.iter() {
+                match dim.is_unlimited() {
+                    true => cdl.push_str(&format!("\t{} = UNLIMITED ; // ({} currently)\n", dim.name(), dim.size())),
+                    false => cdl.push_str(&format!("\t{} = {} ;\n", dim.name(), dim.size())),
+                }
+            }
+        }
+        if !self.vars.is_empty() || !self.attrs.is_empty() {
+            cdl.push_str("variables:\n");
+        }
+        for var in self.vars.iter() {
+            let dim_names: Vec<String> = var.dim_names();
+            match dim_names.is_empty() {
+                true => cdl.push_str(&format!("\t{} {} ;\n", type_name(var.data_type()), var.name())),
+                false => cdl.push_str(&format!("\t{} {}({}) ;\n", type_name(var.data_type()), var.name(), dim_names.join(", "))),
+            }
+            for attr in var.get_attrs() {
+                cdl.push_str(&format!("\t\t{}:{} = {} ;\n", var.name(), attr.name(), format_attr_values(&attr.data)));
+            }
+        }
+        if !self.attrs.is_empty() {
+            cdl.push_str("\n// global attributes:\n");
+            for attr in self.attrs.iter() {
+                cdl.push_str(&format!("\t\t:{} = {} ;\n", attr.name(), format_attr_values(&attr.data)));
+            }
+        }
+
+        let vars_data: Vec<(&Variable, &DataVector)> = self.vars.iter()
+            .filter_map(|var: &Variable| vars_data.get(var.name()).map(|data: &DataVector| (var, data)))
+            .filter(|(var, data): &(&Variable, &DataVector)| data.data_type() == var.data_type() && data.len() == var.len() && data.len() > 0)
+            .collect();
+        if !vars_data.is_empty() {
+            cdl.push_str("data:\n");
+            for (var, data) in vars_data.into_iter() {
+                let values: Vec<String> = match data {
+                    DataVector::U8(chars) => {
+                        // Each string fills the last *fixed-size* dimension
+                        let string_len: usize = match var.get_dims().last() {
+                            Some(dim) if !dim.is_unlimited() => dim.size(),
+                            _ => chars.len(),
+                        };
+                        chars.chunks(string_len.max(1)).map(|chars: &[u8]| format_string(chars)).collect()
+                    },
+                    _ => {
+                        let mut fill_value: DataVector = DataVector::new(var.data_type(), 0);
+                        fill_data_vector(&mut fill_value, 1);
+                        let fill_value: &DataVector = var.get_attr("_FillValue")
+                            .map(|attr: &Attribute| &attr.data)
+                            .filter(|attr_data: &&DataVector| attr_data.data_type() == var.data_type() && attr_data.len() == 1)
+                            .unwrap_or(&fill_value);
+                        let fill_value: String = format_numbers(fill_value, false).remove(0);
+                        format_numbers(data, false).into_iter()
+                            .map(|value: String| if value == fill_value { String::from("_") } else { value })
+                            .collect()
+                    },
+                };
+                cdl.push_str(&format!("\n {} = {} ;\n", var.name(), values.join(", ")));
+            }
+        }
+        cdl.push_str("}\n");
+        return cdl;
+    }
+
+    pub(crate) fn add_global_attr_data(&mut self, attr_name: &str, attr_data: DataVector) -> Result<(), crate::InvalidDataSet> {
+        return match attr_data {
+            DataVector::I8(data) => self.add_global_attr_i8(attr_name, data),
+            DataVector::U8(data) => self.add_global_attr_u8(attr_name, data),
+            DataVector::I16(data) => self.add_global_attr_i16(attr_name, data),
+            DataVector::I32(data) => self.add_global_attr_i32(attr_name, data),
+            DataVector::F32(data) => self.add_global_attr_f32(attr_name, data),
+            DataVector::F64(data) => self.add_global_attr_f64(attr_name, data),
+            DataVector::U16(data) => self.add_global_attr_u16(attr_name, data),
+            DataVector::U32(data) => self.add_global_attr_u32(attr_name, data),
+            DataVector::I64(data) => self.add_global_attr_i64(attr_name, data),
+            DataVector::U64(data) => self.add_global_attr_u64(attr_name, data),
+        };
+    }
+
+    pub(crate) fn add_var_attr_data(&mut self, var_name: &str, attr_name: &str, attr_data: DataVector) -> Result<(), crate::InvalidDataSet> {
+        return match attr_data {
+            DataVector::I8(data) => self.add_var_attr_i8(var_name, attr_name, data),
+            DataVector::U8(data) => self.add_var_attr_u8(var_name, attr_name, data),
+            DataVector::I16(data) => self.add_var_attr_i16(var_name, attr_name, data),
+            DataVector::I32(data) => self.add_var_attr_i32(var_name, attr_name, data),
+            DataVector::F32(data) => self.add_var_attr_f32(var_name, attr_name, data),
+            DataVector::F64(data) => self.add_var_attr_f64(var_name, attr_name, data),
+            DataVector::U16(data) => self.add_var_attr_u16(var_name, attr_name, data),
+            DataVector::U32(data) => self.add_var_attr_u32(var_name, attr_name, data),
+            DataVector::I64(data) => self.add_var_attr_i64(var_name, attr_name, data),
+            DataVector::U64(data) => self.add_var_attr_u64(var_name, attr_name, data),
+        };
+    }
+}
+
+/// Returns the data type of a CDL type name (`long` and `real` are the obsolete names of `int` and `float`).
+fn parse_type_name(type_name: &str) -> Option<DataType> {
+    return match type_name {
+        "byte" => Some(DataType::I8),
+        "char" => Some(DataType::U8),
+        "short" => Some(DataType::I16),
+        "int" | "long" => Some(DataType::I32),
+        "float" | "real" => Some(DataType::F32),
+        "double" => Some(DataType::F64),
+        "ushort" => Some(DataType::U16),
+        "uint" => Some(DataType::U32),
+        "int64" => Some(DataType::I64),
+        "uint64" => Some(DataType::U64),
+        _ => None,
+    };
+}
+
+/// Returns the CDL name of a data type.
+pub(super) fn type_name(data_type: DataType) -> &'static str {
+    return match data_type {
+        DataType::I8 => "byte",
+        DataType::U8 => "char",
+        DataType::I16 => "short",
+        DataType::I32 => "int",
+        DataType::F32 => "float",
+        DataType::F64 => "double",
+        DataType::U16 => "ushort",
+        DataType::U32 => "uint",
+        DataType::I64 => "int64",
+        DataType::U64 => "uint64",
+    };
+}
+
+/// Returns the quoted and escaped string, without its trailing NUL characters.
+fn format_string(chars: &[u8]) -> String {
+    let chars: &[u8] = match chars.iter().rposition(|c: &u8| *c != 0) {
+        Some(last_pos) => &chars[0..=last_pos],
+        None => &[],
+    };
+    let mut text: String = String::with_capacity(chars.len() + 2);
+    text.push('"');
+    for c in String::from_utf8_lossy(chars).chars() {
+        match c {
+            '"' => text.push_str("\\\""),
+            '\\' => text.push_str("\\\\"),
+            '\n' => text.push_str("\\n"),
+            '\t' => text.push_str("\\t"),
+            '\r' => text.push_str("\\r"),
+            '\0' => text.push_str("\\0"),
+            c => text.push(c),
+        }
+    }
+    text.push('"');
+    return text;
+}
+
+/// Returns the values of an attribute, the numbers have the suffix of their data type (e.g. `1b`, `2s` or `3.5f`).
+fn format_attr_values(data: &DataVector) -> String {
+    return match data {
+        DataVector::U8(chars) => format_string(chars),
+        _ => format_numbers(data, true).join(", "),
+    };
+}
+
+/// Returns the CDL numbers, with or without the suffixes of their data type.
+fn format_numbers(data: &DataVector, with_suffix: bool) -> Vec<String> {
+    fn format_float(repr: String) -> String {
+        return match repr.as_str() {
+            "inf" => String::from("Infinity"),
+            "-inf" => String::from("-Infinity"),
+            _ => repr,
+        };
+    }
+    let suffix: &str = match (with_suffix, data.data_type()) {
+        (false, _) => "",
+        (true, DataType::I8) => "b",
+        (true, DataType::I16) => "s",
+        (true, DataType::F32) => "f",
+        (true, DataType::U16) => "us",
+        (true, DataType::U32) => "u",
+        (true, DataType::I64) => "ll",
+        (true, DataType::U64) => "ull",
+        (true, _) => "",
+    };
+    let values: Vec<String> = match data {
+        DataVector::I8(data) => data.iter().map(|value: &i8| value.to_string()).collect(),
+        DataVector::U8(data) => data.iter().map(|value: &u8| value.to_string()).collect(),
+        DataVector::I16(data) => data.iter().map(|value: &i16| value.to_string()).collect(),
+        DataVector::I32(data) => data.iter().map(|value: &i32| value.to_string()).collect(),
+        DataVector::F32(data) => data.iter().map(|value: &f32| format_float(format!("{:?}", value))).collect(),
+        DataVector::F64(data) => data.iter().map(|value: &f64| format_float(format!("{:?}", value))).collect(),
+        DataVector::U16(data) => data.iter().map(|value: &u16| value.to_string()).collect(),
+        DataVector::U32(data) => data.iter().map(|value: &u32| value.to_string()).collect(),
+        DataVector::I64(data) => data.iter().map(|value: &i64| value.to_string()).collect(),
+        DataVector::U64(data) => data.iter().map(|value: &u64| value.to_string()).collect(),
+    };
+    return values.into_iter().map(|value: String| format!("{}{}", value, suffix)).collect();
+}
+
+/// Returns the data type of an attribute without explicit type : `char` for the strings, otherwise the type of the first number.
+fn infer_data_type(values: &[CdlValue]) -> DataType {
+    if values.iter().any(|value: &CdlValue| matches!(value, CdlValue::Str(_))) {
+        return DataType::U8;
+    }
+    return match values.first() {
+        Some(CdlValue::Int(_, Some(data_type))) | Some(CdlValue::Float(_, Some(data_type))) => data_type.clone(),
+        Some(CdlValue::Float(_, None)) => DataType::F64,
+        _ => DataType::I32,
+    };
+}
+
+/// Converts the CDL values into the data type.
+///
+/// The strings are concatenated, and padded to a multiple of `string_len` if it is given.
+fn build_data_vector(data_type: DataType, values: &[CdlValue], string_len: Option<usize>) -> Result<DataVector, String> {
+    fn to_ints(values: &[CdlValue], fill_value: i128) -> Result<Vec<i128>, String> {
+        return values.iter().map(|value: &CdlValue| match value {
+            CdlValue::Int(value, _) => Ok(*value),
+            CdlValue::Float(value, _) if value.fract() == 0.0 && value.abs() < 1e38 => Ok(*value as i128),
+            CdlValue::Float(value, _) => Err(format!("integer expected instead of {}", value)),
+            CdlValue::Str(value) => Err(format!("number expected instead of \"{}\"", value)),
+            CdlValue::Fill => Ok(fill_value),
+        }).collect();
+    }
+    fn to_floats(values: &[CdlValue], fill_value: f64) -> Result<Vec<f64>, String> {
+        return values.iter().map(|value: &CdlValue| match value {
+            CdlValue::Int(value, _) => Ok(*value as f64),
+            CdlValue::Float(value, _) => Ok(*value),
+            CdlValue::Str(value) => Err(format!("number expected instead of \"{}\"", value)),
+            CdlValue::Fill => Ok(fill_value),
+        }).collect();
+    }
+    fn cast<T: TryFrom<i128>>(values: Vec<i128>, data_type: DataType) -> Result<Vec<T>, String> {
+        return values.into_iter().map(|value: i128| {
+            T::try_from(value).map_err(|_err| format!("value {} out of the range of the type {}", value, data_type.c_api_name()))
+        }).collect();
+    }
+    return Ok(match data_type {
+        DataType::U8 => {
+            let mut chars: Vec<u8> = vec![];
+            for value in values.iter() {
+                match value {
+                    CdlValue::Str(value) => {
+                        chars.extend_from_slice(value.as_bytes());
+                        if let Some(string_len) = string_len {
+                            if string_len > 0 && value.len() % string_len != 0 {
+                                chars.resize(chars.len() + string_len - value.len() % string_len, NC_FILL_U8);
+                            }
+                        }
+                    },
+                    CdlValue::Int(value, _) if *value >= -128 && *value <= 255 => chars.push(*value as u8),
+                    CdlValue::Fill => chars.push(NC_FILL_U8),
+                    _ => return Err(format!("character expected instead of {:?}", value)),
+                }
+            }
+            DataVector::U8(chars)
+        },
+        DataType::I8 => DataVector::I8(cast(to_ints(values, i128::from(NC_FILL_I8))?, data_type)?),
+        DataType::I16 => DataVector::I16(cast(to_ints(values, i128::from(NC_FILL_I16))?, data_type)?),
+        DataType::I32 => DataVector::I32(cast(to_ints(values, i128::from(NC_FILL_I32))?, data_type)?),
+        DataType::U16 => DataVector::U16(cast(to_ints(values, i128::from(NC_FILL_U16))?, data_type)?),
+        DataType::U32 => DataVector::U32(cast(to_ints(values, i128::from(NC_FILL_U32))?, data_type)?),
+        DataType::I64 => DataVector::I64(cast(to_ints(values, i128::from(NC_FILL_I64))?, data_type)?),
+        DataType::U64 => DataVector::U64(cast(to_ints(values, i128::from(NC_FILL_U64))?, data_type)?),
+        DataType::F32 => DataVector::F32(to_floats(values, f64::from(NC_FILL_F32))?.into_iter().map(|value: f64| value as f32).collect()),
+        DataType::F64 => DataVector::F64(to_floats(values, NC_FILL_F64)?),
+    });
+}
+
+/// Appends fill values up to the length `len`.
+fn fill_data_vector(data: &mut DataVector, len: usize) {
+    match data {
+        DataVector::I8(data) => data.resize(len, NC_FILL_I8),
+        DataVector::U8(data) => data.resize(len, NC_FILL_U8),
+        DataVector::I16(data) => data.resize(len, NC_FILL_I16),
+        DataVector::I32(data) => data.resize(len, NC_FILL_I32),
+        DataVector::F32(data) => data.resize(len, NC_FILL_F32),
+        DataVector::F64(data) => data.resize(len, NC_FILL_F64),
+        DataVector::U16(data) => data.resize(len, NC_FILL_U16),
+        DataVector::U32(data) => data.resize(len, NC_FILL_U32),
+        DataVector::I64(data) => data.resize(len, NC_FILL_I64),
+        DataVector::U64(data) => data.resize(len, NC_FILL_U64),
+    }
+}
+
+/// Parses a CDL number, with its optional type suffix (e.g. `1b`, `-2s`, `3.5f`, `4ull`, `NaNf` or `-Infinity`).
+fn parse_number(text: &str) -> Option<CdlValue> {
+    let lower: String = text.to_lowercase();
+    let (sign, body): (f64, &str) = match lower.as_bytes().first() {
+        Some(b'-') => (-1.0, &lower[1..]),
+        Some(b'+') => (1.0, &lower[1..]),
+        _ => (1.0, &lower[..]),
+    };
+    // Special floating-point values
+    match body {
+        "nan" => return Some(CdlValue::Float(std::f64::NAN, None)),
+        "nanf" => return Some(CdlValue::Float(std::f64::NAN, Some(DataType::F32))),
+        "infinity" | "inf" => return Some(CdlValue::Float(sign * std::f64::INFINITY, None)),
+        "infinityf" | "inff" => return Some(CdlValue::Float(sign * std::f64::INFINITY, Some(DataType::F32))),
+        _ => {},
+    }
+    if let Some(hex_digits) = body.strip_prefix("0x") {
+        let value: i128 = i128::from_str_radix(hex_digits, 16).ok()?;
+        return Some(CdlValue::Int(if sign < 0.0 { -value } else { value }, None));
+    }
+    const SUFFIXES: [(&str, DataType); 9] = [
+        ("ull", DataType::U64), ("ll", DataType::I64), ("us", DataType::U16), ("u", DataType::U32),
+        ("b", DataType::I8), ("s", DataType::I16), ("l", DataType::I32), ("f", DataType::F32), ("d", DataType::F64),
+    ];
+    let (number, data_type): (&str, Option<DataType>) = SUFFIXES.iter()
+        .find(|(suffix, _data_type): &&(&str, DataType)| lower.ends_with(suffix))
+        .map(|(suffix, data_type): &(&str, DataType)| (&lower[..(lower.len() - suffix.len())], Some(data_type.clone())))
+        .unwrap_or((&lower, None));
+    let is_float: bool = number.contains('.') || number.contains('e') || data_type == Some(DataType::F32) || data_type == Some(DataType::F64);
+    if is_float {
+        return number.parse::<f64>().ok().map(|value: f64| CdlValue::Float(value, data_type));
+    }
+    return number.parse::<i128>().ok().map(|value: i128| CdlValue::Int(value, data_type));
+}
+
+/// Splits the CDL text into tokens, the comments are removed.
+fn tokenize(cdl: &str) -> Result<Vec<(Token, usize)>, CdlError> {
+    let chars: Vec<char> = cdl.chars().collect();
+    let mut tokens: Vec<(Token, usize)> = vec![];
+    let mut line: usize = 1;
+    let mut i: usize = 0;
+    while i < chars.len() {
+        let c: char = chars[i];
+        if c == '\n' {
+            line += 1;
+            i += 1;
+        }
+        else if c.is_whitespace() {
+            i += 1;
+        }
+        else if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        }
+        else if c == '"' || c == '\'' {
+            let quote: char = c;
+            let start_line: usize = line;
+            let mut value: String = String::new();
+            i += 1;
+            loop {
+                let c: char = *chars.get(i).ok_or_else(|| CdlError::Syntax{line: start_line, message: String::from("string not terminated")})?;
+                i += 1;
+                match c {
+                    '\\' => {
+                        let escaped: char = *chars.get(i).ok_or_else(|| CdlError::Syntax{line: start_line, message: String::from("string not terminated")})?;
+                        i += 1;
+                        value.push(match escaped {
+                            'n' => '\n',
+                            't' => '\t',
+                            'r' => '\r',
+                            '0' => '\0',
+                            other => other,
+                        });
+                    },
+                    '\n' => {
+                        line += 1;
+                        value.push(c);
+                    },
+                    _ if c == quote => break,
+                    _ => value.push(c),
+                }
+            }
+            tokens.push((Token::Str(value), start_line));
+        }
+        else if c.is_ascii_alphabetic() || c == '_' {
+            let start: usize = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || "_.@+-".contains(chars[i])) {
+                i += 1;
+            }
+            tokens.push((Token::Word(chars[start..i].iter().collect()), line));
+        }
+        else if c.is_ascii_digit() || c == '.' || c == '-' || c == '+' {
+            let start: usize = i;
+            i += 1;
+            while i < chars.len() {
+                let c: char = chars[i];
+                let prev: char = chars[i - 1];
+                if c.is_alphanumeric() || c == '.' || ((c == '-' || c == '+') && (prev == 'e' || prev == 'E')) {
+                    i += 1;
+                }
+                else {
+                    break;
+                }
+            }
+            tokens.push((Token::Number(chars[start..i].iter().collect()), line));
+        }
+        else if "{}(),;=:".contains(c) {
+            tokens.push((Token::Punct(c), line));
+            i += 1;
+        }
+        else {
+            return Err(CdlError::Syntax{line: line, message: format!("unexpected character '{}'", c)});
+        }
+    }
+    return Ok(tokens);
+}
+
+/// Parser of the tokens of the CDL text
+struct CdlParser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl CdlParser {
+
+    fn new(tokens: Vec<(Token, usize)>) -> CdlParser {
+        return CdlParser{
+            tokens: tokens,
+            pos: 0,
+        };
+    }
+
+    fn peek(&self, offset: usize) -> Option<&Token> {
+        return self.tokens.get(self.pos + offset).map(|(token, _line): &(Token, usize)| token);
+    }
+
+    /// Returns the line of the current token (or of the last token).
+    fn line(&self) -> usize {
+        return self.tokens.get(self.pos).or_else(|| self.tokens.last()).map(|(_token, line): &(Token, usize)| *line).unwrap_or(1);
+    }
+
+    fn error(&self, message: String) -> CdlError {
+        return CdlError::Syntax{line: self.line(), message: message};
+    }
+
+    fn next(&mut self) -> Result<Token, CdlError> {
+        let token: Token = self.peek(0).cloned().ok_or_else(|| self.error(String::from("unexpected end of the text")))?;
+        self.pos += 1;
+        return Ok(token);
+    }
+
+    fn is_punct(&self, offset: usize, punct: char) -> bool {
+        return self.peek(offset) == Some(&Token::Punct(punct));
+    }
+
+    fn expect_punct(&mut self, punct: char) -> Result<(), CdlError> {
+        return match self.next()? {
+            Token::Punct(c) if c == punct => Ok(()),
+            token => {
+                self.pos -= 1;
+                Err(self.error(format!("'{}' expected instead of {:?}", punct, token)))
+            },
+        };
+    }
+
+    fn expect_word(&mut self) -> Result<String, CdlError> {
+        return match self.next()? {
+            Token::Word(word) => Ok(word),
+            token => {
+                self.pos -= 1;
+                Err(self.error(format!("name expected instead of {:?}", token)))
+            },
+        };
+    }
+
+    /// Returns `true` if the next tokens start a new section (`dimensions:`, `variables:` or `data:`).
+    fn is_section_start(&self) -> bool {
+        return match self.peek(0) {
+            Some(Token::Word(word)) => (word == "dimensions" || word == "variables" || word == "data") && self.is_punct(1, ':'),
+            _ => false,
+        };
+    }
+
+    fn parse(mut self) -> Result<CdlDefinition, CdlError> {
+        let mut definition: CdlDefinition = CdlDefinition::default();
+        match self.next()? {
+            Token::Word(ref word) if word == "netcdf" => {},
+            _ => {
+                self.pos -= 1;
+                return Err(self.error(String::from("keyword 'netcdf' expected")));
+            },
+        }
+        match self.next()? {
+            Token::Word(_) | Token::Str(_) => {},
+            _ => {
+                self.pos -= 1;
+                return Err(self.error(String::from("data set name expected")));
+            },
+        }
+        self.expect_punct('{')?;
+        while !self.is_punct(0, '}') {
+            let section: String = self.expect_word()?;
+            self.expect_punct(':')?;
+            match section.as_str() {
+                "dimensions" => self.parse_dims(&mut definition)?,
+                "variables" => self.parse_vars(&mut definition)?,
+                "data" => self.parse_data(&mut definition)?,
+                _ => {
+                    self.pos -= 2;
+                    return Err(self.error(format!("section '{}' not valid", section)));
+                },
+            }
+        }
+        self.expect_punct('}')?;
+        if self.pos < self.tokens.len() {
+            return Err(self.error(String::from("unexpected text after the end of the data set")));
+        }
+        return Ok(definition);
+    }
+
+    /// Parses the declarations `name = size` or `name = UNLIMITED`, separated by `,` or `;`.
+    fn parse_dims(&mut self, definition: &mut CdlDefinition) -> Result<(), CdlError> {
+        while !self.is_punct(0, '}') && !self.is_section_start() {
+            let dim_name: String = self.expect_word()?;
+            self.expect_punct('=')?;
+            let dim_size: Option<usize> = match self.next()? {
+                Token::Word(ref word) if word.to_lowercase() == "unlimited" => None,
+                Token::Number(ref number) => Some(number.parse::<usize>().map_err(|_err| self.error(format!("size of the dimension '{}' not valid", dim_name)))?),
+                _ => {
+                    self.pos -= 1;
+                    return Err(self.error(format!("size of the dimension '{}' expected", dim_name)));
+                },
+            };
+            definition.dims.push((dim_name, dim_size));
+            if !self.is_punct(0, ',') {
+                self.expect_punct(';')?;
+            }
+            else {
+                self.pos += 1;
+            }
+        }
+        return Ok(());
+    }
+
+    /// Parses the declarations of the variables and of the attributes.
+    fn parse_vars(&mut self, definition: &mut CdlDefinition) -> Result<(), CdlError> {
+        while !self.is_punct(0, '}') && !self.is_section_start() {
+            let line: usize = self.line();
+            // Optional data type
+            let data_type: Option<DataType> = match self.peek(0) {
+                Some(Token::Word(word)) => match (parse_type_name(word), self.peek(1)) {
+                    (Some(data_type), Some(Token::Word(_))) | (Some(data_type), Some(Token::Punct(':'))) => Some(data_type),
+                    _ => None,
+                },
+                _ => None,
+            };
+            if data_type.is_some() {
+                self.pos += 1;
+            }
+            // Attribute
+            let is_global_attr: bool = self.is_punct(0, ':');
+            let is_var_attr: bool = self.is_punct(1, ':') && matches!(self.peek(0), Some(Token::Word(_)));
+            if is_global_attr || is_var_attr {
+                let var_name: Option<String> = match is_var_attr {
+                    true => Some(self.expect_word()?),
+                    false => None,
+                };
+                self.expect_punct(':')?;
+                let attr_name: String = self.expect_word()?;
+                self.expect_punct('=')?;
+                let values: Vec<CdlValue> = self.parse_values()?;
+                definition.attrs.push(((var_name, attr_name, data_type, values), line));
+                continue;
+            }
+            // Variables
+            let data_type: DataType = match data_type {
+                Some(data_type) => data_type,
+                None => {
+                    return Err(match self.peek(0) {
+                        Some(Token::Word(word)) => self.error(format!("data type '{}' not supported", word)),
+                        token => self.error(format!("declaration expected instead of {:?}", token)),
+                    });
+                },
+            };
+            loop {
+                let var_name: String = self.expect_word()?;
+                let mut dim_names: Vec<String> = vec![];
+                if self.is_punct(0, '(') {
+                    self.pos += 1;
+                    loop {
+                        dim_names.push(self.expect_word()?);
+                        if !self.is_punct(0, ',') {
+                            break;
+                        }
+                        self.pos += 1;
+                    }
+                    self.expect_punct(')')?;
+                }
+                definition.vars.push((var_name, data_type.clone(), dim_names));
+                if !self.is_punct(0, ',') {
+                    break;
+                }
+                self.pos += 1;
+            }
+            self.expect_punct(';')?;
+        }
+        return Ok(());
+    }
+
+    /// Parses the values of the variables `name = values ;`.
+    fn parse_data(&mut self, definition: &mut CdlDefinition) -> Result<(), CdlError> {
+        while !self.is_punct(0, '}') && !self.is_section_start() {
+            let line: usize = self.line();
+            let var_name: String = self.expect_word()?;
+            self.expect_punct('=')?;
+            let values: Vec<CdlValue> = self.parse_values()?;
+            definition.data.push((var_name, values, line));
+        }
+        return Ok(());
+    }
+
+    /// Parses a list of values separated by `,` and terminated by `;`.
+    fn parse_values(&mut self) -> Result<Vec<CdlValue>, CdlError> {
+        let mut values: Vec<CdlValue> = vec![];
+        loop {
+            let value: CdlValue = match self.next()? {
+                Token::Str(value) => CdlValue::Str(value),
+                Token::Word(ref word) if word == "_" => CdlValue::Fill,
+                Token::Word(ref text) | Token::Number(ref text) => match parse_number(text) {
+                    Some(value) => value,
+                    None => {
+                        self.pos -= 1;
+                        return Err(self.error(format!("value '{}' not valid", text)));
+                    },
+                },
+                token => {
+                    self.pos -= 1;
+                    return Err(self.error(format!("value expected instead of {:?}", token)));
+                },
+            };
+            values.push(value);
+            if !self.is_punct(0, ',') {
+                break;
+            }
+            self.pos += 1;
+        }
+        self.expect_punct(';')?;
+        return Ok(values);
+    }
+}
@@ -0,0 +1,368 @@
+use std::rc::Rc;
+
+use crate::{Attribute, DataSet, DataType, DataVector, Dimension, DimensionType, Variable};
+
+/// Structural differences between two data sets, returned by [`DataSet::diff`](struct.DataSet.html#method.diff).
+///
+/// The data of the variables are not compared. The *added* items are only defined in the other data set,
+/// and the *removed* items are only defined in the compared data set.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DataSetDiff {
+    pub added_dims: Vec<String>,
+    pub removed_dims: Vec<String>,
+    pub changed_dims: Vec<DimensionDiff>,
+    pub added_vars: Vec<String>,
+    pub removed_vars: Vec<String>,
+    pub changed_vars: Vec<VariableDiff>,
+    pub added_global_attrs: Vec<String>,
+    pub removed_global_attrs: Vec<String>,
+    pub changed_global_attrs: Vec<AttributeDiff>,
+}
+
+/// Dimension defined in both data sets, with different sizes or types
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DimensionDiff {
+    pub name: String,
+    pub old_type: DimensionType,
+    pub new_type: DimensionType,
+    pub old_size: usize,
+    pub new_size: usize,
+}
+
+/// Variable defined in both data sets, with different data types, dimensions or attributes
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariableDiff {
+    pub name: String,
+    /// Old and new data types, if they are different
+    pub data_type: Option<(DataType, DataType)>,
+    /// Old and new names of the dimensions, if they are different
+    pub dim_names: Option<(Vec<String>, Vec<String>)>,
+    pub added_attrs: Vec<String>,
+    pub removed_attrs: Vec<String>,
+    pub changed_attrs: Vec<AttributeDiff>,
+}
+
+/// Attribute defined in both data sets, with different values
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeDiff {
+    pub name: String,
+    pub old_value: DataVector,
+    pub new_value: DataVector,
+}
+
+/// Tolerance used to compare the data of two variables (see the function [`compare_vars`](fn.compare_vars.html)).
+///
+/// Two values `a` and `b` are equal if `|a - b| <= absolute` or `|a - b| <= relative * max(|a|, |b|)`.
+/// By default the values must be strictly equal.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Tolerance {
+    pub absolute: f64,
+    pub relative: f64,
+}
+
+impl Tolerance {
+
+    /// Tolerance on the absolute difference of the values.
+    pub fn absolute(epsilon: f64) -> Tolerance {
+        return Tolerance{absolute: epsilon, relative: 0.0};
+    }
+
+    /// Tolerance on the difference of the values relative to their magnitude.
+    pub fn relative(epsilon: f64) -> Tolerance {
+        return Tolerance{absolute: 0.0, relative: epsilon};
+    }
+
+    /// Returns `true` if both values are equal within the tolerance, the `NaN` values are equal and the infinite values are only equal to themselves.
+    pub fn is_equal(&self, a: f64, b: f64) -> bool {
+        if a.is_nan() || b.is_nan() {
+            return a.is_nan() && b.is_nan();
+        }
+        if a == b {
+            return true;
+        }
+        if a.is_infinite() || b.is_infinite() {
+            return false;
+        }
+        let diff: f64 = (a - b).abs();
+        return diff <= self.absolute || diff <= self.relative * a.abs().max(b.abs());
+    }
+}
+
+/// Differences between the data of two variables, returned by [`compare_vars`](fn.compare_vars.html).
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataDiff {
+    /// Numbers of values of both variables
+    pub len: (usize, usize),
+    /// Indices of the differing values, among the indices common to both variables
+    pub indices: Vec<usize>,
+    /// Largest absolute difference between two values which are not fill values, `0.0` if there is none
+    pub max_abs_diff: f64,
+}
+
+impl DataDiff {
+
+    /// Returns `true` if both variables have the same number of values and no difference has been found.
+    pub fn is_empty(&self) -> bool {
+        return self.len.0 == self.len.1 && self.indices.is_empty();
+    }
+}
+
+impl DataSetDiff {
+
+    /// Returns `true` if no difference has been found.
+    pub fn is_empty(&self) -> bool {
+        return self.added_dims.is_empty() && self.removed_dims.is_empty() && self.changed_dims.is_empty()
+            && self.added_vars.is_empty() && self.removed_vars.is_empty() && self.changed_vars.is_empty()
+            && self.added_global_attrs.is_empty() && self.removed_global_attrs.is_empty() && self.changed_global_attrs.is_empty();
+    }
+}
+
+impl DataSet {
+
+    /// Returns the structural differences (the dimensions, the variables and the attributes) between the data set and an other one.
+    ///
+    /// The data of the variables are not compared, and the `NaN` values of the attributes are considered as equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, DataSetDiff, DataType};
+    ///
+    /// let mut reference: DataSet = DataSet::new();
+    /// reference.add_fixed_dim("latitude", 3).unwrap();
+    /// reference.add_var_f32("latitude", &["latitude"]).unwrap();
+    /// reference.add_var_attr_string("latitude", "units", "degrees_north").unwrap();
+    ///
+    /// let mut output: DataSet = DataSet::new();
+    /// output.add_fixed_dim("latitude", 4).unwrap();
+    /// output.add_var_f64("latitude", &["latitude"]).unwrap();
+    /// output.add_global_attr_string("title", "Output").unwrap();
+    ///
+    /// let diff: DataSetDiff = reference.diff(&output);
+    /// assert_eq!(false,                                   diff.is_empty());
+    /// assert_eq!(3,                                       diff.changed_dims[0].old_size);
+    /// assert_eq!(4,                                       diff.changed_dims[0].new_size);
+    /// assert_eq!(Some((DataType::F32, DataType::F64)),    diff.changed_vars[0].data_type);
+    /// assert_eq!(vec!["units"],                           diff.changed_vars[0].removed_attrs);
+    /// assert_eq!(vec!["title"],                           diff.added_global_attrs);
+    ///
+    /// assert_eq!(true,                                    reference.diff(&reference).is_empty());
+    /// ```
+    pub fn diff(&self, other: &DataSet) -> DataSetDiff {
+        let mut diff: DataSetDiff = DataSetDiff::default();
+
+        // Dimensions
+        let find_dim = |dims: &[Rc<Dimension>], dim_name: &str| -> Option<Rc<Dimension>> {
+            return dims.iter().find(|dim: &&Rc<Dimension>| dim.name() == dim_name).cloned();
+        };
+        for dim in self.dims.iter() {
+            match find_dim(&other.dims, &dim.name()) {
+                None => diff.removed_dims.push(dim.name()),
+                Some(other_dim) => {
+                    if dim.dim_type() != other_dim.dim_type() || dim.size() != other_dim.size() {
+                        diff.changed_dims.push(DimensionDiff{
+                            name: dim.name(),
+                            old_type: dim.dim_type(),
+                            new_type: other_dim.dim_type(),
+                            old_size: dim.size(),
+                            new_size: other_dim.size(),
+                        });
+                    }
+                },
+            }
+        }
+        diff.added_dims = other.dims.iter()
+            .filter(|other_dim: &&Rc<Dimension>| find_dim(&self.dims, &other_dim.name()).is_none())
+            .map(|other_dim: &Rc<Dimension>| other_dim.name())
+            .collect();
+
+        // Variables
+        for var in self.vars.iter() {
+            match other.get_var(var.name()) {
+                None => diff.removed_vars.push(var.name().to_owned()),
+                Some(other_var) => {
+                    let var_diff: VariableDiff = diff_vars(var, other_var);
+                    let is_changed: bool = var_diff.data_type.is_some() || var_diff.dim_names.is_some()
+                        || !var_diff.added_attrs.is_empty() || !var_diff.removed_attrs.is_empty() || !var_diff.changed_attrs.is_empty();
+                    if is_changed {
+                        diff.changed_vars.push(var_diff);
+                    }
+                },
+            }
+        }
+        diff.added_vars = other.vars.iter()
+            .filter(|other_var: &&Variable| !self.has_var(other_var.name()))
+            .map(|other_var: &Variable| other_var.name().to_owned())
+            .collect();
+
+        // Global attributes
+        let (added_attrs, removed_attrs, changed_attrs) = diff_attrs(&self.get_global_attrs(), &other.get_global_attrs());
+        diff.added_global_attrs = added_attrs;
+        diff.removed_global_attrs = removed_attrs;
+        diff.changed_global_attrs = changed_attrs;
+        return diff;
+    }
+
+    /// Returns `true` if the data sets define the same dimensions, variables and attributes, regardless of their definition order
+    /// (see the method `diff`).
+    ///
+    /// Unlike `==`, the order of the dimensions, of the variables and of the attributes is ignored. The order of the dimensions
+    /// of each variable is still compared.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::DataSet;
+    ///
+    /// let mut data_set_1: DataSet = DataSet::new();
+    /// data_set_1.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set_1.add_fixed_dim("longitude", 4).unwrap();
+    /// data_set_1.add_global_attr_string("title", "Sea surface temperature").unwrap();
+    /// data_set_1.add_global_attr_string("institution", "Ifremer").unwrap();
+    ///
+    /// let mut data_set_2: DataSet = DataSet::new();
+    /// data_set_2.add_fixed_dim("longitude", 4).unwrap();
+    /// data_set_2.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set_2.add_global_attr_string("institution", "Ifremer").unwrap();
+    /// data_set_2.add_global_attr_string("title", "Sea surface temperature").unwrap();
+    ///
+    /// assert_eq!(true,            data_set_1.equivalent(&data_set_2));
+    /// assert_eq!(false,           data_set_1 == data_set_2);
+    /// ```
+    pub fn equivalent(&self, other: &DataSet) -> bool {
+        return self.diff(other).is_empty();
+    }
+}
+
+/// Compares the data of two variables, converted into `f64`, within a `tolerance`.
+///
+/// Each variable is given with its data. The fill values (the attribute `_FillValue` or the default fill value of the data type)
+/// of both variables are equal, and differ from any other value. Only the indices common to both variables are compared.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{compare_vars, DataDiff, DataSet, DataVector, Tolerance, NC_FILL_F32};
+///
+/// let mut data_set: DataSet = DataSet::new();
+/// data_set.add_fixed_dim("x", 4).unwrap();
+/// data_set.add_var_f32("reference", &["x"]).unwrap();
+/// data_set.add_var_f64("output", &["x"]).unwrap();
+/// data_set.add_var_attr_f64("output", "_FillValue", vec![-999.0]).unwrap();
+///
+/// let reference = DataVector::F32(vec![1.0, 2.0, NC_FILL_F32, 4.0]);
+/// let output = DataVector::F64(vec![1.0 + 1e-9, 2.5, -999.0, 4.0]);
+/// let ref_var = data_set.get_var("reference").unwrap();
+/// let output_var = data_set.get_var("output").unwrap();
+///
+/// let diff: DataDiff = compare_vars((ref_var, &reference), (output_var, &output), Tolerance::relative(1e-6));
+/// assert_eq!((4, 4),                  diff.len);
+/// assert_eq!(vec![1],                 diff.indices);
+/// assert_eq!(0.5,                     diff.max_abs_diff);
+///
+/// assert_eq!(true,                    compare_vars((ref_var, &reference), (output_var, &output), Tolerance::absolute(0.5)).is_empty());
+/// ```
+pub fn compare_vars(a: (&Variable, &DataVector), b: (&Variable, &DataVector), tolerance: Tolerance) -> DataDiff {
+    let (var_a, data_a): (&Variable, &DataVector) = a;
+    let (var_b, data_b): (&Variable, &DataVector) = b;
+    let fill_value_a: Option<f64> = fill_value_as_f64(var_a, data_a.data_type());
+    let fill_value_b: Option<f64> = fill_value_as_f64(var_b, data_b.data_type());
+    let mut diff: DataDiff = DataDiff{
+        len: (data_a.len(), data_b.len()),
+        indices: vec![],
+        max_abs_diff: 0.0,
+    };
+    for (index, (value_a, value_b)) in data_a.iter_as_f64().zip(data_b.iter_as_f64()).enumerate() {
+        let is_fill_a: bool = fill_value_a == Some(value_a);
+        let is_fill_b: bool = fill_value_b == Some(value_b);
+        if is_fill_a || is_fill_b {
+            if is_fill_a != is_fill_b {
+                diff.indices.push(index);
+            }
+            continue;
+        }
+        if !tolerance.is_equal(value_a, value_b) {
+            diff.indices.push(index);
+        }
+        let abs_diff: f64 = (value_a - value_b).abs();
+        if abs_diff > diff.max_abs_diff {
+            diff.max_abs_diff = abs_diff;
+        }
+    }
+    return diff;
+}
+
+/// Returns the fill value of the variable converted into `f64`, if the data have the data type of the variable.
+fn fill_value_as_f64(var: &Variable, data_type: DataType) -> Option<f64> {
+    return match data_type {
+        DataType::I8 => var.fill_value::<i8>().map(f64::from),
+        DataType::U8 => var.fill_value::<u8>().map(f64::from),
+        DataType::I16 => var.fill_value::<i16>().map(f64::from),
+        DataType::I32 => var.fill_value::<i32>().map(f64::from),
+        DataType::F32 => var.fill_value::<f32>().map(f64::from),
+        DataType::F64 => var.fill_value::<f64>(),
+        DataType::U16 => var.fill_value::<u16>().map(f64::from),
+        DataType::U32 => var.fill_value::<u32>().map(f64::from),
+        DataType::I64 => var.fill_value::<i64>().map(|fill_value: i64| fill_value as f64),
+        DataType::U64 => var.fill_value::<u64>().map(|fill_value: u64| fill_value as f64),
+    };
+}
+
+fn diff_vars(var: &Variable, other_var: &Variable) -> VariableDiff {
+    let (added_attrs, removed_attrs, changed_attrs) = diff_attrs(&var.get_attrs(), &other_var.get_attrs());
+    return VariableDiff{
+        name: var.name().to_owned(),
+        data_type: match var.data_type() == other_var.data_type() {
+            true => None,
+            false => Some((var.data_type(), other_var.data_type())),
+        },
+        dim_names: match var.dim_names() == other_var.dim_names() {
+            true => None,
+            false => Some((var.dim_names(), other_var.dim_names())),
+        },
+        added_attrs: added_attrs,
+        removed_attrs: removed_attrs,
+        changed_attrs: changed_attrs,
+    };
+}
+
+/// Returns the names of the added and the removed attributes, and the changed attributes.
+fn diff_attrs(attrs: &[&Attribute], other_attrs: &[&Attribute]) -> (Vec<String>, Vec<String>, Vec<AttributeDiff>) {
+    let find_attr = |attrs: &[&Attribute], attr_name: &str| -> Option<Attribute> {
+        return attrs.iter().find(|attr: &&&Attribute| attr.name() == attr_name).map(|attr: &&Attribute| (*attr).clone());
+    };
+    let mut removed_attrs: Vec<String> = vec![];
+    let mut changed_attrs: Vec<AttributeDiff> = vec![];
+    for attr in attrs.iter() {
+        match find_attr(other_attrs, attr.name()) {
+            None => removed_attrs.push(attr.name().to_owned()),
+            Some(other_attr) => {
+                if !same_values(&attr.data, &other_attr.data) {
+                    changed_attrs.push(AttributeDiff{
+                        name: attr.name().to_owned(),
+                        old_value: attr.data.clone(),
+                        new_value: other_attr.data,
+                    });
+                }
+            },
+        }
+    }
+    let added_attrs: Vec<String> = other_attrs.iter()
+        .filter(|other_attr: &&&Attribute| find_attr(attrs, other_attr.name()).is_none())
+        .map(|other_attr: &&Attribute| other_attr.name().to_owned())
+        .collect();
+    return (added_attrs, removed_attrs, changed_attrs);
+}
+
+/// Compares the values, the floating-point values are compared bit to bit (the `NaN` values are equal).
+pub(in crate::data_set) fn same_values(data: &DataVector, other_data: &DataVector) -> bool {
+    return match (data, other_data) {
+        (DataVector::F32(data), DataVector::F32(other_data)) => {
+            data.len() == other_data.len() && data.iter().zip(other_data.iter()).all(|(a, b): (&f32, &f32)| a.to_bits() == b.to_bits())
+        },
+        (DataVector::F64(data), DataVector::F64(other_data)) => {
+            data.len() == other_data.len() && data.iter().zip(other_data.iter()).all(|(a, b): (&f64, &f64)| a.to_bits() == b.to_bits())
+        },
+        _ => data == other_data,
+    };
+}
@@ -228,7 +228,7 @@ impl Dimension {
         return self.dim_type() == DimensionType::FixedSize;
     }
 
-    pub(in crate::data_set) fn check_dim_name(dim_name: &str) -> Result<(), InvalidDataSet> {
+    pub(crate) fn check_dim_name(dim_name: &str) -> Result<(), InvalidDataSet> {
         return match is_valid_name(dim_name) {
             true => Ok(()),
             false => Err(InvalidDataSet::DimensionNameNotValid(dim_name.to_string())),
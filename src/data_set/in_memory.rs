@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+
+use crate::{DataSet, DataVector, InvalidDataSet, NcType, Variable};
+
+/// Data set coupled with the data of its variables, kept in memory.
+///
+/// The data are checked against the definition of their variable (data type and length), so the definition and
+/// the data cannot drift out of sync. The data of a variable are optional, the variables without data are written
+/// with fill values (see the method `write_file`).
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{DataSet, DataType, DataVector, InMemoryDataSet, InvalidDataSet};
+///
+/// let mut data_set: DataSet = DataSet::new();
+/// data_set.add_fixed_dim("latitude", 3).unwrap();
+/// data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+/// data_set.add_var_i32("count", &["latitude"]).unwrap();
+///
+/// let mut in_memory: InMemoryDataSet = InMemoryDataSet::new(data_set);
+/// in_memory.set_var("latitude", vec![0.0_f32, 0.5, 1.0]).unwrap();
+///
+/// assert_eq!(Some(&[0.0_f32, 0.5, 1.0][..]),      in_memory.get_var::<f32>("latitude"));
+/// assert_eq!(None,                                in_memory.get_var::<f64>("latitude"));
+/// assert_eq!(vec!["count"],                       in_memory.vars_without_data());
+///
+/// // The data are checked
+/// assert_eq!(
+///     InvalidDataSet::VariableMismatchDataLength{var_name: String::from("count"), req: 3, get: 2},
+///     in_memory.set_var("count", vec![1_i32, 2]).unwrap_err()
+/// );
+/// assert_eq!(
+///     InvalidDataSet::VariableMismatchDataType{var_name: String::from("count"), req: DataType::I32, get: DataType::F64},
+///     in_memory.set_var_data("count", DataVector::F64(vec![1.0, 2.0, 3.0])).unwrap_err()
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct InMemoryDataSet {
+    pub(crate) data_set: DataSet,
+    pub(crate) data: HashMap<String, DataVector>,
+}
+
+impl InMemoryDataSet {
+
+    /// Creates an in-memory data set without data.
+    pub fn new(data_set: DataSet) -> InMemoryDataSet {
+        return InMemoryDataSet{
+            data_set: data_set,
+            data: HashMap::new(),
+        };
+    }
+
+    /// Creates an in-memory data set from a data set and the data of its variables, like those returned by
+    /// [`DataSet::from_cdl_with_data`](struct.DataSet.html#method.from_cdl_with_data) or [`FileReader::read_all_vars`](struct.FileReader.html#method.read_all_vars).
+    ///
+    /// Returns the first error found if some data do not match their variable.
+    pub fn from_parts(data_set: DataSet, data: HashMap<String, DataVector>) -> Result<InMemoryDataSet, InvalidDataSet> {
+        for (var_name, var_data) in data.iter() {
+            InMemoryDataSet::check_var_data(&data_set, var_name, var_data)?;
+        }
+        return Ok(InMemoryDataSet{
+            data_set: data_set,
+            data: data,
+        });
+    }
+
+    /// Returns the data set and the data of its variables.
+    pub fn into_parts(self) -> (DataSet, HashMap<String, DataVector>) {
+        return (self.data_set, self.data);
+    }
+
+    /// Returns the definition of the data set.
+    pub fn data_set(&self) -> &DataSet {
+        return &self.data_set;
+    }
+
+    /// Returns `true` if the data of the variable are defined.
+    pub fn has_var_data(&self, var_name: &str) -> bool {
+        return self.data.contains_key(var_name);
+    }
+
+    /// Returns the names of the variables without data, in their definition order.
+    pub fn vars_without_data(&self) -> Vec<String> {
+        return self.data_set.vars()
+            .filter(|var: &&Variable| !self.data.contains_key(var.name()))
+            .map(|var: &Variable| var.name().to_owned())
+            .collect();
+    }
+
+    /// Returns the data of the variable, or `None` if they are not defined.
+    pub fn get_var_data(&self, var_name: &str) -> Option<&DataVector> {
+        return self.data.get(var_name);
+    }
+
+    /// Returns the typed data of the variable, or `None` if they are not defined or if `T` is not the data type of the variable.
+    pub fn get_var<T: NcType>(&self, var_name: &str) -> Option<&[T]> {
+        return self.data.get(var_name).and_then(T::from_data_vector);
+    }
+
+    /// Sets the data of the variable, and returns its previous data.
+    ///
+    /// Returns an error if the variable is not defined, or if the data type or the length of the data do not match the variable.
+    pub fn set_var_data(&mut self, var_name: &str, var_data: DataVector) -> Result<Option<DataVector>, InvalidDataSet> {
+        InMemoryDataSet::check_var_data(&self.data_set, var_name, &var_data)?;
+        return Ok(self.data.insert(var_name.to_owned(), var_data));
+    }
+
+    /// Sets the typed data of the variable (see the method `set_var_data`).
+    pub fn set_var<T: NcType>(&mut self, var_name: &str, var_data: Vec<T>) -> Result<Option<DataVector>, InvalidDataSet> {
+        return self.set_var_data(var_name, T::into_data_vector(var_data));
+    }
+
+    /// Removes and returns the data of the variable.
+    pub fn remove_var_data(&mut self, var_name: &str) -> Option<DataVector> {
+        return self.data.remove(var_name);
+    }
+
+    /// Changes the size of a *fixed-size* dimension (see [`DataSet::resize_fixed_dim`](struct.DataSet.html#method.resize_fixed_dim)).
+    ///
+    /// Returns the error `DimensionYetUsed` if some variables defined over the dimension have data, their data must be removed first.
+    pub fn resize_fixed_dim(&mut self, dim_name: &str, new_dim_size: usize) -> Result<(), InvalidDataSet> {
+        let var_names: Vec<String> = self.data_set.vars()
+            .filter(|var: &&Variable| self.data.contains_key(var.name()))
+            .filter(|var: &&Variable| var.dim_names().iter().any(|var_dim_name: &String| var_dim_name == dim_name))
+            .map(|var: &Variable| var.name().to_owned())
+            .collect();
+        if !var_names.is_empty() {
+            return Err(InvalidDataSet::DimensionYetUsed{var_names: var_names, dim_name: dim_name.to_string()});
+        }
+        return self.data_set.resize_fixed_dim(dim_name, new_dim_size);
+    }
+
+    fn check_var_data(data_set: &DataSet, var_name: &str, var_data: &DataVector) -> Result<(), InvalidDataSet> {
+        let var: &Variable = data_set.get_var(var_name).ok_or_else(|| InvalidDataSet::VariableNotDefined(var_name.to_owned()))?;
+        if var.data_type() != var_data.data_type() {
+            return Err(InvalidDataSet::VariableMismatchDataType{
+                var_name: var_name.to_owned(),
+                req: var.data_type(),
+                get: var_data.data_type(),
+            });
+        }
+        if var.len() != var_data.len() {
+            return Err(InvalidDataSet::VariableMismatchDataLength{
+                var_name: var_name.to_owned(),
+                req: var.len(),
+                get: var_data.len(),
+            });
+        }
+        return Ok(());
+    }
+}
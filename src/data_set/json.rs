@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::{DataSet, DataVector, Dimension, NcType, Variable, Attribute};
+use crate::data_set::cdl::type_name;
+
+impl DataSet {
+
+    /// Returns the JSON text describing the data set, in the format of the command `ncks --json` of NCO.
+    ///
+    /// The dimensions, the variables and the global attributes are the members of the JSON object, the empty ones are omitted.
+    /// The attributes containing a single number are written as a number, the `char` attributes as a string.
+    /// The non-finite floating-point numbers (not allowed by JSON) are written as `null`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::DataSet;
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.set_unlimited_dim("time", 2).unwrap();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    /// data_set.add_var_attr_string("latitude", "units", "degrees_north").unwrap();
+    /// data_set.add_var_i16("temperature", &["time", "latitude"]).unwrap();
+    /// data_set.add_var_attr_i16("temperature", "valid_range", vec![0, 50]).unwrap();
+    /// data_set.add_global_attr_string("title", "Example").unwrap();
+    ///
+    /// assert_eq!(
+    ///     "{\n\
+    ///     \x20 \"dimensions\": {\n\
+    ///     \x20   \"time\": 2,\n\
+    ///     \x20   \"latitude\": 3\n\
+    ///     \x20 },\n\
+    ///     \x20 \"variables\": {\n\
+    ///     \x20   \"latitude\": {\n\
+    ///     \x20     \"shape\": [\"latitude\"],\n\
+    ///     \x20     \"type\": \"float\",\n\
+    ///     \x20     \"attributes\": {\n\
+    ///     \x20       \"units\": \"degrees_north\"\n\
+    ///     \x20     }\n\
+    ///     \x20   },\n\
+    ///     \x20   \"temperature\": {\n\
+    ///     \x20     \"shape\": [\"time\", \"latitude\"],\n\
+    ///     \x20     \"type\": \"short\",\n\
+    ///     \x20     \"attributes\": {\n\
+    ///     \x20       \"valid_range\": [0, 50]\n\
+    ///     \x20     }\n\
+    ///     \x20   }\n\
+    ///     \x20 },\n\
+    ///     \x20 \"attributes\": {\n\
+    ///     \x20   \"title\": \"Example\"\n\
+    ///     \x20 }\n\
+    ///     }\n",
+    ///     data_set.to_json()
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        return self.to_json_with_data(&HashMap::new());
+    }
+
+    /// Returns the JSON text describing the data set and the data of its variables, in the format of the command `ncks --json` of NCO.
+    ///
+    /// The data are nested arrays following the shape of the variables, the fill values are written as `null`, and the
+    /// values of the `char` variables are written as strings splitted on the last dimension.
+    /// The variables missing from `vars_data` (or whose the number of values is not valid) are written without data, which
+    /// allows to only give the data of the small variables.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use netcdf3::{DataSet, DataVector};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    ///
+    /// let mut vars_data: HashMap<String, DataVector> = HashMap::new();
+    /// vars_data.insert(String::from("latitude"), DataVector::F32(vec![0.0, 0.5, netcdf3::NC_FILL_F32]));
+    ///
+    /// let json: String = data_set.to_json_with_data(&vars_data);
+    /// assert_eq!(true,                                    json.contains("\"data\": [0.0, 0.5, null]\n"));
+    /// ```
+    pub fn to_json_with_data(&self, vars_data: &HashMap<String, DataVector>) -> String {
+        let mut members: Vec<(&str, String)> = vec![];
+        if !self.dims.is_empty() {
+            let dims: Vec<(String, String)> = self.dims.iter().map(|dim: &Rc<Dimension>| (dim.name(), dim.size().to_string())).collect();
+            members.push(("dimensions", json_object(&dims, "  ")));
+        }
+        if !self.vars.is_empty() {
+            let vars: Vec<(&str, String)> = self.vars.iter().map(|var: &Variable| {
+                let data: Option<&DataVector> = vars_data.get(var.name())
+                    .filter(|data: &&DataVector| data.data_type() == var.data_type() && data.len() == var.len() && data.len() > 0);
+                return (var.name(), json_var(var, data, "    "));
+            }).collect();
+            members.push(("variables", json_object(&vars, "  ")));
+        }
+        if !self.attrs.is_empty() {
+            members.push(("attributes", json_attrs(self.attrs(), "  ")));
+        }
+        let mut json: String = json_object(&members, "");
+        json.push('\n');
+        return json;
+    }
+}
+
+/// Returns the JSON object of a variable : its dimensions, its data type, its attributes and its optional data.
+fn json_var(var: &Variable, data: Option<&DataVector>, indent: &str) -> String {
+    let mut members: Vec<(&str, String)> = vec![];
+    let dim_names: Vec<String> = var.dim_names();
+    if !dim_names.is_empty() {
+        let dim_names: Vec<String> = dim_names.iter().map(|dim_name: &String| json_string(dim_name)).collect();
+        members.push(("shape", format!("[{}]", dim_names.join(", "))));
+    }
+    members.push(("type", json_string(type_name(var.data_type()))));
+    if var.attrs().len() > 0 {
+        members.push(("attributes", json_attrs(var.attrs(), &format!("{}  ", indent))));
+    }
+    if let Some(data) = data {
+        let shape: Vec<usize> = var.shape();
+        let (values, shape): (Vec<String>, &[usize]) = match data {
+            DataVector::U8(chars) => {
+                // Each string fills the last *fixed-size* dimension
+                let string_len: usize = match var.get_dims().last() {
+                    Some(dim) if !dim.is_unlimited() => dim.size(),
+                    _ => chars.len(),
+                };
+                let strings: Vec<String> = chars.chunks(string_len.max(1)).map(|chars: &[u8]| json_string(&decode_chars(chars))).collect();
+                (strings, &shape[0..shape.len().saturating_sub(1)])
+            },
+            _ => (json_numbers(data, Some(var)), &shape[..]),
+        };
+        members.push(("data", json_nested(&values, shape)));
+    }
+    return json_object(&members, indent);
+}
+
+/// Returns the JSON object of the attributes.
+fn json_attrs<'a>(attrs: std::slice::Iter<'a, Attribute>, indent: &str) -> String {
+    let attrs: Vec<(&str, String)> = attrs.map(|attr: &Attribute| {
+        let value: String = match &attr.data {
+            DataVector::U8(chars) => json_string(&decode_chars(chars)),
+            data => {
+                let values: Vec<String> = json_numbers(data, None);
+                match values.len() {
+                    1 => values.into_iter().next().unwrap_or_default(),
+                    _ => format!("[{}]", values.join(", ")),
+                }
+            },
+        };
+        return (attr.name(), value);
+    }).collect();
+    return json_object(&attrs, indent);
+}
+
+/// Returns the JSON object containing the members, whose the values are already written in JSON.
+///
+/// The members are indented by 2 spaces more than the closing brace.
+fn json_object<N: AsRef<str>>(members: &[(N, String)], indent: &str) -> String {
+    if members.is_empty() {
+        return String::from("{}");
+    }
+    let members: Vec<String> = members.iter().map(|(name, value): &(N, String)| format!("{}  {}: {}", indent, json_string(name.as_ref()), value)).collect();
+    return format!("{{\n{}\n{}}}", members.join(",\n"), indent);
+}
+
+/// Returns the values nested in arrays following the shape (a single value if the shape is empty).
+fn json_nested(values: &[String], shape: &[usize]) -> String {
+    return match shape.len() {
+        0 => values.first().cloned().unwrap_or_else(|| String::from("null")),
+        1 => format!("[{}]", values.join(", ")),
+        _ => {
+            let chunk_len: usize = shape[1..].iter().product::<usize>().max(1);
+            let chunks: Vec<String> = values.chunks(chunk_len).map(|chunk: &[String]| json_nested(chunk, &shape[1..])).collect();
+            format!("[{}]", chunks.join(", "))
+        },
+    };
+}
+
+/// Returns the JSON numbers, the fill values of the variable and the non-finite numbers being `null`.
+pub(crate) fn json_numbers(data: &DataVector, var: Option<&Variable>) -> Vec<String> {
+    fn format<T: NcType>(values: &[T], var: Option<&Variable>, format_value: fn(&T) -> String) -> Vec<String> {
+        let fill_value: Option<T> = var.and_then(|var: &Variable| var.fill_value::<T>());
+        return values.iter().map(|value: &T| match Some(*value) == fill_value {
+            true => String::from("null"),
+            false => format_value(value),
+        }).collect();
+    }
+    return match data {
+        DataVector::I8(data) => format(data, var, |value: &i8| value.to_string()),
+        DataVector::U8(data) => format(data, var, |value: &u8| value.to_string()),
+        DataVector::I16(data) => format(data, var, |value: &i16| value.to_string()),
+        DataVector::I32(data) => format(data, var, |value: &i32| value.to_string()),
+        DataVector::F32(data) => format(data, var, |value: &f32| if value.is_finite() { format!("{:?}", value) } else { String::from("null") }),
+        DataVector::F64(data) => format(data, var, |value: &f64| if value.is_finite() { format!("{:?}", value) } else { String::from("null") }),
+        DataVector::U16(data) => format(data, var, |value: &u16| value.to_string()),
+        DataVector::U32(data) => format(data, var, |value: &u32| value.to_string()),
+        DataVector::I64(data) => format(data, var, |value: &i64| value.to_string()),
+        DataVector::U64(data) => format(data, var, |value: &u64| value.to_string()),
+    };
+}
+
+/// Returns the text of the characters, without their trailing NUL characters.
+fn decode_chars(chars: &[u8]) -> String {
+    let chars: &[u8] = match chars.iter().rposition(|c: &u8| *c != 0) {
+        Some(last_pos) => &chars[0..=last_pos],
+        None => &[],
+    };
+    return String::from_utf8_lossy(chars).into_owned();
+}
+
+/// Returns the quoted and escaped JSON string.
+pub(crate) fn json_string(text: &str) -> String {
+    let mut json: String = String::with_capacity(text.len() + 2);
+    json.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\t' => json.push_str("\\t"),
+            '\r' => json.push_str("\\r"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    return json;
+}
@@ -0,0 +1,224 @@
+use std::rc::Rc;
+
+use crate::{Attribute, DataSet, DataType, Dimension, DimensionType, InvalidDataSet, Variable};
+use crate::data_set::diff::same_values;
+
+/// Handling of the conflicts while merging two data sets (see [`DataSet::merge`](struct.DataSet.html#method.merge)).
+///
+/// A conflict occurs when a dimension, a variable or an attribute is defined in both data sets with different definitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergePolicy {
+    /// An error is returned, and the data set is not modified.
+    Error,
+    /// The definitions of the data set are kept.
+    Skip,
+    /// The definitions of the other data set replace those of the data set.
+    Overwrite,
+    /// The definitions of the other data set are kept with a new name, suffixed by the first free index (`temp_1`, `temp_2`, ...).
+    Rename,
+}
+
+/// Renames done while merging two data sets with the policy `MergePolicy::Rename` (see [`DataSet::merge`](struct.DataSet.html#method.merge)).
+///
+/// The renamed items are those of the other data set, the names are given as pairs `(old_name, new_name)`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MergeReport {
+    pub renamed_dims: Vec<(String, String)>,
+    pub renamed_vars: Vec<(String, String)>,
+    /// Name of the variable in the merged data set, old and new names of its attribute
+    pub renamed_var_attrs: Vec<(String, String, String)>,
+    pub renamed_global_attrs: Vec<(String, String)>,
+}
+
+impl MergeReport {
+
+    /// Returns `true` if nothing has been renamed.
+    pub fn is_empty(&self) -> bool {
+        return self.renamed_dims.is_empty() && self.renamed_vars.is_empty()
+            && self.renamed_var_attrs.is_empty() && self.renamed_global_attrs.is_empty();
+    }
+}
+
+/// Definition of a variable : name, dimension names, data type and attributes
+type VariableDef = (String, Vec<String>, DataType, Vec<Attribute>);
+
+impl DataSet {
+
+    /// Merges the dimensions, the variables and the attributes of an other data set into the data set.
+    ///
+    /// The items only defined in `other` are appended, the items with the same definitions are kept once,
+    /// and the conflicts are handled by the `policy` :
+    /// - `MergePolicy::Error` returns the errors `DimensionAlreadyExists`, `VariableAlreadyExists`, `VariableAttributeAlreadyExists` or `GlobalAttributeAlreadyExists`.
+    /// - `MergePolicy::Skip` keeps the definitions of the data set, the variables of `other` defined over a skipped dimension are also skipped.
+    /// - `MergePolicy::Overwrite` replaces the definitions of the data set, the overwritten variables take all the attributes of `other`.
+    /// - `MergePolicy::Rename` appends the conflicting definitions of `other` with new names, the variables of `other` defined over a renamed dimension use its new name.
+    ///   An error is still returned if both data sets have different *unlimited-size* dimensions.
+    ///
+    /// Returns the renames done (always empty except with `MergePolicy::Rename`). The data set is not modified if an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, MergePolicy, MergeReport};
+    ///
+    /// let mut ocean: DataSet = DataSet::new();
+    /// ocean.add_fixed_dim("latitude", 3).unwrap();
+    /// ocean.add_var_f32("sst", &["latitude"]).unwrap();
+    /// ocean.add_global_attr_string("title", "Ocean").unwrap();
+    ///
+    /// let mut atmosphere: DataSet = DataSet::new();
+    /// atmosphere.add_fixed_dim("latitude", 3).unwrap();
+    /// atmosphere.add_var_f32("air_temperature", &["latitude"]).unwrap();
+    /// atmosphere.add_global_attr_string("title", "Atmosphere").unwrap();
+    ///
+    /// // The global attribute `title` is in conflict
+    /// assert_eq!(true,                                            ocean.merge(&atmosphere, MergePolicy::Error).is_err());
+    ///
+    /// ocean.merge(&atmosphere, MergePolicy::Skip).unwrap();
+    /// assert_eq!(vec!["latitude"],                                ocean.dim_names());
+    /// assert_eq!(vec!["sst", "air_temperature"],                  ocean.get_var_names());
+    /// assert_eq!(Some(String::from("Ocean")),                     ocean.get_global_attr_as_string("title"));
+    ///
+    /// // The global attribute `title` of `atmosphere` is renamed
+    /// let mut ocean: DataSet = DataSet::new();
+    /// ocean.add_global_attr_string("title", "Ocean").unwrap();
+    /// let report: MergeReport = ocean.merge(&atmosphere, MergePolicy::Rename).unwrap();
+    /// assert_eq!(vec![(String::from("title"), String::from("title_1"))], report.renamed_global_attrs);
+    /// assert_eq!(Some(String::from("Atmosphere")),                ocean.get_global_attr_as_string("title_1"));
+    /// ```
+    pub fn merge(&mut self, other: &DataSet, policy: MergePolicy) -> Result<MergeReport, InvalidDataSet> {
+        let mut report: MergeReport = MergeReport::default();
+
+        // Dimensions
+        let mut dims: Vec<(String, DimensionType, usize)> = self.dims.iter()
+            .map(|dim: &Rc<Dimension>| (dim.name(), dim.dim_type(), dim.size()))
+            .collect();
+        let mut skipped_dim_names: Vec<String> = vec![];
+        for other_dim in other.dims.iter() {
+            let mut other_dim: (String, DimensionType, usize) = (other_dim.name(), other_dim.dim_type(), other_dim.size());
+            match dims.iter().position(|dim: &(String, DimensionType, usize)| dim.0 == other_dim.0) {
+                None => dims.push(other_dim),
+                Some(index) if dims[index] == other_dim => {},
+                Some(index) => match policy {
+                    MergePolicy::Error => return Err(InvalidDataSet::DimensionAlreadyExists(other_dim.0)),
+                    MergePolicy::Skip => skipped_dim_names.push(other_dim.0),
+                    MergePolicy::Overwrite => dims[index] = other_dim,
+                    MergePolicy::Rename => {
+                        let new_dim_name: String = free_name(&other_dim.0, |name: &str| {
+                            dims.iter().any(|dim: &(String, DimensionType, usize)| dim.0 == name) || other.has_dim(name)
+                        });
+                        report.renamed_dims.push((other_dim.0, new_dim_name.clone()));
+                        other_dim.0 = new_dim_name;
+                        dims.push(other_dim);
+                    },
+                },
+            }
+        }
+
+        // Variables and their attributes
+        let mut vars: Vec<VariableDef> = self.vars.iter()
+            .map(|var: &Variable| (var.name.clone(), var.dim_names(), var.data_type(), var.attrs.clone()))
+            .collect();
+        for other_var in other.vars.iter() {
+            // The renamed dimensions are replaced by their new names
+            let other_dim_names: Vec<String> = other_var.dim_names().into_iter().map(|dim_name: String| {
+                report.renamed_dims.iter()
+                    .find(|(old_name, _new_name): &&(String, String)| *old_name == dim_name)
+                    .map(|(_old_name, new_name): &(String, String)| new_name.clone())
+                    .unwrap_or(dim_name)
+            }).collect();
+            let mut other_var: VariableDef = (other_var.name.clone(), other_dim_names, other_var.data_type(), other_var.attrs.clone());
+            let depends_on_skipped_dim: bool = other_var.1.iter().any(|dim_name: &String| skipped_dim_names.contains(dim_name));
+            match vars.iter().position(|var: &VariableDef| var.0 == other_var.0) {
+                None if depends_on_skipped_dim => {},
+                None => vars.push(other_var),
+                Some(index) if vars[index].1 == other_var.1 && vars[index].2 == other_var.2 => {
+                    let var_name: String = other_var.0;
+                    let renamed_attrs: Vec<(String, String)> = merge_attrs(&mut vars[index].3, other_var.3, policy).map_err(|attr_name: String| {
+                        InvalidDataSet::VariableAttributeAlreadyExists{var_name: var_name.clone(), attr_name: attr_name}
+                    })?;
+                    report.renamed_var_attrs.extend(renamed_attrs.into_iter().map(|(old_name, new_name): (String, String)| {
+                        (var_name.clone(), old_name, new_name)
+                    }));
+                },
+                Some(index) => match policy {
+                    MergePolicy::Error => return Err(InvalidDataSet::VariableAlreadyExists(other_var.0)),
+                    MergePolicy::Skip => {},
+                    MergePolicy::Overwrite => vars[index] = other_var,
+                    MergePolicy::Rename => {
+                        let new_var_name: String = free_name(&other_var.0, |name: &str| {
+                            vars.iter().any(|var: &VariableDef| var.0 == name) || other.has_var(name)
+                        });
+                        report.renamed_vars.push((other_var.0, new_var_name.clone()));
+                        other_var.0 = new_var_name;
+                        vars.push(other_var);
+                    },
+                },
+            }
+        }
+
+        // Global attributes
+        let mut global_attrs: Vec<Attribute> = self.attrs.clone();
+        report.renamed_global_attrs = merge_attrs(&mut global_attrs, other.attrs.clone(), policy).map_err(InvalidDataSet::GlobalAttributeAlreadyExists)?;
+
+        // Build the merged data set, the data set is only replaced if it is valid
+        let mut data_set: DataSet = DataSet::with_limits(self.limits().clone());
+        for (dim_name, dim_type, dim_size) in dims.into_iter() {
+            match dim_type {
+                DimensionType::FixedSize => data_set.add_fixed_dim(dim_name, dim_size)?,
+                DimensionType::UnlimitedSize => data_set.set_unlimited_dim(dim_name, dim_size)?,
+            }
+        }
+        for (var_name, dim_names, data_type, attrs) in vars.into_iter() {
+            data_set.add_var(&var_name, &dim_names, data_type)?;
+            let var: &mut Variable = data_set.get_var_mut(&var_name).ok_or(InvalidDataSet::VariableNotDefined(var_name))?;
+            var.attrs = attrs;
+        }
+        data_set.attrs = global_attrs;
+        *self = data_set;
+        return Ok(report);
+    }
+}
+
+/// Merges the attributes, and returns the old and new names of the renamed attributes.
+///
+/// Returns the name of the first conflicting attribute with the policy `MergePolicy::Error`.
+fn merge_attrs(attrs: &mut Vec<Attribute>, other_attrs: Vec<Attribute>, policy: MergePolicy) -> Result<Vec<(String, String)>, String> {
+    let other_attr_names: Vec<String> = other_attrs.iter().map(|attr: &Attribute| attr.name.clone()).collect();
+    let mut renamed_attrs: Vec<(String, String)> = vec![];
+    for mut other_attr in other_attrs.into_iter() {
+        match attrs.iter().position(|attr: &Attribute| attr.name == other_attr.name) {
+            None => attrs.push(other_attr),
+            Some(index) if same_values(&attrs[index].data, &other_attr.data) => {},
+            Some(index) => match policy {
+                MergePolicy::Error => return Err(other_attr.name),
+                MergePolicy::Skip => {},
+                MergePolicy::Overwrite => attrs[index] = other_attr,
+                MergePolicy::Rename => {
+                    let new_attr_name: String = free_name(&other_attr.name, |name: &str| {
+                        attrs.iter().any(|attr: &Attribute| attr.name == name) || other_attr_names.iter().any(|attr_name: &String| attr_name == name)
+                    });
+                    renamed_attrs.push((other_attr.name, new_attr_name.clone()));
+                    other_attr.name = new_attr_name;
+                    attrs.push(other_attr);
+                },
+            },
+        }
+    }
+    return Ok(renamed_attrs);
+}
+
+/// Returns the name suffixed by the first index (starting from 1) which gives a free name.
+fn free_name<F>(name: &str, is_used: F) -> String
+where
+    F: Fn(&str) -> bool
+{
+    let mut index: usize = 1;
+    loop {
+        let new_name: String = format!("{}_{}", name, index);
+        if !is_used(&new_name) {
+            return new_name;
+        }
+        index += 1;
+    }
+}
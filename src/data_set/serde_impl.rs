@@ -0,0 +1,221 @@
+//! Implementations of `Serialize` and `Deserialize` (optional feature `serde`) for the definition of the data sets,
+//! and for the in-memory data sets (definition and data).
+//!
+//! The dimensions of a data set are serialized once, and its variables reference them by their names. The deserialized
+//! data sets are built with the methods `add_fixed_dim`, `add_var`, ... so they are checked like any other data set.
+//! The data of the in-memory data sets are serialized in the order of the variables, and checked when deserialized.
+//! Note that some formats (e.g. JSON) cannot represent the `NaN` and infinite values of the floating-point attributes.
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+
+use crate::{Attribute, DataSet, DataType, DataVector, Dimension, InMemoryDataSet, InvalidDataSet, Variable};
+
+/// Serialized form of a dimension
+#[derive(Serialize, Deserialize)]
+struct DimensionDef {
+    name: String,
+    size: usize,
+    #[serde(default)]
+    unlimited: bool,
+}
+
+/// Serialized form of an attribute
+#[derive(Serialize, Deserialize)]
+struct AttributeDef {
+    name: String,
+    data: DataVector,
+}
+
+/// Serialized form of a standalone variable, its dimensions are fully defined
+#[derive(Serialize, Deserialize)]
+struct VariableDef {
+    name: String,
+    dims: Vec<Dimension>,
+    data_type: DataType,
+    #[serde(default)]
+    attrs: Vec<Attribute>,
+}
+
+/// Serialized form of a variable of a data set, its dimensions are referenced by their names
+#[derive(Serialize, Deserialize)]
+struct DataSetVariableDef {
+    name: String,
+    dim_names: Vec<String>,
+    data_type: DataType,
+    #[serde(default)]
+    attrs: Vec<Attribute>,
+}
+
+/// Serialized form of a data set
+#[derive(Serialize, Deserialize)]
+struct DataSetDef {
+    #[serde(default)]
+    dims: Vec<Dimension>,
+    #[serde(default)]
+    attrs: Vec<Attribute>,
+    #[serde(default)]
+    vars: Vec<DataSetVariableDef>,
+}
+
+/// Serialized form of the data of a variable, borrowed while serializing
+#[derive(Serialize)]
+struct VariableDataRef<'a> {
+    name: &'a str,
+    data: &'a DataVector,
+}
+
+/// Deserialized form of the data of a variable
+#[derive(Deserialize)]
+struct VariableDataDef {
+    name: String,
+    data: DataVector,
+}
+
+/// Serialized form of an in-memory data set, borrowed while serializing
+#[derive(Serialize)]
+struct InMemoryDataSetRef<'a> {
+    data_set: &'a DataSet,
+    data: Vec<VariableDataRef<'a>>,
+}
+
+/// Deserialized form of an in-memory data set
+#[derive(Deserialize)]
+struct InMemoryDataSetDef {
+    data_set: DataSet,
+    #[serde(default)]
+    data: Vec<VariableDataDef>,
+}
+
+/// Converts an error of the data set definition into a deserialization error
+fn invalid<E: Error>(err: InvalidDataSet) -> E {
+    return E::custom(format!("invalid data set: {}", err));
+}
+
+impl Serialize for Dimension {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        return DimensionDef{
+            name: self.name(),
+            size: self.size(),
+            unlimited: self.is_unlimited(),
+        }.serialize(serializer);
+    }
+}
+
+impl<'de> Deserialize<'de> for Dimension {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let dim_def = DimensionDef::deserialize(deserializer)?;
+        return match dim_def.unlimited {
+            false => Dimension::new_fixed_size(&dim_def.name, dim_def.size),
+            true => Dimension::new_unlimited_size(&dim_def.name, dim_def.size),
+        }.map_err(invalid);
+    }
+}
+
+impl Serialize for Attribute {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        return AttributeDef{
+            name: self.name.clone(),
+            data: self.data.clone(),
+        }.serialize(serializer);
+    }
+}
+
+impl<'de> Deserialize<'de> for Attribute {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let attr_def = AttributeDef::deserialize(deserializer)?;
+        return Attribute::new(&attr_def.name, attr_def.data)
+            .map_err(|attr_name: String| D::Error::custom(format!("invalid attribute name: {}", attr_name)));
+    }
+}
+
+impl Serialize for Variable {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        return VariableDef{
+            name: self.name.clone(),
+            dims: self.dims.iter().map(|dim: &Rc<Dimension>| dim.as_ref().clone()).collect(),
+            data_type: self.data_type.clone(),
+            attrs: self.attrs.clone(),
+        }.serialize(serializer);
+    }
+}
+
+impl<'de> Deserialize<'de> for Variable {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let var_def = VariableDef::deserialize(deserializer)?;
+        let dims: Vec<Rc<Dimension>> = var_def.dims.into_iter().map(Rc::new).collect();
+        let mut var: Variable = Variable::new(&var_def.name, dims, var_def.data_type).map_err(invalid)?;
+        for attr in var_def.attrs.into_iter() {
+            if var.attrs.iter().any(|other_attr: &Attribute| other_attr.name == attr.name) {
+                return Err(invalid(InvalidDataSet::VariableAttributeAlreadyExists{var_name: var_def.name, attr_name: attr.name}));
+            }
+            var.attrs.push(attr);
+        }
+        return Ok(var);
+    }
+}
+
+impl Serialize for DataSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        return DataSetDef{
+            dims: self.dims.iter().map(|dim: &Rc<Dimension>| dim.as_ref().clone()).collect(),
+            attrs: self.attrs.clone(),
+            vars: self.vars.iter().map(|var: &Variable| DataSetVariableDef{
+                name: var.name.clone(),
+                dim_names: var.dim_names(),
+                data_type: var.data_type.clone(),
+                attrs: var.attrs.clone(),
+            }).collect(),
+        }.serialize(serializer);
+    }
+}
+
+impl<'de> Deserialize<'de> for DataSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data_set_def = DataSetDef::deserialize(deserializer)?;
+        let mut data_set: DataSet = DataSet::new();
+        for dim in data_set_def.dims.into_iter() {
+            match dim.is_unlimited() {
+                false => data_set.add_fixed_dim(dim.name(), dim.size()),
+                true => data_set.set_unlimited_dim(dim.name(), dim.size()),
+            }.map_err(invalid)?;
+        }
+        for attr in data_set_def.attrs.into_iter() {
+            data_set.add_global_attr_data(&attr.name, attr.data).map_err(invalid)?;
+        }
+        for var_def in data_set_def.vars.into_iter() {
+            data_set.add_var(&var_def.name, &var_def.dim_names, var_def.data_type).map_err(invalid)?;
+            for attr in var_def.attrs.into_iter() {
+                data_set.add_var_attr_data(&var_def.name, &attr.name, attr.data).map_err(invalid)?;
+            }
+        }
+        return Ok(data_set);
+    }
+}
+
+impl Serialize for InMemoryDataSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        return InMemoryDataSetRef{
+            data_set: &self.data_set,
+            data: self.data_set.vars.iter()
+                .filter_map(|var: &Variable| self.data.get(var.name()).map(|data: &DataVector| VariableDataRef{name: var.name(), data: data}))
+                .collect(),
+        }.serialize(serializer);
+    }
+}
+
+impl<'de> Deserialize<'de> for InMemoryDataSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let in_memory_def = InMemoryDataSetDef::deserialize(deserializer)?;
+        let mut data: HashMap<String, DataVector> = HashMap::new();
+        for var_data in in_memory_def.data.into_iter() {
+            if data.contains_key(&var_data.name) {
+                return Err(D::Error::custom(format!("duplicated data of the variable: {}", var_data.name)));
+            }
+            data.insert(var_data.name, var_data.data);
+        }
+        return InMemoryDataSet::from_parts(in_memory_def.data_set, data).map_err(invalid);
+    }
+}
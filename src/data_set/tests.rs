@@ -3,3 +3,11 @@ mod tests_variable;
 mod tests_var_attribute;
 mod tests_global_attribute;
 mod tests_dimension;
+mod tests_cdl;
+mod tests_json;
+mod tests_diff;
+mod tests_merge;
+mod tests_builder;
+mod tests_in_memory;
+#[cfg(feature = "serde")]
+mod tests_serde;
@@ -0,0 +1,80 @@
+use crate::{DataSet, DataSetBuilder, DataType, DataVector, DimensionType, InvalidDataSet};
+
+#[test]
+fn test_build_data_set() {
+    let data_set: DataSet = DataSetBuilder::new()
+        .unlimited_dim("time", 2)
+        .fixed_dim("latitude", 3)
+        .fixed_dim("longitude", 4)
+        .var("time", &["time"], DataType::F64, |v| v.attr_str("units", "days since 1970-01-01"))
+        .var("sst", &["time", "latitude", "longitude"], DataType::F32, |v| {
+            v.attr_str("units", "K")
+                .attr_f32("_FillValue", vec![-999.0])
+                .attr_i16("valid_range", vec![-5, 40])
+        })
+        .var("mask", &["latitude", "longitude"], DataType::I8, |v| v)
+        .global_attr_str("title", "Template")
+        .global_attr_u64("version", vec![2])
+        .global_attr("history", DataVector::U8(b"created".to_vec()))
+        .build()
+        .unwrap();
+
+    assert_eq!(vec!["time", "latitude", "longitude"],       data_set.dim_names());
+    assert_eq!(Some(DimensionType::UnlimitedSize),          data_set.dim_type("time"));
+    assert_eq!(Some(2),                                     data_set.num_records());
+    assert_eq!(vec!["time", "sst", "mask"],                 data_set.get_var_names());
+    assert_eq!(Some(DataType::I8),                          data_set.var_data_type("mask"));
+    assert_eq!(vec!["units", "_FillValue", "valid_range"],  data_set.get_var("sst").unwrap().get_attr_names());
+    assert_eq!(Some(&[-5_i16, 40][..]),                     data_set.get_var_attr_i16("sst", "valid_range"));
+    assert_eq!(Some(0),                                     data_set.num_var_attrs("mask"));
+    assert_eq!(vec!["title", "version", "history"],         data_set.get_global_attr_names());
+    assert_eq!(Some(&[2_u64][..]),                          data_set.get_global_attr_u64("version"));
+
+    // Same data set built with the imperative methods
+    let mut expected: DataSet = DataSet::new();
+    expected.set_unlimited_dim("time", 2).unwrap();
+    expected.add_fixed_dim("latitude", 3).unwrap();
+    expected.add_fixed_dim("longitude", 4).unwrap();
+    expected.add_var_f64("time", &["time"]).unwrap();
+    expected.add_var_attr_string("time", "units", "days since 1970-01-01").unwrap();
+    expected.add_var_f32("sst", &["time", "latitude", "longitude"]).unwrap();
+    expected.add_var_attr_string("sst", "units", "K").unwrap();
+    expected.add_var_attr_f32("sst", "_FillValue", vec![-999.0]).unwrap();
+    expected.add_var_attr_i16("sst", "valid_range", vec![-5, 40]).unwrap();
+    expected.add_var_i8("mask", &["latitude", "longitude"]).unwrap();
+    expected.add_global_attr_string("title", "Template").unwrap();
+    expected.add_global_attr_u64("version", vec![2]).unwrap();
+    expected.add_global_attr_string("history", "created").unwrap();
+    assert_eq!(expected,                                    data_set);
+}
+
+#[test]
+fn test_build_empty_data_set() {
+    assert_eq!(DataSet::new(),                              DataSetBuilder::new().build().unwrap());
+}
+
+#[test]
+fn test_build_collects_all_errors() {
+    let errors: Vec<InvalidDataSet> = DataSetBuilder::new()
+        .fixed_dim("latitude", 3)
+        .fixed_dim("latitude", 4)
+        .unlimited_dim("time", 0)
+        .unlimited_dim("record", 0)
+        .var("sst", &["time", "depth"], DataType::F32, |v| v.attr_str("units", "K"))
+        .var("latitude", &["latitude"], DataType::F32, |v| v.attr_str("units", "degrees_north").attr_str("units", "degrees"))
+        .global_attr_str("title", "Template")
+        .global_attr_str("title", "Template")
+        .build()
+        .unwrap_err();
+
+    assert_eq!(
+        vec![
+            InvalidDataSet::DimensionAlreadyExists(String::from("latitude")),
+            InvalidDataSet::UnlimitedDimensionAlreadyExists(String::from("time")),
+            InvalidDataSet::DimensionsNotDefined{var_name: String::from("sst"), undef_dim_names: vec![String::from("depth")]},
+            InvalidDataSet::VariableAttributeAlreadyExists{var_name: String::from("latitude"), attr_name: String::from("units")},
+            InvalidDataSet::GlobalAttributeAlreadyExists(String::from("title")),
+        ],
+        errors
+    );
+}
@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+
+use crate::{DataSet, DataType, DataVector, InvalidDataSet, NC_FILL_F32, NC_FILL_U8};
+use crate::error::CdlError;
+
+const CDL: &str = "netcdf test_file {
+dimensions:
+    time = UNLIMITED ; // (2 currently)
+    latitude = 2, longitude = 3 ;
+    name_len = 4 ;
+variables:
+    int time(time) ;
+        time:units = \"hours since 2000-01-01\" ;
+    float latitude(latitude) ;
+        latitude:valid_range = -90.f, 90.f ;
+    short temperature(time, latitude, longitude) ;
+        temperature:scale_factor = 0.5 ;
+        temperature:_FillValue = -1s ;
+    char station(latitude, name_len) ;
+    double scalar, other_scalar ;
+        double scalar:weights = 1, 2 ;
+    uint64 counter ;
+        counter:max = 18446744073709551615ull ;
+
+// global attributes:
+    :title = \"Test \\\"CDL\\\"\" ;
+    :history = \"line 1\\n\", \"line 2\" ;
+    byte :flags = 0x7f, -1 ;
+    :missing = NaNf ;
+data:
+
+ time = 1, 2 ;
+
+ latitude = 0.5, _ ;
+
+ temperature = 1, 2, 3, 4, 5 ;
+
+ station = \"ab\", \"abcd\" ;
+
+ scalar = -Infinity ;
+}
+";
+
+#[test]
+fn test_from_cdl() {
+    let data_set: DataSet = DataSet::from_cdl(CDL).unwrap();
+
+    assert_eq!(vec!["time", "latitude", "longitude", "name_len"], data_set.dim_names());
+    assert_eq!(Some(String::from("time")),              data_set.get_unlimited_dim().map(|dim| dim.name()));
+    // The number of records is given by the data
+    assert_eq!(Some(2),                                 data_set.num_records());
+    assert_eq!(Some(3),                                 data_set.dim_size("longitude"));
+
+    assert_eq!(
+        vec!["time", "latitude", "temperature", "station", "scalar", "other_scalar", "counter"],
+        data_set.get_var_names()
+    );
+    assert_eq!(Some(DataType::I32),                     data_set.var_data_type("time"));
+    assert_eq!(Some(DataType::U8),                      data_set.var_data_type("station"));
+    assert_eq!(Some(DataType::F64),                     data_set.var_data_type("other_scalar"));
+    assert_eq!(Some(DataType::U64),                     data_set.var_data_type("counter"));
+    assert_eq!(vec!["time", "latitude", "longitude"],   data_set.get_var("temperature").unwrap().dim_names());
+
+    assert_eq!(Some(String::from("hours since 2000-01-01")), data_set.get_var_attr_as_string("time", "units"));
+    assert_eq!(Some(&[-90.0_f32, 90.0][..]),            data_set.get_var_attr_f32("latitude", "valid_range"));
+    assert_eq!(Some(&[0.5_f64][..]),                    data_set.get_var_attr_f64("temperature", "scale_factor"));
+    assert_eq!(Some(&[-1_i16][..]),                     data_set.get_var_attr_i16("temperature", "_FillValue"));
+    assert_eq!(Some(&[1.0_f64, 2.0][..]),               data_set.get_var_attr_f64("scalar", "weights"));
+    assert_eq!(Some(&[std::u64::MAX][..]),              data_set.get_var_attr_u64("counter", "max"));
+
+    assert_eq!(vec!["title", "history", "flags", "missing"], data_set.get_global_attr_names());
+    assert_eq!(Some(String::from("Test \"CDL\"")),      data_set.get_global_attr_as_string("title"));
+    assert_eq!(Some(String::from("line 1\nline 2")),    data_set.get_global_attr_as_string("history"));
+    assert_eq!(Some(&[127_i8, -1][..]),                 data_set.get_global_attr_i8("flags"));
+    assert_eq!(true,                                    data_set.get_global_attr_f32("missing").unwrap()[0].is_nan());
+}
+
+#[test]
+fn test_from_cdl_with_data() {
+    let (data_set, vars_data): (DataSet, HashMap<String, DataVector>) = DataSet::from_cdl_with_data(CDL).unwrap();
+    assert_eq!(Some(2),                                 data_set.num_records());
+    assert_eq!(5,                                       vars_data.len());
+
+    assert_eq!(Some(&[1, 2][..]),                       vars_data["time"].get_i32());
+    assert_eq!(Some(&[0.5, NC_FILL_F32][..]),           vars_data["latitude"].get_f32());
+    // The missing values are filled up to the number of records
+    assert_eq!(Some(&[1, 2, 3, 4, 5, -32767, -32767, -32767, -32767, -32767, -32767, -32767][..]), vars_data["temperature"].get_i16());
+    assert_eq!(Some(&b"ab\0\0abcd"[..]),                vars_data["station"].get_u8());
+    assert_eq!(Some(&[std::f64::NEG_INFINITY][..]),     vars_data["scalar"].get_f64());
+    assert_eq!(false,                                   vars_data.contains_key("counter"));
+
+    // The strings of the variables depending only on the *unlimited-size* dimension are concatenated
+    let (data_set, vars_data): (DataSet, HashMap<String, DataVector>) = DataSet::from_cdl_with_data(
+        "netcdf chars { dimensions: n = unlimited ; variables: char text(n) ; data: text = \"ab\", \"c\", 100, _ ; }"
+    ).unwrap();
+    assert_eq!(Some(5),                                 data_set.num_records());
+    assert_eq!(Some(&[b'a', b'b', b'c', b'd', NC_FILL_U8][..]), vars_data["text"].get_u8());
+}
+
+#[test]
+fn test_from_cdl_errors() {
+    fn syntax_error_line(cdl: &str) -> usize {
+        match DataSet::from_cdl(cdl).unwrap_err() {
+            CdlError::Syntax{line, message: _} => line,
+            err => panic!("Unexpected error: {:?}", err),
+        }
+    }
+    assert_eq!(1,                                       syntax_error_line("netcdf"));
+    assert_eq!(1,                                       syntax_error_line("dataset test { }"));
+    assert_eq!(2,                                       syntax_error_line("netcdf test {\n dims:\n}"));
+    assert_eq!(3,                                       syntax_error_line("netcdf test {\ndimensions:\n  x = -1 ;\n}"));
+    assert_eq!(3,                                       syntax_error_line("netcdf test {\nvariables:\n  ubyte x ;\n}"));
+    // The missing semicolon is detected on the next token
+    assert_eq!(4,                                       syntax_error_line("netcdf test {\nvariables:\n  int x\n}"));
+    assert_eq!(3,                                       syntax_error_line("netcdf test {\nvariables:\n  :title = \"abc ;\n}"));
+    assert_eq!(3,                                       syntax_error_line("netcdf test {\nvariables:\n  :title = _ ;\n}"));
+    assert_eq!(3,                                       syntax_error_line("netcdf test {\nvariables:\n  short :v = 40000 ;\n}"));
+    assert_eq!(3,                                       syntax_error_line("netcdf test {\nvariables:\n  int :v = 1.5 ;\n}"));
+    assert_eq!(4,                                       syntax_error_line("netcdf test {\nvariables:\n  int x ;\ndata: y = 1 ;\n}"));
+    assert_eq!(4,                                       syntax_error_line("netcdf test {\nvariables:\n  int x ;\ndata: x = 1, 2 ;\n}"));
+    assert_eq!(4,                                       syntax_error_line("netcdf test {\nvariables:\n  int x ;\ndata: x = \"a\" ;\n}"));
+    assert_eq!(3,                                       syntax_error_line("netcdf test {\n}\n}"));
+
+    // Data set not valid
+    assert_eq!(
+        CdlError::DataSet(InvalidDataSet::DimensionsNotDefined{var_name: String::from("x"), undef_dim_names: vec![String::from("undef_dim")]}),
+        DataSet::from_cdl("netcdf test { variables: int x(undef_dim) ; }").unwrap_err()
+    );
+    assert_eq!(
+        CdlError::DataSet(InvalidDataSet::VariableNotDefined(String::from("undef_var"))),
+        DataSet::from_cdl("netcdf test { variables: undef_var:units = \"K\" ; }").unwrap_err()
+    );
+}
+
+#[test]
+fn test_to_cdl() {
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("name_len", 3).unwrap();
+    data_set.add_var_i8("flag", &["time"]).unwrap();
+    data_set.add_var_attr_i8("flag", "_FillValue", vec![-1]).unwrap();
+    data_set.add_var_u8("name", &["time", "name_len"]).unwrap();
+    data_set.add_var_f64("scalar", &[] as &[&str]).unwrap();
+    data_set.add_var_attr_f32("scalar", "range", vec![0.5, 1e20, std::f32::INFINITY]).unwrap();
+    data_set.add_var_attr_u64("scalar", "count", vec![1, 2]).unwrap();
+    data_set.add_var_u32("not_written", &["time"]).unwrap();
+    data_set.add_global_attr_string("comment", "Line 1\n\"Line 2\"").unwrap();
+
+    assert_eq!(
+        "netcdf test {
+dimensions:
+\ttime = UNLIMITED ; // (2 currently)
+\tname_len = 3 ;
+variables:
+\tbyte flag(time) ;
+\t\tflag:_FillValue = -1b ;
+\tchar name(time, name_len) ;
+\tdouble scalar ;
+\t\tscalar:range = 0.5f, 1e20f, Infinityf ;
+\t\tscalar:count = 1ull, 2ull ;
+\tuint not_written(time) ;
+
+// global attributes:
+\t\t:comment = \"Line 1\\n\\\"Line 2\\\"\" ;
+}
+",
+        data_set.to_cdl("test")
+    );
+
+    let mut vars_data: HashMap<String, DataVector> = HashMap::new();
+    vars_data.insert(String::from("flag"), DataVector::I8(vec![-1, 3]));
+    vars_data.insert(String::from("name"), DataVector::U8(b"abcd\0\0".to_vec()));
+    vars_data.insert(String::from("scalar"), DataVector::F64(vec![std::f64::NAN]));
+    // Number of values not valid
+    vars_data.insert(String::from("not_written"), DataVector::U32(vec![1]));
+    let cdl: String = data_set.to_cdl_with_data("test", &vars_data);
+    assert_eq!(
+        "data:\n\n flag = _, 3 ;\n\n name = \"abc\", \"d\" ;\n\n scalar = NaN ;\n}\n",
+        &cdl[cdl.find("data:").unwrap()..]
+    );
+}
+
+#[test]
+fn test_cdl_round_trip() {
+    let (data_set, vars_data): (DataSet, HashMap<String, DataVector>) = DataSet::from_cdl_with_data(CDL).unwrap();
+    let cdl: String = data_set.to_cdl_with_data("test_file", &vars_data);
+
+    let (new_data_set, new_vars_data): (DataSet, HashMap<String, DataVector>) = DataSet::from_cdl_with_data(&cdl).unwrap();
+    // The attribute `missing` is NaN
+    assert_eq!(cdl,                                     new_data_set.to_cdl_with_data("test_file", &new_vars_data));
+    assert_eq!(data_set.get_var_names(),                new_data_set.get_var_names());
+    assert_eq!(vars_data["temperature"],                new_vars_data["temperature"]);
+    assert_eq!(vars_data["station"],                    new_vars_data["station"]);
+    assert_eq!(vars_data["scalar"],                     new_vars_data["scalar"]);
+}
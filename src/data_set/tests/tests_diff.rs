@@ -0,0 +1,209 @@
+use crate::{DataSet, DataSetDiff, DimensionDiff, VariableDiff, AttributeDiff, DataType, DataVector, DimensionType};
+use crate::{compare_vars, DataDiff, Tolerance, Variable};
+
+const REFERENCE_CDL: &str = "netcdf reference {
+dimensions:
+    time = UNLIMITED ;
+    latitude = 3 ;
+    longitude = 4 ;
+    level = 2 ;
+variables:
+    float latitude(latitude) ;
+        latitude:units = \"degrees_north\" ;
+        latitude:valid_range = -90.f, 90.f ;
+        latitude:missing = NaNf ;
+    short temperature(time, latitude, longitude) ;
+        temperature:scale_factor = 0.5 ;
+    int removed_var(level) ;
+    :title = \"Reference\" ;
+    :version = 1 ;
+}";
+
+const OUTPUT_CDL: &str = "netcdf output {
+dimensions:
+    time = 2 ;
+    latitude = 3 ;
+    longitude = 5 ;
+    depth = 10 ;
+variables:
+    float latitude(latitude) ;
+        latitude:valid_range = -90.f, 90.f ;
+        latitude:missing = NaNf ;
+    double temperature(time, longitude) ;
+        temperature:scale_factor = 0.25 ;
+        temperature:add_offset = 273.15 ;
+    int added_var(depth) ;
+    :title = \"Output\" ;
+    :history = \"created\" ;
+}";
+
+#[test]
+fn test_diff() {
+    let reference: DataSet = DataSet::from_cdl(REFERENCE_CDL).unwrap();
+    let output: DataSet = DataSet::from_cdl(OUTPUT_CDL).unwrap();
+
+    let diff: DataSetDiff = reference.diff(&output);
+    assert_eq!(false,                                   diff.is_empty());
+    assert_eq!(vec!["depth"],                           diff.added_dims);
+    assert_eq!(vec!["level"],                           diff.removed_dims);
+    assert_eq!(
+        vec![
+            DimensionDiff{name: String::from("time"), old_type: DimensionType::UnlimitedSize, new_type: DimensionType::FixedSize, old_size: 0, new_size: 2},
+            DimensionDiff{name: String::from("longitude"), old_type: DimensionType::FixedSize, new_type: DimensionType::FixedSize, old_size: 4, new_size: 5},
+        ],
+        diff.changed_dims
+    );
+
+    assert_eq!(vec!["added_var"],                       diff.added_vars);
+    assert_eq!(vec!["removed_var"],                     diff.removed_vars);
+    // The NaN values are equal
+    assert_eq!(
+        vec![
+            VariableDiff{
+                name: String::from("latitude"),
+                data_type: None,
+                dim_names: None,
+                added_attrs: vec![],
+                removed_attrs: vec![String::from("units")],
+                changed_attrs: vec![],
+            },
+            VariableDiff{
+                name: String::from("temperature"),
+                data_type: Some((DataType::I16, DataType::F64)),
+                dim_names: Some((
+                    vec![String::from("time"), String::from("latitude"), String::from("longitude")],
+                    vec![String::from("time"), String::from("longitude")],
+                )),
+                added_attrs: vec![String::from("add_offset")],
+                removed_attrs: vec![],
+                changed_attrs: vec![AttributeDiff{name: String::from("scale_factor"), old_value: DataVector::F64(vec![0.5]), new_value: DataVector::F64(vec![0.25])}],
+            },
+        ],
+        diff.changed_vars
+    );
+
+    assert_eq!(vec!["history"],                         diff.added_global_attrs);
+    assert_eq!(vec!["version"],                         diff.removed_global_attrs);
+    assert_eq!(
+        vec![AttributeDiff{name: String::from("title"), old_value: DataVector::U8(b"Reference".to_vec()), new_value: DataVector::U8(b"Output".to_vec())}],
+        diff.changed_global_attrs
+    );
+
+    // Reversed comparison
+    let reversed_diff: DataSetDiff = output.diff(&reference);
+    assert_eq!(diff.added_dims,                         reversed_diff.removed_dims);
+    assert_eq!(diff.removed_vars,                       reversed_diff.added_vars);
+    assert_eq!(diff.added_global_attrs,                 reversed_diff.removed_global_attrs);
+}
+
+#[test]
+fn test_diff_identical_data_sets() {
+    let reference: DataSet = DataSet::from_cdl(REFERENCE_CDL).unwrap();
+    assert_eq!(DataSetDiff::default(),                  reference.diff(&reference));
+    assert_eq!(true,                                    reference.diff(&DataSet::from_cdl(REFERENCE_CDL).unwrap()).is_empty());
+    assert_eq!(true,                                    DataSet::new().diff(&DataSet::new()).is_empty());
+}
+
+#[test]
+fn test_equivalent() {
+    let mut data_set_1 = DataSet::new();
+    data_set_1.set_unlimited_dim("time", 2).unwrap();
+    data_set_1.add_fixed_dim("latitude", 3).unwrap();
+    data_set_1.add_var_f64("time", &["time"]).unwrap();
+    data_set_1.add_var_f32("sst", &["time", "latitude"]).unwrap();
+    data_set_1.add_var_attr_string("sst", "units", "K").unwrap();
+    data_set_1.add_var_attr_f32("sst", "_FillValue", vec![std::f32::NAN]).unwrap();
+
+    // Same definitions, in an other order
+    let mut data_set_2 = DataSet::new();
+    data_set_2.add_fixed_dim("latitude", 3).unwrap();
+    data_set_2.set_unlimited_dim("time", 2).unwrap();
+    data_set_2.add_var_f32("sst", &["time", "latitude"]).unwrap();
+    data_set_2.add_var_attr_f32("sst", "_FillValue", vec![std::f32::NAN]).unwrap();
+    data_set_2.add_var_attr_string("sst", "units", "K").unwrap();
+    data_set_2.add_var_f64("time", &["time"]).unwrap();
+
+    assert_eq!(true,                data_set_1.equivalent(&data_set_2));
+    assert_eq!(true,                data_set_2.equivalent(&data_set_1));
+    assert_eq!(true,                data_set_1.equivalent(&data_set_1));
+    assert_eq!(false,               data_set_1 == data_set_2);
+
+    // An attribute value is changed
+    data_set_2.set_var_attr_string("sst", "units", "degree_Celsius").unwrap();
+    assert_eq!(false,               data_set_1.equivalent(&data_set_2));
+    data_set_2.set_var_attr_string("sst", "units", "K").unwrap();
+
+    // A dimension size is changed
+    data_set_2.remove_var("sst").unwrap();
+    data_set_2.remove_dim("latitude").unwrap();
+    data_set_2.add_fixed_dim("latitude", 4).unwrap();
+    data_set_2.add_var_f32("sst", &["time", "latitude"]).unwrap();
+    data_set_2.add_var_attr_string("sst", "units", "K").unwrap();
+    data_set_2.add_var_attr_f32("sst", "_FillValue", vec![std::f32::NAN]).unwrap();
+    assert_eq!(false,               data_set_1.equivalent(&data_set_2));
+
+    // The order of the dimensions of a variable is compared
+    let mut data_set_3 = DataSet::new();
+    data_set_3.add_fixed_dim("x", 2).unwrap();
+    data_set_3.add_fixed_dim("y", 3).unwrap();
+    data_set_3.add_var_i32("z", &["x", "y"]).unwrap();
+    let mut data_set_4 = DataSet::new();
+    data_set_4.add_fixed_dim("x", 2).unwrap();
+    data_set_4.add_fixed_dim("y", 3).unwrap();
+    data_set_4.add_var_i32("z", &["y", "x"]).unwrap();
+    assert_eq!(false,               data_set_3.equivalent(&data_set_4));
+
+    // An empty data set
+    assert_eq!(true,                DataSet::new().equivalent(&DataSet::new()));
+    assert_eq!(false,               DataSet::new().equivalent(&data_set_3));
+}
+
+#[test]
+fn test_tolerance() {
+    assert_eq!(true,                Tolerance::default().is_equal(1.0, 1.0));
+    assert_eq!(false,               Tolerance::default().is_equal(1.0, 1.0 + std::f64::EPSILON));
+    assert_eq!(true,                Tolerance::relative(std::f64::EPSILON).is_equal(1.0, 1.0 + std::f64::EPSILON));
+    assert_eq!(false,               Tolerance::relative(std::f64::EPSILON).is_equal(0.0, std::f64::EPSILON));
+    assert_eq!(true,                Tolerance::absolute(0.1).is_equal(0.0, 0.1));
+    assert_eq!(false,               Tolerance::absolute(0.1).is_equal(0.0, 0.2));
+    assert_eq!(true,                Tolerance::absolute(0.1).is_equal(std::f64::NAN, std::f64::NAN));
+    assert_eq!(false,               Tolerance::absolute(0.1).is_equal(std::f64::NAN, 0.0));
+    assert_eq!(true,                Tolerance::default().is_equal(std::f64::INFINITY, std::f64::INFINITY));
+    assert_eq!(false,               Tolerance{absolute: 1.0, relative: 1.0}.is_equal(std::f64::INFINITY, std::f64::NEG_INFINITY));
+}
+
+#[test]
+fn test_compare_vars() {
+    let mut data_set = DataSet::new();
+    data_set.add_fixed_dim("x", 5).unwrap();
+    data_set.add_var_f32("a", &["x"]).unwrap();
+    data_set.add_var_attr_f32("a", "_FillValue", vec![-1.0]).unwrap();
+    data_set.add_var_f32("b", &["x"]).unwrap();
+    data_set.add_var_i16("c", &["x"]).unwrap();
+    let var_a: &Variable = data_set.get_var("a").unwrap();
+    let var_b: &Variable = data_set.get_var("b").unwrap();
+    let var_c: &Variable = data_set.get_var("c").unwrap();
+
+    // values differing in the last ULP, fill values of both variables
+    let last_ulp: f32 = f32::from_bits(1.5_f32.to_bits() + 1);
+    let data_a = DataVector::F32(vec![1.5, -1.0, -1.0, 3.0, std::f32::NAN]);
+    let data_b = DataVector::F32(vec![last_ulp, crate::NC_FILL_F32, 2.0, 3.0, std::f32::NAN]);
+    let diff: DataDiff = compare_vars((var_a, &data_a), (var_b, &data_b), Tolerance::relative(f64::from(std::f32::EPSILON)));
+    assert_eq!((5, 5),                              diff.len);
+    assert_eq!(vec![2],                             diff.indices);
+    assert_eq!(f64::from(last_ulp) - 1.5,           diff.max_abs_diff);
+    assert_eq!(false,                               diff.is_empty());
+
+    let diff: DataDiff = compare_vars((var_a, &data_a), (var_b, &data_b), Tolerance::default());
+    assert_eq!(vec![0, 2],                          diff.indices);
+
+    // different data types and lengths, the default fill value of the `i16` variable
+    let data_c = DataVector::I16(vec![2, crate::NC_FILL_I16, 3]);
+    let diff: DataDiff = compare_vars((var_a, &data_a), (var_c, &data_c), Tolerance::absolute(0.5));
+    assert_eq!((5, 3),                              diff.len);
+    assert_eq!(vec![2],                             diff.indices);
+    assert_eq!(0.5,                                 diff.max_abs_diff);
+    assert_eq!(false,                               diff.is_empty());
+
+    assert_eq!(true,                                compare_vars((var_a, &data_a), (var_a, &data_a), Tolerance::default()).is_empty());
+}
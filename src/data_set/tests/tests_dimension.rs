@@ -1,7 +1,8 @@
 #![cfg(test)]
 use std::rc::Rc;
 
-use crate::{DataSet, Dimension, DimensionType, InvalidDataSet};
+use crate::{DataSet, Dimension, DimensionType, InvalidDataSet, Limits, Variable, NC_MAX_DIM_SIZE, NC_MAX_VAR_DIMS, NC_MAX_NAME_SIZE};
+use crate::data_set::DimensionSize;
 
 #[test]
 fn test_add_fixed_size_dims() {
@@ -755,4 +756,261 @@ fn test_rc_dim_equality() {
     assert!(Rc::ptr_eq(&dim_a_1, &dim_a_2));
     assert!(Rc::ptr_eq(&dim_b_1, &dim_b_2));
     assert!(!Rc::ptr_eq(&dim_a_1, &dim_b_2));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_clone_data_set() {
+    const FIXED_DIM_NAME: &str = "latitude";
+    const UNLIM_DIM_NAME: &str = "time";
+    const VAR_NAME: &str = "sst";
+
+    let mut data_set_a = DataSet::new();
+    data_set_a.set_unlimited_dim(UNLIM_DIM_NAME, 2).unwrap();
+    data_set_a.add_fixed_dim(FIXED_DIM_NAME, 3).unwrap();
+    data_set_a.add_var_f32(VAR_NAME, &[UNLIM_DIM_NAME, FIXED_DIM_NAME]).unwrap();
+    data_set_a.add_var_attr_string(VAR_NAME, "units", "K").unwrap();
+    data_set_a.add_global_attr_string("title", "Template").unwrap();
+
+    let mut data_set_b: DataSet = data_set_a.clone();
+    assert_eq!(data_set_a,                          data_set_b);
+
+    // The dimensions are not shared between the 2 data sets
+    let dim_a: Rc<Dimension> = data_set_a.get_dim(FIXED_DIM_NAME).unwrap();
+    let dim_b: Rc<Dimension> = data_set_b.get_dim(FIXED_DIM_NAME).unwrap();
+    assert!(!Rc::ptr_eq(&dim_a, &dim_b));
+
+    // But they are shared within the copy
+    {
+        let var_b: &Variable = data_set_b.get_var(VAR_NAME).unwrap();
+        assert!(Rc::ptr_eq(&data_set_b.get_unlimited_dim().unwrap(),    var_b.unlimited_dim.as_ref().unwrap()));
+        assert!(Rc::ptr_eq(&data_set_b.get_unlimited_dim().unwrap(),    &var_b.dims[0]));
+        assert!(Rc::ptr_eq(&dim_b,                                      &var_b.dims[1]));
+    }
+
+    // Modify the copy
+    data_set_b.rename_dim(FIXED_DIM_NAME, "lat").unwrap();
+    if let DimensionSize::Unlimited(num_records) = &data_set_b.get_unlimited_dim().unwrap().size {
+        num_records.replace(5);
+    }
+
+    assert_eq!(vec!["lat"],                         data_set_b.get_var(VAR_NAME).unwrap().dim_names()[1..].to_vec());
+    assert_eq!(Some(5),                             data_set_b.dim_size(UNLIM_DIM_NAME));
+
+    assert_eq!(vec![UNLIM_DIM_NAME, FIXED_DIM_NAME], data_set_a.get_var(VAR_NAME).unwrap().dim_names());
+    assert_eq!(Some(2),                             data_set_a.dim_size(UNLIM_DIM_NAME));
+    assert_eq!(true,                                data_set_a.has_dim(FIXED_DIM_NAME));
+}
+
+
+#[test]
+fn test_dims_iterator() {
+    let mut data_set = DataSet::new();
+    assert_eq!(0,                                   data_set.dims().len());
+
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_var_f32("sst", &["time", "latitude"]).unwrap();
+
+    let dim_names: Vec<String> = data_set.dims().map(|dim: &Rc<Dimension>| dim.name()).collect();
+    assert_eq!(data_set.dim_names(),                dim_names);
+    assert!(data_set.dims().zip(data_set.get_dims().iter()).all(|(a, b): (&Rc<Dimension>, &Rc<Dimension>)| Rc::ptr_eq(a, b)));
+
+    let var: &Variable = data_set.get_var("sst").unwrap();
+    let sizes: Vec<usize> = var.dims().map(|dim: &Rc<Dimension>| dim.size()).collect();
+    assert_eq!(vec![2, 3],                          sizes);
+}
+
+
+#[test]
+fn test_dim_index() {
+    let mut data_set = DataSet::new();
+    data_set.add_fixed_dim("dim_1", 1).unwrap();
+    data_set.set_unlimited_dim("dim_2", 2).unwrap();
+    data_set.add_fixed_dim("dim_3", 3).unwrap();
+
+    assert_eq!(Some(0),                             data_set.dim_index("dim_1"));
+    assert_eq!(Some(1),                             data_set.dim_index("dim_2"));
+    assert_eq!(Some(2),                             data_set.dim_index("dim_3"));
+    assert_eq!(None,                                data_set.dim_index("undef_dim"));
+
+    for dim_id in 0..3 {
+        let dim: Rc<Dimension> = data_set.get_dim_by_index(dim_id).unwrap();
+        assert_eq!(Some(dim_id),                    data_set.dim_index(&dim.name()));
+        assert!(Rc::ptr_eq(&data_set.get_dim(&dim.name()).unwrap(), &dim));
+    }
+    assert_eq!(None,                                data_set.get_dim_by_index(3));
+
+    // The positions are shifted after a removal
+    data_set.remove_dim("dim_1").unwrap();
+    assert_eq!(Some(0),                             data_set.dim_index("dim_2"));
+    assert_eq!(Some(1),                             data_set.dim_index("dim_3"));
+    assert_eq!(Some(String::from("dim_3")),         data_set.get_dim_by_index(1).map(|dim: Rc<Dimension>| dim.name()));
+}
+
+
+#[test]
+fn test_remove_unused_dims() {
+    let mut data_set = DataSet::new();
+    assert_eq!(0,                                   data_set.remove_unused_dims().len());
+
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_fixed_dim("longitude", 4).unwrap();
+    data_set.add_fixed_dim("depth", 5).unwrap();
+    data_set.add_var_f32("sst", &["time", "latitude"]).unwrap();
+    data_set.add_var_f32("depth", &["depth"]).unwrap();
+
+    let removed_dims: Vec<Rc<Dimension>> = data_set.remove_unused_dims();
+    assert_eq!(1,                                   removed_dims.len());
+    assert_eq!("longitude",                         removed_dims[0].name());
+    assert_eq!(vec!["time", "latitude", "depth"],   data_set.dim_names());
+    assert_eq!(true,                                data_set.has_unlimited_dim());
+
+    // Nothing else to remove
+    assert_eq!(0,                                   data_set.remove_unused_dims().len());
+
+    data_set.remove_var("sst").unwrap();
+    let removed_dims: Vec<Rc<Dimension>> = data_set.remove_unused_dims();
+    assert_eq!(2,                                   removed_dims.len());
+    assert_eq!(true,                                removed_dims[0].is_unlimited());
+    assert_eq!(vec!["depth"],                       data_set.dim_names());
+    assert_eq!(false,                               data_set.has_unlimited_dim());
+    assert_eq!(None,                                data_set.num_records());
+}
+
+#[test]
+fn test_remove_var_and_unused_dims() {
+    let mut data_set = DataSet::new();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_fixed_dim("longitude", 4).unwrap();
+    data_set.add_fixed_dim("depth", 5).unwrap();
+    data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    data_set.add_var_f32("sst", &["time", "latitude", "longitude"]).unwrap();
+
+    let removed_var: Variable = data_set.remove_var_and_unused_dims("sst").unwrap();
+    assert_eq!("sst",                               removed_var.name());
+    // `latitude` is still used, and `depth` was already unused
+    assert_eq!(vec!["latitude", "depth"],           data_set.dim_names());
+    assert_eq!(false,                               data_set.has_unlimited_dim());
+
+    assert_eq!(
+        InvalidDataSet::VariableNotDefined(String::from("sst")),
+        data_set.remove_var_and_unused_dims("sst").unwrap_err()
+    );
+    assert_eq!(vec!["latitude", "depth"],           data_set.dim_names());
+}
+
+#[test]
+fn test_limits() {
+    assert_eq!(Limits{max_dim_size: NC_MAX_DIM_SIZE, max_var_dims: NC_MAX_VAR_DIMS, max_name_size: NC_MAX_NAME_SIZE},   *DataSet::new().limits());
+
+    let limits = Limits{max_dim_size: 10, max_var_dims: 2, max_name_size: 5};
+    let mut data_set = DataSet::with_limits(limits.clone());
+    assert_eq!(&limits,                         data_set.limits());
+
+    // Dimensions
+    data_set.add_fixed_dim("x", 10).unwrap();
+    data_set.add_fixed_dim("y", 10).unwrap();
+    data_set.add_fixed_dim("z", 10).unwrap();
+    assert_eq!(
+        InvalidDataSet::MaximumFixedDimensionSizeExceeded{dim_name: String::from("w"), get: 11},
+        data_set.add_fixed_dim("w", 11).unwrap_err()
+    );
+    assert_eq!(
+        InvalidDataSet::DimensionNameNotValid(String::from("record")),
+        data_set.set_unlimited_dim("record", 100).unwrap_err()
+    );
+    data_set.set_unlimited_dim("time", 100).unwrap();
+    assert_eq!(
+        InvalidDataSet::DimensionNameNotValid(String::from("x_axis")),
+        data_set.rename_dim("x", "x_axis").unwrap_err()
+    );
+
+    // Variables
+    data_set.add_var_f32("v", &["x", "y"]).unwrap();
+    assert_eq!(
+        InvalidDataSet::MaximumDimensionsPerVariableExceeded{var_name: String::from("w"), num_dims: 3},
+        data_set.add_var_f32("w", &["x", "y", "z"]).unwrap_err()
+    );
+    assert_eq!(
+        InvalidDataSet::VariableNameNotValid(String::from("values")),
+        data_set.add_var_f32("values", &["x"]).unwrap_err()
+    );
+    assert_eq!(
+        InvalidDataSet::VariableNameNotValid(String::from("values")),
+        data_set.rename_var("v", "values").unwrap_err()
+    );
+
+    // Attributes
+    assert_eq!(
+        InvalidDataSet::VariableAttributeNameNotValid{var_name: String::from("v"), attr_name: String::from("long_name")},
+        data_set.add_var_attr_string("v", "long_name", "V").unwrap_err()
+    );
+    assert_eq!(
+        InvalidDataSet::VariableAttributeNameNotValid{var_name: String::from("v"), attr_name: String::from("valid_min")},
+        data_set.set_var_attr_f32("v", "valid_min", vec![0.0]).unwrap_err()
+    );
+    data_set.add_var_attr_string("v", "units", "m").unwrap();
+    assert_eq!(
+        InvalidDataSet::VariableAttributeNameNotValid{var_name: String::from("v"), attr_name: String::from("unit_name")},
+        data_set.rename_var_attr("v", "units", "unit_name").unwrap_err()
+    );
+    assert_eq!(
+        InvalidDataSet::GlobalAttributeNameNotValid(String::from("history")),
+        data_set.add_global_attr_string("history", "").unwrap_err()
+    );
+    data_set.add_global_attr_string("title", "").unwrap();
+    assert_eq!(
+        InvalidDataSet::GlobalAttributeNameNotValid(String::from("titles")),
+        data_set.rename_global_attr("title", "titles").unwrap_err()
+    );
+
+    assert_eq!(vec!["x", "y", "z", "time"],     data_set.dim_names());
+    assert_eq!(vec!["v"],                       data_set.get_var_names());
+
+    // The limits are copied
+    assert_eq!(&limits,                         data_set.clone().limits());
+}
+
+#[test]
+fn test_resize_fixed_dim() {
+    let mut data_set = DataSet::new();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_fixed_dim("longitude", 4).unwrap();
+    data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    data_set.add_var_f32("sst", &["time", "latitude", "longitude"]).unwrap();
+
+    data_set.resize_fixed_dim("latitude", 5).unwrap();
+    assert_eq!(vec!["time", "latitude", "longitude"],   data_set.dim_names());
+    assert_eq!(Some(5),                                 data_set.dim_size("latitude"));
+    assert_eq!(Some(DimensionType::FixedSize),          data_set.dim_type("latitude"));
+    assert_eq!(Some(5),                                 data_set.var_len("latitude"));
+    assert_eq!(Some(40),                                data_set.var_len("sst"));
+    assert_eq!(vec![2, 5, 4],                           data_set.get_var("sst").unwrap().shape());
+    // The variables share the new dimension
+    let new_dim: Rc<Dimension> = data_set.get_dim("latitude").unwrap();
+    assert_eq!(true,                                    data_set.vars().all(|var: &Variable| var.dims().any(|dim: &Rc<Dimension>| Rc::ptr_eq(dim, &new_dim))));
+
+    // The dimension is not defined
+    assert_eq!(
+        InvalidDataSet::DimensionNotDefined(String::from("depth")),
+        data_set.resize_fixed_dim("depth", 2).unwrap_err()
+    );
+    // The unlimited-size dimension is not resized
+    assert_eq!(
+        InvalidDataSet::DimensionNotFixedSize(String::from("time")),
+        data_set.resize_fixed_dim("time", 3).unwrap_err()
+    );
+    // The new size is not valid
+    assert_eq!(
+        InvalidDataSet::FixedDimensionWithZeroSize(String::from("latitude")),
+        data_set.resize_fixed_dim("latitude", 0).unwrap_err()
+    );
+    assert_eq!(
+        InvalidDataSet::MaximumFixedDimensionSizeExceeded{dim_name: String::from("latitude"), get: NC_MAX_DIM_SIZE + 1},
+        data_set.resize_fixed_dim("latitude", NC_MAX_DIM_SIZE + 1).unwrap_err()
+    );
+    assert_eq!(Some(5),                                 data_set.dim_size("latitude"));
+}
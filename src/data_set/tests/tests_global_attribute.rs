@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 #![cfg(test)]
-use crate::{DataSet, DataType, InvalidDataSet};
+use crate::{Attribute, DataSet, DataType, DateTime, InvalidDataSet};
 
 #[test]
 fn test_add_global_attr_i8() {
@@ -474,4 +474,150 @@ fn test_remove_var_attr_error_attr_not_defined () {
     assert_eq!(None,    data_set.get_global_attr_data_type(UNDEF_GLOBAL_ATTR_NAME));
     assert_eq!(None,    data_set.get_global_attr_i8(UNDEF_GLOBAL_ATTR_NAME));
     assert_eq!(None,    data_set.get_global_attr_i8(UNDEF_GLOBAL_ATTR_NAME));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_set_global_attr() {
+    const GLOBAL_ATTR_NAME_1: &str = "attr_1";
+    const GLOBAL_ATTR_NAME_2: &str = "attr_2";
+
+    let mut data_set = DataSet::new();
+    data_set.add_global_attr_i16(GLOBAL_ATTR_NAME_1, vec![1, 2]).unwrap();
+    data_set.add_global_attr_string(GLOBAL_ATTR_NAME_2, "foo").unwrap();
+
+    // Overwrite the value and the data type
+    data_set.set_global_attr_u32(GLOBAL_ATTR_NAME_1, vec![3, 4, 5]).unwrap();
+    assert_eq!(2,                               data_set.num_global_attrs());
+    assert_eq!(Some(DataType::U32),             data_set.get_global_attr_data_type(GLOBAL_ATTR_NAME_1));
+    assert_eq!(Some(&[3_u32, 4, 5][..]),        data_set.get_global_attr_u32(GLOBAL_ATTR_NAME_1));
+    assert_eq!(None,                            data_set.get_global_attr_i16(GLOBAL_ATTR_NAME_1));
+
+    // Overwrite the value
+    data_set.set_global_attr_string(GLOBAL_ATTR_NAME_2, "bar").unwrap();
+    assert_eq!(Some(String::from("bar")),       data_set.get_global_attr_as_string(GLOBAL_ATTR_NAME_2));
+    // The order of the attributes is kept
+    assert_eq!(vec![GLOBAL_ATTR_NAME_1, GLOBAL_ATTR_NAME_2], data_set.get_global_attr_names());
+
+    // Insert a new attribute
+    data_set.set_global_attr_f32("attr_3", vec![6.0]).unwrap();
+    assert_eq!(3,                               data_set.num_global_attrs());
+    assert_eq!(Some(&[6.0_f32][..]),            data_set.get_global_attr_f32("attr_3"));
+
+    // Error
+    assert_eq!(
+        InvalidDataSet::GlobalAttributeNameNotValid(String::from("!attr")),
+        data_set.set_global_attr_i8("!attr", vec![1]).unwrap_err()
+    );
+    assert_eq!(3,                               data_set.num_global_attrs());
+}
+
+
+#[test]
+fn test_add_global_attr_generic() {
+    let mut data_set = DataSet::new();
+    data_set.add_global_attr("attr_i8", vec![1_i8]).unwrap();
+    data_set.add_global_attr("attr_u8", vec![2_u8]).unwrap();
+    data_set.add_global_attr("attr_i16", vec![3_i16]).unwrap();
+    data_set.add_global_attr("attr_i32", vec![4_i32]).unwrap();
+    data_set.add_global_attr("attr_f32", vec![5.0_f32]).unwrap();
+    data_set.add_global_attr("attr_f64", vec![6.0_f64]).unwrap();
+    data_set.add_global_attr("attr_u16", vec![7_u16]).unwrap();
+    data_set.add_global_attr("attr_u32", vec![8_u32]).unwrap();
+    data_set.add_global_attr("attr_i64", vec![9_i64]).unwrap();
+    data_set.add_global_attr("attr_u64", vec![10_u64]).unwrap();
+
+    assert_eq!(10,                              data_set.num_global_attrs());
+    assert_eq!(Some(&[1_i8][..]),               data_set.get_global_attr_i8("attr_i8"));
+    assert_eq!(Some(&[2_u8][..]),               data_set.get_global_attr_u8("attr_u8"));
+    assert_eq!(Some(&[3_i16][..]),              data_set.get_global_attr_i16("attr_i16"));
+    assert_eq!(Some(&[4_i32][..]),              data_set.get_global_attr_i32("attr_i32"));
+    assert_eq!(Some(&[5.0_f32][..]),            data_set.get_global_attr_f32("attr_f32"));
+    assert_eq!(Some(&[6.0_f64][..]),            data_set.get_global_attr_f64("attr_f64"));
+    assert_eq!(Some(&[7_u16][..]),              data_set.get_global_attr_u16("attr_u16"));
+    assert_eq!(Some(&[8_u32][..]),              data_set.get_global_attr_u32("attr_u32"));
+    assert_eq!(Some(&[9_i64][..]),              data_set.get_global_attr_i64("attr_i64"));
+    assert_eq!(Some(&[10_u64][..]),             data_set.get_global_attr_u64("attr_u64"));
+
+    assert_eq!(
+        InvalidDataSet::GlobalAttributeAlreadyExists(String::from("attr_i8")),
+        data_set.add_global_attr("attr_i8", vec![1_i32]).unwrap_err()
+    );
+
+    data_set.set_global_attr("attr_i8", vec![1_i32]).unwrap();
+    assert_eq!(Some(DataType::I32),             data_set.get_global_attr_data_type("attr_i8"));
+}
+
+
+#[test]
+fn test_get_global_attr_scalar() {
+    let mut data_set = DataSet::new();
+    data_set.add_global_attr_i32("version", vec![2]).unwrap();
+    data_set.add_global_attr_f64("range", vec![0.0, 1.0]).unwrap();
+    data_set.add_global_attr_u8("empty", vec![]).unwrap();
+
+    assert_eq!(Ok(2_i32),                       data_set.get_global_attr_scalar("version"));
+    assert_eq!(
+        Err(InvalidDataSet::GlobalAttributeMismatchDataType{attr_name: String::from("version"), req: DataType::F32, get: DataType::I32}),
+        data_set.get_global_attr_scalar::<f32>("version")
+    );
+    assert_eq!(
+        Err(InvalidDataSet::GlobalAttributeNotScalar{attr_name: String::from("range"), len: 2}),
+        data_set.get_global_attr_scalar::<f64>("range")
+    );
+    assert_eq!(
+        Err(InvalidDataSet::GlobalAttributeNotScalar{attr_name: String::from("empty"), len: 0}),
+        data_set.get_global_attr_scalar::<u8>("empty")
+    );
+    assert_eq!(
+        Err(InvalidDataSet::GlobalAttributeNotDefined(String::from("undef_attr"))),
+        data_set.get_global_attr_scalar::<i32>("undef_attr")
+    );
+}
+
+
+#[test]
+fn test_global_attrs_iterator() {
+    let mut data_set = DataSet::new();
+    assert_eq!(0,                               data_set.attrs().len());
+
+    data_set.add_global_attr_string("title", "Template").unwrap();
+    data_set.add_global_attr_i32("version", vec![2]).unwrap();
+
+    let attr_names: Vec<&str> = data_set.attrs().map(|attr: &Attribute| attr.name()).collect();
+    assert_eq!(vec!["title", "version"],        attr_names);
+    assert_eq!(data_set.get_global_attrs(),     data_set.attrs().collect::<Vec<&Attribute>>());
+}
+
+#[test]
+fn test_append_history() {
+    let mut data_set = DataSet::new();
+    data_set.append_history_at("created", &DateTime::parse("2020-01-01").unwrap()).unwrap();
+    assert_eq!(Some(String::from("2020-01-01T00:00:00Z: created")),     data_set.get_global_attr_as_string("history"));
+
+    data_set.append_history_at("ncks -v sst in.nc out.nc", &DateTime::parse("2020-01-02 06:00:00").unwrap()).unwrap();
+    assert_eq!(
+        Some(String::from("2020-01-02T06:00:00Z: ncks -v sst in.nc out.nc\n2020-01-01T00:00:00Z: created")),
+        data_set.get_global_attr_as_string("history")
+    );
+
+    data_set.append_history("subset").unwrap();
+    let history: String = data_set.get_global_attr_as_string("history").unwrap();
+    assert_eq!(3,                                                       history.lines().count());
+    assert_eq!(true,                                                    history.lines().next().unwrap().ends_with("Z: subset"));
+    assert_eq!(1,                                                       data_set.num_global_attrs());
+
+    // The previous history is empty
+    let mut data_set = DataSet::new();
+    data_set.add_global_attr_string("history", "").unwrap();
+    data_set.append_history_at("created", &DateTime::parse("2020-01-01").unwrap()).unwrap();
+    assert_eq!(Some(String::from("2020-01-01T00:00:00Z: created")),     data_set.get_global_attr_as_string("history"));
+
+    // The history is not a text
+    let mut data_set = DataSet::new();
+    data_set.add_global_attr_i32("history", vec![1]).unwrap();
+    assert_eq!(
+        InvalidDataSet::GlobalAttributeMismatchDataType{attr_name: String::from("history"), req: DataType::U8, get: DataType::I32},
+        data_set.append_history("created").unwrap_err()
+    );
+    assert_eq!(Some(&[1_i32][..]),                                      data_set.get_global_attr_i32("history"));
+}
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use crate::{DataSet, DataType, DataVector, InMemoryDataSet, InvalidDataSet};
+
+fn init_data_set() -> DataSet {
+    let mut data_set = DataSet::new();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_var_f64("time", &["time"]).unwrap();
+    data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    data_set.add_var_i16("sst", &["time", "latitude"]).unwrap();
+    data_set.add_var_u8("flag", &[] as &[&str]).unwrap();
+    return data_set;
+}
+
+#[test]
+fn test_set_and_get_var_data() {
+    let mut in_memory = InMemoryDataSet::new(init_data_set());
+    assert_eq!(&init_data_set(),                                in_memory.data_set());
+    assert_eq!(vec!["time", "latitude", "sst", "flag"],         in_memory.vars_without_data());
+
+    assert_eq!(Ok(None),                                        in_memory.set_var("sst", vec![1_i16, 2, 3, 4, 5, 6]));
+    assert_eq!(Ok(None),                                        in_memory.set_var_data("flag", DataVector::U8(vec![1])));
+    assert_eq!(
+        Ok(Some(DataVector::U8(vec![1]))),
+        in_memory.set_var("flag", vec![0_u8])
+    );
+    assert_eq!(true,                                            in_memory.has_var_data("sst"));
+    assert_eq!(false,                                           in_memory.has_var_data("time"));
+    assert_eq!(vec!["time", "latitude"],                        in_memory.vars_without_data());
+    assert_eq!(Some(&[1_i16, 2, 3, 4, 5, 6][..]),               in_memory.get_var::<i16>("sst"));
+    assert_eq!(None,                                            in_memory.get_var::<i32>("sst"));
+    assert_eq!(None,                                            in_memory.get_var::<f64>("time"));
+    assert_eq!(Some(&DataVector::U8(vec![0])),                  in_memory.get_var_data("flag"));
+
+    // Errors
+    assert_eq!(
+        InvalidDataSet::VariableNotDefined(String::from("undef_var")),
+        in_memory.set_var("undef_var", vec![0_u8]).unwrap_err()
+    );
+    assert_eq!(
+        InvalidDataSet::VariableMismatchDataType{var_name: String::from("time"), req: DataType::F64, get: DataType::F32},
+        in_memory.set_var("time", vec![0.0_f32, 1.0]).unwrap_err()
+    );
+    assert_eq!(
+        InvalidDataSet::VariableMismatchDataLength{var_name: String::from("sst"), req: 6, get: 3},
+        in_memory.set_var("sst", vec![1_i16, 2, 3]).unwrap_err()
+    );
+    assert_eq!(Some(&[1_i16, 2, 3, 4, 5, 6][..]),               in_memory.get_var::<i16>("sst"));
+
+    assert_eq!(Some(DataVector::U8(vec![0])),                   in_memory.remove_var_data("flag"));
+    assert_eq!(None,                                            in_memory.remove_var_data("flag"));
+
+    let (data_set, data): (DataSet, HashMap<String, DataVector>) = in_memory.into_parts();
+    assert_eq!(init_data_set(),                                 data_set);
+    assert_eq!(1,                                               data.len());
+}
+
+#[test]
+fn test_from_parts() {
+    let mut data: HashMap<String, DataVector> = HashMap::new();
+    data.insert(String::from("time"), DataVector::F64(vec![0.0, 1.0]));
+    data.insert(String::from("latitude"), DataVector::F32(vec![0.0, 0.5, 1.0]));
+    let in_memory: InMemoryDataSet = InMemoryDataSet::from_parts(init_data_set(), data.clone()).unwrap();
+    assert_eq!(vec!["sst", "flag"],                             in_memory.vars_without_data());
+
+    data.insert(String::from("flag"), DataVector::U8(vec![0, 1]));
+    assert_eq!(
+        InvalidDataSet::VariableMismatchDataLength{var_name: String::from("flag"), req: 1, get: 2},
+        InMemoryDataSet::from_parts(init_data_set(), data.clone()).unwrap_err()
+    );
+}
+
+#[test]
+fn test_resize_fixed_dim() {
+    let mut in_memory = InMemoryDataSet::new(init_data_set());
+    in_memory.set_var("latitude", vec![0.0_f32, 0.5, 1.0]).unwrap();
+    in_memory.set_var("sst", vec![1_i16, 2, 3, 4, 5, 6]).unwrap();
+
+    // The data would not match their variable anymore
+    assert_eq!(
+        InvalidDataSet::DimensionYetUsed{var_names: vec![String::from("latitude"), String::from("sst")], dim_name: String::from("latitude")},
+        in_memory.resize_fixed_dim("latitude", 4).unwrap_err()
+    );
+    assert_eq!(Some(3),                                 in_memory.data_set().dim_size("latitude"));
+
+    in_memory.remove_var_data("latitude").unwrap();
+    in_memory.remove_var_data("sst").unwrap();
+    in_memory.resize_fixed_dim("latitude", 4).unwrap();
+    assert_eq!(Some(4),                                 in_memory.data_set().dim_size("latitude"));
+    assert_eq!(Some(8),                                 in_memory.data_set().var_len("sst"));
+    in_memory.set_var("sst", vec![1_i16, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::{DataSet, DataVector};
+
+#[test]
+fn test_to_json() {
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("latitude", 2).unwrap();
+    data_set.add_fixed_dim("longitude", 3).unwrap();
+    data_set.add_fixed_dim("name_len", 4).unwrap();
+    data_set.add_var_i32("time", &["time"]).unwrap();
+    data_set.add_var_attr_string("time", "units", "hours since 2000-01-01").unwrap();
+    data_set.add_var_i16("temperature", &["time", "latitude", "longitude"]).unwrap();
+    data_set.add_var_attr_f64("temperature", "scale_factor", vec![0.5]).unwrap();
+    data_set.add_var_attr_i16("temperature", "_FillValue", vec![-1]).unwrap();
+    data_set.add_var_u8("station", &["latitude", "name_len"]).unwrap();
+    data_set.add_var_f64("scalar", &[] as &[&str]).unwrap();
+    data_set.add_var_f32("not_written", &["latitude"]).unwrap();
+    data_set.add_global_attr_string("title", "Test \"JSON\"\n").unwrap();
+    data_set.add_global_attr_f32("missing", vec![std::f32::NAN]).unwrap();
+
+    let mut vars_data: HashMap<String, DataVector> = HashMap::new();
+    vars_data.insert(String::from("time"), DataVector::I32(vec![1, 2]));
+    vars_data.insert(String::from("temperature"), DataVector::I16(vec![1, 2, 3, 4, 5, -1, 7, 8, 9, 10, 11, 12]));
+    vars_data.insert(String::from("station"), DataVector::U8(b"ab\0\0abcd".to_vec()));
+    vars_data.insert(String::from("scalar"), DataVector::F64(vec![std::f64::NEG_INFINITY]));
+    // Number of values not valid
+    vars_data.insert(String::from("not_written"), DataVector::F32(vec![1.0]));
+
+    let json: Value = serde_json::from_str(&data_set.to_json_with_data(&vars_data)).unwrap();
+    assert_eq!(
+        json!({
+            "dimensions": {"time": 2, "latitude": 2, "longitude": 3, "name_len": 4},
+            "variables": {
+                "time": {
+                    "shape": ["time"],
+                    "type": "int",
+                    "attributes": {"units": "hours since 2000-01-01"},
+                    "data": [1, 2],
+                },
+                "temperature": {
+                    "shape": ["time", "latitude", "longitude"],
+                    "type": "short",
+                    "attributes": {"scale_factor": 0.5, "_FillValue": -1},
+                    "data": [[[1, 2, 3], [4, 5, null]], [[7, 8, 9], [10, 11, 12]]],
+                },
+                "station": {
+                    "shape": ["latitude", "name_len"],
+                    "type": "char",
+                    "data": ["ab", "abcd"],
+                },
+                "scalar": {
+                    "type": "double",
+                    "data": null,
+                },
+                "not_written": {
+                    "shape": ["latitude"],
+                    "type": "float",
+                },
+            },
+            "attributes": {"title": "Test \"JSON\"\n", "missing": null},
+        }),
+        json
+    );
+    // Without data
+    let json: Value = serde_json::from_str(&data_set.to_json()).unwrap();
+    assert_eq!(None,                                json["variables"]["time"].get("data"));
+    assert_eq!(json!(["latitude", "name_len"]),     json["variables"]["station"]["shape"]);
+
+    // Empty data set
+    assert_eq!("{}\n",                              DataSet::new().to_json());
+}
@@ -0,0 +1,180 @@
+use crate::{DataSet, DataType, DataVector, InvalidDataSet, MergePolicy, MergeReport};
+
+const DATA_SET_CDL: &str = "netcdf data_set {
+dimensions:
+    time = UNLIMITED ;
+    latitude = 3 ;
+    longitude = 4 ;
+variables:
+    float latitude(latitude) ;
+        latitude:units = \"degrees_north\" ;
+    short temperature(time, latitude, longitude) ;
+        temperature:scale_factor = 0.5 ;
+    :title = \"Ocean\" ;
+    :version = 1 ;
+}";
+
+const OTHER_CDL: &str = "netcdf other {
+dimensions:
+    time = UNLIMITED ;
+    latitude = 3 ;
+    longitude = 5 ;
+    depth = 2 ;
+variables:
+    float latitude(latitude) ;
+        latitude:units = \"degrees_north\" ;
+        latitude:axis = \"Y\" ;
+    double temperature(time, latitude) ;
+    float longitude(longitude) ;
+    float depth(depth) ;
+        depth:positive = \"down\" ;
+    short salinity(time, latitude) ;
+        salinity:scale_factor = 0.1f ;
+    :title = \"Atmosphere\" ;
+    :history = \"merged\" ;
+}";
+
+#[test]
+fn test_merge_without_conflict() {
+    let mut data_set: DataSet = DataSet::from_cdl(DATA_SET_CDL).unwrap();
+    let other: DataSet = DataSet::from_cdl("netcdf other {
+dimensions:
+    latitude = 3 ;
+    depth = 2 ;
+variables:
+    float latitude(latitude) ;
+        latitude:units = \"degrees_north\" ;
+        latitude:axis = \"Y\" ;
+    float depth(depth) ;
+    :version = 1 ;
+    :history = \"merged\" ;
+}").unwrap();
+
+    data_set.merge(&other, MergePolicy::Error).unwrap();
+    // The policy is not used
+    for policy in [MergePolicy::Skip, MergePolicy::Overwrite].iter() {
+        let mut merged: DataSet = DataSet::from_cdl(DATA_SET_CDL).unwrap();
+        merged.merge(&other, *policy).unwrap();
+        assert_eq!(data_set,                                merged);
+    }
+    assert_eq!(vec!["time", "latitude", "longitude", "depth"], data_set.dim_names());
+    assert_eq!(vec!["latitude", "temperature", "depth"],    data_set.get_var_names());
+    assert_eq!(vec!["units", "axis"],                       data_set.get_var("latitude").unwrap().get_attr_names());
+    assert_eq!(vec!["title", "version", "history"],         data_set.get_global_attr_names());
+
+    // Merge with itself
+    let copy: DataSet = DataSet::from_cdl(&data_set.to_cdl("copy")).unwrap();
+    data_set.merge(&copy, MergePolicy::Error).unwrap();
+    assert_eq!(true,                                        data_set.diff(&copy).is_empty());
+}
+
+#[test]
+fn test_merge_error_policy() {
+    let data_set: DataSet = DataSet::from_cdl(DATA_SET_CDL).unwrap();
+    let mut merged: DataSet = DataSet::from_cdl(DATA_SET_CDL).unwrap();
+    assert_eq!(
+        InvalidDataSet::DimensionAlreadyExists(String::from("longitude")),
+        merged.merge(&DataSet::from_cdl(OTHER_CDL).unwrap(), MergePolicy::Error).unwrap_err()
+    );
+    assert_eq!(
+        InvalidDataSet::VariableAlreadyExists(String::from("temperature")),
+        merged.merge(&DataSet::from_cdl("netcdf other { dimensions: time = unlimited ; variables: int temperature(time) ; }").unwrap(), MergePolicy::Error).unwrap_err()
+    );
+    assert_eq!(
+        InvalidDataSet::VariableAttributeAlreadyExists{var_name: String::from("latitude"), attr_name: String::from("units")},
+        merged.merge(&DataSet::from_cdl("netcdf other { dimensions: latitude = 3 ; variables: float latitude(latitude) ; latitude:units = \"K\" ; }").unwrap(), MergePolicy::Error).unwrap_err()
+    );
+    assert_eq!(
+        InvalidDataSet::GlobalAttributeAlreadyExists(String::from("version")),
+        merged.merge(&DataSet::from_cdl("netcdf other { variables: :version = 2 ; }").unwrap(), MergePolicy::Error).unwrap_err()
+    );
+    // Two *unlimited-size* dimensions
+    assert_eq!(
+        InvalidDataSet::UnlimitedDimensionAlreadyExists(String::from("time")),
+        merged.merge(&DataSet::from_cdl("netcdf other { dimensions: record = unlimited ; }").unwrap(), MergePolicy::Overwrite).unwrap_err()
+    );
+    // The data set is not modified
+    assert_eq!(data_set,                                    merged);
+}
+
+#[test]
+fn test_merge_skip_policy() {
+    let mut data_set: DataSet = DataSet::from_cdl(DATA_SET_CDL).unwrap();
+    data_set.merge(&DataSet::from_cdl(OTHER_CDL).unwrap(), MergePolicy::Skip).unwrap();
+
+    assert_eq!(vec!["time", "latitude", "longitude", "depth"], data_set.dim_names());
+    assert_eq!(Some(4),                                     data_set.dim_size("longitude"));
+    // The variable `longitude` is defined over the skipped dimension
+    assert_eq!(vec!["latitude", "temperature", "depth", "salinity"], data_set.get_var_names());
+    assert_eq!(Some(DataType::I16),                         data_set.var_data_type("temperature"));
+    assert_eq!(Some(&[0.5][..]),                            data_set.get_var_attr_f64("temperature", "scale_factor"));
+    assert_eq!(vec!["units", "axis"],                       data_set.get_var("latitude").unwrap().get_attr_names());
+    assert_eq!(Some(String::from("down")),                  data_set.get_var_attr_as_string("depth", "positive"));
+    assert_eq!(Some(String::from("Ocean")),                 data_set.get_global_attr_as_string("title"));
+    assert_eq!(vec!["title", "version", "history"],         data_set.get_global_attr_names());
+}
+
+#[test]
+fn test_merge_overwrite_policy() {
+    let mut data_set: DataSet = DataSet::from_cdl(DATA_SET_CDL).unwrap();
+    data_set.merge(&DataSet::from_cdl(OTHER_CDL).unwrap(), MergePolicy::Overwrite).unwrap();
+
+    assert_eq!(vec!["time", "latitude", "longitude", "depth"], data_set.dim_names());
+    assert_eq!(Some(5),                                     data_set.dim_size("longitude"));
+    assert_eq!(vec!["latitude", "temperature", "longitude", "depth", "salinity"], data_set.get_var_names());
+    // The overwritten variable takes the attributes of the other data set
+    assert_eq!(Some(DataType::F64),                         data_set.var_data_type("temperature"));
+    assert_eq!(vec!["time", "latitude"],                    data_set.get_var("temperature").unwrap().dim_names());
+    assert_eq!(Vec::<String>::new(),                        data_set.get_var("temperature").unwrap().get_attr_names());
+    assert_eq!(Some(String::from("Atmosphere")),            data_set.get_global_attr_as_string("title"));
+    assert_eq!(Some(&DataVector::I32(vec![1])),             data_set.get_global_attr("version").map(|attr| &attr.data));
+}
+
+#[test]
+fn test_merge_rename_policy() {
+    let mut data_set: DataSet = DataSet::from_cdl(DATA_SET_CDL).unwrap();
+    let report: MergeReport = data_set.merge(&DataSet::from_cdl(OTHER_CDL).unwrap(), MergePolicy::Rename).unwrap();
+
+    assert_eq!(vec![(String::from("longitude"), String::from("longitude_1"))],       report.renamed_dims);
+    assert_eq!(vec![(String::from("temperature"), String::from("temperature_1"))],   report.renamed_vars);
+    assert_eq!(Vec::<(String, String, String)>::new(),                              report.renamed_var_attrs);
+    assert_eq!(vec![(String::from("title"), String::from("title_1"))],               report.renamed_global_attrs);
+
+    assert_eq!(vec!["time", "latitude", "longitude", "longitude_1", "depth"], data_set.dim_names());
+    assert_eq!(Some(4),                                     data_set.dim_size("longitude"));
+    assert_eq!(Some(5),                                     data_set.dim_size("longitude_1"));
+    assert_eq!(vec!["latitude", "temperature", "temperature_1", "longitude", "depth", "salinity"], data_set.get_var_names());
+    // The variables of the other data set use the renamed dimension
+    assert_eq!(vec!["longitude_1"],                         data_set.get_var("longitude").unwrap().dim_names());
+    assert_eq!(Some(DataType::I16),                         data_set.var_data_type("temperature"));
+    assert_eq!(Some(DataType::F64),                         data_set.var_data_type("temperature_1"));
+    assert_eq!(Some(String::from("Ocean")),                 data_set.get_global_attr_as_string("title"));
+    assert_eq!(Some(String::from("Atmosphere")),            data_set.get_global_attr_as_string("title_1"));
+    assert_eq!(vec!["title", "version", "title_1", "history"], data_set.get_global_attr_names());
+
+    // The variable attributes are renamed, and the free names are searched in both data sets
+    let mut data_set: DataSet = DataSet::from_cdl("netcdf data_set { variables: int x ; x:units = \"m\" ; x:units_1 = \"km\" ; }").unwrap();
+    let report: MergeReport = data_set.merge(
+        &DataSet::from_cdl("netcdf other { variables: int x ; x:units = \"cm\" ; x:units_2 = \"mm\" ; }").unwrap(),
+        MergePolicy::Rename
+    ).unwrap();
+    assert_eq!(
+        vec![(String::from("x"), String::from("units"), String::from("units_3"))],
+        report.renamed_var_attrs
+    );
+    assert_eq!(vec!["units", "units_1", "units_3", "units_2"], data_set.get_var("x").unwrap().get_attr_names());
+    assert_eq!(Some(String::from("cm")),                    data_set.get_var_attr_as_string("x", "units_3"));
+
+    // Without conflict, nothing is renamed
+    let mut data_set: DataSet = DataSet::from_cdl(DATA_SET_CDL).unwrap();
+    let copy: DataSet = data_set.clone();
+    assert_eq!(true,                                        data_set.merge(&copy, MergePolicy::Rename).unwrap().is_empty());
+    assert_eq!(copy,                                        data_set);
+
+    // Both unlimited-size dimensions cannot be kept
+    assert_eq!(
+        InvalidDataSet::UnlimitedDimensionAlreadyExists(String::from("time")),
+        data_set.merge(&DataSet::from_cdl("netcdf other { dimensions: record = unlimited ; variables: int x(record) ; }").unwrap(), MergePolicy::Rename).unwrap_err()
+    );
+    assert_eq!(copy,                                        data_set);
+}
@@ -0,0 +1,169 @@
+use std::rc::Rc;
+
+use crate::{Attribute, DataSet, DataType, DataVector, Dimension, InMemoryDataSet, Variable};
+
+fn new_data_set() -> DataSet {
+    let mut data_set = DataSet::new();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_var_f64("time", &["time"]).unwrap();
+    data_set.add_var_attr_string("time", "units", "days since 1970-01-01").unwrap();
+    data_set.add_var_f32("sst", &["time", "latitude"]).unwrap();
+    data_set.add_var_attr_f32("sst", "_FillValue", vec![-999.0]).unwrap();
+    data_set.add_var_attr_i64("sst", "valid_range", vec![-5, 40]).unwrap();
+    data_set.add_global_attr_string("title", "Template").unwrap();
+    return data_set;
+}
+
+#[test]
+fn test_serde_data_type() {
+    assert_eq!("\"F32\"",                           serde_json::to_string(&DataType::F32).unwrap());
+    assert_eq!(DataType::U64,                       serde_json::from_str::<DataType>("\"U64\"").unwrap());
+    assert_eq!(true,                                serde_json::from_str::<DataType>("\"F16\"").is_err());
+}
+
+#[test]
+fn test_serde_dimension() {
+    let dim: Dimension = serde_json::from_str("{\"name\": \"time\", \"size\": 2, \"unlimited\": true}").unwrap();
+    assert_eq!("time",                              dim.name());
+    assert_eq!(2,                                   dim.size());
+    assert_eq!(true,                                dim.is_unlimited());
+    assert_eq!("{\"name\":\"time\",\"size\":2,\"unlimited\":true}", serde_json::to_string(&dim).unwrap());
+
+    // The field `unlimited` is optional
+    let dim: Dimension = serde_json::from_str("{\"name\": \"latitude\", \"size\": 3}").unwrap();
+    assert_eq!(true,                                dim.is_fixed());
+
+    // Invalid dimensions
+    assert_eq!(true,                                serde_json::from_str::<Dimension>("{\"name\": \"latitude\", \"size\": 0}").is_err());
+    assert_eq!(true,                                serde_json::from_str::<Dimension>("{\"name\": \"!latitude\", \"size\": 3}").is_err());
+}
+
+#[test]
+fn test_serde_attribute() {
+    let attr: Attribute = serde_json::from_str("{\"name\": \"valid_range\", \"data\": {\"I32\": [0, 10]}}").unwrap();
+    assert_eq!("valid_range",                       attr.name());
+    assert_eq!(DataVector::I32(vec![0, 10]),        attr.data);
+    assert_eq!(attr,                                serde_json::from_str(&serde_json::to_string(&attr).unwrap()).unwrap());
+
+    assert_eq!(true,                                serde_json::from_str::<Attribute>("{\"name\": \"\", \"data\": {\"I32\": [0]}}").is_err());
+}
+
+#[test]
+fn test_serde_variable() {
+    let data_set: DataSet = new_data_set();
+    let var: &Variable = data_set.get_var("sst").unwrap();
+
+    let json: String = serde_json::to_string(var).unwrap();
+    let var_copy: Variable = serde_json::from_str(&json).unwrap();
+    assert_eq!(var,                                 &var_copy);
+    assert!(Rc::ptr_eq(var_copy.unlimited_dim.as_ref().unwrap(), &var_copy.dims[0]));
+
+    // The unlimited dimension must be the first one
+    let json: &str = "{\"name\": \"sst\", \"dims\": [{\"name\": \"latitude\", \"size\": 3}, {\"name\": \"time\", \"size\": 2, \"unlimited\": true}], \"data_type\": \"F32\"}";
+    assert_eq!(true,                                serde_json::from_str::<Variable>(json).is_err());
+}
+
+#[test]
+fn test_serde_data_set() {
+    let data_set: DataSet = new_data_set();
+
+    let json: String = serde_json::to_string(&data_set).unwrap();
+    let data_set_copy: DataSet = serde_json::from_str(&json).unwrap();
+    assert_eq!(true,                                data_set.diff(&data_set_copy).is_empty());
+    assert_eq!(data_set.dim_names(),                data_set_copy.dim_names());
+    assert_eq!(data_set.get_var_names(),            data_set_copy.get_var_names());
+    assert_eq!(Some(2),                             data_set_copy.num_records());
+
+    // The dimensions are shared by the variables
+    let var: &Variable = data_set_copy.get_var("sst").unwrap();
+    assert!(Rc::ptr_eq(&data_set_copy.get_dim("latitude").unwrap(), &var.dims[1]));
+    assert!(Rc::ptr_eq(&data_set_copy.get_unlimited_dim().unwrap(), &var.dims[0]));
+}
+
+#[test]
+fn test_deserialize_data_set() {
+    let json: &str = "{
+        \"dims\": [{\"name\": \"latitude\", \"size\": 3}],
+        \"attrs\": [{\"name\": \"title\", \"data\": {\"U8\": [79, 75]}}],
+        \"vars\": [{\"name\": \"latitude\", \"dim_names\": [\"latitude\"], \"data_type\": \"F32\"}]
+    }";
+    let data_set: DataSet = serde_json::from_str(json).unwrap();
+    assert_eq!(vec!["latitude"],                    data_set.dim_names());
+    assert_eq!(vec!["latitude"],                    data_set.get_var_names());
+    assert_eq!(Some(String::from("OK")),            data_set.get_global_attr_as_string("title"));
+    assert_eq!(DataSet::new(),                      serde_json::from_str("{}").unwrap());
+
+    // Undefined dimension
+    let json: &str = "{\"vars\": [{\"name\": \"latitude\", \"dim_names\": [\"latitude\"], \"data_type\": \"F32\"}]}";
+    let err: String = serde_json::from_str::<DataSet>(json).unwrap_err().to_string();
+    assert_eq!(true,                                err.contains("DimensionsNotDefined"));
+
+    // Duplicated global attribute
+    let json: &str = "{\"attrs\": [{\"name\": \"title\", \"data\": {\"U8\": []}}, {\"name\": \"title\", \"data\": {\"U8\": []}}]}";
+    let err: String = serde_json::from_str::<DataSet>(json).unwrap_err().to_string();
+    assert_eq!(true,                                err.contains("GlobalAttributeAlreadyExists"));
+}
+
+#[test]
+fn test_serde_in_memory_data_set() {
+    let mut in_memory: InMemoryDataSet = InMemoryDataSet::new(new_data_set());
+    in_memory.set_var("time", vec![1.0_f64, 2.0]).unwrap();
+    in_memory.set_var("sst", vec![10.5_f32, std::f32::NAN, -999.0, 12.0, 13.0, 14.0]).unwrap();
+
+    // Binary format
+    let bytes: Vec<u8> = rmp_serde::to_vec(&in_memory).unwrap();
+    let in_memory_copy: InMemoryDataSet = rmp_serde::from_slice(&bytes).unwrap();
+    assert_eq!(true,                                in_memory.data_set().diff(in_memory_copy.data_set()).is_empty());
+    assert_eq!(Some(&[1.0_f64, 2.0][..]),           in_memory_copy.get_var::<f64>("time"));
+    let sst: &[f32] = in_memory_copy.get_var::<f32>("sst").unwrap();
+    assert_eq!(10.5,                                sst[0]);
+    assert_eq!(true,                                sst[1].is_nan());
+    assert_eq!(&[-999.0, 12.0, 13.0, 14.0],         &sst[2..]);
+
+    // The variables without data are kept
+    in_memory.remove_var_data("sst");
+    let json: String = serde_json::to_string(&in_memory).unwrap();
+    let in_memory_copy: InMemoryDataSet = serde_json::from_str(&json).unwrap();
+    assert_eq!(in_memory,                           in_memory_copy);
+    assert_eq!(vec!["sst"],                         in_memory_copy.vars_without_data());
+}
+
+#[test]
+fn test_deserialize_in_memory_data_set() {
+    let json: &str = "{
+        \"data_set\": {
+            \"dims\": [{\"name\": \"latitude\", \"size\": 2}],
+            \"vars\": [{\"name\": \"latitude\", \"dim_names\": [\"latitude\"], \"data_type\": \"F32\"}]
+        },
+        \"data\": [{\"name\": \"latitude\", \"data\": {\"F32\": [0.0, 0.5]}}]
+    }";
+    let in_memory: InMemoryDataSet = serde_json::from_str(json).unwrap();
+    assert_eq!(Some(&[0.0_f32, 0.5][..]),           in_memory.get_var::<f32>("latitude"));
+
+    // The data are optional
+    let in_memory: InMemoryDataSet = serde_json::from_str("{\"data_set\": {}}").unwrap();
+    assert_eq!(InMemoryDataSet::new(DataSet::new()), in_memory);
+
+    // Data not matching the variable
+    let json: &str = "{
+        \"data_set\": {
+            \"dims\": [{\"name\": \"latitude\", \"size\": 2}],
+            \"vars\": [{\"name\": \"latitude\", \"dim_names\": [\"latitude\"], \"data_type\": \"F32\"}]
+        },
+        \"data\": [{\"name\": \"latitude\", \"data\": {\"F32\": [0.0]}}]
+    }";
+    let err: String = serde_json::from_str::<InMemoryDataSet>(json).unwrap_err().to_string();
+    assert_eq!(true,                                err.contains("VariableMismatchDataLength"));
+
+    // Duplicated data
+    let json: &str = "{
+        \"data_set\": {
+            \"dims\": [{\"name\": \"latitude\", \"size\": 1}],
+            \"vars\": [{\"name\": \"latitude\", \"dim_names\": [\"latitude\"], \"data_type\": \"F32\"}]
+        },
+        \"data\": [{\"name\": \"latitude\", \"data\": {\"F32\": [0.0]}}, {\"name\": \"latitude\", \"data\": {\"F32\": [1.0]}}]
+    }";
+    let err: String = serde_json::from_str::<InMemoryDataSet>(json).unwrap_err().to_string();
+    assert_eq!(true,                                err.contains("duplicated data"));
+}
@@ -1,5 +1,5 @@
 #![cfg(test)]
-use crate::{DataSet, DataType, InvalidDataSet};
+use crate::{Attribute, DataSet, DataType, InvalidDataSet, Variable};
 
 #[test]
 fn test_add_var_attr_i8() {
@@ -456,4 +456,169 @@ fn test_remove_var_attr_error_attr_not_defined () {
     );
 
     assert_eq!(Some(0), data_set.num_var_attrs(VAR_NAME));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_set_var_attr() {
+    const DIM_NAME: &str = "dim_1";
+    const VAR_NAME: &str = "var_1";
+    const UNDEF_VAR_NAME: &str = "undef_var";
+    const VAR_ATTR_NAME_1: &str = "attr_1";
+    const VAR_ATTR_NAME_2: &str = "attr_2";
+
+    let mut data_set = DataSet::new();
+    data_set.add_fixed_dim(DIM_NAME, 2).unwrap();
+    data_set.add_var_f32(VAR_NAME, &[DIM_NAME]).unwrap();
+    data_set.add_var_attr_i32(VAR_NAME, VAR_ATTR_NAME_1, vec![1, 2]).unwrap();
+    data_set.add_var_attr_string(VAR_NAME, VAR_ATTR_NAME_2, "foo").unwrap();
+
+    // Overwrite the value and the data type
+    data_set.set_var_attr_f64(VAR_NAME, VAR_ATTR_NAME_1, vec![3.0]).unwrap();
+    assert_eq!(Some(2),                         data_set.num_var_attrs(VAR_NAME));
+    assert_eq!(Some(DataType::F64),             data_set.get_var_attr_data_type(VAR_NAME, VAR_ATTR_NAME_1));
+    assert_eq!(Some(&[3.0][..]),                data_set.get_var_attr_f64(VAR_NAME, VAR_ATTR_NAME_1));
+    assert_eq!(None,                            data_set.get_var_attr_i32(VAR_NAME, VAR_ATTR_NAME_1));
+
+    // Overwrite the value
+    data_set.set_var_attr_string(VAR_NAME, VAR_ATTR_NAME_2, "bar").unwrap();
+    assert_eq!(Some(String::from("bar")),       data_set.get_var_attr_as_string(VAR_NAME, VAR_ATTR_NAME_2));
+    // The order of the attributes is kept
+    assert_eq!(vec![VAR_ATTR_NAME_1, VAR_ATTR_NAME_2], data_set.get_var(VAR_NAME).unwrap().get_attr_names());
+
+    // Insert a new attribute
+    data_set.set_var_attr_u64(VAR_NAME, "attr_3", vec![4]).unwrap();
+    assert_eq!(Some(3),                         data_set.num_var_attrs(VAR_NAME));
+    assert_eq!(Some(&[4_u64][..]),              data_set.get_var_attr_u64(VAR_NAME, "attr_3"));
+
+    // Errors
+    assert_eq!(
+        InvalidDataSet::VariableNotDefined(String::from(UNDEF_VAR_NAME)),
+        data_set.set_var_attr_i8(UNDEF_VAR_NAME, VAR_ATTR_NAME_1, vec![1]).unwrap_err()
+    );
+    assert_eq!(
+        InvalidDataSet::VariableAttributeNameNotValid{var_name: String::from(VAR_NAME), attr_name: String::from("!attr")},
+        data_set.set_var_attr_i8(VAR_NAME, "!attr", vec![1]).unwrap_err()
+    );
+    assert_eq!(Some(3),                         data_set.num_var_attrs(VAR_NAME));
+}
+
+
+#[test]
+fn test_add_var_attr_generic() {
+    const DIM_NAME: &str = "dim_1";
+    const VAR_NAME: &str = "var_1";
+
+    let mut data_set = DataSet::new();
+    data_set.add_fixed_dim(DIM_NAME, 2).unwrap();
+    data_set.add_var_f32(VAR_NAME, &[DIM_NAME]).unwrap();
+
+    data_set.add_var_attr(VAR_NAME, "valid_range", vec![-5_i16, 40]).unwrap();
+    data_set.add_var_attr(VAR_NAME, "scale_factor", vec![0.5_f64]).unwrap();
+    assert_eq!(Some(&[-5_i16, 40][..]),         data_set.get_var_attr_i16(VAR_NAME, "valid_range"));
+    assert_eq!(Some(&[0.5_f64][..]),            data_set.get_var_attr_f64(VAR_NAME, "scale_factor"));
+
+    assert_eq!(
+        InvalidDataSet::VariableAttributeAlreadyExists{var_name: String::from(VAR_NAME), attr_name: String::from("valid_range")},
+        data_set.add_var_attr(VAR_NAME, "valid_range", vec![0_u64]).unwrap_err()
+    );
+    assert_eq!(
+        InvalidDataSet::VariableNotDefined(String::from("undef_var")),
+        data_set.add_var_attr("undef_var", "valid_range", vec![0_u64]).unwrap_err()
+    );
+
+    data_set.set_var_attr(VAR_NAME, "valid_range", vec![0_u64, 10]).unwrap();
+    assert_eq!(Some(DataType::U64),             data_set.get_var_attr_data_type(VAR_NAME, "valid_range"));
+    assert_eq!(Some(2),                         data_set.num_var_attrs(VAR_NAME));
+}
+
+
+#[test]
+fn test_get_var_attr_scalar() {
+    const DIM_NAME: &str = "dim_1";
+    const VAR_NAME: &str = "var_1";
+
+    let mut data_set = DataSet::new();
+    data_set.add_fixed_dim(DIM_NAME, 2).unwrap();
+    data_set.add_var_i16(VAR_NAME, &[DIM_NAME]).unwrap();
+    data_set.add_var_attr_i16(VAR_NAME, "_FillValue", vec![-1]).unwrap();
+    data_set.add_var_attr_f32(VAR_NAME, "scale_factor", vec![0.5]).unwrap();
+    data_set.add_var_attr_i16(VAR_NAME, "valid_range", vec![0, 100]).unwrap();
+
+    assert_eq!(Ok(-1_i16),                      data_set.get_var_attr_scalar(VAR_NAME, "_FillValue"));
+    assert_eq!(Ok(0.5_f32),                     data_set.get_var_attr_scalar(VAR_NAME, "scale_factor"));
+    assert_eq!(
+        Err(InvalidDataSet::VariableAttributeMismatchDataType{
+            var_name: String::from(VAR_NAME),
+            attr_name: String::from("scale_factor"),
+            req: DataType::F64,
+            get: DataType::F32,
+        }),
+        data_set.get_var_attr_scalar::<f64>(VAR_NAME, "scale_factor")
+    );
+    assert_eq!(
+        Err(InvalidDataSet::VariableAttributeNotScalar{var_name: String::from(VAR_NAME), attr_name: String::from("valid_range"), len: 2}),
+        data_set.get_var_attr_scalar::<i16>(VAR_NAME, "valid_range")
+    );
+    assert_eq!(
+        Err(InvalidDataSet::VariableAttributeNotDefined{var_name: String::from(VAR_NAME), attr_name: String::from("undef_attr")}),
+        data_set.get_var_attr_scalar::<i16>(VAR_NAME, "undef_attr")
+    );
+    assert_eq!(
+        Err(InvalidDataSet::VariableNotDefined(String::from("undef_var"))),
+        data_set.get_var_attr_scalar::<i16>("undef_var", "_FillValue")
+    );
+}
+
+
+#[test]
+fn test_var_attrs_iterator() {
+    const DIM_NAME: &str = "dim_1";
+    const VAR_NAME: &str = "var_1";
+
+    let mut data_set = DataSet::new();
+    data_set.add_fixed_dim(DIM_NAME, 2).unwrap();
+    data_set.add_var_f32(VAR_NAME, &[DIM_NAME]).unwrap();
+    assert_eq!(Some(0),                         data_set.var_attrs(VAR_NAME).map(|attrs| attrs.len()));
+
+    data_set.add_var_attr_string(VAR_NAME, "units", "K").unwrap();
+    data_set.add_var_attr_f32(VAR_NAME, "scale_factor", vec![0.5]).unwrap();
+
+    let attr_names: Vec<&str> = data_set.var_attrs(VAR_NAME).unwrap().map(|attr: &Attribute| attr.name()).collect();
+    assert_eq!(vec!["units", "scale_factor"],   attr_names);
+    assert_eq!(data_set.get_var_attrs(VAR_NAME).unwrap(), data_set.var_attrs(VAR_NAME).unwrap().collect::<Vec<&Attribute>>());
+    assert_eq!(2,                               data_set.get_var(VAR_NAME).unwrap().attrs().len());
+    assert_eq!(true,                            data_set.var_attrs("undef_var").is_none());
+}
+
+#[test]
+fn test_edit_attrs_of_variable() {
+    let mut data_set = DataSet::new();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_var_f32("sst", &["latitude"]).unwrap();
+
+    {
+        let var: &mut Variable = data_set.get_var_mut("sst").unwrap();
+        var.add_attr_string("units", "K").unwrap();
+        var.set_attr_f32("_FillValue", vec![-999.0]).unwrap();
+        var.set_attr_i16("valid_min", vec![0]).unwrap();
+        // Overwrite the value and the data type
+        var.set_attr_f32("valid_min", vec![-2.0]).unwrap();
+        var.set_attr_u64("count", vec![3]).unwrap();
+        var.rename_attr("count", "num_obs").unwrap();
+        var.remove_attr("units").unwrap();
+
+        assert_eq!(
+            InvalidDataSet::VariableAttributeNameNotValid{var_name: String::from("sst"), attr_name: String::from("!name")},
+            var.set_attr_i8("!name", vec![0]).unwrap_err()
+        );
+        assert_eq!(
+            InvalidDataSet::VariableAttributeAlreadyExists{var_name: String::from("sst"), attr_name: String::from("valid_min")},
+            var.rename_attr("num_obs", "valid_min").unwrap_err()
+        );
+    }
+
+    assert_eq!(vec!["_FillValue", "valid_min", "num_obs"],  data_set.get_var("sst").unwrap().get_attr_names());
+    assert_eq!(Some(&[-999.0_f32][..]),                     data_set.get_var_attr_f32("sst", "_FillValue"));
+    assert_eq!(Some(&[-2.0_f32][..]),                       data_set.get_var_attr_f32("sst", "valid_min"));
+    assert_eq!(Some(&[3_u64][..]),                          data_set.get_var_attr_u64("sst", "num_obs"));
+}
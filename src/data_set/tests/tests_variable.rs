@@ -1,4 +1,5 @@
-use crate::{DataSet, InvalidDataSet, DataType, DimensionType};
+use crate::{DataSet, InvalidDataSet, DataType, DimensionType, Variable};
+use crate::data_set::DimensionSize;
 
 #[test]
 fn test_add_var_error_invalid_name() {
@@ -452,3 +453,307 @@ fn test_remove_var_error_not_defined() {
     assert_eq!(None,    data_set.var_len(VAR_NAME));
     assert_eq!(None,    data_set.var_data_type(VAR_NAME));
 }
+
+#[test]
+fn test_locate_slice() {
+    const UNLIM_DIM_NAME: &str = "unlim_dim";
+    const UNLIM_DIM_SIZE: usize = 2;
+    const DIM_NAME_1: &str = "dim_1";
+    const DIM_SIZE_1: usize = 3;
+    const DIM_NAME_2: &str = "dim_2";
+    const DIM_SIZE_2: usize = 4;
+    const RECORD_VAR_NAME: &str = "record_var";
+    const FIXED_VAR_NAME: &str = "fixed_var";
+    const SCALAR_VAR_NAME: &str = "scalar_var";
+
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim(UNLIM_DIM_NAME, UNLIM_DIM_SIZE).unwrap();
+    data_set.add_fixed_dim(DIM_NAME_1, DIM_SIZE_1).unwrap();
+    data_set.add_fixed_dim(DIM_NAME_2, DIM_SIZE_2).unwrap();
+    data_set.add_var_i8(RECORD_VAR_NAME, &[UNLIM_DIM_NAME, DIM_NAME_1, DIM_NAME_2]).unwrap();
+    data_set.add_var_i8(FIXED_VAR_NAME, &[DIM_NAME_1, DIM_NAME_2]).unwrap();
+    data_set.add_var_i8::<&str>(SCALAR_VAR_NAME, &[]).unwrap();
+
+    // *Record* variable
+    {
+        let var: &Variable = data_set.get_var(RECORD_VAR_NAME).unwrap();
+        // The whole variable, one run per record
+        assert_eq!(Some(vec![(0, 0, 12), (1, 0, 12)]),                  var.locate_slice(&[0, 0, 0], &[2, 3, 4]));
+        // Full rows are merged
+        assert_eq!(Some(vec![(1, 4, 8)]),                               var.locate_slice(&[1, 1, 0], &[1, 2, 4]));
+        // Partial rows
+        assert_eq!(Some(vec![(0, 5, 2), (0, 9, 2), (1, 5, 2), (1, 9, 2)]),  var.locate_slice(&[0, 1, 1], &[2, 2, 2]));
+        // One column
+        assert_eq!(Some(vec![(0, 2, 1), (0, 6, 1), (0, 10, 1)]),        var.locate_slice(&[0, 0, 2], &[1, 3, 1]));
+        // Empty slice
+        assert_eq!(Some(vec![]),                                        var.locate_slice(&[0, 0, 0], &[2, 0, 4]));
+        // Not valid slices
+        assert_eq!(None,                                                var.locate_slice(&[0, 0, 0], &[3, 1, 1]));
+        assert_eq!(None,                                                var.locate_slice(&[0, 2, 0], &[1, 2, 1]));
+        assert_eq!(None,                                                var.locate_slice(&[0, 0], &[1, 1]));
+        assert_eq!(None,                                                var.locate_slice(&[0, 0, 0], &[1, 1]));
+        assert_eq!(None,                                                var.locate_slice(&[0, 0, usize::MAX], &[1, 1, 2]));
+
+        assert_eq!(Some((1, 6)),                                        var.locate_element(&[1, 1, 2]));
+        assert_eq!(None,                                                var.locate_element(&[2, 1, 2]));
+        assert_eq!(None,                                                var.locate_element(&[1, 1]));
+    }
+    // *Fixed-size* variable
+    {
+        let var: &Variable = data_set.get_var(FIXED_VAR_NAME).unwrap();
+        assert_eq!(Some(vec![(0, 0, 12)]),                              var.locate_slice(&[0, 0], &[3, 4]));
+        assert_eq!(Some(vec![(0, 4, 4)]),                               var.locate_slice(&[1, 0], &[1, 4]));
+        assert_eq!(Some(vec![(0, 1, 3), (0, 5, 3)]),                    var.locate_slice(&[0, 1], &[2, 3]));
+
+        assert_eq!(Some((0, 11)),                                       var.locate_element(&[2, 3]));
+    }
+    // Scalar variable
+    {
+        let var: &Variable = data_set.get_var(SCALAR_VAR_NAME).unwrap();
+        assert_eq!(Some(vec![(0, 0, 1)]),                               var.locate_slice(&[], &[]));
+        assert_eq!(None,                                                var.locate_slice(&[0], &[1]));
+
+        assert_eq!(Some((0, 0)),                                        var.locate_element(&[]));
+    }
+}
+
+#[test]
+fn test_add_string_var() {
+    let mut data_set = DataSet::new();
+    data_set.set_unlimited_dim("time", 0).unwrap();
+    data_set.add_fixed_dim("station", 2).unwrap();
+
+    data_set.add_string_var("station_name", &["station"], 8).unwrap();
+    data_set.add_string_var("comment", &["time", "station"], 3).unwrap();
+    data_set.add_string_var("title", &[] as &[&str], 10).unwrap();
+    assert_eq!(Some(8),                                                 data_set.dim_size("station_name_strlen"));
+    assert_eq!(Some(DataType::U8),                                      data_set.var_data_type("comment"));
+    assert_eq!(vec!["time", "station", "comment_strlen"],               data_set.get_var("comment").unwrap().dim_names());
+    assert_eq!(vec!["title_strlen"],                                    data_set.get_var("title").unwrap().dim_names());
+
+    // Errors, the auxiliary dimension is not kept
+    assert_eq!(
+        InvalidDataSet::VariableAlreadyExists(String::from("title")),
+        data_set.add_string_var("title", &[] as &[&str], 10).unwrap_err()
+    );
+    assert_eq!(
+        InvalidDataSet::DimensionsNotDefined{var_name: String::from("label"), undef_dim_names: vec![String::from("undef_dim")]},
+        data_set.add_string_var("label", &["undef_dim"], 4).unwrap_err()
+    );
+    assert_eq!(false,                                                   data_set.has_dim("label_strlen"));
+    assert_eq!(
+        InvalidDataSet::FixedDimensionWithZeroSize(String::from("label_strlen")),
+        data_set.add_string_var("label", &["station"], 0).unwrap_err()
+    );
+    assert_eq!(false,                                                   data_set.has_var("label"));
+}
+
+
+#[test]
+fn test_name_lookups_after_remove_and_rename() {
+    let mut data_set = DataSet::new();
+    data_set.add_fixed_dim("dim_1", 1).unwrap();
+    data_set.add_fixed_dim("dim_2", 2).unwrap();
+    data_set.add_fixed_dim("dim_3", 3).unwrap();
+    for i in 0..100 {
+        data_set.add_var_i32(&format!("var_{}", i), &["dim_3"]).unwrap();
+    }
+
+    // Remove a dimension and some variables located at the beginning and in the middle
+    data_set.remove_dim("dim_1").unwrap();
+    data_set.remove_var("var_0").unwrap();
+    data_set.remove_var("var_50").unwrap();
+    assert_eq!(Some(2),                         data_set.dim_size("dim_2"));
+    assert_eq!(Some(3),                         data_set.dim_size("dim_3"));
+    assert_eq!(98,                              data_set.num_vars());
+    assert_eq!(false,                           data_set.has_var("var_50"));
+    for (index, var) in data_set.vars().enumerate() {
+        assert_eq!(Some(var),                   data_set.get_var(var.name()));
+        assert_eq!(index,                       data_set.find_var_from_name(var.name()).unwrap().0);
+    }
+
+    // Rename a dimension and a variable
+    data_set.rename_dim("dim_3", "dim_4").unwrap();
+    data_set.rename_var("var_99", "var_0").unwrap();
+    assert_eq!(false,                           data_set.has_dim("dim_3"));
+    assert_eq!(Some(3),                         data_set.dim_size("dim_4"));
+    assert_eq!(false,                           data_set.has_var("var_99"));
+    assert_eq!(Some(vec![String::from("dim_4")]), data_set.get_var("var_0").map(|var: &Variable| var.dim_names()));
+    assert_eq!(97,                              data_set.find_var_from_name("var_0").unwrap().0);
+
+    // The names can be reused
+    data_set.add_fixed_dim("dim_3", 5).unwrap();
+    data_set.add_var_i32("var_99", &["dim_3"]).unwrap();
+    assert_eq!(Some(5),                         data_set.dim_size("dim_3"));
+    assert_eq!(vec![String::from("dim_3")],     data_set.get_var("var_99").unwrap().dim_names());
+
+    // The indices are copied
+    let data_set_copy: DataSet = data_set.clone();
+    assert_eq!(data_set.get_var_names(),        data_set_copy.get_var_names());
+    assert_eq!(Some(3),                         data_set_copy.dim_size("dim_4"));
+}
+
+
+#[test]
+fn test_var_shape() {
+    let mut data_set = DataSet::new();
+    data_set.set_unlimited_dim("time", 0).unwrap();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_fixed_dim("longitude", 5).unwrap();
+    data_set.add_var_i16("sst", &["time", "latitude", "longitude"]).unwrap();
+    data_set.add_var_f64("latitude", &["latitude"]).unwrap();
+    data_set.add_var_u8::<&str>("scalar", &[]).unwrap();
+
+    {
+        let var: &Variable = data_set.get_var("sst").unwrap();
+        assert_eq!(3,                           var.ndim());
+        assert_eq!(vec![0, 3, 5],               var.shape());
+        assert_eq!(0,                           var.size_bytes());
+    }
+    {
+        let var: &Variable = data_set.get_var("latitude").unwrap();
+        assert_eq!(1,                           var.ndim());
+        assert_eq!(vec![3],                     var.shape());
+        assert_eq!(24,                          var.size_bytes());
+    }
+    {
+        let var: &Variable = data_set.get_var("scalar").unwrap();
+        assert_eq!(0,                           var.ndim());
+        assert_eq!(Vec::<usize>::new(),         var.shape());
+        // the padding bytes are not included
+        assert_eq!(1,                           var.size_bytes());
+    }
+
+    // The shape follows the number of records
+    if let DimensionSize::Unlimited(num_records) = &data_set.get_unlimited_dim().unwrap().size {
+        num_records.replace(2);
+    }
+    let var: &Variable = data_set.get_var("sst").unwrap();
+    assert_eq!(vec![2, 3, 5],                   var.shape());
+    assert_eq!(2 * 3 * 5 * 2,                   var.size_bytes());
+}
+
+#[test]
+fn test_cast_var() {
+    let mut data_set = DataSet::new();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_var_f64("sst", &["latitude"]).unwrap();
+    data_set.add_var_attr_f64("sst", "_FillValue", vec![-999.0]).unwrap();
+    data_set.add_var_attr_f64("sst", "valid_range", vec![-2.5, 40.5]).unwrap();
+    data_set.add_var_attr_f32("sst", "missing_value", vec![-1.0]).unwrap();
+    data_set.add_var_attr_f64("sst", "scale_factor", vec![0.01]).unwrap();
+    data_set.add_var_attr_string("sst", "units", "degree_Celsius").unwrap();
+
+    assert_eq!(Ok(DataType::F64),                       data_set.cast_var("sst", DataType::I16));
+    assert_eq!(Some(DataType::I16),                     data_set.var_data_type("sst"));
+    assert_eq!(Some(&[-999_i16][..]),                   data_set.get_var_attr_i16("sst", "_FillValue"));
+    assert_eq!(Some(&[-2_i16, 40][..]),                 data_set.get_var_attr_i16("sst", "valid_range"));
+    // The attributes having an other data type are unchanged
+    assert_eq!(Some(&[-1.0_f32][..]),                   data_set.get_var_attr_f32("sst", "missing_value"));
+    assert_eq!(Some(&[0.01_f64][..]),                   data_set.get_var_attr_f64("sst", "scale_factor"));
+    assert_eq!(Some(String::from("degree_Celsius")),    data_set.get_var_attr_as_string("sst", "units"));
+    assert_eq!(6,                                       data_set.get_var("sst").unwrap().size_bytes());
+
+    assert_eq!(Ok(DataType::I16),                       data_set.cast_var("sst", DataType::I16));
+    assert_eq!(
+        InvalidDataSet::VariableNotDefined(String::from("undef_var")),
+        data_set.cast_var("undef_var", DataType::F32).unwrap_err()
+    );
+}
+
+#[test]
+fn test_add_coord_var() {
+    let mut data_set = DataSet::new();
+    data_set.add_coord_var("time", 4, DataType::F64, Some("days since 1970-01-01"), Some("T")).unwrap();
+
+    assert_eq!(vec!["time"],                            data_set.dim_names());
+    assert_eq!(Some(DimensionType::FixedSize),          data_set.dim_type("time"));
+    assert_eq!(vec!["time"],                            data_set.get_var_names());
+    assert_eq!(Some(4),                                 data_set.var_len("time"));
+    assert_eq!(Some(String::from("T")),                 data_set.get_var_attr_as_string("time", "axis"));
+
+    // The variable already exists, the dimension is not added
+    data_set.add_fixed_dim("x", 2).unwrap();
+    data_set.add_var_f32("latitude", &["x"]).unwrap();
+    assert_eq!(
+        InvalidDataSet::VariableAlreadyExists(String::from("latitude")),
+        data_set.add_coord_var("latitude", 3, DataType::F32, None, None).unwrap_err()
+    );
+    assert_eq!(vec!["time", "x"],                       data_set.dim_names());
+
+    // The dimension already exists
+    assert_eq!(
+        InvalidDataSet::DimensionAlreadyExists(String::from("x")),
+        data_set.add_coord_var("x", 3, DataType::F32, None, None).unwrap_err()
+    );
+    assert_eq!(vec!["time", "latitude"],                data_set.get_var_names());
+}
+
+#[test]
+fn test_find_vars_where() {
+    let mut data_set = DataSet::new();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_var_f64("time", &["time"]).unwrap();
+    data_set.add_var_attr_string("time", "axis", "T").unwrap();
+    data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    data_set.add_var_attr_string("latitude", "axis", "Y").unwrap();
+    data_set.add_var_i16("sst", &["time", "latitude"]).unwrap();
+    data_set.add_var_attr_i16("sst", "axis", vec![1]).unwrap();
+
+    let var_names = |vars: Vec<&Variable>| -> Vec<String> {
+        return vars.into_iter().map(|var: &Variable| var.name().to_owned()).collect();
+    };
+    assert_eq!(vec!["time", "latitude", "sst"],     var_names(data_set.find_vars_where(|_var: &Variable| true)));
+    assert_eq!(Vec::<String>::new(),                var_names(data_set.find_vars_where(|_var: &Variable| false)));
+    assert_eq!(vec!["sst"],                         var_names(data_set.find_vars_where(|var: &Variable| var.data_type() == DataType::I16)));
+    assert_eq!(vec!["latitude", "sst"],             var_names(data_set.find_vars_where(|var: &Variable| var.dim_names().contains(&String::from("latitude")))));
+
+    assert_eq!(vec!["latitude"],                    var_names(data_set.vars_with_attr("axis", "Y")));
+    // The non-text attributes are ignored
+    assert_eq!(Vec::<String>::new(),                var_names(data_set.vars_with_attr("axis", "\u{1}")));
+    assert_eq!(Vec::<String>::new(),                var_names(data_set.vars_with_attr("units", "K")));
+}
+
+#[test]
+fn test_copy_var_def_from() {
+    let mut source = DataSet::new();
+    source.set_unlimited_dim("time", 2).unwrap();
+    source.add_fixed_dim("latitude", 3).unwrap();
+    source.add_fixed_dim("longitude", 4).unwrap();
+    source.add_var_f32("sst", &["time", "latitude"]).unwrap();
+    source.add_var_attr_string("sst", "units", "K").unwrap();
+    source.add_var_attr_f32("sst", "_FillValue", vec![-999.0]).unwrap();
+    source.add_var_i16("mask", &["latitude", "longitude"]).unwrap();
+
+    let mut data_set = DataSet::new();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.copy_var_def_from(&source, "sst").unwrap();
+    assert_eq!(vec!["latitude", "time"],            data_set.dim_names());
+    assert_eq!(Some(DimensionType::UnlimitedSize),  data_set.dim_type("time"));
+    assert_eq!(Some(2),                             data_set.dim_size("time"));
+    assert_eq!(vec!["sst"],                         data_set.get_var_names());
+    assert_eq!(Some(DataType::F32),                 data_set.var_data_type("sst"));
+    assert_eq!(source.get_var("sst"),               data_set.get_var("sst"));
+
+    // The variable is already defined
+    assert_eq!(
+        InvalidDataSet::VariableAlreadyExists(String::from("sst")),
+        data_set.copy_var_def_from(&source, "sst").unwrap_err()
+    );
+    // The variable is not defined in the other data set
+    assert_eq!(
+        InvalidDataSet::VariableNotDefined(String::from("undef_var")),
+        data_set.copy_var_def_from(&source, "undef_var").unwrap_err()
+    );
+
+    // The dimension `longitude` has an other size, the data set is not modified
+    let mut data_set = DataSet::new();
+    data_set.add_fixed_dim("longitude", 5).unwrap();
+    assert_eq!(
+        InvalidDataSet::DimensionAlreadyExists(String::from("longitude")),
+        data_set.copy_var_def_from(&source, "mask").unwrap_err()
+    );
+    assert_eq!(vec!["longitude"],                   data_set.dim_names());
+    assert_eq!(0,                                   data_set.num_vars());
+}
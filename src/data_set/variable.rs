@@ -4,7 +4,8 @@ use std::collections::HashSet;
 use std::iter::FromIterator;
 use std::rc::Rc;
 
-use crate::{is_valid_name, Attribute, DataType, Dimension, InvalidDataSet, NC_MAX_VAR_DIMS};
+use crate::{is_valid_name, Attribute, CastOverflow, DataType, DataVector, Dimension, InvalidDataSet, NcType, NC_MAX_VAR_DIMS};
+use crate::error::CastError;
 use crate::{data_set::dimension::DimensionSize};
 use crate::io::compute_padding_size;
 
@@ -191,12 +192,57 @@ impl Variable {
         return self.dims.len();
     }
 
+    /// Returns the number of dimensions (the rank) of the variable, same as the method `num_dims`.
+    pub fn ndim(&self) -> usize {
+        return self.num_dims();
+    }
+
+    /// Returns the sizes of the dimensions of the variable.
+    ///
+    /// The size of the *unlimited-size* dimension is the current number of records, and the shape of a scalar variable is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, Variable};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.set_unlimited_dim("time", 2).unwrap();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_fixed_dim("longitude", 4).unwrap();
+    /// data_set.add_var_f64("sst", &["time", "latitude", "longitude"]).unwrap();
+    /// data_set.add_var_i32::<&str>("scalar", &[]).unwrap();
+    ///
+    /// let sst: &Variable = data_set.get_var("sst").unwrap();
+    /// assert_eq!(3,                       sst.ndim());
+    /// assert_eq!(vec![2, 3, 4],           sst.shape());
+    /// assert_eq!(2 * 3 * 4 * 8,           sst.size_bytes());
+    ///
+    /// let scalar: &Variable = data_set.get_var("scalar").unwrap();
+    /// assert_eq!(0,                       scalar.ndim());
+    /// assert_eq!(Vec::<usize>::new(),     scalar.shape());
+    /// assert_eq!(4,                       scalar.size_bytes());
+    /// ```
+    pub fn shape(&self) -> Vec<usize> {
+        return self.dims.iter().map(|dim: &Rc<Dimension>| dim.size()).collect();
+    }
+
+    /// Returns the number of bytes of the data (`len * size_of`), the padding bytes written in the files are not included.
+    pub fn size_bytes(&self) -> usize {
+        return self.len() * self.data_type.size_of();
+    }
+
     /// Returns the list of the dimensions
     pub fn get_dims(&self) -> Vec<Rc<Dimension>>
     {
         self.dims.clone()
     }
 
+    /// Returns an iterator over the dimensions, without allocating a `Vec` like the method `get_dims`.
+    pub fn dims(&self) -> std::slice::Iter<'_, Rc<Dimension>> {
+        return self.dims.iter();
+    }
+
     /// Returns the list of the dimension names
     pub fn dim_names(&self) -> Vec<String>
     {
@@ -286,6 +332,102 @@ impl Variable {
         return chunk_size
     }
 
+    /// Locates the element at the multi-dimensional `index`, and returns a tuple containing :
+    ///
+    /// - 0 : the index of the record (always `0` for the *fixed-size* variables)
+    /// - 1 : the position of the element inside this record
+    ///
+    /// Returns `None` if `index` does not match the number of dimensions or exceeds the size of a dimension.
+    pub(crate) fn locate_element(&self, index: &[usize]) -> Option<(usize, usize)> {
+        if index.len() != self.dims.len() {
+            return None;
+        }
+        if index.iter().zip(self.dims.iter()).any(|(i, dim): (&usize, &Rc<Dimension>)| *i >= dim.size()) {
+            return None;
+        }
+        let (record_index, chunk_index, chunk_dims): (usize, &[usize], &[Rc<Dimension>]) = match self.is_record_var() {
+            true => (index[0], &index[1..], &self.dims[1..]),
+            false => (0, index, &self.dims[..]),
+        };
+        let element_pos: usize = chunk_index.iter().zip(chunk_dims.iter()).fold(0, |pos: usize, (i, dim): (&usize, &Rc<Dimension>)| {
+            pos * dim.size() + i
+        });
+        return Some((record_index, element_pos));
+    }
+
+    /// Locates the hyperslab defined by `start` and `count`, and returns the list of its contiguous runs of elements.
+    ///
+    /// Each run is a tuple containing :
+    ///
+    /// - 0 : the index of the record (always `0` for the *fixed-size* variables)
+    /// - 1 : the position of the first element of the run inside this record
+    /// - 2 : the number of elements of the run
+    ///
+    /// The runs are sorted in the row-major order of the hyperslab.
+    ///
+    /// Returns `None` if `start` or `count` do not match the number of dimensions or if the hyperslab exceeds the size of a dimension.
+    pub(crate) fn locate_slice(&self, start: &[usize], count: &[usize]) -> Option<Vec<(usize, usize, usize)>> {
+        let num_dims: usize = self.dims.len();
+        if start.len() != num_dims || count.len() != num_dims {
+            return None;
+        }
+        let shape: Vec<usize> = self.shape();
+        if (0..num_dims).any(|i: usize| start[i].checked_add(count[i]).map(|end: usize| end > shape[i]).unwrap_or(true)) {
+            return None;
+        }
+        if count.contains(&0) {
+            return Some(vec![]);
+        }
+        let (records, chunk_start, chunk_count, chunk_shape): (std::ops::Range<usize>, &[usize], &[usize], &[usize]) = match self.is_record_var() {
+            true => (start[0]..(start[0] + count[0]), &start[1..], &count[1..], &shape[1..]),
+            false => (0..1, start, count, &shape[..]),
+        };
+
+        // Merge the inner dimensions entirely covered by the hyperslab into one run
+        let mut run_len: usize = 1;
+        let mut num_outer_dims: usize = chunk_shape.len();
+        while num_outer_dims > 0 {
+            num_outer_dims -= 1;
+            run_len *= chunk_count[num_outer_dims];
+            if chunk_count[num_outer_dims] != chunk_shape[num_outer_dims] {
+                break;
+            }
+        }
+        // Compute the strides of the dimensions inside a record
+        let mut strides: Vec<usize> = vec![1; chunk_shape.len()];
+        for i in (0..chunk_shape.len().saturating_sub(1)).rev() {
+            strides[i] = strides[i + 1] * chunk_shape[i + 1];
+        }
+        let first_pos: usize = chunk_start.iter().zip(strides.iter()).map(|(i, stride): (&usize, &usize)| i * stride).sum();
+
+        // Loop over the outer dimensions (row-major order)
+        let mut runs: Vec<(usize, usize, usize)> = vec![];
+        for record_index in records {
+            let mut outer_index: Vec<usize> = vec![0; num_outer_dims];
+            loop {
+                let pos: usize = first_pos + outer_index.iter().zip(strides.iter()).map(|(i, stride): (&usize, &usize)| i * stride).sum::<usize>();
+                runs.push((record_index, pos, run_len));
+                // Increment the outer index
+                let mut dim: usize = num_outer_dims;
+                loop {
+                    if dim == 0 {
+                        break;
+                    }
+                    dim -= 1;
+                    outer_index[dim] += 1;
+                    if outer_index[dim] < chunk_count[dim] {
+                        break;
+                    }
+                    outer_index[dim] = 0;
+                }
+                if outer_index.iter().all(|i: &usize| *i == 0) {
+                    break;
+                }
+            }
+        }
+        return Some(runs);
+    }
+
     /// Returns the number of chunks.
     pub fn num_chunks(&self) -> usize {
         match self.dims.first() {
@@ -304,6 +446,11 @@ impl Variable {
         return self.attrs.iter().collect();
     }
 
+    /// Returns an iterator over the attributes, without allocating a `Vec` like the method `get_attrs`.
+    pub fn attrs(&self) -> std::slice::Iter<'_, Attribute> {
+        return self.attrs.iter();
+    }
+
     /// Returns all attributs defined in the dataset or in the variable.
     pub fn get_attr_names(&self) -> Vec<String> {
         return self.attrs.iter().map(|attr: &Attribute| {
@@ -375,6 +522,162 @@ impl Variable {
         attr.get_f64()
     }
 
+    /// Returns the attribute value as a `&[u16]`.
+    ///
+    /// Also see the method [Attribute::get_u16](struct.Attribute.html#method.get_u16).
+    pub fn get_attr_u16(&self, attr_name: &str) -> Option<&[u16]> {
+        let attr: &Attribute = self.get_attr(attr_name)?;
+        attr.get_u16()
+    }
+
+    /// Returns the attribute value as a `&[u32]`.
+    ///
+    /// Also see the method [Attribute::get_u32](struct.Attribute.html#method.get_u32).
+    pub fn get_attr_u32(&self, attr_name: &str) -> Option<&[u32]> {
+        let attr: &Attribute = self.get_attr(attr_name)?;
+        attr.get_u32()
+    }
+
+    /// Returns the attribute value as a `&[i64]`.
+    ///
+    /// Also see the method [Attribute::get_i64](struct.Attribute.html#method.get_i64).
+    pub fn get_attr_i64(&self, attr_name: &str) -> Option<&[i64]> {
+        let attr: &Attribute = self.get_attr(attr_name)?;
+        attr.get_i64()
+    }
+
+    /// Returns the attribute value as a `&[u64]`.
+    ///
+    /// Also see the method [Attribute::get_u64](struct.Attribute.html#method.get_u64).
+    pub fn get_attr_u64(&self, attr_name: &str) -> Option<&[u64]> {
+        let attr: &Attribute = self.get_attr(attr_name)?;
+        attr.get_u64()
+    }
+
+    /// Returns the fill value of the variable, namely its attribute `_FillValue` if it is a single value of the same data type,
+    /// otherwise the default fill value of the data type (see [`NcType::FILL_VALUE`](trait.NcType.html#associatedconstant.FILL_VALUE)).
+    ///
+    /// Returns `None` if `T` is not the data type of the variable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, Variable, NC_FILL_I16};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("sst", &["latitude"]).unwrap();
+    /// data_set.add_var_attr_f32("sst", "_FillValue", vec![-999.0]).unwrap();
+    /// data_set.add_var_i16("mask", &["latitude"]).unwrap();
+    ///
+    /// let sst: &Variable = data_set.get_var("sst").unwrap();
+    /// assert_eq!(Some(-999.0_f32),                    sst.fill_value::<f32>());
+    /// assert_eq!(None,                                sst.fill_value::<f64>());
+    /// let mask: &Variable = data_set.get_var("mask").unwrap();
+    /// assert_eq!(Some(NC_FILL_I16),                   mask.fill_value::<i16>());
+    /// ```
+    pub fn fill_value<T: NcType>(&self) -> Option<T> {
+        if self.data_type != T::DATA_TYPE {
+            return None;
+        }
+        let fill_value: Option<T> = self.get_attr("_FillValue")
+            .and_then(|attr: &Attribute| T::from_data_vector(&attr.data))
+            .filter(|values: &&[T]| values.len() == 1)
+            .map(|values: &[T]| values[0]);
+        return Some(fill_value.unwrap_or(T::FILL_VALUE));
+    }
+
+    /// Returns the CF attributes `scale_factor` and `add_offset` of the packed variables, by default `1.0` and `0.0`.
+    ///
+    /// The attributes are only used if they are a single number (the `u8` attributes are texts).
+    pub(crate) fn packing_attrs(&self) -> (f64, f64) {
+        let get_number = |attr_name: &str| -> Option<f64> {
+            return self.get_attr(attr_name)
+                .filter(|attr: &&Attribute| attr.data_type() != DataType::U8 && attr.len() == 1)
+                .and_then(|attr: &Attribute| attr.data.iter_as_f64().next());
+        };
+        return (get_number("scale_factor").unwrap_or(1.0), get_number("add_offset").unwrap_or(0.0));
+    }
+
+    /// Packs the values with the CF attributes `scale_factor` and `add_offset` (`(value - add_offset) / scale_factor`),
+    /// rounded to the nearest integer for the integer data types, the `NaN` values being replaced by the fill value.
+    ///
+    /// Returns an error if a packed value is outside the range of the data type of the variable.
+    pub(crate) fn pack_values(&self, values: &[f64]) -> Result<DataVector, CastError> {
+        let (scale_factor, add_offset): (f64, f64) = self.packing_attrs();
+        let is_integer: bool = self.data_type != DataType::F32 && self.data_type != DataType::F64;
+        let packed_values: Vec<f64> = values.iter().map(|value: &f64| {
+            if value.is_nan() {
+                return 0.0;  // replaced by the fill value below
+            }
+            let packed_value: f64 = (value - add_offset) / scale_factor;
+            return if is_integer { packed_value.round() } else { packed_value };
+        }).collect();
+        let mut data: DataVector = DataVector::F64(packed_values).cast_to(self.data_type(), CastOverflow::Error)?;
+        match data {
+            DataVector::I8(ref mut data) => fill_nan_values(data, values, self.fill_value::<i8>()),
+            DataVector::U8(ref mut data) => fill_nan_values(data, values, self.fill_value::<u8>()),
+            DataVector::I16(ref mut data) => fill_nan_values(data, values, self.fill_value::<i16>()),
+            DataVector::I32(ref mut data) => fill_nan_values(data, values, self.fill_value::<i32>()),
+            DataVector::F32(ref mut data) => fill_nan_values(data, values, self.fill_value::<f32>()),
+            DataVector::F64(ref mut data) => fill_nan_values(data, values, self.fill_value::<f64>()),
+            DataVector::U16(ref mut data) => fill_nan_values(data, values, self.fill_value::<u16>()),
+            DataVector::U32(ref mut data) => fill_nan_values(data, values, self.fill_value::<u32>()),
+            DataVector::I64(ref mut data) => fill_nan_values(data, values, self.fill_value::<i64>()),
+            DataVector::U64(ref mut data) => fill_nan_values(data, values, self.fill_value::<u64>()),
+        }
+        return Ok(data);
+    }
+
+    /// Returns the valid range `(valid_min, valid_max)` of the values, given by the CF attribute `valid_range`,
+    /// or by the CF attributes `valid_min` and `valid_max` (an absent bound being infinite).
+    ///
+    /// Returns `None` if none of these attributes is defined (the `u8` attributes are texts and are ignored).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, Variable};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_i16("sst", &["latitude"]).unwrap();
+    /// data_set.add_var_attr_i16("sst", "valid_range", vec![-100, 100]).unwrap();
+    /// data_set.add_var_f32("depth", &["latitude"]).unwrap();
+    /// data_set.add_var_attr_f32("depth", "valid_min", vec![0.0]).unwrap();
+    /// data_set.add_var_f32("temperature", &["latitude"]).unwrap();
+    ///
+    /// assert_eq!(Some((-100.0, 100.0)),                           data_set.get_var("sst").unwrap().valid_range());
+    /// assert_eq!(Some((0.0, std::f64::INFINITY)),                 data_set.get_var("depth").unwrap().valid_range());
+    /// assert_eq!(None,                                            data_set.get_var("temperature").unwrap().valid_range());
+    /// ```
+    pub fn valid_range(&self) -> Option<(f64, f64)> {
+        let get_numbers = |attr_name: &str, num_values: usize| -> Option<Vec<f64>> {
+            return self.get_attr(attr_name)
+                .filter(|attr: &&Attribute| attr.data_type() != DataType::U8 && attr.len() == num_values)
+                .map(|attr: &Attribute| attr.data.iter_as_f64().collect());
+        };
+        if let Some(valid_range) = get_numbers("valid_range", 2) {
+            return Some((valid_range[0], valid_range[1]));
+        }
+        let valid_min: Option<f64> = get_numbers("valid_min", 1).map(|values: Vec<f64>| values[0]);
+        let valid_max: Option<f64> = get_numbers("valid_max", 1).map(|values: Vec<f64>| values[0]);
+        if valid_min.is_none() && valid_max.is_none() {
+            return None;
+        }
+        return Some((valid_min.unwrap_or(std::f64::NEG_INFINITY), valid_max.unwrap_or(std::f64::INFINITY)));
+    }
+
+    /// Returns the index of the first value outside the valid range (see the method `valid_range`), the fill values being ignored.
+    pub(crate) fn find_value_out_of_range<T: NcType>(&self, data: &[T]) -> Option<usize> {
+        let (valid_min, valid_max): (f64, f64) = self.valid_range()?;
+        let fill_value: Option<T> = self.fill_value::<T>();
+        return data.iter().position(|value: &T| {
+            let number: f64 = value.to_f64();
+            Some(*value) != fill_value && (number < valid_min || number > valid_max)
+        });
+    }
+
     /// Appends a new attribute.
     ///
     /// An error is returned if an other attribute with the same name has already been added.
@@ -477,13 +780,155 @@ impl Variable {
         Ok(())
     }
 
+    /// Append a new `u16` attribute.
+    ///
+    /// An error is returned if an other attribute with the same name has already been added.
+    pub fn add_attr_u16(&mut self, attr_name: &str, u16_data: Vec<u16>) -> Result<(), InvalidDataSet> {
+        let attr: Attribute = Attribute::new_u16(attr_name, u16_data)
+            .map_err(|var_attr_name: String| InvalidDataSet::VariableAttributeNameNotValid{
+                var_name: self.name.to_string(),
+                attr_name: var_attr_name,
+            })?;
+        self.add_attr(attr)?;
+        Ok(())
+    }
+
+    /// Append a new `u32` attribute.
+    ///
+    /// An error is returned if an other attribute with the same name has already been added.
+    pub fn add_attr_u32(&mut self, attr_name: &str, u32_data: Vec<u32>) -> Result<(), InvalidDataSet> {
+        let attr: Attribute = Attribute::new_u32(attr_name, u32_data)
+            .map_err(|var_attr_name: String| InvalidDataSet::VariableAttributeNameNotValid{
+                var_name: self.name.to_string(),
+                attr_name: var_attr_name,
+            })?;
+        self.add_attr(attr)?;
+        Ok(())
+    }
+
+    /// Append a new `i64` attribute.
+    ///
+    /// An error is returned if an other attribute with the same name has already been added.
+    pub fn add_attr_i64(&mut self, attr_name: &str, i64_data: Vec<i64>) -> Result<(), InvalidDataSet> {
+        let attr: Attribute = Attribute::new_i64(attr_name, i64_data)
+            .map_err(|var_attr_name: String| InvalidDataSet::VariableAttributeNameNotValid{
+                var_name: self.name.to_string(),
+                attr_name: var_attr_name,
+            })?;
+        self.add_attr(attr)?;
+        Ok(())
+    }
+
+    /// Append a new `u64` attribute.
+    ///
+    /// An error is returned if an other attribute with the same name has already been added.
+    pub fn add_attr_u64(&mut self, attr_name: &str, u64_data: Vec<u64>) -> Result<(), InvalidDataSet> {
+        let attr: Attribute = Attribute::new_u64(attr_name, u64_data)
+            .map_err(|var_attr_name: String| InvalidDataSet::VariableAttributeNameNotValid{
+                var_name: self.name.to_string(),
+                attr_name: var_attr_name,
+            })?;
+        self.add_attr(attr)?;
+        Ok(())
+    }
+
+    /// Overwrites the value of the attribute, or appends it if it is not defined.
+    pub(in crate::data_set) fn set_attr_data(&mut self, attr_name: &str, attr_data: DataVector) -> Result<(), InvalidDataSet> {
+        if let Some(attr) = self.attrs.iter_mut().find(|attr: &&mut Attribute| attr.name == attr_name) {
+            attr.data = attr_data;
+            return Ok(());
+        }
+        let attr: Attribute = Attribute::new(attr_name, attr_data)
+            .map_err(|var_attr_name: String| InvalidDataSet::VariableAttributeNameNotValid{
+                var_name: self.name.to_string(),
+                attr_name: var_attr_name,
+            })?;
+        self.attrs.push(attr);
+        return Ok(());
+    }
+
+    /// Sets a `i8` attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_attr_i8(&mut self, attr_name: &str, i8_data: Vec<i8>) -> Result<(), InvalidDataSet> {
+        self.set_attr_data(attr_name, DataVector::I8(i8_data))
+    }
+
+    /// Sets a `u8` attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_attr_u8(&mut self, attr_name: &str, u8_data: Vec<u8>) -> Result<(), InvalidDataSet> {
+        self.set_attr_data(attr_name, DataVector::U8(u8_data))
+    }
+
+    /// Sets a `u8` attribute from a string, its value and its data type are overwritten if the attribute already exists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, DataType, Variable};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    ///
+    /// let var: &mut Variable = data_set.get_var_mut("latitude").unwrap();
+    /// var.add_attr_f32("units", vec![0.0]).unwrap();
+    /// var.set_attr_string("units", "degrees_north").unwrap();
+    /// var.set_attr_string("long_name", "Latitude").unwrap();
+    /// var.rename_attr("long_name", "standard_name").unwrap();
+    ///
+    /// assert_eq!(Some(DataType::U8),                      data_set.get_var_attr_data_type("latitude", "units"));
+    /// assert_eq!(Some(String::from("degrees_north")),     data_set.get_var_attr_as_string("latitude", "units"));
+    /// assert_eq!(Some(String::from("Latitude")),          data_set.get_var_attr_as_string("latitude", "standard_name"));
+    /// ```
+    pub fn set_attr_string<T: AsRef<str>>(&mut self, attr_name: &str, str_data: T) -> Result<(), InvalidDataSet> {
+        self.set_attr_u8(attr_name, String::from(str_data.as_ref()).into_bytes())
+    }
+
+    /// Sets a `i16` attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_attr_i16(&mut self, attr_name: &str, i16_data: Vec<i16>) -> Result<(), InvalidDataSet> {
+        self.set_attr_data(attr_name, DataVector::I16(i16_data))
+    }
+
+    /// Sets a `i32` attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_attr_i32(&mut self, attr_name: &str, i32_data: Vec<i32>) -> Result<(), InvalidDataSet> {
+        self.set_attr_data(attr_name, DataVector::I32(i32_data))
+    }
+
+    /// Sets a `f32` attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_attr_f32(&mut self, attr_name: &str, f32_data: Vec<f32>) -> Result<(), InvalidDataSet> {
+        self.set_attr_data(attr_name, DataVector::F32(f32_data))
+    }
+
+    /// Sets a `f64` attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_attr_f64(&mut self, attr_name: &str, f64_data: Vec<f64>) -> Result<(), InvalidDataSet> {
+        self.set_attr_data(attr_name, DataVector::F64(f64_data))
+    }
+
+    /// Sets a `u16` attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_attr_u16(&mut self, attr_name: &str, u16_data: Vec<u16>) -> Result<(), InvalidDataSet> {
+        self.set_attr_data(attr_name, DataVector::U16(u16_data))
+    }
+
+    /// Sets a `u32` attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_attr_u32(&mut self, attr_name: &str, u32_data: Vec<u32>) -> Result<(), InvalidDataSet> {
+        self.set_attr_data(attr_name, DataVector::U32(u32_data))
+    }
+
+    /// Sets a `i64` attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_attr_i64(&mut self, attr_name: &str, i64_data: Vec<i64>) -> Result<(), InvalidDataSet> {
+        self.set_attr_data(attr_name, DataVector::I64(i64_data))
+    }
+
+    /// Sets a `u64` attribute, its value and its data type are overwritten if the attribute already exists.
+    pub fn set_attr_u64(&mut self, attr_name: &str, u64_data: Vec<u64>) -> Result<(), InvalidDataSet> {
+        self.set_attr_data(attr_name, DataVector::U64(u64_data))
+    }
+
     /// Rename an existing attribute.
     ///
     /// An error is returned :
     ///  - the `old_attr_name`is not a valid NetCDF-3 name
     ///  - the `old_attr_name` attribute doesn't exist
     ///  - an other `new_attr_name` attribute already exist
-    pub(in crate::data_set) fn rename_attr(&mut self, old_attr_name: &str, new_attr_name: &str) -> Result<(), InvalidDataSet> {
+    pub fn rename_attr(&mut self, old_attr_name: &str, new_attr_name: &str) -> Result<(), InvalidDataSet> {
         if old_attr_name == new_attr_name {
             return Ok(());
         }
@@ -533,14 +978,14 @@ impl Variable {
             })
     }
 
-    pub(super) fn check_var_name(var_name: &str) -> Result<(), InvalidDataSet> {
+    pub(crate) fn check_var_name(var_name: &str) -> Result<(), InvalidDataSet> {
         return match is_valid_name(var_name) {
             true => Ok(()),
             false => Err(InvalidDataSet::VariableNameNotValid(var_name.to_string())),
         };
     }
 
-    fn check_dims_validity(var_name: &str, dims: &Vec<Rc<Dimension>>) -> Result<(), InvalidDataSet> {
+    pub(crate) fn check_dims_validity(var_name: &str, dims: &Vec<Rc<Dimension>>) -> Result<(), InvalidDataSet> {
         if dims.is_empty() {
             return Ok(());
         }
@@ -585,3 +1030,14 @@ impl Variable {
         Ok(())
     }
 }
+
+/// Replaces the packed values of the `NaN` values by the fill value.
+fn fill_nan_values<T: Copy>(data: &mut [T], values: &[f64], fill_value: Option<T>) {
+    if let Some(fill_value) = fill_value {
+        for (packed_value, value) in data.iter_mut().zip(values.iter()) {
+            if value.is_nan() {
+                *packed_value = fill_value;
+            }
+        }
+    }
+}
@@ -12,10 +12,20 @@ const I32_TYPE_C_API_NAME: &'static str = "NC_INT";
 const F32_TYPE_C_API_NAME: &'static str = "NC_FLOAT";
 /// Name of the `DataType::F64` (a.k.a. `NC_DOUBLE`) used in the NetCDF C-API.
 const F64_TYPE_C_API_NAME: &'static str = "NC_DOUBLE";
+/// Name of the `DataType::U16` (a.k.a. `NC_USHORT`) used in the NetCDF C-API.
+const U16_TYPE_C_API_NAME: &'static str = "NC_USHORT";
+/// Name of the `DataType::U32` (a.k.a. `NC_UINT`) used in the NetCDF C-API.
+const U32_TYPE_C_API_NAME: &'static str = "NC_UINT";
+/// Name of the `DataType::I64` (a.k.a. `NC_INT64`) used in the NetCDF C-API.
+const I64_TYPE_C_API_NAME: &'static str = "NC_INT64";
+/// Name of the `DataType::U64` (a.k.a. `NC_UINT64`) used in the NetCDF C-API.
+const U64_TYPE_C_API_NAME: &'static str = "NC_UINT64";
 
 
 /// All the data types supported by the NetCDF-3 format
 ///
+/// The data types `U16`, `U32`, `I64` and `U64` are only supported by the CDF-5 version (see [`Version::Cdf5`](enum.Version.html)).
+///
 /// # Example
 ///
 /// ```
@@ -34,9 +44,13 @@ const F64_TYPE_C_API_NAME: &'static str = "NC_DOUBLE";
 /// assert_eq!(4, DataType::I32.size_of());
 /// assert_eq!(4, DataType::F32.size_of());
 /// assert_eq!(8, DataType::F64.size_of());
+///
+/// assert_eq!(false, DataType::F64.is_cdf5_only());
+/// assert_eq!(true, DataType::U16.is_cdf5_only());
 /// ```
 #[repr(u32)]
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataType {
     /// 8-bit signed integer, a.k.a. `NC_BYTE`
     I8 = 1,
@@ -50,6 +64,14 @@ pub enum DataType {
     F32 = 5,
     /// 64-bit floating-point number, a.k.a. `NC_DOUBLE`
     F64 = 6,
+    /// 16-bit unsigned integer, a.k.a. `NC_USHORT` (CDF-5 only)
+    U16 = 8,
+    /// 32-bit unsigned integer, a.k.a. `NC_UINT` (CDF-5 only)
+    U32 = 9,
+    /// 64-bit signed integer, a.k.a. `NC_INT64` (CDF-5 only)
+    I64 = 10,
+    /// 64-bit unsigned integer, a.k.a. `NC_UINT64` (CDF-5 only)
+    U64 = 11,
 }
 
 impl std::fmt::Display for DataType {
@@ -62,6 +84,10 @@ impl std::fmt::Display for DataType {
             DataType::I32 => "I32",
             DataType::F32 => "F32",
             DataType::F64 => "F64",
+            DataType::U16 => "U16",
+            DataType::U32 => "U32",
+            DataType::I64 => "I64",
+            DataType::U64 => "U64",
         })
     }
 }
@@ -76,6 +102,10 @@ impl std::convert::TryFrom<u32> for DataType {
             4_u32 => Ok(DataType::I32),
             5_u32 => Ok(DataType::F32),
             6_u32 => Ok(DataType::F64),
+            8_u32 => Ok(DataType::U16),
+            9_u32 => Ok(DataType::U32),
+            10_u32 => Ok(DataType::I64),
+            11_u32 => Ok(DataType::U64),
             _ => Err("Invalid value for a NetCDF-3 data type."),
         }
     }
@@ -104,9 +134,21 @@ impl DataType {
             DataType::I32 => std::mem::size_of::<i32>(),
             DataType::F32 => std::mem::size_of::<f32>(),
             DataType::F64 => std::mem::size_of::<f64>(),
+            DataType::U16 => std::mem::size_of::<u16>(),
+            DataType::U32 => std::mem::size_of::<u32>(),
+            DataType::I64 => std::mem::size_of::<i64>(),
+            DataType::U64 => std::mem::size_of::<u64>(),
         }
     }
 
+    /// Returns `true` if the `DataType` is only supported by the CDF-5 version.
+    pub fn is_cdf5_only(&self) -> bool {
+        return match self {
+            DataType::I8 | DataType::U8 | DataType::I16 | DataType::I32 | DataType::F32 | DataType::F64 => false,
+            DataType::U16 | DataType::U32 | DataType::I64 | DataType::U64 => true,
+        };
+    }
+
 
     /// Returns the name of the `DataType` commoly used in the NedCDF C API.
     ///
@@ -133,6 +175,10 @@ impl DataType {
             DataType::I32 => I32_TYPE_C_API_NAME,
             DataType::F32 => F32_TYPE_C_API_NAME,
             DataType::F64 => F64_TYPE_C_API_NAME,
+            DataType::U16 => U16_TYPE_C_API_NAME,
+            DataType::U32 => U32_TYPE_C_API_NAME,
+            DataType::I64 => I64_TYPE_C_API_NAME,
+            DataType::U64 => U64_TYPE_C_API_NAME,
         }
     }
 }
\ No newline at end of file
@@ -11,6 +11,10 @@ fn test_data_type_display() {
     assert_eq!("DataType::I32", format!("{}", DataType::I32));
     assert_eq!("DataType::F32", format!("{}", DataType::F32));
     assert_eq!("DataType::F64", format!("{}", DataType::F64));
+    assert_eq!("DataType::U16", format!("{}", DataType::U16));
+    assert_eq!("DataType::U32", format!("{}", DataType::U32));
+    assert_eq!("DataType::I64", format!("{}", DataType::I64));
+    assert_eq!("DataType::U64", format!("{}", DataType::U64));
 }
 
 #[test]
@@ -21,6 +25,10 @@ fn test_data_type_size_of_element() {
     assert_eq!(4, DataType::I32.size_of());
     assert_eq!(4, DataType::F32.size_of());
     assert_eq!(8, DataType::F64.size_of());
+    assert_eq!(2, DataType::U16.size_of());
+    assert_eq!(4, DataType::U32.size_of());
+    assert_eq!(8, DataType::I64.size_of());
+    assert_eq!(8, DataType::U64.size_of());
 }
 
 #[test]
@@ -31,6 +39,10 @@ fn test_data_type_c_api_name() {
     assert_eq!("NC_INT", DataType::I32.c_api_name());
     assert_eq!("NC_FLOAT", DataType::F32.c_api_name());
     assert_eq!("NC_DOUBLE", DataType::F64.c_api_name());
+    assert_eq!("NC_USHORT", DataType::U16.c_api_name());
+    assert_eq!("NC_UINT", DataType::U32.c_api_name());
+    assert_eq!("NC_INT64", DataType::I64.c_api_name());
+    assert_eq!("NC_UINT64", DataType::U64.c_api_name());
 }
 
 #[test]
@@ -44,6 +56,25 @@ fn test_data_type_try_from_u32() -> Result<(), &'static str> {
     assert_eq!(Ok(DataType::F32),                           DataType::try_from(5_u32));
     assert_eq!(Ok(DataType::F64),                           DataType::try_from(6_u32));
     assert_eq!(Err("Invalid value for a NetCDF-3 data type."), DataType::try_from(7_u32));
+    assert_eq!(Ok(DataType::U16),                           DataType::try_from(8_u32));
+    assert_eq!(Ok(DataType::U32),                           DataType::try_from(9_u32));
+    assert_eq!(Ok(DataType::I64),                           DataType::try_from(10_u32));
+    assert_eq!(Ok(DataType::U64),                           DataType::try_from(11_u32));
+    assert_eq!(Err("Invalid value for a NetCDF-3 data type."), DataType::try_from(12_u32));
 
     Ok(())
 }
+
+#[test]
+fn test_data_type_is_cdf5_only() {
+    assert_eq!(false, DataType::I8.is_cdf5_only());
+    assert_eq!(false, DataType::U8.is_cdf5_only());
+    assert_eq!(false, DataType::I16.is_cdf5_only());
+    assert_eq!(false, DataType::I32.is_cdf5_only());
+    assert_eq!(false, DataType::F32.is_cdf5_only());
+    assert_eq!(false, DataType::F64.is_cdf5_only());
+    assert_eq!(true, DataType::U16.is_cdf5_only());
+    assert_eq!(true, DataType::U32.is_cdf5_only());
+    assert_eq!(true, DataType::I64.is_cdf5_only());
+    assert_eq!(true, DataType::U64.is_cdf5_only());
+}
@@ -1,8 +1,202 @@
 mod tests;
 
-use crate::DataType;
+#[cfg(feature = "ndarray")]
+mod ndarray_impl;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl;
 
-/// Wraps the six NetCDF-3 data types.
+use std::convert::TryFrom;
+
+use crate::{DataType, NcType};
+use crate::io::{compute_padding_size, encode_be_into};
+use crate::error::CastError;
+
+macro_rules! cast_data {
+    ($data:expr, $data_type:expr) => {
+        match $data_type {
+            DataType::I8 => DataVector::I8($data.iter().map(|value| *value as i8).collect()),
+            DataType::U8 => DataVector::U8($data.iter().map(|value| *value as u8).collect()),
+            DataType::I16 => DataVector::I16($data.iter().map(|value| *value as i16).collect()),
+            DataType::I32 => DataVector::I32($data.iter().map(|value| *value as i32).collect()),
+            DataType::F32 => DataVector::F32($data.iter().map(|value| *value as f32).collect()),
+            DataType::F64 => DataVector::F64($data.iter().map(|value| *value as f64).collect()),
+            DataType::U16 => DataVector::U16($data.iter().map(|value| *value as u16).collect()),
+            DataType::U32 => DataVector::U32($data.iter().map(|value| *value as u32).collect()),
+            DataType::I64 => DataVector::I64($data.iter().map(|value| *value as i64).collect()),
+            DataType::U64 => DataVector::U64($data.iter().map(|value| *value as u64).collect()),
+        }
+    }
+}
+
+macro_rules! cast_checked_int {
+    ($data:expr, $prim_type:ty, $data_type:expr, $overflow:expr) => {
+        $data.iter().enumerate().map(|(index, value)| {
+            value.to_cast_number().to_int(<$prim_type>::MIN as i128, <$prim_type>::MAX as i128, $overflow)
+                .map(|value: i128| value as $prim_type)
+                .ok_or_else(|| CastError::ValueOutOfRange{index: index, data_type: $data_type.clone()})
+        }).collect::<Result<Vec<$prim_type>, CastError>>()?
+    }
+}
+
+macro_rules! cast_checked_float {
+    ($data:expr, $prim_type:ty, $data_type:expr, $overflow:expr) => {
+        $data.iter().enumerate().map(|(index, value)| {
+            value.to_cast_number().to_float(<$prim_type>::MAX as f64, $overflow)
+                .map(|value: f64| value as $prim_type)
+                .ok_or_else(|| CastError::ValueOutOfRange{index: index, data_type: $data_type.clone()})
+        }).collect::<Result<Vec<$prim_type>, CastError>>()?
+    }
+}
+
+macro_rules! cast_checked_data {
+    ($data:expr, $data_type:expr, $overflow:expr) => {
+        match $data_type {
+            DataType::I8 => DataVector::I8(cast_checked_int!($data, i8, $data_type, $overflow)),
+            DataType::U8 => DataVector::U8(cast_checked_int!($data, u8, $data_type, $overflow)),
+            DataType::I16 => DataVector::I16(cast_checked_int!($data, i16, $data_type, $overflow)),
+            DataType::I32 => DataVector::I32(cast_checked_int!($data, i32, $data_type, $overflow)),
+            DataType::F32 => DataVector::F32(cast_checked_float!($data, f32, $data_type, $overflow)),
+            DataType::F64 => DataVector::F64(cast_checked_float!($data, f64, $data_type, $overflow)),
+            DataType::U16 => DataVector::U16(cast_checked_int!($data, u16, $data_type, $overflow)),
+            DataType::U32 => DataVector::U32(cast_checked_int!($data, u32, $data_type, $overflow)),
+            DataType::I64 => DataVector::I64(cast_checked_int!($data, i64, $data_type, $overflow)),
+            DataType::U64 => DataVector::U64(cast_checked_int!($data, u64, $data_type, $overflow)),
+        }
+    }
+}
+
+/// Handling of the values outside the range of the new data type, while converting a `DataVector` (see [`DataVector::cast_to`](enum.DataVector.html#method.cast_to)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastOverflow {
+    /// The values are clamped to the nearest bound of the new data type, `NaN` is converted into `0` for the integer types.
+    Saturate,
+    /// The error `CastError::ValueOutOfRange` is returned, also for `NaN` and the infinities converted into integers.
+    Error,
+    /// The integers wrap around, with the conversions of the operator `as` (see [`DataVector::cast`](enum.DataVector.html#method.cast)).
+    Wrap,
+}
+
+/// Element value used to check the conversions, the integers of all the data types fit in a `i128`.
+#[derive(Debug, Clone, Copy)]
+enum CastNumber {
+    Int(i128),
+    Float(f64),
+}
+
+impl CastNumber {
+
+    /// Converts the value into the range `[min, max]`, the floating-point numbers are truncated.
+    fn to_int(self, min: i128, max: i128, overflow: CastOverflow) -> Option<i128> {
+        let value: i128 = match self {
+            CastNumber::Int(value) => value,
+            CastNumber::Float(value) if value.is_nan() => return match overflow {
+                CastOverflow::Error => None,
+                _ => Some(0),
+            },
+            // saturated to `i128::MIN` or `i128::MAX`, which are out of the range
+            CastNumber::Float(value) => value as i128,
+        };
+        if value < min || value > max {
+            return match overflow {
+                CastOverflow::Error => None,
+                _ => Some(value.clamp(min, max)),
+            };
+        }
+        return Some(value);
+    }
+
+    /// Converts the value into the range `[-max, max]`, `NaN` and the infinities are kept.
+    fn to_float(self, max: f64, overflow: CastOverflow) -> Option<f64> {
+        let value: f64 = match self {
+            CastNumber::Int(value) => value as f64,
+            CastNumber::Float(value) => value,
+        };
+        if value.is_finite() && value.abs() > max {
+            return match overflow {
+                CastOverflow::Error => None,
+                _ => Some(max.copysign(value)),
+            };
+        }
+        return Some(value);
+    }
+}
+
+trait ToCastNumber: Copy {
+    fn to_cast_number(self) -> CastNumber;
+}
+
+macro_rules! impl_to_cast_number {
+    ($prim_type:ty, $cast_number:path, $inter_type:ty) => {
+        impl ToCastNumber for $prim_type {
+            fn to_cast_number(self) -> CastNumber {
+                return $cast_number(self as $inter_type);
+            }
+        }
+    }
+}
+
+impl_to_cast_number!(i8, CastNumber::Int, i128);
+impl_to_cast_number!(u8, CastNumber::Int, i128);
+impl_to_cast_number!(i16, CastNumber::Int, i128);
+impl_to_cast_number!(i32, CastNumber::Int, i128);
+impl_to_cast_number!(f32, CastNumber::Float, f64);
+impl_to_cast_number!(f64, CastNumber::Float, f64);
+impl_to_cast_number!(u16, CastNumber::Int, i128);
+impl_to_cast_number!(u32, CastNumber::Int, i128);
+impl_to_cast_number!(i64, CastNumber::Int, i128);
+impl_to_cast_number!(u64, CastNumber::Int, i128);
+
+/// Iterator over the elements of a `DataVector` converted into `f64` (see [`DataVector::iter_as_f64`](enum.DataVector.html#method.iter_as_f64)).
+enum IterAsF64<'a> {
+    I8(std::slice::Iter<'a, i8>),
+    U8(std::slice::Iter<'a, u8>),
+    I16(std::slice::Iter<'a, i16>),
+    I32(std::slice::Iter<'a, i32>),
+    F32(std::slice::Iter<'a, f32>),
+    F64(std::slice::Iter<'a, f64>),
+    U16(std::slice::Iter<'a, u16>),
+    U32(std::slice::Iter<'a, u32>),
+    I64(std::slice::Iter<'a, i64>),
+    U64(std::slice::Iter<'a, u64>),
+}
+
+impl<'a> Iterator for IterAsF64<'a> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        return match self {
+            IterAsF64::I8(iter) => iter.next().map(|value: &i8| *value as f64),
+            IterAsF64::U8(iter) => iter.next().map(|value: &u8| *value as f64),
+            IterAsF64::I16(iter) => iter.next().map(|value: &i16| *value as f64),
+            IterAsF64::I32(iter) => iter.next().map(|value: &i32| *value as f64),
+            IterAsF64::F32(iter) => iter.next().map(|value: &f32| *value as f64),
+            IterAsF64::F64(iter) => iter.next().copied(),
+            IterAsF64::U16(iter) => iter.next().map(|value: &u16| *value as f64),
+            IterAsF64::U32(iter) => iter.next().map(|value: &u32| *value as f64),
+            IterAsF64::I64(iter) => iter.next().map(|value: &i64| *value as f64),
+            IterAsF64::U64(iter) => iter.next().map(|value: &u64| *value as f64),
+        };
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return match self {
+            IterAsF64::I8(iter) => iter.size_hint(),
+            IterAsF64::U8(iter) => iter.size_hint(),
+            IterAsF64::I16(iter) => iter.size_hint(),
+            IterAsF64::I32(iter) => iter.size_hint(),
+            IterAsF64::F32(iter) => iter.size_hint(),
+            IterAsF64::F64(iter) => iter.size_hint(),
+            IterAsF64::U16(iter) => iter.size_hint(),
+            IterAsF64::U32(iter) => iter.size_hint(),
+            IterAsF64::I64(iter) => iter.size_hint(),
+            IterAsF64::U64(iter) => iter.size_hint(),
+        };
+    }
+}
+
+impl<'a> ExactSizeIterator for IterAsF64<'a> {}
+
+/// Wraps the NetCDF-3 data types.
 ///
 /// It allows to load variable data from files easily through the methods:
 /// - [FileReader::read_all_vars](struct.FileReader.html#method.read_all_vars).
@@ -46,6 +240,7 @@ use crate::DataType;
 /// assert_eq!(LATITUDE_VAR_DATA.to_vec(),      latitude);
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataVector {
     I8(Vec<i8>),
     U8(Vec<u8>),
@@ -53,6 +248,10 @@ pub enum DataVector {
     I32(Vec<i32>),
     F32(Vec<f32>),
     F64(Vec<f64>),
+    U16(Vec<u16>),
+    U32(Vec<u32>),
+    I64(Vec<i64>),
+    U64(Vec<u64>),
 }
 
 impl DataVector {
@@ -65,6 +264,10 @@ impl DataVector {
             DataType::I32 => DataVector::I32(vec![0; length]),
             DataType::F32 => DataVector::F32(vec![0.0; length]),
             DataType::F64 => DataVector::F64(vec![0.0; length]),
+            DataType::U16 => DataVector::U16(vec![0; length]),
+            DataType::U32 => DataVector::U32(vec![0; length]),
+            DataType::I64 => DataVector::I64(vec![0; length]),
+            DataType::U64 => DataVector::U64(vec![0; length]),
         }
     }
 
@@ -77,6 +280,10 @@ impl DataVector {
             DataVector::I32(_) => DataType::I32,
             DataVector::F32(_) => DataType::F32,
             DataVector::F64(_) => DataType::F64,
+            DataVector::U16(_) => DataType::U16,
+            DataVector::U32(_) => DataType::U32,
+            DataVector::I64(_) => DataType::I64,
+            DataVector::U64(_) => DataType::U64,
         }
     }
 
@@ -89,9 +296,210 @@ impl DataVector {
             DataVector::I32(data) => data.len(),
             DataVector::F32(data) => data.len(),
             DataVector::F64(data) => data.len(),
+            DataVector::U16(data) => data.len(),
+            DataVector::U32(data) => data.len(),
+            DataVector::I64(data) => data.len(),
+            DataVector::U64(data) => data.len(),
         }
     }
 
+    /// Returns the big-endian bytes of the elements, as they are stored in the NetCDF-3 files (without the padding bytes).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::DataVector;
+    ///
+    /// let data_vec = DataVector::I16(vec![1, -2, 0x0304]);
+    /// assert_eq!(vec![0x00, 0x01, 0xff, 0xfe, 0x03, 0x04],     data_vec.as_be_bytes());
+    /// // 2 padding bytes align the 6 bytes on 4 bytes
+    /// assert_eq!(8,                                           data_vec.size_bytes());
+    /// ```
+    pub fn as_be_bytes(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = vec![0_u8; self.len() * self.data_type().size_of()];
+        match self {
+            DataVector::I8(data) => encode_be_into(data, &mut bytes),
+            DataVector::U8(data) => bytes.copy_from_slice(data),
+            DataVector::I16(data) => encode_be_into(data, &mut bytes),
+            DataVector::I32(data) => encode_be_into(data, &mut bytes),
+            DataVector::F32(data) => encode_be_into(data, &mut bytes),
+            DataVector::F64(data) => encode_be_into(data, &mut bytes),
+            DataVector::U16(data) => encode_be_into(data, &mut bytes),
+            DataVector::U32(data) => encode_be_into(data, &mut bytes),
+            DataVector::I64(data) => encode_be_into(data, &mut bytes),
+            DataVector::U64(data) => encode_be_into(data, &mut bytes),
+        }
+        return bytes;
+    }
+
+    /// Returns the number of bytes of the elements encoded in a NetCDF-3 file, including the padding bytes which align them on 4 bytes
+    /// (e.g. the data of an attribute or of a *fixed-size* variable).
+    pub fn size_bytes(&self) -> usize {
+        let num_bytes: usize = self.len() * self.data_type().size_of();
+        return num_bytes + compute_padding_size(num_bytes);
+    }
+
+    /// Returns a copy of the elements converted into the `data_type`, with the conversions of the operator `as`.
+    ///
+    /// The floating-point numbers converted into integers are truncated and saturated (`NaN` is converted into `0`),
+    /// and the integers converted into smaller integers are wrapped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataVector, DataType};
+    ///
+    /// let data_vec = DataVector::F64(vec![1.5, -2.5, 1e6]);
+    ///
+    /// assert_eq!(DataVector::F32(vec![1.5, -2.5, 1e6]),       data_vec.cast(DataType::F32));
+    /// assert_eq!(DataVector::I16(vec![1, -2, 32_767]),        data_vec.cast(DataType::I16));
+    /// assert_eq!(DataVector::U8(vec![1, 0, 255]),             data_vec.cast(DataType::U8));
+    /// ```
+    pub fn cast(&self, data_type: DataType) -> DataVector {
+        return match self {
+            DataVector::I8(data) => cast_data!(data, data_type),
+            DataVector::U8(data) => cast_data!(data, data_type),
+            DataVector::I16(data) => cast_data!(data, data_type),
+            DataVector::I32(data) => cast_data!(data, data_type),
+            DataVector::F32(data) => cast_data!(data, data_type),
+            DataVector::F64(data) => cast_data!(data, data_type),
+            DataVector::U16(data) => cast_data!(data, data_type),
+            DataVector::U32(data) => cast_data!(data, data_type),
+            DataVector::I64(data) => cast_data!(data, data_type),
+            DataVector::U64(data) => cast_data!(data, data_type),
+        };
+    }
+
+    /// Returns a copy of the elements converted into the `data_type`, the values outside its range are handled by the `overflow` policy.
+    ///
+    /// The floating-point numbers converted into integers are truncated, and the integers converted into floating-point numbers may be rounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataVector, DataType, CastOverflow};
+    /// use netcdf3::error::CastError;
+    ///
+    /// let data_vec = DataVector::I32(vec![1, -2, 300]);
+    ///
+    /// assert_eq!(Ok(DataVector::U8(vec![1, 0, 255])),         data_vec.cast_to(DataType::U8, CastOverflow::Saturate));
+    /// assert_eq!(Ok(DataVector::U8(vec![1, 254, 44])),        data_vec.cast_to(DataType::U8, CastOverflow::Wrap));
+    /// assert_eq!(
+    ///     Err(CastError::ValueOutOfRange{index: 1, data_type: DataType::U8}),
+    ///     data_vec.cast_to(DataType::U8, CastOverflow::Error)
+    /// );
+    /// assert_eq!(Ok(DataVector::F64(vec![1.0, -2.0, 300.0])), data_vec.cast_to(DataType::F64, CastOverflow::Error));
+    /// ```
+    pub fn cast_to(&self, data_type: DataType, overflow: CastOverflow) -> Result<DataVector, CastError> {
+        if overflow == CastOverflow::Wrap {
+            return Ok(self.cast(data_type));
+        }
+        return Ok(match self {
+            DataVector::I8(data) => cast_checked_data!(data, data_type, overflow),
+            DataVector::U8(data) => cast_checked_data!(data, data_type, overflow),
+            DataVector::I16(data) => cast_checked_data!(data, data_type, overflow),
+            DataVector::I32(data) => cast_checked_data!(data, data_type, overflow),
+            DataVector::F32(data) => cast_checked_data!(data, data_type, overflow),
+            DataVector::F64(data) => cast_checked_data!(data, data_type, overflow),
+            DataVector::U16(data) => cast_checked_data!(data, data_type, overflow),
+            DataVector::U32(data) => cast_checked_data!(data, data_type, overflow),
+            DataVector::I64(data) => cast_checked_data!(data, data_type, overflow),
+            DataVector::U64(data) => cast_checked_data!(data, data_type, overflow),
+        });
+    }
+
+    /// Returns an iterator over the elements converted into `f64`, whatever the data type.
+    ///
+    /// The 64-bit integers larger than `2^53` may be rounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::DataVector;
+    ///
+    /// let data_vec = DataVector::I16(vec![1, -2, 300]);
+    /// assert_eq!(3,                                   data_vec.iter_as_f64().len());
+    /// assert_eq!(vec![1.0, -2.0, 300.0],              data_vec.iter_as_f64().collect::<Vec<f64>>());
+    /// assert_eq!(299.0,                               data_vec.iter_as_f64().sum::<f64>());
+    /// ```
+    pub fn iter_as_f64(&self) -> impl ExactSizeIterator<Item = f64> + '_ {
+        return match self {
+            DataVector::I8(data) => IterAsF64::I8(data.iter()),
+            DataVector::U8(data) => IterAsF64::U8(data.iter()),
+            DataVector::I16(data) => IterAsF64::I16(data.iter()),
+            DataVector::I32(data) => IterAsF64::I32(data.iter()),
+            DataVector::F32(data) => IterAsF64::F32(data.iter()),
+            DataVector::F64(data) => IterAsF64::F64(data.iter()),
+            DataVector::U16(data) => IterAsF64::U16(data.iter()),
+            DataVector::U32(data) => IterAsF64::U32(data.iter()),
+            DataVector::I64(data) => IterAsF64::I64(data.iter()),
+            DataVector::U64(data) => IterAsF64::U64(data.iter()),
+        };
+    }
+
+    /// Returns the smallest element converted into `f64`, the `NaN` values are ignored.
+    ///
+    /// Returns `None` if there is no element other than `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::DataVector;
+    ///
+    /// let data_vec = DataVector::F32(vec![2.0, std::f32::NAN, -1.0, 4.0, -999.0]);
+    /// assert_eq!(Some(-999.0),                        data_vec.min());
+    /// assert_eq!(Some(4.0),                           data_vec.max());
+    /// assert_eq!(Some(-994.0),                        data_vec.sum());
+    /// assert_eq!(Some(-248.5),                        data_vec.mean());
+    /// assert_eq!(1,                                   data_vec.count_fill(-999.0));
+    ///
+    /// assert_eq!(None,                                DataVector::I32(vec![]).min());
+    /// assert_eq!(None,                                DataVector::I32(vec![]).mean());
+    /// ```
+    pub fn min(&self) -> Option<f64> {
+        return self.iter_as_f64()
+            .filter(|value: &f64| !value.is_nan())
+            .fold(None, |min: Option<f64>, value: f64| Some(min.map_or(value, |min: f64| min.min(value))));
+    }
+
+    /// Returns the largest element converted into `f64`, the `NaN` values are ignored (see the method `min`).
+    pub fn max(&self) -> Option<f64> {
+        return self.iter_as_f64()
+            .filter(|value: &f64| !value.is_nan())
+            .fold(None, |max: Option<f64>, value: f64| Some(max.map_or(value, |max: f64| max.max(value))));
+    }
+
+    /// Returns the sum of the elements converted into `f64`, the `NaN` values are ignored (see the method `min`).
+    ///
+    /// Returns `None` if there is no element other than `NaN`.
+    pub fn sum(&self) -> Option<f64> {
+        return self.iter_as_f64()
+            .filter(|value: &f64| !value.is_nan())
+            .fold(None, |sum: Option<f64>, value: f64| Some(sum.unwrap_or(0.0) + value));
+    }
+
+    /// Returns the arithmetic mean of the elements converted into `f64`, the `NaN` values are ignored (see the method `min`).
+    ///
+    /// Returns `None` if there is no element other than `NaN`.
+    pub fn mean(&self) -> Option<f64> {
+        let (sum, count): (f64, usize) = self.iter_as_f64()
+            .filter(|value: &f64| !value.is_nan())
+            .fold((0.0, 0), |(sum, count): (f64, usize), value: f64| (sum + value, count + 1));
+        if count == 0 {
+            return None;
+        }
+        return Some(sum / (count as f64));
+    }
+
+    /// Returns the number of elements equal to the `fill_value`, compared as `f64` (see the method `min`).
+    ///
+    /// The `NaN` values are counted if the `fill_value` is `NaN`.
+    pub fn count_fill(&self, fill_value: f64) -> usize {
+        return self.iter_as_f64()
+            .filter(|value: &f64| *value == fill_value || (value.is_nan() && fill_value.is_nan()))
+            .count();
+    }
+
     /// Returns a slice to the internal `Vec<i8>`.
     ///
     /// # Example
@@ -118,6 +526,10 @@ impl DataVector {
             DataVector::I32(_) => None,
             DataVector::F32(_) => None,
             DataVector::F64(_) => None,
+            DataVector::U16(_) => None,
+            DataVector::U32(_) => None,
+            DataVector::I64(_) => None,
+            DataVector::U64(_) => None,
         };
     }
 
@@ -132,6 +544,10 @@ impl DataVector {
             DataVector::I32(_) => None,
             DataVector::F32(_) => None,
             DataVector::F64(_) => None,
+            DataVector::U16(_) => None,
+            DataVector::U32(_) => None,
+            DataVector::I64(_) => None,
+            DataVector::U64(_) => None,
         };
     }
 
@@ -143,6 +559,26 @@ impl DataVector {
             DataVector::I32(_) => None,
             DataVector::F32(_) => None,
             DataVector::F64(_) => None,
+            DataVector::U16(_) => None,
+            DataVector::U32(_) => None,
+            DataVector::I64(_) => None,
+            DataVector::U64(_) => None,
+        };
+    }
+
+    /// Returns the values converted into `f64`.
+    pub(crate) fn get_as_f64(&self) -> Vec<f64> {
+        return match self {
+            DataVector::I8(data) => data.iter().map(|value: &i8| *value as f64).collect(),
+            DataVector::U8(data) => data.iter().map(|value: &u8| *value as f64).collect(),
+            DataVector::I16(data) => data.iter().map(|value: &i16| *value as f64).collect(),
+            DataVector::I32(data) => data.iter().map(|value: &i32| *value as f64).collect(),
+            DataVector::F32(data) => data.iter().map(|value: &f32| *value as f64).collect(),
+            DataVector::F64(data) => data.clone(),
+            DataVector::U16(data) => data.iter().map(|value: &u16| *value as f64).collect(),
+            DataVector::U32(data) => data.iter().map(|value: &u32| *value as f64).collect(),
+            DataVector::I64(data) => data.iter().map(|value: &i64| *value as f64).collect(),
+            DataVector::U64(data) => data.iter().map(|value: &u64| *value as f64).collect(),
         };
     }
 
@@ -157,6 +593,10 @@ impl DataVector {
             DataVector::I32(_) => None,
             DataVector::F32(_) => None,
             DataVector::F64(_) => None,
+            DataVector::U16(_) => None,
+            DataVector::U32(_) => None,
+            DataVector::I64(_) => None,
+            DataVector::U64(_) => None,
         };
     }
 
@@ -171,6 +611,10 @@ impl DataVector {
             DataVector::I32(data) => Some(data),
             DataVector::F32(_) => None,
             DataVector::F64(_) => None,
+            DataVector::U16(_) => None,
+            DataVector::U32(_) => None,
+            DataVector::I64(_) => None,
+            DataVector::U64(_) => None,
         };
     }
 
@@ -185,6 +629,10 @@ impl DataVector {
             DataVector::I32(_) => None,
             DataVector::F32(data) => Some(data),
             DataVector::F64(_) => None,
+            DataVector::U16(_) => None,
+            DataVector::U32(_) => None,
+            DataVector::I64(_) => None,
+            DataVector::U64(_) => None,
         };
     }
 
@@ -199,6 +647,82 @@ impl DataVector {
             DataVector::I32(_) => None,
             DataVector::F32(_) => None,
             DataVector::F64(data) => Some(data),
+            DataVector::U16(_) => None,
+            DataVector::U32(_) => None,
+            DataVector::I64(_) => None,
+            DataVector::U64(_) => None,
+        };
+    }
+
+    /// Returns a slice to the internal `Vec<u16>`.
+    ///
+    /// Also see the method [get_i8](enum.DataVector.html#method.get_i8).
+    pub fn get_u16(&self) -> Option<&[u16]> {
+        return match self {
+            DataVector::I8(_) => None,
+            DataVector::U8(_) => None,
+            DataVector::I16(_) => None,
+            DataVector::I32(_) => None,
+            DataVector::F32(_) => None,
+            DataVector::F64(_) => None,
+            DataVector::U16(data) => Some(data),
+            DataVector::U32(_) => None,
+            DataVector::I64(_) => None,
+            DataVector::U64(_) => None,
+        };
+    }
+
+    /// Returns a slice to the internal `Vec<u32>`.
+    ///
+    /// Also see the method [get_i8](enum.DataVector.html#method.get_i8).
+    pub fn get_u32(&self) -> Option<&[u32]> {
+        return match self {
+            DataVector::I8(_) => None,
+            DataVector::U8(_) => None,
+            DataVector::I16(_) => None,
+            DataVector::I32(_) => None,
+            DataVector::F32(_) => None,
+            DataVector::F64(_) => None,
+            DataVector::U16(_) => None,
+            DataVector::U32(data) => Some(data),
+            DataVector::I64(_) => None,
+            DataVector::U64(_) => None,
+        };
+    }
+
+    /// Returns a slice to the internal `Vec<i64>`.
+    ///
+    /// Also see the method [get_i8](enum.DataVector.html#method.get_i8).
+    pub fn get_i64(&self) -> Option<&[i64]> {
+        return match self {
+            DataVector::I8(_) => None,
+            DataVector::U8(_) => None,
+            DataVector::I16(_) => None,
+            DataVector::I32(_) => None,
+            DataVector::F32(_) => None,
+            DataVector::F64(_) => None,
+            DataVector::U16(_) => None,
+            DataVector::U32(_) => None,
+            DataVector::I64(data) => Some(data),
+            DataVector::U64(_) => None,
+        };
+    }
+
+    /// Returns a slice to the internal `Vec<u64>`.
+    ///
+    /// Also see the method [get_i8](enum.DataVector.html#method.get_i8).
+    pub fn get_u64(&self) -> Option<&[u64]> {
+        return match self {
+            DataVector::I8(_) => None,
+            DataVector::U8(_) => None,
+            DataVector::I16(_) => None,
+            DataVector::I32(_) => None,
+            DataVector::F32(_) => None,
+            DataVector::F64(_) => None,
+            DataVector::U16(_) => None,
+            DataVector::U32(_) => None,
+            DataVector::I64(_) => None,
+            DataVector::U64(data) => Some(data),
         };
     }
 
@@ -275,4 +799,63 @@ impl DataVector {
         }
         return Err(self);
     }
+
+    pub fn get_u16_into(self) -> Result<Vec<u16>, DataVector> {
+        if let DataVector::U16(data) = self {
+            return Ok(data);
+        }
+        return Err(self);
+    }
+
+    pub fn get_u32_into(self) -> Result<Vec<u32>, DataVector> {
+        if let DataVector::U32(data) = self {
+            return Ok(data);
+        }
+        return Err(self);
+    }
+
+    pub fn get_i64_into(self) -> Result<Vec<i64>, DataVector> {
+        if let DataVector::I64(data) = self {
+            return Ok(data);
+        }
+        return Err(self);
+    }
+
+    pub fn get_u64_into(self) -> Result<Vec<u64>, DataVector> {
+        if let DataVector::U64(data) = self {
+            return Ok(data);
+        }
+        return Err(self);
+    }
+}
+
+/// Wraps a typed `Vec` into a `DataVector` (see the trait [NcType](trait.NcType.html)).
+///
+/// # Example
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use netcdf3::DataVector;
+///
+/// let data_vec: DataVector = DataVector::from(vec![1_i16, 2, 3]);
+/// assert_eq!(DataVector::I16(vec![1, 2, 3]),      data_vec);
+///
+/// let data_vec: DataVector = vec![0.5_f32, 1.0].into();
+/// assert_eq!(Ok(vec![0.5_f32, 1.0]),              Vec::<f32>::try_from(data_vec.clone()));
+/// // The data type does not match, the `DataVector` is returned
+/// assert_eq!(Err(data_vec.clone()),               Vec::<f64>::try_from(data_vec));
+/// ```
+impl<T: NcType> From<Vec<T>> for DataVector {
+    fn from(data: Vec<T>) -> Self {
+        return T::into_data_vector(data);
+    }
+}
+
+/// Unwraps the typed `Vec` of a `DataVector` without copying it, the `DataVector` is returned if its data type does not match.
+impl<T: NcType> TryFrom<DataVector> for Vec<T> {
+    type Error = DataVector;
+
+    fn try_from(data: DataVector) -> Result<Self, Self::Error> {
+        return T::from_data_vector_into(data);
+    }
 }
@@ -0,0 +1,69 @@
+use nalgebra::{DMatrix, DVector, Scalar};
+
+use crate::{DataVector, NcType};
+
+impl DataVector {
+
+    /// Converts the data of a 1-D or 2-D variable into a matrix of the crate [nalgebra](https://nalgebra.org) (optional feature `nalgebra`).
+    ///
+    /// The elements of the 2-D variables are in the row-major order, the rows being the first dimension. The 1-D variables
+    /// become column matrices. The `DataVector` is returned if its data type is not the one of `T`, if the `shape` has not
+    /// 1 or 2 dimensions, or if its length is not the product of the `shape`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nalgebra::DMatrix;
+    /// use netcdf3::{DataSet, DataVector};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 2).unwrap();
+    /// data_set.add_fixed_dim("longitude", 3).unwrap();
+    /// data_set.add_var_f64("sst", &["latitude", "longitude"]).unwrap();
+    /// let shape: Vec<usize> = data_set.get_var("sst").unwrap().shape();
+    ///
+    /// let data_vec = DataVector::F64(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// let matrix: DMatrix<f64> = data_vec.into_dmatrix(&shape).unwrap();
+    /// assert_eq!((2, 3),                                          matrix.shape());
+    /// assert_eq!(6.0,                                             matrix[(1, 2)]);
+    ///
+    /// // And the reverse
+    /// assert_eq!(DataVector::F64(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),  DataVector::from(matrix));
+    /// ```
+    pub fn into_dmatrix<T: NcType + Scalar>(self, shape: &[usize]) -> Result<DMatrix<T>, DataVector> {
+        let (num_rows, num_cols): (usize, usize) = match *shape {
+            [num_rows] => (num_rows, 1),
+            [num_rows, num_cols] => (num_rows, num_cols),
+            _ => return Err(self),
+        };
+        if num_rows.checked_mul(num_cols) != Some(self.len()) {
+            return Err(self);
+        }
+        let data: Vec<T> = T::from_data_vector_into(self)?;
+        return Ok(DMatrix::from_row_slice(num_rows, num_cols, &data));
+    }
+
+    /// Converts the data into a column vector of the crate [nalgebra](https://nalgebra.org) (optional feature `nalgebra`).
+    ///
+    /// The data are not copied. The `DataVector` is returned if its data type is not the one of `T`.
+    pub fn into_dvector<T: NcType + Scalar>(self) -> Result<DVector<T>, DataVector> {
+        let data: Vec<T> = T::from_data_vector_into(self)?;
+        return Ok(DVector::from_vec(data));
+    }
+}
+
+/// Flattens a matrix of the crate [nalgebra](https://nalgebra.org) in the row-major order (optional feature `nalgebra`).
+impl<T: NcType + Scalar> From<DMatrix<T>> for DataVector {
+    fn from(matrix: DMatrix<T>) -> Self {
+        // nalgebra stores the matrices in the column-major order
+        let data: Vec<T> = matrix.transpose().data.into();
+        return T::into_data_vector(data);
+    }
+}
+
+/// Converts a column vector of the crate [nalgebra](https://nalgebra.org) without copying its elements (optional feature `nalgebra`).
+impl<T: NcType + Scalar> From<DVector<T>> for DataVector {
+    fn from(vector: DVector<T>) -> Self {
+        return T::into_data_vector(vector.data.into());
+    }
+}
@@ -0,0 +1,54 @@
+use ndarray::{Array, ArrayD, Dimension, IxDyn};
+
+use crate::{DataVector, NcType};
+
+impl DataVector {
+
+    /// Converts the data into a n-dimensional array of the crate [ndarray](https://github.com/rust-ndarray/ndarray) (optional feature `ndarray`),
+    /// the elements being in the row-major order (like the variables of the NetCDF-3 files).
+    ///
+    /// The data are not copied. The `DataVector` is returned if its data type is not the one of `T`,
+    /// or if its length is not the product of the `shape`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{ArrayD, IxDyn};
+    /// use netcdf3::{DataSet, DataVector};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 2).unwrap();
+    /// data_set.add_fixed_dim("longitude", 3).unwrap();
+    /// data_set.add_var_i16("mask", &["latitude", "longitude"]).unwrap();
+    /// let shape: Vec<usize> = data_set.get_var("mask").unwrap().shape();
+    ///
+    /// let data_vec = DataVector::I16(vec![1, 2, 3, 4, 5, 6]);
+    /// let array: ArrayD<i16> = data_vec.into_ndarray(&shape).unwrap();
+    /// assert_eq!(&[2, 3],                             array.shape());
+    /// assert_eq!(6,                                   array[IxDyn(&[1, 2])]);
+    ///
+    /// // And the reverse
+    /// assert_eq!(DataVector::I16(vec![1, 2, 3, 4, 5, 6]), DataVector::from(array));
+    /// ```
+    pub fn into_ndarray<T: NcType>(self, shape: &[usize]) -> Result<ArrayD<T>, DataVector> {
+        let shape: IxDyn = IxDyn(shape);
+        if shape.size_checked() != Some(self.len()) {
+            return Err(self);
+        }
+        let data: Vec<T> = T::from_data_vector_into(self)?;
+        return ArrayD::from_shape_vec(shape, data).map_err(|_err| T::into_data_vector(vec![]));  // previously checked
+    }
+}
+
+/// Flattens a n-dimensional array of the crate [ndarray](https://github.com/rust-ndarray/ndarray) in the row-major order (optional feature `ndarray`).
+///
+/// Only the elements of the array are copied, not the whole buffer of the sliced arrays.
+impl<T: NcType, D: Dimension> From<Array<T, D>> for DataVector {
+    fn from(array: Array<T, D>) -> Self {
+        let data: Vec<T> = match array.as_slice() {
+            Some(elements) => elements.to_vec(),
+            None => array.iter().copied().collect(),
+        };
+        return T::into_data_vector(data);
+    }
+}
@@ -1,7 +1,10 @@
 #![cfg(test)]
 
-use super::DataVector;
+use std::convert::{TryFrom, TryInto};
+
+use super::{DataVector, CastOverflow};
 use crate::DataType;
+use crate::error::CastError;
 
 #[test]
 fn test_get_data_type() {
@@ -416,3 +419,263 @@ fn test_equality_operator() {
         assert_ne!(data_f32, data_f64);
     }
 }
+
+#[test]
+fn test_cdf5_data_vectors() {
+    assert_eq!(DataType::U16, DataVector::U16(vec![]).data_type());
+    assert_eq!(DataType::U32, DataVector::U32(vec![]).data_type());
+    assert_eq!(DataType::I64, DataVector::I64(vec![]).data_type());
+    assert_eq!(DataType::U64, DataVector::U64(vec![]).data_type());
+
+    assert_eq!(3, DataVector::U16(vec![1, 2, 3]).len());
+    assert_eq!(3, DataVector::U32(vec![1, 2, 3]).len());
+    assert_eq!(3, DataVector::I64(vec![1, 2, 3]).len());
+    assert_eq!(3, DataVector::U64(vec![1, 2, 3]).len());
+
+    assert_eq!(Some(&[1, 2, 3][..]),        DataVector::U16(vec![1, 2, 3]).get_u16());
+    assert_eq!(None,                        DataVector::I16(vec![1, 2, 3]).get_u16());
+    assert_eq!(Some(&[1, 2, 3][..]),        DataVector::U32(vec![1, 2, 3]).get_u32());
+    assert_eq!(None,                        DataVector::I32(vec![1, 2, 3]).get_u32());
+    assert_eq!(Some(&[1, 2, 3][..]),        DataVector::I64(vec![1, 2, 3]).get_i64());
+    assert_eq!(None,                        DataVector::U64(vec![1, 2, 3]).get_i64());
+    assert_eq!(Some(&[1, 2, 3][..]),        DataVector::U64(vec![1, 2, 3]).get_u64());
+    assert_eq!(None,                        DataVector::I64(vec![1, 2, 3]).get_u64());
+    assert_eq!(None,                        DataVector::U64(vec![1, 2, 3]).get_i8());
+    assert_eq!(None,                        DataVector::U16(vec![1, 2, 3]).get_f64());
+
+    assert_eq!(Ok(vec![1_u16, 2, 3]),       DataVector::U16(vec![1, 2, 3]).get_u16_into());
+    assert_eq!(Ok(vec![1_u32, 2, 3]),       DataVector::U32(vec![1, 2, 3]).get_u32_into());
+    assert_eq!(Ok(vec![1_i64, 2, 3]),       DataVector::I64(vec![1, 2, 3]).get_i64_into());
+    assert_eq!(Ok(vec![1_u64, 2, 3]),       DataVector::U64(vec![1, 2, 3]).get_u64_into());
+    assert_eq!(Err(DataVector::U32(vec![1, 2, 3])),     DataVector::U32(vec![1, 2, 3]).get_u16_into());
+}
+
+#[test]
+fn test_cast() {
+    let data_vec = DataVector::I32(vec![-1, 0, 300]);
+    assert_eq!(DataVector::I32(vec![-1, 0, 300]),       data_vec.cast(DataType::I32));
+    assert_eq!(DataVector::I8(vec![-1, 0, 44]),         data_vec.cast(DataType::I8));
+    assert_eq!(DataVector::U8(vec![255, 0, 44]),        data_vec.cast(DataType::U8));
+    assert_eq!(DataVector::F64(vec![-1.0, 0.0, 300.0]), data_vec.cast(DataType::F64));
+    assert_eq!(DataVector::U64(vec![std::u64::MAX, 0, 300]), data_vec.cast(DataType::U64));
+
+    let data_vec = DataVector::F32(vec![-1.7, std::f32::NAN, 1e10]);
+    assert_eq!(DataVector::I16(vec![-1, 0, std::i16::MAX]),  data_vec.cast(DataType::I16));
+    assert_eq!(DataVector::U32(vec![0, 0, std::u32::MAX]),   data_vec.cast(DataType::U32));
+    assert_eq!(DataVector::I64(vec![-1, 0, 10_000_000_000]), data_vec.cast(DataType::I64));
+
+    assert_eq!(DataVector::F32(vec![]),                 DataVector::U16(vec![]).cast(DataType::F32));
+}
+
+#[test]
+fn test_cast_to() {
+    let data_vec = DataVector::I32(vec![-1, 0, 300]);
+    assert_eq!(Ok(DataVector::I8(vec![-1, 0, 127])),    data_vec.cast_to(DataType::I8, CastOverflow::Saturate));
+    assert_eq!(Ok(DataVector::U8(vec![0, 0, 255])),     data_vec.cast_to(DataType::U8, CastOverflow::Saturate));
+    assert_eq!(Ok(DataVector::I8(vec![-1, 0, 44])),     data_vec.cast_to(DataType::I8, CastOverflow::Wrap));
+    assert_eq!(Ok(DataVector::I16(vec![-1, 0, 300])),   data_vec.cast_to(DataType::I16, CastOverflow::Error));
+    assert_eq!(
+        Err(CastError::ValueOutOfRange{index: 2, data_type: DataType::I8}),
+        data_vec.cast_to(DataType::I8, CastOverflow::Error)
+    );
+    assert_eq!(
+        Err(CastError::ValueOutOfRange{index: 0, data_type: DataType::U64}),
+        data_vec.cast_to(DataType::U64, CastOverflow::Error)
+    );
+
+    // The bounds of the 64-bit integers
+    let data_vec = DataVector::U64(vec![std::u64::MAX, 0]);
+    assert_eq!(Ok(DataVector::I64(vec![std::i64::MAX, 0])), data_vec.cast_to(DataType::I64, CastOverflow::Saturate));
+    assert_eq!(Ok(DataVector::I64(vec![-1, 0])),            data_vec.cast_to(DataType::I64, CastOverflow::Wrap));
+    assert_eq!(Ok(DataVector::U64(vec![std::u64::MAX, 0])), DataVector::I64(vec![-1, 0]).cast_to(DataType::U64, CastOverflow::Wrap));
+
+    // The floating-point numbers are truncated, `NaN` is only converted when saturated
+    let data_vec = DataVector::F64(vec![-1.7, std::f64::NAN, 1e10, std::f64::INFINITY]);
+    assert_eq!(Ok(DataVector::I16(vec![-1, 0, std::i16::MAX, std::i16::MAX])), data_vec.cast_to(DataType::I16, CastOverflow::Saturate));
+    assert_eq!(Ok(DataVector::U32(vec![0, 0, std::u32::MAX, std::u32::MAX])), data_vec.cast_to(DataType::U32, CastOverflow::Saturate));
+    assert_eq!(
+        Err(CastError::ValueOutOfRange{index: 1, data_type: DataType::I64}),
+        data_vec.cast_to(DataType::I64, CastOverflow::Error)
+    );
+    assert_eq!(
+        Ok(DataVector::I64(vec![-1, 10_000_000_000])),
+        DataVector::F64(vec![-1.7, 1e10]).cast_to(DataType::I64, CastOverflow::Error)
+    );
+
+    // The finite values outside the range of `f32` (the infinities and `NaN` are kept)
+    let data_vec = DataVector::F64(vec![1e300, -1e300, std::f64::NEG_INFINITY, 0.5]);
+    assert_eq!(Ok(DataVector::F32(vec![std::f32::MAX, std::f32::MIN, std::f32::NEG_INFINITY, 0.5])), data_vec.cast_to(DataType::F32, CastOverflow::Saturate));
+    assert_eq!(Ok(DataVector::F32(vec![std::f32::INFINITY, std::f32::NEG_INFINITY, std::f32::NEG_INFINITY, 0.5])), data_vec.cast_to(DataType::F32, CastOverflow::Wrap));
+    assert_eq!(
+        Err(CastError::ValueOutOfRange{index: 0, data_type: DataType::F32}),
+        data_vec.cast_to(DataType::F32, CastOverflow::Error)
+    );
+    assert_eq!(Ok(DataVector::F64(vec![std::u64::MAX as f64])), DataVector::U64(vec![std::u64::MAX]).cast_to(DataType::F64, CastOverflow::Error));
+
+    assert_eq!(Ok(DataVector::U8(vec![])),              DataVector::F32(vec![]).cast_to(DataType::U8, CastOverflow::Error));
+}
+
+#[test]
+fn test_from_and_try_from_vec() {
+    assert_eq!(DataVector::I8(vec![1, 2]),              DataVector::from(vec![1_i8, 2]));
+    assert_eq!(DataVector::U8(vec![1, 2]),              DataVector::from(vec![1_u8, 2]));
+    assert_eq!(DataVector::I16(vec![1, 2]),             DataVector::from(vec![1_i16, 2]));
+    assert_eq!(DataVector::I32(vec![1, 2]),             DataVector::from(vec![1_i32, 2]));
+    assert_eq!(DataVector::F32(vec![1.0, 2.0]),         DataVector::from(vec![1.0_f32, 2.0]));
+    assert_eq!(DataVector::F64(vec![1.0, 2.0]),         DataVector::from(vec![1.0_f64, 2.0]));
+    assert_eq!(DataVector::U16(vec![1, 2]),             DataVector::from(vec![1_u16, 2]));
+    assert_eq!(DataVector::U32(vec![1, 2]),             DataVector::from(vec![1_u32, 2]));
+    assert_eq!(DataVector::I64(vec![1, 2]),             DataVector::from(vec![1_i64, 2]));
+    assert_eq!(DataVector::U64(vec![1, 2]),             DataVector::from(vec![1_u64, 2]));
+
+    let data_vec: DataVector = vec![1_i32, 2].into();
+    assert_eq!(Ok(vec![1_i32, 2]),                      Vec::<i32>::try_from(data_vec.clone()));
+    assert_eq!(Err(data_vec.clone()),                   Vec::<i8>::try_from(data_vec.clone()));
+    assert_eq!(Err(data_vec.clone()),                   Vec::<u32>::try_from(data_vec.clone()));
+    assert_eq!(Err(data_vec.clone()),                   Vec::<f64>::try_from(data_vec.clone()));
+
+    let data: Result<Vec<u64>, DataVector> = DataVector::U64(vec![]).try_into();
+    assert_eq!(Ok(vec![]),                              data);
+}
+
+#[test]
+fn test_iter_as_f64() {
+    assert_eq!(vec![-1.0, 2.0],                         DataVector::I8(vec![-1, 2]).iter_as_f64().collect::<Vec<f64>>());
+    assert_eq!(vec![255.0, 2.0],                        DataVector::U8(vec![255, 2]).iter_as_f64().collect::<Vec<f64>>());
+    assert_eq!(vec![-1.0, 2.0],                         DataVector::I16(vec![-1, 2]).iter_as_f64().collect::<Vec<f64>>());
+    assert_eq!(vec![-1.0, 2.0],                         DataVector::I32(vec![-1, 2]).iter_as_f64().collect::<Vec<f64>>());
+    assert_eq!(vec![-1.5, 2.0],                         DataVector::F32(vec![-1.5, 2.0]).iter_as_f64().collect::<Vec<f64>>());
+    assert_eq!(vec![-1.5, 2.0],                         DataVector::F64(vec![-1.5, 2.0]).iter_as_f64().collect::<Vec<f64>>());
+    assert_eq!(vec![65_535.0, 2.0],                     DataVector::U16(vec![65_535, 2]).iter_as_f64().collect::<Vec<f64>>());
+    assert_eq!(vec![4_294_967_295.0, 2.0],              DataVector::U32(vec![4_294_967_295, 2]).iter_as_f64().collect::<Vec<f64>>());
+    assert_eq!(vec![-1.0, 2.0],                         DataVector::I64(vec![-1, 2]).iter_as_f64().collect::<Vec<f64>>());
+    assert_eq!(vec![std::u64::MAX as f64, 2.0],         DataVector::U64(vec![std::u64::MAX, 2]).iter_as_f64().collect::<Vec<f64>>());
+
+    let data_vec = DataVector::F32(vec![std::f32::NAN]);
+    assert_eq!(true,                                    data_vec.iter_as_f64().next().unwrap().is_nan());
+
+    let data_vec = DataVector::I32(vec![1, 2, 3]);
+    let mut iter = data_vec.iter_as_f64();
+    assert_eq!(3,                                       iter.len());
+    assert_eq!(Some(1.0),                               iter.next());
+    assert_eq!(2,                                       iter.len());
+    assert_eq!(0,                                       DataVector::U8(vec![]).iter_as_f64().len());
+}
+
+#[test]
+fn test_statistics() {
+    let data_vec = DataVector::I16(vec![3, -2, 7, -32_767, 0]);
+    assert_eq!(Some(-32_767.0),                         data_vec.min());
+    assert_eq!(Some(7.0),                               data_vec.max());
+    assert_eq!(Some(-32_759.0),                         data_vec.sum());
+    assert_eq!(Some(-6_551.8),                          data_vec.mean());
+    assert_eq!(1,                                       data_vec.count_fill(-32_767.0));
+    assert_eq!(0,                                       data_vec.count_fill(1.0));
+
+    // The `NaN` values are ignored
+    let data_vec = DataVector::F64(vec![std::f64::NAN, 1.5, std::f64::NAN, -0.5]);
+    assert_eq!(Some(-0.5),                              data_vec.min());
+    assert_eq!(Some(1.5),                               data_vec.max());
+    assert_eq!(Some(1.0),                               data_vec.sum());
+    assert_eq!(Some(0.5),                               data_vec.mean());
+    assert_eq!(2,                                       data_vec.count_fill(std::f64::NAN));
+
+    let data_vec = DataVector::F32(vec![std::f32::NAN]);
+    assert_eq!(None,                                    data_vec.min());
+    assert_eq!(None,                                    data_vec.max());
+    assert_eq!(None,                                    data_vec.sum());
+    assert_eq!(None,                                    data_vec.mean());
+
+    let data_vec = DataVector::U64(vec![]);
+    assert_eq!(None,                                    data_vec.min());
+    assert_eq!(None,                                    data_vec.max());
+    assert_eq!(None,                                    data_vec.sum());
+    assert_eq!(None,                                    data_vec.mean());
+    assert_eq!(0,                                       data_vec.count_fill(0.0));
+
+    let data_vec = DataVector::U8(vec![255, 1]);
+    assert_eq!(Some(1.0),                               data_vec.min());
+    assert_eq!(Some(255.0),                             data_vec.max());
+    assert_eq!(Some(128.0),                             data_vec.mean());
+}
+
+#[test]
+fn test_as_be_bytes_and_size_bytes() {
+    assert_eq!(vec![0x81_u8],                           DataVector::I8(vec![-127]).as_be_bytes());
+    assert_eq!(4,                                       DataVector::I8(vec![-127]).size_bytes());
+    assert_eq!(b"abcde".to_vec(),                       DataVector::U8(b"abcde".to_vec()).as_be_bytes());
+    assert_eq!(8,                                       DataVector::U8(b"abcde".to_vec()).size_bytes());
+    assert_eq!(vec![0x3f, 0xf8, 0, 0, 0, 0, 0, 0],      DataVector::F64(vec![1.5]).as_be_bytes());
+    assert_eq!(8,                                       DataVector::F64(vec![1.5]).size_bytes());
+    assert_eq!(vec![0, 0, 0, 0, 0, 0, 0, 1],            DataVector::U64(vec![1]).as_be_bytes());
+    assert_eq!(Vec::<u8>::new(),                        DataVector::I32(vec![]).as_be_bytes());
+    assert_eq!(0,                                       DataVector::I32(vec![]).size_bytes());
+
+    // Same bytes as the ones written by `FileWriter`
+    let data_vec = DataVector::U16(vec![1, 2, 0xfffe]);
+    let mut written_bytes: Vec<u8> = vec![];
+    crate::FileWriter::write_data_vector_range(&mut written_bytes, &data_vec, 0, data_vec.len()).unwrap();
+    assert_eq!(written_bytes,                           data_vec.as_be_bytes());
+    assert_eq!(8,                                       data_vec.size_bytes());
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn test_ndarray_conversions() {
+    use ndarray::{arr2, s, Array1, ArrayD, IxDyn};
+
+    let data_vec = DataVector::F32(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let array: ArrayD<f32> = data_vec.clone().into_ndarray(&[3, 2]).unwrap();
+    assert_eq!(&[3, 2],                                 array.shape());
+    assert_eq!(4.0,                                     array[IxDyn(&[1, 1])]);
+    assert_eq!(data_vec,                                DataVector::from(array));
+
+    // The scalar variables
+    let array: ArrayD<u8> = DataVector::U8(vec![7]).into_ndarray(&[]).unwrap();
+    assert_eq!(0,                                       array.ndim());
+    assert_eq!(DataVector::U8(vec![7]),                 DataVector::from(array));
+
+    // The data type or the shape do not match
+    assert_eq!(Err(data_vec.clone()),                   data_vec.clone().into_ndarray::<f64>(&[3, 2]));
+    assert_eq!(Err(data_vec.clone()),                   data_vec.clone().into_ndarray::<f32>(&[4, 2]));
+    assert_eq!(Err(data_vec.clone()),                   data_vec.clone().into_ndarray::<f32>(&[std::usize::MAX, 2]));
+
+    // The arrays are flattened in the row-major order, whatever their memory layout
+    let array = arr2(&[[1_i32, 2, 3], [4, 5, 6]]);
+    assert_eq!(DataVector::I32(vec![1, 2, 3, 4, 5, 6]), DataVector::from(array.clone()));
+    assert_eq!(DataVector::I32(vec![1, 4, 2, 5, 3, 6]), DataVector::from(array.clone().reversed_axes()));
+
+    // The sliced arrays keep the whole buffer, only their elements are converted
+    assert_eq!(DataVector::I32(vec![2, 3]),             DataVector::from(Array1::from(vec![1_i32, 2, 3]).slice_move(s![1..])));
+    assert_eq!(DataVector::I32(vec![4, 5, 6]),          DataVector::from(array.clone().slice_move(s![1.., ..])));
+    assert_eq!(DataVector::I32(vec![2, 5]),             DataVector::from(array.slice_move(s![.., 1..2])));
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn test_nalgebra_conversions() {
+    use nalgebra::{DMatrix, DVector};
+
+    let data_vec = DataVector::I32(vec![1, 2, 3, 4, 5, 6]);
+    let matrix: DMatrix<i32> = data_vec.clone().into_dmatrix(&[2, 3]).unwrap();
+    assert_eq!((2, 3),                                  matrix.shape());
+    assert_eq!(DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]),  matrix);
+    assert_eq!(data_vec,                                DataVector::from(matrix.clone()));
+    assert_eq!(DataVector::I32(vec![1, 4, 2, 5, 3, 6]), DataVector::from(matrix.transpose()));
+
+    // The 1-D variables
+    let matrix: DMatrix<i32> = data_vec.clone().into_dmatrix(&[6]).unwrap();
+    assert_eq!((6, 1),                                  matrix.shape());
+    assert_eq!(data_vec,                                DataVector::from(matrix));
+    let vector: DVector<i32> = data_vec.clone().into_dvector().unwrap();
+    assert_eq!(DVector::from_vec(vec![1, 2, 3, 4, 5, 6]),  vector);
+    assert_eq!(data_vec,                                DataVector::from(vector));
+
+    // The data type or the shape do not match
+    assert_eq!(Err(data_vec.clone()),                   data_vec.clone().into_dmatrix::<f64>(&[2, 3]));
+    assert_eq!(Err(data_vec.clone()),                   data_vec.clone().into_dmatrix::<i32>(&[3, 3]));
+    assert_eq!(Err(data_vec.clone()),                   data_vec.clone().into_dmatrix::<i32>(&[1, 2, 3]));
+    assert_eq!(Err(data_vec.clone()),                   data_vec.clone().into_dmatrix::<i32>(&[]));
+    assert_eq!(Err(data_vec.clone()),                   data_vec.clone().into_dmatrix::<i32>(&[std::usize::MAX, 2]));
+    assert_eq!(Err(data_vec.clone()),                   data_vec.clone().into_dvector::<u32>());
+}
@@ -2,7 +2,7 @@ pub mod parse_header_error;
 pub use parse_header_error::ParseHeaderError;
 
 use std::rc::Rc;
-use crate::{Dimension, DataType};
+use crate::{Dimension, DataType, Version};
 
 /// NetCDF-3 data set error
 ///
@@ -49,12 +49,16 @@ pub enum InvalidDataSet {
     DimensionNameNotValid(String),
     DimensionIdsNotFound{defined: Vec<usize>, searched: Vec<usize>, not_found: Vec<usize>},
     FixedDimensionWithZeroSize(String),
+    DimensionNotFixedSize(String),
     MaximumFixedDimensionSizeExceeded{dim_name: String, get: usize},
     DimensionsNotFound{defined: Vec<Rc<Dimension>>, searched: Vec<Rc<Dimension>>, not_found: Vec<Rc<Dimension>>},
 
     VariableAttributeAlreadyExists{var_name: String, attr_name: String},
     VariableAttributeNotDefined{var_name: String, attr_name: String},
     VariableAttributeNameNotValid{var_name: String, attr_name: String},
+    VariableAttributeMismatchDataType{var_name: String, attr_name: String, req: DataType, get: DataType},
+    VariableAttributeNotScalar{var_name: String, attr_name: String, len: usize},
+    VariableAttributeDataTypeNotSupported{var_name: String, attr_name: String, data_type: DataType, version: Version},
 
     VariableNotDefined(String),
     VariableNameNotValid(String),
@@ -63,10 +67,16 @@ pub enum InvalidDataSet {
     VariableMismatchDataLength{var_name: String, req: usize, get: usize},
     UnlimitedDimensionMustBeDefinedFirst{var_name: String, unlim_dim_name: String, get_dim_names: Vec<String>},
     MaximumDimensionsPerVariableExceeded{var_name: String, num_dims: usize},
+    VariableDataTypeNotSupported{var_name: String, data_type: DataType, version: Version},
+    VariableChunkSizeExceeded{var_name: String, chunk_size: usize, version: Version},
+    VariableBeginOffsetExceeded{var_name: String, begin_offset: usize, version: Version},
 
     GlobalAttributeAlreadyExists(String),
     GlobalAttributeNotDefined(String),
     GlobalAttributeNameNotValid(String),
+    GlobalAttributeMismatchDataType{attr_name: String, req: DataType, get: DataType},
+    GlobalAttributeNotScalar{attr_name: String, len: usize},
+    GlobalAttributeDataTypeNotSupported{attr_name: String, data_type: DataType, version: Version},
 }
 
 impl std::fmt::Display for InvalidDataSet {
@@ -77,6 +87,92 @@ impl std::fmt::Display for InvalidDataSet {
 
 impl std::error::Error for InvalidDataSet {}
 
+/// Error occuring while building a data set from a CDL text (see [`DataSet::from_cdl`](../struct.DataSet.html#method.from_cdl)).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CdlError {
+    /// The CDL text is not valid, the line numbers start at 1.
+    Syntax{line: usize, message: String},
+    /// The CDL text is valid but it describes a data set which is not valid.
+    DataSet(InvalidDataSet),
+}
+
+impl std::fmt::Display for CdlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for CdlError {}
+
+impl std::convert::From<InvalidDataSet> for CdlError {
+    fn from(err: InvalidDataSet) -> Self {
+        Self::DataSet(err)
+    }
+}
+
+/// Error occuring while converting a `DataVector` with the policy `CastOverflow::Error` (see [`DataVector::cast_to`](../enum.DataVector.html#method.cast_to)).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CastError {
+    /// The value at `index` is outside the range of `data_type`, or is not a number converted into an integer type.
+    ValueOutOfRange{index: usize, data_type: DataType},
+}
+
+impl std::fmt::Display for CastError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for CastError {}
+
+/// Error occuring while computing the packing attributes of a variable (see [`cf::quantize`](../cf/fn.quantize.html)).
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuantizationError {
+    DataSet(InvalidDataSet),
+    /// The packed variable must be of type `i8`, `i16`, `i32`, `u16` or `u32`.
+    DataTypeNotSupported{var_name: String, data_type: DataType},
+    /// The unpacked values must be `f32` or `f64` values.
+    ValuesNotFloat{var_name: String, data_type: DataType},
+    ValueNotFinite{var_name: String, index: usize},
+    ValueOutOfRange{var_name: String, index: usize},
+    /// The quantization error (half of `scale_factor`) exceeds the requested precision.
+    PrecisionNotReached{var_name: String, precision: f64, scale_factor: f64},
+}
+
+impl std::fmt::Display for QuantizationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for QuantizationError {}
+
+impl std::convert::From<InvalidDataSet> for QuantizationError {
+    fn from(err: InvalidDataSet) -> Self {
+        Self::DataSet(err)
+    }
+}
+
+/// Error occuring while parsing the grid mapping of a variable (see [`cf::grid_mapping`](../cf/fn.grid_mapping.html)).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridMappingError {
+    VariableNotDefined(String),
+    /// The container variable referenced by the attribute `grid_mapping` is not defined.
+    ContainerNotDefined{var_name: String, container_name: String},
+    /// The container variable has no attribute `grid_mapping_name`.
+    NameNotDefined(String),
+    /// A required parameter of the projection is missing, or is not a number.
+    ParameterNotDefined{container_name: String, param_name: String},
+}
+
+impl std::fmt::Display for GridMappingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for GridMappingError {}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ReadError {
     ParseHeader(ParseHeaderError),
@@ -86,9 +182,46 @@ pub enum ReadError {
     IOErrorKind(std::io::ErrorKind),
     ComputationNumberOfRecords,
     RecordIndexExceeded{index: usize, num_records: usize},
+    ElementIndexNotValid{var_name: String, index: Vec<usize>, shape: Vec<usize>},
+    SliceNotValid{var_name: String, start: Vec<usize>, count: Vec<usize>, shape: Vec<usize>},
+    CoordinateVariableNotValid(String),
+    TimeUnitsNotValid(String),
+    TimeValueNotValid{var_name: String, index: usize},
+    NotRecordVariable(String),
+    StringNotValid{var_name: String, index: usize},
+    Hdf5FormatNotSupported,
+    MagicBytesNotValid(Vec<u8>),
+    Netcdf4(String),
+    Netcdf4ModelNotSupported(String),
+    Dap2UrlNotValid(String),
+    Dap2HttpStatus{url: String, status: u16},
+    Dap2ResponseNotValid(String),
+    Dap2TypeNotSupported(String),
+    Arrow(String),
+    DimensionNotDefined{var_name: String, dim_name: String},
+    CsvColumnNotFound(String),
+    CsvLineNotValid(usize),
+    CsvValueNotValid{line: usize, column: String, value: String},
+    ArchiveNotValid(String),
+    ArchiveMemberNotFound(String),
+    ArchiveMethodNotSupported{member_name: String, method: u16},
+    MemoryLimitExceeded{var_name: String, req: usize, limit: usize},
+    NotInMemory,
+    VariableNotContiguous(String),
     Unexpected,
 }
 
+/// Deviation from the NetCDF-3 format tolerated by a lenient [`FileReader`](../struct.FileReader.html#method.open_lenient).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReadWarning {
+    /// Non-zero bytes have been found instead of the zero padding bytes of the header.
+    NonZeroPadding(Vec<u8>),
+    /// The data section is followed by bytes which do not fill up a whole record.
+    TrailingBytes(usize),
+    /// Less attributes than announced in the header have been parsed.
+    AttributeCountMismatch{var_name: Option<String>, expected: usize, found: usize},
+}
+
 impl ReadError {
 
     pub fn header_is_incomplete(&self) -> bool
@@ -131,6 +264,13 @@ impl std::convert::From<std::io::Error> for ReadError {
     }
 }
 
+#[cfg(feature = "netcdf")]
+impl std::convert::From<netcdf::Error> for ReadError {
+    fn from(err: netcdf::Error) -> Self {
+        Self::Netcdf4(err.to_string())
+    }
+}
+
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WriteError {
@@ -138,14 +278,38 @@ pub enum WriteError {
     VariableNotDefined(String),
     VariableMismatchDataType{var_name: String, req: DataType, get: DataType},
     VariableMismatchDataLength{var_name: String, req: usize, get: usize},
+    VariableValueOutOfRange{var_name: String, index: usize},
     ClassicVersionNotPossible,
+    Offset64BitVersionNotPossible,
     HeaderAlreadyDefined,
     HeaderNotDefined,
     RecordIndexExceeded{index: usize, num_records: usize},
     RecordMismatchDataLength{var_name: String, req: usize, get: usize},
+    ElementIndexNotValid{var_name: String, index: Vec<usize>, shape: Vec<usize>},
+    SliceNotValid{var_name: String, start: Vec<usize>, count: Vec<usize>, shape: Vec<usize>},
+    Read(ReadError),
+    DataSetMismatch,
+    NotRecordVariable(String),
+    DataTypeNotSupported{data_type: DataType, version: Version},
+    ZipSizeExceeded,
+    VariableMismatchDims{var_name: String, req: Vec<String>, get: Vec<String>},
+    Parquet(String),
+    Netcdf4(String),
+    NotInMemory,
+    NotOnFileSystem,
+    ImageShapeNotValid{var_name: String, shape: Vec<usize>},
+    Png(String),
+    FeatureTypeNotSupported(String),
+    CoordinateVariableNotFound(String),
     Unexpected,
 }
 
+impl std::convert::From<ReadError> for WriteError {
+    fn from(err: ReadError) -> Self {
+        WriteError::Read(err)
+    }
+}
+
 impl std::convert::From<std::io::Error> for WriteError {
     fn from(err: std::io::Error) -> Self {
         WriteError::IOErrorKind(err.kind())
@@ -49,6 +49,7 @@ pub enum ParseHeaderErrorKind {
     MagicWord,
     VersionNumber,
     NonNegativeI32,
+    NonNegativeI64,
     // NameString,
     ZeroPadding,
     DimTag,
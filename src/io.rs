@@ -2,10 +2,73 @@
 
 mod file_reader;
 mod file_writer;
+mod variable_view;
+mod extract;
+mod convert;
+mod geo_subset;
+mod time_subset;
+mod text_encoding;
+mod summary;
+mod flatten;
+mod labeled;
+mod csv;
+mod compare;
+mod geojson;
+mod reader_pool;
+mod timings;
+#[cfg(feature = "netcdf")]
+mod netcdf4;
+#[cfg(feature = "dap2")]
+mod dap2;
+#[cfg(feature = "zarr")]
+mod zarr;
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "parquet")]
+mod parquet;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "image")]
+mod image;
+#[cfg(feature = "archive")]
+mod archive;
 mod tests_io;
 
 pub use file_reader::FileReader;
 pub use file_writer::FileWriter;
+pub use variable_view::{VariableView, SliceArg};
+pub use extract::extract;
+pub use convert::convert;
+pub use geo_subset::{BoundingBox, GeoSubset};
+pub use time_subset::{DateTime, TimeUnits, TimeSubset};
+pub use text_encoding::{TextEncoding, StringOptions};
+pub use summary::{Summarizer, Summary, SummaryOptions, Histogram, HistogramBins, summarize_var};
+pub use csv::{export_csv, import_csv, CsvOptions, CsvImportOptions};
+pub use geojson::export_geojson;
+pub use compare::{compare_files, CompareOptions, FileDiff};
+pub use reader_pool::ReaderPool;
+pub use timings::{Timings, VarTimings};
+pub(crate) use timings::Stopwatch;
+pub use labeled::{LabeledDataset, Label};
+#[cfg(feature = "netcdf")]
+pub use netcdf4::{read_netcdf4, write_netcdf4, define_netcdf4, write_netcdf4_var};
+#[cfg(feature = "dap2")]
+pub use dap2::Dap2Reader;
+#[cfg(feature = "zarr")]
+pub use zarr::{export_zarr, kerchunk_refs, ZarrStore};
+#[cfg(feature = "arrow")]
+pub use arrow::to_arrow;
+#[cfg(feature = "parquet")]
+pub use parquet::export_parquet;
+#[cfg(feature = "wasm")]
+pub use wasm::{WasmReader, WasmWriter};
+#[cfg(feature = "image")]
+pub use image::{export_png, ImageOptions, Colormap};
+#[cfg(feature = "archive")]
+pub use archive::archive_members;
+use std::ops::Range;
+
+use crate::{NcType, Version};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub (crate) enum Offset {
@@ -26,6 +89,8 @@ impl std::convert::From<Offset> for i64 {
 
 /// These bytes mean the list (dimensions, attributes or variable) is not defined.
 pub(crate) const ABSENT_TAG: [u8; 8] = [0; 8];
+/// These bytes mean the list (dimensions, attributes or variable) is not defined (CDF-5 version).
+pub(crate) const ABSENT_TAG_CDF5: [u8; 12] = [0; 12];
 /// Bytes for the list of dimensions
 pub(crate) const DIMENSION_TAG: [u8; 4] = [0, 0, 0, 0x0A];
 /// Bytes for the list of variables
@@ -33,6 +98,14 @@ pub(crate) const VARIABLE_TAG: [u8; 4] = [0, 0, 0, 0x0b];
 /// Bytes for the lists attributes (global or for each variable).
 pub(crate) const ATTRIBUTE_TAG: [u8; 4] = [0, 0, 0, 0x0C];
 
+/// Returns the bytes meaning that a list is not defined, depending on the version.
+pub(crate) fn absent_tag(version: &Version) -> &'static [u8] {
+    return match version {
+        Version::Classic | Version::Offset64Bit => &ABSENT_TAG,
+        Version::Cdf5 => &ABSENT_TAG_CDF5,
+    };
+}
+
 #[inline]
 /// Compute and return the number of bytes of the padding required to fill remaining bytes up.
 ///
@@ -44,4 +117,380 @@ pub fn compute_padding_size(num_bytes: usize) -> usize {
         0 => 0,
         n => ALIGNMENT_SIZE - n,
     };
-}
\ No newline at end of file
+}
+/// Returns the smallest range of indices containing all the `values` between `min` and `max` (inclusive).
+///
+/// Returns an empty range if no value is between `min` and `max`.
+pub(crate) fn compute_index_range(values: &[f64], min: f64, max: f64) -> Range<usize> {
+    let is_inside = |value: &f64| -> bool { min <= *value && *value <= max };
+    let first: Option<usize> = values.iter().position(is_inside);
+    let last: Option<usize> = values.iter().rposition(is_inside);
+    return match (first, last) {
+        (Some(first), Some(last)) => first..(last + 1),
+        _ => 0..0,
+    };
+}
+
+/// Number of elements encoded at once before being written, which keeps the buffer small and in the CPU cache.
+pub(crate) const ENCODING_BLOCK_LEN: usize = 8_192;
+
+/// Encodes the `values` into their big-endian bytes, `bytes` must contain exactly `size_of::<T>()` bytes per value.
+///
+/// The loop has no branch and no bounds check, so that it can be vectorized.
+#[inline]
+pub(crate) fn encode_be_into<T: NcType>(values: &[T], bytes: &mut [u8]) {
+    let size_of: usize = std::mem::size_of::<T>();
+    debug_assert_eq!(std::mem::size_of_val(values), bytes.len());
+    for (value, value_bytes) in values.iter().zip(bytes.chunks_exact_mut(size_of)) {
+        value.copy_be_bytes(value_bytes);
+    }
+}
+
+/// Encodes the `values` by blocks of `ENCODING_BLOCK_LEN` elements, and writes them into the output stream.
+///
+/// Returns the number of written bytes.
+pub(crate) fn write_be_values<T: NcType, W: std::io::Write>(out_stream: &mut W, values: &[T]) -> Result<usize, std::io::Error> {
+    let size_of: usize = std::mem::size_of::<T>();
+    let mut buffer: Vec<u8> = vec![0_u8; std::cmp::min(values.len(), ENCODING_BLOCK_LEN) * size_of];
+    for block in values.chunks(ENCODING_BLOCK_LEN) {
+        let block_bytes: &mut [u8] = &mut buffer[0..std::mem::size_of_val(block)];
+        encode_be_into(block, block_bytes);
+        out_stream.write_all(block_bytes)?;
+    }
+    return Ok(std::mem::size_of_val(values));
+}
+
+/// Storage of the bytes read by a `FileReader` or written by a `FileWriter`, either a file or an in-memory buffer.
+#[derive(Debug)]
+pub(crate) enum Storage {
+    File(std::fs::File),
+    Memory(std::io::Cursor<Vec<u8>>),
+    /// Read-only range of bytes of a file (e.g. a member of an archive)
+    #[cfg(feature = "archive")]
+    FileRange(FileRange),
+    /// Reference-counted buffer, shared by the readers without copy
+    #[cfg(feature = "bytes")]
+    Shared(std::io::Cursor<bytes::Bytes>),
+    /// File written through a memory mapping
+    #[cfg(feature = "mmap")]
+    Mapped(MappedFile),
+}
+
+impl std::io::Read for Storage {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        return match self {
+            Storage::File(file) => file.read(buf),
+            Storage::Memory(cursor) => cursor.read(buf),
+            #[cfg(feature = "archive")]
+            Storage::FileRange(file_range) => file_range.read(buf),
+            #[cfg(feature = "bytes")]
+            Storage::Shared(cursor) => cursor.read(buf),
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(mapped_file) => mapped_file.read(buf),
+        };
+    }
+}
+
+impl std::io::Write for Storage {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        return match self {
+            Storage::File(file) => file.write(buf),
+            Storage::Memory(cursor) => cursor.write(buf),
+            #[cfg(feature = "archive")]
+            Storage::FileRange(_file_range) => Err(std::io::Error::from(std::io::ErrorKind::PermissionDenied)),
+            #[cfg(feature = "bytes")]
+            Storage::Shared(_cursor) => Err(std::io::Error::from(std::io::ErrorKind::PermissionDenied)),
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(mapped_file) => mapped_file.write(buf),
+        };
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        return match self {
+            Storage::File(file) => file.flush(),
+            Storage::Memory(cursor) => cursor.flush(),
+            #[cfg(feature = "archive")]
+            Storage::FileRange(_file_range) => Ok(()),
+            #[cfg(feature = "bytes")]
+            Storage::Shared(_cursor) => Ok(()),
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(mapped_file) => mapped_file.flush(),
+        };
+    }
+}
+
+impl std::io::Seek for Storage {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        return match self {
+            Storage::File(file) => file.seek(pos),
+            Storage::Memory(cursor) => cursor.seek(pos),
+            #[cfg(feature = "archive")]
+            Storage::FileRange(file_range) => file_range.seek(pos),
+            #[cfg(feature = "bytes")]
+            Storage::Shared(cursor) => cursor.seek(pos),
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(mapped_file) => mapped_file.seek(pos),
+        };
+    }
+}
+
+impl Storage {
+    /// Returns all the bytes of the storage if they are stored in memory (or memory-mapped), `None` otherwise.
+    pub(crate) fn as_slice(&self) -> Option<&[u8]> {
+        return match self {
+            Storage::File(_file) => None,
+            Storage::Memory(cursor) => Some(cursor.get_ref()),
+            #[cfg(feature = "archive")]
+            Storage::FileRange(_file_range) => None,
+            #[cfg(feature = "bytes")]
+            Storage::Shared(cursor) => Some(cursor.get_ref()),
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(mapped_file) => Some(mapped_file.as_slice()),
+        };
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl Storage {
+    /// Reads the exact number of bytes required to fill `buf` from the `position`, without moving the cursor of the storage.
+    ///
+    /// Unlike the `Read` and `Seek` methods, can be called by several threads at the same time.
+    pub(crate) fn read_exact_at(&self, buf: &mut [u8], position: u64) -> std::io::Result<()> {
+        return match self {
+            Storage::File(file) => read_file_exact_at(file, buf, position),
+            Storage::Memory(cursor) => read_slice_exact_at(cursor.get_ref(), buf, position),
+            #[cfg(feature = "archive")]
+            Storage::FileRange(file_range) => match position.checked_add(buf.len() as u64) {
+                Some(end) if end <= file_range.len => read_file_exact_at(&file_range.file, buf, file_range.start + position),
+                _ => Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof)),
+            },
+            #[cfg(feature = "bytes")]
+            Storage::Shared(cursor) => read_slice_exact_at(cursor.get_ref(), buf, position),
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(mapped_file) => read_slice_exact_at(mapped_file.as_slice(), buf, position),
+        };
+    }
+}
+
+#[cfg(feature = "rayon")]
+fn read_slice_exact_at(bytes: &[u8], buf: &mut [u8], position: u64) -> std::io::Result<()> {
+    use std::convert::TryFrom;
+    let start: usize = usize::try_from(position).map_err(|_err| std::io::Error::from(std::io::ErrorKind::UnexpectedEof))?;
+    let src: &[u8] = start.checked_add(buf.len()).and_then(|end: usize| bytes.get(start..end)).ok_or_else(|| {
+        std::io::Error::from(std::io::ErrorKind::UnexpectedEof)
+    })?;
+    buf.copy_from_slice(src);
+    return Ok(());
+}
+
+#[cfg(all(feature = "rayon", unix))]
+fn read_file_exact_at(file: &std::fs::File, buf: &mut [u8], position: u64) -> std::io::Result<()> {
+    use std::os::unix::fs::FileExt;
+    return file.read_exact_at(buf, position);
+}
+
+#[cfg(all(feature = "rayon", windows))]
+fn read_file_exact_at(file: &std::fs::File, mut buf: &mut [u8], mut position: u64) -> std::io::Result<()> {
+    use std::os::windows::fs::FileExt;
+    while !buf.is_empty() {
+        match file.seek_read(buf, position) {
+            Ok(0) => return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof)),
+            Ok(num_bytes) => {
+                buf = &mut buf[num_bytes..];
+                position += num_bytes as u64;
+            },
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {},
+            Err(err) => return Err(err),
+        }
+    }
+    return Ok(());
+}
+
+#[cfg(all(feature = "rayon", not(any(unix, windows))))]
+fn read_file_exact_at(_file: &std::fs::File, _buf: &mut [u8], _position: u64) -> std::io::Result<()> {
+    return Err(std::io::Error::from(std::io::ErrorKind::Unsupported));
+}
+
+/// Range of bytes of a file, read and seeked as if it were a whole file.
+#[cfg(feature = "archive")]
+#[derive(Debug)]
+pub(crate) struct FileRange {
+    file: std::fs::File,
+    start: u64,
+    len: u64,
+    /// Position relative to `start`
+    pos: u64,
+}
+
+#[cfg(feature = "archive")]
+impl FileRange {
+    pub(crate) fn new(file: std::fs::File, start: u64, len: u64) -> FileRange {
+        return FileRange{
+            file: file,
+            start: start,
+            len: len,
+            pos: 0,
+        };
+    }
+}
+
+#[cfg(feature = "archive")]
+impl std::io::Read for FileRange {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        use std::io::Seek;
+        let remaining: u64 = self.len.saturating_sub(self.pos);
+        let buf_len: usize = std::cmp::min(buf.len() as u64, remaining) as usize;
+        if buf_len == 0 {
+            return Ok(0);
+        }
+        self.file.seek(std::io::SeekFrom::Start(self.start + self.pos))?;
+        let num_bytes: usize = self.file.read(&mut buf[0..buf_len])?;
+        self.pos += num_bytes as u64;
+        return Ok(num_bytes);
+    }
+}
+
+#[cfg(feature = "archive")]
+impl std::io::Seek for FileRange {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        let new_pos: Option<u64> = match pos {
+            std::io::SeekFrom::Start(offset) => Some(offset),
+            std::io::SeekFrom::End(offset) => add_signed(self.len, offset),
+            std::io::SeekFrom::Current(offset) => add_signed(self.pos, offset),
+        };
+        fn add_signed(base: u64, offset: i64) -> Option<u64> {
+            return match offset >= 0 {
+                true => base.checked_add(offset as u64),
+                false => base.checked_sub(offset.unsigned_abs()),
+            };
+        }
+        self.pos = new_pos.ok_or_else(|| std::io::Error::from(std::io::ErrorKind::InvalidInput))?;
+        return Ok(self.pos);
+    }
+}
+
+/// File written through a memory mapping, the writes being copies into the mapped memory.
+///
+/// The file is extended (and mapped again) when the bytes are written beyond the end of the mapping, and is truncated
+/// to the position after the last written byte when it is finished (or dropped).
+#[cfg(feature = "mmap")]
+#[derive(Debug)]
+pub(crate) struct MappedFile {
+    file: std::fs::File,
+    map: Option<memmap2::MmapMut>,
+    /// Position after the last written byte
+    len: u64,
+    pos: u64,
+}
+
+#[cfg(feature = "mmap")]
+impl MappedFile {
+    pub(crate) fn new(file: std::fs::File) -> MappedFile {
+        return MappedFile{
+            file: file,
+            map: None,
+            len: 0,
+            pos: 0,
+        };
+    }
+
+    fn mapped_len(&self) -> u64 {
+        return self.map.as_ref().map(|map: &memmap2::MmapMut| map.len() as u64).unwrap_or(0);
+    }
+
+    /// Extends the file and its mapping to at least `size` bytes.
+    pub(crate) fn reserve(&mut self, size: u64) -> std::io::Result<()> {
+        if size <= self.mapped_len() {
+            return Ok(());
+        }
+        if let Some(map) = self.map.take() {
+            map.flush()?;
+        }
+        self.file.set_len(size)?;
+        // SAFETY: the file is owned by the writer, and its length is only changed while it is not mapped
+        let map: memmap2::MmapMut = unsafe { memmap2::MmapOptions::new().len(size as usize).map_mut(&self.file)? };
+        self.map = Some(map);
+        return Ok(());
+    }
+
+    /// Flushes the mapped memory, releases the mapping and truncates the file after the last written byte.
+    pub(crate) fn finish(&mut self) -> std::io::Result<()> {
+        if let Some(map) = self.map.take() {
+            map.flush()?;
+        }
+        return self.file.set_len(self.len);
+    }
+
+    /// Returns the written bytes.
+    fn as_slice(&self) -> &[u8] {
+        return match &self.map {
+            Some(map) => &map[0..(self.len as usize)],
+            None => &[],
+        };
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl Drop for MappedFile {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl std::io::Read for MappedFile {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let map: &memmap2::MmapMut = match &self.map {
+            Some(map) if self.pos < self.len => map,
+            _ => return Ok(0),
+        };
+        let num_bytes: usize = std::cmp::min(buf.len() as u64, self.len - self.pos) as usize;
+        let start: usize = self.pos as usize;
+        buf[0..num_bytes].copy_from_slice(&map[start..(start + num_bytes)]);
+        self.pos += num_bytes as u64;
+        return Ok(num_bytes);
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl std::io::Write for MappedFile {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let end: u64 = self.pos.checked_add(buf.len() as u64).ok_or_else(|| std::io::Error::from(std::io::ErrorKind::InvalidInput))?;
+        if end > self.mapped_len() {
+            // Grow geometrically, the records are often appended one by one
+            self.reserve(std::cmp::max(end, 2 * self.mapped_len()))?;
+        }
+        if let Some(map) = self.map.as_mut() {
+            map[(self.pos as usize)..(end as usize)].copy_from_slice(buf);
+        }
+        self.pos = end;
+        self.len = std::cmp::max(self.len, end);
+        return Ok(buf.len());
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        return match &self.map {
+            Some(map) => map.flush(),
+            None => Ok(()),
+        };
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl std::io::Seek for MappedFile {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        let new_pos: Option<u64> = match pos {
+            std::io::SeekFrom::Start(offset) => Some(offset),
+            std::io::SeekFrom::End(offset) => add_signed(self.len, offset),
+            std::io::SeekFrom::Current(offset) => add_signed(self.pos, offset),
+        };
+        fn add_signed(base: u64, offset: i64) -> Option<u64> {
+            return match offset >= 0 {
+                true => base.checked_add(offset as u64),
+                false => base.checked_sub(offset.unsigned_abs()),
+            };
+        }
+        self.pos = new_pos.ok_or_else(|| std::io::Error::from(std::io::ErrorKind::InvalidInput))?;
+        return Ok(self.pos);
+    }
+}
@@ -0,0 +1,374 @@
+mod tests_archive;
+
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use byteorder::{ByteOrder, LittleEndian};
+use flate2::read::DeflateDecoder;
+
+use crate::{FileReader, TextEncoding};
+use crate::error::ReadError;
+use crate::io::{FileRange, Storage};
+
+/// Format of an archive, detected from its bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArchiveFormat {
+    Zip,
+    Tar,
+}
+
+/// Regular file stored in an archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Member {
+    name: String,
+    /// Offset of the local header (ZIP), or of the data (TAR)
+    offset: u64,
+    compressed_size: u64,
+    size: u64,
+    /// Compression method of the ZIP archives (`0` : stored, `8` : deflated), always `0` for the TAR archives
+    method: u16,
+}
+
+const ZIP_LOCAL_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const ZIP_CENTRAL_HEADER_SIGNATURE: u32 = 0x0201_4b50;
+const ZIP_END_OF_CENTRAL_DIR_SIGNATURE: u32 = 0x0605_4b50;
+const ZIP64_END_OF_CENTRAL_DIR_SIGNATURE: u32 = 0x0606_4b50;
+const ZIP64_LOCATOR_SIGNATURE: u32 = 0x0706_4b50;
+/// Identifier of the extra field containing the 64-bit sizes and offsets
+const ZIP64_EXTRA_FIELD_ID: u16 = 0x0001;
+const ZIP_METHOD_STORED: u16 = 0;
+const ZIP_METHOD_DEFLATED: u16 = 8;
+/// Size of the end of central directory record, without its comment
+const ZIP_END_OF_CENTRAL_DIR_SIZE: usize = 22;
+const ZIP_MAX_COMMENT_SIZE: usize = 0xFFFF;
+/// Maximum compression ratio of the *deflate* method
+const DEFLATE_MAX_RATIO: u64 = 1032;
+
+const TAR_BLOCK_SIZE: usize = 512;
+
+impl FileReader {
+
+    /// Opens a NetCDF-3 file stored in a ZIP (including ZIP64) or a TAR archive, without extracting it on the disk.
+    ///
+    /// Requires the optional feature `archive`. The `member_name` is the path of the file inside the archive (see
+    /// [`archive_members`](fn.archive_members.html)). The uncompressed members (the TAR archives and the *stored* ZIP
+    /// members) are read directly from the archive, and the *deflated* ZIP members are decompressed in memory. The
+    /// [file path](struct.FileReader.html#method.file_path) of the reader is the path of the archive joined with the
+    /// `member_name`.
+    ///
+    /// Returns the error `ReadError::ArchiveNotValid` if the archive is neither a ZIP nor a TAR archive,
+    /// `ReadError::ArchiveMemberNotFound` if it does not contain the member, and `ReadError::ArchiveMethodNotSupported`
+    /// for the other compression methods.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{FileReader, archive_members};
+    /// # use std::io::Write;
+    /// # use copy_to_tmp_file::NC3_CLASSIC_FILE_BYTES;
+    /// # use tempdir::TempDir;
+    /// # let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    /// # let archive_path = tmp_dir.path().join("bundle.tar");
+    /// # {
+    /// #     let mut header: Vec<u8> = vec![0; 512];
+    /// #     header[0..14].copy_from_slice(b"data/sample.nc");
+    /// #     header[100..108].copy_from_slice(b"0000644\0");
+    /// #     header[124..136].copy_from_slice(format!("{:011o}\0", NC3_CLASSIC_FILE_BYTES.len()).as_bytes());
+    /// #     header[156] = b'0';
+    /// #     header[257..263].copy_from_slice(b"ustar\0");
+    /// #     header[148..156].copy_from_slice(b"        ");
+    /// #     let checksum: u32 = header.iter().map(|byte: &u8| u32::from(*byte)).sum();
+    /// #     header[148..156].copy_from_slice(format!("{:06o}\0 ", checksum).as_bytes());
+    /// #     let mut archive = std::fs::File::create(&archive_path).unwrap();
+    /// #     archive.write_all(&header).unwrap();
+    /// #     archive.write_all(NC3_CLASSIC_FILE_BYTES).unwrap();
+    /// #     archive.write_all(&vec![0; 1024 + (512 - NC3_CLASSIC_FILE_BYTES.len() % 512) % 512]).unwrap();
+    /// # }
+    ///
+    /// assert_eq!(vec!["data/sample.nc"],       archive_members(&archive_path).unwrap());
+    ///
+    /// let mut file_reader: FileReader = FileReader::open_archive_member(&archive_path, "data/sample.nc").unwrap();
+    /// assert_eq!(vec![0.0, 0.5, 1.0],         file_reader.read_var_f32("latitude").unwrap());
+    /// # tmp_dir.close();
+    /// ```
+    pub fn open_archive_member<P: AsRef<Path>>(archive_path: P, member_name: &str) -> Result<Self, ReadError>
+    {
+        let archive_path: &Path = archive_path.as_ref();
+        let mut archive: File = File::open(archive_path)?;
+        let archive_size: u64 = archive.metadata()?.len();
+        let (format, members): (ArchiveFormat, Vec<Member>) = read_members(&mut archive)?;
+        let member: Member = members.into_iter()
+            .find(|member: &Member| member.name == member_name)
+            .ok_or_else(|| ReadError::ArchiveMemberNotFound(member_name.to_owned()))?;
+        let data_offset: u64 = match format {
+            ArchiveFormat::Zip => zip_data_offset(&mut archive, member.offset)?,
+            ArchiveFormat::Tar => member.offset,
+        };
+        let (input, size): (Storage, usize) = match member.method {
+            ZIP_METHOD_STORED => {
+                let size: usize = checked_size(data_offset, member.size, archive_size, "member data")?;
+                (Storage::FileRange(FileRange::new(archive, data_offset, member.size)), size)
+            },
+            ZIP_METHOD_DEFLATED => {
+                checked_size(data_offset, member.compressed_size, archive_size, "compressed member data")?;
+                // The uncompressed size is not bounded by the archive size, but by the compression ratio
+                if member.size > member.compressed_size.saturating_mul(DEFLATE_MAX_RATIO) {
+                    return Err(ReadError::ArchiveNotValid(String::from("uncompressed member size not valid")));
+                }
+                let size: usize = usize::try_from(member.size).map_err(|_err| ReadError::ArchiveNotValid(String::from("uncompressed member size not valid")))?;
+                archive.seek(SeekFrom::Start(data_offset))?;
+                let mut bytes: Vec<u8> = Vec::with_capacity(size);
+                DeflateDecoder::new(archive.take(member.compressed_size)).take(member.size).read_to_end(&mut bytes)?;
+                if bytes.len() != size {
+                    return Err(ReadError::ArchiveNotValid(String::from("uncompressed member size not valid")));
+                }
+                (Storage::Memory(std::io::Cursor::new(bytes)), size)
+            },
+            method => return Err(ReadError::ArchiveMethodNotSupported{member_name: member.name, method: method}),
+        };
+        let file_path: PathBuf = archive_path.join(member_name);
+        return FileReader::from_storage(input, file_path, size, false, TextEncoding::Utf8, None);
+    }
+}
+
+/// Returns the paths of the regular files stored in a ZIP (including ZIP64) or a TAR archive.
+///
+/// Requires the optional feature `archive`, see [`FileReader::open_archive_member`](struct.FileReader.html#method.open_archive_member).
+pub fn archive_members<P: AsRef<Path>>(archive_path: P) -> Result<Vec<String>, ReadError> {
+    let mut archive: File = File::open(archive_path)?;
+    let (_format, members): (ArchiveFormat, Vec<Member>) = read_members(&mut archive)?;
+    return Ok(members.into_iter().map(|member: Member| member.name).collect());
+}
+
+/// Detects the format of the archive, and reads the list of its regular files.
+fn read_members(archive: &mut File) -> Result<(ArchiveFormat, Vec<Member>), ReadError> {
+    let archive_size: u64 = archive.metadata()?.len();
+    if let Some(end_offset) = find_zip_end_of_central_dir(archive, archive_size)? {
+        return Ok((ArchiveFormat::Zip, read_zip_members(archive, archive_size, end_offset)?));
+    }
+    let mut header: Vec<u8> = vec![0; TAR_BLOCK_SIZE];
+    archive.seek(SeekFrom::Start(0))?;
+    if archive_size >= TAR_BLOCK_SIZE as u64 {
+        archive.read_exact(&mut header)?;
+        if is_tar_header(&header) {
+            return Ok((ArchiveFormat::Tar, read_tar_members(archive, archive_size)?));
+        }
+    }
+    return Err(ReadError::ArchiveNotValid(String::from("neither a ZIP nor a TAR archive")));
+}
+
+/// Returns the `size` as a `usize` if the `size` bytes starting at `offset` are stored in the archive, an error otherwise.
+fn checked_size(offset: u64, size: u64, archive_size: u64, what: &str) -> Result<usize, ReadError> {
+    return match offset.checked_add(size) {
+        Some(end) if end <= archive_size => usize::try_from(size).map_err(|_err| ReadError::ArchiveNotValid(format!("{} too large", what))),
+        _ => Err(ReadError::ArchiveNotValid(format!("{} truncated", what))),
+    };
+}
+
+/// Returns the offset of the end of central directory record, searched backwards from the end of the archive.
+fn find_zip_end_of_central_dir(archive: &mut File, archive_size: u64) -> Result<Option<u64>, ReadError> {
+    let tail_size: u64 = archive_size.min((ZIP_END_OF_CENTRAL_DIR_SIZE + ZIP_MAX_COMMENT_SIZE) as u64);
+    if tail_size < ZIP_END_OF_CENTRAL_DIR_SIZE as u64 {
+        return Ok(None);
+    }
+    let tail_offset: u64 = archive_size - tail_size;
+    let mut tail: Vec<u8> = vec![0; tail_size as usize];
+    archive.seek(SeekFrom::Start(tail_offset))?;
+    archive.read_exact(&mut tail)?;
+    return Ok((0..=(tail.len() - ZIP_END_OF_CENTRAL_DIR_SIZE)).rev()
+        .find(|pos: &usize| LittleEndian::read_u32(&tail[*pos..]) == ZIP_END_OF_CENTRAL_DIR_SIGNATURE)
+        .map(|pos: usize| tail_offset + pos as u64));
+}
+
+/// Reads the entries of the central directory, the ZIP64 records and extra fields replace the saturated values.
+fn read_zip_members(archive: &mut File, archive_size: u64, end_offset: u64) -> Result<Vec<Member>, ReadError> {
+    let mut end_record: [u8; ZIP_END_OF_CENTRAL_DIR_SIZE] = [0; ZIP_END_OF_CENTRAL_DIR_SIZE];
+    archive.seek(SeekFrom::Start(end_offset))?;
+    archive.read_exact(&mut end_record)?;
+    let mut num_entries: u64 = u64::from(LittleEndian::read_u16(&end_record[10..12]));
+    let mut central_dir_size: u64 = u64::from(LittleEndian::read_u32(&end_record[12..16]));
+    let mut central_dir_offset: u64 = u64::from(LittleEndian::read_u32(&end_record[16..20]));
+
+    // ZIP64 end of central directory record, located just before its locator
+    if end_offset >= 20 {
+        let mut locator: [u8; 20] = [0; 20];
+        archive.seek(SeekFrom::Start(end_offset - 20))?;
+        archive.read_exact(&mut locator)?;
+        if LittleEndian::read_u32(&locator[0..4]) == ZIP64_LOCATOR_SIGNATURE {
+            let mut end_record_64: [u8; 56] = [0; 56];
+            archive.seek(SeekFrom::Start(LittleEndian::read_u64(&locator[8..16])))?;
+            archive.read_exact(&mut end_record_64)?;
+            if LittleEndian::read_u32(&end_record_64[0..4]) != ZIP64_END_OF_CENTRAL_DIR_SIGNATURE {
+                return Err(ReadError::ArchiveNotValid(String::from("ZIP64 end of central directory not found")));
+            }
+            num_entries = LittleEndian::read_u64(&end_record_64[32..40]);
+            central_dir_size = LittleEndian::read_u64(&end_record_64[40..48]);
+            central_dir_offset = LittleEndian::read_u64(&end_record_64[48..56]);
+        }
+    }
+
+    let mut central_dir: Vec<u8> = vec![0; checked_size(central_dir_offset, central_dir_size, archive_size, "central directory")?];
+    archive.seek(SeekFrom::Start(central_dir_offset))?;
+    archive.read_exact(&mut central_dir)?;
+    let mut members: Vec<Member> = vec![];
+    let mut pos: usize = 0;
+    for _ in 0..num_entries {
+        let entry: &[u8] = pos.checked_add(46).and_then(|entry_end: usize| central_dir.get(pos..entry_end)).ok_or_else(|| ReadError::ArchiveNotValid(String::from("central directory truncated")))?;
+        if LittleEndian::read_u32(&entry[0..4]) != ZIP_CENTRAL_HEADER_SIGNATURE {
+            return Err(ReadError::ArchiveNotValid(String::from("central directory entry not valid")));
+        }
+        let method: u16 = LittleEndian::read_u16(&entry[10..12]);
+        let mut compressed_size: u64 = u64::from(LittleEndian::read_u32(&entry[20..24]));
+        let mut size: u64 = u64::from(LittleEndian::read_u32(&entry[24..28]));
+        let name_len: usize = usize::from(LittleEndian::read_u16(&entry[28..30]));
+        let extra_len: usize = usize::from(LittleEndian::read_u16(&entry[30..32]));
+        let comment_len: usize = usize::from(LittleEndian::read_u16(&entry[32..34]));
+        let mut offset: u64 = u64::from(LittleEndian::read_u32(&entry[42..46]));
+        let name_start: usize = pos + 46;
+        let extra_start: usize = name_start + name_len;
+        let extra_end: usize = extra_start + extra_len;
+        let name: &[u8] = central_dir.get(name_start..extra_start).ok_or_else(|| ReadError::ArchiveNotValid(String::from("central directory truncated")))?;
+        let name: String = String::from_utf8_lossy(name).into_owned();
+        let mut extra: &[u8] = central_dir.get(extra_start..extra_end).unwrap_or(&[]);
+
+        // The 64-bit values are only given for the saturated 32-bit values, in this order
+        while extra.len() >= 4 {
+            let (field_id, field_len): (u16, usize) = (LittleEndian::read_u16(&extra[0..2]), usize::from(LittleEndian::read_u16(&extra[2..4])));
+            let field: &[u8] = extra.get(4..(4 + field_len)).unwrap_or(&[]);
+            if field_id == ZIP64_EXTRA_FIELD_ID {
+                let mut values = field.chunks_exact(8).map(LittleEndian::read_u64);
+                for value in [&mut size, &mut compressed_size, &mut offset].iter_mut() {
+                    if **value == u64::from(u32::MAX) {
+                        **value = values.next().ok_or_else(|| ReadError::ArchiveNotValid(String::from("ZIP64 extra field truncated")))?;
+                    }
+                }
+            }
+            extra = extra.get((4 + field_len)..).unwrap_or(&[]);
+        }
+        pos = extra_end + comment_len;
+        if !name.ends_with('/') {
+            members.push(Member{
+                name: name,
+                offset: offset,
+                compressed_size: compressed_size,
+                size: size,
+                method: method,
+            });
+        }
+    }
+    return Ok(members);
+}
+
+/// Returns the offset of the data of a ZIP member, located after its local header.
+fn zip_data_offset(archive: &mut File, local_header_offset: u64) -> Result<u64, ReadError> {
+    let mut local_header: [u8; 30] = [0; 30];
+    archive.seek(SeekFrom::Start(local_header_offset))?;
+    archive.read_exact(&mut local_header)?;
+    if LittleEndian::read_u32(&local_header[0..4]) != ZIP_LOCAL_HEADER_SIGNATURE {
+        return Err(ReadError::ArchiveNotValid(String::from("local header not valid")));
+    }
+    let name_len: u64 = u64::from(LittleEndian::read_u16(&local_header[26..28]));
+    let extra_len: u64 = u64::from(LittleEndian::read_u16(&local_header[28..30]));
+    return local_header_offset.checked_add(30 + name_len + extra_len)
+        .ok_or_else(|| ReadError::ArchiveNotValid(String::from("local header not valid")));
+}
+
+/// Returns `true` if the checksum of the TAR header is valid.
+fn is_tar_header(header: &[u8]) -> bool {
+    let checksum: u32 = header.iter().enumerate()
+        .map(|(i, byte): (usize, &u8)| if (148..156).contains(&i) { u32::from(b' ') } else { u32::from(*byte) })
+        .sum();
+    return parse_tar_number(&header[148..156]) == Some(u64::from(checksum));
+}
+
+/// Parses an octal number, or a base-256 number if its first bit is set (GNU extension for the large sizes).
+fn parse_tar_number(field: &[u8]) -> Option<u64> {
+    if field.first().map(|byte: &u8| byte & 0x80 != 0).unwrap_or(false) {
+        return Some(field[1..].iter().fold(u64::from(field[0] & 0x7f), |number: u64, byte: &u8| (number << 8) | u64::from(*byte)));
+    }
+    let field: &str = std::str::from_utf8(field).ok()?;
+    let field: &str = field.trim_matches(|c: char| c == '\0' || c == ' ');
+    return match field.is_empty() {
+        true => Some(0),
+        false => u64::from_str_radix(field, 8).ok(),
+    };
+}
+
+/// Reads the headers of the TAR archive, including the long names of the GNU and the PAX formats.
+fn read_tar_members(archive: &mut File, archive_size: u64) -> Result<Vec<Member>, ReadError> {
+    let mut members: Vec<Member> = vec![];
+    let mut header: Vec<u8> = vec![0; TAR_BLOCK_SIZE];
+    let mut offset: u64 = 0;
+    let mut long_name: Option<String> = None;
+    let mut pax_size: Option<u64> = None;
+    while offset.checked_add(TAR_BLOCK_SIZE as u64).map(|header_end: u64| header_end <= archive_size).unwrap_or(false) {
+        archive.seek(SeekFrom::Start(offset))?;
+        archive.read_exact(&mut header)?;
+        if header.iter().all(|byte: &u8| *byte == 0) {
+            break;
+        }
+        if !is_tar_header(&header) {
+            return Err(ReadError::ArchiveNotValid(String::from("TAR header checksum not valid")));
+        }
+        let data_offset: u64 = offset + TAR_BLOCK_SIZE as u64;
+        let size: u64 = pax_size.take().map(Ok).unwrap_or_else(|| {
+            parse_tar_number(&header[124..136]).ok_or_else(|| ReadError::ArchiveNotValid(String::from("TAR size not valid")))
+        })?;
+        // The data of the member, the long name and the extended header must be stored in the archive
+        let payload_size: usize = checked_size(data_offset, size, archive_size, "TAR member data")?;
+        let type_flag: u8 = header[156];
+        match type_flag {
+            // Long name of the next member (GNU)
+            b'L' => {
+                let mut name: Vec<u8> = vec![0; payload_size];
+                archive.read_exact(&mut name)?;
+                long_name = Some(String::from_utf8_lossy(&name).trim_end_matches('\0').to_owned());
+            },
+            // Extended header of the next member (PAX), records formatted as `<length> <key>=<value>\n`
+            b'x' => {
+                let mut records: Vec<u8> = vec![0; payload_size];
+                archive.read_exact(&mut records)?;
+                for record in String::from_utf8_lossy(&records).lines() {
+                    let (_length, key_value): (&str, &str) = record.split_once(' ').unwrap_or(("", ""));
+                    match key_value.split_once('=') {
+                        Some(("path", path)) => long_name = Some(path.to_owned()),
+                        Some(("size", size)) => pax_size = size.parse::<u64>().ok(),
+                        _ => {},
+                    }
+                }
+            },
+            b'0' | b'\0' | b'7' => {
+                let name: String = long_name.take().unwrap_or_else(|| {
+                    let field_to_string = |field: &[u8]| -> String {
+                        let len: usize = field.iter().position(|byte: &u8| *byte == 0).unwrap_or(field.len());
+                        return String::from_utf8_lossy(&field[0..len]).into_owned();
+                    };
+                    let name: String = field_to_string(&header[0..100]);
+                    let prefix: String = match &header[257..262] == b"ustar" {
+                        true => field_to_string(&header[345..500]),
+                        false => String::new(),
+                    };
+                    match prefix.is_empty() {
+                        true => name,
+                        false => format!("{}/{}", prefix, name),
+                    }
+                });
+                members.push(Member{
+                    name: name,
+                    offset: data_offset,
+                    compressed_size: size,
+                    size: size,
+                    method: ZIP_METHOD_STORED,
+                });
+            },
+            _ => long_name = None,
+        }
+        let num_data_blocks: u64 = size / TAR_BLOCK_SIZE as u64 + u64::from(size % TAR_BLOCK_SIZE as u64 != 0);
+        offset = num_data_blocks.checked_mul(TAR_BLOCK_SIZE as u64)
+            .and_then(|data_size: u64| data_offset.checked_add(data_size))
+            .ok_or_else(|| ReadError::ArchiveNotValid(String::from("TAR member data truncated")))?;
+    }
+    return Ok(members);
+}
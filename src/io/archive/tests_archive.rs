@@ -0,0 +1,256 @@
+#![cfg(test)]
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use byteorder::{ByteOrder, LittleEndian, WriteBytesExt};
+use flate2::{Compression, write::DeflateEncoder};
+use tempdir::TempDir;
+
+use copy_to_tmp_file::NC3_CLASSIC_FILE_BYTES;
+
+use crate::{FileReader, archive_members};
+use crate::error::ReadError;
+
+use super::{ZIP_METHOD_DEFLATED, ZIP_METHOD_STORED};
+
+const TMP_DIR_PREFIX: &str = "netcdf3_tests_";
+
+/// Member of a ZIP archive written by `write_zip`
+struct ZipEntry<'a> {
+    name: &'a str,
+    method: u16,
+    data: &'a [u8],
+}
+
+/// Writes a ZIP archive, using the ZIP64 records and extra fields if `zip64` is `true`.
+fn write_zip(archive_path: &Path, entries: &[ZipEntry], zip64: bool) {
+    let mut archive: Vec<u8> = vec![];
+    let mut central_dir: Vec<u8> = vec![];
+    for entry in entries.iter() {
+        let data: Vec<u8> = match entry.method {
+            ZIP_METHOD_DEFLATED => {
+                let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+                encoder.write_all(entry.data).unwrap();
+                encoder.finish().unwrap()
+            },
+            _ => entry.data.to_vec(),
+        };
+        let local_header_offset: u64 = archive.len() as u64;
+        archive.write_u32::<LittleEndian>(0x0403_4b50).unwrap();
+        archive.write_all(&[20, 0, 0, 0]).unwrap();
+        archive.write_u16::<LittleEndian>(entry.method).unwrap();
+        archive.write_all(&[0; 8]).unwrap();  // time, date and CRC-32 (not checked)
+        archive.write_u32::<LittleEndian>(data.len() as u32).unwrap();
+        archive.write_u32::<LittleEndian>(entry.data.len() as u32).unwrap();
+        archive.write_u16::<LittleEndian>(entry.name.len() as u16).unwrap();
+        archive.write_u16::<LittleEndian>(4).unwrap();
+        archive.write_all(entry.name.as_bytes()).unwrap();
+        archive.write_all(&[0xff, 0xff, 0, 0]).unwrap();  // unknown extra field
+        archive.write_all(&data).unwrap();
+
+        let saturate = |value: u64| -> u32 { if zip64 { u32::MAX } else { value as u32 } };
+        central_dir.write_u32::<LittleEndian>(0x0201_4b50).unwrap();
+        central_dir.write_all(&[20, 0, 20, 0, 0, 0]).unwrap();
+        central_dir.write_u16::<LittleEndian>(entry.method).unwrap();
+        central_dir.write_all(&[0; 8]).unwrap();
+        central_dir.write_u32::<LittleEndian>(saturate(data.len() as u64)).unwrap();
+        central_dir.write_u32::<LittleEndian>(saturate(entry.data.len() as u64)).unwrap();
+        central_dir.write_u16::<LittleEndian>(entry.name.len() as u16).unwrap();
+        central_dir.write_u16::<LittleEndian>(if zip64 { 28 } else { 0 }).unwrap();
+        central_dir.write_all(&[0; 10]).unwrap();  // comment length, disk number, attributes
+        central_dir.write_u32::<LittleEndian>(saturate(local_header_offset)).unwrap();
+        central_dir.write_all(entry.name.as_bytes()).unwrap();
+        if zip64 {
+            central_dir.write_u16::<LittleEndian>(0x0001).unwrap();
+            central_dir.write_u16::<LittleEndian>(24).unwrap();
+            central_dir.write_u64::<LittleEndian>(entry.data.len() as u64).unwrap();
+            central_dir.write_u64::<LittleEndian>(data.len() as u64).unwrap();
+            central_dir.write_u64::<LittleEndian>(local_header_offset).unwrap();
+        }
+    }
+    let central_dir_offset: u64 = archive.len() as u64;
+    archive.write_all(&central_dir).unwrap();
+    if zip64 {
+        let end_record_64_offset: u64 = archive.len() as u64;
+        archive.write_u32::<LittleEndian>(0x0606_4b50).unwrap();
+        archive.write_u64::<LittleEndian>(44).unwrap();
+        archive.write_all(&[45, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+        archive.write_u64::<LittleEndian>(entries.len() as u64).unwrap();
+        archive.write_u64::<LittleEndian>(entries.len() as u64).unwrap();
+        archive.write_u64::<LittleEndian>(central_dir.len() as u64).unwrap();
+        archive.write_u64::<LittleEndian>(central_dir_offset).unwrap();
+        archive.write_u32::<LittleEndian>(0x0706_4b50).unwrap();
+        archive.write_u32::<LittleEndian>(0).unwrap();
+        archive.write_u64::<LittleEndian>(end_record_64_offset).unwrap();
+        archive.write_u32::<LittleEndian>(1).unwrap();
+    }
+    archive.write_u32::<LittleEndian>(0x0605_4b50).unwrap();
+    archive.write_all(&[0; 4]).unwrap();
+    let num_entries: u16 = if zip64 { u16::MAX } else { entries.len() as u16 };
+    archive.write_u16::<LittleEndian>(num_entries).unwrap();
+    archive.write_u16::<LittleEndian>(num_entries).unwrap();
+    archive.write_u32::<LittleEndian>(if zip64 { u32::MAX } else { central_dir.len() as u32 }).unwrap();
+    archive.write_u32::<LittleEndian>(if zip64 { u32::MAX } else { central_dir_offset as u32 }).unwrap();
+    archive.write_u16::<LittleEndian>(7).unwrap();
+    archive.write_all(b"comment").unwrap();
+    std::fs::write(archive_path, &archive).unwrap();
+}
+
+/// Appends a TAR header followed by the data padded to the block size.
+fn append_tar_entry(archive: &mut Vec<u8>, name: &str, type_flag: u8, data: &[u8]) {
+    append_tar_header(archive, name, type_flag, data.len() as u64);
+    archive.extend_from_slice(data);
+    archive.extend_from_slice(&vec![0; (512 - data.len() % 512) % 512]);
+}
+
+/// Appends a TAR header giving the `size` of the data.
+fn append_tar_header(archive: &mut Vec<u8>, name: &str, type_flag: u8, size: u64) {
+    let mut header: Vec<u8> = vec![0; 512];
+    header[0..name.len()].copy_from_slice(name.as_bytes());
+    header[100..108].copy_from_slice(b"0000644\0");
+    header[124..136].copy_from_slice(format!("{:011o}\0", size).as_bytes());
+    header[156] = type_flag;
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[148..156].copy_from_slice(b"        ");
+    let checksum: u32 = header.iter().map(|byte: &u8| u32::from(*byte)).sum();
+    header[148..156].copy_from_slice(format!("{:06o}\0 ", checksum).as_bytes());
+    archive.extend_from_slice(&header);
+}
+
+fn check_nc3_classic_file(file_reader: &mut FileReader) {
+    assert_eq!(vec![0.0, 0.5, 1.0],                 file_reader.read_var_f32("latitude").unwrap());
+    assert_eq!(30,                                  file_reader.read_var_f32("temperature_f32").unwrap().len());
+}
+
+#[test]
+fn test_open_zip_member() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    for zip64 in [false, true].iter() {
+        let archive_path: PathBuf = tmp_dir.path().join("bundle.zip");
+        write_zip(&archive_path, &[
+            ZipEntry{name: "readme.txt", method: ZIP_METHOD_STORED, data: b"NetCDF-3 files"},
+            ZipEntry{name: "data/", method: ZIP_METHOD_STORED, data: b""},
+            ZipEntry{name: "data/stored.nc", method: ZIP_METHOD_STORED, data: NC3_CLASSIC_FILE_BYTES},
+            ZipEntry{name: "data/deflated.nc", method: ZIP_METHOD_DEFLATED, data: NC3_CLASSIC_FILE_BYTES},
+            ZipEntry{name: "data/other.nc", method: 12, data: NC3_CLASSIC_FILE_BYTES},
+        ], *zip64);
+
+        assert_eq!(vec!["readme.txt", "data/stored.nc", "data/deflated.nc", "data/other.nc"],   archive_members(&archive_path).unwrap());
+        let mut file_reader: FileReader = FileReader::open_archive_member(&archive_path, "data/stored.nc").unwrap();
+        assert_eq!(archive_path.join("data/stored.nc"),     file_reader.file_path());
+        check_nc3_classic_file(&mut file_reader);
+        let mut file_reader: FileReader = FileReader::open_archive_member(&archive_path, "data/deflated.nc").unwrap();
+        check_nc3_classic_file(&mut file_reader);
+
+        assert_eq!(
+            ReadError::ArchiveMethodNotSupported{member_name: String::from("data/other.nc"), method: 12},
+            FileReader::open_archive_member(&archive_path, "data/other.nc").unwrap_err()
+        );
+        assert_eq!(
+            ReadError::ArchiveMemberNotFound(String::from("data/undef.nc")),
+            FileReader::open_archive_member(&archive_path, "data/undef.nc").unwrap_err()
+        );
+        assert_eq!(true,                                FileReader::open_archive_member(&archive_path, "readme.txt").is_err());
+    }
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_open_tar_member() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let archive_path: PathBuf = tmp_dir.path().join("bundle.tar");
+    let long_name: String = format!("{}/long.nc", "d".repeat(120));
+    {
+        let mut archive: Vec<u8> = vec![];
+        append_tar_entry(&mut archive, "data/", b'5', b"");
+        append_tar_entry(&mut archive, "data/short.nc", b'0', NC3_CLASSIC_FILE_BYTES);
+        append_tar_entry(&mut archive, "././@LongLink", b'L', format!("{}\0", long_name).as_bytes());
+        append_tar_entry(&mut archive, "truncated", b'0', NC3_CLASSIC_FILE_BYTES);
+        let pax_record: String = String::from(" path=data/pax.nc\n");
+        let pax_record: String = format!("{}{}", pax_record.len() + 2, pax_record);
+        append_tar_entry(&mut archive, "PaxHeader", b'x', pax_record.as_bytes());
+        append_tar_entry(&mut archive, "pax.nc", b'0', NC3_CLASSIC_FILE_BYTES);
+        archive.extend_from_slice(&[0; 1024]);
+        std::fs::write(&archive_path, &archive).unwrap();
+    }
+
+    assert_eq!(vec![String::from("data/short.nc"), long_name.clone(), String::from("data/pax.nc")],   archive_members(&archive_path).unwrap());
+    for member_name in [String::from("data/short.nc"), long_name, String::from("data/pax.nc")].iter() {
+        let mut file_reader: FileReader = FileReader::open_archive_member(&archive_path, member_name).unwrap();
+        check_nc3_classic_file(&mut file_reader);
+    }
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_open_archive_member_errors() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let archive_path: PathBuf = tmp_dir.path().join("not_archive.nc");
+    std::fs::write(&archive_path, NC3_CLASSIC_FILE_BYTES).unwrap();
+    assert_eq!(
+        ReadError::ArchiveNotValid(String::from("neither a ZIP nor a TAR archive")),
+        FileReader::open_archive_member(&archive_path, "data.nc").unwrap_err()
+    );
+    assert_eq!(
+        ReadError::IOErrorKind(std::io::ErrorKind::NotFound),
+        archive_members(tmp_dir.path().join("undef.zip")).unwrap_err()
+    );
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_open_archive_sizes_not_valid() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+
+    // Long name (GNU) and extended header (PAX) larger than the archive
+    let archive_path: PathBuf = tmp_dir.path().join("bundle.tar");
+    for type_flag in [b'L', b'x', b'0'].iter() {
+        let mut archive: Vec<u8> = vec![];
+        append_tar_header(&mut archive, "././@LongLink", *type_flag, 0o77_777_777_777);
+        archive.extend_from_slice(&[0; 1024]);
+        std::fs::write(&archive_path, &archive).unwrap();
+        assert_eq!(
+            ReadError::ArchiveNotValid(String::from("TAR member data truncated")),
+            archive_members(&archive_path).unwrap_err()
+        );
+    }
+
+    // Central directory larger than the archive
+    let archive_path: PathBuf = tmp_dir.path().join("bundle.zip");
+    write_zip(&archive_path, &[ZipEntry{name: "data.nc", method: ZIP_METHOD_DEFLATED, data: NC3_CLASSIC_FILE_BYTES}], false);
+    let archive: Vec<u8> = std::fs::read(&archive_path).unwrap();
+    let end_offset: usize = archive.len() - 22 - 7;
+    {
+        let mut archive: Vec<u8> = archive.clone();
+        (&mut archive[(end_offset + 12)..(end_offset + 16)]).write_u32::<LittleEndian>(u32::MAX - 1).unwrap();
+        std::fs::write(&archive_path, &archive).unwrap();
+        assert_eq!(
+            ReadError::ArchiveNotValid(String::from("central directory truncated")),
+            archive_members(&archive_path).unwrap_err()
+        );
+    }
+
+    // Uncompressed sizes of the deflated member not matching its data
+    let central_dir_offset: usize = LittleEndian::read_u32(&archive[(end_offset + 16)..(end_offset + 20)]) as usize;
+    for size in [NC3_CLASSIC_FILE_BYTES.len() as u32 + 1, u32::MAX - 1].iter() {
+        let mut archive: Vec<u8> = archive.clone();
+        (&mut archive[(central_dir_offset + 24)..(central_dir_offset + 28)]).write_u32::<LittleEndian>(*size).unwrap();
+        std::fs::write(&archive_path, &archive).unwrap();
+        assert_eq!(
+            ReadError::ArchiveNotValid(String::from("uncompressed member size not valid")),
+            FileReader::open_archive_member(&archive_path, "data.nc").unwrap_err()
+        );
+    }
+
+    // Compressed size larger than the archive
+    {
+        let mut archive: Vec<u8> = archive.clone();
+        (&mut archive[(central_dir_offset + 20)..(central_dir_offset + 24)]).write_u32::<LittleEndian>(u32::MAX - 1).unwrap();
+        std::fs::write(&archive_path, &archive).unwrap();
+        assert_eq!(
+            ReadError::ArchiveNotValid(String::from("compressed member data truncated")),
+            FileReader::open_archive_member(&archive_path, "data.nc").unwrap_err()
+        );
+    }
+    tmp_dir.close().unwrap();
+}
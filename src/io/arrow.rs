@@ -0,0 +1,128 @@
+mod tests_arrow;
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use arrow_array::{Array, ArrayRef, FixedSizeListArray, PrimitiveArray, RecordBatch, RecordBatchOptions};
+use arrow_array::types::{
+    ArrowPrimitiveType,
+    Int8Type, UInt8Type, Int16Type, Int32Type, Float32Type, Float64Type, UInt16Type, UInt32Type, Int64Type, UInt64Type,
+};
+use arrow_schema::{ArrowError, DataType as ArrowDataType, Field, Schema};
+
+use crate::{FileReader, DataType, DataVector, NcType, Variable};
+use crate::error::ReadError;
+
+/// Reads variables into an Arrow [`RecordBatch`](https://docs.rs/arrow-array/latest/arrow_array/struct.RecordBatch.html), one column per variable.
+///
+/// Requires the optional feature `arrow`.
+///
+/// - The *record* variables become one row per record. Each row of a column contains the values of one record in a
+///   fixed-size list, or a single value if the variable has no other dimension than the unlimited one.
+/// - The *fixed-size* variables become a single row, containing all their values in a fixed-size list (or the value of
+///   the scalar variables). The *record* and the *fixed-size* variables cannot be mixed.
+/// - The fill values (see [`Variable::fill_value`](struct.Variable.html#method.fill_value)) are null values.
+///
+/// # Example
+///
+/// ```
+/// use arrow_array::{Array, FixedSizeListArray, RecordBatch};
+/// use netcdf3::{FileReader, to_arrow};
+/// # use copy_to_tmp_file::{copy_bytes_to_tmp_file, NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME};
+/// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+///
+/// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+/// let batch: RecordBatch = to_arrow(&mut file_reader, &["time", "temperature_f32"]).unwrap();
+/// assert_eq!(2,                                           batch.num_rows());
+/// assert_eq!(2,                                           batch.num_columns());
+///
+/// // The 3 latitudes x 5 longitudes of each record
+/// let temperature = batch.column(1).as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+/// assert_eq!(15,                                          temperature.value_length());
+/// # tmp_dir.close();
+/// ```
+pub fn to_arrow<T: AsRef<str>>(reader: &mut FileReader, var_names: &[T]) -> Result<RecordBatch, ReadError> {
+    let vars: Vec<Variable> = var_names.iter().map(|var_name: &T| {
+        let var_name: &str = var_name.as_ref();
+        return reader.data_set().get_var(var_name).cloned().ok_or_else(|| ReadError::VariableNotDefined(var_name.to_owned()));
+    }).collect::<Result<Vec<Variable>, ReadError>>()?;
+    let num_rows: usize = match vars.iter().any(|var: &Variable| var.is_record_var()) {
+        true => {
+            if let Some(fixed_var) = vars.iter().find(|var: &&Variable| !var.is_record_var()) {
+                return Err(ReadError::NotRecordVariable(fixed_var.name().to_owned()));
+            }
+            reader.data_set().num_records().unwrap_or(0)
+        },
+        false => if vars.is_empty() { 0 } else { 1 },
+    };
+
+    let mut fields: Vec<Field> = vec![];
+    let mut columns: Vec<ArrayRef> = vec![];
+    for var in vars.iter() {
+        let var_name: &str = var.name();
+        // The values of each row
+        let row_len: usize = match var.is_record_var() {
+            true => var.chunk_len(),
+            false => var.len(),
+        };
+        let data: DataVector = reader.read_var(var_name)?;
+        let values: ArrayRef = to_arrow_array(var, data);
+        let has_list: bool = var.num_dims() > (if var.is_record_var() { 1 } else { 0 });
+        let (field, column): (Field, ArrayRef) = match has_list {
+            false => (Field::new(var_name, values.data_type().clone(), true), values),
+            true => {
+                let item_field = Arc::new(Field::new("item", values.data_type().clone(), true));
+                let list_len: i32 = i32::try_from(row_len).map_err(|_err| ReadError::Arrow(format!("too many values per row in the variable '{}'", var_name)))?;
+                let column = FixedSizeListArray::try_new(item_field, list_len, values, None).map_err(|err: ArrowError| ReadError::Arrow(err.to_string()))?;
+                (Field::new(var_name, column.data_type().clone(), false), Arc::new(column) as ArrayRef)
+            },
+        };
+        fields.push(field);
+        columns.push(column);
+    }
+    let options = RecordBatchOptions::new().with_row_count(Some(num_rows));
+    return RecordBatch::try_new_with_options(Arc::new(Schema::new(fields)), columns, &options).map_err(|err: ArrowError| ReadError::Arrow(err.to_string()));
+}
+
+/// Converts the values of the variable into an Arrow array, the fill values being null values.
+pub(crate) fn to_arrow_array(var: &Variable, data: DataVector) -> ArrayRef {
+    return match data {
+        DataVector::I8(data) => primitive_array::<i8, Int8Type>(var, data),
+        DataVector::U8(data) => primitive_array::<u8, UInt8Type>(var, data),
+        DataVector::I16(data) => primitive_array::<i16, Int16Type>(var, data),
+        DataVector::I32(data) => primitive_array::<i32, Int32Type>(var, data),
+        DataVector::F32(data) => primitive_array::<f32, Float32Type>(var, data),
+        DataVector::F64(data) => primitive_array::<f64, Float64Type>(var, data),
+        DataVector::U16(data) => primitive_array::<u16, UInt16Type>(var, data),
+        DataVector::U32(data) => primitive_array::<u32, UInt32Type>(var, data),
+        DataVector::I64(data) => primitive_array::<i64, Int64Type>(var, data),
+        DataVector::U64(data) => primitive_array::<u64, UInt64Type>(var, data),
+    };
+}
+
+/// Returns the Arrow data type of the NetCDF data type.
+pub(crate) fn arrow_data_type(data_type: DataType) -> ArrowDataType {
+    return match data_type {
+        DataType::I8 => ArrowDataType::Int8,
+        DataType::U8 => ArrowDataType::UInt8,
+        DataType::I16 => ArrowDataType::Int16,
+        DataType::I32 => ArrowDataType::Int32,
+        DataType::F32 => ArrowDataType::Float32,
+        DataType::F64 => ArrowDataType::Float64,
+        DataType::U16 => ArrowDataType::UInt16,
+        DataType::U32 => ArrowDataType::UInt32,
+        DataType::I64 => ArrowDataType::Int64,
+        DataType::U64 => ArrowDataType::UInt64,
+    };
+}
+
+/// Converts the values into an Arrow array, the fill values being null values.
+fn primitive_array<T, A>(var: &Variable, data: Vec<T>) -> ArrayRef
+where
+    T: NcType,
+    A: ArrowPrimitiveType<Native = T>,
+{
+    let fill_value: Option<T> = var.fill_value::<T>();
+    let values: PrimitiveArray<A> = data.into_iter().map(|value: T| if Some(value) == fill_value { None } else { Some(value) }).collect();
+    return Arc::new(values);
+}
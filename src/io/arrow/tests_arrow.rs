@@ -0,0 +1,89 @@
+#![cfg(test)]
+use std::path::PathBuf;
+
+use arrow_array::{Array, FixedSizeListArray, Float32Array, Float64Array, Int16Array, RecordBatch};
+use arrow_schema::DataType as ArrowDataType;
+use tempdir::TempDir;
+
+use crate::{DataSet, FileReader, FileWriter, Version, to_arrow};
+use crate::error::ReadError;
+
+const TMP_DIR_PREFIX: &str = "netcdf3_tests_";
+
+fn write_test_file(file_path: &PathBuf) {
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 3).unwrap();
+    data_set.add_fixed_dim("lat", 2).unwrap();
+    data_set.add_var_f64("time", &["time"]).unwrap();
+    data_set.add_var_i16("temp", &["time", "lat"]).unwrap();
+    data_set.add_var_attr_i16("temp", "_FillValue", vec![-1]).unwrap();
+    data_set.add_var_f32("lat", &["lat"]).unwrap();
+    data_set.add_var_f64("scalar", &[] as &[&str]).unwrap();
+
+    let mut file_writer: FileWriter = FileWriter::open(file_path).unwrap();
+    file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    file_writer.write_var_f64("time", &[0.0, 1.0, 2.0]).unwrap();
+    file_writer.write_var_i16("temp", &[1, 2, -1, 4, 5, 6]).unwrap();
+    file_writer.write_var_f32("lat", &[10.0, 20.0]).unwrap();
+    file_writer.write_var_f64("scalar", &[1.5]).unwrap();
+    file_writer.close().unwrap();
+}
+
+#[test]
+fn test_to_arrow_record_vars() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let file_path: PathBuf = tmp_dir.path().join("arrow.nc");
+    write_test_file(&file_path);
+
+    let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    let batch: RecordBatch = to_arrow(&mut file_reader, &["time", "temp"]).unwrap();
+    assert_eq!(3,                                           batch.num_rows());
+    assert_eq!(vec!["time", "temp"],                        batch.schema().fields().iter().map(|field| field.name().as_str()).collect::<Vec<&str>>());
+
+    // The record variable without other dimension
+    assert_eq!(&ArrowDataType::Float64,                     batch.column(0).data_type());
+    let time = batch.column(0).as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(&[0.0, 1.0, 2.0],                            time.values().as_ref());
+
+    // One fixed-size list per record, the fill values are null values
+    let temp = batch.column(1).as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    assert_eq!(2,                                           temp.value_length());
+    let record = temp.value(1);
+    let record = record.as_any().downcast_ref::<Int16Array>().unwrap();
+    assert_eq!(true,                                        record.is_null(0));
+    assert_eq!(4,                                           record.value(1));
+    assert_eq!(1,                                           temp.values().null_count());
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_to_arrow_fixed_size_vars() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let file_path: PathBuf = tmp_dir.path().join("arrow.nc");
+    write_test_file(&file_path);
+
+    let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    let batch: RecordBatch = to_arrow(&mut file_reader, &["lat", "scalar"]).unwrap();
+    assert_eq!(1,                                           batch.num_rows());
+    let lat = batch.column(0).as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    assert_eq!(2,                                           lat.value_length());
+    assert_eq!(&[10.0, 20.0],                               lat.values().as_any().downcast_ref::<Float32Array>().unwrap().values().as_ref());
+    let scalar = batch.column(1).as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(&[1.5],                                      scalar.values().as_ref());
+
+    // No variable
+    let batch: RecordBatch = to_arrow::<&str>(&mut file_reader, &[]).unwrap();
+    assert_eq!(0,                                           batch.num_rows());
+    assert_eq!(0,                                           batch.num_columns());
+
+    // Errors
+    assert_eq!(
+        ReadError::NotRecordVariable(String::from("lat")),
+        to_arrow(&mut file_reader, &["temp", "lat"]).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        to_arrow(&mut file_reader, &["temp", "undef_var"]).unwrap_err()
+    );
+    tmp_dir.close().unwrap();
+}
@@ -0,0 +1,146 @@
+mod tests_compare;
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::{FileReader, DataDiff, DataSet, DataSetDiff, DataVector, Tolerance, Variable, compare_vars};
+use crate::error::ReadError;
+
+/// Maximum number of values of each variable read at once by [`compare_files`](fn.compare_files.html)
+const COMPARE_BLOCK_LEN: usize = 65_536;
+
+/// Options of the function [`compare_files`](fn.compare_files.html).
+///
+/// By default the values must be strictly equal.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CompareOptions {
+    /// Tolerance used to compare the data of the variables
+    pub tolerance: Tolerance,
+    /// Tolerances of some variables, by variable name, replacing the default `tolerance`
+    pub var_tolerances: HashMap<String, Tolerance>,
+}
+
+/// Differences between two NetCDF-3 files, returned by [`compare_files`](fn.compare_files.html).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FileDiff {
+    /// Structural differences between the headers
+    pub header: DataSetDiff,
+    /// Differences between the data of the variables defined in both files, only the differing variables are listed
+    pub data: Vec<(String, DataDiff)>,
+}
+
+impl FileDiff {
+
+    /// Returns `true` if no difference has been found.
+    pub fn is_empty(&self) -> bool {
+        return self.header.is_empty() && self.data.is_empty();
+    }
+
+    /// Returns the differences between the data of the variable, if any.
+    pub fn get_data_diff(&self, var_name: &str) -> Option<&DataDiff> {
+        return self.data.iter().find(|(name, _data_diff): &&(String, DataDiff)| name == var_name).map(|(_name, data_diff): &(String, DataDiff)| data_diff);
+    }
+}
+
+/// Compares two NetCDF-3 files : their headers and the data of their variables.
+///
+/// The headers are compared semantically (see [`DataSet::diff`](struct.DataSet.html#method.diff)), so the order of the
+/// definitions, the version and the padding bytes of the files do not matter. The data of the variables defined in both
+/// files are compared within the tolerance of the `options` (see [`compare_vars`](fn.compare_vars.html)), and are read
+/// by blocks along their first dimension, so the memory use does not depend on the size of the files.
+///
+/// The variables having the same shape are compared element by element. For the *record* variables having different
+/// numbers of records, only the common records are compared. The other variables are reported as different, without
+/// comparing their values.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{FileReader, FileWriter, DataSet, Version, Tolerance, FileDiff, CompareOptions, compare_files};
+/// # use tempdir::TempDir;
+/// # let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+/// # let (path_a, path_b) = (tmp_dir.path().join("a.nc"), tmp_dir.path().join("b.nc"));
+///
+/// let mut data_set: DataSet = DataSet::new();
+/// data_set.add_fixed_dim("x", 3).unwrap();
+/// data_set.add_var_f32("values", &["x"]).unwrap();
+/// data_set.add_global_attr_string("title", "Output").unwrap();
+/// for (path, version, values) in vec![(&path_a, Version::Classic, [1.0, 2.0, 3.0]), (&path_b, Version::Offset64Bit, [1.0, 2.5, 3.0])] {
+///     let mut file_writer: FileWriter = FileWriter::open(path).unwrap();
+///     file_writer.set_def(&data_set, version, 0).unwrap();
+///     file_writer.write_var_f32("values", &values[..]).unwrap();
+///     file_writer.close().unwrap();
+/// }
+///
+/// let diff: FileDiff = compare_files(&path_a, &path_b, &CompareOptions::default()).unwrap();
+/// assert_eq!(true,                    diff.header.is_empty());
+/// assert_eq!(vec![1],                 diff.get_data_diff("values").unwrap().indices);
+///
+/// let options = CompareOptions{tolerance: Tolerance::absolute(0.5), ..CompareOptions::default()};
+/// assert_eq!(true,                    compare_files(&path_a, &path_b, &options).unwrap().is_empty());
+/// # tmp_dir.close();
+/// ```
+pub fn compare_files<P: AsRef<Path>, Q: AsRef<Path>>(path_a: P, path_b: Q, options: &CompareOptions) -> Result<FileDiff, ReadError> {
+    let mut reader_a: FileReader = FileReader::open(path_a)?;
+    let mut reader_b: FileReader = FileReader::open(path_b)?;
+    let data_set_a: DataSet = reader_a.data_set().clone();
+    let data_set_b: DataSet = reader_b.data_set().clone();
+
+    let mut file_diff: FileDiff = FileDiff{
+        header: data_set_a.diff(&data_set_b),
+        data: vec![],
+    };
+    for var_a in data_set_a.get_vars() {
+        let var_b: &Variable = match data_set_b.get_var(var_a.name()) {
+            None => continue,
+            Some(var_b) => var_b,
+        };
+        let tolerance: Tolerance = options.var_tolerances.get(var_a.name()).cloned().unwrap_or(options.tolerance);
+        let data_diff: DataDiff = compare_var_data(&mut reader_a, var_a, &mut reader_b, var_b, tolerance)?;
+        if !data_diff.is_empty() {
+            file_diff.data.push((var_a.name().to_owned(), data_diff));
+        }
+    }
+    return Ok(file_diff);
+}
+
+/// Compares the data of a variable defined in both files, by blocks of rows along the first dimension.
+fn compare_var_data(reader_a: &mut FileReader, var_a: &Variable, reader_b: &mut FileReader, var_b: &Variable, tolerance: Tolerance) -> Result<DataDiff, ReadError> {
+    let (shape_a, shape_b): (Vec<usize>, Vec<usize>) = (var_a.shape(), var_b.shape());
+    let mut data_diff: DataDiff = DataDiff{
+        len: (var_a.len(), var_b.len()),
+        indices: vec![],
+        max_abs_diff: 0.0,
+    };
+    let same_inner_shape: bool = match var_a.is_record_var() && var_b.is_record_var() {
+        true => shape_a[1..] == shape_b[1..],
+        false => shape_a == shape_b,
+    };
+    if !same_inner_shape {
+        return Ok(data_diff);
+    }
+    if shape_a.is_empty() {
+        let (data_a, data_b): (DataVector, DataVector) = (reader_a.read_var(var_a.name())?, reader_b.read_var(var_b.name())?);
+        let block_diff: DataDiff = compare_vars((var_a, &data_a), (var_b, &data_b), tolerance);
+        data_diff.indices = block_diff.indices;
+        data_diff.max_abs_diff = block_diff.max_abs_diff;
+        return Ok(data_diff);
+    }
+
+    let num_rows: usize = shape_a[0].min(shape_b[0]);
+    let row_len: usize = shape_a[1..].iter().product();
+    let block_size: usize = (COMPARE_BLOCK_LEN / row_len.max(1)).max(1);
+    for first_row in (0..num_rows).step_by(block_size) {
+        let mut start: Vec<usize> = vec![0; shape_a.len()];
+        let mut count: Vec<usize> = shape_a.clone();
+        start[0] = first_row;
+        count[0] = block_size.min(num_rows - first_row);
+        let data_a: DataVector = reader_a.read_var_slice(var_a.name(), &start, &count)?;
+        let data_b: DataVector = reader_b.read_var_slice(var_b.name(), &start, &count)?;
+        let block_diff: DataDiff = compare_vars((var_a, &data_a), (var_b, &data_b), tolerance);
+        let offset: usize = first_row * row_len;
+        data_diff.indices.extend(block_diff.indices.into_iter().map(|index: usize| offset + index));
+        data_diff.max_abs_diff = data_diff.max_abs_diff.max(block_diff.max_abs_diff);
+    }
+    return Ok(data_diff);
+}
@@ -0,0 +1,120 @@
+#![cfg(test)]
+use std::path::{Path, PathBuf};
+
+use tempdir::TempDir;
+
+use crate::{DataSet, FileWriter, Version, Tolerance, DataDiff, FileDiff, CompareOptions, compare_files};
+use crate::error::ReadError;
+
+use super::COMPARE_BLOCK_LEN;
+
+const TMP_DIR_PREFIX: &str = "netcdf3_tests_";
+
+fn write_file(file_path: &Path, data_set: &DataSet, version: Version, write_data: impl FnOnce(&mut FileWriter)) {
+    let mut file_writer: FileWriter = FileWriter::open(file_path).unwrap();
+    file_writer.set_def(data_set, version, 0).unwrap();
+    write_data(&mut file_writer);
+    file_writer.close().unwrap();
+}
+
+#[test]
+fn test_compare_files_ordering_and_version() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let (path_a, path_b): (PathBuf, PathBuf) = (tmp_dir.path().join("a.nc"), tmp_dir.path().join("b.nc"));
+
+    // Same definitions in a different order, and different versions
+    let mut data_set_a: DataSet = DataSet::new();
+    data_set_a.set_unlimited_dim("time", 2).unwrap();
+    data_set_a.add_fixed_dim("x", 3).unwrap();
+    data_set_a.add_var_i32("counts", &["time", "x"]).unwrap();
+    data_set_a.add_var_f64("scalar", &[] as &[&str]).unwrap();
+    data_set_a.add_global_attr_string("title", "Output").unwrap();
+    data_set_a.add_global_attr_i32("run", vec![1]).unwrap();
+    let mut data_set_b: DataSet = DataSet::new();
+    data_set_b.add_fixed_dim("x", 3).unwrap();
+    data_set_b.set_unlimited_dim("time", 2).unwrap();
+    data_set_b.add_var_f64("scalar", &[] as &[&str]).unwrap();
+    data_set_b.add_var_i32("counts", &["time", "x"]).unwrap();
+    data_set_b.add_global_attr_i32("run", vec![1]).unwrap();
+    data_set_b.add_global_attr_string("title", "Output").unwrap();
+
+    write_file(&path_a, &data_set_a, Version::Classic, |file_writer: &mut FileWriter| {
+        file_writer.write_var_i32("counts", &[1, 2, 3, 4, 5, 6]).unwrap();
+        file_writer.write_var_f64("scalar", &[0.5]).unwrap();
+    });
+    write_file(&path_b, &data_set_b, Version::Cdf5, |file_writer: &mut FileWriter| {
+        file_writer.write_var_i32("counts", &[1, 2, 3, 4, 5, 6]).unwrap();
+        file_writer.write_var_f64("scalar", &[0.5]).unwrap();
+    });
+    assert_eq!(FileDiff::default(),             compare_files(&path_a, &path_b, &CompareOptions::default()).unwrap());
+
+    write_file(&path_b, &data_set_b, Version::Cdf5, |file_writer: &mut FileWriter| {
+        file_writer.write_var_i32("counts", &[1, 2, 3, 4, 0, 6]).unwrap();
+        file_writer.write_var_f64("scalar", &[0.75]).unwrap();
+    });
+    let diff: FileDiff = compare_files(&path_a, &path_b, &CompareOptions::default()).unwrap();
+    assert_eq!(true,                            diff.header.is_empty());
+    assert_eq!(
+        vec![
+            (String::from("counts"), DataDiff{len: (6, 6), indices: vec![4], max_abs_diff: 5.0}),
+            (String::from("scalar"), DataDiff{len: (1, 1), indices: vec![0], max_abs_diff: 0.25}),
+        ],
+        diff.data
+    );
+
+    // Tolerance of a single variable
+    let mut options: CompareOptions = CompareOptions::default();
+    options.var_tolerances.insert(String::from("scalar"), Tolerance::absolute(0.25));
+    let diff: FileDiff = compare_files(&path_a, &path_b, &options).unwrap();
+    assert_eq!(None,                            diff.get_data_diff("scalar"));
+    assert_eq!(Some(&vec![4]),                  diff.get_data_diff("counts").map(|data_diff: &DataDiff| &data_diff.indices));
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_compare_files_shapes() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let (path_a, path_b): (PathBuf, PathBuf) = (tmp_dir.path().join("a.nc"), tmp_dir.path().join("b.nc"));
+    let num_rows: usize = COMPARE_BLOCK_LEN + 10;
+
+    let new_data_set = |num_records: usize, x_size: usize| -> DataSet {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.set_unlimited_dim("time", num_records).unwrap();
+        data_set.add_fixed_dim("x", x_size).unwrap();
+        data_set.add_var_u16("values", &["time"]).unwrap();
+        data_set.add_var_u8("grid", &["x"]).unwrap();
+        return data_set;
+    };
+    let data_set_a: DataSet = new_data_set(num_rows, 2);
+    let data_set_b: DataSet = new_data_set(num_rows + 1, 3);
+
+    let values: Vec<u16> = (0..num_rows).map(|i: usize| (i % 1000) as u16).collect();
+    write_file(&path_a, &data_set_a, Version::Cdf5, |file_writer: &mut FileWriter| {
+        file_writer.write_var_u16("values", &values).unwrap();
+    });
+    write_file(&path_b, &data_set_b, Version::Cdf5, |file_writer: &mut FileWriter| {
+        let mut values: Vec<u16> = values.clone();
+        values[num_rows - 1] += 1;
+        values.push(0);
+        file_writer.write_var_u16("values", &values).unwrap();
+    });
+    let diff: FileDiff = compare_files(&path_a, &path_b, &CompareOptions::default()).unwrap();
+    assert_eq!(false,                           diff.header.is_empty());
+    // Only the common records are compared, in the second block
+    assert_eq!(
+        Some(&DataDiff{len: (num_rows, num_rows + 1), indices: vec![num_rows - 1], max_abs_diff: 1.0}),
+        diff.get_data_diff("values")
+    );
+    // Not compared
+    assert_eq!(
+        Some(&DataDiff{len: (2, 3), indices: vec![], max_abs_diff: 0.0}),
+        diff.get_data_diff("grid")
+    );
+
+    // Errors
+    assert_eq!(
+        ReadError::IOErrorKind(std::io::ErrorKind::NotFound),
+        compare_files(&path_a, tmp_dir.path().join("undef.nc"), &CompareOptions::default()).unwrap_err()
+    );
+    tmp_dir.close().unwrap();
+}
@@ -0,0 +1,54 @@
+mod tests_convert;
+
+use std::path::Path;
+
+use crate::{FileReader, FileWriter, DataSet, Version};
+use crate::error::WriteError;
+
+/// Rewrites a NetCDF-3 file into a new file with an other `version`, for example from the *classic* version to the *64-bit offset* version.
+///
+/// Only the header is rewritten, the data are copied byte-for-byte one chunk at a time, so the used memory does not depend on the size of the file.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{FileReader, Version};
+///
+/// const TEMP_F32_VAR_NAME: &str = "temperature_f32";
+///
+/// // ...
+/// # use copy_to_tmp_file::{
+/// #     copy_bytes_to_tmp_file,
+/// #     NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+/// # };
+/// #
+/// # // Copy bytes to an temporary file
+/// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+/// # let output_file_path = tmp_dir.path().join("converted.nc");
+///
+/// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+/// assert_eq!(Version::Classic,                                file_reader.version());
+///
+/// // Convert the file
+/// netcdf3::convert(&mut file_reader, &output_file_path, Version::Offset64Bit).unwrap();
+///
+/// // Read the converted file
+/// let mut converted_file_reader: FileReader = FileReader::open(&output_file_path).unwrap();
+/// assert_eq!(Version::Offset64Bit,                            converted_file_reader.version());
+/// assert_eq!(file_reader.data_set(),                          converted_file_reader.data_set());
+/// assert_eq!(file_reader.read_var_f32(TEMP_F32_VAR_NAME),     converted_file_reader.read_var_f32(TEMP_F32_VAR_NAME));
+/// # tmp_dir.close();
+/// ```
+pub fn convert<P: AsRef<Path>>(reader: &mut FileReader, output_file_path: P, version: Version) -> Result<(), WriteError>
+{
+    // The pending lazy attributes are decoded first, the writer copies the whole definition of the reader
+    reader.decode_lazy_attrs()?;
+    let data_set: DataSet = reader.data_set().clone();
+    let mut writer: FileWriter = FileWriter::create_new(output_file_path)?;
+    writer.set_def(&data_set, version, 0)?;
+    for var in data_set.vars.iter() {
+        writer.copy_var_bytes(reader, &var.name)?;
+    }
+    writer.close()?;
+    Ok(())
+}
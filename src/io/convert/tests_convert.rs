@@ -0,0 +1,113 @@
+#![cfg(test)]
+use std::path::{Path, PathBuf};
+
+use crate::{FileReader, Version, Variable};
+use crate::error::WriteError;
+
+use super::convert;
+
+use copy_to_tmp_file::{
+    copy_bytes_to_tmp_file,
+    NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+    NC3_64BIT_OFFSET_FILE_NAME, NC3_64BIT_OFFSET_FILE_BYTES,
+};
+
+/// Returns the bytes of the data part of the file (following the header).
+fn read_data_part(file_path: &Path) -> Vec<u8> {
+    let file_reader: FileReader = FileReader::open(file_path).unwrap();
+    let data_part_start: usize = file_reader.data_set().vars.iter()
+        .map(|var: &Variable| i64::from(file_reader.var_begin_offset(&var.name).unwrap()) as usize)
+        .min()
+        .unwrap();
+    return std::fs::read(file_path).unwrap()[data_part_start..].to_vec();
+}
+
+#[test]
+fn test_convert_classic_to_64bit_offset() {
+    let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    let output_file_path: PathBuf = tmp_dir.path().join("test_convert.nc");
+
+    let mut file_reader: FileReader = FileReader::open(&input_file_path).unwrap();
+    convert(&mut file_reader, &output_file_path, Version::Offset64Bit).unwrap();
+
+    let mut converted_file_reader: FileReader = FileReader::open(&output_file_path).unwrap();
+    assert_eq!(Version::Offset64Bit,                        converted_file_reader.version());
+    assert_eq!(file_reader.data_set(),                      converted_file_reader.data_set());
+    assert_eq!(file_reader.read_all_vars(),                 converted_file_reader.read_all_vars());
+    // The data are copied byte-for-byte
+    assert_eq!(read_data_part(&input_file_path),            read_data_part(&output_file_path));
+
+    // The output file must not exist
+    assert_eq!(
+        WriteError::IOErrorKind(std::io::ErrorKind::AlreadyExists),
+        convert(&mut file_reader, &output_file_path, Version::Offset64Bit).unwrap_err()
+    );
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_convert_64bit_offset_to_classic() {
+    let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_64BIT_OFFSET_FILE_BYTES, NC3_64BIT_OFFSET_FILE_NAME);
+    let output_file_path: PathBuf = tmp_dir.path().join("test_convert.nc");
+
+    let mut file_reader: FileReader = FileReader::open(&input_file_path).unwrap();
+    convert(&mut file_reader, &output_file_path, Version::Classic).unwrap();
+
+    let mut converted_file_reader: FileReader = FileReader::open(&output_file_path).unwrap();
+    assert_eq!(Version::Classic,                            converted_file_reader.version());
+    assert_eq!(file_reader.data_set(),                      converted_file_reader.data_set());
+    assert_eq!(file_reader.read_all_vars(),                 converted_file_reader.read_all_vars());
+    assert_eq!(read_data_part(&input_file_path),            read_data_part(&output_file_path));
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_convert_to_cdf5() {
+    let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    let output_file_path: PathBuf = tmp_dir.path().join("test_convert.nc");
+
+    let mut file_reader: FileReader = FileReader::open(&input_file_path).unwrap();
+    convert(&mut file_reader, &output_file_path, Version::Cdf5).unwrap();
+
+    let mut converted_file_reader: FileReader = FileReader::open(&output_file_path).unwrap();
+    assert_eq!(Version::Cdf5,                               converted_file_reader.version());
+    assert_eq!(file_reader.data_set(),                      converted_file_reader.data_set());
+    assert_eq!(file_reader.read_all_vars(),                 converted_file_reader.read_all_vars());
+    assert_eq!(read_data_part(&input_file_path),            read_data_part(&output_file_path));
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_convert_from_bytes() {
+    let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    let output_file_path: PathBuf = tmp_dir.path().join("test_convert.nc");
+
+    // The reader is not backed by a file
+    let mut file_reader: FileReader = FileReader::from_bytes(NC3_CLASSIC_FILE_BYTES.to_vec()).unwrap();
+    convert(&mut file_reader, &output_file_path, Version::Offset64Bit).unwrap();
+
+    let mut converted_file_reader: FileReader = FileReader::open(&output_file_path).unwrap();
+    assert_eq!(Version::Offset64Bit,                        converted_file_reader.version());
+    assert_eq!(file_reader.data_set(),                      converted_file_reader.data_set());
+    assert_eq!(file_reader.read_all_vars(),                 converted_file_reader.read_all_vars());
+    assert_eq!(read_data_part(&input_file_path),            read_data_part(&output_file_path));
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_convert_with_lazy_attrs() {
+    let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    let output_file_path: PathBuf = tmp_dir.path().join("test_convert.nc");
+
+    // All the non-eager attributes are loaded lazily
+    let mut file_reader: FileReader = FileReader::open_with_lazy_attrs(&input_file_path, 0).unwrap();
+    convert(&mut file_reader, &output_file_path, Version::Offset64Bit).unwrap();
+
+    let mut converted_file_reader: FileReader = FileReader::open(&output_file_path).unwrap();
+    let mut input_file_reader: FileReader = FileReader::open(&input_file_path).unwrap();
+    assert_eq!(Version::Offset64Bit,                        converted_file_reader.version());
+    assert_eq!(input_file_reader.data_set(),                converted_file_reader.data_set());
+    assert_eq!(input_file_reader.read_all_vars(),           converted_file_reader.read_all_vars());
+    assert_eq!(read_data_part(&input_file_path),            read_data_part(&output_file_path));
+    tmp_dir.close().unwrap();
+}
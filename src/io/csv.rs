@@ -0,0 +1,298 @@
+mod tests_csv;
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::{BufRead, Write};
+
+use crate::{FileReader, DataSet, DataVector, DateTime, InMemoryDataSet, NcType, TimeUnits, Variable, NC_FILL_F64};
+use crate::error::{ReadError, WriteError};
+use crate::io::flatten::{FlatVars, gather};
+
+/// Maximum number of rows read at once by [`export_csv`](fn.export_csv.html)
+const CSV_BLOCK_LEN: usize = 65_536;
+
+/// Options of the function [`export_csv`](fn.export_csv.html).
+///
+/// By default the fields are separated by commas, the first line contains the column names, and the fill values are
+/// empty fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// Separator of the fields
+    pub delimiter: char,
+    /// Write the column names in the first line
+    pub header: bool,
+    /// Text written in place of the fill values
+    pub fill_text: String,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        return CsvOptions{
+            delimiter: ',',
+            header: true,
+            fill_text: String::new(),
+        };
+    }
+}
+
+/// Exports variables as CSV, flattened with the coordinates of their dimensions.
+///
+/// The variables must have the same dimensions. The CSV has one line per element of the variables, and the columns :
+/// - one column per dimension, containing the values of its *coordinate variable* (the 1-D variable having the same
+///   name as the dimension), or the indices along the dimension,
+/// - then one column per exported variable (the coordinate variables are only exported once).
+///
+/// The variables are read by blocks of rows along their first dimension, so the memory use does not depend on their
+/// size. Wrapping the `writer` into a [`BufWriter`](https://doc.rust-lang.org/std/io/struct.BufWriter.html) is recommended.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{FileReader, CsvOptions, export_csv};
+/// # use copy_to_tmp_file::{copy_bytes_to_tmp_file, NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME};
+/// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+///
+/// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+/// let mut csv: Vec<u8> = vec![];
+/// export_csv(&mut file_reader, &["temperature_f32"], &mut csv, &CsvOptions::default()).unwrap();
+///
+/// let csv: String = String::from_utf8(csv).unwrap();
+/// let mut lines = csv.lines();
+/// assert_eq!(Some("time,latitude,longitude,temperature_f32"),   lines.next());
+/// assert_eq!(Some("438300,0,0,0"),                               lines.next());
+/// // The remaining values of the 2 times x 3 latitudes x 5 longitudes
+/// assert_eq!(29,                                                  lines.count());
+/// # tmp_dir.close();
+/// ```
+pub fn export_csv<T: AsRef<str>, W: Write>(reader: &mut FileReader, var_names: &[T], writer: &mut W, options: &CsvOptions) -> Result<(), WriteError> {
+    let flat_vars = FlatVars::new(reader, var_names)?;
+    let delimiter: String = options.delimiter.to_string();
+
+    let column_names: Vec<String> = flat_vars.dim_names.iter()
+        .map(|dim_name: &String| dim_name.as_str())
+        .chain(flat_vars.value_vars.iter().map(|var: &Variable| var.name()))
+        .map(|name: &str| escape_field(name, options.delimiter))
+        .collect();
+    if options.header && !column_names.is_empty() {
+        writeln!(writer, "{}", column_names.join(&delimiter))?;
+    }
+    for (start, count) in flat_vars.blocks(CSV_BLOCK_LEN) {
+        let mut columns: Vec<Vec<String>> = vec![];
+        for (dim_index, coord_var) in flat_vars.coord_vars.iter().enumerate() {
+            let indices: Vec<usize> = FlatVars::dim_indices(dim_index, &start, &count);
+            columns.push(match coord_var {
+                None => indices.iter().map(|index: &usize| index.to_string()).collect(),
+                Some((coord_var, coord_data)) => format_values(coord_var, &gather(coord_data, &indices), &options.fill_text),
+            });
+        }
+        for var in flat_vars.value_vars.iter() {
+            let data: DataVector = FlatVars::read_block(reader, var, &start, &count)?;
+            columns.push(format_values(var, &data, &options.fill_text));
+        }
+        let num_rows: usize = count.iter().product();
+        let mut lines: String = String::new();
+        for row in 0..num_rows {
+            for (column_index, column) in columns.iter().enumerate() {
+                if column_index > 0 {
+                    lines.push(options.delimiter);
+                }
+                lines.push_str(&column[row]);
+            }
+            lines.push('\n');
+        }
+        writer.write_all(lines.as_bytes())?;
+    }
+    writer.flush()?;
+    return Ok(());
+}
+
+/// Options of the function [`import_csv`](fn.import_csv.html).
+///
+/// By default the fields are separated by commas, the time column is named `time`, and the variables have no units.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvImportOptions {
+    /// Separator of the fields
+    pub delimiter: char,
+    /// Name of the time column, also the name of the *unlimited-size* dimension
+    pub time_column: String,
+    /// Units of the time variable, the date times of the time column (e.g. `2020-01-01 06:00:00`) are converted into
+    /// numbers if they are CF time units (e.g. `hours since 2020-01-01`)
+    pub time_units: Option<String>,
+    /// Units of the value columns, by column name
+    pub units: HashMap<String, String>,
+    /// Text of the missing values (in addition to the empty fields), replaced by the fill value
+    pub fill_text: String,
+}
+
+impl Default for CsvImportOptions {
+    fn default() -> Self {
+        return CsvImportOptions{
+            delimiter: ',',
+            time_column: String::from("time"),
+            time_units: None,
+            units: HashMap::new(),
+            fill_text: String::new(),
+        };
+    }
+}
+
+/// Imports a CSV table, having a time column and value columns, into a data set.
+///
+/// The first line contains the column names and each following line is a record. The data set has :
+/// - the *unlimited-size* dimension named after the time column, its size is the number of records,
+/// - one `NC_DOUBLE` variable per column along this dimension, having the `units` given by the `options`.
+///
+/// The empty fields and the fields equal to the `fill_text` are replaced by the default fill value. The fields can be
+/// quoted (RFC 4180), but cannot contain line breaks. The data set can then be written with
+/// [`InMemoryDataSet::write_file`](struct.InMemoryDataSet.html#method.write_file).
+///
+/// Returns the error `ReadError::TimeUnitsNotValid` if the `time_units` are not valid CF time units,
+/// `ReadError::CsvColumnNotFound` if the time column is missing, `ReadError::CsvLineNotValid` if a line does not have
+/// a field per column, and `ReadError::CsvValueNotValid` if a value cannot be parsed.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Cursor;
+/// use netcdf3::{FileReader, InMemoryDataSet, Version, CsvImportOptions, import_csv};
+/// # use tempdir::TempDir;
+/// # let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+/// # let file_path = tmp_dir.path().join("weather.nc");
+///
+/// let csv: &str = "time,temperature,pressure\n\
+///                  2020-01-01 00:00,4.5,1013.2\n\
+///                  2020-01-01 06:00,,1012.8\n";
+/// let mut options = CsvImportOptions::default();
+/// options.time_units = Some(String::from("hours since 2020-01-01"));
+/// options.units.insert(String::from("temperature"), String::from("degC"));
+///
+/// let in_memory: InMemoryDataSet = import_csv(Cursor::new(csv), &options).unwrap();
+/// in_memory.write_file(&file_path, Version::Classic).unwrap();
+///
+/// let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+/// assert_eq!(Some(2),                                 file_reader.data_set().num_records());
+/// assert_eq!(vec![0.0, 6.0],                          file_reader.read_var_f64("time").unwrap());
+/// assert_eq!(Some(String::from("degC")),              file_reader.data_set().get_var_attr_as_string("temperature", "units"));
+/// assert_eq!(vec![1013.2, 1012.8],                    file_reader.read_var_f64("pressure").unwrap());
+/// # tmp_dir.close();
+/// ```
+pub fn import_csv<R: BufRead>(csv: R, options: &CsvImportOptions) -> Result<InMemoryDataSet, ReadError> {
+    let time_units: Option<TimeUnits> = match options.time_units.as_deref() {
+        None => None,
+        Some(time_units) => Some(TimeUnits::parse(time_units).ok_or_else(|| ReadError::TimeUnitsNotValid(options.time_column.clone()))?),
+    };
+    let mut lines = csv.lines().enumerate().filter(|(_line_index, line): &(usize, std::io::Result<String>)| {
+        line.as_ref().map(|line: &String| !line.trim().is_empty()).unwrap_or(true)
+    });
+
+    let column_names: Vec<String> = match lines.next() {
+        None => vec![],
+        Some((line_index, line)) => split_fields(&line?, options.delimiter).ok_or(ReadError::CsvLineNotValid(line_index + 1))?,
+    };
+    let column_names: Vec<String> = column_names.iter().map(|column_name: &String| column_name.trim().to_owned()).collect();
+    let time_index: usize = column_names.iter().position(|column_name: &String| column_name == &options.time_column)
+        .ok_or_else(|| ReadError::CsvColumnNotFound(options.time_column.clone()))?;
+
+    let mut columns: Vec<Vec<f64>> = vec![vec![]; column_names.len()];
+    for (line_index, line) in lines {
+        let fields: Vec<String> = split_fields(&line?, options.delimiter)
+            .filter(|fields: &Vec<String>| fields.len() == column_names.len())
+            .ok_or(ReadError::CsvLineNotValid(line_index + 1))?;
+        for (column_index, field) in fields.iter().enumerate() {
+            let field: &str = field.trim();
+            let value: Option<f64> = match field.is_empty() || field == options.fill_text {
+                true => Some(NC_FILL_F64),
+                false => field.parse::<f64>().ok().or_else(|| match (column_index == time_index, time_units.as_ref()) {
+                    (true, Some(time_units)) => DateTime::parse(field).map(|date_time: DateTime| time_units.to_value(&date_time)),
+                    _ => None,
+                }),
+            };
+            columns[column_index].push(value.ok_or_else(|| ReadError::CsvValueNotValid{
+                line: line_index + 1,
+                column: column_names[column_index].clone(),
+                value: field.to_owned(),
+            })?);
+        }
+    }
+
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim(&options.time_column, columns[time_index].len())?;
+    for column_name in column_names.iter() {
+        data_set.add_var_f64(column_name, &[&options.time_column])?;
+        let units: Option<&String> = match column_name == &options.time_column {
+            true => options.time_units.as_ref(),
+            false => options.units.get(column_name),
+        };
+        if let Some(units) = units {
+            data_set.add_var_attr_string(column_name, "units", units)?;
+        }
+    }
+    let mut in_memory: InMemoryDataSet = InMemoryDataSet::new(data_set);
+    for (column_name, values) in column_names.into_iter().zip(columns.into_iter()) {
+        in_memory.set_var(&column_name, values)?;
+    }
+    return Ok(in_memory);
+}
+
+/// Splits a CSV line into its fields, the quoted fields are unquoted (RFC 4180).
+///
+/// Returns `None` if a quote is not closed.
+fn split_fields(line: &str, delimiter: char) -> Option<Vec<String>> {
+    let line: &str = line.strip_suffix('\r').unwrap_or(line);
+    let mut fields: Vec<String> = vec![];
+    let mut field: String = String::new();
+    let mut chars = line.chars().peekable();
+    let mut in_quotes: bool = false;
+    while let Some(c) = chars.next() {
+        match (in_quotes, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            },
+            (true, '"') => in_quotes = false,
+            (true, c) => field.push(c),
+            (false, '"') if field.trim().is_empty() => {
+                field.clear();
+                in_quotes = true;
+            },
+            (false, c) if c == delimiter => fields.push(std::mem::take(&mut field)),
+            (false, c) => field.push(c),
+        }
+    }
+    if in_quotes {
+        return None;
+    }
+    fields.push(field);
+    return Some(fields);
+}
+
+/// Formats the values of the variable, the fill values being replaced by the `fill_text`.
+fn format_values(var: &Variable, data: &DataVector, fill_text: &str) -> Vec<String> {
+    fn format<T: NcType + Display>(var: &Variable, values: &[T], fill_text: &str) -> Vec<String> {
+        let fill_value: Option<T> = var.fill_value::<T>();
+        return values.iter().map(|value: &T| match Some(*value) == fill_value {
+            true => String::from(fill_text),
+            false => value.to_string(),
+        }).collect();
+    }
+    return match data {
+        DataVector::I8(values) => format(var, values, fill_text),
+        DataVector::U8(values) => format(var, values, fill_text),
+        DataVector::I16(values) => format(var, values, fill_text),
+        DataVector::I32(values) => format(var, values, fill_text),
+        DataVector::F32(values) => format(var, values, fill_text),
+        DataVector::F64(values) => format(var, values, fill_text),
+        DataVector::U16(values) => format(var, values, fill_text),
+        DataVector::U32(values) => format(var, values, fill_text),
+        DataVector::I64(values) => format(var, values, fill_text),
+        DataVector::U64(values) => format(var, values, fill_text),
+    };
+}
+
+/// Quotes the field if it contains the delimiter, a double quote or a line break (RFC 4180).
+fn escape_field(field: &str, delimiter: char) -> String {
+    return match field.contains(&[delimiter, '"', '\n', '\r'][..]) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => String::from(field),
+    };
+}
@@ -0,0 +1,200 @@
+#![cfg(test)]
+use std::path::PathBuf;
+
+use tempdir::TempDir;
+
+use std::io::Cursor;
+
+use crate::{DataSet, FileReader, FileWriter, InMemoryDataSet, Version, CsvOptions, CsvImportOptions, export_csv, import_csv, NC_FILL_F64};
+use crate::error::{ReadError, WriteError};
+
+use super::CSV_BLOCK_LEN;
+
+const TMP_DIR_PREFIX: &str = "netcdf3_tests_";
+
+fn export_to_string(file_reader: &mut FileReader, var_names: &[&str], options: &CsvOptions) -> String {
+    let mut csv: Vec<u8> = vec![];
+    export_csv(file_reader, var_names, &mut csv, options).unwrap();
+    return String::from_utf8(csv).unwrap();
+}
+
+#[test]
+fn test_export_csv() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let input_file_path: PathBuf = tmp_dir.path().join("input.nc");
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.set_unlimited_dim("time", 2).unwrap();
+        data_set.add_fixed_dim("lat", 3).unwrap();
+        data_set.add_var_f64("time", &["time"]).unwrap();
+        data_set.add_var_i16("temp", &["time", "lat"]).unwrap();
+        data_set.add_var_attr_i16("temp", "_FillValue", vec![-1]).unwrap();
+        data_set.add_var_f32("humidity, %", &["time", "lat"]).unwrap();
+        data_set.add_var_f32("lat_bounds", &["lat"]).unwrap();
+        let mut file_writer: FileWriter = FileWriter::open(&input_file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        file_writer.write_var_f64("time", &[100.0, 200.5]).unwrap();
+        file_writer.write_var_i16("temp", &[1, 2, 3, -1, 5, 6]).unwrap();
+        file_writer.write_var_f32("humidity, %", &[0.1, 0.2, 0.3, 0.4, 0.5, 0.6]).unwrap();
+        file_writer.write_var_f32("lat_bounds", &[0.0, 1.0, 2.0]).unwrap();
+        file_writer.close().unwrap();
+    }
+    let mut file_reader: FileReader = FileReader::open(&input_file_path).unwrap();
+
+    // The coordinate variable `time`, and the indices along the dimension `lat` (no coordinate variable)
+    assert_eq!(
+        "time,lat,temp,\"humidity, %\"\n\
+         100,0,1,0.1\n\
+         100,1,2,0.2\n\
+         100,2,3,0.3\n\
+         200.5,0,,0.4\n\
+         200.5,1,5,0.5\n\
+         200.5,2,6,0.6\n",
+        export_to_string(&mut file_reader, &["temp", "humidity, %"], &CsvOptions::default())
+    );
+    let options = CsvOptions{delimiter: ';', header: false, fill_text: String::from("NA")};
+    assert_eq!(
+        "100;0;1\n\
+         100;1;2\n\
+         100;2;3\n\
+         200.5;0;NA\n\
+         200.5;1;5\n\
+         200.5;2;6\n",
+        export_to_string(&mut file_reader, &["temp"], &options)
+    );
+    // The coordinate variable is only exported once
+    assert_eq!(
+        "time\n100\n200.5\n",
+        export_to_string(&mut file_reader, &["time"], &CsvOptions::default())
+    );
+    assert_eq!(
+        "",
+        export_to_string(&mut file_reader, &[], &CsvOptions::default())
+    );
+
+    // Errors
+    let mut csv: Vec<u8> = vec![];
+    assert_eq!(
+        WriteError::VariableMismatchDims{var_name: String::from("lat_bounds"), req: vec![String::from("time"), String::from("lat")], get: vec![String::from("lat")]},
+        export_csv(&mut file_reader, &["temp", "lat_bounds"], &mut csv, &CsvOptions::default()).unwrap_err()
+    );
+    assert_eq!(
+        WriteError::VariableNotDefined(String::from("undef_var")),
+        export_csv(&mut file_reader, &["undef_var"], &mut csv, &CsvOptions::default()).unwrap_err()
+    );
+    assert_eq!(true,                                csv.is_empty());
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_export_csv_by_blocks() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let input_file_path: PathBuf = tmp_dir.path().join("input.nc");
+    let num_rows: usize = CSV_BLOCK_LEN + 10;
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.add_fixed_dim("x", num_rows).unwrap();
+        data_set.add_var_i32("value", &["x"]).unwrap();
+        data_set.add_var_u8("scalar", &[] as &[&str]).unwrap();
+        let mut file_writer: FileWriter = FileWriter::open(&input_file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Cdf5, 0).unwrap();
+        file_writer.write_var_i32("value", &(0..num_rows).map(|i: usize| (2 * i) as i32).collect::<Vec<i32>>()).unwrap();
+        file_writer.write_var_u8("scalar", &[7]).unwrap();
+        file_writer.close().unwrap();
+    }
+    let mut file_reader: FileReader = FileReader::open(&input_file_path).unwrap();
+    let csv: String = export_to_string(&mut file_reader, &["value"], &CsvOptions::default());
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(num_rows + 1,                        lines.len());
+    assert_eq!("65545,131090",                      lines[num_rows]);
+
+    // The scalar variables have a single line
+    assert_eq!(
+        "scalar\n7\n",
+        export_to_string(&mut file_reader, &["scalar"], &CsvOptions::default())
+    );
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_import_csv() {
+    let csv: &str = "station,time,\"humidity, %\"\r\n\
+                     A,NA,10.5\r\n\
+                     \r\n\
+                     B,2020-01-02,\"\"\r\n";
+    let options = CsvImportOptions{
+        delimiter: ',',
+        time_column: String::from("time"),
+        time_units: Some(String::from("days since 2020-01-01")),
+        units: vec![(String::from("humidity, %"), String::from("%"))].into_iter().collect(),
+        fill_text: String::from("NA"),
+    };
+    // The column `station` is not numeric
+    assert_eq!(
+        ReadError::CsvValueNotValid{line: 2, column: String::from("station"), value: String::from("A")},
+        import_csv(Cursor::new(csv), &options).unwrap_err()
+    );
+
+    let csv: String = csv.replace("A,NA", "1,NA").replace("B,", "2,");
+    let in_memory: InMemoryDataSet = import_csv(Cursor::new(csv), &options).unwrap();
+    let data_set: &DataSet = in_memory.data_set();
+    assert_eq!(Some(2),                                         data_set.num_records());
+    assert_eq!(vec!["station", "time", "humidity, %"],          data_set.get_var_names());
+    assert_eq!(Some(String::from("days since 2020-01-01")),     data_set.get_var_attr_as_string("time", "units"));
+    assert_eq!(Some(String::from("%")),                         data_set.get_var_attr_as_string("humidity, %", "units"));
+    assert_eq!(None,                                            data_set.get_var_attr_as_string("station", "units"));
+    assert_eq!(Some(&[1.0, 2.0][..]),                           in_memory.get_var::<f64>("station"));
+    assert_eq!(Some(&[NC_FILL_F64, 1.0][..]),                   in_memory.get_var::<f64>("time"));
+    assert_eq!(Some(&[10.5, NC_FILL_F64][..]),                  in_memory.get_var::<f64>("humidity, %"));
+}
+
+#[test]
+fn test_import_csv_errors() {
+    let options = CsvImportOptions::default();
+    assert_eq!(
+        ReadError::CsvColumnNotFound(String::from("time")),
+        import_csv(Cursor::new("date,value\n0,1\n"), &options).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::CsvColumnNotFound(String::from("time")),
+        import_csv(Cursor::new(""), &options).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::CsvLineNotValid(3),
+        import_csv(Cursor::new("time,value\n0,1\n1\n"), &options).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::CsvLineNotValid(2),
+        import_csv(Cursor::new("time,value\n0,\"1\n"), &options).unwrap_err()
+    );
+    // The date times require CF time units
+    assert_eq!(
+        ReadError::CsvValueNotValid{line: 2, column: String::from("time"), value: String::from("2020-01-01")},
+        import_csv(Cursor::new("time,value\n2020-01-01,1\n"), &options).unwrap_err()
+    );
+    let mut options = CsvImportOptions::default();
+    options.time_units = Some(String::from("fortnights after 2020-01-01"));
+    assert_eq!(
+        ReadError::TimeUnitsNotValid(String::from("time")),
+        import_csv(Cursor::new("time,value\n2020-01-01,1\n"), &options).unwrap_err()
+    );
+}
+
+#[test]
+fn test_import_exported_csv() {
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 3).unwrap();
+    data_set.add_var_f64("time", &["time"]).unwrap();
+    data_set.add_var_f64("level; m", &["time"]).unwrap();
+    let mut file_writer: FileWriter = FileWriter::in_memory();
+    file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    file_writer.write_var_f64("time", &[0.0, 0.5, 1.0]).unwrap();
+    file_writer.write_var_f64("level; m", &[1.25, NC_FILL_F64, -3.0]).unwrap();
+    let mut file_reader: FileReader = FileReader::from_bytes(file_writer.close_into_bytes().unwrap()).unwrap();
+
+    let export_options = CsvOptions{delimiter: ';', header: true, fill_text: String::from("-")};
+    let csv: String = export_to_string(&mut file_reader, &["level; m"], &export_options);
+    let import_options = CsvImportOptions{delimiter: ';', fill_text: String::from("-"), ..CsvImportOptions::default()};
+    let in_memory: InMemoryDataSet = import_csv(Cursor::new(csv), &import_options).unwrap();
+    assert_eq!(file_reader.read_in_memory().unwrap(),          in_memory);
+}
@@ -0,0 +1,207 @@
+mod http;
+mod parser;
+mod tests_dap2;
+
+use crate::{DataSet, DataType, DataVector, Variable};
+use crate::error::ReadError;
+
+use parser::{DdsVar, DapType, DasContainers};
+
+/// Name, type and dimensions of a variable of a base type
+type BaseVar<'a> = (&'a str, &'a DapType, &'a [(Option<String>, usize)]);
+
+/// Allows to read the remote data sets served by an OPeNDAP server, with the DAP2 protocol.
+///
+/// Requires the optional feature `dap2`. Only the `http` scheme is supported.
+///
+/// The header of the data set is built from the DDS and the DAS responses, and the variables are read from the DODS responses.
+/// The slices are selected by the server with a constraint expression, so only the selected values are transferred.
+///
+/// The following rules are applied while building the [`DataSet`](struct.DataSet.html) :
+/// - The `Byte` variables and attributes are converted to `i8` values, and the `String` attributes to `u8` values.
+/// - The `String` and `Url` variables are ignored.
+/// - A `Grid` is read as a variable with the name of the grid, its maps are read as the other variables.
+/// - The anonymous dimensions are named `<var_name>_dim_<i>`.
+/// - The *unlimited-size* dimension is given by the attribute `Unlimited_Dimension` of the container `DODS_EXTRA`.
+/// - The attributes of the container `NC_GLOBAL` (or of any container ending by `_GLOBAL`) are the global attributes.
+///
+/// # Example
+///
+/// ```no_run
+/// use netcdf3::{Dap2Reader, DataVector};
+///
+/// let reader: Dap2Reader = Dap2Reader::open("http://test.opendap.org/opendap/data/nc/coads_climatology.nc").unwrap();
+/// let sst_units: Option<String> = reader.data_set().get_var_attr_as_string("SST", "units");
+///
+/// // Only the selected values are sent by the server
+/// let sst: DataVector = reader.read_var_slice("SST", &[0, 10, 20], &[1, 2, 3]).unwrap();
+/// assert_eq!(6,                   sst.len());
+/// ```
+#[derive(Debug)]
+pub struct Dap2Reader {
+    url: String,
+    data_set: DataSet,
+}
+
+impl Dap2Reader {
+
+    /// Requests the DDS and the DAS of the remote data set, and builds its definition.
+    ///
+    /// The `url` is the URL of the data set without the suffixes `.dds`, `.das` or `.dods`.
+    pub fn open(url: &str) -> Result<Dap2Reader, ReadError> {
+        let url: String = url.trim_end_matches('/').to_owned();
+        let dds_vars: Vec<DdsVar> = parser::parse_dds(&Dap2Reader::get_text(&format!("{}.dds", url))?)?;
+        let das_containers: DasContainers = parser::parse_das(&Dap2Reader::get_text(&format!("{}.das", url))?)?;
+        let data_set: DataSet = Dap2Reader::build_data_set(&dds_vars, das_containers)?;
+        return Ok(Dap2Reader{
+            url: url,
+            data_set: data_set,
+        });
+    }
+
+    /// Returns the URL of the remote data set.
+    pub fn url(&self) -> &str {
+        return &self.url;
+    }
+
+    /// Returns the definition of the remote data set.
+    pub fn data_set(&self) -> &DataSet {
+        return &self.data_set;
+    }
+
+    /// Reads all the values of the variable.
+    pub fn read_var(&self, var_name: &str) -> Result<DataVector, ReadError> {
+        let var: &Variable = self.data_set.get_var(var_name).ok_or_else(|| ReadError::VariableNotDefined(var_name.to_owned()))?;
+        return self.request_var(var, var_name.to_owned());
+    }
+
+    /// Reads a slice (an hyperslab) of the variable, selected by the server with a constraint expression.
+    ///
+    /// The arguments `start` and `count` are the same as [`FileReader::read_var_slice`](struct.FileReader.html#method.read_var_slice).
+    pub fn read_var_slice(&self, var_name: &str, start: &[usize], count: &[usize]) -> Result<DataVector, ReadError> {
+        let var: &Variable = self.data_set.get_var(var_name).ok_or_else(|| ReadError::VariableNotDefined(var_name.to_owned()))?;
+        if var.locate_slice(start, count).is_none() {
+            return Err(ReadError::SliceNotValid{
+                var_name: var_name.to_owned(),
+                start: start.to_vec(),
+                count: count.to_vec(),
+                shape: var.shape(),
+            });
+        }
+        if count.contains(&0) {
+            return Ok(DataVector::new(var.data_type(), 0));
+        }
+        // Constraint expression, e.g. `var_name[0:1][10:19]`
+        let hyperslab: String = start.iter().zip(count.iter()).map(|(start, count): (&usize, &usize)| {
+            format!("[{}:{}]", start, start + count - 1)
+        }).collect();
+        return self.request_var(var, format!("{}{}", var_name, hyperslab));
+    }
+
+    /// Requests the data of the variable selected by the constraint expression.
+    fn request_var(&self, var: &Variable, constraint: String) -> Result<DataVector, ReadError> {
+        let response: Vec<u8> = http::get(&format!("{}.dods?{}", self.url, http::encode_query(&constraint)))?;
+        let (dds, mut xdr_data): (String, &[u8]) = parser::split_dods(&response)?;
+        let dds_vars: Vec<DdsVar> = parser::parse_dds(&dds)?;
+        let dds_var: &DdsVar = dds_vars.iter().find(|dds_var: &&DdsVar| dds_var.name() == var.name())
+            .ok_or_else(|| ReadError::Dap2ResponseNotValid(format!("variable '{}' not found in the DODS response", var.name())))?;
+        // The variables before the requested one are skipped
+        for other_var in dds_vars.iter().take_while(|other_var: &&DdsVar| other_var.name() != var.name()) {
+            let _other_data: DataVector = parser::decode_xdr(other_var, &mut xdr_data)?;
+        }
+        let data: DataVector = parser::decode_xdr(dds_var, &mut xdr_data)?;
+        if data.data_type() != var.data_type() {
+            return Err(ReadError::Dap2ResponseNotValid(format!("data type of the variable '{}' not valid", var.name())));
+        }
+        return Ok(data);
+    }
+
+    fn get_text(url: &str) -> Result<String, ReadError> {
+        return String::from_utf8(http::get(url)?).map_err(|_err| ReadError::Dap2ResponseNotValid(format!("response of '{}' is not UTF-8", url)));
+    }
+
+    /// Builds the data set from the variables of the DDS and the attributes of the DAS.
+    fn build_data_set(dds_vars: &[DdsVar], das_containers: DasContainers) -> Result<DataSet, ReadError> {
+        let unlim_dim_name: Option<String> = das_containers.iter()
+            .filter(|(container_name, _attrs): &&(String, Vec<(String, DataVector)>)| container_name == "DODS_EXTRA")
+            .flat_map(|(_container_name, attrs): &(String, Vec<(String, DataVector)>)| attrs.iter())
+            .find(|(attr_name, _attr_data): &&(String, DataVector)| attr_name == "Unlimited_Dimension")
+            .and_then(|(_attr_name, attr_data): &(String, DataVector)| attr_data.get_u8())
+            .map(|bytes: &[u8]| String::from_utf8_lossy(bytes).into_owned());
+
+        // The arrays of the grids and their maps are flattened
+        let arrays = dds_vars.iter().map(|dds_var: &DdsVar| {
+            match dds_var {
+                DdsVar::Grid{name, array, ..} => (name.as_str(), array.as_ref()),
+                _ => (dds_var.name(), dds_var),
+            }
+        });
+        let maps = dds_vars.iter().flat_map(|dds_var: &DdsVar| {
+            match dds_var {
+                DdsVar::Grid{maps, ..} => maps.iter().collect(),
+                _ => vec![],
+            }
+        }).map(|map: &DdsVar| (map.name(), map));
+        let mut base_vars: Vec<BaseVar> = vec![];
+        for (var_name, dds_var) in arrays.chain(maps) {
+            if let DdsVar::Base{dap_type, dims, ..} = dds_var {
+                if dap_type.data_type().is_some() && !base_vars.iter().any(|(other_name, _, _)| *other_name == var_name) {
+                    base_vars.push((var_name, dap_type, dims));
+                }
+            }
+        }
+
+        let mut data_set: DataSet = DataSet::new();
+        let mut var_dim_names: Vec<Vec<String>> = vec![];
+        for (var_name, _dap_type, dims) in base_vars.iter() {
+            let mut dim_names: Vec<String> = vec![];
+            for (i, (dim_name, dim_size)) in dims.iter().enumerate() {
+                let dim_name: String = dim_name.clone().unwrap_or_else(|| format!("{}_dim_{}", var_name, i));
+                match data_set.dim_size(&dim_name) {
+                    Some(size) if size == *dim_size => {},
+                    Some(_) => return Err(ReadError::Dap2ResponseNotValid(format!("sizes of the dimension '{}' not consistent", dim_name))),
+                    None if Some(&dim_name) == unlim_dim_name.as_ref() => data_set.set_unlimited_dim(&dim_name, *dim_size)?,
+                    None => data_set.add_fixed_dim(&dim_name, *dim_size)?,
+                }
+                dim_names.push(dim_name);
+            }
+            var_dim_names.push(dim_names);
+        }
+        for ((var_name, dap_type, _dims), dim_names) in base_vars.iter().zip(var_dim_names.iter()) {
+            let data_type: DataType = dap_type.data_type().ok_or(ReadError::Unexpected)?;  // previously checked
+            data_set.add_var(var_name, dim_names, data_type)?;
+        }
+
+        for (container_name, attrs) in das_containers.into_iter() {
+            let is_global: bool = container_name == "NC_GLOBAL" || container_name.ends_with("_GLOBAL");
+            if !is_global && !data_set.has_var(&container_name) {
+                continue;
+            }
+            for (attr_name, attr_data) in attrs.into_iter() {
+                match (is_global, attr_data) {
+                    (true, DataVector::I8(data)) => data_set.add_global_attr_i8(&attr_name, data)?,
+                    (true, DataVector::U8(data)) => data_set.add_global_attr_u8(&attr_name, data)?,
+                    (true, DataVector::I16(data)) => data_set.add_global_attr_i16(&attr_name, data)?,
+                    (true, DataVector::I32(data)) => data_set.add_global_attr_i32(&attr_name, data)?,
+                    (true, DataVector::F32(data)) => data_set.add_global_attr_f32(&attr_name, data)?,
+                    (true, DataVector::F64(data)) => data_set.add_global_attr_f64(&attr_name, data)?,
+                    (true, DataVector::U16(data)) => data_set.add_global_attr_u16(&attr_name, data)?,
+                    (true, DataVector::U32(data)) => data_set.add_global_attr_u32(&attr_name, data)?,
+                    (true, DataVector::I64(data)) => data_set.add_global_attr_i64(&attr_name, data)?,
+                    (true, DataVector::U64(data)) => data_set.add_global_attr_u64(&attr_name, data)?,
+                    (false, DataVector::I8(data)) => data_set.add_var_attr_i8(&container_name, &attr_name, data)?,
+                    (false, DataVector::U8(data)) => data_set.add_var_attr_u8(&container_name, &attr_name, data)?,
+                    (false, DataVector::I16(data)) => data_set.add_var_attr_i16(&container_name, &attr_name, data)?,
+                    (false, DataVector::I32(data)) => data_set.add_var_attr_i32(&container_name, &attr_name, data)?,
+                    (false, DataVector::F32(data)) => data_set.add_var_attr_f32(&container_name, &attr_name, data)?,
+                    (false, DataVector::F64(data)) => data_set.add_var_attr_f64(&container_name, &attr_name, data)?,
+                    (false, DataVector::U16(data)) => data_set.add_var_attr_u16(&container_name, &attr_name, data)?,
+                    (false, DataVector::U32(data)) => data_set.add_var_attr_u32(&container_name, &attr_name, data)?,
+                    (false, DataVector::I64(data)) => data_set.add_var_attr_i64(&container_name, &attr_name, data)?,
+                    (false, DataVector::U64(data)) => data_set.add_var_attr_u64(&container_name, &attr_name, data)?,
+                }
+            }
+        }
+        return Ok(data_set);
+    }
+}
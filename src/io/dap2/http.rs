@@ -0,0 +1,96 @@
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+use crate::error::ReadError;
+
+/// Sends a HTTP/1.0 `GET` request and returns the body of the response.
+///
+/// Only the `http` scheme is supported.
+pub(crate) fn get(url: &str) -> Result<Vec<u8>, ReadError> {
+    let (host, path): (&str, &str) = split_url(url)?;
+    let address: String = match host.contains(':') {
+        true => host.to_owned(),
+        false => format!("{}:80", host),
+    };
+    let mut stream: TcpStream = TcpStream::connect(address)?;
+    write!(stream, "GET {} HTTP/1.0\r\nHost: {}\r\nAccept: */*\r\nConnection: close\r\n\r\n", path, host)?;
+    let mut response: Vec<u8> = vec![];
+    stream.read_to_end(&mut response)?;
+
+    // Status line and headers
+    const HEADERS_END: &[u8] = b"\r\n\r\n";
+    let headers_end: usize = response.windows(HEADERS_END.len()).position(|window: &[u8]| window == HEADERS_END)
+        .ok_or_else(|| ReadError::Dap2ResponseNotValid(String::from("HTTP headers not terminated")))?;
+    let headers: String = String::from_utf8_lossy(&response[0..headers_end]).into_owned();
+    let status: u16 = headers.split_whitespace().nth(1).and_then(|status: &str| status.parse().ok())
+        .ok_or_else(|| ReadError::Dap2ResponseNotValid(String::from("HTTP status line not valid")))?;
+    if status != 200 {
+        return Err(ReadError::Dap2HttpStatus{url: url.to_owned(), status: status});
+    }
+    let chunked: bool = headers.lines().any(|line: &str| {
+        let line: String = line.to_lowercase();
+        line.starts_with("transfer-encoding:") && line.contains("chunked")
+    });
+    let body: &[u8] = &response[(headers_end + HEADERS_END.len())..];
+    let body: Vec<u8> = match chunked {
+        true => decode_chunked(body)?,
+        false => body.to_vec(),
+    };
+    // The DAP2 errors can be sent with the status 200
+    if body.starts_with(b"Error {") {
+        return Err(ReadError::Dap2ResponseNotValid(String::from_utf8_lossy(&body).into_owned()));
+    }
+    return Ok(body);
+}
+
+/// Splits an URL into the host (with the optional port) and the path (with the optional query).
+fn split_url(url: &str) -> Result<(&str, &str), ReadError> {
+    const SCHEME: &str = "http://";
+    if !url.starts_with(SCHEME) {
+        return Err(ReadError::Dap2UrlNotValid(url.to_owned()));
+    }
+    let url_rest: &str = &url[SCHEME.len()..];
+    let (host, path): (&str, &str) = match url_rest.find('/') {
+        Some(pos) => (&url_rest[..pos], &url_rest[pos..]),
+        None => (url_rest, "/"),
+    };
+    if host.is_empty() {
+        return Err(ReadError::Dap2UrlNotValid(url.to_owned()));
+    }
+    return Ok((host, path));
+}
+
+/// Decodes a body sent with the *chunked* transfer encoding.
+pub(crate) fn decode_chunked(mut body: &[u8]) -> Result<Vec<u8>, ReadError> {
+    let not_valid = || ReadError::Dap2ResponseNotValid(String::from("HTTP chunk not valid"));
+    let mut data: Vec<u8> = vec![];
+    loop {
+        let line_end: usize = body.windows(2).position(|window: &[u8]| window == b"\r\n").ok_or_else(not_valid)?;
+        let size_line: String = String::from_utf8_lossy(&body[0..line_end]).into_owned();
+        let size_hex: &str = size_line.split(';').next().unwrap_or("").trim();
+        let size: usize = usize::from_str_radix(size_hex, 16).map_err(|_err| not_valid())?;
+        if size == 0 {
+            break;
+        }
+        let start: usize = line_end + 2;
+        let end: usize = start + size;
+        if body.len() < end + 2 {
+            return Err(not_valid());
+        }
+        data.extend_from_slice(&body[start..end]);
+        body = &body[(end + 2)..];
+    }
+    return Ok(data);
+}
+
+/// Percent-encodes a constraint expression to be sent in the query of an URL.
+pub(crate) fn encode_query(query: &str) -> String {
+    let mut encoded: String = String::with_capacity(query.len());
+    for byte in query.bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_' | b'-' | b'.' | b',' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    return encoded;
+}
@@ -0,0 +1,342 @@
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::{DataType, DataVector};
+use crate::error::ReadError;
+
+/// DAP2 base types
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum DapType {
+    Byte,
+    Int16,
+    UInt16,
+    Int32,
+    UInt32,
+    Float32,
+    Float64,
+    String,
+    Url,
+}
+
+impl DapType {
+
+    fn from_name(type_name: &str) -> Option<DapType> {
+        return match type_name.to_lowercase().as_str() {
+            "byte" => Some(DapType::Byte),
+            "int16" => Some(DapType::Int16),
+            "uint16" => Some(DapType::UInt16),
+            "int32" => Some(DapType::Int32),
+            "uint32" => Some(DapType::UInt32),
+            "float32" => Some(DapType::Float32),
+            "float64" => Some(DapType::Float64),
+            "string" => Some(DapType::String),
+            "url" => Some(DapType::Url),
+            _ => None,
+        };
+    }
+
+    /// Returns the NetCDF-3 data type of the variables, the DAP2 bytes are the NetCDF-3 signed bytes.
+    pub(crate) fn data_type(&self) -> Option<DataType> {
+        return match self {
+            DapType::Byte => Some(DataType::I8),
+            DapType::Int16 => Some(DataType::I16),
+            DapType::UInt16 => Some(DataType::U16),
+            DapType::Int32 => Some(DataType::I32),
+            DapType::UInt32 => Some(DataType::U32),
+            DapType::Float32 => Some(DataType::F32),
+            DapType::Float64 => Some(DataType::F64),
+            DapType::String | DapType::Url => None,
+        };
+    }
+}
+
+/// Variable declared in a DDS
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum DdsVar {
+    /// Scalar or array of a base type, the dimensions can be anonymous
+    Base{name: String, dap_type: DapType, dims: Vec<(Option<String>, usize)>},
+    /// Array and its coordinate variables (the maps)
+    Grid{name: String, array: Box<DdsVar>, maps: Vec<DdsVar>},
+}
+
+impl DdsVar {
+
+    pub(crate) fn name(&self) -> &str {
+        return match self {
+            DdsVar::Base{name, ..} => name,
+            DdsVar::Grid{name, ..} => name,
+        };
+    }
+}
+
+/// Attributes of the DAS, grouped by container (global attributes or variable attributes)
+pub(crate) type DasContainers = Vec<(String, Vec<(String, DataVector)>)>;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Punct(char),
+}
+
+/// Splits the text of a DDS or a DAS into tokens.
+fn tokenize(text: &str) -> Result<Vec<Token>, ReadError> {
+    const PUNCTS: &str = "{}[];=,";
+    let mut tokens: Vec<Token> = vec![];
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+        if PUNCTS.contains(c) {
+            tokens.push(Token::Punct(c));
+        } else if c == '"' {
+            let mut value: String = String::new();
+            loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') => value.push(chars.next().ok_or_else(|| response_not_valid("unterminated string"))?),
+                    Some(c) => value.push(c),
+                    None => return Err(response_not_valid("unterminated string")),
+                }
+            }
+            tokens.push(Token::Quoted(value));
+        } else {
+            let mut word: String = c.to_string();
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() || c == '"' || PUNCTS.contains(c) {
+                    break;
+                }
+                word.push(c);
+                chars.next();
+            }
+            tokens.push(Token::Word(word));
+        }
+    }
+    return Ok(tokens);
+}
+
+fn response_not_valid(message: &str) -> ReadError {
+    return ReadError::Dap2ResponseNotValid(message.to_owned());
+}
+
+/// Cursor over the tokens
+struct Tokens {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Tokens {
+
+    fn peek(&self) -> Option<&Token> {
+        return self.tokens.get(self.pos);
+    }
+
+    fn next(&mut self) -> Result<Token, ReadError> {
+        let token: Token = self.tokens.get(self.pos).cloned().ok_or_else(|| response_not_valid("unexpected end"))?;
+        self.pos += 1;
+        return Ok(token);
+    }
+
+    fn next_word(&mut self) -> Result<String, ReadError> {
+        return match self.next()? {
+            Token::Word(word) => Ok(word),
+            token => Err(ReadError::Dap2ResponseNotValid(format!("expected a word, found {:?}", token))),
+        };
+    }
+
+    fn expect_punct(&mut self, punct: char) -> Result<(), ReadError> {
+        return match self.next()? {
+            Token::Punct(c) if c == punct => Ok(()),
+            token => Err(ReadError::Dap2ResponseNotValid(format!("expected '{}', found {:?}", punct, token))),
+        };
+    }
+
+    fn next_is_punct(&self, punct: char) -> bool {
+        return self.peek() == Some(&Token::Punct(punct));
+    }
+}
+
+/// Parses a Dataset Descriptor Structure (DDS), and returns its variables.
+pub(crate) fn parse_dds(text: &str) -> Result<Vec<DdsVar>, ReadError> {
+    let mut tokens = Tokens{tokens: tokenize(text)?, pos: 0};
+    if !tokens.next_word()?.eq_ignore_ascii_case("Dataset") {
+        return Err(response_not_valid("expected the DDS keyword 'Dataset'"));
+    }
+    tokens.expect_punct('{')?;
+    let mut vars: Vec<DdsVar> = vec![];
+    while !tokens.next_is_punct('}') {
+        vars.push(parse_dds_var(&mut tokens)?);
+    }
+    tokens.expect_punct('}')?;
+    let _data_set_name: String = tokens.next_word()?;
+    tokens.expect_punct(';')?;
+    return Ok(vars);
+}
+
+fn parse_dds_var(tokens: &mut Tokens) -> Result<DdsVar, ReadError> {
+    let type_name: String = tokens.next_word()?;
+    if type_name.eq_ignore_ascii_case("Grid") {
+        tokens.expect_punct('{')?;
+        if !tokens.next_word()?.eq_ignore_ascii_case("ARRAY:") {
+            return Err(response_not_valid("expected the Grid keyword 'ARRAY:'"));
+        }
+        let array: DdsVar = parse_dds_var(tokens)?;
+        if !tokens.next_word()?.eq_ignore_ascii_case("MAPS:") {
+            return Err(response_not_valid("expected the Grid keyword 'MAPS:'"));
+        }
+        let mut maps: Vec<DdsVar> = vec![];
+        while !tokens.next_is_punct('}') {
+            maps.push(parse_dds_var(tokens)?);
+        }
+        tokens.expect_punct('}')?;
+        let name: String = tokens.next_word()?;
+        tokens.expect_punct(';')?;
+        return Ok(DdsVar::Grid{name: name, array: Box::new(array), maps: maps});
+    }
+    let dap_type: DapType = DapType::from_name(&type_name).ok_or(ReadError::Dap2TypeNotSupported(type_name))?;
+    let name: String = tokens.next_word()?;
+    let mut dims: Vec<(Option<String>, usize)> = vec![];
+    while tokens.next_is_punct('[') {
+        tokens.expect_punct('[')?;
+        let first: String = tokens.next_word()?;
+        let dim: (Option<String>, usize) = match tokens.next_is_punct('=') {
+            true => {
+                tokens.expect_punct('=')?;
+                (Some(first), tokens.next_word()?.parse().map_err(|_err| response_not_valid("dimension size not valid"))?)
+            },
+            false => (None, first.parse().map_err(|_err| response_not_valid("dimension size not valid"))?),
+        };
+        tokens.expect_punct(']')?;
+        dims.push(dim);
+    }
+    tokens.expect_punct(';')?;
+    return Ok(DdsVar::Base{name: name, dap_type: dap_type, dims: dims});
+}
+
+/// Parses a Dataset Attribute Structure (DAS), and returns the attributes of each top-level container.
+///
+/// The nested containers are ignored.
+pub(crate) fn parse_das(text: &str) -> Result<DasContainers, ReadError> {
+    let mut tokens = Tokens{tokens: tokenize(text)?, pos: 0};
+    if !tokens.next_word()?.eq_ignore_ascii_case("Attributes") {
+        return Err(response_not_valid("expected the DAS keyword 'Attributes'"));
+    }
+    tokens.expect_punct('{')?;
+    let mut containers: DasContainers = vec![];
+    while !tokens.next_is_punct('}') {
+        let container_name: String = tokens.next_word()?;
+        containers.push((container_name, parse_das_container(&mut tokens)?));
+    }
+    tokens.expect_punct('}')?;
+    return Ok(containers);
+}
+
+fn parse_das_container(tokens: &mut Tokens) -> Result<Vec<(String, DataVector)>, ReadError> {
+    tokens.expect_punct('{')?;
+    let mut attrs: Vec<(String, DataVector)> = vec![];
+    while !tokens.next_is_punct('}') {
+        let first: String = tokens.next_word()?;
+        if tokens.next_is_punct('{') {
+            // nested container
+            let _nested_attrs: Vec<(String, DataVector)> = parse_das_container(tokens)?;
+            continue;
+        }
+        let attr_name: String = tokens.next_word()?;
+        let mut values: Vec<Token> = vec![tokens.next()?];
+        while tokens.next_is_punct(',') {
+            tokens.expect_punct(',')?;
+            values.push(tokens.next()?);
+        }
+        tokens.expect_punct(';')?;
+        let dap_type: DapType = DapType::from_name(&first).ok_or(ReadError::Dap2TypeNotSupported(first))?;
+        attrs.push((attr_name, convert_attr_values(&dap_type, values)?));
+    }
+    tokens.expect_punct('}')?;
+    return Ok(attrs);
+}
+
+/// Converts the values of an attribute, the strings are joined with new lines and converted to bytes.
+fn convert_attr_values(dap_type: &DapType, values: Vec<Token>) -> Result<DataVector, ReadError> {
+    let values: Vec<String> = values.into_iter().map(|token: Token| {
+        match token {
+            Token::Word(value) | Token::Quoted(value) => Ok(value),
+            Token::Punct(c) => Err(ReadError::Dap2ResponseNotValid(format!("unexpected '{}' in an attribute value", c))),
+        }
+    }).collect::<Result<Vec<String>, ReadError>>()?;
+    fn parse_all<T: std::str::FromStr>(values: &[String]) -> Result<Vec<T>, ReadError> {
+        return values.iter().map(|value: &String| {
+            value.parse::<T>().map_err(|_err| ReadError::Dap2ResponseNotValid(format!("attribute value '{}' not valid", value)))
+        }).collect();
+    }
+    return Ok(match dap_type {
+        // the NetCDF-3 signed bytes can be written as unsigned DAP2 bytes
+        DapType::Byte => DataVector::I8(parse_all::<i16>(&values)?.into_iter().map(|value: i16| value as i8).collect()),
+        DapType::Int16 => DataVector::I16(parse_all(&values)?),
+        DapType::UInt16 => DataVector::U16(parse_all(&values)?),
+        DapType::Int32 => DataVector::I32(parse_all(&values)?),
+        DapType::UInt32 => DataVector::U32(parse_all(&values)?),
+        DapType::Float32 => DataVector::F32(parse_all(&values)?),
+        DapType::Float64 => DataVector::F64(parse_all(&values)?),
+        DapType::String | DapType::Url => DataVector::U8(values.join("\n").into_bytes()),
+    });
+}
+
+/// Splits a DODS response into its DDS and its XDR-encoded data.
+pub(crate) fn split_dods(response: &[u8]) -> Result<(String, &[u8]), ReadError> {
+    const DATA_SEPARATOR: &[u8] = b"\nData:\n";
+    let pos: usize = response.windows(DATA_SEPARATOR.len()).position(|window: &[u8]| window == DATA_SEPARATOR)
+        .ok_or_else(|| response_not_valid("DODS data separator not found"))?;
+    let dds: String = String::from_utf8(response[0..pos].to_vec()).map_err(|_err| response_not_valid("DDS is not UTF-8"))?;
+    return Ok((dds, &response[(pos + DATA_SEPARATOR.len())..]));
+}
+
+/// Decodes the XDR-encoded data of a variable, the data of the maps of the grids are skipped.
+pub(crate) fn decode_xdr(var: &DdsVar, input: &mut &[u8]) -> Result<DataVector, ReadError> {
+    match var {
+        DdsVar::Grid{array, maps, ..} => {
+            let data: DataVector = decode_xdr(array, input)?;
+            for map in maps.iter() {
+                let _map_data: DataVector = decode_xdr(map, input)?;
+            }
+            return Ok(data);
+        },
+        DdsVar::Base{name, dap_type, dims} => {
+            let data_type: DataType = dap_type.data_type().ok_or_else(|| ReadError::Dap2TypeNotSupported(format!("{:?}", dap_type)))?;
+            let len: usize = match dims.is_empty() {
+                true => 1,
+                false => {
+                    // arrays are prefixed by their length, written twice
+                    let len: usize = input.read_u32::<BigEndian>()? as usize;
+                    let _len: u32 = input.read_u32::<BigEndian>()?;
+                    len
+                },
+            };
+            let expected_len: usize = dims.iter().map(|(_dim_name, dim_size): &(Option<String>, usize)| dim_size).product();
+            if len != expected_len {
+                return Err(ReadError::Dap2ResponseNotValid(format!("length of the variable '{}' not valid", name)));
+            }
+            let data: DataVector = match data_type {
+                DataType::I8 if dims.is_empty() => {
+                    // scalar bytes are encoded as 32-bit integers
+                    DataVector::I8(vec![input.read_u32::<BigEndian>()? as u8 as i8])
+                },
+                DataType::I8 => {
+                    let mut data: Vec<i8> = vec![0; len];
+                    input.read_i8_into(&mut data)?;
+                    let mut padding: Vec<u8> = vec![0; crate::io::compute_padding_size(len)];
+                    std::io::Read::read_exact(input, &mut padding)?;
+                    DataVector::I8(data)
+                },
+                // 16-bit integers are encoded as 32-bit integers
+                DataType::I16 => DataVector::I16((0..len).map(|_| input.read_i32::<BigEndian>().map(|value: i32| value as i16)).collect::<Result<Vec<i16>, std::io::Error>>()?),
+                DataType::U16 => DataVector::U16((0..len).map(|_| input.read_u32::<BigEndian>().map(|value: u32| value as u16)).collect::<Result<Vec<u16>, std::io::Error>>()?),
+                DataType::I32 => { let mut data: Vec<i32> = vec![0; len]; input.read_i32_into::<BigEndian>(&mut data)?; DataVector::I32(data) },
+                DataType::U32 => { let mut data: Vec<u32> = vec![0; len]; input.read_u32_into::<BigEndian>(&mut data)?; DataVector::U32(data) },
+                DataType::F32 => { let mut data: Vec<f32> = vec![0.0; len]; input.read_f32_into::<BigEndian>(&mut data)?; DataVector::F32(data) },
+                DataType::F64 => { let mut data: Vec<f64> = vec![0.0; len]; input.read_f64_into::<BigEndian>(&mut data)?; DataVector::F64(data) },
+                _ => return Err(ReadError::Unexpected),  // not a DAP2 type
+            };
+            return Ok(data);
+        },
+    }
+}
@@ -0,0 +1,254 @@
+#![cfg(test)]
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+
+use byteorder::{WriteBytesExt, BigEndian};
+
+use crate::{Dap2Reader, DataSet, DataType, DataVector};
+use crate::error::ReadError;
+
+use super::parser::{self, DdsVar, DapType, DasContainers};
+use super::http;
+
+const DDS: &str = "Dataset {
+    Float32 lat[lat = 2];
+    Grid {
+      ARRAY:
+        Int16 temp[time = 2][lat = 2];
+      MAPS:
+        Float64 time[time = 2];
+        Float32 lat[lat = 2];
+    } temp;
+    String station;
+} test.nc;
+";
+
+const DAS: &str = "Attributes {
+    NC_GLOBAL {
+        String title \"Test \\\"data\\\"\";
+    }
+    temp {
+        String units \"K\";
+        Int16 valid_range -10, 50;
+        Byte flag 255;
+        nested {
+            String comment \"ignored\";
+        }
+    }
+    time {
+        String units \"hours\";
+    }
+    DODS_EXTRA {
+        String Unlimited_Dimension \"time\";
+    }
+}
+";
+
+/// Returns the XDR encoding of an array of 32-bit words, prefixed by its length written twice.
+fn xdr_array_u32(values: &[u32]) -> Vec<u8> {
+    let mut bytes: Vec<u8> = vec![];
+    bytes.write_u32::<BigEndian>(values.len() as u32).unwrap();
+    bytes.write_u32::<BigEndian>(values.len() as u32).unwrap();
+    for value in values.iter() {
+        bytes.write_u32::<BigEndian>(*value).unwrap();
+    }
+    return bytes;
+}
+
+/// Serves the responses (the paths and their bodies) from a local HTTP server, and returns the URL of the data set.
+fn serve(responses: Vec<(String, Vec<u8>)>) -> String {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address: std::net::SocketAddr = listener.local_addr().unwrap();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream: TcpStream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line: String = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            loop {
+                let mut header_line: String = String::new();
+                reader.read_line(&mut header_line).unwrap();
+                if header_line == "\r\n" || header_line.is_empty() {
+                    break;
+                }
+            }
+            let path: &str = request_line.split_whitespace().nth(1).unwrap();
+            match responses.iter().find(|(response_path, _body): &&(String, Vec<u8>)| response_path == path) {
+                Some((_path, body)) => {
+                    stream.write_all(b"HTTP/1.0 200 OK\r\nContent-Type: text/plain\r\n\r\n").unwrap();
+                    stream.write_all(body).unwrap();
+                },
+                None => stream.write_all(b"HTTP/1.0 404 Not Found\r\n\r\n").unwrap(),
+            }
+        }
+    });
+    return format!("http://{}/opendap/test.nc", address);
+}
+
+#[test]
+fn test_parse_dds() {
+    let dds_vars: Vec<DdsVar> = parser::parse_dds(DDS).unwrap();
+    assert_eq!(3,                                           dds_vars.len());
+    assert_eq!(
+        DdsVar::Base{name: String::from("lat"), dap_type: DapType::Float32, dims: vec![(Some(String::from("lat")), 2)]},
+        dds_vars[0]
+    );
+    match &dds_vars[1] {
+        DdsVar::Grid{name, array, maps} => {
+            assert_eq!("temp",                              name);
+            assert_eq!("temp",                              array.name());
+            assert_eq!(vec!["time", "lat"],                 maps.iter().map(|map: &DdsVar| map.name()).collect::<Vec<&str>>());
+        },
+        _ => panic!("Unexpected DDS variable: {:?}", dds_vars[1]),
+    }
+    assert_eq!(
+        DdsVar::Base{name: String::from("station"), dap_type: DapType::String, dims: vec![]},
+        dds_vars[2]
+    );
+
+    // Anonymous dimension
+    let dds_vars: Vec<DdsVar> = parser::parse_dds("Dataset { Int32 values[3]; } test;").unwrap();
+    assert_eq!(
+        vec![DdsVar::Base{name: String::from("values"), dap_type: DapType::Int32, dims: vec![(None, 3)]}],
+        dds_vars
+    );
+
+    // Errors
+    assert_eq!(
+        ReadError::Dap2TypeNotSupported(String::from("Sequence")),
+        parser::parse_dds("Dataset { Sequence { Int32 a; } seq; } test;").unwrap_err()
+    );
+    assert_eq!(true,                                        parser::parse_dds("Dataset { Int32 values[3] } test;").is_err());
+    assert_eq!(true,                                        parser::parse_dds("Attributes { }").is_err());
+}
+
+#[test]
+fn test_parse_das() {
+    let containers: DasContainers = parser::parse_das(DAS).unwrap();
+    assert_eq!(
+        vec!["NC_GLOBAL", "temp", "time", "DODS_EXTRA"],
+        containers.iter().map(|(name, _attrs): &(String, Vec<(String, DataVector)>)| name.as_str()).collect::<Vec<&str>>()
+    );
+    assert_eq!(
+        vec![(String::from("title"), DataVector::U8(b"Test \"data\"".to_vec()))],
+        containers[0].1
+    );
+    // The nested container is ignored
+    assert_eq!(
+        vec![
+            (String::from("units"), DataVector::U8(b"K".to_vec())),
+            (String::from("valid_range"), DataVector::I16(vec![-10, 50])),
+            (String::from("flag"), DataVector::I8(vec![-1])),
+        ],
+        containers[1].1
+    );
+
+    // Errors
+    assert_eq!(true,                                        parser::parse_das("Attributes { NC_GLOBAL { Int32 a \"x\"; } }").is_err());
+    assert_eq!(true,                                        parser::parse_das("Attributes { NC_GLOBAL { String a \"x }").is_err());
+}
+
+#[test]
+fn test_decode_xdr() {
+    // Byte arrays are padded
+    {
+        let var = DdsVar::Base{name: String::from("bytes"), dap_type: DapType::Byte, dims: vec![(None, 3)]};
+        let bytes: Vec<u8> = vec![0, 0, 0, 3, 0, 0, 0, 3, 1, 2, 255, 0, 9];
+        let mut input: &[u8] = &bytes;
+        assert_eq!(Ok(DataVector::I8(vec![1, 2, -1])),      parser::decode_xdr(&var, &mut input));
+        assert_eq!(&[9_u8] as &[u8],                        input);
+    }
+    // Scalar bytes and 16-bit integers are encoded as 32-bit integers
+    {
+        let var = DdsVar::Base{name: String::from("byte"), dap_type: DapType::Byte, dims: vec![]};
+        let mut input: &[u8] = &[0, 0, 0, 7];
+        assert_eq!(Ok(DataVector::I8(vec![7])),             parser::decode_xdr(&var, &mut input));
+
+        let var = DdsVar::Base{name: String::from("shorts"), dap_type: DapType::Int16, dims: vec![(None, 2)]};
+        let bytes: Vec<u8> = xdr_array_u32(&[(-2_i32) as u32, 3]);
+        let mut input: &[u8] = &bytes;
+        assert_eq!(Ok(DataVector::I16(vec![-2, 3])),        parser::decode_xdr(&var, &mut input));
+    }
+    // Length mismatch
+    {
+        let var = DdsVar::Base{name: String::from("ints"), dap_type: DapType::Int32, dims: vec![(None, 3)]};
+        let bytes: Vec<u8> = xdr_array_u32(&[1, 2]);
+        let mut input: &[u8] = &bytes;
+        assert_eq!(true,                                    parser::decode_xdr(&var, &mut input).is_err());
+    }
+}
+
+#[test]
+fn test_http_utils() {
+    assert_eq!("temp%5B0%3A1%5D.a,b",                       http::encode_query("temp[0:1].a,b"));
+    assert_eq!(
+        Ok(b"Hello World".to_vec()),
+        http::decode_chunked(b"5\r\nHello\r\n6;ext=1\r\n World\r\n0\r\n\r\n")
+    );
+    assert_eq!(true,                                        http::decode_chunked(b"5\r\nHel").is_err());
+}
+
+#[test]
+fn test_dap2_reader() {
+    let lat_dods: Vec<u8> = {
+        let mut bytes: Vec<u8> = b"Dataset {\n    Float32 lat[lat = 2];\n} test.nc;\nData:\n".to_vec();
+        bytes.extend(xdr_array_u32(&[10.5_f32.to_bits(), 11.5_f32.to_bits()]));
+        bytes
+    };
+    let temp_slice_dods: Vec<u8> = {
+        let mut bytes: Vec<u8> = b"Dataset {
+    Grid {
+      ARRAY:
+        Int16 temp[time = 2][lat = 1];
+      MAPS:
+        Float64 time[time = 2];
+        Float32 lat[lat = 1];
+    } temp;
+} test.nc;
+Data:
+".to_vec();
+        bytes.extend(xdr_array_u32(&[3, 4]));
+        bytes.extend(&[0, 0, 0, 2, 0, 0, 0, 2]);
+        bytes.write_f64::<BigEndian>(0.0).unwrap();
+        bytes.write_f64::<BigEndian>(1.0).unwrap();
+        bytes.extend(xdr_array_u32(&[11.5_f32.to_bits()]));
+        bytes
+    };
+    let url: String = serve(vec![
+        (String::from("/opendap/test.nc.dds"), DDS.as_bytes().to_vec()),
+        (String::from("/opendap/test.nc.das"), DAS.as_bytes().to_vec()),
+        (String::from("/opendap/test.nc.dods?lat"), lat_dods),
+        (String::from("/opendap/test.nc.dods?temp%5B0%3A1%5D%5B1%3A1%5D"), temp_slice_dods),
+    ]);
+
+    let reader: Dap2Reader = Dap2Reader::open(&url).unwrap();
+    assert_eq!(url.as_str(),                                reader.url());
+    let data_set: &DataSet = reader.data_set();
+    assert_eq!(vec!["lat", "temp", "time"],                 data_set.get_var_names());
+    assert_eq!(Some(String::from("time")),                  data_set.get_unlimited_dim().map(|dim| dim.name()));
+    assert_eq!(Some(2),                                     data_set.num_records());
+    assert_eq!(Some(DataType::I16),                         data_set.var_data_type("temp"));
+    assert_eq!(Some(String::from("Test \"data\"")),         data_set.get_global_attr_as_string("title"));
+    assert_eq!(Some(&[-10_i16, 50][..]),                    data_set.get_var_attr_i16("temp", "valid_range"));
+    assert_eq!(Some(String::from("hours")),                 data_set.get_var_attr_as_string("time", "units"));
+
+    // Read the data
+    assert_eq!(Ok(DataVector::F32(vec![10.5, 11.5])),       reader.read_var("lat"));
+    assert_eq!(Ok(DataVector::I16(vec![3, 4])),             reader.read_var_slice("temp", &[0, 1], &[2, 1]));
+    assert_eq!(Ok(DataVector::I16(vec![])),                 reader.read_var_slice("temp", &[0, 1], &[0, 1]));
+
+    // Errors
+    assert_eq!(ReadError::VariableNotDefined(String::from("station")), reader.read_var("station").unwrap_err());
+    assert_eq!(
+        ReadError::SliceNotValid{var_name: String::from("temp"), start: vec![0, 1], count: vec![2, 2], shape: vec![2, 2]},
+        reader.read_var_slice("temp", &[0, 1], &[2, 2]).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::Dap2HttpStatus{url: format!("{}.dods?time", url), status: 404},
+        reader.read_var("time").unwrap_err()
+    );
+    assert_eq!(
+        ReadError::Dap2UrlNotValid(String::from("https://example.org/test.nc.dds")),
+        Dap2Reader::open("https://example.org/test.nc").unwrap_err()
+    );
+}
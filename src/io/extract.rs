@@ -0,0 +1,65 @@
+mod tests_extract;
+
+use crate::{FileReader, FileWriter, DataSet, DataVector, Variable};
+use crate::error::WriteError;
+
+/// Copies the data of the selected variables from a NetCDF-3 file into an other one, one record at a time.
+///
+/// The definition of the `writer` must already be set, usually with the data set returned by [`DataSet::extract_vars`](struct.DataSet.html#method.extract_vars).
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{FileReader, FileWriter, DataSet, Version};
+///
+/// const TEMP_I16_VAR_NAME: &str = "temperature_i16";
+///
+/// // ...
+/// # use tempdir::TempDir;
+/// # use copy_to_tmp_file::{
+/// #     copy_bytes_to_tmp_file,
+/// #     NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+/// # };
+/// #
+/// # // Copy bytes to an temporary file
+/// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+/// # let output_file_path = tmp_dir.path().join("extracted.nc");
+///
+/// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+/// let sub_data_set: DataSet = file_reader.data_set().extract_vars(&[TEMP_I16_VAR_NAME, "latitude"]).unwrap();
+/// assert_eq!(vec!["latitude", "temperature_i16"],            sub_data_set.get_var_names());
+///
+/// // Copy the selected variables
+/// let mut file_writer: FileWriter = FileWriter::create_new(&output_file_path).unwrap();
+/// file_writer.set_def(&sub_data_set, file_reader.version(), 0).unwrap();
+/// netcdf3::extract(&mut file_reader, &mut file_writer, &[TEMP_I16_VAR_NAME, "latitude"]).unwrap();
+/// file_writer.close().unwrap();
+///
+/// // Read the extracted file
+/// let mut extracted_file_reader: FileReader = FileReader::open(&output_file_path).unwrap();
+/// assert_eq!(&sub_data_set,                                   extracted_file_reader.data_set());
+/// assert_eq!(file_reader.read_var_i16(TEMP_I16_VAR_NAME),     extracted_file_reader.read_var_i16(TEMP_I16_VAR_NAME));
+/// assert_eq!(file_reader.read_var_f32("latitude"),            extracted_file_reader.read_var_f32("latitude"));
+/// # tmp_dir.close();
+/// ```
+pub fn extract<T: AsRef<str>>(reader: &mut FileReader, writer: &mut FileWriter, var_names: &[T]) -> Result<(), WriteError>
+{
+    let output_data_set: &DataSet = writer.data_set().ok_or(WriteError::HeaderNotDefined)?;
+    for var_name in var_names.iter() {
+        let var_name: &str = var_name.as_ref();
+        let var: &Variable = output_data_set.get_var(var_name).ok_or_else(|| WriteError::VariableNotDefined(var_name.to_owned()))?;
+        let shape: Vec<usize> = var.shape();
+        let (num_chunks, mut start, count): (usize, Vec<usize>, Vec<usize>) = match var.is_record_var() {
+            true => (var.num_chunks(), vec![0; shape.len()], std::iter::once(1).chain(shape[1..].iter().cloned()).collect()),
+            false => (1, vec![0; shape.len()], shape),
+        };
+        for i in 0..num_chunks {
+            if var.is_record_var() {
+                start[0] = i;
+            }
+            let chunk: DataVector = reader.read_var_slice(var_name, &start, &count)?;
+            writer.write_var_slice(var_name, &start, &count, &chunk)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,92 @@
+#![cfg(test)]
+use std::path::PathBuf;
+
+use crate::{FileReader, FileWriter, DataSet, DataType, Version};
+use crate::error::{WriteError, ReadError, InvalidDataSet};
+
+use super::extract;
+
+use copy_to_tmp_file::{
+    copy_bytes_to_tmp_file,
+    NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+};
+
+const TEMP_I8_VAR_NAME: &str = "temperature_i8";
+const TEMP_F64_VAR_NAME: &str = "temperature_f64";
+
+#[test]
+fn test_extract() {
+    let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    let output_file_path: PathBuf = tmp_dir.path().join("test_extract.nc");
+
+    let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+    let input_data_set: &DataSet = file_reader.data_set();
+    let sub_data_set: DataSet = input_data_set.extract_vars(&[TEMP_F64_VAR_NAME, "time", TEMP_I8_VAR_NAME]).unwrap();
+    assert_eq!(input_data_set.dim_names(),                              sub_data_set.dim_names());
+    assert_eq!(vec!["time", TEMP_I8_VAR_NAME, TEMP_F64_VAR_NAME],       sub_data_set.get_var_names());
+    assert_eq!(input_data_set.get_global_attrs(),                       sub_data_set.get_global_attrs());
+    assert_eq!(input_data_set.get_var_attrs(TEMP_I8_VAR_NAME),          sub_data_set.get_var_attrs(TEMP_I8_VAR_NAME));
+    assert_eq!(input_data_set.get_var_attrs("time"),                    sub_data_set.get_var_attrs("time"));
+    assert_eq!(input_data_set.num_records(),                            sub_data_set.num_records());
+
+    {
+        let mut file_writer: FileWriter = FileWriter::create_new(&output_file_path).unwrap();
+        assert_eq!(
+            WriteError::HeaderNotDefined,
+            extract(&mut file_reader, &mut file_writer, &[TEMP_I8_VAR_NAME]).unwrap_err()
+        );
+        file_writer.set_def(&sub_data_set, Version::Classic, 0).unwrap();
+        assert_eq!(
+            WriteError::VariableNotDefined(String::from("latitude")),
+            extract(&mut file_reader, &mut file_writer, &["latitude"]).unwrap_err()
+        );
+        extract(&mut file_reader, &mut file_writer, &[TEMP_F64_VAR_NAME, "time", TEMP_I8_VAR_NAME]).unwrap();
+        file_writer.close().unwrap();
+    }
+
+    let mut extracted_file_reader: FileReader = FileReader::open(&output_file_path).unwrap();
+    assert_eq!(&sub_data_set,                                       extracted_file_reader.data_set());
+    assert_eq!(file_reader.read_var_i8(TEMP_I8_VAR_NAME),           extracted_file_reader.read_var_i8(TEMP_I8_VAR_NAME));
+    assert_eq!(file_reader.read_var_f64(TEMP_F64_VAR_NAME),         extracted_file_reader.read_var_f64(TEMP_F64_VAR_NAME));
+    assert_eq!(file_reader.read_var_f32("time"),                    extracted_file_reader.read_var_f32("time"));
+
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_extract_errors() {
+    let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    let output_file_path: PathBuf = tmp_dir.path().join("test_extract_errors.nc");
+
+    let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+    assert_eq!(
+        InvalidDataSet::VariableNotDefined(String::from("undef_var")),
+        file_reader.data_set().extract_vars(&[TEMP_I8_VAR_NAME, "undef_var"]).unwrap_err()
+    );
+
+    // The variable is not defined in the input file
+    let mut data_set: DataSet = DataSet::new();
+    data_set.add_fixed_dim("dim", 2).unwrap();
+    data_set.add_var_i8("undef_var", &["dim"]).unwrap();
+    let mut file_writer: FileWriter = FileWriter::create_new(&output_file_path).unwrap();
+    file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    assert_eq!(
+        WriteError::Read(ReadError::VariableNotDefined(String::from("undef_var"))),
+        extract(&mut file_reader, &mut file_writer, &["undef_var"]).unwrap_err()
+    );
+    file_writer.close().unwrap();
+
+    // The variable is not defined with the same data type
+    let mut data_set: DataSet = DataSet::new();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_var_f64("latitude", &["latitude"]).unwrap();
+    let mut file_writer: FileWriter = FileWriter::open(&output_file_path).unwrap();
+    file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    assert_eq!(
+        WriteError::VariableMismatchDataType{var_name: String::from("latitude"), req: DataType::F64, get: DataType::F32},
+        extract(&mut file_reader, &mut file_writer, &["latitude"]).unwrap_err()
+    );
+    file_writer.close().unwrap();
+
+    tmp_dir.close().unwrap();
+}
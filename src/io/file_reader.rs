@@ -5,6 +5,7 @@ use std::rc::Rc;
 use std::io::{Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 use std::collections::HashMap;
+use std::time::Duration;
 
 use byteorder::{ReadBytesExt, BigEndian};
 
@@ -25,7 +26,9 @@ use nom::{
         be_f32,
         be_f64,
         be_i64,
+        be_u16,
         be_u32,
+        be_u64,
     },
     branch::alt,
     multi::many_m_n,
@@ -34,19 +37,28 @@ use nom::{
 
 use crate::{
     data_set::DimensionSize,
+    Attribute,
     DataSet,
+    InMemoryDataSet,
+    NcType,
+    NcFloat,
     DataType,
     Dimension,
     DataVector,
     Variable,
     Version,
-    error::ReadError,
+    VariableView,
+    TextEncoding,
+    StringOptions,
+    is_valid_name,
+    error::{InvalidDataSet, ReadError, ReadWarning},
     error::parse_header_error::{ParseHeaderError, ParseHeaderErrorKind, NomError},
-    io::{compute_padding_size, Offset, ABSENT_TAG, DIMENSION_TAG, VARIABLE_TAG, ATTRIBUTE_TAG},
+    io::{compute_padding_size, absent_tag, Offset, Storage, DIMENSION_TAG, VARIABLE_TAG, ATTRIBUTE_TAG},
+    io::{Stopwatch, Timings, VarTimings},
 };
 
 
-/// Allows to read NetCDF-3 files (the *classic* and the *64-bit offset* versions).
+/// Allows to read NetCDF-3 files (the *classic*, the *64-bit offset* and the *CDF-5* versions).
 ///
 /// # Example
 ///
@@ -210,8 +222,17 @@ pub struct FileReader {
     data_set: DataSet,
     version: Version,
     input_file_path: PathBuf,
-    input_file: std::fs::File,
-    vars_info: Vec<VariableParsedMetadata>
+    input_file: Storage,
+    vars_info: Vec<VariableParsedMetadata>,
+    warnings: Vec<ReadWarning>,
+    mask_out_of_range: bool,
+    file_size: u64,
+    memory_limit: Option<usize>,
+    lazy_attrs: Vec<LazyAttribute>,
+    instrumented: bool,
+    /// `true` while the read values are converted, the nested conversions being recorded once
+    converting: bool,
+    timings: Timings,
 }
 
 macro_rules! impl_read_typed_var {
@@ -253,6 +274,57 @@ macro_rules! impl_read_typed_record {
     };
 }
 
+macro_rules! impl_read_typed_column {
+    ($func_name:ident, $prim_type:ty, $data_type:path, $data_vector:path) => {
+        /// Reads the typed values located at the same `chunk_index` in all the records, and returns them into a typed `Vec`.
+        pub fn $func_name(&mut self, var_name: &str, chunk_index: &[usize]) -> Result<Vec<$prim_type>, ReadError>
+        {
+            let (_var_index, var): (usize, &Variable) = self.data_set.find_var_from_name(var_name).map_err(|_err|{
+                ReadError::VariableNotDefined(String::from(var_name))
+            })?;
+            if var.data_type != $data_type {
+                return Err(ReadError::VariableMismatchDataType{var_name: String::from(var_name), req: var.data_type.clone(), get: $data_type});
+            }
+            let data_vec: DataVector = self.read_column(var_name, chunk_index)?;
+            match data_vec {
+                $data_vector(data) => return Ok(data),
+                _ => return Err(ReadError::Unexpected),  // previously checked
+            };
+        }
+    };
+}
+
+macro_rules! impl_read_typed_element {
+    ($func_name:ident, $prim_type:ty, $data_type:path) => {
+        /// Reads the typed element located at the multi-dimensional `index` of the variable.
+        ///
+        /// Only the bytes of this element are read from the file.
+        pub fn $func_name(&mut self, var_name: &str, index: &[usize]) -> Result<$prim_type, ReadError>
+        {
+            let (_var_index, var): (usize, &Variable) = self.data_set.find_var_from_name(var_name).map_err(|_err|{
+                ReadError::VariableNotDefined(String::from(var_name))
+            })?;
+            if var.data_type != $data_type {
+                return Err(ReadError::VariableMismatchDataType{var_name: String::from(var_name), req: var.data_type.clone(), get: $data_type});
+            }
+            let stopwatch: Stopwatch = Stopwatch::start(self.instrumented);
+            let position: u64 = self.compute_element_position(var_name, index)?;
+            self.input_file.seek(SeekFrom::Start(position))?;
+            const SIZE_OF: usize = std::mem::size_of::<$prim_type>();
+            let mut bytes: [u8; SIZE_OF] = [0_u8; SIZE_OF];
+            self.input_file.read_exact(&mut bytes)?;
+            let value: $prim_type = <$prim_type>::from_be_bytes(bytes);
+            if self.instrumented {
+                let var_timings: &mut VarTimings = self.timings.var_mut(var_name);
+                var_timings.num_reads += 1;
+                var_timings.read_bytes += SIZE_OF as u64;
+                var_timings.read += stopwatch.elapsed();
+            }
+            return Ok(value);
+        }
+    };
+}
+
 impl FileReader {
 
     /// Returns the data set managed by the reader.
@@ -271,33 +343,462 @@ impl FileReader {
     }
 
     /// Opens the file and parses the header of the NetCDF-3.
+    ///
+    /// Returns the error `ReadError::Hdf5FormatNotSupported` for the NetCDF-4 files (see the function `read_netcdf4` of the optional feature `netcdf`).
     pub fn open<P: AsRef<Path>>(input_file_path: P) -> Result<Self, ReadError>
     {
-        const BUFFER_SIZE: usize = 1024;
+        return FileReader::open_with_mode(input_file_path, false, TextEncoding::Utf8, None);
+    }
+
+    /// Opens the file and parses the header of the NetCDF-3, tolerating some common deviations from the format.
+    ///
+    /// The following deviations are recorded as [warnings](struct.FileReader.html#method.warnings) instead of failing:
+    /// - non-zero padding bytes in the header,
+    /// - trailing bytes after the data section, when the number of records is *indeterminate*,
+    /// - less attributes than announced in the header.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{FileReader, error::ReadWarning};
+    ///
+    /// # use copy_to_tmp_file::{
+    /// #     copy_bytes_to_tmp_file,
+    /// #     NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+    /// # };
+    /// #
+    /// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    /// let file_reader: FileReader = FileReader::open_lenient(input_file_path).unwrap();
+    /// assert_eq!(&[] as &[ReadWarning],               file_reader.warnings());
+    /// # tmp_dir.close();
+    /// ```
+    pub fn open_lenient<P: AsRef<Path>>(input_file_path: P) -> Result<Self, ReadError>
+    {
+        return FileReader::open_with_mode(input_file_path, true, TextEncoding::Utf8, None);
+    }
+
+    /// Opens the file and parses the header of the NetCDF-3, the names being decoded with the `encoding` instead of the strict UTF-8.
+    ///
+    /// Allows to open the files written by old tools whose names contain Latin-1 bytes, which otherwise fail with
+    /// the error kind `ParseHeaderErrorKind::Utf8` (the text attributes can be decoded with
+    /// [`Attribute::get_as_string_with_encoding`](struct.Attribute.html#method.get_as_string_with_encoding)).
+    pub fn open_with_encoding<P: AsRef<Path>>(input_file_path: P, encoding: TextEncoding) -> Result<Self, ReadError>
+    {
+        return FileReader::open_with_mode(input_file_path, false, encoding, None);
+    }
+
+    /// Opens the file and parses the header of the NetCDF-3, the values of the attributes larger than `max_attr_size` bytes
+    /// being not decoded.
+    ///
+    /// Keeps the opening fast and light for the files carrying large attributes (e.g. embedded colormaps or JSON documents)
+    /// when only a part of their metadata are used. These *lazy* attributes are not in the [data set](struct.FileReader.html#method.data_set)
+    /// until their values are decoded by [`read_global_attr`](struct.FileReader.html#method.read_global_attr) or
+    /// [`read_var_attr`](struct.FileReader.html#method.read_var_attr), they are then inserted at their position among the attributes of the data set.
+    ///
+    /// The attributes used by the masked, NaN and unpacked reads (`_FillValue`, `valid_min`, `valid_max`, `valid_range`,
+    /// `scale_factor` and `add_offset`) are always decoded while parsing the header.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{FileReader, Attribute};
+    /// # use copy_to_tmp_file::{
+    /// #     copy_bytes_to_tmp_file,
+    /// #     NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+    /// # };
+    /// #
+    /// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    ///
+    /// let mut file_reader: FileReader = FileReader::open_with_lazy_attrs(input_file_path, 16).unwrap();
+    /// assert_eq!(vec!["title"],                       file_reader.lazy_global_attr_names());
+    /// assert_eq!(None,                                file_reader.data_set().get_global_attr("title"));
+    ///
+    /// // The attribute values are decoded on first access
+    /// let title: &Attribute = file_reader.read_global_attr("title").unwrap();
+    /// assert_eq!(Some(String::from("Example of NETCDF3_CLASSIC file")), title.get_as_string());
+    /// assert_eq!(true,                                file_reader.lazy_global_attr_names().is_empty());
+    /// assert_eq!(true,                                file_reader.data_set().has_global_attr("title"));
+    /// # tmp_dir.close();
+    /// ```
+    pub fn open_with_lazy_attrs<P: AsRef<Path>>(input_file_path: P, max_attr_size: usize) -> Result<Self, ReadError>
+    {
+        return FileReader::open_with_mode(input_file_path, false, TextEncoding::Utf8, Some(max_attr_size));
+    }
+
+    /// Returns the names of the global attributes whose values have not been decoded yet (see [`open_with_lazy_attrs`](struct.FileReader.html#method.open_with_lazy_attrs)).
+    pub fn lazy_global_attr_names(&self) -> Vec<&str> {
+        return self.lazy_attrs.iter()
+            .filter(|attr: &&LazyAttribute| attr.var_name.is_none())
+            .map(|attr: &LazyAttribute| attr.name.as_str())
+            .collect();
+    }
+
+    /// Returns the names of the attributes of the variable whose values have not been decoded yet (see [`open_with_lazy_attrs`](struct.FileReader.html#method.open_with_lazy_attrs)).
+    pub fn lazy_var_attr_names(&self, var_name: &str) -> Vec<&str> {
+        return self.lazy_attrs.iter()
+            .filter(|attr: &&LazyAttribute| attr.var_name.as_deref() == Some(var_name))
+            .map(|attr: &LazyAttribute| attr.name.as_str())
+            .collect();
+    }
+
+    /// Returns the global attribute, its values being decoded and inserted into the data set if they have not been yet.
+    pub fn read_global_attr(&mut self, attr_name: &str) -> Result<&Attribute, ReadError> {
+        self.decode_lazy_attr(None, attr_name)?;
+        return self.data_set.get_global_attr(attr_name).ok_or_else(|| {
+            ReadError::DataSet(InvalidDataSet::GlobalAttributeNotDefined(attr_name.to_owned()))
+        });
+    }
+
+    /// Returns the attribute of the variable, its values being decoded and inserted into the data set if they have not been yet.
+    pub fn read_var_attr(&mut self, var_name: &str, attr_name: &str) -> Result<&Attribute, ReadError> {
+        if !self.data_set.has_var(var_name) {
+            return Err(ReadError::VariableNotDefined(var_name.to_owned()));
+        }
+        self.decode_lazy_attr(Some(var_name), attr_name)?;
+        return self.data_set.get_var_attr(var_name, attr_name).ok_or_else(|| {
+            ReadError::DataSet(InvalidDataSet::VariableAttributeNotDefined{var_name: var_name.to_owned(), attr_name: attr_name.to_owned()})
+        });
+    }
+
+    /// Decodes the values of all the lazy attributes not decoded yet (see [`open_with_lazy_attrs`](struct.FileReader.html#method.open_with_lazy_attrs)),
+    /// so the [data set](struct.FileReader.html#method.data_set) contains all the attributes of the file.
+    pub fn decode_lazy_attrs(&mut self) -> Result<(), ReadError> {
+        while let Some(attr) = self.lazy_attrs.first() {
+            let (var_name, attr_name): (Option<String>, String) = (attr.var_name.clone(), attr.name.clone());
+            self.decode_lazy_attr(var_name.as_deref(), &attr_name)?;
+        }
+        return Ok(());
+    }
+
+    /// Decodes the values of the lazy attribute, if any, and inserts it into the data set at its position in the header.
+    fn decode_lazy_attr(&mut self, var_name: Option<&str>, attr_name: &str) -> Result<(), ReadError> {
+        let attr_index: usize = match self.lazy_attrs.iter().position(|attr: &LazyAttribute| attr.var_name.as_deref() == var_name && attr.name == attr_name) {
+            None => return Ok(()),
+            Some(attr_index) => attr_index,
+        };
+        let attr: &LazyAttribute = &self.lazy_attrs[attr_index];
+        let mut attr_data: DataVector = DataVector::new(attr.data_type.clone(), attr.len);
+        self.input_file.seek(SeekFrom::Start(attr.begin_offset))?;
+        read_values_into(&mut self.input_file, &mut attr_data, 0, attr.len)?;
+        let attr: LazyAttribute = self.lazy_attrs.remove(attr_index);
+        // Position among the attributes already decoded
+        let attr_pos: usize = attr.index - self.lazy_attrs.iter()
+            .filter(|other: &&LazyAttribute| other.var_name == attr.var_name && other.index < attr.index)
+            .count();
+        let attrs: &mut Vec<Attribute> = match attr.var_name {
+            None => {
+                add_global_attr(&mut self.data_set, &attr.name, attr_data)?;
+                &mut self.data_set.attrs
+            },
+            Some(ref var_name) => {
+                add_var_attr(&mut self.data_set, var_name, &attr.name, attr_data)?;
+                &mut self.data_set.get_var_mut(var_name).ok_or(ReadError::Unexpected)?.attrs
+            },
+        };
+        // Move the appended attribute
+        let decoded_attr: Attribute = attrs.pop().ok_or(ReadError::Unexpected)?;
+        attrs.insert(std::cmp::min(attr_pos, attrs.len()), decoded_attr);
+        return Ok(());
+    }
+
+    /// Opens the file, loads all its bytes into memory at once, and parses the header of the NetCDF-3.
+    ///
+    /// The data are then read from memory, without any more access to the file system. Suits the files small enough
+    /// to fit in memory (e.g. up to a few hundreds of MB) whose variables are read many times, one by one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::FileReader;
+    /// # use copy_to_tmp_file::{
+    /// #     copy_bytes_to_tmp_file,
+    /// #     NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+    /// # };
+    /// #
+    /// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    /// let mut file_reader: FileReader = FileReader::open_preloaded(&input_file_path).unwrap();
+    /// assert_eq!(input_file_path,                     file_reader.file_path());
+    ///
+    /// // The file can be removed, its data have been loaded
+    /// std::fs::remove_file(&input_file_path).unwrap();
+    /// assert_eq!(vec![0.0, 0.5, 1.0],                 file_reader.read_var_f32("latitude").unwrap());
+    /// # tmp_dir.close();
+    /// ```
+    pub fn open_preloaded<P: AsRef<Path>>(input_file_path: P) -> Result<Self, ReadError>
+    {
+        let input_file_path: PathBuf = input_file_path.as_ref().to_path_buf();
+        let bytes: Vec<u8> = std::fs::read(&input_file_path)?;
+        let file_size: usize = bytes.len();
+        return FileReader::from_storage(Storage::Memory(std::io::Cursor::new(bytes)), input_file_path, file_size, false, TextEncoding::Utf8, None);
+    }
+
+    /// Returns the deviations from the format tolerated while opening the file in lenient mode.
+    pub fn warnings(&self) -> &[ReadWarning] {
+        return &self.warnings;
+    }
+
+    /// Enables (or disables) the masking of the values outside the valid range of the variables
+    /// (see [`Variable::valid_range`](struct.Variable.html#method.valid_range)).
+    ///
+    /// The masked reads (`read_var_masked`, `read_record_masked` and `read_var_unpacked_f64`) then also mask these values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, FileReader, FileWriter, Version};
+    /// # use tempdir::TempDir;
+    /// # let tmp_dir: TempDir = TempDir::new("netcdf3_doc_").unwrap();
+    /// # let file_path = tmp_dir.path().join("valid_range.nc");
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("sst", &["latitude"]).unwrap();
+    /// data_set.add_var_attr_f32("sst", "valid_range", vec![250.0, 320.0]).unwrap();
+    ///
+    /// let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+    /// file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    /// file_writer.write_var_f32("sst", &[280.5, 999.0, 281.0]).unwrap();
+    /// file_writer.close().unwrap();
+    ///
+    /// let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    /// assert_eq!(vec![Some(280.5_f32), Some(999.0), Some(281.0)],    file_reader.read_var_masked::<f32>("sst").unwrap());
+    /// file_reader.set_mask_out_of_range(true);
+    /// assert_eq!(vec![Some(280.5_f32), None, Some(281.0)],           file_reader.read_var_masked::<f32>("sst").unwrap());
+    /// ```
+    pub fn set_mask_out_of_range(&mut self, mask_out_of_range: bool) {
+        self.mask_out_of_range = mask_out_of_range;
+    }
+
+    /// Returns `true` if the values outside the valid range are masked (see [`set_mask_out_of_range`](struct.FileReader.html#method.set_mask_out_of_range)).
+    pub fn is_masking_out_of_range(&self) -> bool {
+        return self.mask_out_of_range;
+    }
+
+    /// Sets the maximum number of bytes allocated to read the data of a variable (`None` by default, no limit).
+    ///
+    /// The reads requiring more memory (e.g. a variable of a crafted header declaring huge dimensions) return the error
+    /// `ReadError::MemoryLimitExceeded` before allocating anything. The limit can also be given for a single read, see
+    /// [`read_var_with_limit`](struct.FileReader.html#method.read_var_with_limit).
+    ///
+    /// Whatever the limit, the reads of data located beyond the end of the file fail with the error
+    /// `ReadError::IOErrorKind(std::io::ErrorKind::UnexpectedEof)` before allocating anything.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{FileReader, error::ReadError};
+    /// use copy_to_tmp_file::NC3_CLASSIC_FILE_BYTES;
+    ///
+    /// let mut file_reader: FileReader = FileReader::from_bytes(NC3_CLASSIC_FILE_BYTES.to_vec()).unwrap();
+    /// file_reader.set_memory_limit(Some(100));
+    /// assert_eq!(Some(100),                           file_reader.memory_limit());
+    ///
+    /// // 3 `f32` values
+    /// assert_eq!(vec![0.0, 0.5, 1.0],                 file_reader.read_var_f32("latitude").unwrap());
+    /// // 30 `f64` values
+    /// assert_eq!(
+    ///     ReadError::MemoryLimitExceeded{var_name: String::from("temperature_f64"), req: 240, limit: 100},
+    ///     file_reader.read_var_f64("temperature_f64").unwrap_err()
+    /// );
+    /// ```
+    pub fn set_memory_limit(&mut self, memory_limit: Option<usize>) {
+        self.memory_limit = memory_limit;
+    }
+
+    /// Returns the maximum number of bytes allocated to read the data of a variable (see [`set_memory_limit`](struct.FileReader.html#method.set_memory_limit)).
+    pub fn memory_limit(&self) -> Option<usize> {
+        return self.memory_limit;
+    }
+
+    /// Enables (or disables) the instrumentation mode, recording the durations and the counters of the reads and of the conversions
+    /// of each variable (see [`timings`](struct.FileReader.html#method.timings)).
+    ///
+    /// Disabled by default. The reads of [`read_vars_parallel`](struct.FileReader.html#method.read_vars_parallel) are not recorded.
+    pub fn set_instrumented(&mut self, instrumented: bool) {
+        self.instrumented = instrumented;
+    }
+
+    /// Returns `true` if the instrumentation mode is enabled (see [`set_instrumented`](struct.FileReader.html#method.set_instrumented)).
+    pub fn is_instrumented(&self) -> bool {
+        return self.instrumented;
+    }
+
+    /// Returns the durations and the counters recorded in the instrumentation mode (see the struct [`Timings`](struct.Timings.html)).
+    pub fn timings(&self) -> Timings {
+        return self.timings.clone();
+    }
+
+    /// Clears the durations and the counters of the variables, the duration of the parsing of the header is kept.
+    pub fn reset_timings(&mut self) {
+        self.timings.vars.clear();
+    }
+
+    /// Records the read of the values of the variable in the instrumentation mode.
+    fn record_read(&mut self, var_name: &str, data_vec: &DataVector, stopwatch: Stopwatch) {
+        if self.instrumented {
+            let var_timings: &mut VarTimings = self.timings.var_mut(var_name);
+            var_timings.num_reads += 1;
+            var_timings.read_bytes += (data_vec.len() * data_vec.data_type().size_of()) as u64;
+            var_timings.read += stopwatch.elapsed();
+        }
+    }
+
+    /// Reads and converts the values of the variable with `read_and_convert`, the duration of the conversion being recorded
+    /// without the duration of the reads in the instrumentation mode.
+    fn read_converted<T, F>(&mut self, var_name: &str, read_and_convert: F) -> Result<T, ReadError>
+    where
+        F: FnOnce(&mut FileReader) -> Result<T, ReadError>
+    {
+        // The nested conversions (e.g. the unpacking of the masked values) are recorded by the outer one
+        if !self.instrumented || self.converting {
+            return read_and_convert(self);
+        }
+        let read_duration: Duration = self.timings.var(var_name).map(|var_timings: &VarTimings| var_timings.read).unwrap_or_default();
+        let stopwatch: Stopwatch = Stopwatch::start(true);
+        self.converting = true;
+        let result: Result<T, ReadError> = read_and_convert(self);
+        self.converting = false;
+        if result.is_ok() {
+            let elapsed: Duration = stopwatch.elapsed();
+            let var_timings: &mut VarTimings = self.timings.var_mut(var_name);
+            var_timings.convert += elapsed.checked_sub(var_timings.read - read_duration).unwrap_or_default();
+        }
+        return result;
+    }
+
+    /// Parses the header of the NetCDF-3 stored in the `bytes`, the data are then read from these bytes.
+    ///
+    /// Allows to read the NetCDF-3 without file system (e.g. a file downloaded or selected in a web browser).
+    /// The [file path](struct.FileReader.html#method.file_path) of the reader is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::FileReader;
+    /// use copy_to_tmp_file::NC3_CLASSIC_FILE_BYTES;
+    ///
+    /// let mut file_reader: FileReader = FileReader::from_bytes(NC3_CLASSIC_FILE_BYTES.to_vec()).unwrap();
+    /// assert_eq!(vec![0.0, 0.5, 1.0],        file_reader.read_var_f32("latitude").unwrap());
+    /// ```
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, ReadError>
+    {
+        let file_size: usize = bytes.len();
+        let input: Storage = Storage::Memory(std::io::Cursor::new(bytes));
+        return FileReader::from_storage(input, PathBuf::new(), file_size, false, TextEncoding::Utf8, None);
+    }
+
+    /// Parses the header of the NetCDF-3 stored in the reference-counted `bytes`, the data are then read from these bytes.
+    ///
+    /// Requires the optional feature `bytes`. Unlike [`from_bytes`](struct.FileReader.html#method.from_bytes) the buffer
+    /// is not copied, so a body received by a HTTP server can be read by several readers (see
+    /// [`try_clone`](struct.FileReader.html#method.try_clone)), each of them keeping the buffer alive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use netcdf3::FileReader;
+    /// use copy_to_tmp_file::NC3_CLASSIC_FILE_BYTES;
+    ///
+    /// let body: Bytes = Bytes::from_static(NC3_CLASSIC_FILE_BYTES);
+    /// let mut file_reader: FileReader = FileReader::from_shared_bytes(body.clone()).unwrap();
+    /// assert_eq!(vec![0.0, 0.5, 1.0],        file_reader.read_var_f32("latitude").unwrap());
+    ///
+    /// // The same buffer, read from an other thread
+    /// let handle = std::thread::spawn(move || {
+    ///     let mut file_reader: FileReader = FileReader::from_shared_bytes(body).unwrap();
+    ///     return file_reader.read_var_f32("longitude").unwrap();
+    /// });
+    /// assert_eq!(vec![0.0, 0.5, 1.0, 1.5, 2.0],   handle.join().unwrap());
+    /// ```
+    #[cfg(feature = "bytes")]
+    pub fn from_shared_bytes(bytes: bytes::Bytes) -> Result<Self, ReadError>
+    {
+        let file_size: usize = bytes.len();
+        let input: Storage = Storage::Shared(std::io::Cursor::new(bytes));
+        return FileReader::from_storage(input, PathBuf::new(), file_size, false, TextEncoding::Utf8, None);
+    }
+
+    /// Returns a new reader of the same reference-counted bytes (see [`from_shared_bytes`](struct.FileReader.html#method.from_shared_bytes)),
+    /// without copying the bytes nor parsing the header again.
+    ///
+    /// Requires the optional feature `bytes`. Returns `None` if the reader does not read shared bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use netcdf3::FileReader;
+    /// use copy_to_tmp_file::NC3_CLASSIC_FILE_BYTES;
+    ///
+    /// let mut file_reader: FileReader = FileReader::from_shared_bytes(Bytes::from_static(NC3_CLASSIC_FILE_BYTES)).unwrap();
+    /// let mut other_reader: FileReader = file_reader.try_clone().unwrap();
+    /// assert_eq!(file_reader.read_var_f32("latitude").unwrap(),   other_reader.read_var_f32("latitude").unwrap());
+    ///
+    /// let file_reader: FileReader = FileReader::from_bytes(NC3_CLASSIC_FILE_BYTES.to_vec()).unwrap();
+    /// assert_eq!(true,                                            file_reader.try_clone().is_none());
+    /// ```
+    #[cfg(feature = "bytes")]
+    pub fn try_clone(&self) -> Option<Self>
+    {
+        let input: Storage = match &self.input_file {
+            Storage::Shared(cursor) => Storage::Shared(std::io::Cursor::new(cursor.get_ref().clone())),
+            _ => return None,
+        };
+        return Some(FileReader{
+            data_set: self.data_set.clone(),
+            version: self.version.clone(),
+            input_file_path: self.input_file_path.clone(),
+            input_file: input,
+            vars_info: self.vars_info.clone(),
+            warnings: self.warnings.clone(),
+            mask_out_of_range: self.mask_out_of_range,
+            file_size: self.file_size,
+            memory_limit: self.memory_limit,
+            lazy_attrs: self.lazy_attrs.clone(),
+            instrumented: self.instrumented,
+            converting: false,
+            timings: Timings{header_parse: self.timings.header_parse, ..Timings::default()},
+        });
+    }
+
+    fn open_with_mode<P: AsRef<Path>>(input_file_path: P, lenient: bool, encoding: TextEncoding, max_attr_size: Option<usize>) -> Result<Self, ReadError>
+    {
         // Open the file
         let input_file_path: PathBuf = {
             let mut path = PathBuf::new();
             path.push(input_file_path);
             path
         };
-        let mut input_file = std::fs::File::open(input_file_path.clone())?;
+        let input_file = std::fs::File::open(input_file_path.clone())?;
         let file_size: usize = std::fs::metadata(&input_file_path)?.len() as usize; 
-        
+        return FileReader::from_storage(Storage::File(input_file), input_file_path, file_size, lenient, encoding, max_attr_size);
+    }
+
+    pub(crate) fn from_storage(mut input_file: Storage, input_file_path: PathBuf, file_size: usize, lenient: bool, encoding: TextEncoding, max_attr_size: Option<usize>) -> Result<Self, ReadError>
+    {
+        const BUFFER_SIZE: usize = 1024;
         // Parse the header
-        let (data_set, version, vars_info): (DataSet, Version, Vec<VariableParsedMetadata>) = {
+        let stopwatch: Stopwatch = Stopwatch::start(true);
+        let (data_set, version, vars_info, warnings, lazy_attrs): (DataSet, Version, Vec<VariableParsedMetadata>, Option<Vec<ReadWarning>>, Option<LazyAttrs>) = {
             let mut buffer: Vec<u8> = vec![];
             let (data_set, version, vars_info): (DataSet, Version, Vec<VariableParsedMetadata>);
+            let mut warnings: Option<Vec<ReadWarning>>;
+            let mut lazy_attrs: Option<LazyAttrs>;
             loop {
-                // Load bytes
+                // Load bytes, the buffer size being doubled to parse the large headers in a few attempts
                 let old_buf_start: usize = buffer.len();
-                let new_buf_size: usize = std::cmp::min(buffer.len() + BUFFER_SIZE, file_size);
+                let new_buf_size: usize = std::cmp::min(buffer.len() + std::cmp::max(buffer.len(), BUFFER_SIZE), file_size);
                 let start: &usize = &old_buf_start;
                 let end: &usize = &new_buf_size;
                 buffer.resize(new_buf_size, 0_u8);
                 let _num_of_bytes = input_file.read(&mut buffer[*start..*end])?;
 
+                // The warnings are collected again at each parsing attempt
+                warnings = if lenient { Some(vec![]) } else { None };
+                lazy_attrs = max_attr_size.map(|max_attr_size: usize| LazyAttrs{max_attr_size: max_attr_size, header_len: buffer.len(), attrs: vec![]});
                 let parsing_result: Result<(DataSet, Version, Vec<VariableParsedMetadata>), ReadError>;
-                parsing_result = FileReader::parse_header(&buffer, file_size);
+                parsing_result = FileReader::parse_header(&buffer, file_size, &mut warnings, &mut lazy_attrs, encoding);
                 match parsing_result {
                     Ok((data_set_2, version_2, vars_info_2)) => {
                         data_set = data_set_2;
@@ -306,6 +807,10 @@ impl FileReader {
                         break;
                     },
                     Err(read_err) => {
+                        // NetCDF-4 files are HDF5 files
+                        if let Err(ReadError::Hdf5FormatNotSupported) = Version::detect(&buffer) {
+                            return Err(ReadError::Hdf5FormatNotSupported);
+                        }
                         if read_err.header_is_incomplete() {
                             let buf_size: usize = buffer.len();
                             if buf_size < file_size {
@@ -321,46 +826,746 @@ impl FileReader {
                     },
                 }
             }
-            (data_set, version, vars_info)
+            (data_set, version, vars_info, warnings, lazy_attrs)
+        };
+
+        // Return the result
+        return Ok(FileReader{
+            data_set: data_set,
+            version: version,
+            input_file_path: input_file_path,
+            input_file: input_file,
+            vars_info: vars_info,  // convert the list of tuples to a map
+            warnings: warnings.unwrap_or_default(),
+            mask_out_of_range: false,
+            file_size: file_size as u64,
+            memory_limit: None,
+            lazy_attrs: lazy_attrs.map(|lazy_attrs: LazyAttrs| lazy_attrs.attrs).unwrap_or_default(),
+            instrumented: false,
+            converting: false,
+            timings: Timings{header_parse: stopwatch.elapsed(), ..Timings::default()},
+        })
+    }
+
+    /// Returns a copy of the parsed header, to open the same file again without parsing it (see `from_parsed_header`).
+    pub(crate) fn parsed_header(&self) -> ParsedHeader {
+        return ParsedHeader{
+            data_set: self.data_set.clone(),
+            version: self.version.clone(),
+            vars_info: self.vars_info.clone(),
+            warnings: self.warnings.clone(),
+            lazy_attrs: self.lazy_attrs.clone(),
+        };
+    }
+
+    /// Builds a reader of the `input_file` whose header has already been parsed.
+    pub(crate) fn from_parsed_header(input_file: Storage, input_file_path: PathBuf, file_size: usize, header: &ParsedHeader) -> Self {
+        return FileReader{
+            data_set: header.data_set.clone(),
+            version: header.version.clone(),
+            input_file_path: input_file_path,
+            input_file: input_file,
+            vars_info: header.vars_info.clone(),
+            warnings: header.warnings.clone(),
+            mask_out_of_range: false,
+            file_size: file_size as u64,
+            memory_limit: None,
+            lazy_attrs: header.lazy_attrs.clone(),
+            instrumented: false,
+            converting: false,
+            timings: Timings::default(),
+        };
+    }
+
+    /// Closes the file and releases the data set and the file version.
+    ///
+    /// The lazy attributes not decoded yet (see [`open_with_lazy_attrs`](struct.FileReader.html#method.open_with_lazy_attrs))
+    /// are decoded into the data set first, the ones which cannot be read are lost (see [`decode_lazy_attrs`](struct.FileReader.html#method.decode_lazy_attrs)
+    /// to get the errors).
+    pub fn close(mut self) -> (DataSet, Version) {
+        let _ = self.decode_lazy_attrs();
+        (self.data_set, self.version)
+    }
+
+    /// Allows to read all variable data easily.
+    ///
+    /// Also see an example [here](struct.FileReader.html#example).
+    pub fn read_all_vars(&mut self) -> Result<HashMap<String, DataVector>, ReadError>
+    {
+        let var_names: Vec<String> = self.data_set.get_var_names();
+        var_names.into_iter()
+            .map(|var_name: String| {
+                let var_data: DataVector = self.read_var(&var_name)?;
+                Ok((var_name, var_data))
+            }).collect()
+    }
+
+    /// Reads and decodes the variables concurrently on the threads of the [rayon](https://github.com/rayon-rs/rayon)
+    /// global pool, and returns their data by variable name.
+    ///
+    /// Requires the optional feature `rayon`. The variables are read with positional reads (the reader does not need
+    /// to be mutable), so the reads of the files and the conversions of the byte order of the large variables are
+    /// spread over the threads.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use netcdf3::{FileReader, DataVector};
+    /// use copy_to_tmp_file::NC3_CLASSIC_FILE_BYTES;
+    ///
+    /// let file_reader: FileReader = FileReader::from_bytes(NC3_CLASSIC_FILE_BYTES.to_vec()).unwrap();
+    /// let data: HashMap<String, DataVector> = file_reader.read_vars_parallel(&["latitude", "temperature_f64"]).unwrap();
+    /// assert_eq!(2,                                   data.len());
+    /// assert_eq!(Some(&[0.0, 0.5, 1.0][..]),          data["latitude"].get_f32());
+    /// assert_eq!(30,                                  data["temperature_f64"].len());
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn read_vars_parallel<T: AsRef<str>>(&self, var_names: &[T]) -> Result<HashMap<String, DataVector>, ReadError>
+    {
+        use rayon::prelude::*;
+        // The data set is not shared with the threads, only the positions of the chunks
+        let var_chunks: Vec<(String, DataType, Vec<(u64, usize)>)> = var_names.iter().map(|var_name: &T| {
+            let var_name: &str = var_name.as_ref();
+            let (data_type, runs): (DataType, Vec<(u64, usize)>) = self.locate_var_chunks(var_name, self.memory_limit)?;
+            Ok((var_name.to_owned(), data_type, runs))
+        }).collect::<Result<Vec<(String, DataType, Vec<(u64, usize)>)>, ReadError>>()?;
+        let input: &Storage = &self.input_file;
+        let vars_data: Vec<(String, DataVector)> = var_chunks.into_par_iter()
+            .map(|(var_name, data_type, runs): (String, DataType, Vec<(u64, usize)>)| {
+                let var_len: usize = runs.iter().map(|(_position, len): &(u64, usize)| len).sum();
+                let mut var_data = DataVector::new(data_type, var_len);
+                read_runs_at(input, &mut var_data, &runs)?;
+                Ok((var_name, var_data))
+            }).collect::<std::io::Result<Vec<(String, DataVector)>>>()?;
+        return Ok(vars_data.into_iter().collect());
+    }
+
+    /// Reads all variable data, and returns them with a copy of the data set.
+    pub fn read_in_memory(&mut self) -> Result<InMemoryDataSet, ReadError>
+    {
+        let data: HashMap<String, DataVector> = self.read_all_vars()?;
+        return Ok(InMemoryDataSet{
+            data_set: self.data_set.clone(),
+            data: data,
+        });
+    }
+
+    /// Reads the typed variable and returns its values into `Vec`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{FileReader, DataSet, DataVector, DataType};
+    ///
+    /// const LATITUDE_VAR_NAME: &str = "latitude";
+    /// const LATITUDE_VAR_DATA: [f32; 3] = [0.0, 0.5, 1.0];
+    ///
+    /// // ...
+    /// # use copy_to_tmp_file::{
+    /// #     copy_bytes_to_tmp_file,
+    /// #     NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+    /// # };
+    /// #
+    /// # // Copy bytes to an temporary file
+    /// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    ///
+    /// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+    ///
+    /// // Open the file
+    /// // -------------
+    /// assert_eq!(true,                    file_reader.data_set().has_var(LATITUDE_VAR_NAME));
+    /// assert_eq!(Some(DataType::F32),     file_reader.data_set().var_data_type(LATITUDE_VAR_NAME));
+    ///
+    /// // Read the variable
+    /// // -----------------
+    /// // using the method `FileReader::read_var`
+    /// {
+    ///     let latitudes: DataVector = file_reader.read_var(LATITUDE_VAR_NAME).unwrap();
+    ///     assert_eq!(DataType::F32,                           latitudes.data_type());
+    /// 
+    ///     assert_eq!(None,                                    latitudes.get_i8());
+    ///     assert_eq!(None,                                    latitudes.get_u8());
+    ///     assert_eq!(None,                                    latitudes.get_i16());
+    ///     assert_eq!(None,                                    latitudes.get_i32());
+    ///     assert_eq!(Some(&LATITUDE_VAR_DATA[..]),            latitudes.get_f32());
+    ///     assert_eq!(None,                                    latitudes.get_f64());
+    /// }
+    /// 
+    /// // using the method `FileReader::read_var_f32`
+    /// {
+    ///     let latitudes: Vec<f32> = file_reader.read_var_f32(LATITUDE_VAR_NAME).unwrap();
+    ///     assert_eq!(&LATITUDE_VAR_DATA[..],                  &latitudes[..]);
+    /// }
+    /// ```
+    pub fn read_var(&mut self, var_name: &str) -> Result<DataVector, ReadError>
+    {
+        return self.read_var_within(var_name, self.memory_limit);
+    }
+
+    /// Reads the variable like [`read_var`](struct.FileReader.html#method.read_var), allocating at most `memory_limit` bytes
+    /// instead of the [limit of the reader](struct.FileReader.html#method.set_memory_limit).
+    ///
+    /// Returns the error `ReadError::MemoryLimitExceeded` if the data of the variable require more memory.
+    pub fn read_var_with_limit(&mut self, var_name: &str, memory_limit: usize) -> Result<DataVector, ReadError>
+    {
+        return self.read_var_within(var_name, Some(memory_limit));
+    }
+
+    fn read_var_within(&mut self, var_name: &str, memory_limit: Option<usize>) -> Result<DataVector, ReadError>
+    {
+        let stopwatch: Stopwatch = Stopwatch::start(self.instrumented);
+        let (data_type, runs): (DataType, Vec<(u64, usize)>) = self.locate_var_chunks(var_name, memory_limit)?;
+        // memory allocation
+        let var_len: usize = runs.iter().map(|(_position, len): &(u64, usize)| len).sum();
+        let mut data_vec = DataVector::new(data_type, var_len);
+        read_runs(&mut self.input_file, &mut data_vec, &runs)?;
+        self.record_read(var_name, &data_vec, stopwatch);
+        Ok(data_vec)
+    }
+
+    impl_read_typed_var!(read_var_i8, i8, DataType::I8, DataVector::I8);
+    impl_read_typed_var!(read_var_u8, u8, DataType::U8, DataVector::U8);
+    impl_read_typed_var!(read_var_i16, i16, DataType::I16, DataVector::I16);
+    impl_read_typed_var!(read_var_i32, i32, DataType::I32, DataVector::I32);
+    impl_read_typed_var!(read_var_f32, f32, DataType::F32, DataVector::F32);
+    impl_read_typed_var!(read_var_f64, f64, DataType::F64, DataVector::F64);
+    impl_read_typed_var!(read_var_u16, u16, DataType::U16, DataVector::U16);
+    impl_read_typed_var!(read_var_u32, u32, DataType::U32, DataVector::U32);
+    impl_read_typed_var!(read_var_i64, i64, DataType::I64, DataVector::I64);
+    impl_read_typed_var!(read_var_u64, u64, DataType::U64, DataVector::U64);
+
+    /// Returns the bytes of the `u8` variable borrowed from the bytes of the reader, without copy.
+    ///
+    /// Allows to access the large text or byte blobs of the readers created by [`from_bytes`](struct.FileReader.html#method.from_bytes),
+    /// [`open_preloaded`](struct.FileReader.html#method.open_preloaded) or [`from_shared_bytes`](struct.FileReader.html#method.from_shared_bytes).
+    ///
+    /// Returns the error `ReadError::NotInMemory` if the reader reads a file, and the error `ReadError::VariableNotContiguous`
+    /// if the records of the variable are interleaved with the other record variables or padded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{FileReader, FileWriter, DataSet, Version, ReadError};
+    /// use copy_to_tmp_file::NC3_CLASSIC_FILE_BYTES;
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("blob_len", 11).unwrap();
+    /// data_set.add_var_u8("blob", &["blob_len"]).unwrap();
+    /// let mut file_writer: FileWriter = FileWriter::in_memory();
+    /// file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    /// file_writer.write_var_u8("blob", b"hello world").unwrap();
+    ///
+    /// let file_reader: FileReader = FileReader::from_bytes(file_writer.close_into_bytes().unwrap()).unwrap();
+    /// let blob: &[u8] = file_reader.read_var_u8_ref("blob").unwrap();
+    /// assert_eq!(b"hello world",                     blob);
+    ///
+    /// // The records of `temperature_u8` are interleaved with the records of the other variables
+    /// let file_reader: FileReader = FileReader::from_bytes(NC3_CLASSIC_FILE_BYTES.to_vec()).unwrap();
+    /// assert_eq!(
+    ///     ReadError::VariableNotContiguous(String::from("temperature_u8")),
+    ///     file_reader.read_var_u8_ref("temperature_u8").unwrap_err()
+    /// );
+    /// ```
+    pub fn read_var_u8_ref(&self, var_name: &str) -> Result<&[u8], ReadError>
+    {
+        self.check_var_data_type(var_name, DataType::U8)?;
+        let (_data_type, runs): (DataType, Vec<(u64, usize)>) = self.locate_var_chunks(var_name, None)?;
+        let bytes: &[u8] = self.input_file.as_slice().ok_or(ReadError::NotInMemory)?;
+        let start: u64 = match runs.first() {
+            None => return Ok(&[]),
+            Some((position, _len)) => *position,
+        };
+        // The records are contiguous only if the variable is the only record variable, and if its records are not padded
+        let mut end: u64 = start;
+        for (position, len) in runs.iter() {
+            if *position != end {
+                return Err(ReadError::VariableNotContiguous(String::from(var_name)));
+            }
+            end += *len as u64;
+        }
+        return bytes.get((start as usize)..(end as usize)).ok_or(ReadError::IOErrorKind(std::io::ErrorKind::UnexpectedEof));
+    }
+
+    /// Reads the variable and returns its values into a typed `Vec`, the element type is generic (see the trait [NcType](trait.NcType.html)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{FileReader, ReadError, DataType};
+    /// # use copy_to_tmp_file::{copy_bytes_to_tmp_file, NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME};
+    /// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    ///
+    /// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+    ///
+    /// let latitudes: Vec<f32> = file_reader.read_var_as::<f32>("latitude").unwrap();
+    /// assert_eq!(file_reader.read_var_f32("latitude").unwrap(),  latitudes);
+    ///
+    /// assert_eq!(
+    ///     ReadError::VariableMismatchDataType{var_name: String::from("latitude"), req: DataType::F32, get: DataType::F64},
+    ///     file_reader.read_var_as::<f64>("latitude").unwrap_err()
+    /// );
+    /// # tmp_dir.close();
+    /// ```
+    pub fn read_var_as<T: NcType>(&mut self, var_name: &str) -> Result<Vec<T>, ReadError> {
+        self.check_var_data_type(var_name, T::DATA_TYPE)?;
+        let data_vec: DataVector = self.read_var(var_name)?;
+        return T::from_data_vector_into(data_vec).map_err(|_data_vec: DataVector| ReadError::Unexpected);  // previously checked
+    }
+
+    /// Reads the variable of any data type and returns its values converted into `f64`.
+    ///
+    /// The values are neither masked nor unpacked (see the methods `read_var_masked` and `read_var_unpacked_f64`),
+    /// and the `i64` and `u64` values larger than 2^53 may be rounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::FileReader;
+    /// # use copy_to_tmp_file::{copy_bytes_to_tmp_file, NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME};
+    /// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    ///
+    /// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+    ///
+    /// let latitudes: Vec<f64> = file_reader.read_var_as_f64("latitude").unwrap();
+    /// assert_eq!(vec![0.0, 0.5, 1.0],                 latitudes[0..3].to_vec());
+    /// let temperatures: Vec<f64> = file_reader.read_var_as_f64("temperature_i32").unwrap();
+    /// assert_eq!(vec![0.0, 1.0, 2.0],                 temperatures[0..3].to_vec());
+    /// # tmp_dir.close();
+    /// ```
+    pub fn read_var_as_f64(&mut self, var_name: &str) -> Result<Vec<f64>, ReadError> {
+        return self.read_converted(var_name, |file_reader: &mut FileReader| {
+            let data_vec: DataVector = file_reader.read_var(var_name)?;
+            return Ok(data_vec.iter_as_f64().collect());
+        });
+    }
+
+    /// Reads a `u8` variable as a list of strings, the last dimension being the length of the strings.
+    ///
+    /// The trailing NUL and space padding characters are trimmed, and the invalid UTF-8 sequences are replaced by `U+FFFD`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{FileReader, FileWriter, DataSet};
+    /// # use tempdir::TempDir;
+    ///
+    /// # let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    /// # let file_path = tmp_dir.path().join("stations.nc");
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("station", 2).unwrap();
+    /// data_set.add_fixed_dim("name_len", 6).unwrap();
+    /// data_set.add_var_u8("station_name", &["station", "name_len"]).unwrap();
+    ///
+    /// let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+    /// file_writer.set_def(&data_set, netcdf3::Version::Classic, 0).unwrap();
+    /// file_writer.write_var_u8("station_name", b"Paris\0Lyon  ").unwrap();
+    /// file_writer.close().unwrap();
+    ///
+    /// let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    /// assert_eq!(vec!["Paris", "Lyon"],       file_reader.read_var_strings("station_name").unwrap());
+    /// # tmp_dir.close();
+    /// ```
+    pub fn read_var_strings(&mut self, var_name: &str) -> Result<Vec<String>, ReadError> {
+        return self.read_var_strings_with(var_name, &StringOptions::default());
+    }
+
+    /// Reads a `u8` variable as a list of strings, the last dimension being the length of the strings,
+    /// the strings being trimmed and decoded with the `options`.
+    ///
+    /// Returns the error `StringNotValid` if a string cannot be decoded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{FileReader, FileWriter, DataSet, StringOptions, TextEncoding, error::ReadError};
+    /// # use tempdir::TempDir;
+    ///
+    /// # let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    /// # let file_path = tmp_dir.path().join("stations.nc");
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("station", 2).unwrap();
+    /// data_set.add_fixed_dim("name_len", 8).unwrap();
+    /// data_set.add_var_u8("station_name", &["station", "name_len"]).unwrap();
+    ///
+    /// let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+    /// file_writer.set_def(&data_set, netcdf3::Version::Classic, 0).unwrap();
+    /// file_writer.write_var_u8("station_name", b"Lyon  \0\0Orl\xe9ans ").unwrap();
+    /// file_writer.close().unwrap();
+    ///
+    /// let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    /// let options = StringOptions{trim_nuls: true, trim_spaces: false, encoding: TextEncoding::Utf8OrLatin1};
+    /// assert_eq!(vec!["Lyon  ", "Orl\u{e9}ans "],       file_reader.read_var_strings_with("station_name", &options).unwrap());
+    ///
+    /// let options = StringOptions{encoding: TextEncoding::Utf8, ..StringOptions::default()};
+    /// assert_eq!(
+    ///     ReadError::StringNotValid{var_name: String::from("station_name"), index: 1},
+    ///     file_reader.read_var_strings_with("station_name", &options).unwrap_err()
+    /// );
+    /// # tmp_dir.close();
+    /// ```
+    pub fn read_var_strings_with(&mut self, var_name: &str, options: &StringOptions) -> Result<Vec<String>, ReadError> {
+        let (_var_index, var): (usize, &Variable) = self.data_set.find_var_from_name(var_name).map_err(|_err|{
+            ReadError::VariableNotDefined(String::from(var_name))
+        })?;
+        if var.data_type != DataType::U8 {
+            return Err(ReadError::VariableMismatchDataType{var_name: String::from(var_name), req: var.data_type.clone(), get: DataType::U8});
+        }
+        let string_len: usize = var.dims.last().map(|dim: &Rc<Dimension>| dim.size()).unwrap_or(1);
+        return self.read_converted(var_name, |file_reader: &mut FileReader| {
+            let chars: Vec<u8> = file_reader.read_var_u8(var_name)?;
+            if string_len == 0 {
+                return Ok(vec![]);
+            }
+            return chars.chunks(string_len).enumerate().map(|(index, chars): (usize, &[u8])| {
+                options.decode(chars).ok_or_else(|| ReadError::StringNotValid{var_name: String::from(var_name), index: index})
+            }).collect();
+        });
+    }
+
+    /// Reads the typed records and returns its values into a typed`Vec`.
+    pub fn read_record(&mut self, var_name: &str, record_index: usize) -> Result<DataVector, ReadError>
+    {
+        let stopwatch: Stopwatch = Stopwatch::start(self.instrumented);
+        let (_var_index, var): (usize, &Variable) = self.data_set.find_var_from_name(var_name).map_err(|_err|{
+            ReadError::VariableNotDefined(String::from(var_name))
+        })?;
+        let num_records: usize = self.data_set.num_records().unwrap_or(1); // fixed-size variables haves exaclty one record
+        if record_index >= num_records {
+            return Err(ReadError::RecordIndexExceeded{index: record_index, num_records: num_records});
+        }
+
+        // Compute the record offset from the start of the NetCDF3 file
+        let var_info: &VariableParsedMetadata = self.find_var_info(var_name).ok_or(ReadError::Unexpected)?;
+        let record_offset: u64 = (i64::from(var_info.begin_offset.clone()) as u64) + ((record_index * self.data_set.record_size().unwrap_or(0)) as u64);
+        let data_type: DataType = var.data_type();
+        let chunk_len: usize = self.check_read_size(var_name, &data_type, checked_chunk_len(var), self.memory_limit, |num_bytes: u64| record_offset.checked_add(num_bytes))?;
+        self.input_file.seek(SeekFrom::Start(record_offset))?;
+
+        // Read the data
+        let mut data_vec: DataVector = DataVector::new(data_type, chunk_len);
+        match data_vec {
+            DataVector::I8(ref mut data) => self.input_file.read_i8_into(&mut data[..]),
+            DataVector::U8(ref mut data) => self.input_file.read_exact(&mut data[..]),
+            DataVector::I16(ref mut data) => self.input_file.read_i16_into::<BigEndian>(&mut data[..]),
+            DataVector::I32(ref mut data) => self.input_file.read_i32_into::<BigEndian>(&mut data[..]),
+            DataVector::F32(ref mut data) => self.input_file.read_f32_into::<BigEndian>(&mut data[..]),
+            DataVector::F64(ref mut data) => self.input_file.read_f64_into::<BigEndian>(&mut data[..]),
+            DataVector::U16(ref mut data) => self.input_file.read_u16_into::<BigEndian>(&mut data[..]),
+            DataVector::U32(ref mut data) => self.input_file.read_u32_into::<BigEndian>(&mut data[..]),
+            DataVector::I64(ref mut data) => self.input_file.read_i64_into::<BigEndian>(&mut data[..]),
+            DataVector::U64(ref mut data) => self.input_file.read_u64_into::<BigEndian>(&mut data[..]),
+        }?;
+        self.record_read(var_name, &data_vec, stopwatch);
+        return Ok(data_vec);
+    }
+
+    impl_read_typed_record!(read_record_i8, i8, DataType::I8, DataVector::I8);
+    impl_read_typed_record!(read_record_u8, u8, DataType::U8, DataVector::U8);
+    impl_read_typed_record!(read_record_i16, i16, DataType::I16, DataVector::I16);
+    impl_read_typed_record!(read_record_i32, i32, DataType::I32, DataVector::I32);
+    impl_read_typed_record!(read_record_f32, f32, DataType::F32, DataVector::F32);
+    impl_read_typed_record!(read_record_f64, f64, DataType::F64, DataVector::F64);
+    impl_read_typed_record!(read_record_u16, u16, DataType::U16, DataVector::U16);
+    impl_read_typed_record!(read_record_u32, u32, DataType::U32, DataVector::U32);
+    impl_read_typed_record!(read_record_i64, i64, DataType::I64, DataVector::I64);
+    impl_read_typed_record!(read_record_u64, u64, DataType::U64, DataVector::U64);
+
+    /// Reads a record of the variable and returns its values into a typed `Vec`, the element type is generic (see the trait [NcType](trait.NcType.html)).
+    pub fn read_record_as<T: NcType>(&mut self, var_name: &str, record_index: usize) -> Result<Vec<T>, ReadError> {
+        self.check_var_data_type(var_name, T::DATA_TYPE)?;
+        let data_vec: DataVector = self.read_record(var_name, record_index)?;
+        return T::from_data_vector_into(data_vec).map_err(|_data_vec: DataVector| ReadError::Unexpected);  // previously checked
+    }
+
+    /// Reads a record of the variable of any data type and returns its values converted into `f64` (see the method `read_var_as_f64`).
+    pub fn read_record_as_f64(&mut self, var_name: &str, record_index: usize) -> Result<Vec<f64>, ReadError> {
+        return self.read_converted(var_name, |file_reader: &mut FileReader| {
+            let data_vec: DataVector = file_reader.read_record(var_name, record_index)?;
+            return Ok(data_vec.iter_as_f64().collect());
+        });
+    }
+
+    /// Reads the variable and returns its values, the fill values being replaced by `None` (see [`Variable::fill_value`](struct.Variable.html#method.fill_value)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, FileReader, FileWriter, Version, NC_FILL_I32};
+    /// # use tempdir::TempDir;
+    /// # let tmp_dir: TempDir = TempDir::new("netcdf3_doc_").unwrap();
+    /// # let file_path = tmp_dir.path().join("masked.nc");
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("sst", &["latitude"]).unwrap();
+    /// data_set.add_var_attr_f32("sst", "_FillValue", vec![-999.0]).unwrap();
+    /// data_set.add_var_i32("count", &["latitude"]).unwrap();
+    ///
+    /// let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+    /// file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    /// file_writer.write_var_f32("sst", &[280.5, -999.0, 281.0]).unwrap();
+    /// file_writer.write_var_i32("count", &[1, NC_FILL_I32, 3]).unwrap();
+    /// file_writer.close().unwrap();
+    ///
+    /// let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    /// assert_eq!(vec![Some(280.5_f32), None, Some(281.0)],   file_reader.read_var_masked::<f32>("sst").unwrap());
+    /// // The default fill value of the data type
+    /// assert_eq!(vec![Some(1_i32), None, Some(3)],           file_reader.read_var_masked::<i32>("count").unwrap());
+    /// ```
+    pub fn read_var_masked<T: NcType + PartialOrd>(&mut self, var_name: &str) -> Result<Vec<Option<T>>, ReadError> {
+        return self.read_converted(var_name, |file_reader: &mut FileReader| {
+            let data: Vec<T> = file_reader.read_var_as::<T>(var_name)?;
+            return file_reader.mask_values(var_name, data);
+        });
+    }
+
+    /// Reads a record of the variable and returns its values, the fill values being replaced by `None` (see the method `read_var_masked`).
+    pub fn read_record_masked<T: NcType + PartialOrd>(&mut self, var_name: &str, record_index: usize) -> Result<Vec<Option<T>>, ReadError> {
+        return self.read_converted(var_name, |file_reader: &mut FileReader| {
+            let record: Vec<T> = file_reader.read_record_as::<T>(var_name, record_index)?;
+            return file_reader.mask_values(var_name, record);
+        });
+    }
+
+    /// Reads all the values of a floating-point variable, the fill values being replaced by `NaN` (see the method `read_var_masked`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, FileReader, FileWriter, Version, NC_FILL_F64};
+    /// # use tempdir::TempDir;
+    /// # let tmp_dir: TempDir = TempDir::new("netcdf3_doc_").unwrap();
+    /// # let file_path = tmp_dir.path().join("nan.nc");
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f64("sst", &["latitude"]).unwrap();
+    ///
+    /// let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+    /// file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    /// file_writer.write_var_nan("sst", &[280.5, std::f64::NAN, 281.0]).unwrap();
+    /// file_writer.close().unwrap();
+    ///
+    /// let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    /// assert_eq!(vec![280.5, NC_FILL_F64, 281.0],         file_reader.read_var_f64("sst").unwrap());
+    ///
+    /// let sst: Vec<f64> = file_reader.read_var_nan::<f64>("sst").unwrap();
+    /// assert_eq!(280.5,                                   sst[0]);
+    /// assert_eq!(true,                                    sst[1].is_nan());
+    /// assert_eq!(281.0,                                   sst[2]);
+    /// ```
+    pub fn read_var_nan<T: NcFloat>(&mut self, var_name: &str) -> Result<Vec<T>, ReadError> {
+        return self.read_converted(var_name, |file_reader: &mut FileReader| {
+            let data: Vec<Option<T>> = file_reader.read_var_masked::<T>(var_name)?;
+            return Ok(data.into_iter().map(|value: Option<T>| value.unwrap_or(T::NAN)).collect());
+        });
+    }
+
+    /// Reads a record of a floating-point variable, the fill values being replaced by `NaN` (see the method `read_var_nan`).
+    pub fn read_record_nan<T: NcFloat>(&mut self, var_name: &str, record_index: usize) -> Result<Vec<T>, ReadError> {
+        return self.read_converted(var_name, |file_reader: &mut FileReader| {
+            let record: Vec<Option<T>> = file_reader.read_record_masked::<T>(var_name, record_index)?;
+            return Ok(record.into_iter().map(|value: Option<T>| value.unwrap_or(T::NAN)).collect());
+        });
+    }
+
+    /// Replaces the fill values, and the values outside the valid range if enabled, by `None`.
+    fn mask_values<T: NcType + PartialOrd>(&self, var_name: &str, data: Vec<T>) -> Result<Vec<Option<T>>, ReadError> {
+        let var: &Variable = self.data_set.get_var(var_name).ok_or(ReadError::Unexpected)?;  // previously checked
+        let fill_value: T = var.fill_value::<T>().ok_or(ReadError::Unexpected)?;  // previously checked
+        let mut masked_data: Vec<Option<T>> = mask_fill_values(data, fill_value);
+        if let (true, Some((valid_min, valid_max))) = (self.mask_out_of_range, var.valid_range()) {
+            for value in masked_data.iter_mut() {
+                let is_out_of_range: bool = match value {
+                    Some(value) => value.to_f64() < valid_min || value.to_f64() > valid_max,
+                    None => false,
+                };
+                if is_out_of_range {
+                    *value = None;
+                }
+            }
+        }
+        return Ok(masked_data);
+    }
+
+    /// Reads a packed variable and returns its unpacked values, computed with its CF attributes `scale_factor` and `add_offset`
+    /// (`value * scale_factor + add_offset`, by default `1.0` and `0.0`).
+    ///
+    /// The fill values are replaced by `NaN` (see the method `read_var_masked`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, FileReader, FileWriter, Version};
+    /// # use tempdir::TempDir;
+    /// # let tmp_dir: TempDir = TempDir::new("netcdf3_doc_").unwrap();
+    /// # let file_path = tmp_dir.path().join("packed.nc");
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_i16("sst", &["latitude"]).unwrap();
+    /// data_set.add_var_attr_f32("sst", "scale_factor", vec![0.5]).unwrap();
+    /// data_set.add_var_attr_f32("sst", "add_offset", vec![273.0]).unwrap();
+    /// data_set.add_var_attr_i16("sst", "_FillValue", vec![-1]).unwrap();
+    ///
+    /// let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+    /// file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    /// file_writer.write_var_packed("sst", &[280.5, std::f64::NAN, 273.0]).unwrap();
+    /// file_writer.close().unwrap();
+    ///
+    /// let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    /// assert_eq!(vec![15_i16, -1, 0],                 file_reader.read_var_i16("sst").unwrap());
+    ///
+    /// let sst: Vec<f64> = file_reader.read_var_unpacked_f64("sst").unwrap();
+    /// assert_eq!(280.5,                               sst[0]);
+    /// assert_eq!(true,                                sst[1].is_nan());
+    /// assert_eq!(273.0,                               sst[2]);
+    /// ```
+    pub fn read_var_unpacked_f64(&mut self, var_name: &str) -> Result<Vec<f64>, ReadError> {
+        let var: &Variable = self.data_set.get_var(var_name).ok_or_else(|| ReadError::VariableNotDefined(String::from(var_name)))?;
+        let data_type: DataType = var.data_type();
+        let (scale_factor, add_offset): (f64, f64) = var.packing_attrs();
+        let unpack = |value: Option<f64>| -> f64 {
+            return value.map_or(std::f64::NAN, |value: f64| value * scale_factor + add_offset);
+        };
+        return self.read_converted(var_name, |file_reader: &mut FileReader| Ok(match data_type {
+            DataType::I8 => file_reader.read_var_masked::<i8>(var_name)?.into_iter().map(|value: Option<i8>| unpack(value.map(f64::from))).collect(),
+            DataType::U8 => file_reader.read_var_masked::<u8>(var_name)?.into_iter().map(|value: Option<u8>| unpack(value.map(f64::from))).collect(),
+            DataType::I16 => file_reader.read_var_masked::<i16>(var_name)?.into_iter().map(|value: Option<i16>| unpack(value.map(f64::from))).collect(),
+            DataType::I32 => file_reader.read_var_masked::<i32>(var_name)?.into_iter().map(|value: Option<i32>| unpack(value.map(f64::from))).collect(),
+            DataType::F32 => file_reader.read_var_masked::<f32>(var_name)?.into_iter().map(|value: Option<f32>| unpack(value.map(f64::from))).collect(),
+            DataType::F64 => file_reader.read_var_masked::<f64>(var_name)?.into_iter().map(unpack).collect(),
+            DataType::U16 => file_reader.read_var_masked::<u16>(var_name)?.into_iter().map(|value: Option<u16>| unpack(value.map(f64::from))).collect(),
+            DataType::U32 => file_reader.read_var_masked::<u32>(var_name)?.into_iter().map(|value: Option<u32>| unpack(value.map(f64::from))).collect(),
+            DataType::I64 => file_reader.read_var_masked::<i64>(var_name)?.into_iter().map(|value: Option<i64>| unpack(value.map(|value: i64| value as f64))).collect(),
+            DataType::U64 => file_reader.read_var_masked::<u64>(var_name)?.into_iter().map(|value: Option<u64>| unpack(value.map(|value: u64| value as f64))).collect(),
+        }));
+    }
+
+    /// Checks that the variable is defined and that it has the data type `data_type`.
+    fn check_var_data_type(&self, var_name: &str, data_type: DataType) -> Result<(), ReadError> {
+        let (_var_index, var): (usize, &Variable) = self.data_set.find_var_from_name(var_name).map_err(|_err|{
+            ReadError::VariableNotDefined(String::from(var_name))
+        })?;
+        if var.data_type != data_type {
+            return Err(ReadError::VariableMismatchDataType{var_name: String::from(var_name), req: var.data_type.clone(), get: data_type});
+        }
+        return Ok(());
+    }
+
+    impl_read_typed_element!(read_element_i8, i8, DataType::I8);
+    impl_read_typed_element!(read_element_u8, u8, DataType::U8);
+    impl_read_typed_element!(read_element_i16, i16, DataType::I16);
+    impl_read_typed_element!(read_element_i32, i32, DataType::I32);
+    impl_read_typed_element!(read_element_f32, f32, DataType::F32);
+    impl_read_typed_element!(read_element_f64, f64, DataType::F64);
+    impl_read_typed_element!(read_element_u16, u16, DataType::U16);
+    impl_read_typed_element!(read_element_u32, u32, DataType::U32);
+    impl_read_typed_element!(read_element_i64, i64, DataType::I64);
+    impl_read_typed_element!(read_element_u64, u64, DataType::U64);
+
+    /// Reads a slice (an hyperslab) of the variable, whatever its data type.
+    ///
+    /// The slice starts at the multi-dimensional index `start` and contains `count[i]` elements along the i-th dimension.
+    /// The values are returned in row-major order, only the bytes of the slice are read from the file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{FileReader, DataVector};
+    ///
+    /// const TEMP_I16_VAR_NAME: &str = "temperature_i16";
+    ///
+    /// // ...
+    /// # use copy_to_tmp_file::{
+    /// #     copy_bytes_to_tmp_file,
+    /// #     NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+    /// # };
+    /// #
+    /// # // Copy bytes to an temporary file
+    /// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    ///
+    /// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+    /// assert_eq!(vec!["time", "latitude", "longitude"],  file_reader.data_set().get_var(TEMP_I16_VAR_NAME).unwrap().dim_names());
+    ///
+    /// // Read the 2 times of the sub-grid `latitude = 1..3` and `longitude = 3..5`
+    /// let data: DataVector = file_reader.read_var_slice(TEMP_I16_VAR_NAME, &[0, 1, 3], &[2, 2, 2]).unwrap();
+    /// assert_eq!(Some(&[8, 9, 13, 14, 23, 24, 28, 29][..]),      data.get_i16());
+    /// # tmp_dir.close();
+    /// ```
+    pub fn read_var_slice(&mut self, var_name: &str, start: &[usize], count: &[usize]) -> Result<DataVector, ReadError>
+    {
+        let stopwatch: Stopwatch = Stopwatch::start(self.instrumented);
+        let (_var_index, var): (usize, &Variable) = self.data_set.find_var_from_name(var_name).map_err(|_err|{
+            ReadError::VariableNotDefined(String::from(var_name))
+        })?;
+        let runs: Vec<(usize, usize, usize)> = var.locate_slice(start, count).ok_or_else(|| {
+            ReadError::SliceNotValid{
+                var_name: String::from(var_name),
+                start: start.to_vec(),
+                count: count.to_vec(),
+                shape: var.shape(),
+            }
+        })?;
+        let data_type: DataType = var.data_type();
+        let begin_offset: u64 = {
+            let var_info: &VariableParsedMetadata = self.find_var_info(var_name).ok_or(ReadError::Unexpected)?;
+            i64::from(var_info.begin_offset.clone()) as u64
         };
+        let record_size: usize = self.data_set.record_size().unwrap_or(0);
 
-        // Return the result
-        return Ok(FileReader{
-            data_set: data_set,
-            version: version,
-            input_file_path: input_file_path,
-            input_file: input_file,
-            vars_info: vars_info,  // convert the list of tuples to a map
-        })
+        let runs: Vec<(u64, usize)> = runs.into_iter().map(|(record_index, pos, len): (usize, usize, usize)| {
+            (begin_offset + ((record_index * record_size) as u64) + ((pos * data_type.size_of()) as u64), len)
+        }).collect();
+
+        // memory allocation
+        let slice_len: Option<usize> = runs.iter().try_fold(0_usize, |sum: usize, (_position, len): &(u64, usize)| sum.checked_add(*len));
+        let slice_end: u64 = runs.last().map(|(position, _len): &(u64, usize)| *position).unwrap_or(0);
+        let last_len: u64 = runs.last().map(|(_position, len): &(u64, usize)| (*len * data_type.size_of()) as u64).unwrap_or(0);
+        let slice_len: usize = self.check_read_size(var_name, &data_type, slice_len, self.memory_limit, |_num_bytes: u64| slice_end.checked_add(last_len))?;
+        let mut data_vec = DataVector::new(data_type.clone(), slice_len);
+        read_runs(&mut self.input_file, &mut data_vec, &runs)?;
+        self.record_read(var_name, &data_vec, stopwatch);
+        return Ok(data_vec);
     }
 
-    /// Closes the file and releases the data set and the file version.
-    pub fn close(self) -> (DataSet, Version) {
-        (self.data_set, self.version)
+    /// Returns a lazy view on the whole variable, no data is read.
+    ///
+    /// Also see an example [here](struct.VariableView.html#example).
+    pub fn view(&self, var_name: &str) -> Result<VariableView, ReadError>
+    {
+        let (_var_index, var): (usize, &Variable) = self.data_set.find_var_from_name(var_name).map_err(|_err|{
+            ReadError::VariableNotDefined(String::from(var_name))
+        })?;
+        return Ok(VariableView::new(var));
     }
 
-    /// Allows to read all variable data easily.
+    /// Reads the data selected by the `view`, only the bytes of the viewed hyperslab are read.
     ///
-    /// Also see an example [here](struct.FileReader.html#example).
-    pub fn read_all_vars(&mut self) -> Result<HashMap<String, DataVector>, ReadError>
+    /// Also see an example [here](struct.VariableView.html#example).
+    pub fn read_view(&mut self, view: &VariableView) -> Result<DataVector, ReadError>
     {
-        let var_names: Vec<String> = self.data_set.get_var_names();
-        var_names.into_iter()
-            .map(|var_name: String| {
-                let var_data: DataVector = self.read_var(&var_name)?;
-                Ok((var_name, var_data))
-            }).collect()
+        let var: &Variable = self.data_set.find_var_from_name(view.var_name()).map_err(|_err|{
+            ReadError::VariableNotDefined(String::from(view.var_name()))
+        })?.1;
+        if var.data_type != view.data_type() {
+            return Err(ReadError::VariableMismatchDataType{var_name: String::from(view.var_name()), req: var.data_type.clone(), get: view.data_type()});
+        }
+        return self.read_var_slice(view.var_name(), view.start(), view.count());
     }
 
-    /// Reads the typed variable and returns its values into `Vec`.
+    /// Reads the values located at the same `chunk_index` in all the records of the variable (for example the time series of one grid cell).
+    ///
+    /// The `chunk_index` is the multi-dimensional index inside one record, so without the *unlimited-size* dimension.
+    /// Only one value is read per record. The *fixed-size* variables have only one record.
     ///
     /// # Example
     ///
     /// ```
-    /// use netcdf3::{FileReader, DataSet, DataVector, DataType};
+    /// use netcdf3::{FileReader, DataVector};
     ///
-    /// const LATITUDE_VAR_NAME: &str = "latitude";
-    /// const LATITUDE_VAR_DATA: [f32; 3] = [0.0, 0.5, 1.0];
+    /// const TEMP_F32_VAR_NAME: &str = "temperature_f32";
     ///
     /// // ...
     /// # use copy_to_tmp_file::{
@@ -372,147 +1577,85 @@ impl FileReader {
     /// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
     ///
     /// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+    /// assert_eq!(vec!["time", "latitude", "longitude"],  file_reader.data_set().get_var(TEMP_F32_VAR_NAME).unwrap().dim_names());
+    /// assert_eq!(Some(2),                                 file_reader.data_set().num_records());
     ///
-    /// // Open the file
-    /// // -------------
-    /// assert_eq!(true,                    file_reader.data_set().has_var(LATITUDE_VAR_NAME));
-    /// assert_eq!(Some(DataType::F32),     file_reader.data_set().var_data_type(LATITUDE_VAR_NAME));
-    ///
-    /// // Read the variable
-    /// // -----------------
-    /// // using the method `FileReader::read_var`
-    /// {
-    ///     let latitudes: DataVector = file_reader.read_var(LATITUDE_VAR_NAME).unwrap();
-    ///     assert_eq!(DataType::F32,                           latitudes.data_type());
-    /// 
-    ///     assert_eq!(None,                                    latitudes.get_i8());
-    ///     assert_eq!(None,                                    latitudes.get_u8());
-    ///     assert_eq!(None,                                    latitudes.get_i16());
-    ///     assert_eq!(None,                                    latitudes.get_i32());
-    ///     assert_eq!(Some(&LATITUDE_VAR_DATA[..]),            latitudes.get_f32());
-    ///     assert_eq!(None,                                    latitudes.get_f64());
-    /// }
-    /// 
-    /// // using the method `FileReader::read_var_f32`
-    /// {
-    ///     let latitudes: Vec<f32> = file_reader.read_var_f32(LATITUDE_VAR_NAME).unwrap();
-    ///     assert_eq!(&LATITUDE_VAR_DATA[..],                  &latitudes[..]);
-    /// }
+    /// // Read the time series at `latitude = 2` and `longitude = 3`
+    /// assert_eq!(DataVector::F32(vec![13.0, 28.0]),       file_reader.read_column(TEMP_F32_VAR_NAME, &[2, 3]).unwrap());
+    /// assert_eq!(vec![13.0, 28.0],                        file_reader.read_column_f32(TEMP_F32_VAR_NAME, &[2, 3]).unwrap());
+    /// # tmp_dir.close();
     /// ```
-    pub fn read_var(&mut self, var_name: &str) -> Result<DataVector, ReadError>
+    pub fn read_column(&mut self, var_name: &str, chunk_index: &[usize]) -> Result<DataVector, ReadError>
     {
-        let (_, var): (usize, &Variable) = self.data_set.find_var_from_name(var_name).map_err(|_err|{
+        let (_var_index, var): (usize, &Variable) = self.data_set.find_var_from_name(var_name).map_err(|_err|{
             ReadError::VariableNotDefined(String::from(var_name))
         })?;
-        let record_size: usize = self.data_set.record_size().unwrap_or(0);
-        let num_records: usize = self.data_set.num_records().unwrap_or(0);
-        let begin_offset: u64 = {
-            let var_info: &VariableParsedMetadata = self.find_var_info(var_name).ok_or(ReadError::Unexpected)?;
-            i64::from(var_info.begin_offset.clone()) as u64
-        };
-        let data_type: DataType = var.data_type();
-        let chunk_len: usize = var.chunk_len();
-        let padding_size: usize = {
-            let num_bytes: usize = chunk_len * data_type.size_of();
-            compute_padding_size(num_bytes)
+        let (start, count): (Vec<usize>, Vec<usize>) = match var.is_record_var() {
+            true => (
+                std::iter::once(0).chain(chunk_index.iter().cloned()).collect(),
+                std::iter::once(var.num_chunks()).chain(chunk_index.iter().map(|_| 1)).collect(),
+            ),
+            false => (chunk_index.to_vec(), vec![1; chunk_index.len()]),
         };
-        let ref mut input = self.input_file;
-        input.seek(SeekFrom::Start(begin_offset))?;
-        // memory allocation
-        let mut data_vec = DataVector::new(data_type, var.len());
-        if !var.is_record_var() {
-            match data_vec {
-                DataVector::I8(ref mut data) => { input.read_i8_into(&mut data[..]) },
-                DataVector::U8(ref mut data) => { input.read_exact(&mut data[..]) },
-                DataVector::I16(ref mut data) => { input.read_i16_into::<BigEndian>(&mut data[..]) },
-                DataVector::I32(ref mut data) => { input.read_i32_into::<BigEndian>(&mut data[..]) },
-                DataVector::F32(ref mut data) => { input.read_f32_into::<BigEndian>(&mut data[..]) },
-                DataVector::F64(ref mut data) => { input.read_f64_into::<BigEndian>(&mut data[..]) },
-            }?;
-            if padding_size > 0
-            {
-                input.seek(SeekFrom::Current(padding_size as i64))?;
-            }
-        }
-        else {
-            let chunk_size: usize = var.chunk_size();
-
-            let offset_size: i64 = (record_size + padding_size - chunk_size) as i64;
-            for i in 0_usize..num_records
-            {
-                // reader.seek(SeekFrom::)
-                let start: usize = i * chunk_len;
-                let end: usize = (i + 1) * chunk_len;
-                match data_vec {
-                    DataVector::I8(ref mut data) => { input.read_i8_into(&mut data[start..end]) },
-                    DataVector::U8(ref mut data) => { input.read_exact(&mut data[start..end]) },
-                    DataVector::I16(ref mut data) => { input.read_i16_into::<BigEndian>(&mut data[start..end]) },
-                    DataVector::I32(ref mut data) => { input.read_i32_into::<BigEndian>(&mut data[start..end]) },
-                    DataVector::F32(ref mut data) => { input.read_f32_into::<BigEndian>(&mut data[start..end]) },
-                    DataVector::F64(ref mut data) => { input.read_f64_into::<BigEndian>(&mut data[start..end]) },
-                }?;
-                input.seek(SeekFrom::Current(offset_size))?;
+        return self.read_var_slice(var_name, &start, &count).map_err(|err: ReadError| {
+            match err {
+                ReadError::SliceNotValid{var_name, start: _, count: _, shape} => ReadError::ElementIndexNotValid{
+                    var_name: var_name,
+                    index: chunk_index.to_vec(),
+                    shape: shape,
+                },
+                _ => err,
             }
-        }
-        Ok(data_vec)
+        });
     }
 
-    impl_read_typed_var!(read_var_i8, i8, DataType::I8, DataVector::I8);
-    impl_read_typed_var!(read_var_u8, u8, DataType::U8, DataVector::U8);
-    impl_read_typed_var!(read_var_i16, i16, DataType::I16, DataVector::I16);
-    impl_read_typed_var!(read_var_i32, i32, DataType::I32, DataVector::I32);
-    impl_read_typed_var!(read_var_f32, f32, DataType::F32, DataVector::F32);
-    impl_read_typed_var!(read_var_f64, f64, DataType::F64, DataVector::F64);
+    impl_read_typed_column!(read_column_i8, i8, DataType::I8, DataVector::I8);
+    impl_read_typed_column!(read_column_u8, u8, DataType::U8, DataVector::U8);
+    impl_read_typed_column!(read_column_i16, i16, DataType::I16, DataVector::I16);
+    impl_read_typed_column!(read_column_i32, i32, DataType::I32, DataVector::I32);
+    impl_read_typed_column!(read_column_f32, f32, DataType::F32, DataVector::F32);
+    impl_read_typed_column!(read_column_f64, f64, DataType::F64, DataVector::F64);
+    impl_read_typed_column!(read_column_u16, u16, DataType::U16, DataVector::U16);
+    impl_read_typed_column!(read_column_u32, u32, DataType::U32, DataVector::U32);
+    impl_read_typed_column!(read_column_i64, i64, DataType::I64, DataVector::I64);
+    impl_read_typed_column!(read_column_u64, u64, DataType::U64, DataVector::U64);
 
-    /// Reads the typed records and returns its values into a typed`Vec`.
-    pub fn read_record(&mut self, var_name: &str, record_index: usize) -> Result<DataVector, ReadError>
+    /// Computes the position (number of bytes from the start of the file) of the element located at the multi-dimensional `index`.
+    fn compute_element_position(&self, var_name: &str, index: &[usize]) -> Result<u64, ReadError>
     {
         let (_var_index, var): (usize, &Variable) = self.data_set.find_var_from_name(var_name).map_err(|_err|{
             ReadError::VariableNotDefined(String::from(var_name))
         })?;
-        let num_records: usize = self.data_set.num_records().unwrap_or(1); // fixed-size variables haves exaclty one record
-        if record_index >= num_records {
-            return Err(ReadError::RecordIndexExceeded{index: record_index, num_records: num_records});
-        }
-
-        // Compute the record offset from the start of the NetCDF3 file
+        let (record_index, element_pos): (usize, usize) = var.locate_element(index).ok_or_else(|| {
+            ReadError::ElementIndexNotValid{
+                var_name: String::from(var_name),
+                index: index.to_vec(),
+                shape: var.shape(),
+            }
+        })?;
         let var_info: &VariableParsedMetadata = self.find_var_info(var_name).ok_or(ReadError::Unexpected)?;
-        let record_offset: u64 = (i64::from(var_info.begin_offset.clone()) as u64) + ((record_index * self.data_set.record_size().unwrap_or(0)) as u64);
-        self.input_file.seek(SeekFrom::Start(record_offset))?;
-
-        // Read the data
-        let data_type: DataType = var.data_type();
-        let mut data_vec: DataVector = DataVector::new(data_type, var.chunk_len());
-        match data_vec {
-            DataVector::I8(ref mut data) => self.input_file.read_i8_into(&mut data[..]),
-            DataVector::U8(ref mut data) => self.input_file.read_exact(&mut data[..]),
-            DataVector::I16(ref mut data) => self.input_file.read_i16_into::<BigEndian>(&mut data[..]),
-            DataVector::I32(ref mut data) => self.input_file.read_i32_into::<BigEndian>(&mut data[..]),
-            DataVector::F32(ref mut data) => self.input_file.read_f32_into::<BigEndian>(&mut data[..]),
-            DataVector::F64(ref mut data) => self.input_file.read_f64_into::<BigEndian>(&mut data[..]),
-        }?;
-        return Ok(data_vec);
+        let record_size: usize = self.data_set.record_size().unwrap_or(0);
+        let position: u64 = (i64::from(var_info.begin_offset.clone()) as u64)
+            + ((record_index * record_size) as u64)
+            + ((element_pos * var.data_type.size_of()) as u64);
+        return Ok(position);
     }
 
-    impl_read_typed_record!(read_record_i8, i8, DataType::I8, DataVector::I8);
-    impl_read_typed_record!(read_record_u8, u8, DataType::U8, DataVector::U8);
-    impl_read_typed_record!(read_record_i16, i16, DataType::I16, DataVector::I16);
-    impl_read_typed_record!(read_record_i32, i32, DataType::I32, DataVector::I32);
-    impl_read_typed_record!(read_record_f32, f32, DataType::F32, DataVector::F32);
-    impl_read_typed_record!(read_record_f64, f64, DataType::F64, DataVector::F64);
-
     /// Parses the NetCDF-3 header
-    fn parse_header(input: &[u8], total_file_size: usize) -> Result<(DataSet, Version, Vec<VariableParsedMetadata>), ReadError> {
+    ///
+    /// The tolerated deviations are pushed into `warnings` in lenient mode, and cause an error if `warnings` is `None`.
+    /// The values of the attributes larger than the limit of `lazy_attrs` are skipped and pushed into `lazy_attrs`.
+    fn parse_header(input: &[u8], total_file_size: usize, warnings: &mut Option<Vec<ReadWarning>>, lazy_attrs: &mut Option<LazyAttrs>, encoding: TextEncoding) -> Result<(DataSet, Version, Vec<VariableParsedMetadata>), ReadError> {
         // the magic word
         let (input, _): (&[u8], &[u8]) = FileReader::parse_magic_word(input)?;
         // the version number
         let (input, version) : (&[u8], Version) = FileReader::parse_version(input)?;
 
         // the number of records
-        let (input, num_records): (&[u8], Option<usize>) = FileReader::parse_as_usize_optional(input)?;
-        let (input, dims_list): (&[u8], Vec<(String, usize)>) = FileReader::parse_dims_list(input)?;
-        let (input, global_attrs_list): (&[u8], Vec<_>) = FileReader::parse_attrs_list(input)?;
-        let (_input, var_info_list): (&[u8], Vec<VariableParsedMetadata>) = FileReader::parse_vars_list(input, version.clone())?;
+        let (input, num_records): (&[u8], Option<usize>) = FileReader::parse_as_usize_optional(input, &version)?;
+        let (input, dims_list): (&[u8], Vec<(String, usize)>) = FileReader::parse_dims_list(input, &version, warnings, encoding)?;
+        let (input, global_attrs_list): (&[u8], Vec<_>) = FileReader::parse_attrs_list(input, &version, None, warnings, lazy_attrs, encoding)?;
+        let (_input, var_info_list): (&[u8], Vec<VariableParsedMetadata>) = FileReader::parse_vars_list(input, version.clone(), warnings, lazy_attrs, encoding)?;
 
         // Create a new dataset
         let mut data_set = DataSet::new();
@@ -552,6 +1695,18 @@ impl FileReader {
                 F64(data) => {
                     data_set.add_global_attr_f64(&attr_name, data)?;
                 }
+                U16(data) => {
+                    data_set.add_global_attr_u16(&attr_name, data)?;
+                }
+                U32(data) => {
+                    data_set.add_global_attr_u32(&attr_name, data)?;
+                }
+                I64(data) => {
+                    data_set.add_global_attr_i64(&attr_name, data)?;
+                }
+                U64(data) => {
+                    data_set.add_global_attr_u64(&attr_name, data)?;
+                }
             }
         }
 
@@ -588,6 +1743,18 @@ impl FileReader {
                     F64(data) => {
                         data_set.add_var_attr_f64(&var_name, &attr_name, data.clone())?;
                     }
+                    U16(data) => {
+                        data_set.add_var_attr_u16(&var_name, &attr_name, data.clone())?;
+                    }
+                    U32(data) => {
+                        data_set.add_var_attr_u32(&var_name, &attr_name, data.clone())?;
+                    }
+                    I64(data) => {
+                        data_set.add_var_attr_i64(&var_name, &attr_name, data.clone())?;
+                    }
+                    U64(data) => {
+                        data_set.add_var_attr_u64(&var_name, &attr_name, data.clone())?;
+                    }
                 }
             }
         }
@@ -611,7 +1778,10 @@ impl FileReader {
                     num_records = all_records_size.checked_div_euclid(record_size).ok_or(ReadError::Unexpected)?;
                     let num_rem_bytes: usize = all_records_size.checked_rem_euclid(record_size).ok_or(ReadError::Unexpected)?;  // the number of remaining bytes
                     if num_rem_bytes != 0 {
-                        return Err(ReadError::ComputationNumberOfRecords);
+                        match warnings {
+                            Some(warnings) => warnings.push(ReadWarning::TrailingBytes(num_rem_bytes)),
+                            None => return Err(ReadError::ComputationNumberOfRecords),
+                        }
                     }
                 }
                 match &dim.size {
@@ -636,7 +1806,7 @@ impl FileReader {
     fn parse_version(input: &[u8]) -> Result<(&[u8], Version), ParseHeaderError>
     {
         let (input, version_number): (&[u8], u8) = verify(be_u8, |ver_num: &u8|{
-            ver_num == &(Version::Classic as u8) || ver_num == &(Version::Offset64Bit as u8)
+            Version::try_from(*ver_num).is_ok()
         })(input).map_err(|err: NomError|{
             ParseHeaderError::new(err, ParseHeaderErrorKind::VersionNumber)
         })?;
@@ -651,10 +1821,27 @@ impl FileReader {
         })
     }
 
-    /// Parses a non-negative `i32` word and converts it to a `usize`.
-    fn parse_as_usize(input: &[u8]) -> Result<(&[u8], usize), ParseHeaderError> {
-        let (input, number): (&[u8], i32) = FileReader::parse_non_neg_i32(input)?;
-        Ok((input, number as usize))
+    /// Parses a `i64` word and checks that it is non-negative.
+    fn parse_non_neg_i64(input: &[u8]) -> Result<(&[u8], i64), ParseHeaderError> {
+        verify(be_i64, |number: &i64| *number >= 0_i64)(input).map_err(|err: NomError|{
+            ParseHeaderError::new(err, ParseHeaderErrorKind::NonNegativeI64)
+        })
+    }
+
+    /// Parses a non-negative integer and converts it to a `usize`.
+    ///
+    /// The integer is a `i32` word, or a `i64` word for the CDF-5 version.
+    fn parse_as_usize<'a>(input: &'a [u8], version: &Version) -> Result<(&'a [u8], usize), ParseHeaderError> {
+        match version {
+            Version::Classic | Version::Offset64Bit => {
+                let (input, number): (&[u8], i32) = FileReader::parse_non_neg_i32(input)?;
+                Ok((input, number as usize))
+            },
+            Version::Cdf5 => {
+                let (input, number): (&[u8], i64) = FileReader::parse_non_neg_i64(input)?;
+                Ok((input, number as usize))
+            },
+        }
     }
 
     /// Parses the number of records
@@ -662,16 +1849,51 @@ impl FileReader {
     /// Returns :
     /// - The numbers of records if it is a valid integer.
     /// - `None` if the number of records is indeterminated
-    fn parse_as_usize_optional(input: &[u8]) -> Result<(&[u8], Option<usize>), ParseHeaderError> {
-        const INDETERMINATE_VALUE: u32 = std::u32::MAX;
-        let (input, value): (&[u8], u32) = verify(be_u32, |number: &u32| *number <= (std::i32::MAX as u32) || *number == INDETERMINATE_VALUE)(input).map_err(|err: NomError|{
-            ParseHeaderError::new(err, ParseHeaderErrorKind::NonNegativeI32)
-        })?;
-        let value: Option<usize> = match value {
-            INDETERMINATE_VALUE => None,
-            _ => Some(value as usize),
-        };
-        Ok((input, value))
+    fn parse_as_usize_optional<'a>(input: &'a [u8], version: &Version) -> Result<(&'a [u8], Option<usize>), ParseHeaderError> {
+        match version {
+            Version::Classic | Version::Offset64Bit => {
+                const INDETERMINATE_VALUE: u32 = std::u32::MAX;
+                let (input, value): (&[u8], u32) = verify(be_u32, |number: &u32| *number <= (std::i32::MAX as u32) || *number == INDETERMINATE_VALUE)(input).map_err(|err: NomError|{
+                    ParseHeaderError::new(err, ParseHeaderErrorKind::NonNegativeI32)
+                })?;
+                let value: Option<usize> = match value {
+                    INDETERMINATE_VALUE => None,
+                    _ => Some(value as usize),
+                };
+                Ok((input, value))
+            },
+            Version::Cdf5 => {
+                const INDETERMINATE_VALUE: u64 = std::u64::MAX;
+                let (input, value): (&[u8], u64) = verify(be_u64, |number: &u64| *number <= (std::i64::MAX as u64) || *number == INDETERMINATE_VALUE)(input).map_err(|err: NomError|{
+                    ParseHeaderError::new(err, ParseHeaderErrorKind::NonNegativeI64)
+                })?;
+                let value: Option<usize> = match value {
+                    INDETERMINATE_VALUE => None,
+                    _ => Some(value as usize),
+                };
+                Ok((input, value))
+            },
+        }
+    }
+
+    /// Parses the size of the chunks of a variable (`vsize`), `None` means *indeterminate*.
+    ///
+    /// For the *classic* and the *64-bit offset* versions, the `vsize` is an unsigned 32-bit integer and `2^32 - 1` means *indeterminate*
+    /// (the chunk size of the variable is greater than `2^32 - 4`).
+    fn parse_var_size<'a>(input: &'a [u8], version: &Version) -> Result<(&'a [u8], Option<usize>), ParseHeaderError> {
+        match version {
+            Version::Classic | Version::Offset64Bit => {
+                let (input, value): (&[u8], u32) = be_u32(input).map_err(|err: NomError|{
+                    ParseHeaderError::new(err, ParseHeaderErrorKind::NonNegativeI32)
+                })?;
+                let value: Option<usize> = match value {
+                    std::u32::MAX => None,
+                    _ => Some(value as usize),
+                };
+                Ok((input, value))
+            },
+            Version::Cdf5 => FileReader::parse_as_usize_optional(input, version),
+        }
     }
 
     /// Parses a non-negative `i32` word and converts it to a `u32`.
@@ -680,25 +1902,27 @@ impl FileReader {
         Ok((input, number as u32))
     }
     /// Parses a string
-    fn parse_name_string(input: &[u8]) -> Result<(&[u8], String), ParseHeaderError>
+    fn parse_name_string<'a>(input: &'a [u8], version: &Version, warnings: &mut Option<Vec<ReadWarning>>, encoding: TextEncoding) -> Result<(&'a [u8], String), ParseHeaderError>
     {
-        let (input, num_of_bytes): (&[u8], usize) = FileReader::parse_as_usize(input)?;
+        let (input, num_of_bytes): (&[u8], usize) = FileReader::parse_as_usize(input, version)?;
         let (input, name): (&[u8], String) = map_res(take(num_of_bytes), |bytes: &[u8]| {
-            String::from_utf8(bytes.to_vec())
+            encoding.decode(bytes).ok_or(())
         })(input).map_err(|err: NomError|{
             ParseHeaderError::new(err, ParseHeaderErrorKind::Utf8)
         })?;
         // Take the zero padding bytes if necessary
-        let (input, _zero_padding_bytes): (&[u8], &[u8]) = FileReader::parse_zero_padding(input, compute_padding_size(num_of_bytes))?;
+        let (input, _zero_padding_bytes): (&[u8], &[u8]) = FileReader::parse_padding(input, compute_padding_size(num_of_bytes), warnings)?;
         Ok((input, name))
     }
 
-    // Parses a NetCDF-3 data type.
-    fn parse_data_type(input: &[u8]) -> Result<(&[u8], DataType), ParseHeaderError>
+    // Parses a NetCDF-3 data type, which must be supported by the version.
+    fn parse_data_type<'a>(input: &'a [u8], version: &Version) -> Result<(&'a [u8], DataType), ParseHeaderError>
     {
         let start: &[u8] = input;
         let (input, data_type_number): (&[u8], u32) = FileReader::parse_as_u32(input)?;
-        let data_type: DataType = DataType::try_from(data_type_number).map_err(|_err|{
+        let data_type: DataType = DataType::try_from(data_type_number).ok().filter(|data_type: &DataType| {
+            version.supports_data_type(data_type)
+        }).ok_or_else(|| {
             nom::Err::Error((&start[0..4], nom::error::ErrorKind::Verify))
         }).map_err(|err: NomError|{
             ParseHeaderError::new(err, ParseHeaderErrorKind::DataType)
@@ -706,7 +1930,7 @@ impl FileReader {
         Ok((input, data_type))
     }
 
-    fn parse_typed_data_elements(input: &[u8], num_of_elements: usize, data_type: DataType) -> Result<(&[u8], DataVector), ParseHeaderError>
+    fn parse_typed_data_elements<'a>(input: &'a [u8], num_of_elements: usize, data_type: DataType, warnings: &mut Option<Vec<ReadWarning>>) -> Result<(&'a [u8], DataVector), ParseHeaderError>
     {
         // Parsed the useful data
         let (input, data_vector): (&[u8], DataVector) = match data_type {
@@ -716,13 +1940,17 @@ impl FileReader {
             DataType::I32 => many_m_n(num_of_elements, num_of_elements, be_i32)(input).map(|(input, data): (&[u8], Vec<i32>)| (input, DataVector::I32(data))),
             DataType::F32 => many_m_n(num_of_elements, num_of_elements, be_f32)(input).map(|(input, data): (&[u8], Vec<f32>)| (input, DataVector::F32(data))),
             DataType::F64 => many_m_n(num_of_elements, num_of_elements, be_f64)(input).map(|(input, data): (&[u8], Vec<f64>)| (input, DataVector::F64(data))),
+            DataType::U16 => many_m_n(num_of_elements, num_of_elements, be_u16)(input).map(|(input, data): (&[u8], Vec<u16>)| (input, DataVector::U16(data))),
+            DataType::U32 => many_m_n(num_of_elements, num_of_elements, be_u32)(input).map(|(input, data): (&[u8], Vec<u32>)| (input, DataVector::U32(data))),
+            DataType::I64 => many_m_n(num_of_elements, num_of_elements, be_i64)(input).map(|(input, data): (&[u8], Vec<i64>)| (input, DataVector::I64(data))),
+            DataType::U64 => many_m_n(num_of_elements, num_of_elements, be_u64)(input).map(|(input, data): (&[u8], Vec<u64>)| (input, DataVector::U64(data))),
         }.map_err(|err: NomError|{
             ParseHeaderError::new(err, ParseHeaderErrorKind::DataElements)
         })?;
 
         // Parse the zero padding bytes if necessary
         let num_of_bytes: usize = data_type.size_of() * num_of_elements;
-        let (input, _zero_padding_bytes): (&[u8], &[u8]) = FileReader::parse_zero_padding(input, compute_padding_size(num_of_bytes))?;
+        let (input, _zero_padding_bytes): (&[u8], &[u8]) = FileReader::parse_padding(input, compute_padding_size(num_of_bytes), warnings)?;
         Ok((input, data_vector))
     }
 
@@ -737,25 +1965,38 @@ impl FileReader {
         })
     }
 
+    /// Parses the zero padding bytes, the non-zero padding bytes are only tolerated in lenient mode.
+    fn parse_padding<'a>(input: &'a [u8], num_bytes: usize, warnings: &mut Option<Vec<ReadWarning>>) -> Result<(&'a [u8], &'a [u8]), ParseHeaderError>
+    {
+        match (FileReader::parse_zero_padding(input, num_bytes), warnings) {
+            (Err(err), Some(warnings)) if !err.header_is_incomplete() => {
+                let (padding_bytes, input): (&[u8], &[u8]) = input.split_at(num_bytes);  // the input is long enough, previously checked
+                warnings.push(ReadWarning::NonZeroPadding(padding_bytes.to_vec()));
+                Ok((input, padding_bytes))
+            },
+            (parsing_result, _) => parsing_result,
+        }
+    }
+
     // Parses the list of the dimensions from the header.
-    fn parse_dims_list(input: &[u8]) -> Result<(&[u8], Vec<(String, usize)>), ParseHeaderError>
+    fn parse_dims_list<'a>(input: &'a [u8], version: &Version, warnings: &mut Option<Vec<ReadWarning>>, encoding: TextEncoding) -> Result<(&'a [u8], Vec<(String, usize)>), ParseHeaderError>
     {
-        fn parse_dim(input: &[u8]) -> Result<(&[u8], (String, usize)), ParseHeaderError>
+        fn parse_dim<'a>(input: &'a [u8], version: &Version, warnings: &mut Option<Vec<ReadWarning>>, encoding: TextEncoding) -> Result<(&'a [u8], (String, usize)), ParseHeaderError>
         {
-            let (input, dim_name): (&[u8], String) = FileReader::parse_name_string(input)?;
-            let (input, dim_size): (&[u8], usize) = FileReader::parse_as_usize(input)?;
+            let (input, dim_name): (&[u8], String) = FileReader::parse_name_string(input, version, warnings, encoding)?;
+            let (input, dim_size): (&[u8], usize) = FileReader::parse_as_usize(input, version)?;
             Ok((input, (dim_name, dim_size)))
         }
-        let (input, dim_tag): (&[u8], &[u8]) = alt((tag(ABSENT_TAG), tag(DIMENSION_TAG)))(input).map_err(|err: NomError|{
+        let (input, dim_tag): (&[u8], &[u8]) = alt((tag(absent_tag(version)), tag(DIMENSION_TAG)))(input).map_err(|err: NomError|{
             ParseHeaderError::new(err, ParseHeaderErrorKind::DimTag)
         })?;
-        if dim_tag == &ABSENT_TAG {
+        if dim_tag == absent_tag(version) {
             return Ok((input, vec![]));
         }
-        let (mut input, num_of_dims): (&[u8], usize) = FileReader::parse_as_usize(input)?;
+        let (mut input, num_of_dims): (&[u8], usize) = FileReader::parse_as_usize(input, version)?;
         let mut dims_list: Vec<(String, usize)> = Vec::with_capacity(num_of_dims);
         for _ in 0..num_of_dims{
-            let (rem_input, dim): (&[u8], (String, usize)) = parse_dim(input)?;
+            let (rem_input, dim): (&[u8], (String, usize)) = parse_dim(input, version, warnings, encoding)?;
             input = rem_input;
             dims_list.push(dim);
         }
@@ -764,44 +2005,97 @@ impl FileReader {
     }
 
     // Parses a list of attributes (global of from any variables) from the header.
-    fn parse_attrs_list(input: &[u8]) -> Result<(&[u8], Vec<(String, DataVector)>), ParseHeaderError>
+    //
+    // In lenient mode, the list ends at the first attribute which cannot be parsed.
+    fn parse_attrs_list<'a>(input: &'a [u8], version: &Version, var_name: Option<&str>, warnings: &mut Option<Vec<ReadWarning>>, lazy_attrs: &mut Option<LazyAttrs>, encoding: TextEncoding) -> Result<(&'a [u8], Vec<(String, DataVector)>), ParseHeaderError>
     {
-        fn parse_attr(input: &[u8]) -> Result<(&[u8], (String, DataVector)), ParseHeaderError>
+        fn parse_attr<'a>(input: &'a [u8], version: &Version, warnings: &mut Option<Vec<ReadWarning>>, lazy_attrs: &Option<LazyAttrs>, encoding: TextEncoding) -> Result<(&'a [u8], (String, ParsedAttrValue)), ParseHeaderError>
         {
-            let (input, attr_name): (&[u8], String) = FileReader::parse_name_string(input)?;
-            let (input, attr_data_type): (&[u8], DataType) = FileReader::parse_data_type(input)?;
-            let (input, num_of_elements): (&[u8], usize) = FileReader::parse_as_usize(input)?;
-            let (input, attr_data): (&[u8], DataVector) = FileReader::parse_typed_data_elements(input, num_of_elements, attr_data_type)?;
-            Ok((input, (attr_name, attr_data)))
+            let (input, attr_name): (&[u8], String) = FileReader::parse_name_string(input, version, warnings, encoding)?;
+            let (input, attr_data_type): (&[u8], DataType) = FileReader::parse_data_type(input, version)?;
+            let (input, num_of_elements): (&[u8], usize) = FileReader::parse_as_usize(input, version)?;
+            let num_of_bytes: usize = attr_data_type.size_of() * num_of_elements;
+            let is_lazy = |lazy_attrs: &&LazyAttrs| num_of_bytes > lazy_attrs.max_attr_size && !LazyAttrs::EAGER_ATTR_NAMES.contains(&attr_name.as_str());
+            if let Some(lazy_attrs) = lazy_attrs.as_ref().filter(is_lazy) {
+                // Skip the values, only their position is kept
+                let begin_offset: u64 = (lazy_attrs.header_len - input.len()) as u64;
+                let (input, _attr_bytes): (&[u8], &[u8]) = take(num_of_bytes)(input).map_err(|err: NomError|{
+                    ParseHeaderError::new(err, ParseHeaderErrorKind::DataElements)
+                })?;
+                let (input, _zero_padding_bytes): (&[u8], &[u8]) = FileReader::parse_padding(input, compute_padding_size(num_of_bytes), warnings)?;
+                return Ok((input, (attr_name, ParsedAttrValue::Lazy{data_type: attr_data_type, len: num_of_elements, begin_offset: begin_offset})));
+            }
+            let (input, attr_data): (&[u8], DataVector) = FileReader::parse_typed_data_elements(input, num_of_elements, attr_data_type, warnings)?;
+            Ok((input, (attr_name, ParsedAttrValue::Decoded(attr_data))))
         }
-        let (input, attr_tag): (&[u8], &[u8]) = alt((tag(ABSENT_TAG), tag(ATTRIBUTE_TAG)))(input).map_err(|err: NomError|{
+        fn push_attr(attrs_list: &mut Vec<(String, DataVector)>, lazy_attrs: &mut Option<LazyAttrs>, var_name: Option<&str>, (attr_name, attr_value): (String, ParsedAttrValue)) {
+            match (attr_value, lazy_attrs) {
+                (ParsedAttrValue::Decoded(attr_data), _) => attrs_list.push((attr_name, attr_data)),
+                (ParsedAttrValue::Lazy{data_type, len, begin_offset}, Some(lazy_attrs)) => lazy_attrs.attrs.push(LazyAttribute{
+                    var_name: var_name.map(|var_name: &str| var_name.to_owned()),
+                    index: attrs_list.len() + lazy_attrs.attrs.iter().filter(|attr: &&LazyAttribute| attr.var_name.as_deref() == var_name).count(),
+                    name: attr_name,
+                    data_type: data_type,
+                    len: len,
+                    begin_offset: begin_offset,
+                }),
+                (ParsedAttrValue::Lazy{..}, None) => {},
+            }
+        }
+        let (input, attr_tag): (&[u8], &[u8]) = alt((tag(absent_tag(version)), tag(ATTRIBUTE_TAG)))(input).map_err(|err: NomError|{
             ParseHeaderError::new(err, ParseHeaderErrorKind::AttrTag)
         })?;
-        if attr_tag == &ABSENT_TAG {
+        if attr_tag == absent_tag(version) {
             return Ok((input, vec![]));
         }
-        let (mut input, num_of_attrs): (&[u8], usize) = FileReader::parse_as_usize(input)?;
+        let (mut input, num_of_attrs): (&[u8], usize) = FileReader::parse_as_usize(input, version)?;
         let mut attrs_list: Vec<(String, DataVector)> = Vec::with_capacity(num_of_attrs);
         for _ in 0..num_of_attrs
         {
-            let (rem_input, attr): (&[u8], (String, DataVector)) = parse_attr(input)?;
-            input = rem_input;
-            attrs_list.push(attr);
+            let lenient_warnings: &mut Vec<ReadWarning> = match warnings {
+                None => {
+                    let (rem_input, attr): (&[u8], (String, ParsedAttrValue)) = parse_attr(input, version, &mut None, lazy_attrs, encoding)?;
+                    input = rem_input;
+                    push_attr(&mut attrs_list, lazy_attrs, var_name, attr);
+                    continue;
+                },
+                Some(lenient_warnings) => lenient_warnings,
+            };
+            // Lenient mode, the warnings of an attribute are kept only if this attribute is valid
+            let mut attr_warnings: Option<Vec<ReadWarning>> = Some(vec![]);
+            match parse_attr(input, version, &mut attr_warnings, lazy_attrs, encoding) {
+                Ok((rem_input, attr)) if is_valid_name(&attr.0) => {
+                    input = rem_input;
+                    push_attr(&mut attrs_list, lazy_attrs, var_name, attr);
+                    lenient_warnings.extend(attr_warnings.unwrap_or_default());
+                },
+                Err(err) if err.header_is_incomplete() => return Err(err),
+                _ => {
+                    lenient_warnings.push(ReadWarning::AttributeCountMismatch{
+                        var_name: var_name.map(|var_name: &str| var_name.to_owned()),
+                        expected: num_of_attrs,
+                        found: attrs_list.len() + lazy_attrs.as_ref().map(|lazy_attrs: &LazyAttrs| {
+                            lazy_attrs.attrs.iter().filter(|attr: &&LazyAttribute| attr.var_name.as_deref() == var_name).count()
+                        }).unwrap_or(0),
+                    });
+                    break;
+                },
+            }
         }
         Ok((input, attrs_list))
     }
 
     // Parses a list of variables from the header.
-    fn parse_vars_list(input: &[u8], version: Version) -> Result<(&[u8], Vec<VariableParsedMetadata>), ParseHeaderError>
+    fn parse_vars_list<'a>(input: &'a [u8], version: Version, warnings: &mut Option<Vec<ReadWarning>>, lazy_attrs: &mut Option<LazyAttrs>, encoding: TextEncoding) -> Result<(&'a [u8], Vec<VariableParsedMetadata>), ParseHeaderError>
     {
-        fn parse_dim_ids_list(input: &[u8]) -> Result<(&[u8], Vec<usize>), ParseHeaderError>
+        fn parse_dim_ids_list<'a>(input: &'a [u8], version: &Version) -> Result<(&'a [u8], Vec<usize>), ParseHeaderError>
         {
                 // number of dimensions
-                let (mut input, num_of_dims): (&[u8], usize) = FileReader::parse_as_usize(input)?;
+                let (mut input, num_of_dims): (&[u8], usize) = FileReader::parse_as_usize(input, version)?;
                 // list of the dimension ids
                 let mut dim_ids_list: Vec<usize> = Vec::with_capacity(num_of_dims);
                 for _ in 0..num_of_dims {
-                    let(rem_input, dim_id): (&[u8], usize) = FileReader::parse_as_usize(input)?;
+                    let(rem_input, dim_id): (&[u8], usize) = FileReader::parse_as_usize(input, version)?;
                     input = rem_input;
                     dim_ids_list.push(dim_id);
                 }
@@ -816,7 +2110,7 @@ impl FileReader {
                         (input, Offset::I32(num_of_bytes))
                     })
                 },
-                Version::Offset64Bit => {
+                Version::Offset64Bit | Version::Cdf5 => {
                     be_i64(input).map(|(input, num_of_bytes): (&[u8], i64)| {
                         (input, Offset::I64(num_of_bytes))
                     })
@@ -826,18 +2120,18 @@ impl FileReader {
             })
         }
 
-        fn parse_var(input: &[u8], version: Version) -> Result<(&[u8], VariableParsedMetadata), ParseHeaderError> {
+        fn parse_var<'a>(input: &'a [u8], version: Version, warnings: &mut Option<Vec<ReadWarning>>, lazy_attrs: &mut Option<LazyAttrs>, encoding: TextEncoding) -> Result<(&'a [u8], VariableParsedMetadata), ParseHeaderError> {
             // Variable name
-            let (input, var_name): (&[u8], String) = FileReader::parse_name_string(input)?;
+            let (input, var_name): (&[u8], String) = FileReader::parse_name_string(input, &version, warnings, encoding)?;
 
             // list of the dimensions
-            let (input, dim_ids): (&[u8], Vec<usize>) = parse_dim_ids_list(input)?;
+            let (input, dim_ids): (&[u8], Vec<usize>) = parse_dim_ids_list(input, &version)?;
             // list of the variable attributes
-            let (input, attrs_list): (&[u8], Vec<(String, DataVector)>) = FileReader::parse_attrs_list(input)?;
+            let (input, attrs_list): (&[u8], Vec<(String, DataVector)>) = FileReader::parse_attrs_list(input, &version, Some(&var_name), warnings, lazy_attrs, encoding)?;
             // data type of the variable
-            let (input, data_type): (& [u8], DataType) = FileReader::parse_data_type(input)?;
+            let (input, data_type): (& [u8], DataType) = FileReader::parse_data_type(input, &version)?;
             // size occupied in each record by the variable (number of bytes)
-            let (input, chunk_size): (&[u8], Option<usize>) = FileReader::parse_as_usize_optional(input)?;
+            let (input, chunk_size): (&[u8], Option<usize>) = FileReader::parse_var_size(input, &version)?;
             // begin offset (number of bytes)
             let (input, begin_offset): (&[u8], Offset) = parse_offset(input, version)?;
             let var_def = VariableParsedMetadata {
@@ -850,27 +2144,153 @@ impl FileReader {
             };
             return Ok((input, var_def));
         }
-        let (input, var_tag): (&[u8], &[u8]) = alt((tag(ABSENT_TAG), tag(VARIABLE_TAG)))(input).map_err(|err: NomError| {
+        let (input, var_tag): (&[u8], &[u8]) = alt((tag(absent_tag(&version)), tag(VARIABLE_TAG)))(input).map_err(|err: NomError| {
             ParseHeaderError::new(err, ParseHeaderErrorKind::VarTag)
         })?;
-        if var_tag == &ABSENT_TAG {
+        if var_tag == absent_tag(&version) {
             return Ok((input, vec![]));
         }
-        let (mut input, num_of_vars): (&[u8], usize) = FileReader::parse_as_usize(input)?;
+        let (mut input, num_of_vars): (&[u8], usize) = FileReader::parse_as_usize(input, &version)?;
         let mut vars_list: Vec<VariableParsedMetadata> = vec![];
         for _ in 0..num_of_vars {
-            let (temp_input, var) = parse_var(input, version.clone())?;
+            let (temp_input, var) = parse_var(input, version.clone(), warnings, lazy_attrs, encoding)?;
             input = temp_input;
             vars_list.push(var);
         }
         Ok((input, vars_list))
     }
 
+    /// Returns the begin offset of the variable parsed from the header.
+    pub(crate) fn var_begin_offset(&self, var_name: &str) -> Option<Offset> {
+        return self.find_var_info(var_name).map(|var_info: &VariableParsedMetadata| var_info.begin_offset.clone());
+    }
+
+    /// Returns a reader of the raw bytes (including the padding bytes) of one chunk of the variable.
+    pub(crate) fn chunk_bytes(&mut self, var_name: &str, chunk_index: usize) -> Result<std::io::Take<&mut Storage>, ReadError> {
+        let var: &Variable = self.data_set.get_var(var_name).ok_or_else(|| ReadError::VariableNotDefined(var_name.to_owned()))?;
+        if chunk_index >= var.num_chunks() {
+            return Err(ReadError::RecordIndexExceeded{index: chunk_index, num_records: var.num_chunks()});
+        }
+        let chunk_size: u64 = var.chunk_size() as u64;
+        let begin_offset: i64 = i64::from(self.var_begin_offset(var_name).ok_or(ReadError::Unexpected)?);
+        let record_size: usize = self.data_set.record_size().unwrap_or(0);
+        let position: u64 = (begin_offset as u64) + ((chunk_index * record_size) as u64);
+        self.input_file.seek(SeekFrom::Start(position))?;
+        return Ok((&mut self.input_file).take(chunk_size));
+    }
+
+    /// Returns the data type of the variable, and the position and the length of each of its chunks.
+    ///
+    /// Returns the error `ReadError::MemoryLimitExceeded` if the data require more than `memory_limit` bytes, and the
+    /// error `ReadError::IOErrorKind(UnexpectedEof)` if they are located beyond the end of the file.
+    fn locate_var_chunks(&self, var_name: &str, memory_limit: Option<usize>) -> Result<(DataType, Vec<(u64, usize)>), ReadError> {
+        let (_, var): (usize, &Variable) = self.data_set.find_var_from_name(var_name).map_err(|_err|{
+            ReadError::VariableNotDefined(String::from(var_name))
+        })?;
+        let record_size: usize = self.data_set.record_size().unwrap_or(0);
+        let num_records: usize = self.data_set.num_records().unwrap_or(0);
+        let begin_offset: u64 = {
+            let var_info: &VariableParsedMetadata = self.find_var_info(var_name).ok_or(ReadError::Unexpected)?;
+            i64::from(var_info.begin_offset.clone()) as u64
+        };
+        let num_chunks: usize = match var.is_record_var() {
+            false => 1,
+            true => num_records,
+        };
+        let var_len: Option<usize> = checked_chunk_len(var).and_then(|chunk_len: usize| chunk_len.checked_mul(num_chunks));
+        let data_type: DataType = var.data_type();
+        self.check_read_size(var_name, &data_type, var_len, memory_limit, |num_bytes: u64| {
+            // end of the last chunk
+            let last_chunk_size: u64 = num_bytes / (num_chunks as u64);
+            return ((num_chunks - 1) as u64).checked_mul(record_size as u64)
+                .and_then(|last_chunk_offset: u64| last_chunk_offset.checked_add(begin_offset))
+                .and_then(|last_chunk_begin: u64| last_chunk_begin.checked_add(last_chunk_size));
+        })?;
+        let chunk_len: usize = var.chunk_len();
+        let runs: Vec<(u64, usize)> = match var.is_record_var() {
+            false => vec![(begin_offset, chunk_len)],
+            true => (0..num_records).map(|record_index: usize| (begin_offset + (record_index * record_size) as u64, chunk_len)).collect(),
+        };
+        return Ok((data_type, runs));
+    }
+
+    /// Checks that `num_values` values can be allocated within the `memory_limit`, and that the position after their
+    /// last byte (computed by `data_end` from their number of bytes) does not exceed the size of the file.
+    ///
+    /// Returns the number of values, `None` meaning that it overflows.
+    fn check_read_size<F>(&self, var_name: &str, data_type: &DataType, num_values: Option<usize>, memory_limit: Option<usize>, data_end: F) -> Result<usize, ReadError>
+    where
+        F: FnOnce(u64) -> Option<u64>,
+    {
+        let num_bytes: Option<u64> = num_values.and_then(|num_values: usize| num_values.checked_mul(data_type.size_of())).map(|num_bytes: usize| num_bytes as u64);
+        if let Some(limit) = memory_limit {
+            if num_bytes.map(|num_bytes: u64| num_bytes > limit as u64).unwrap_or(true) {
+                return Err(ReadError::MemoryLimitExceeded{
+                    var_name: String::from(var_name),
+                    req: num_bytes.map(|num_bytes: u64| num_bytes as usize).unwrap_or(usize::MAX),
+                    limit: limit,
+                });
+            }
+        }
+        if num_values == Some(0) {
+            return Ok(0);
+        }
+        match num_bytes.and_then(data_end) {
+            Some(end) if end <= self.file_size => {},
+            _ => return Err(ReadError::IOErrorKind(std::io::ErrorKind::UnexpectedEof)),
+        }
+        return num_values.ok_or(ReadError::Unexpected);
+    }
+
     fn find_var_info(&self, var_name: &str) -> Option<&VariableParsedMetadata> {
         self.vars_info.iter().find(|var_info| var_info.name == var_name)
     }
 }
 
+/// Attribute whose values have not been decoded while parsing the header (see `FileReader::open_with_lazy_attrs`).
+#[derive(Debug, Clone, PartialEq)]
+struct LazyAttribute {
+    /// Name of the variable, `None` for a global attribute
+    var_name: Option<String>,
+    /// Position in the attributes of the header
+    index: usize,
+    name: String,
+    data_type: DataType,
+    len: usize,
+    begin_offset: u64,
+}
+
+/// Lazy attributes collected while parsing the header.
+#[derive(Debug)]
+struct LazyAttrs {
+    /// Maximum number of bytes of the values decoded while parsing the header
+    max_attr_size: usize,
+    /// Number of bytes of the parsed buffer, starting at the beginning of the file
+    header_len: usize,
+    attrs: Vec<LazyAttribute>,
+}
+
+impl LazyAttrs {
+    /// Attributes used by the masked, NaN and unpacked reads, never deferred
+    const EAGER_ATTR_NAMES: [&'static str; 6] = ["_FillValue", "valid_min", "valid_max", "valid_range", "scale_factor", "add_offset"];
+}
+
+/// Values of an attribute parsed from the header.
+enum ParsedAttrValue {
+    Decoded(DataVector),
+    Lazy{data_type: DataType, len: usize, begin_offset: u64},
+}
+
+/// Header of a file parsed by a `FileReader`, kept to open the same file again (see [`ReaderPool`](struct.ReaderPool.html)).
+#[derive(Debug, Clone)]
+pub(crate) struct ParsedHeader {
+    data_set: DataSet,
+    version: Version,
+    vars_info: Vec<VariableParsedMetadata>,
+    warnings: Vec<ReadWarning>,
+    lazy_attrs: Vec<LazyAttribute>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 struct VariableParsedMetadata {
     name: String,
@@ -882,3 +2302,186 @@ struct VariableParsedMetadata {
 }
 
 
+
+/// Maximum number of bytes skipped between two runs of values read at once (see `read_runs`)
+const COALESCED_MAX_GAP: u64 = 8_192;
+/// Maximum number of bytes buffered to read several runs of values at once (see `read_runs`)
+const COALESCED_BUFFER_SIZE: u64 = 4 * 1024 * 1024;
+
+/// Groups the consecutive runs of values `(position, length)` read at once, and returns the ranges of their indices.
+///
+/// The adjacent runs (e.g. the records of a file having a single *record* variable) are grouped, as well as the runs
+/// separated by small gaps (e.g. the chunks of a small *record* variable) while the whole group fits in a buffer.
+fn coalesce_runs(runs: &[(u64, usize)], size_of: u64) -> Vec<std::ops::Range<usize>> {
+    let mut groups: Vec<std::ops::Range<usize>> = vec![];
+    let mut first: usize = 0;
+    while first < runs.len() {
+        let group_begin: u64 = runs[first].0;
+        let mut group_end: u64 = group_begin + runs[first].1 as u64 * size_of;
+        let mut contiguous: bool = true;
+        let mut last: usize = first + 1;
+        while let Some((position, len)) = runs.get(last) {
+            if *position < group_end || *position - group_end > COALESCED_MAX_GAP {
+                break;
+            }
+            let run_end: u64 = *position + *len as u64 * size_of;
+            let run_contiguous: bool = contiguous && *position == group_end;
+            if !run_contiguous && run_end - group_begin > COALESCED_BUFFER_SIZE {
+                break;
+            }
+            contiguous = run_contiguous;
+            group_end = run_end;
+            last += 1;
+        }
+        groups.push(first..last);
+        first = last;
+    }
+    return groups;
+}
+
+/// Returns the position of the first byte and the position after the last byte of the runs.
+fn runs_span(runs: &[(u64, usize)], size_of: u64) -> (u64, u64) {
+    let begin: u64 = runs.first().map(|(position, _len): &(u64, usize)| *position).unwrap_or(0);
+    let end: u64 = runs.last().map(|(position, len): &(u64, usize)| *position + *len as u64 * size_of).unwrap_or(0);
+    return (begin, end);
+}
+
+/// Reads the runs of values `(position, length)` consecutively into the `data_vec`.
+///
+/// The runs grouped by `coalesce_runs` are read at once, instead of one read per run : the adjacent runs are read
+/// directly into the `data_vec` and their byte order is converted in place (so the data are allocated once), and the
+/// other groups are read into a buffer of at most `COALESCED_BUFFER_SIZE` bytes.
+fn read_runs(input: &mut Storage, data_vec: &mut DataVector, runs: &[(u64, usize)]) -> Result<(), ReadError> {
+    let size_of: u64 = data_vec.data_type().size_of() as u64;
+    let mut buffer: Vec<u8> = vec![];
+    let mut data_start: usize = 0;
+    for group in coalesce_runs(runs, size_of).into_iter() {
+        let group_runs: &[(u64, usize)] = &runs[group];
+        let (group_begin, group_end): (u64, u64) = runs_span(group_runs, size_of);
+        let group_len: usize = group_runs.iter().map(|(_position, len): &(u64, usize)| len).sum();
+        input.seek(SeekFrom::Start(group_begin))?;
+        if group_len as u64 * size_of == group_end - group_begin {
+            read_values_into(input, data_vec, data_start, data_start + group_len)?;
+            data_start += group_len;
+        }
+        else {
+            buffer.resize((group_end - group_begin) as usize, 0);
+            input.read_exact(&mut buffer[..])?;
+            data_start = decode_runs(&buffer, group_begin, group_runs, data_vec, data_start)?;
+        }
+    }
+    return Ok(());
+}
+
+/// Reads the runs of values `(position, length)` into the `data_vec` with positional reads, so several variables
+/// can be read at the same time from the same `input`.
+///
+/// The bytes are read into a buffer of at most `COALESCED_BUFFER_SIZE` bytes, and decoded into the `data_vec`.
+#[cfg(feature = "rayon")]
+fn read_runs_at(input: &Storage, data_vec: &mut DataVector, runs: &[(u64, usize)]) -> std::io::Result<()> {
+    let size_of: u64 = data_vec.data_type().size_of() as u64;
+    let mut buffer: Vec<u8> = vec![];
+    let mut data_start: usize = 0;
+    for group in coalesce_runs(runs, size_of).into_iter() {
+        let group_runs: &[(u64, usize)] = &runs[group];
+        let (group_begin, group_end): (u64, u64) = runs_span(group_runs, size_of);
+        let group_len: usize = group_runs.iter().map(|(_position, len): &(u64, usize)| len).sum();
+        if group_len as u64 * size_of == group_end - group_begin {
+            // The adjacent runs are decoded block by block, the whole group is not buffered
+            let block_len: usize = (COALESCED_BUFFER_SIZE / size_of) as usize;
+            for block_start in (0..group_len).step_by(block_len) {
+                let len: usize = std::cmp::min(block_len, group_len - block_start);
+                buffer.resize(len * size_of as usize, 0);
+                input.read_exact_at(&mut buffer[..], group_begin + block_start as u64 * size_of)?;
+                read_values_into(&mut &buffer[..], data_vec, data_start, data_start + len)?;
+                data_start += len;
+            }
+        }
+        else {
+            buffer.resize((group_end - group_begin) as usize, 0);
+            input.read_exact_at(&mut buffer[..], group_begin)?;
+            data_start = decode_runs(&buffer, group_begin, group_runs, data_vec, data_start)?;
+        }
+    }
+    return Ok(());
+}
+
+/// Decodes the runs of values from the `buffer` (starting at the position `buffer_begin`) into `data_vec[data_start..]`,
+/// and returns the index following the last decoded value.
+fn decode_runs(buffer: &[u8], buffer_begin: u64, runs: &[(u64, usize)], data_vec: &mut DataVector, mut data_start: usize) -> std::io::Result<usize> {
+    for (position, len) in runs.iter() {
+        let mut run_bytes: &[u8] = &buffer[((position - buffer_begin) as usize)..];
+        read_values_into(&mut run_bytes, data_vec, data_start, data_start + len)?;
+        data_start += len;
+    }
+    return Ok(data_start);
+}
+
+/// Returns the number of values of each chunk of the variable, `None` if it overflows.
+fn checked_chunk_len(var: &Variable) -> Option<usize> {
+    let skip_len: usize = if var.is_record_var() { 1 } else { 0 };
+    return var.shape().into_iter().skip(skip_len).try_fold(1_usize, |product: usize, dim_size: usize| product.checked_mul(dim_size));
+}
+
+/// Reads and decodes the big-endian values of `data_vec[start..end]`.
+fn read_values_into<R: Read>(input: &mut R, data_vec: &mut DataVector, start: usize, end: usize) -> std::io::Result<()> {
+    return match data_vec {
+        DataVector::I8(ref mut data) => { input.read_i8_into(&mut data[start..end]) },
+        DataVector::U8(ref mut data) => { input.read_exact(&mut data[start..end]) },
+        DataVector::I16(ref mut data) => { input.read_i16_into::<BigEndian>(&mut data[start..end]) },
+        DataVector::I32(ref mut data) => { input.read_i32_into::<BigEndian>(&mut data[start..end]) },
+        DataVector::F32(ref mut data) => { input.read_f32_into::<BigEndian>(&mut data[start..end]) },
+        DataVector::F64(ref mut data) => { input.read_f64_into::<BigEndian>(&mut data[start..end]) },
+        DataVector::U16(ref mut data) => { input.read_u16_into::<BigEndian>(&mut data[start..end]) },
+        DataVector::U32(ref mut data) => { input.read_u32_into::<BigEndian>(&mut data[start..end]) },
+        DataVector::I64(ref mut data) => { input.read_i64_into::<BigEndian>(&mut data[start..end]) },
+        DataVector::U64(ref mut data) => { input.read_u64_into::<BigEndian>(&mut data[start..end]) },
+    };
+}
+
+/// Replaces the fill values by `None`, the fill value `NaN` matches all the `NaN` values.
+fn mask_fill_values<T: PartialOrd>(data: Vec<T>, fill_value: T) -> Vec<Option<T>> {
+    let fill_value_is_nan: bool = fill_value.partial_cmp(&fill_value).is_none();
+    return data.into_iter().map(|value: T| {
+        let is_fill_value: bool = match fill_value_is_nan {
+            true => value.partial_cmp(&value).is_none(),
+            false => value == fill_value,
+        };
+        match is_fill_value {
+            true => None,
+            false => Some(value),
+        }
+    }).collect();
+}
+
+/// Appends the global attribute to the data set.
+fn add_global_attr(data_set: &mut DataSet, attr_name: &str, attr_data: DataVector) -> Result<(), InvalidDataSet> {
+    return match attr_data {
+        DataVector::I8(data) => data_set.add_global_attr_i8(attr_name, data),
+        DataVector::U8(data) => data_set.add_global_attr_u8(attr_name, data),
+        DataVector::I16(data) => data_set.add_global_attr_i16(attr_name, data),
+        DataVector::I32(data) => data_set.add_global_attr_i32(attr_name, data),
+        DataVector::F32(data) => data_set.add_global_attr_f32(attr_name, data),
+        DataVector::F64(data) => data_set.add_global_attr_f64(attr_name, data),
+        DataVector::U16(data) => data_set.add_global_attr_u16(attr_name, data),
+        DataVector::U32(data) => data_set.add_global_attr_u32(attr_name, data),
+        DataVector::I64(data) => data_set.add_global_attr_i64(attr_name, data),
+        DataVector::U64(data) => data_set.add_global_attr_u64(attr_name, data),
+    };
+}
+
+/// Appends the attribute of the variable to the data set.
+fn add_var_attr(data_set: &mut DataSet, var_name: &str, attr_name: &str, attr_data: DataVector) -> Result<(), InvalidDataSet> {
+    return match attr_data {
+        DataVector::I8(data) => data_set.add_var_attr_i8(var_name, attr_name, data),
+        DataVector::U8(data) => data_set.add_var_attr_u8(var_name, attr_name, data),
+        DataVector::I16(data) => data_set.add_var_attr_i16(var_name, attr_name, data),
+        DataVector::I32(data) => data_set.add_var_attr_i32(var_name, attr_name, data),
+        DataVector::F32(data) => data_set.add_var_attr_f32(var_name, attr_name, data),
+        DataVector::F64(data) => data_set.add_var_attr_f64(var_name, attr_name, data),
+        DataVector::U16(data) => data_set.add_var_attr_u16(var_name, attr_name, data),
+        DataVector::U32(data) => data_set.add_var_attr_u32(var_name, attr_name, data),
+        DataVector::I64(data) => data_set.add_var_attr_i64(var_name, attr_name, data),
+        DataVector::U64(data) => data_set.add_var_attr_u64(var_name, attr_name, data),
+    };
+}
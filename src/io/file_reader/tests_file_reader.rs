@@ -1,14 +1,20 @@
 #![cfg(test)]
 use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::time::Duration;
 use byteorder::{WriteBytesExt, BigEndian};
 
 use crate::{
-    FileReader, Variable, DataSet, Attribute, Dimension, DataType, DimensionType, Version,
-    error::ReadError,
+    FileReader, FileWriter, Variable, DataSet, DataVector, Attribute, Dimension, DataType, DimensionType, Version, TextEncoding, StringOptions, Timings, VarTimings,
+    error::{InvalidDataSet, ReadError, ReadWarning},
     error::parse_header_error::{ParseHeaderError, ParseHeaderErrorKind, InvalidBytes},
     io::compute_padding_size,
+    NC_FILL_I8,
+    NC_FILL_U64,
 };
 
+use tempdir::TempDir;
+
 use copy_to_tmp_file::{
     copy_bytes_to_tmp_file,
     NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
@@ -332,7 +338,7 @@ fn test_parse_header() {
 
     let num_of_bytes: usize = NC3_CLASSIC_FILE_BYTES.len();
     let parsing_result: Result<(DataSet, Version, Vec<VariableParsedMetadata>), ReadError>;
-    parsing_result = FileReader::parse_header(NC3_CLASSIC_FILE_BYTES, num_of_bytes);
+    parsing_result = FileReader::parse_header(NC3_CLASSIC_FILE_BYTES, num_of_bytes, &mut None, &mut None, TextEncoding::Utf8);
     assert_eq!(true,                        parsing_result.is_ok());
     let (data_set, version, _vars_info) = parsing_result.unwrap();
 
@@ -570,7 +576,7 @@ fn test_parse_truncated_header()
         let truncated_file_bytes: &[u8] = &b""[..];
         let file_size: usize = truncated_file_bytes.len();
         // Open the NetCDF-3 file
-        let parsing_res: Result<(DataSet, Version, Vec<_>), ReadError> = FileReader::parse_header(truncated_file_bytes, file_size);
+        let parsing_res: Result<(DataSet, Version, Vec<_>), ReadError> = FileReader::parse_header(truncated_file_bytes, file_size, &mut None, &mut None, TextEncoding::Utf8);
         assert_eq!(true,                parsing_res.is_err());
         let parsing_err: ReadError = parsing_res.unwrap_err();
         assert_eq!(true,                parsing_err.header_is_incomplete());
@@ -581,7 +587,7 @@ fn test_parse_truncated_header()
         let truncated_file_bytes: &[u8] = &NC3_CLASSIC_FILE_BYTES[..1];
         let file_size: usize = truncated_file_bytes.len();
         // Open the NetCDF-3 file
-        let parsing_res: Result<(DataSet, Version, Vec<_>), ReadError> = FileReader::parse_header(truncated_file_bytes, file_size);
+        let parsing_res: Result<(DataSet, Version, Vec<_>), ReadError> = FileReader::parse_header(truncated_file_bytes, file_size, &mut None, &mut None, TextEncoding::Utf8);
         assert_eq!(true,                parsing_res.is_err());
         let parsing_err: ReadError = parsing_res.unwrap_err();
         assert_eq!(true,                parsing_err.header_is_incomplete());
@@ -592,7 +598,7 @@ fn test_parse_truncated_header()
         let truncated_file_bytes: &[u8] = &NC3_CLASSIC_FILE_BYTES[..(HEADER_NUM_OF_BYTES - 1)];
         let file_size: usize = truncated_file_bytes.len();
         // Open the NetCDF-3 file
-        let parsing_res: Result<(DataSet, Version, Vec<_>), ReadError> = FileReader::parse_header(truncated_file_bytes, file_size);
+        let parsing_res: Result<(DataSet, Version, Vec<_>), ReadError> = FileReader::parse_header(truncated_file_bytes, file_size, &mut None, &mut None, TextEncoding::Utf8);
         assert_eq!(true,                parsing_res.is_err());
         let parsing_err: ReadError = parsing_res.unwrap_err();
         assert_eq!(true,                parsing_err.header_is_incomplete());
@@ -603,7 +609,7 @@ fn test_parse_truncated_header()
         let truncated_file_bytes: &[u8] = &NC3_CLASSIC_FILE_BYTES[..(HEADER_NUM_OF_BYTES)];
         let file_size: usize = truncated_file_bytes.len();
         // Open the NetCDF-3 file
-        let parsing_res: Result<(DataSet, Version, Vec<_>), ReadError> = FileReader::parse_header(truncated_file_bytes, file_size);
+        let parsing_res: Result<(DataSet, Version, Vec<_>), ReadError> = FileReader::parse_header(truncated_file_bytes, file_size, &mut None, &mut None, TextEncoding::Utf8);
         assert_eq!(true,                parsing_res.is_ok());
     }
 }
@@ -718,7 +724,7 @@ fn test_parse_num_records() {
         let a: u32 = std::u32::MAX;
         let bytes: [u8; 4] = a.to_be_bytes();
         // parse the integer
-        let (rem_bytes, b): (&[u8], Option<usize>) = FileReader::parse_as_usize_optional(&bytes[..]).unwrap();
+        let (rem_bytes, b): (&[u8], Option<usize>) = FileReader::parse_as_usize_optional(&bytes[..], &Version::Classic).unwrap();
         // test remaining bytes and the parsed value
         assert_eq!(&[] as &[u8],                    rem_bytes);
         assert_eq!(None,                            b);
@@ -729,7 +735,7 @@ fn test_parse_num_records() {
         let a: u32 = 0_u32;
         let bytes: [u8; 4] = a.to_be_bytes();
         // parse the integer
-        let (rem_bytes, b): (&[u8],Option<usize>) = FileReader::parse_as_usize_optional(&bytes[..]).unwrap();
+        let (rem_bytes, b): (&[u8],Option<usize>) = FileReader::parse_as_usize_optional(&bytes[..], &Version::Classic).unwrap();
         // test remaining bytes and the parsed value
         assert_eq!(&[] as &[u8],                rem_bytes);
         assert_eq!(Some(0),                     b);
@@ -740,7 +746,7 @@ fn test_parse_num_records() {
         let a: u32 = 1_u32;
         let bytes: [u8; 4] = a.to_be_bytes();
         // parse the integer
-        let (rem_bytes, b): (&[u8],Option<usize>) = FileReader::parse_as_usize_optional(&bytes[..]).unwrap();
+        let (rem_bytes, b): (&[u8],Option<usize>) = FileReader::parse_as_usize_optional(&bytes[..], &Version::Classic).unwrap();
         // test remaining bytes and the parsed value
         assert_eq!(&[] as &[u8],                rem_bytes);
         assert_eq!(Some(1),                     b);
@@ -751,7 +757,7 @@ fn test_parse_num_records() {
         let a: u32 = std::i32::MAX as u32;
         let bytes: [u8; 4] = a.to_be_bytes();
         // parse the integer
-        let (rem_bytes, b): (&[u8], Option<usize>) = FileReader::parse_as_usize_optional(&bytes[..]).unwrap();
+        let (rem_bytes, b): (&[u8], Option<usize>) = FileReader::parse_as_usize_optional(&bytes[..], &Version::Classic).unwrap();
         // test remaining bytes and the parsed value
         assert_eq!(&[] as &[u8],                    rem_bytes);
         assert_eq!(Some(std::i32::MAX as usize),    b);
@@ -762,7 +768,7 @@ fn test_parse_num_records() {
         let a: i32 = std::i32::MIN;
         let bytes: [u8; 4] = a.to_be_bytes();
         // parse the integer
-        let parsing_result = FileReader::parse_as_usize_optional(&bytes[..]);
+        let parsing_result = FileReader::parse_as_usize_optional(&bytes[..], &Version::Classic);
         // check the returned error
         assert_eq!(true,                                        parsing_result.is_err());
         let parsing_err: ParseHeaderError = parsing_result.unwrap_err();
@@ -779,7 +785,7 @@ fn test_parse_num_records() {
         let a: u32 = (std::i32::MIN as u32) + 1;
         let bytes: [u8; 4] = a.to_be_bytes();
         // parse the integer
-        let parsing_result = FileReader::parse_as_usize_optional(&bytes[..]);
+        let parsing_result = FileReader::parse_as_usize_optional(&bytes[..], &Version::Classic);
         // check the returned error
         assert_eq!(true,                                        parsing_result.is_err());
         let parsing_err: ParseHeaderError = parsing_result.unwrap_err();
@@ -794,7 +800,7 @@ fn test_parse_num_records() {
         let bytes: Vec<u8> = Vec::from(&a.to_be_bytes()[0..3]);
         // parse the integer
         // parse the integer
-        let parsing_result = FileReader::parse_as_usize_optional(&bytes[..]);
+        let parsing_result = FileReader::parse_as_usize_optional(&bytes[..], &Version::Classic);
         // check the returned error
         assert!(parsing_result.is_err());
         let parsing_err: ParseHeaderError = parsing_result.unwrap_err();
@@ -804,6 +810,68 @@ fn test_parse_num_records() {
     }
 }
 
+#[test]
+fn test_parse_var_size() {
+    // The values greater than `std::i32::MAX` are valid for the classic and the 64-bit offset versions
+    for version in [Version::Classic, Version::Offset64Bit].iter() {
+        let bytes: [u8; 4] = (std::u32::MAX - 3).to_be_bytes();
+        let (rem_bytes, var_size): (&[u8], Option<usize>) = FileReader::parse_var_size(&bytes[..], version).unwrap();
+        assert_eq!(&[] as &[u8],                            rem_bytes);
+        assert_eq!(Some((std::u32::MAX - 3) as usize),      var_size);
+
+        // The indeterminate value
+        let bytes: [u8; 4] = std::u32::MAX.to_be_bytes();
+        let (rem_bytes, var_size): (&[u8], Option<usize>) = FileReader::parse_var_size(&bytes[..], version).unwrap();
+        assert_eq!(&[] as &[u8],                            rem_bytes);
+        assert_eq!(None,                                    var_size);
+    }
+
+    // The CDF-5 version uses 64-bit integers
+    {
+        let bytes: [u8; 8] = (5_u64 << 32).to_be_bytes();
+        let (rem_bytes, var_size): (&[u8], Option<usize>) = FileReader::parse_var_size(&bytes[..], &Version::Cdf5).unwrap();
+        assert_eq!(&[] as &[u8],                            rem_bytes);
+        assert_eq!(Some(5 << 32),                           var_size);
+    }
+
+    // Missing input bytes
+    {
+        let parsing_err: ParseHeaderError = FileReader::parse_var_size(&[0, 0, 0], &Version::Classic).unwrap_err();
+        assert_eq!(true,                                    parsing_err.header_is_incomplete());
+    }
+}
+
+#[test]
+fn test_read_header_of_large_vars() {
+    // 3 GiB and 5 GiB variables
+    const DIM_SIZE_1: usize = 3 * 1024 * 1024 * 1024 / 8;
+    const DIM_SIZE_2: usize = 5 * 1024 * 1024 * 1024 / 8;
+
+    let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    let file_path: PathBuf = tmp_dir.path().join("large_vars.nc");
+
+    let mut data_set: DataSet = DataSet::new();
+    data_set.add_fixed_dim("dim_1", DIM_SIZE_1).unwrap();
+    data_set.add_fixed_dim("dim_2", DIM_SIZE_2).unwrap();
+    data_set.add_var_f64("var_1", &["dim_1"]).unwrap();
+    data_set.add_var_f64("var_2", &["dim_2"]).unwrap();
+    {
+        // Only the header is written
+        let mut file_writer: FileWriter = FileWriter::create_new(&file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Offset64Bit, 0).unwrap();
+    }
+
+    let file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    assert_eq!(&data_set,                                   file_reader.data_set());
+    // The `vsize` of the 5 GiB variable is indeterminate
+    assert_eq!(Some(DIM_SIZE_1 * 8),                        file_reader.find_var_info("var_1").unwrap()._chunk_size);
+    assert_eq!(None,                                        file_reader.find_var_info("var_2").unwrap()._chunk_size);
+    let begin_offset_1: i64 = i64::from(file_reader.var_begin_offset("var_1").unwrap());
+    let begin_offset_2: i64 = i64::from(file_reader.var_begin_offset("var_2").unwrap());
+    assert_eq!((DIM_SIZE_1 * 8) as i64,                     begin_offset_2 - begin_offset_1);
+    tmp_dir.close().unwrap();
+}
+
 #[test]
 fn test_parse_name_string() {
     {
@@ -827,7 +895,7 @@ fn test_parse_name_string() {
                 bytes
             };
             // Parse the bytes into a string
-            let (rem_bytes, name): (&[u8], String)= FileReader::parse_name_string(&bytes).unwrap();
+            let (rem_bytes, name): (&[u8], String)= FileReader::parse_name_string(&bytes, &Version::Classic, &mut None, TextEncoding::Utf8).unwrap();
             // Test the parsed string
             assert_eq!("foo", name);
             // And test the remaining bytes
@@ -856,7 +924,7 @@ fn test_parse_name_string() {
                 bytes
             };
             // Parse the bytes into a string
-            let (rem_bytes, name): (&[u8], String)= FileReader::parse_name_string(&bytes).unwrap();
+            let (rem_bytes, name): (&[u8], String)= FileReader::parse_name_string(&bytes, &Version::Classic, &mut None, TextEncoding::Utf8).unwrap();
             // Test the parsed string
             assert_eq!("foo", name);
             // And test the remaining bytes
@@ -889,7 +957,7 @@ fn test_parse_name_string() {
                 bytes
             };
             // check the returned error
-            let parsing_result = FileReader::parse_name_string(&bytes[..]);
+            let parsing_result = FileReader::parse_name_string(&bytes[..], &Version::Classic, &mut None, TextEncoding::Utf8);
             assert!(parsing_result.is_err());
             let parsing_err: ParseHeaderError = parsing_result.unwrap_err();
             assert_eq!(false,                               parsing_err.header_is_incomplete());
@@ -917,7 +985,7 @@ fn test_parse_name_string() {
                 bytes
             };
             // Parse the bytes into a string
-            let (rem_bytes, name): (&[u8], String)= FileReader::parse_name_string(&bytes).unwrap();
+            let (rem_bytes, name): (&[u8], String)= FileReader::parse_name_string(&bytes, &Version::Classic, &mut None, TextEncoding::Utf8).unwrap();
             // Test the parsed string
             assert_eq!("café", name);
             // And test the remaining bytes
@@ -945,7 +1013,7 @@ fn test_parse_name_string() {
                 bytes
             };
             // Parse the bytes into a string
-            let parsing_result: Result<_, _> = FileReader::parse_name_string(&bytes);
+            let parsing_result: Result<_, _> = FileReader::parse_name_string(&bytes, &Version::Classic, &mut None, TextEncoding::Utf8);
             // Test the parsed string
             assert!(parsing_result.is_err());
             assert!(parsing_result.is_err());
@@ -981,7 +1049,7 @@ fn test_parse_name_string() {
                 bytes
             };
             // Parse the bytes into a string
-            let parsing_result: Result<_, _> = FileReader::parse_name_string(&bytes);
+            let parsing_result: Result<_, _> = FileReader::parse_name_string(&bytes, &Version::Classic, &mut None, TextEncoding::Utf8);
             // Test the parsed string
             assert!(parsing_result.is_err());
             let parsing_err: ParseHeaderError = parsing_result.unwrap_err();
@@ -1003,7 +1071,7 @@ fn test_parse_data_type() {
     {
         let a: u32 = DataType::I8 as u32;
         let bytes: [u8; 4] = a.to_be_bytes();
-        let (rem_input, data_type): (&[u8], DataType) = FileReader::parse_data_type(&bytes[..]).unwrap();
+        let (rem_input, data_type): (&[u8], DataType) = FileReader::parse_data_type(&bytes[..], &Version::Classic).unwrap();
         assert_eq!(DataType::I8, data_type);
         assert_eq!(&[] as &[u8], rem_input);
     }
@@ -1012,7 +1080,7 @@ fn test_parse_data_type() {
     {
         let a: u32 = DataType::U8 as u32;
         let bytes: [u8; 4] = a.to_be_bytes();
-        let (rem_input, data_type): (&[u8], DataType) = FileReader::parse_data_type(&bytes[..]).unwrap();
+        let (rem_input, data_type): (&[u8], DataType) = FileReader::parse_data_type(&bytes[..], &Version::Classic).unwrap();
         assert_eq!(DataType::U8, data_type);
         assert_eq!(&[] as &[u8], rem_input);
     }
@@ -1021,7 +1089,7 @@ fn test_parse_data_type() {
     {
         let a: u32 = DataType::I16 as u32;
         let bytes: [u8; 4] = a.to_be_bytes();
-        let (rem_input, data_type): (&[u8], DataType) = FileReader::parse_data_type(&bytes[..]).unwrap();
+        let (rem_input, data_type): (&[u8], DataType) = FileReader::parse_data_type(&bytes[..], &Version::Classic).unwrap();
         assert_eq!(DataType::I16, data_type);
         assert_eq!(&[] as &[u8], rem_input);
     }
@@ -1030,7 +1098,7 @@ fn test_parse_data_type() {
     {
         let a: u32 = DataType::I32 as u32;
         let bytes: [u8; 4] = a.to_be_bytes();
-        let (rem_input, data_type): (&[u8], DataType) = FileReader::parse_data_type(&bytes[..]).unwrap();
+        let (rem_input, data_type): (&[u8], DataType) = FileReader::parse_data_type(&bytes[..], &Version::Classic).unwrap();
         assert_eq!(DataType::I32, data_type);
         assert_eq!(&[] as &[u8], rem_input);
     }
@@ -1039,7 +1107,7 @@ fn test_parse_data_type() {
     {
         let a: u32 = DataType::F32 as u32;
         let bytes: [u8; 4] = a.to_be_bytes();
-        let (rem_input, data_type): (&[u8], DataType) = FileReader::parse_data_type(&bytes[..]).unwrap();
+        let (rem_input, data_type): (&[u8], DataType) = FileReader::parse_data_type(&bytes[..], &Version::Classic).unwrap();
         assert_eq!(DataType::F32, data_type);
         assert_eq!(&[] as &[u8], rem_input);
     }
@@ -1048,7 +1116,7 @@ fn test_parse_data_type() {
     {
         let a: u32 = DataType::F64 as u32;
         let bytes: [u8; 4] = a.to_be_bytes();
-        let (rem_input, data_type): (&[u8], DataType) = FileReader::parse_data_type(&bytes[..]).unwrap();
+        let (rem_input, data_type): (&[u8], DataType) = FileReader::parse_data_type(&bytes[..], &Version::Classic).unwrap();
         assert_eq!(DataType::F64, data_type);
         assert_eq!(&[] as &[u8], rem_input);
     }
@@ -1059,16 +1127,29 @@ fn test_parse_data_type() {
         assert!(DataType::try_from(a).is_err());
 
         let bytes: [u8; 4] = a.to_be_bytes();
-        let parsing_result = FileReader::parse_data_type(&bytes[..]);
+        let parsing_result = FileReader::parse_data_type(&bytes[..], &Version::Classic);
         assert!(parsing_result.is_err());
     }
 
+    // test parse the CDF-5 data types, which are only supported by the CDF-5 version
+    for data_type in [DataType::U16, DataType::U32, DataType::I64, DataType::U64].iter() {
+        let bytes: [u8; 4] = (data_type.clone() as u32).to_be_bytes();
+        for version in [Version::Classic, Version::Offset64Bit].iter() {
+            let parsing_err: ParseHeaderError = FileReader::parse_data_type(&bytes[..], version).unwrap_err();
+            assert_eq!(false,  parsing_err.header_is_incomplete());
+            assert_eq!(ParseHeaderErrorKind::DataType, parsing_err.kind);
+        }
+        let (rem_input, parsed_data_type): (&[u8], DataType) = FileReader::parse_data_type(&bytes[..], &Version::Cdf5).unwrap();
+        assert_eq!(data_type.clone(), parsed_data_type);
+        assert_eq!(&[] as &[u8], rem_input);
+    }
+
     // test parse a negative `DataType` number
     {
         let a: i32 = -1_i32;
 
         let bytes: [u8; 4] = a.to_be_bytes();
-        let parsing_result = FileReader::parse_data_type(&bytes[..]);
+        let parsing_result = FileReader::parse_data_type(&bytes[..], &Version::Classic);
         // Check the return error
         assert!(parsing_result.is_err());
         let parsing_err: ParseHeaderError = parsing_result.unwrap_err();
@@ -1089,7 +1170,7 @@ fn test_parse_data_type() {
         bytes.push(43);
         bytes.push(44);
 
-        let (rem_input, data_type): (&[u8], DataType) = FileReader::parse_data_type(&bytes[..]).unwrap();
+        let (rem_input, data_type): (&[u8], DataType) = FileReader::parse_data_type(&bytes[..], &Version::Classic).unwrap();
         assert_eq!(DataType::F64, data_type);
         assert_eq!(
             &[42, 43, 44],
@@ -1102,7 +1183,7 @@ fn test_parse_data_type() {
         let a: u32 = DataType::F64 as u32;
         let bytes: Vec<u8> = Vec::from(&a.to_be_bytes()[..3]);
         assert_eq!(3, bytes.len());
-        let parsing_result = FileReader::parse_data_type(&bytes[..]);
+        let parsing_result = FileReader::parse_data_type(&bytes[..], &Version::Classic);
         // Check the return error
         assert!(parsing_result.is_err());
         let parsing_err: ParseHeaderError = parsing_result.unwrap_err();
@@ -1698,3 +1779,860 @@ fn test_read_record_f64() {
     let _ = file_reader.close();
     tmp_dir.close().unwrap();
 }
+
+#[test]
+fn test_read_element_i8() {
+    let (tmp_dir, input_data_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+
+    let mut file_reader = FileReader::open(input_data_file_path).unwrap();
+    assert_eq!(vec!["time", "latitude", "longitude"],   file_reader.data_set().get_var(TEMP_I8_VAR_NAME).unwrap().dim_names());
+
+    // Read each element of the variable
+    for t in 0..2 {
+        for la in 0..3 {
+            for lo in 0..5 {
+                assert_eq!(Ok(TEMP_I8_VAR_DATA[t * 15 + la * 5 + lo]),  file_reader.read_element_i8(TEMP_I8_VAR_NAME, &[t, la, lo]));
+            }
+        }
+    }
+
+    // Index out of bounds
+    assert_eq!(
+        ReadError::ElementIndexNotValid{var_name: String::from(TEMP_I8_VAR_NAME), index: vec![2, 0, 0], shape: vec![2, 3, 5]},
+        file_reader.read_element_i8(TEMP_I8_VAR_NAME, &[2, 0, 0]).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::ElementIndexNotValid{var_name: String::from(TEMP_I8_VAR_NAME), index: vec![0, 0, 5], shape: vec![2, 3, 5]},
+        file_reader.read_element_i8(TEMP_I8_VAR_NAME, &[0, 0, 5]).unwrap_err()
+    );
+    // Wrong number of dimensions
+    assert_eq!(
+        ReadError::ElementIndexNotValid{var_name: String::from(TEMP_I8_VAR_NAME), index: vec![0, 0], shape: vec![2, 3, 5]},
+        file_reader.read_element_i8(TEMP_I8_VAR_NAME, &[0, 0]).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::VariableMismatchDataType{var_name: String::from(TEMP_U8_VAR_NAME), req: DataType::U8, get: DataType::I8},
+        file_reader.read_element_i8(TEMP_U8_VAR_NAME, &[0, 0, 0]).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        file_reader.read_element_i8("undef_var", &[0, 0, 0]).unwrap_err()
+    );
+
+    let _ = file_reader.close();
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_read_element_all_types() {
+    let (tmp_dir, input_data_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+
+    let mut file_reader = FileReader::open(input_data_file_path).unwrap();
+
+    // Element at `time = 1`, `latitude = 2` and `longitude = 3`
+    const INDEX: [usize; 3] = [1, 2, 3];
+    const POS: usize = 15 + 2 * 5 + 3;
+    assert_eq!(Ok(TEMP_I8_VAR_DATA[POS]),       file_reader.read_element_i8(TEMP_I8_VAR_NAME, &INDEX));
+    assert_eq!(Ok(TEMP_U8_VAR_DATA[POS]),       file_reader.read_element_u8(TEMP_U8_VAR_NAME, &INDEX));
+    assert_eq!(Ok(TEMP_I16_VAR_DATA[POS]),      file_reader.read_element_i16(TEMP_I16_VAR_NAME, &INDEX));
+    assert_eq!(Ok(TEMP_I32_VAR_DATA[POS]),      file_reader.read_element_i32(TEMP_I32_VAR_NAME, &INDEX));
+    assert_eq!(Ok(TEMP_F32_VAR_DATA[POS]),      file_reader.read_element_f32(TEMP_F32_VAR_NAME, &INDEX));
+    assert_eq!(Ok(TEMP_F64_VAR_DATA[POS]),      file_reader.read_element_f64(TEMP_F64_VAR_NAME, &INDEX));
+
+    // *Fixed-size* variables
+    assert_eq!(Ok(0.5),                         file_reader.read_element_f32("latitude", &[1]));
+    assert_eq!(Ok(2.0),                         file_reader.read_element_f32("longitude", &[4]));
+    // *Record* variable with only the *unlimited-size* dimension
+    assert_eq!(Ok(438_324.0),                   file_reader.read_element_f32("time", &[1]));
+
+    let _ = file_reader.close();
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_read_element_scalar_vars() {
+    let (tmp_dir, input_data_file_path) = copy_bytes_to_tmp_file(SCALAR_VARIABLES_FILE_BYTES, SCALAR_VARIABLES_FILE_NAME);
+
+    let mut file_reader = FileReader::open(input_data_file_path).unwrap();
+
+    assert_eq!(Ok(42),                          file_reader.read_element_i8("scalar_value_i8", &[]));
+    assert_eq!(Ok(42),                          file_reader.read_element_u8("scalar_value_u8", &[]));
+    assert_eq!(Ok(42),                          file_reader.read_element_i16("scalar_value_i16", &[]));
+    assert_eq!(Ok(42),                          file_reader.read_element_i32("scalar_value_i32", &[]));
+    assert_eq!(Ok(42.0),                        file_reader.read_element_f32("scalar_value_f32", &[]));
+    assert_eq!(Ok(42.0),                        file_reader.read_element_f64("scalar_value_f64", &[]));
+
+    assert_eq!(
+        ReadError::ElementIndexNotValid{var_name: String::from("scalar_value_i8"), index: vec![0], shape: vec![]},
+        file_reader.read_element_i8("scalar_value_i8", &[0]).unwrap_err()
+    );
+
+    let _ = file_reader.close();
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_read_var_slice() {
+    let (tmp_dir, input_data_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+
+    let mut file_reader = FileReader::open(input_data_file_path).unwrap();
+
+    // The whole variables
+    assert_eq!(Ok(DataVector::I8(TEMP_I8_VAR_DATA.to_vec())),       file_reader.read_var_slice(TEMP_I8_VAR_NAME, &[0, 0, 0], &[2, 3, 5]));
+    assert_eq!(Ok(DataVector::U8(TEMP_U8_VAR_DATA.to_vec())),       file_reader.read_var_slice(TEMP_U8_VAR_NAME, &[0, 0, 0], &[2, 3, 5]));
+    assert_eq!(Ok(DataVector::I16(TEMP_I16_VAR_DATA.to_vec())),     file_reader.read_var_slice(TEMP_I16_VAR_NAME, &[0, 0, 0], &[2, 3, 5]));
+    assert_eq!(Ok(DataVector::I32(TEMP_I32_VAR_DATA.to_vec())),     file_reader.read_var_slice(TEMP_I32_VAR_NAME, &[0, 0, 0], &[2, 3, 5]));
+    assert_eq!(Ok(DataVector::F32(TEMP_F32_VAR_DATA.to_vec())),     file_reader.read_var_slice(TEMP_F32_VAR_NAME, &[0, 0, 0], &[2, 3, 5]));
+    assert_eq!(Ok(DataVector::F64(TEMP_F64_VAR_DATA.to_vec())),     file_reader.read_var_slice(TEMP_F64_VAR_NAME, &[0, 0, 0], &[2, 3, 5]));
+
+    // Sub-grids
+    assert_eq!(Ok(DataVector::I8(vec![8, 9, 13, 14, 23, 24, 28, 29])),      file_reader.read_var_slice(TEMP_I8_VAR_NAME, &[0, 1, 3], &[2, 2, 2]));
+    assert_eq!(Ok(DataVector::F64(vec![20., 21., 22., 23., 24.])),          file_reader.read_var_slice(TEMP_F64_VAR_NAME, &[1, 1, 0], &[1, 1, 5]));
+    assert_eq!(Ok(DataVector::I32(vec![2, 7, 12, 17, 22, 27])),             file_reader.read_var_slice(TEMP_I32_VAR_NAME, &[0, 0, 2], &[2, 3, 1]));
+    assert_eq!(Ok(DataVector::F32(vec![])),                                 file_reader.read_var_slice(TEMP_F32_VAR_NAME, &[0, 0, 0], &[0, 3, 5]));
+    // *Fixed-size* variable
+    assert_eq!(Ok(DataVector::F32(vec![0.5, 1.0, 1.5])),                    file_reader.read_var_slice("longitude", &[1], &[3]));
+
+    // Errors
+    assert_eq!(
+        ReadError::SliceNotValid{var_name: String::from(TEMP_I8_VAR_NAME), start: vec![0, 1, 3], count: vec![2, 2, 3], shape: vec![2, 3, 5]},
+        file_reader.read_var_slice(TEMP_I8_VAR_NAME, &[0, 1, 3], &[2, 2, 3]).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::SliceNotValid{var_name: String::from(TEMP_I8_VAR_NAME), start: vec![0, 0], count: vec![1, 1, 1], shape: vec![2, 3, 5]},
+        file_reader.read_var_slice(TEMP_I8_VAR_NAME, &[0, 0], &[1, 1, 1]).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        file_reader.read_var_slice("undef_var", &[0], &[1]).unwrap_err()
+    );
+
+    let _ = file_reader.close();
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_read_column() {
+    let (tmp_dir, input_data_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+
+    let mut file_reader = FileReader::open(input_data_file_path).unwrap();
+    assert_eq!(Some(2),                                     file_reader.data_set().num_records());
+
+    // Time series at `latitude = 1` and `longitude = 4`
+    assert_eq!(Ok(DataVector::I8(vec![9, 24])),             file_reader.read_column(TEMP_I8_VAR_NAME, &[1, 4]));
+    assert_eq!(Ok(vec![9, 24]),                             file_reader.read_column_i8(TEMP_I8_VAR_NAME, &[1, 4]));
+    assert_eq!(Ok(vec![9, 24]),                             file_reader.read_column_u8(TEMP_U8_VAR_NAME, &[1, 4]));
+    assert_eq!(Ok(vec![9, 24]),                             file_reader.read_column_i16(TEMP_I16_VAR_NAME, &[1, 4]));
+    assert_eq!(Ok(vec![9, 24]),                             file_reader.read_column_i32(TEMP_I32_VAR_NAME, &[1, 4]));
+    assert_eq!(Ok(vec![9.0, 24.0]),                         file_reader.read_column_f32(TEMP_F32_VAR_NAME, &[1, 4]));
+    assert_eq!(Ok(vec![9.0, 24.0]),                         file_reader.read_column_f64(TEMP_F64_VAR_NAME, &[1, 4]));
+    // Record variable defined only on the *unlimited-size* dimension
+    assert_eq!(Ok(vec![438_300.0, 438_324.0]),              file_reader.read_column_f32("time", &[]));
+    // *Fixed-size* variable
+    assert_eq!(Ok(vec![1.0]),                               file_reader.read_column_f32("latitude", &[2]));
+
+    // Errors
+    assert_eq!(
+        ReadError::ElementIndexNotValid{var_name: String::from(TEMP_I8_VAR_NAME), index: vec![3, 0], shape: vec![2, 3, 5]},
+        file_reader.read_column_i8(TEMP_I8_VAR_NAME, &[3, 0]).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::ElementIndexNotValid{var_name: String::from(TEMP_I8_VAR_NAME), index: vec![0, 0, 0], shape: vec![2, 3, 5]},
+        file_reader.read_column_i8(TEMP_I8_VAR_NAME, &[0, 0, 0]).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::VariableMismatchDataType{var_name: String::from(TEMP_U8_VAR_NAME), req: DataType::U8, get: DataType::I8},
+        file_reader.read_column_i8(TEMP_U8_VAR_NAME, &[0, 0]).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        file_reader.read_column("undef_var", &[0, 0]).unwrap_err()
+    );
+
+    let _ = file_reader.close();
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_open_lenient() {
+    let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+
+    // Writes the data set, and modifies the bytes of the file
+    let write_file = |file_name: &str, data_set: &DataSet, modify: &dyn Fn(&mut Vec<u8>)| -> PathBuf {
+        let file_path: PathBuf = tmp_dir.path().join(file_name);
+        let mut file_writer: FileWriter = FileWriter::create_new(&file_path).unwrap();
+        file_writer.set_def(data_set, Version::Classic, 0).unwrap();
+        for var in data_set.get_vars() {
+            let data: Vec<i16> = (0..var.len() as i16).collect();
+            file_writer.write_var_i16(var.name(), &data).unwrap();
+        }
+        file_writer.close().unwrap();
+        let mut bytes: Vec<u8> = std::fs::read(&file_path).unwrap();
+        modify(&mut bytes);
+        std::fs::write(&file_path, &bytes).unwrap();
+        return file_path;
+    };
+
+    // Non-zero padding bytes
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.add_fixed_dim("x_1", 2).unwrap();
+        data_set.add_var_i16("var_1", &["x_1"]).unwrap();
+        // the padding byte of the dimension name
+        let file_path: PathBuf = write_file("non_zero_padding.nc", &data_set, &|bytes: &mut Vec<u8>| bytes[23] = 0xFF);
+
+        let read_err: ReadError = FileReader::open(&file_path).unwrap_err();
+        match read_err {
+            ReadError::ParseHeader(parse_header_err) => assert_eq!(ParseHeaderErrorKind::ZeroPadding, parse_header_err.kind),
+            _ => panic!("Unexpected error: {:?}", read_err),
+        }
+
+        let mut file_reader: FileReader = FileReader::open_lenient(&file_path).unwrap();
+        assert_eq!(&[ReadWarning::NonZeroPadding(vec![0xFF])],  file_reader.warnings());
+        assert_eq!(&data_set,                                   file_reader.data_set());
+        assert_eq!(Ok(vec![0, 1]),                              file_reader.read_var_i16("var_1"));
+    }
+
+    // Trailing bytes after the data section
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.set_unlimited_dim("time", 2).unwrap();
+        data_set.add_var_i16("var_1", &["time"]).unwrap();
+        // the number of records is indeterminate and a byte is appended
+        let file_path: PathBuf = write_file("trailing_bytes.nc", &data_set, &|bytes: &mut Vec<u8>| {
+            bytes[4..8].copy_from_slice(&std::u32::MAX.to_be_bytes());
+            bytes.push(0xFF);
+        });
+
+        assert_eq!(ReadError::ComputationNumberOfRecords,       FileReader::open(&file_path).unwrap_err());
+
+        let mut file_reader: FileReader = FileReader::open_lenient(&file_path).unwrap();
+        assert_eq!(&[ReadWarning::TrailingBytes(1)],            file_reader.warnings());
+        assert_eq!(&data_set,                                   file_reader.data_set());
+        assert_eq!(Ok(vec![0, 1]),                              file_reader.read_var_i16("var_1"));
+    }
+
+    // Less attributes than announced
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.add_global_attr_u8("title", b"abc".to_vec()).unwrap();
+        // the number of global attributes
+        let file_path: PathBuf = write_file("attr_count_mismatch.nc", &data_set, &|bytes: &mut Vec<u8>| {
+            bytes[20..24].copy_from_slice(&2_i32.to_be_bytes());
+        });
+
+        assert_eq!(true,                                        FileReader::open(&file_path).is_err());
+
+        let file_reader: FileReader = FileReader::open_lenient(&file_path).unwrap();
+        assert_eq!(
+            &[ReadWarning::AttributeCountMismatch{var_name: None, expected: 2, found: 1}],
+            file_reader.warnings()
+        );
+        assert_eq!(&data_set,                                   file_reader.data_set());
+    }
+
+    // No warning for a valid file
+    {
+        let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+        let file_reader: FileReader = FileReader::open_lenient(input_file_path).unwrap();
+        assert_eq!(&[] as &[ReadWarning],                       file_reader.warnings());
+        tmp_dir.close().unwrap();
+    }
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_open_hdf5_file() {
+    let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    let file_path: PathBuf = tmp_dir.path().join("netcdf4.nc");
+    // The signature of the HDF5 files followed by some bytes
+    let mut bytes: Vec<u8> = b"\x89HDF\r\n\x1a\n".to_vec();
+    bytes.extend_from_slice(&[0_u8; 32]);
+    std::fs::write(&file_path, &bytes).unwrap();
+
+    assert_eq!(ReadError::Hdf5FormatNotSupported,           FileReader::open(&file_path).unwrap_err());
+    assert_eq!(ReadError::Hdf5FormatNotSupported,           FileReader::open_lenient(&file_path).unwrap_err());
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_read_var_strings() {
+    let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    let file_path: PathBuf = tmp_dir.path().join("strings.nc");
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.set_unlimited_dim("time", 2).unwrap();
+        data_set.add_fixed_dim("station", 2).unwrap();
+        data_set.add_fixed_dim("name_len", 4).unwrap();
+        data_set.add_var_u8("station_name", &["station", "name_len"]).unwrap();
+        data_set.add_var_u8("flag", &["time", "station", "name_len"]).unwrap();
+        data_set.add_var_u8("scalar_char", &[] as &[&str]).unwrap();
+        data_set.add_var_i8("station_id", &["station"]).unwrap();
+        let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        file_writer.write_var_u8("station_name", b"ABCDE\0\0\0").unwrap();
+        file_writer.write_var_u8("flag", b"ok  ko\0 \0\0\0\0 x y").unwrap();
+        file_writer.write_var_u8("scalar_char", b"z").unwrap();
+        file_writer.write_var_i8("station_id", &[1, 2]).unwrap();
+        file_writer.close().unwrap();
+    }
+    let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    assert_eq!(vec!["ABCD", "E"],                           file_reader.read_var_strings("station_name").unwrap());
+    // The inner spaces are kept
+    assert_eq!(vec!["ok", "ko", "", " x y"],                file_reader.read_var_strings("flag").unwrap());
+    assert_eq!(vec!["z"],                                   file_reader.read_var_strings("scalar_char").unwrap());
+
+    // Trimming options
+    let only_nuls = StringOptions{trim_nuls: true, trim_spaces: false, encoding: TextEncoding::Utf8};
+    assert_eq!(vec!["ok  ", "ko\0 ", "", " x y"],            file_reader.read_var_strings_with("flag", &only_nuls).unwrap());
+    let only_spaces = StringOptions{trim_nuls: false, trim_spaces: true, encoding: TextEncoding::Utf8};
+    assert_eq!(vec!["ok", "ko\0", "\0\0\0\0", " x y"],         file_reader.read_var_strings_with("flag", &only_spaces).unwrap());
+    let no_trim = StringOptions{trim_nuls: false, trim_spaces: false, encoding: TextEncoding::Utf8};
+    assert_eq!(vec!["ABCD", "E\0\0\0"],                       file_reader.read_var_strings_with("station_name", &no_trim).unwrap());
+
+    // Errors
+    assert_eq!(
+        ReadError::VariableMismatchDataType{var_name: String::from("station_id"), req: DataType::I8, get: DataType::U8},
+        file_reader.read_var_strings("station_id").unwrap_err()
+    );
+    assert_eq!(
+        ReadError::VariableMismatchDataType{var_name: String::from("station_id"), req: DataType::I8, get: DataType::U8},
+        file_reader.read_var_strings_with("station_id", &no_trim).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        file_reader.read_var_strings("undef_var").unwrap_err()
+    );
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_read_var_masked() {
+    let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    let file_path: PathBuf = tmp_dir.path().join("masked.nc");
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.set_unlimited_dim("time", 2).unwrap();
+        data_set.add_fixed_dim("latitude", 2).unwrap();
+        data_set.add_var_f64("sst", &["time", "latitude"]).unwrap();
+        data_set.add_var_attr_f64("sst", "_FillValue", vec![std::f64::NAN]).unwrap();
+        data_set.add_var_u64("count", &["latitude"]).unwrap();
+        // Not a single value, the default fill value is used
+        data_set.add_var_attr_u64("count", "_FillValue", vec![0, 1]).unwrap();
+        let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Cdf5, 0).unwrap();
+        file_writer.write_var_f64("sst", &[1.5, std::f64::NAN, std::f64::NAN, 2.5]).unwrap();
+        file_writer.write_var_u64("count", &[0, NC_FILL_U64]).unwrap();
+        file_writer.close().unwrap();
+    }
+    let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    assert_eq!(vec![Some(1.5), None, None, Some(2.5)],      file_reader.read_var_masked::<f64>("sst").unwrap());
+    assert_eq!(vec![None, Some(2.5)],                       file_reader.read_record_masked::<f64>("sst", 1).unwrap());
+    assert_eq!(vec![Some(0), None],                         file_reader.read_var_masked::<u64>("count").unwrap());
+
+    // Errors
+    assert_eq!(
+        ReadError::VariableMismatchDataType{var_name: String::from("sst"), req: DataType::F64, get: DataType::F32},
+        file_reader.read_var_masked::<f32>("sst").unwrap_err()
+    );
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        file_reader.read_record_masked::<f64>("undef_var", 0).unwrap_err()
+    );
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_read_var_as_f64() {
+    let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    let file_path: PathBuf = tmp_dir.path().join("as_f64.nc");
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.set_unlimited_dim("time", 2).unwrap();
+        data_set.add_fixed_dim("latitude", 2).unwrap();
+        data_set.add_var_i8("flag", &["time", "latitude"]).unwrap();
+        data_set.add_var_u32("count", &["latitude"]).unwrap();
+        data_set.add_var_f32("sst", &["time", "latitude"]).unwrap();
+        let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Cdf5, 0).unwrap();
+        file_writer.write_var_i8("flag", &[-1, 0, 1, NC_FILL_I8]).unwrap();
+        file_writer.write_var_u32("count", &[0, 4_000_000_000]).unwrap();
+        file_writer.write_var_f32("sst", &[1.5, 2.5, 3.5, std::f32::NAN]).unwrap();
+        file_writer.close().unwrap();
+    }
+    let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    // The fill values are kept
+    assert_eq!(vec![-1.0, 0.0, 1.0, -127.0],                file_reader.read_var_as_f64("flag").unwrap());
+    assert_eq!(vec![0.0, 4_000_000_000.0],                  file_reader.read_var_as_f64("count").unwrap());
+    assert_eq!(vec![1.0, -127.0],                           file_reader.read_record_as_f64("flag", 1).unwrap());
+    assert_eq!(vec![1.5, 2.5],                              file_reader.read_record_as_f64("sst", 0).unwrap());
+    let sst: Vec<f64> = file_reader.read_record_as_f64("sst", 1).unwrap();
+    assert_eq!(3.5,                                         sst[0]);
+    assert_eq!(true,                                        sst[1].is_nan());
+
+    // Errors
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        file_reader.read_var_as_f64("undef_var").unwrap_err()
+    );
+    assert_eq!(
+        ReadError::RecordIndexExceeded{index: 2, num_records: 2},
+        file_reader.read_record_as_f64("sst", 2).unwrap_err()
+    );
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_open_with_encoding() {
+    let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    let file_path: PathBuf = tmp_dir.path().join("latin1.nc");
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.add_fixed_dim("latitude", 2).unwrap();
+        data_set.add_var_f32("temperature", &["latitude"]).unwrap();
+        data_set.add_var_attr_u8("temperature", "comment", b"mesure a 2 metres".to_vec()).unwrap();
+        let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        file_writer.write_var_f32("temperature", &[1.0, 2.0]).unwrap();
+        file_writer.close().unwrap();
+    }
+    // Replace the 'e' and the 'a' by the Latin-1 bytes of 'é' and 'à'
+    {
+        let mut bytes: Vec<u8> = std::fs::read(&file_path).unwrap();
+        let var_name_pos: usize = bytes.windows(11).position(|window: &[u8]| window == b"temperature").unwrap();
+        bytes[var_name_pos + 10] = b'\xe9';
+        let comment_pos: usize = bytes.windows(17).position(|window: &[u8]| window == b"mesure a 2 metres").unwrap();
+        bytes[comment_pos + 1] = b'\xe9';
+        bytes[comment_pos + 7] = b'\xe0';
+        std::fs::write(&file_path, &bytes).unwrap();
+    }
+
+    // Strict UTF-8 by default
+    match FileReader::open(&file_path).unwrap_err() {
+        ReadError::ParseHeader(parse_header_err) => assert_eq!(ParseHeaderErrorKind::Utf8, parse_header_err.kind),
+        err => panic!("Unexpected error: {:?}", err),
+    }
+
+    let mut file_reader: FileReader = FileReader::open_with_encoding(&file_path, TextEncoding::Utf8OrLatin1).unwrap();
+    assert_eq!(vec![1.0_f32, 2.0],                      file_reader.read_var_f32("temperatur\u{e9}").unwrap());
+    let attr: &Attribute = file_reader.data_set().get_var_attr("temperatur\u{e9}", "comment").unwrap();
+    assert_eq!(None,                                    attr.get_as_string());
+    assert_eq!(Some(String::from("m\u{e9}sure \u{e0} 2 metres")), attr.get_as_string_with_encoding(TextEncoding::Utf8OrLatin1));
+
+    let file_reader: FileReader = FileReader::open_with_encoding(&file_path, TextEncoding::Utf8Lossy).unwrap();
+    assert_eq!(true,                                    file_reader.data_set().has_var("temperatur\u{fffd}"));
+    tmp_dir.close().unwrap();
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn test_file_reader_from_shared_bytes() {
+    let body: bytes::Bytes = bytes::Bytes::from(NC3_CLASSIC_FILE_BYTES.to_vec());
+    let mut file_reader: FileReader = FileReader::from_shared_bytes(body.clone()).unwrap();
+    let mut ref_file_reader: FileReader = FileReader::from_bytes(NC3_CLASSIC_FILE_BYTES.to_vec()).unwrap();
+    assert_eq!(ref_file_reader.data_set(),              file_reader.data_set());
+    assert_eq!(PathBuf::new(),                          file_reader.file_path());
+
+    // The clones share the buffer, and read it independently
+    let mut other_reader: FileReader = file_reader.try_clone().unwrap();
+    assert_eq!(TEMP_I8_VAR_DATA.to_vec(),               file_reader.read_var_i8(TEMP_I8_VAR_NAME).unwrap());
+    assert_eq!(ref_file_reader.read_all_vars().unwrap(), other_reader.read_all_vars().unwrap());
+    assert_eq!(ref_file_reader.read_all_vars().unwrap(), file_reader.read_all_vars().unwrap());
+    drop(file_reader);
+    drop(other_reader);
+    assert_eq!(true,                                    body.is_unique());
+
+    // Truncated buffer
+    let truncated: bytes::Bytes = body.slice(0..100);
+    assert_eq!(true,                                    FileReader::from_shared_bytes(truncated).is_err());
+}
+
+#[test]
+fn test_read_record_vars_coalesced() {
+    // Single *record* variables : adjacent records, records separated by padding bytes, and records larger than the read buffer
+    let num_records: usize = 1_000;
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", num_records).unwrap();
+    data_set.add_fixed_dim("x", 4_999).unwrap();
+    data_set.add_fixed_dim("y", 3).unwrap();
+    let test_cases: Vec<(&str, DataVector)> = vec![
+        ("f64_time_y", DataVector::F64((0..(num_records * 3)).map(|i: usize| i as f64).collect())),
+        ("i16_time", DataVector::I16((0..num_records).map(|i: usize| i as i16).collect())),
+        ("u8_time_x", DataVector::U8((0..(num_records * 4_999)).map(|i: usize| (i % 251) as u8).collect())),
+    ];
+    for (var_name, data) in test_cases.iter() {
+        let mut data_set: DataSet = data_set.clone();
+        let dim_names: Vec<&str> = match *var_name {
+            "f64_time_y" => vec!["time", "y"],
+            "i16_time" => vec!["time"],
+            _ => vec!["time", "x"],
+        };
+        data_set.add_var(var_name, &dim_names, data.data_type()).unwrap();
+        let mut file_writer: FileWriter = FileWriter::in_memory();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        let shape: Vec<usize> = data_set.get_var(var_name).unwrap().shape();
+        file_writer.write_var_slice(var_name, &vec![0; shape.len()], &shape, data).unwrap();
+        let mut file_reader: FileReader = FileReader::from_bytes(file_writer.close_into_bytes().unwrap()).unwrap();
+
+        assert_eq!(data,                                &file_reader.read_var(var_name).unwrap());
+        let mut start: Vec<usize> = vec![0; shape.len()];
+        let mut count: Vec<usize> = shape.clone();
+        start[0] = 10;
+        count[0] = 900;
+        let row_len: usize = shape[1..].iter().product();
+        let slice: DataVector = file_reader.read_var_slice(var_name, &start, &count).unwrap();
+        assert_eq!(
+            data.iter_as_f64().skip(10 * row_len).take(900 * row_len).collect::<Vec<f64>>(),
+            slice.iter_as_f64().collect::<Vec<f64>>()
+        );
+    }
+
+    // Several *record* variables
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 5).unwrap();
+    data_set.add_fixed_dim("y", 3).unwrap();
+    data_set.add_var_i8("a", &["time", "y"]).unwrap();
+    data_set.add_var_i32("b", &["time"]).unwrap();
+    data_set.add_var_f32("fixed", &["y"]).unwrap();
+    let mut file_writer: FileWriter = FileWriter::in_memory();
+    file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    file_writer.write_var_i8("a", &(0..15).collect::<Vec<i8>>()).unwrap();
+    file_writer.write_var_i32("b", &[-1, -2, -3, -4, -5]).unwrap();
+    file_writer.write_var_f32("fixed", &[0.5, 1.5, 2.5]).unwrap();
+    let mut file_reader: FileReader = FileReader::from_bytes(file_writer.close_into_bytes().unwrap()).unwrap();
+    assert_eq!((0..15).collect::<Vec<i8>>(),            file_reader.read_var_i8("a").unwrap());
+    assert_eq!(vec![-1, -2, -3, -4, -5],                file_reader.read_var_i32("b").unwrap());
+    assert_eq!(vec![0.5, 1.5, 2.5],                     file_reader.read_var_f32("fixed").unwrap());
+    assert_eq!(DataVector::I8(vec![4, 5, 7, 8, 10, 11]), file_reader.read_var_slice("a", &[1, 1], &[3, 2]).unwrap());
+    assert_eq!(DataVector::I32(vec![-2, -3]),           file_reader.read_var_slice("b", &[1], &[2]).unwrap());
+}
+
+#[test]
+fn test_open_preloaded() {
+    let (tmp_dir, input_file_path): (TempDir, PathBuf) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    let mut file_reader: FileReader = FileReader::open_preloaded(&input_file_path).unwrap();
+    let mut ref_file_reader: FileReader = FileReader::open(&input_file_path).unwrap();
+    assert_eq!(ref_file_reader.data_set(),              file_reader.data_set());
+    assert_eq!(input_file_path,                         file_reader.file_path());
+    std::fs::remove_file(&input_file_path).unwrap();
+    for _ in 0..2 {
+        assert_eq!(ref_file_reader.read_all_vars().unwrap(), file_reader.read_all_vars().unwrap());
+    }
+    assert_eq!(TEMP_F64_VAR_DATA.to_vec(),              file_reader.read_var_f64(TEMP_F64_VAR_NAME).unwrap());
+
+    // Errors
+    assert_eq!(
+        ReadError::IOErrorKind(std::io::ErrorKind::NotFound),
+        FileReader::open_preloaded(&input_file_path).unwrap_err()
+    );
+    std::fs::write(&input_file_path, &NC3_CLASSIC_FILE_BYTES[0..100]).unwrap();
+    assert_eq!(true,                                    FileReader::open_preloaded(&input_file_path).is_err());
+    tmp_dir.close().unwrap();
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_read_vars_parallel() {
+    let (tmp_dir, input_file_path): (TempDir, PathBuf) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    let mut ref_file_reader: FileReader = FileReader::open(&input_file_path).unwrap();
+    let ref_data: std::collections::HashMap<String, DataVector> = ref_file_reader.read_all_vars().unwrap();
+    let var_names: Vec<String> = ref_file_reader.data_set().get_var_names();
+
+    let file_readers: Vec<FileReader> = vec![
+        FileReader::open(&input_file_path).unwrap(),
+        FileReader::from_bytes(NC3_CLASSIC_FILE_BYTES.to_vec()).unwrap(),
+    ];
+    for file_reader in file_readers.iter() {
+        assert_eq!(ref_data,                            file_reader.read_vars_parallel(&var_names).unwrap());
+        let data: std::collections::HashMap<String, DataVector> = file_reader.read_vars_parallel(&[TEMP_I16_VAR_NAME]).unwrap();
+        assert_eq!(Some(&TEMP_I16_VAR_DATA[..]),        data[TEMP_I16_VAR_NAME].get_i16());
+        assert_eq!(
+            ReadError::VariableNotDefined(String::from("undef_var")),
+            file_reader.read_vars_parallel(&[TEMP_I16_VAR_NAME, "undef_var"]).unwrap_err()
+        );
+    }
+
+    // Variables larger than the read buffer
+    let num_values: usize = 1_500_000;
+    let mut data_set: DataSet = DataSet::new();
+    data_set.add_fixed_dim("x", num_values).unwrap();
+    data_set.set_unlimited_dim("time", num_values).unwrap();
+    data_set.add_var_f64("fixed", &["x"]).unwrap();
+    data_set.add_var_i32("series", &["time"]).unwrap();
+    let mut file_writer: FileWriter = FileWriter::in_memory();
+    file_writer.set_def(&data_set, Version::Cdf5, 0).unwrap();
+    file_writer.write_var_f64("fixed", &(0..num_values).map(|i: usize| i as f64 * 0.5).collect::<Vec<f64>>()).unwrap();
+    file_writer.write_var_i32("series", &(0..num_values).map(|i: usize| -(i as i32)).collect::<Vec<i32>>()).unwrap();
+    let mut file_reader: FileReader = FileReader::from_bytes(file_writer.close_into_bytes().unwrap()).unwrap();
+    let data: std::collections::HashMap<String, DataVector> = file_reader.read_vars_parallel(&["fixed", "series"]).unwrap();
+    assert_eq!(file_reader.read_var("fixed").unwrap(),  data["fixed"]);
+    assert_eq!(file_reader.read_var("series").unwrap(), data["series"]);
+
+    // Truncated data
+    let file_reader: FileReader = FileReader::open_lenient(&input_file_path).unwrap();
+    std::fs::write(&input_file_path, &NC3_CLASSIC_FILE_BYTES[..(NC3_CLASSIC_FILE_BYTES.len() - 10)]).unwrap();
+    assert_eq!(
+        ReadError::IOErrorKind(std::io::ErrorKind::UnexpectedEof),
+        file_reader.read_vars_parallel(&var_names).unwrap_err()
+    );
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_read_var_memory_limit() {
+    // Header declaring a huge dimension, without the data
+    let mut data_set: DataSet = DataSet::new();
+    data_set.add_fixed_dim("x", 4).unwrap();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_var_f64("fixed", &["x"]).unwrap();
+    data_set.add_var_i16("series", &["time", "x"]).unwrap();
+    let mut file_writer: FileWriter = FileWriter::in_memory();
+    file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    file_writer.write_var_f64("fixed", &[1.0, 2.0, 3.0, 4.0]).unwrap();
+    file_writer.write_var_i16("series", &[1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+    let bytes: Vec<u8> = file_writer.close_into_bytes().unwrap();
+    // magic bytes, number of records, dimension tag, number of dimensions, name length, name "x" and its padding
+    const DIM_SIZE_POS: usize = 4 + 4 + 4 + 4 + 4 + 4;
+    assert_eq!(&[0, 0, 0, 4],                           &bytes[DIM_SIZE_POS..(DIM_SIZE_POS + 4)]);
+
+    let mut file_reader: FileReader = FileReader::from_bytes(bytes.clone()).unwrap();
+    file_reader.set_memory_limit(Some(40));
+    assert_eq!(vec![1.0, 2.0, 3.0, 4.0],                file_reader.read_var_f64("fixed").unwrap());
+    assert_eq!(vec![1, 2, 3, 4, 5, 6, 7, 8],            file_reader.read_var_i16("series").unwrap());
+    assert_eq!(
+        ReadError::MemoryLimitExceeded{var_name: String::from("fixed"), req: 32, limit: 16},
+        file_reader.read_var_with_limit("fixed", 16).unwrap_err()
+    );
+    file_reader.set_memory_limit(Some(8));
+    assert_eq!(
+        ReadError::MemoryLimitExceeded{var_name: String::from("series"), req: 16, limit: 8},
+        file_reader.read_var("series").unwrap_err()
+    );
+    assert_eq!(
+        ReadError::MemoryLimitExceeded{var_name: String::from("series"), req: 12, limit: 8},
+        file_reader.read_var_slice("series", &[0, 1], &[2, 3]).unwrap_err()
+    );
+    assert_eq!(vec![1, 2, 3, 4],                        file_reader.read_record_i16("series", 0).unwrap());
+    assert_eq!(DataVector::F64(vec![1.0, 2.0, 3.0, 4.0]), file_reader.read_var_with_limit("fixed", 32).unwrap());
+
+    // Crafted dimension size
+    let mut crafted_bytes: Vec<u8> = bytes.clone();
+    crafted_bytes[DIM_SIZE_POS..(DIM_SIZE_POS + 4)].copy_from_slice(&[0x7f, 0xff, 0xff, 0xf0]);
+    let mut file_reader: FileReader = FileReader::from_bytes(crafted_bytes).unwrap();
+    assert_eq!(
+        ReadError::IOErrorKind(std::io::ErrorKind::UnexpectedEof),
+        file_reader.read_var("fixed").unwrap_err()
+    );
+    assert_eq!(
+        ReadError::IOErrorKind(std::io::ErrorKind::UnexpectedEof),
+        file_reader.read_record("series", 1).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::IOErrorKind(std::io::ErrorKind::UnexpectedEof),
+        file_reader.read_var_slice("series", &[1, 1_000_000], &[1, 2]).unwrap_err()
+    );
+    file_reader.set_memory_limit(Some(1_000_000));
+    assert_eq!(
+        ReadError::MemoryLimitExceeded{var_name: String::from("fixed"), req: 0x7fff_fff0 * 8, limit: 1_000_000},
+        file_reader.read_var("fixed").unwrap_err()
+    );
+}
+
+#[test]
+fn test_open_with_lazy_attrs() {
+    let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    let file_path: PathBuf = tmp_dir.path().join("large_attrs.nc");
+    let colormap: Vec<f64> = (0..30_000).map(|i: usize| i as f64 / 30_000.0).collect();
+    let json: String = format!("{{\"values\": [{}]}}", vec!["0.5"; 100_000].join(", "));
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.add_global_attr_string("title", "large attributes").unwrap();
+        data_set.add_global_attr_string("json", &json).unwrap();
+        data_set.add_global_attr_i32("version", vec![1]).unwrap();
+        data_set.set_unlimited_dim("time", 2).unwrap();
+        data_set.add_var_i16("sst", &["time"]).unwrap();
+        data_set.add_var_attr_f64("sst", "colormap", colormap.clone()).unwrap();
+        data_set.add_var_attr_string("sst", "units", "K").unwrap();
+        let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        file_writer.write_var_i16("sst", &[280, 281]).unwrap();
+        file_writer.close().unwrap();
+    }
+    let ref_data_set: DataSet = FileReader::open(&file_path).unwrap().close().0;
+
+    let mut file_reader: FileReader = FileReader::open_with_lazy_attrs(&file_path, 1_024).unwrap();
+    assert_eq!(vec!["json"],                                file_reader.lazy_global_attr_names());
+    assert_eq!(vec!["colormap"],                            file_reader.lazy_var_attr_names("sst"));
+    assert_eq!(true,                                        file_reader.lazy_var_attr_names("undef_var").is_empty());
+    assert_eq!(vec!["title", "version"],                    file_reader.data_set().get_global_attr_names());
+    assert_eq!(None,                                        file_reader.data_set().get_var_attr("sst", "colormap"));
+    assert_eq!(Some(String::from("K")),                     file_reader.data_set().get_var_attr_as_string("sst", "units"));
+    assert_eq!(vec![280, 281],                              file_reader.read_var_i16("sst").unwrap());
+
+    // The lazy attributes are decoded on first access, and inserted at their position in the header
+    assert_eq!(Some(json.clone()),                          file_reader.read_global_attr("json").unwrap().get_as_string());
+    assert_eq!(Some(&colormap[..]),                         file_reader.read_var_attr("sst", "colormap").unwrap().get_f64());
+    assert_eq!(Some(String::from("K")),                     file_reader.read_var_attr("sst", "units").unwrap().get_as_string());
+    assert_eq!(Some(&[1][..]),                              file_reader.read_global_attr("version").unwrap().get_i32());
+    assert_eq!(true,                                        file_reader.lazy_global_attr_names().is_empty());
+    assert_eq!(true,                                        file_reader.lazy_var_attr_names("sst").is_empty());
+    assert_eq!(vec!["title", "json", "version"],            file_reader.data_set().get_global_attr_names());
+    assert_eq!(ref_data_set,                                *file_reader.data_set());
+
+    // Errors
+    assert_eq!(
+        ReadError::DataSet(InvalidDataSet::GlobalAttributeNotDefined(String::from("undef_attr"))),
+        file_reader.read_global_attr("undef_attr").unwrap_err()
+    );
+    assert_eq!(
+        ReadError::DataSet(InvalidDataSet::VariableAttributeNotDefined{var_name: String::from("sst"), attr_name: String::from("undef_attr")}),
+        file_reader.read_var_attr("sst", "undef_attr").unwrap_err()
+    );
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        file_reader.read_var_attr("undef_var", "units").unwrap_err()
+    );
+
+    // The remaining lazy attributes are decoded at the closing
+    let file_reader: FileReader = FileReader::open_with_lazy_attrs(&file_path, 1_024).unwrap();
+    assert_eq!(ref_data_set,                                file_reader.close().0);
+    let mut file_reader: FileReader = FileReader::open_with_lazy_attrs(&file_path, 1_024).unwrap();
+    assert_eq!(Ok(()),                                      file_reader.decode_lazy_attrs());
+    assert_eq!(true,                                        file_reader.lazy_var_attr_names("sst").is_empty());
+    assert_eq!(ref_data_set,                                *file_reader.data_set());
+
+    // All the attributes are decoded while opening the file by default
+    let file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    assert_eq!(true,                                        file_reader.lazy_global_attr_names().is_empty());
+    assert_eq!(vec!["title", "json", "version"],            file_reader.data_set().get_global_attr_names());
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_open_with_lazy_attrs_masked_and_unpacked() {
+    let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    let file_path: PathBuf = tmp_dir.path().join("lazy_cf_attrs.nc");
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.add_fixed_dim("x", 4).unwrap();
+        data_set.add_var_i16("sst", &["x"]).unwrap();
+        data_set.add_var_attr_string("sst", "long_name", "sea surface temperature").unwrap();
+        data_set.add_var_attr_i16("sst", "_FillValue", vec![-1]).unwrap();
+        data_set.add_var_attr_string("sst", "comment", "large comment").unwrap();
+        data_set.add_var_attr_i16("sst", "valid_range", vec![0, 100]).unwrap();
+        data_set.add_var_attr_f64("sst", "scale_factor", vec![0.5]).unwrap();
+        data_set.add_var_attr_f64("sst", "add_offset", vec![10.0]).unwrap();
+        data_set.add_var_attr_string("sst", "units", "degree_Celsius").unwrap();
+        let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        file_writer.write_var_i16("sst", &[-1, 20, 200, 40]).unwrap();
+        file_writer.close().unwrap();
+    }
+    let ref_data_set: DataSet = FileReader::open(&file_path).unwrap().close().0;
+
+    // All the attributes are larger than the threshold, the attributes of the masked and unpacked reads are decoded anyway
+    let mut file_reader: FileReader = FileReader::open_with_lazy_attrs(&file_path, 0).unwrap();
+    assert_eq!(vec!["long_name", "comment", "units"],       file_reader.lazy_var_attr_names("sst"));
+    file_reader.set_mask_out_of_range(true);
+    assert_eq!(vec![None, Some(20), None, Some(40)],        file_reader.read_var_masked::<i16>("sst").unwrap());
+    let sst: Vec<f64> = file_reader.read_var_unpacked_f64("sst").unwrap();
+    assert_eq!(vec![true, false, true, false],              sst.iter().map(|value: &f64| value.is_nan()).collect::<Vec<bool>>());
+    assert_eq!((20.0, 30.0),                                (sst[1], sst[3]));
+
+    // The decoded attributes keep their position, whatever the order of the accesses
+    file_reader.read_var_attr("sst", "units").unwrap();
+    file_reader.read_var_attr("sst", "long_name").unwrap();
+    assert_eq!(vec!["long_name", "_FillValue", "valid_range", "scale_factor", "add_offset", "units"],  file_reader.data_set().get_var("sst").unwrap().get_attr_names());
+    file_reader.read_var_attr("sst", "comment").unwrap();
+    assert_eq!(ref_data_set,                                *file_reader.data_set());
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_instrumented_reads() {
+    const LATITUDE_VAR_NAME: &str = "latitude";
+    let (tmp_dir, input_data_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+
+    let mut file_reader: FileReader = FileReader::open(&input_data_file_path).unwrap();
+    assert_eq!(false,                                   file_reader.is_instrumented());
+    // Nothing recorded when disabled
+    file_reader.read_var_f32(LATITUDE_VAR_NAME).unwrap();
+    file_reader.read_var_as_f64(TEMP_I16_VAR_NAME).unwrap();
+    assert_eq!(Vec::<(String, VarTimings)>::new(),      file_reader.timings().vars);
+
+    file_reader.set_instrumented(true);
+    assert_eq!(true,                                    file_reader.is_instrumented());
+    file_reader.read_var_f32(LATITUDE_VAR_NAME).unwrap();
+    file_reader.read_var_f32(LATITUDE_VAR_NAME).unwrap();
+    file_reader.read_record(TEMP_F32_VAR_NAME, 0).unwrap();
+    file_reader.read_var_unpacked_f64(TEMP_I16_VAR_NAME).unwrap();
+    // Invalid reads are not recorded
+    assert!(file_reader.read_var_f32("undef_var").is_err());
+
+    let timings: Timings = file_reader.timings();
+    assert_eq!(vec![LATITUDE_VAR_NAME, TEMP_F32_VAR_NAME, TEMP_I16_VAR_NAME], timings.vars.iter().map(|(name, _)| name.as_str()).collect::<Vec<&str>>());
+    let latitude: &VarTimings = timings.var(LATITUDE_VAR_NAME).unwrap();
+    assert_eq!(2,                                       latitude.num_reads);
+    assert_eq!(24,                                      latitude.read_bytes);
+    assert_eq!(Duration::default(),                     latitude.convert);
+    let temp_f32: &VarTimings = timings.var(TEMP_F32_VAR_NAME).unwrap();
+    assert_eq!(1,                                       temp_f32.num_reads);
+    assert_eq!(60,                                      temp_f32.read_bytes);
+    // The nested conversions are counted once, with a single read
+    let temp_i16: &VarTimings = timings.var(TEMP_I16_VAR_NAME).unwrap();
+    assert_eq!(1,                                       temp_i16.num_reads);
+    assert_eq!(60,                                      temp_i16.read_bytes);
+    assert_eq!(0,                                       temp_i16.num_writes);
+
+    file_reader.reset_timings();
+    assert_eq!(Vec::<(String, VarTimings)>::new(),      file_reader.timings().vars);
+    // The duration of the parsing of the header is kept
+    assert_eq!(timings.header_parse,                    file_reader.timings().header_parse);
+    file_reader.close();
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_read_var_u8_ref() {
+    let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    let file_path: PathBuf = tmp_dir.path().join("u8_ref.nc");
+    let text: Vec<u8> = b"abcdefghijkl".to_vec();
+    let bytes: Vec<u8> = {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.set_unlimited_dim("time", 3).unwrap();
+        data_set.add_fixed_dim("name_len", 4).unwrap();
+        data_set.add_var_u8("text", &["time", "name_len"]).unwrap();
+        data_set.add_var_i8("flags", &["name_len"]).unwrap();
+        let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        file_writer.write_var_u8("text", &text).unwrap();
+        file_writer.write_var_i8("flags", &[0, 1, 2, 3]).unwrap();
+        file_writer.close().unwrap();
+        std::fs::read(&file_path).unwrap()
+    };
+
+    let file_reader: FileReader = FileReader::from_bytes(bytes).unwrap();
+    // The only record variable, the records are contiguous (no padding bytes)
+    assert_eq!(&text[..],                               file_reader.read_var_u8_ref("text").unwrap());
+    assert_eq!(
+        ReadError::VariableMismatchDataType{var_name: String::from("flags"), req: DataType::I8, get: DataType::U8},
+        file_reader.read_var_u8_ref("flags").unwrap_err()
+    );
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        file_reader.read_var_u8_ref("undef_var").unwrap_err()
+    );
+
+    // The records of the variables are interleaved
+    let file_reader: FileReader = FileReader::from_bytes(NC3_CLASSIC_FILE_BYTES.to_vec()).unwrap();
+    assert_eq!(
+        ReadError::VariableNotContiguous(String::from(TEMP_U8_VAR_NAME)),
+        file_reader.read_var_u8_ref(TEMP_U8_VAR_NAME).unwrap_err()
+    );
+
+    // Not stored in memory
+    let file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    assert_eq!(ReadError::NotInMemory,                  file_reader.read_var_u8_ref("text").unwrap_err());
+    file_reader.close();
+    tmp_dir.close().unwrap();
+}
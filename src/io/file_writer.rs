@@ -6,16 +6,17 @@ use std::rc::Rc;
 use std::path::{Path, PathBuf};
 use std::convert::TryFrom;
 use std::collections::BTreeSet;
+use std::time::Duration;
 
-use crate::{DataSet, Version, Dimension, Attribute, DataType, Variable};
-use crate::io::Offset;
+use crate::{DataSet, InMemoryDataSet, Version, Dimension, Attribute, DataType, Variable, FileReader, NcType, NcFloat, NC_MAX_DIM_SIZE};
+use crate::io::{Offset, Storage, Stopwatch, Timings, VarTimings};
 use crate::data_set::DimensionSize;
 use crate::data_vector::DataVector;
-use crate::error::WriteError;
+use crate::error::{WriteError, InvalidDataSet, CastError};
 
 use crate::io::{
-    ABSENT_TAG, DIMENSION_TAG, VARIABLE_TAG, ATTRIBUTE_TAG,
-    compute_padding_size,
+    DIMENSION_TAG, VARIABLE_TAG, ATTRIBUTE_TAG,
+    absent_tag, compute_padding_size, encode_be_into, write_be_values, ENCODING_BLOCK_LEN,
 };
 
 use crate::{
@@ -25,6 +26,10 @@ use crate::{
     NC_FILL_I32,
     NC_FILL_F32,
     NC_FILL_F64,
+    NC_FILL_U16,
+    NC_FILL_U32,
+    NC_FILL_I64,
+    NC_FILL_U64,
 };
 
 macro_rules! impl_write_typed_chunk {
@@ -32,14 +37,9 @@ macro_rules! impl_write_typed_chunk {
         /// Write the `$prim_type` slice into the output stream.
         fn $func_name<T: Write>(out_stream: &mut T, slice: &[$prim_type]) -> Result<usize, std::io::Error>
         {
-            // Write the useful bytes
+            // Write the useful bytes, encoded by blocks
             const SIZE_OF: usize = std::mem::size_of::<$prim_type>();
-            let mut bytes: [u8; SIZE_OF];
-            for value in slice.iter() {
-                bytes = value.to_be_bytes();
-                out_stream.write_all(&bytes)?;
-            }
-            let mut num_bytes: usize = slice.len() * std::mem::size_of::<$prim_type>();
+            let mut num_bytes: usize = write_be_values(out_stream, slice)?;
 
             // Write the padding bytes if necessary
             let padding_size: usize = compute_padding_size(num_bytes);
@@ -59,6 +59,7 @@ macro_rules! impl_write_typed_chunk {
 macro_rules! impl_write_typed_var {
     ($func_name:ident, $write_typed_chunk: path, $prim_type:ty, $data_type:path, $data_vector:path) => {
         pub fn $func_name(&mut self, var_name: &str, data: &[$prim_type]) -> Result<(), WriteError> {
+            let stopwatch: Stopwatch = Stopwatch::start(self.instrumented);
             let header_def: &HeaderDefinition = self.header_def.as_ref().ok_or(WriteError::HeaderNotDefined)?;
             let var: &Variable = header_def.data_set.find_var_from_name(var_name).map_err(|_err| WriteError::VariableNotDefined(var_name.to_owned()))?.1;
             if var.data_type != $data_type {
@@ -67,14 +68,16 @@ macro_rules! impl_write_typed_var {
             if var.len() != data.len() {
                 return Err(WriteError::VariableMismatchDataLength{var_name: var_name.to_owned(), req:var.len(), get: data.len()});
             }
+            self.check_valid_range(var, data)?;
             let var_metadata: &ComputedVariableMetadata = header_def.get_var_metadata(var)?;
+            self.output.flush_records()?;
 
             // Write the `$prim_type` data
             let begin_offset: u64 = i64::from(var_metadata.begin_offset.clone()) as u64;
             match header_def.data_set.record_size() {
                 None => {  // fixed-size variable
-                    self.output_file.seek(SeekFrom::Start(begin_offset))?;
-                    let _chunk_size: usize = $write_typed_chunk(&mut self.output_file, data)?;
+                    self.output.storage.seek(SeekFrom::Start(begin_offset))?;
+                    let _chunk_size: usize = $write_typed_chunk(&mut self.output.storage, data)?;
                 },
                 Some(record_size) => {  // record variable
                     let num_chunks: usize = var.num_chunks();
@@ -85,15 +88,15 @@ macro_rules! impl_write_typed_var {
                         let end: usize = (i + 1) * chunk_len;
                         let chunk_slice: &[$prim_type] = &data[start..end];
                         let position: u64 = begin_offset + ((i * record_size) as u64);
-                        self.output_file.seek(SeekFrom::Start(position))?;
-                        let _chunk_size: usize = $write_typed_chunk(&mut self.output_file, chunk_slice)?;
+                        self.output.storage.seek(SeekFrom::Start(position))?;
+                        let _chunk_size: usize = $write_typed_chunk(&mut self.output.storage, chunk_slice)?;
                     }
                 }
             }
 
-            // Save the records already written
-            let num_records: usize = header_def.data_set.num_records().unwrap_or(1);
-            self.written_records.push((var, (0..num_records).collect()));
+            // Save the records already written (the *fixed-size* variables have exactly one chunk)
+            self.written_records.push((var, (0..var.num_chunks()).collect()));
+            self.record_write(var_name, data.len() * std::mem::size_of::<$prim_type>(), stopwatch);
             Ok(())
         }
     };
@@ -102,6 +105,7 @@ macro_rules! impl_write_typed_var {
 macro_rules! impl_write_typed_record {
     ($func_name:ident, $write_typed_chunk: path, $prim_type:ty, $data_type: path)=> {
         pub fn $func_name(&mut self, var_name: &str, record_index: usize, record: &[$prim_type]) -> Result<(), WriteError> {
+            let stopwatch: Stopwatch = Stopwatch::start(self.instrumented);
             // Check that the defintion has been set
             let header_def: &HeaderDefinition = self.header_def.as_ref().ok_or(WriteError::HeaderNotDefined)?;
             // Check that the variable has been defined
@@ -118,31 +122,115 @@ macro_rules! impl_write_typed_record {
             if record.len() != var.chunk_len() {
                 return Err(WriteError::RecordMismatchDataLength{var_name: var.name.clone(), req: var.chunk_len(), get: record.len()});
             }
+            self.check_valid_range(var, record)?;
             let var_metadata: &ComputedVariableMetadata = header_def.get_var_metadata(var)?;
             let record_size: usize = header_def.data_set.record_size().unwrap_or(0);
 
-            // Set the output cursor to the record offset
+            // Append the record to the pending bytes, merged into one write with the records written just before it
             let begin_offset: u64 = i64::from(var_metadata.begin_offset.clone()) as u64 + (record_size * record_index) as u64;
-            self.output_file.seek(SeekFrom::Start(begin_offset))?;
-            let _chunk_size: usize = $write_typed_chunk(&mut self.output_file, record)?;
+            let record_bytes: &mut Vec<u8> = self.output.record_bytes_at(begin_offset)?;
+            let _chunk_size: usize = $write_typed_chunk(record_bytes, record)?;
 
             // Save the written record
             self.update_written_records(var, &[record_index][..])?;
+            // The readers follow the streaming file as it grows
+            if self.streaming {
+                self.output.flush_records()?;
+            }
+            self.record_write(var_name, record.len() * std::mem::size_of::<$prim_type>(), stopwatch);
+            Ok(())
+        }
+    };
+}
+
+macro_rules! impl_write_typed_element {
+    ($func_name:ident, $prim_type:ty, $data_type: path)=> {
+        /// Writes the typed element located at the multi-dimensional `index` of the variable.
+        ///
+        /// Only the bytes of this element are written into the file.
+        pub fn $func_name(&mut self, var_name: &str, index: &[usize], value: $prim_type) -> Result<(), WriteError> {
+            let stopwatch: Stopwatch = Stopwatch::start(self.instrumented);
+            // Check that the defintion has been set
+            let header_def: &HeaderDefinition = self.header_def.as_ref().ok_or(WriteError::HeaderNotDefined)?;
+            // Check that the variable has been defined
+            let var: &'a Variable = header_def.data_set.find_var_from_name(var_name).map_err(|_err| WriteError::VariableNotDefined(var_name.to_owned()))?.1;
+            if var.data_type != $data_type {
+                return Err(WriteError::VariableMismatchDataType{var_name: var_name.to_owned(), req:var.data_type(), get: $data_type});
+            }
+            // Check the index validity
+            let (record_index, element_pos): (usize, usize) = var.locate_element(index).ok_or_else(|| {
+                WriteError::ElementIndexNotValid{
+                    var_name: var_name.to_owned(),
+                    index: index.to_vec(),
+                    shape: var.shape(),
+                }
+            })?;
+            self.check_valid_range(var, &[value])?;
+            let var_metadata: &ComputedVariableMetadata = header_def.get_var_metadata(var)?;
+            let record_size: usize = header_def.data_set.record_size().unwrap_or(0);
+            let record_offset: u64 = i64::from(var_metadata.begin_offset.clone()) as u64 + (record_size * record_index) as u64;
+
+            // Fill the record first if it has not been written yet, otherwise it would be overwritten at closing
+            self.output.flush_records()?;
+            self.fill_record_if_not_written(var, record_index, record_offset)?;
+
+            // Write the element
+            let position: u64 = record_offset + (element_pos * std::mem::size_of::<$prim_type>()) as u64;
+            self.output.storage.seek(SeekFrom::Start(position))?;
+            self.output.storage.write_all(&value.to_be_bytes())?;
+            self.record_write(var_name, std::mem::size_of::<$prim_type>(), stopwatch);
             Ok(())
         }
     };
 }
 
+macro_rules! impl_push_typed_record {
+    ($func_name:ident, $write_typed_record:ident, $prim_type:ty, $data_type: path)=> {
+        /// Writes the next record of the *record* variable, and returns its index.
+        ///
+        /// The *unlimited-size* dimension is grown if the record is beyond the current number of records,
+        /// the number of records of the written data set and of the file header are updated.
+        pub fn $func_name(&mut self, var_name: &str, record: &[$prim_type]) -> Result<usize, WriteError> {
+            // Check that the defintion has been set
+            let header_def: &HeaderDefinition = self.header_def.as_ref().ok_or(WriteError::HeaderNotDefined)?;
+            // Check that the variable has been defined
+            let var: &'a Variable = header_def.data_set.find_var_from_name(var_name).map_err(|_err| WriteError::VariableNotDefined(var_name.to_owned()))?.1;
+            if var.data_type != $data_type {
+                return Err(WriteError::VariableMismatchDataType{var_name: var_name.to_owned(), req:var.data_type(), get: $data_type});
+            }
+            if !var.is_record_var() {
+                return Err(WriteError::NotRecordVariable(var_name.to_owned()));
+            }
+            // Check the length of the record
+            if record.len() != var.chunk_len() {
+                return Err(WriteError::RecordMismatchDataLength{var_name: var.name.clone(), req: var.chunk_len(), get: record.len()});
+            }
+            self.check_valid_range(var, record)?;
+            let record_index: usize = self.next_record_index(var);
+            if record_index >= header_def.data_set.num_records().unwrap_or(0) {
+                self.push_record()?;
+            }
+            self.$write_typed_record(var_name, record_index, record)?;
+            Ok(record_index)
+        }
+    };
+}
+
 macro_rules! impl_write_typed_chunk_nc_fill {
     ($func_name: ident, $prim_type:ty, $nc_fill_value:path) => {
         /// Fill the output stream with the default value [`$nc_fill_value`](constant.$nc_fill_value.html).
         fn $func_name<T: Write>(out_stream: &mut T, num_values: usize) -> Result<usize, std::io::Error>
         {
-            // Write the useful bytes
+            // Write the useful bytes, the same block of fill values being written repeatedly
             const SIZE_OF: usize = std::mem::size_of::<$prim_type>();
-            let bytes: [u8; SIZE_OF] = $nc_fill_value.to_be_bytes();
-            for _ in 0..num_values {
-                out_stream.write_all(&bytes)?;
+            let block: Vec<$prim_type> = vec![$nc_fill_value; std::cmp::min(num_values, ENCODING_BLOCK_LEN)];
+            let mut block_bytes: Vec<u8> = vec![0_u8; block.len() * SIZE_OF];
+            encode_be_into(&block, &mut block_bytes);
+            let mut num_remaining_values: usize = num_values;
+            while num_remaining_values > 0 {
+                let num_block_values: usize = std::cmp::min(num_remaining_values, block.len());
+                out_stream.write_all(&block_bytes[0..(num_block_values * SIZE_OF)])?;
+                num_remaining_values -= num_block_values;
             }
             let mut num_bytes: usize = num_values * std::mem::size_of::<$prim_type>();
 
@@ -161,7 +249,7 @@ macro_rules! impl_write_typed_chunk_nc_fill {
     };
 }
 
-/// Allows to write NetCDF-3 files (the *classic* and the *64-bit offset* versions).
+/// Allows to write NetCDF-3 files (the *classic*, the *64-bit offset* and the *CDF-5* versions).
 ///
 /// # Example
 ///
@@ -240,12 +328,21 @@ pub struct FileWriter<'a>
 {
     /// Path of the output file
     output_file_path: PathBuf,
-    /// Opened file on the file system
-    output_file: std::fs::File,
+    /// Opened file on the file system, or in-memory buffer, and the records not written yet
+    output: Output,
     /// Defintion of the data set.
     header_def: Option<HeaderDefinition<'a>>,
     /// List of already written records of each variable
     written_records: Vec<(&'a Variable, BTreeSet<usize>)>,
+    /// Write the number of records as *indeterminate* until the closing
+    streaming: bool,
+    /// Reject the values outside the valid range of the variables
+    reject_out_of_range: bool,
+    /// Bytes of the header, encoded before being written at once (kept to be reused by the next files)
+    header_buffer: Vec<u8>,
+    /// Record the durations of the writes
+    instrumented: bool,
+    timings: Timings,
 }
 
 impl<'a> FileWriter<'a> {
@@ -266,10 +363,15 @@ impl<'a> FileWriter<'a> {
             .append(false)
             .open(output_file_path.clone())?;
         Ok(FileWriter{
-            output_file: output_file,
+            output: Output::new(Storage::File(output_file)),
             output_file_path: output_file_path,
             header_def: None,
             written_records: vec![],
+            streaming: false,
+            reject_out_of_range: false,
+            header_buffer: vec![],
+            instrumented: false,
+            timings: Timings::default(),
         })
     }
 
@@ -290,13 +392,183 @@ impl<'a> FileWriter<'a> {
             .create_new(true)
             .open(output_file_path.clone())?;
         Ok(FileWriter{
-            output_file: output_file,
+            output: Output::new(Storage::File(output_file)),
+            output_file_path: output_file_path,
+            header_def: None,
+            written_records: vec![],
+            streaming: false,
+            reject_out_of_range: false,
+            header_buffer: vec![],
+            instrumented: false,
+            timings: Timings::default(),
+        })
+    }
+
+    /// Opens and overwrites an existing NetCDF-3 file or creates one, the file being written through a memory mapping.
+    ///
+    /// Requires the optional feature `mmap`. The file is sized up front by [`set_def`](struct.FileWriter.html#method.set_def)
+    /// and mapped into memory, then the writes (e.g. `write_var_XX`, `write_record_XX`) only copy the encoded values
+    /// into the mapped memory instead of calling the file system, which speeds up the dense writes of many small records.
+    /// The file is extended if records are appended (see `push_record_XX`), and is truncated to its actual size at the closing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{FileReader, FileWriter, DataSet, Version};
+    /// # use tempdir::TempDir;
+    /// # let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    /// # let file_path = tmp_dir.path().join("mapped.nc");
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.set_unlimited_dim("time", 1_000).unwrap();
+    /// data_set.add_var_f32("values", &["time"]).unwrap();
+    ///
+    /// let mut file_writer: FileWriter = FileWriter::open_mapped(&file_path).unwrap();
+    /// file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    /// for record_index in 0..1_000 {
+    ///     file_writer.write_record_f32("values", record_index, &[record_index as f32]).unwrap();
+    /// }
+    /// file_writer.close().unwrap();
+    ///
+    /// let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    /// assert_eq!(Some(999.0),                 file_reader.read_var_f32("values").unwrap().last().copied());
+    /// # tmp_dir.close();
+    /// ```
+    #[cfg(feature = "mmap")]
+    pub fn open_mapped<P: std::convert::AsRef<Path>>(output_file_path: P) -> Result<FileWriter<'a>, WriteError> {
+        let output_file_path: PathBuf = output_file_path.as_ref().to_path_buf();
+        // The mapping requires the read access
+        let output_file: std::fs::File = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(output_file_path.clone())?;
+        Ok(FileWriter{
+            output: Output::new(Storage::Mapped(crate::io::MappedFile::new(output_file))),
             output_file_path: output_file_path,
             header_def: None,
             written_records: vec![],
+            streaming: false,
+            reject_out_of_range: false,
+            header_buffer: vec![],
+            instrumented: false,
+            timings: Timings::default(),
         })
     }
 
+    /// Opens an existing NetCDF-3 file to update its data, the header of the file is kept unchanged.
+    ///
+    /// The `data_set` must be the one stored in the header of the file (for example returned by [`FileReader::close`](struct.FileReader.html#method.close)).
+    /// All the data already stored in the file are kept, nothing is filled at closing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{FileReader, FileWriter, DataSet, Version};
+    ///
+    /// const TEMP_F32_VAR_NAME: &str = "temperature_f32";
+    ///
+    /// // ...
+    /// # use copy_to_tmp_file::{
+    /// #     copy_bytes_to_tmp_file,
+    /// #     NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+    /// # };
+    /// #
+    /// # // Copy bytes to an temporary file
+    /// # let (tmp_dir, file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    ///
+    /// // Read the data set stored in the file
+    /// let file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    /// let (data_set, _version): (DataSet, Version) = file_reader.close();
+    ///
+    /// // Patch one value at `time = 1`, `latitude = 2` and `longitude = 3`
+    /// let mut file_writer: FileWriter = FileWriter::open_existing(&file_path, &data_set).unwrap();
+    /// file_writer.write_element_f32(TEMP_F32_VAR_NAME, &[1, 2, 3], -1.0).unwrap();
+    /// file_writer.close().unwrap();
+    ///
+    /// // Check the patched value and its neighbours
+    /// let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    /// assert_eq!(27.0,                    file_reader.read_element_f32(TEMP_F32_VAR_NAME, &[1, 2, 2]).unwrap());
+    /// assert_eq!(-1.0,                    file_reader.read_element_f32(TEMP_F32_VAR_NAME, &[1, 2, 3]).unwrap());
+    /// assert_eq!(29.0,                    file_reader.read_element_f32(TEMP_F32_VAR_NAME, &[1, 2, 4]).unwrap());
+    /// # tmp_dir.close();
+    /// ```
+    pub fn open_existing<P: std::convert::AsRef<Path>>(output_file_path: P, data_set: &'a DataSet) -> Result<FileWriter<'a>, WriteError> {
+        let output_file_path: PathBuf = {
+            let mut path = PathBuf::new();
+            path.push(output_file_path);
+            path
+        };
+        // Parse the header of the existing file
+        let file_reader: FileReader = FileReader::open(&output_file_path)?;
+        if file_reader.data_set() != data_set {
+            return Err(WriteError::DataSetMismatch);
+        }
+        let header_def: HeaderDefinition<'a> = HeaderDefinition::from_existing(data_set, &file_reader)?;
+        let _ = file_reader.close();
+
+        let output_file: std::fs::File = std::fs::OpenOptions::new()
+            .read(false)
+            .write(true)
+            .create(false)
+            .truncate(false)
+            .append(false)
+            .open(output_file_path.clone())?;
+        // All the records are already stored in the file
+        let num_records: usize = data_set.num_records().unwrap_or(1);
+        let written_records: Vec<(&'a Variable, BTreeSet<usize>)> = data_set.vars.iter()
+            .map(|var: &'a Variable| (var, (0..num_records).collect()))
+            .collect();
+        Ok(FileWriter{
+            output: Output::new(Storage::File(output_file)),
+            output_file_path: output_file_path,
+            header_def: Some(header_def),
+            written_records: written_records,
+            streaming: false,
+            reject_out_of_range: false,
+            header_buffer: vec![],
+            instrumented: false,
+            timings: Timings::default(),
+        })
+    }
+
+    /// Creates a writer storing the NetCDF-3 file in memory, without file system.
+    ///
+    /// The [file path](struct.FileWriter.html#method.file_path) of the writer is empty, the bytes of the file are returned by
+    /// [`close_into_bytes`](struct.FileWriter.html#method.close_into_bytes).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, FileReader, FileWriter, Version};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    ///
+    /// let mut file_writer: FileWriter = FileWriter::in_memory();
+    /// file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    /// file_writer.write_var_f32("latitude", &[0.0, 0.5, 1.0]).unwrap();
+    /// let bytes: Vec<u8> = file_writer.close_into_bytes().unwrap();
+    ///
+    /// let mut file_reader: FileReader = FileReader::from_bytes(bytes).unwrap();
+    /// assert_eq!(vec![0.0, 0.5, 1.0],        file_reader.read_var_f32("latitude").unwrap());
+    /// ```
+    pub fn in_memory() -> FileWriter<'a> {
+        return FileWriter{
+            output: Output::new(Storage::Memory(std::io::Cursor::new(vec![]))),
+            output_file_path: PathBuf::new(),
+            header_def: None,
+            written_records: vec![],
+            streaming: false,
+            reject_out_of_range: false,
+            header_buffer: vec![],
+            instrumented: false,
+            timings: Timings::default(),
+        };
+    }
+
     /// Path of the output file.
     pub fn file_path(&self) -> &Path {
         return &self.output_file_path;
@@ -310,6 +582,8 @@ impl<'a> FileWriter<'a> {
     /// - `version`: the NetCDF-3 version (also see [`Version`](enum.Version.html)).
     /// - `header_min_size`: the mininum number of bytes reserved for header of the NetCDF-3 file.
     ///
+    /// Returns an error if the data set cannot be stored with the `version` (also see [`set_def_auto`](struct.FileWriter.html#method.set_def_auto)).
+    ///
     /// # Example
     ///
     /// ```
@@ -355,6 +629,238 @@ impl<'a> FileWriter<'a> {
         Ok(())
     }
 
+    /// Set the NetCDF-3 definition, and selects the smallest version able to store the data set.
+    ///
+    /// The versions are tried in the order *classic*, *64-bit offset* and *CDF-5*, up to `max_version`.
+    /// A version is rejected if the begin offsets or the chunk sizes of the variables exceed its limits, or if it does not support their data types.
+    /// Use [`set_def`](struct.FileWriter.html#method.set_def) to force a specific version.
+    ///
+    /// Returns the selected version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    /// use netcdf3::{FileWriter, DataSet, Version};
+    /// use netcdf3::error::WriteError;
+    /// use tempdir::TempDir;
+    ///
+    /// const TMP_DIR_PREFIX: &str = "netcdf3_tests_";
+    /// const FILE_NAME: &str = "auto_version.nc";
+    ///
+    /// let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    /// let file_path: PathBuf = tmp_dir.path().join(FILE_NAME);
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("dim_1", 3).unwrap();
+    /// data_set.add_var_u16("var_u16", &["dim_1"]).unwrap();
+    ///
+    /// // The data type `u16` is not supported by the *64-bit offset* version
+    /// let mut file_writer: FileWriter = FileWriter::create_new(&file_path).unwrap();
+    /// assert_eq!(
+    ///     WriteError::DataTypeNotSupported{data_type: netcdf3::DataType::U16, version: Version::Offset64Bit},
+    ///     file_writer.set_def_auto(&data_set, Version::Offset64Bit, 0).unwrap_err()
+    /// );
+    ///
+    /// // Then the *CDF-5* version is selected
+    /// assert_eq!(Ok(Version::Cdf5),           file_writer.set_def_auto(&data_set, Version::Cdf5, 0));
+    /// assert_eq!(Some(Version::Cdf5),         file_writer.version());
+    /// file_writer.close().unwrap();
+    /// # tmp_dir.close();
+    /// ```
+    pub fn set_def_auto(&mut self, data_set: &'a DataSet, max_version: Version, header_min_size: usize) -> Result<Version, WriteError> {
+        if self.header_def.is_some() {
+            return Err(WriteError::HeaderAlreadyDefined);
+        }
+        let versions: Vec<Version> = [Version::Classic, Version::Offset64Bit, Version::Cdf5].iter()
+            .filter(|version: &&Version| (**version).clone() as u8 <= max_version.clone() as u8)
+            .cloned()
+            .collect();
+        let mut last_err: WriteError = WriteError::Unexpected;
+        for version in versions.into_iter() {
+            match HeaderDefinition::new(data_set, version.clone(), header_min_size) {
+                Ok(header_def) => {
+                    self.header_def = Some(header_def);
+                    // The header is kept only if it has been written
+                    if let Err(err) = self.write_header() {
+                        self.header_def = None;
+                        return Err(err);
+                    }
+                    return Ok(version);
+                },
+                Err(err @ WriteError::ClassicVersionNotPossible) |
+                Err(err @ WriteError::Offset64BitVersionNotPossible) |
+                Err(err @ WriteError::DataTypeNotSupported{..}) => last_err = err,
+                Err(err) => return Err(err),
+            }
+        }
+        return Err(last_err);
+    }
+
+    /// Enables or disables the streaming mode.
+    ///
+    /// In the streaming mode, the number of records is written as *indeterminate* (`numrecs = 2^32 - 1`, or `2^64 - 1` for the CDF-5 version) while the records are appended,
+    /// the actual number of records is written at the closing.
+    /// So the readers supporting the streaming files can follow the file as it grows (their number of records is computed from the file size).
+    /// The records are then written into the file as soon as they are appended, instead of being merged (see [`flush`](struct.FileWriter.html#method.flush)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    /// use netcdf3::{FileReader, FileWriter, DataSet, Version};
+    /// use tempdir::TempDir;
+    ///
+    /// const TMP_DIR_PREFIX: &str = "netcdf3_tests_";
+    /// const FILE_NAME: &str = "streaming.nc";
+    ///
+    /// let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    /// let file_path: PathBuf = tmp_dir.path().join(FILE_NAME);
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.set_unlimited_dim("time", 0).unwrap();
+    /// data_set.add_var_f64("time", &["time"]).unwrap();
+    ///
+    /// let mut file_writer: FileWriter = FileWriter::create_new(&file_path).unwrap();
+    /// file_writer.set_streaming(true).unwrap();
+    /// file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    /// file_writer.push_record_f64("time", &[0.0]).unwrap();
+    /// file_writer.push_record_f64("time", &[1.0]).unwrap();
+    ///
+    /// // The number of records is *indeterminate* in the header
+    /// assert_eq!([0xff, 0xff, 0xff, 0xff],    std::fs::read(&file_path).unwrap()[4..8]);
+    /// let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    /// assert_eq!(Ok(vec![0.0, 1.0]),          file_reader.read_var_f64("time"));
+    ///
+    /// // The actual number of records is written at the closing
+    /// file_writer.close().unwrap();
+    /// assert_eq!([0, 0, 0, 2],                std::fs::read(&file_path).unwrap()[4..8]);
+    /// # tmp_dir.close();
+    /// ```
+    pub fn set_streaming(&mut self, streaming: bool) -> Result<(), WriteError> {
+        self.output.flush_records()?;
+        self.streaming = streaming;
+        if self.header_def.is_some() {
+            self.update_num_records()?;
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the streaming mode is enabled (see [`set_streaming`](struct.FileWriter.html#method.set_streaming)).
+    pub fn is_streaming(&self) -> bool {
+        return self.streaming;
+    }
+
+    /// Enables (or disables) the rejection of the values outside the valid range of the variables
+    /// (see [`Variable::valid_range`](struct.Variable.html#method.valid_range)).
+    ///
+    /// The writes then return the error `VariableValueOutOfRange` before writing anything, the fill values being accepted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, FileWriter, Version, error::WriteError};
+    /// # use tempdir::TempDir;
+    /// # let tmp_dir: TempDir = TempDir::new("netcdf3_doc_").unwrap();
+    /// # let file_path = tmp_dir.path().join("valid_range.nc");
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("sst", &["latitude"]).unwrap();
+    /// data_set.add_var_attr_f32("sst", "valid_min", vec![250.0]).unwrap();
+    ///
+    /// let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+    /// file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    /// file_writer.set_reject_out_of_range(true);
+    /// assert_eq!(
+    ///     WriteError::VariableValueOutOfRange{var_name: String::from("sst"), index: 1},
+    ///     file_writer.write_var_f32("sst", &[280.5, -999.0, 281.0]).unwrap_err()
+    /// );
+    /// file_writer.write_var_f32("sst", &[280.5, netcdf3::NC_FILL_F32, 281.0]).unwrap();
+    /// file_writer.close().unwrap();
+    /// ```
+    pub fn set_reject_out_of_range(&mut self, reject_out_of_range: bool) {
+        self.reject_out_of_range = reject_out_of_range;
+    }
+
+    /// Returns `true` if the values outside the valid range are rejected (see [`set_reject_out_of_range`](struct.FileWriter.html#method.set_reject_out_of_range)).
+    pub fn is_rejecting_out_of_range(&self) -> bool {
+        return self.reject_out_of_range;
+    }
+
+    /// Enables (or disables) the instrumentation mode, recording the durations and the counters of the writes of each
+    /// variable (see [`timings`](struct.FileWriter.html#method.timings)).
+    ///
+    /// Disabled by default. The timings are kept by [`reset`](struct.FileWriter.html#method.reset), and must be retrieved before the closing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, FileWriter, Timings, Version};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.set_unlimited_dim("time", 2).unwrap();
+    /// data_set.add_var_f64("time", &["time"]).unwrap();
+    ///
+    /// let mut file_writer: FileWriter = FileWriter::in_memory();
+    /// file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    /// file_writer.set_instrumented(true);
+    /// file_writer.write_record_f64("time", 0, &[0.0]).unwrap();
+    /// file_writer.write_record_f64("time", 1, &[1.0]).unwrap();
+    ///
+    /// let timings: Timings = file_writer.timings();
+    /// assert_eq!(2,                           timings.var("time").unwrap().num_writes);
+    /// assert_eq!(16,                          timings.var("time").unwrap().written_bytes);
+    /// file_writer.close_into_bytes().unwrap();
+    /// ```
+    pub fn set_instrumented(&mut self, instrumented: bool) {
+        self.instrumented = instrumented;
+        self.output.record_buffer.instrumented = instrumented;
+    }
+
+    /// Returns `true` if the instrumentation mode is enabled (see [`set_instrumented`](struct.FileWriter.html#method.set_instrumented)).
+    pub fn is_instrumented(&self) -> bool {
+        return self.instrumented;
+    }
+
+    /// Returns the durations and the counters recorded in the instrumentation mode (see the struct [`Timings`](struct.Timings.html)).
+    ///
+    /// The writes of the records count the encoding and the merging of the records written contiguously, the writes of
+    /// the merged records into the file are counted in `Timings::flush`.
+    pub fn timings(&self) -> Timings {
+        return Timings{
+            flush: self.output.record_buffer.flush_duration,
+            ..self.timings.clone()
+        };
+    }
+
+    /// Clears the recorded durations and counters.
+    pub fn reset_timings(&mut self) {
+        self.timings = Timings::default();
+        self.output.record_buffer.flush_duration = Duration::default();
+    }
+
+    /// Records the write of the values of the variable in the instrumentation mode.
+    fn record_write(&mut self, var_name: &str, num_bytes: usize, stopwatch: Stopwatch) {
+        if self.instrumented {
+            let var_timings: &mut VarTimings = self.timings.var_mut(var_name);
+            var_timings.num_writes += 1;
+            var_timings.written_bytes += num_bytes as u64;
+            var_timings.write += stopwatch.elapsed();
+        }
+    }
+
+    /// Returns the error `VariableValueOutOfRange` if the rejection is enabled and a value is outside the valid range of the variable.
+    fn check_valid_range<T: NcType>(&self, var: &Variable, data: &[T]) -> Result<(), WriteError> {
+        if !self.reject_out_of_range {
+            return Ok(());
+        }
+        match var.find_value_out_of_range(data) {
+            None => Ok(()),
+            Some(index) => Err(WriteError::VariableValueOutOfRange{var_name: var.name.clone(), index: index}),
+        }
+    }
+
     pub fn header_is_defined(&self) -> bool {
         return self.header_def.is_some();
     }
@@ -372,24 +878,128 @@ impl<'a> FileWriter<'a> {
     }
 
 
+    /// Writes the pending records into the file.
+    ///
+    /// The records written contiguously (e.g. the consecutive records of the only record variable, or the records of all the
+    /// record variables at the same index) are merged into one write, which is done when the next record is not contiguous,
+    /// when the merged records exceed a few MB, or when any other data are written. The pending records are also written at the closing
+    /// (or when the writer is dropped), and they are not merged in the streaming mode (see [`set_streaming`](struct.FileWriter.html#method.set_streaming)).
+    pub fn flush(&mut self) -> Result<(), WriteError>
+    {
+        self.output.flush_records()?;
+        self.output.storage.flush()?;
+        return Ok(());
+    }
+
     /// Fills the unwritten data, and closes the NetCDF-3 file.
     pub fn close(mut self) -> Result<(), WriteError>
     {
+        self.fill_unwritten_data()?;
+        #[cfg(feature = "mmap")]
+        if let Storage::Mapped(mapped_file) = &mut self.output.storage {
+            mapped_file.finish()?;
+        }
+        return Ok(());
+    }
+
+    /// Fills the unwritten data, and returns the bytes of the NetCDF-3 file stored in memory (see [`in_memory`](struct.FileWriter.html#method.in_memory)).
+    ///
+    /// Returns the error `WriteError::NotInMemory` if the file is written on the file system.
+    pub fn close_into_bytes(mut self) -> Result<Vec<u8>, WriteError>
+    {
+        self.fill_unwritten_data()?;
+        return match std::mem::replace(&mut self.output.storage, Storage::Memory(std::io::Cursor::new(vec![]))) {
+            Storage::Memory(cursor) => Ok(cursor.into_inner()),
+            _ => Err(WriteError::NotInMemory),
+        };
+    }
+
+    /// Fills the unwritten data and closes the NetCDF-3 file (see [`close`](struct.FileWriter.html#method.close)), then
+    /// opens and overwrites the file `output_file_path` (or creates it) to write the next file.
+    ///
+    /// The allocations of the writer (e.g. the buffer of the header) are reused, so the batch jobs writing many small
+    /// files do not allocate them again for each file. The header of the next file must then be defined (see
+    /// [`set_def`](struct.FileWriter.html#method.set_def)), the other settings of the writer are kept (e.g. the streaming mode
+    /// and the memory mapping).
+    ///
+    /// Returns the error `WriteError::NotOnFileSystem` if the file is written in memory (see [`in_memory`](struct.FileWriter.html#method.in_memory)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{FileReader, FileWriter, DataSet, Version};
+    /// # use tempdir::TempDir;
+    /// # let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("x", 2).unwrap();
+    /// data_set.add_var_i32("station_id", &["x"]).unwrap();
+    ///
+    /// let mut file_writer: FileWriter = FileWriter::open(tmp_dir.path().join("station_0.nc")).unwrap();
+    /// for file_index in 0..10 {
+    ///     if file_index > 0 {
+    ///         file_writer.reset(tmp_dir.path().join(format!("station_{}.nc", file_index))).unwrap();
+    ///     }
+    ///     file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    ///     file_writer.write_var_i32("station_id", &[file_index, -file_index]).unwrap();
+    /// }
+    /// file_writer.close().unwrap();
+    ///
+    /// let mut file_reader: FileReader = FileReader::open(tmp_dir.path().join("station_7.nc")).unwrap();
+    /// assert_eq!(vec![7, -7],                 file_reader.read_var_i32("station_id").unwrap());
+    /// # tmp_dir.close();
+    /// ```
+    pub fn reset<P: AsRef<Path>>(&mut self, output_file_path: P) -> Result<(), WriteError>
+    {
+        // Nothing is written if the file cannot be reset
+        match &self.output.storage {
+            Storage::File(_file) => {},
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(_mapped_file) => {},
+            _ => return Err(WriteError::NotOnFileSystem),
+        }
+        self.fill_unwritten_data()?;
+        let output_file_path: PathBuf = output_file_path.as_ref().to_path_buf();
+        let mut open_options: std::fs::OpenOptions = std::fs::OpenOptions::new();
+        open_options.write(true).create(true).truncate(true);
+        let output_file: Storage = match &mut self.output.storage {
+            Storage::File(_file) => Storage::File(open_options.open(&output_file_path)?),
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(mapped_file) => {
+                mapped_file.finish()?;
+                Storage::Mapped(crate::io::MappedFile::new(open_options.read(true).open(&output_file_path)?))
+            },
+            _ => return Err(WriteError::NotOnFileSystem),
+        };
+        self.output.storage = output_file;
+        self.output_file_path = output_file_path;
+        self.header_def = None;
+        self.written_records.clear();
+        return Ok(());
+    }
+
+    fn fill_unwritten_data(&mut self) -> Result<(), WriteError>
+    {
+        self.output.flush_records()?;
         let header_def: &HeaderDefinition = match self.header_def {
             None => return Ok(()),
             Some(ref header_def) => header_def,
         };
         let num_records: usize = header_def.data_set.num_records().unwrap_or(1);
-        let all_records: BTreeSet<usize> = (0..num_records).collect();
         let not_written_records: Vec<(&'a Variable, Vec<usize>)> = {
             let num_vars = header_def.data_set.vars.len();
             let mut not_written_records: Vec<(&'a Variable, Vec<usize>)> = Vec::with_capacity(num_vars);
             for var in header_def.data_set.vars.iter() {
+                // The *fixed-size* variables have exactly one chunk
+                let all_records: BTreeSet<usize> = match var.is_record_var() {
+                    true => (0..num_records).collect(),
+                    false => (0..1).collect(),
+                };
                 let written_records: Option<&BTreeSet<usize>> = self.written_records.iter()
-                    .find(|(var_2, _written_records): &&(&'a Variable, BTreeSet<usize>)| var == *var_2)
+                    .find(|(var_2, _written_records): &&(&'a Variable, BTreeSet<usize>)| var.name == var_2.name)
                     .map(|(_var_2, written_records): &(&'a Variable, BTreeSet<_>)| written_records);
                 let not_written_record: Vec<usize> = match written_records {
-                    None => all_records.clone().into_iter().collect(),
+                    None => all_records.into_iter().collect(),
                     Some(written_records) => all_records.difference(&written_records).cloned().collect(),
                 };
                 not_written_records.push((var, not_written_record));
@@ -405,17 +1015,15 @@ impl<'a> FileWriter<'a> {
             let begin_offset: usize = i64::from(var_metadata.begin_offset.clone()) as usize;
             for i in not_written_records.into_iter() {
                 let position: usize = begin_offset + (i * record_size);
-                self.output_file.seek(SeekFrom::Start(position as u64))?;
-                let _num_bytes: usize = match var.data_type() {
-                    DataType::I8 => FileWriter::write_chunk_nc_fill_i8(&mut self.output_file, chunk_len),
-                    DataType::U8 => FileWriter::write_chunk_nc_fill_u8(&mut self.output_file, chunk_len),
-                    DataType::I16 => FileWriter::write_chunk_nc_fill_i16(&mut self.output_file, chunk_len),
-                    DataType::I32 => FileWriter::write_chunk_nc_fill_i32(&mut self.output_file, chunk_len),
-                    DataType::F32 => FileWriter::write_chunk_nc_fill_f32(&mut self.output_file, chunk_len),
-                    DataType::F64 => FileWriter::write_chunk_nc_fill_f64(&mut self.output_file, chunk_len),
-                }?;
+                self.output.storage.seek(SeekFrom::Start(position as u64))?;
+                let _num_bytes: usize = FileWriter::write_chunk_nc_fill(&mut self.output.storage, var.data_type(), chunk_len)?;
             }
         }
+        // Patch the actual number of records
+        if self.streaming {
+            self.streaming = false;
+            self.update_num_records()?;
+        }
         Ok(())
     }
 
@@ -425,6 +1033,10 @@ impl<'a> FileWriter<'a> {
     impl_write_typed_chunk!(write_chunk_i32, i32, NC_FILL_I32);
     impl_write_typed_chunk!(write_chunk_f32, f32, NC_FILL_F32);
     impl_write_typed_chunk!(write_chunk_f64, f64, NC_FILL_F64);
+    impl_write_typed_chunk!(write_chunk_u16, u16, NC_FILL_U16);
+    impl_write_typed_chunk!(write_chunk_u32, u32, NC_FILL_U32);
+    impl_write_typed_chunk!(write_chunk_i64, i64, NC_FILL_I64);
+    impl_write_typed_chunk!(write_chunk_u64, u64, NC_FILL_U64);
 
     impl_write_typed_var!(write_var_i8, FileWriter::write_chunk_i8, i8, DataType::I8, DataVector::I8);
     impl_write_typed_var!(write_var_u8, FileWriter::write_chunk_u8, u8, DataType::U8, DataVector::U8);
@@ -432,6 +1044,76 @@ impl<'a> FileWriter<'a> {
     impl_write_typed_var!(write_var_i32, FileWriter::write_chunk_i32, i32, DataType::I32, DataVector::I32);
     impl_write_typed_var!(write_var_f32, FileWriter::write_chunk_f32, f32, DataType::F32, DataVector::F32);
     impl_write_typed_var!(write_var_f64, FileWriter::write_chunk_f64, f64, DataType::F64, DataVector::F64);
+    impl_write_typed_var!(write_var_u16, FileWriter::write_chunk_u16, u16, DataType::U16, DataVector::U16);
+    impl_write_typed_var!(write_var_u32, FileWriter::write_chunk_u32, u32, DataType::U32, DataVector::U32);
+    impl_write_typed_var!(write_var_i64, FileWriter::write_chunk_i64, i64, DataType::I64, DataVector::I64);
+    impl_write_typed_var!(write_var_u64, FileWriter::write_chunk_u64, u64, DataType::U64, DataVector::U64);
+
+    /// Writes all the values of the variable, the element type is generic (see the trait [NcType](trait.NcType.html)).
+    ///
+    /// Same as the typed methods `write_var_i8`, `write_var_u8`, ..., `write_var_u64`.
+    pub fn write_var<T: NcType>(&mut self, var_name: &str, data: &[T]) -> Result<(), WriteError> {
+        return T::write_var(self, var_name, data);
+    }
+
+    /// Writes all the values of a floating-point variable, the `NaN` values being replaced by the fill value of the variable
+    /// (see the method [`FileReader::read_var_nan`](struct.FileReader.html#method.read_var_nan)).
+    pub fn write_var_nan<T: NcFloat>(&mut self, var_name: &str, data: &[T]) -> Result<(), WriteError> {
+        let data: Vec<T> = self.replace_nan_values(var_name, data);
+        return self.write_var(var_name, &data);
+    }
+
+    /// Writes a list of strings into a `u8` variable, the last dimension being the length of the strings (see [`DataSet::add_string_var`](struct.DataSet.html#method.add_string_var)).
+    ///
+    /// The strings are padded with NUL characters, and the longer strings are truncated (on a UTF-8 character boundary).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{FileReader, FileWriter, DataSet, Version};
+    /// # use tempdir::TempDir;
+    ///
+    /// # let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    /// # let file_path = tmp_dir.path().join("stations.nc");
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("station", 2).unwrap();
+    /// data_set.add_string_var("station_name", &["station"], 5).unwrap();
+    ///
+    /// let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+    /// file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    /// file_writer.write_var_strings("station_name", &["Lyon", "Marseille"]).unwrap();
+    /// file_writer.close().unwrap();
+    ///
+    /// let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    /// assert_eq!(b"Lyon\0Marse",              &file_reader.read_var_u8("station_name").unwrap()[..]);
+    /// assert_eq!(vec!["Lyon", "Marse"],       file_reader.read_var_strings("station_name").unwrap());
+    /// # tmp_dir.close();
+    /// ```
+    pub fn write_var_strings(&mut self, var_name: &str, values: &[&str]) -> Result<(), WriteError> {
+        let chars: Vec<u8> = {
+            let header_def: &HeaderDefinition = self.header_def.as_ref().ok_or(WriteError::HeaderNotDefined)?;
+            let var: &Variable = header_def.data_set.find_var_from_name(var_name).map_err(|_err| WriteError::VariableNotDefined(var_name.to_owned()))?.1;
+            if var.data_type != DataType::U8 {
+                return Err(WriteError::VariableMismatchDataType{var_name: var_name.to_owned(), req: var.data_type(), get: DataType::U8});
+            }
+            let string_len: usize = var.dims.last().map(|dim: &Rc<Dimension>| dim.size()).unwrap_or(1);
+            let num_strings: usize = var.len().checked_div(string_len).unwrap_or(0);
+            if values.len() != num_strings {
+                return Err(WriteError::VariableMismatchDataLength{var_name: var_name.to_owned(), req: num_strings, get: values.len()});
+            }
+            let mut chars: Vec<u8> = Vec::with_capacity(var.len());
+            for value in values.iter() {
+                let mut end: usize = value.len().min(string_len);
+                while !value.is_char_boundary(end) {
+                    end -= 1;
+                }
+                chars.extend_from_slice(&value.as_bytes()[0..end]);
+                chars.resize(chars.len() + string_len - end, 0);
+            }
+            chars
+        };
+        return self.write_var_u8(var_name, &chars);
+    }
 
     impl_write_typed_record!(write_record_i8, FileWriter::write_chunk_i8, i8, DataType::I8);
     impl_write_typed_record!(write_record_u8, FileWriter::write_chunk_u8, u8, DataType::U8);
@@ -439,7 +1121,183 @@ impl<'a> FileWriter<'a> {
     impl_write_typed_record!(write_record_i32, FileWriter::write_chunk_i32, i32, DataType::I32);
     impl_write_typed_record!(write_record_f32, FileWriter::write_chunk_f32, f32, DataType::F32);
     impl_write_typed_record!(write_record_f64, FileWriter::write_chunk_f64, f64, DataType::F64);
+    impl_write_typed_record!(write_record_u16, FileWriter::write_chunk_u16, u16, DataType::U16);
+    impl_write_typed_record!(write_record_u32, FileWriter::write_chunk_u32, u32, DataType::U32);
+    impl_write_typed_record!(write_record_i64, FileWriter::write_chunk_i64, i64, DataType::I64);
+    impl_write_typed_record!(write_record_u64, FileWriter::write_chunk_u64, u64, DataType::U64);
+
+    /// Writes a record of the variable, the element type is generic (see the trait [NcType](trait.NcType.html)).
+    ///
+    /// Same as the typed methods `write_record_i8`, `write_record_u8`, ..., `write_record_u64`.
+    pub fn write_record<T: NcType>(&mut self, var_name: &str, record_index: usize, record: &[T]) -> Result<(), WriteError> {
+        return T::write_record(self, var_name, record_index, record);
+    }
+
+    /// Writes a record of a floating-point variable, the `NaN` values being replaced by the fill value of the variable (see the method `write_var_nan`).
+    pub fn write_record_nan<T: NcFloat>(&mut self, var_name: &str, record_index: usize, record: &[T]) -> Result<(), WriteError> {
+        let record: Vec<T> = self.replace_nan_values(var_name, record);
+        return self.write_record(var_name, record_index, &record);
+    }
+
+    /// Replaces the `NaN` values by the fill value of the variable, the values are unchanged if the variable is not defined
+    /// or has an other data type (the error is returned by the write).
+    fn replace_nan_values<T: NcFloat>(&self, var_name: &str, data: &[T]) -> Vec<T> {
+        let fill_value: Option<T> = self.data_set()
+            .and_then(|data_set: &DataSet| data_set.get_var(var_name))
+            .and_then(|var: &Variable| var.fill_value::<T>());
+        return match fill_value {
+            None => data.to_vec(),
+            Some(fill_value) => data.iter().map(|value: &T| if value.is_nan() { fill_value } else { *value }).collect(),
+        };
+    }
+
+    impl_push_typed_record!(push_record_i8, write_record_i8, i8, DataType::I8);
+    impl_push_typed_record!(push_record_u8, write_record_u8, u8, DataType::U8);
+    impl_push_typed_record!(push_record_i16, write_record_i16, i16, DataType::I16);
+    impl_push_typed_record!(push_record_i32, write_record_i32, i32, DataType::I32);
+    impl_push_typed_record!(push_record_f32, write_record_f32, f32, DataType::F32);
+    impl_push_typed_record!(push_record_f64, write_record_f64, f64, DataType::F64);
+    impl_push_typed_record!(push_record_u16, write_record_u16, u16, DataType::U16);
+    impl_push_typed_record!(push_record_u32, write_record_u32, u32, DataType::U32);
+    impl_push_typed_record!(push_record_i64, write_record_i64, i64, DataType::I64);
+    impl_push_typed_record!(push_record_u64, write_record_u64, u64, DataType::U64);
+
+    impl_write_typed_element!(write_element_i8, i8, DataType::I8);
+    impl_write_typed_element!(write_element_u8, u8, DataType::U8);
+    impl_write_typed_element!(write_element_i16, i16, DataType::I16);
+    impl_write_typed_element!(write_element_i32, i32, DataType::I32);
+    impl_write_typed_element!(write_element_f32, f32, DataType::F32);
+    impl_write_typed_element!(write_element_f64, f64, DataType::F64);
+    impl_write_typed_element!(write_element_u16, u16, DataType::U16);
+    impl_write_typed_element!(write_element_u32, u32, DataType::U32);
+    impl_write_typed_element!(write_element_i64, i64, DataType::I64);
+    impl_write_typed_element!(write_element_u64, u64, DataType::U64);
+
+
+    /// Writes a slice (an hyperslab) of the variable, whatever its data type.
+    ///
+    /// The slice starts at the multi-dimensional index `start` and contains `count[i]` elements along the i-th dimension.
+    /// The values of `data` are given in row-major order, only the bytes of the slice are written into the file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{FileReader, FileWriter, DataSet, DataVector, Version};
+    ///
+    /// const TEMP_I16_VAR_NAME: &str = "temperature_i16";
+    ///
+    /// // ...
+    /// # use copy_to_tmp_file::{
+    /// #     copy_bytes_to_tmp_file,
+    /// #     NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+    /// # };
+    /// #
+    /// # // Copy bytes to an temporary file
+    /// # let (tmp_dir, file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    ///
+    /// let (data_set, _version): (DataSet, Version) = FileReader::open(&file_path).unwrap().close();
+    ///
+    /// // Overwrite the sub-grid `latitude = 1..3` and `longitude = 3..5` of the 1st time
+    /// let mut file_writer: FileWriter = FileWriter::open_existing(&file_path, &data_set).unwrap();
+    /// file_writer.write_var_slice(TEMP_I16_VAR_NAME, &[0, 1, 3], &[1, 2, 2], &DataVector::I16(vec![-1, -2, -3, -4])).unwrap();
+    /// file_writer.close().unwrap();
+    ///
+    /// let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    /// assert_eq!(
+    ///     vec![0, 1, 2, 3, 4, 5, 6, 7, -1, -2, 10, 11, 12, -3, -4],
+    ///     file_reader.read_record_i16(TEMP_I16_VAR_NAME, 0).unwrap()
+    /// );
+    /// # tmp_dir.close();
+    /// ```
+    pub fn write_var_slice(&mut self, var_name: &str, start: &[usize], count: &[usize], data: &DataVector) -> Result<(), WriteError>
+    {
+        let stopwatch: Stopwatch = Stopwatch::start(self.instrumented);
+        // Check that the defintion has been set
+        let header_def: &HeaderDefinition = self.header_def.as_ref().ok_or(WriteError::HeaderNotDefined)?;
+        // Check that the variable has been defined
+        let var: &'a Variable = header_def.data_set.find_var_from_name(var_name).map_err(|_err| WriteError::VariableNotDefined(var_name.to_owned()))?.1;
+        if var.data_type != data.data_type() {
+            return Err(WriteError::VariableMismatchDataType{var_name: var_name.to_owned(), req: var.data_type(), get: data.data_type()});
+        }
+        // Check the slice validity
+        let runs: Vec<(usize, usize, usize)> = var.locate_slice(start, count).ok_or_else(|| {
+            WriteError::SliceNotValid{
+                var_name: var_name.to_owned(),
+                start: start.to_vec(),
+                count: count.to_vec(),
+                shape: var.shape(),
+            }
+        })?;
+        let slice_len: usize = runs.iter().map(|(_record_index, _pos, len): &(usize, usize, usize)| len).sum();
+        if data.len() != slice_len {
+            return Err(WriteError::VariableMismatchDataLength{var_name: var_name.to_owned(), req: slice_len, get: data.len()});
+        }
+        match data {
+            DataVector::I8(data) => self.check_valid_range(var, data)?,
+            DataVector::U8(data) => self.check_valid_range(var, data)?,
+            DataVector::I16(data) => self.check_valid_range(var, data)?,
+            DataVector::I32(data) => self.check_valid_range(var, data)?,
+            DataVector::F32(data) => self.check_valid_range(var, data)?,
+            DataVector::F64(data) => self.check_valid_range(var, data)?,
+            DataVector::U16(data) => self.check_valid_range(var, data)?,
+            DataVector::U32(data) => self.check_valid_range(var, data)?,
+            DataVector::I64(data) => self.check_valid_range(var, data)?,
+            DataVector::U64(data) => self.check_valid_range(var, data)?,
+        }
+        let var_metadata: &ComputedVariableMetadata = header_def.get_var_metadata(var)?;
+        let begin_offset: u64 = i64::from(var_metadata.begin_offset.clone()) as u64;
+        let record_size: usize = header_def.data_set.record_size().unwrap_or(0);
+        let size_of: usize = var.data_type.size_of();
+        self.output.flush_records()?;
+
+        let mut start: usize = 0;
+        for (record_index, pos, len) in runs.into_iter() {
+            let record_offset: u64 = begin_offset + (record_index * record_size) as u64;
+            // Fill the record first if it has not been written yet, otherwise it would be overwritten at closing
+            self.fill_record_if_not_written(var, record_index, record_offset)?;
+            self.output.storage.seek(SeekFrom::Start(record_offset + (pos * size_of) as u64))?;
+            FileWriter::write_data_vector_range(&mut self.output.storage, data, start, start + len)?;
+            start += len;
+        }
+        self.record_write(var_name, data.len() * data.data_type().size_of(), stopwatch);
+        Ok(())
+    }
 
+    /// Packs the values with the CF attributes `scale_factor` and `add_offset` of the variable, and writes them
+    /// (`(value - add_offset) / scale_factor`, rounded to the nearest integer for the integer data types).
+    ///
+    /// The `NaN` values are written as the fill value of the variable (see [`Variable::fill_value`](struct.Variable.html#method.fill_value)),
+    /// and the error `VariableValueOutOfRange` is returned if a packed value is outside the range of the data type.
+    ///
+    /// See the method [`FileReader::read_var_unpacked_f64`](struct.FileReader.html#method.read_var_unpacked_f64).
+    pub fn write_var_packed(&mut self, var_name: &str, values: &[f64]) -> Result<(), WriteError> {
+        let header_def: &HeaderDefinition = self.header_def.as_ref().ok_or(WriteError::HeaderNotDefined)?;
+        let var: &Variable = header_def.data_set.find_var_from_name(var_name).map_err(|_err| WriteError::VariableNotDefined(var_name.to_owned()))?.1;
+        let data: DataVector = var.pack_values(values).map_err(|err: CastError| {
+            match err {
+                CastError::ValueOutOfRange{index, data_type: _} => WriteError::VariableValueOutOfRange{var_name: var_name.to_owned(), index: index},
+            }
+        })?;
+        let shape: Vec<usize> = var.shape();
+        return self.write_var_slice(var_name, &vec![0; shape.len()], &shape, &data);
+    }
+
+    /// Writes the values `data[start..end]` into the output stream, without padding bytes.
+    pub(crate) fn write_data_vector_range<T: Write>(out_stream: &mut T, data: &DataVector, start: usize, end: usize) -> Result<usize, std::io::Error>
+    {
+        return match data {
+            DataVector::I8(data) => write_be_values(out_stream, &data[start..end]),
+            DataVector::U8(data) => write_be_values(out_stream, &data[start..end]),
+            DataVector::I16(data) => write_be_values(out_stream, &data[start..end]),
+            DataVector::I32(data) => write_be_values(out_stream, &data[start..end]),
+            DataVector::F32(data) => write_be_values(out_stream, &data[start..end]),
+            DataVector::F64(data) => write_be_values(out_stream, &data[start..end]),
+            DataVector::U16(data) => write_be_values(out_stream, &data[start..end]),
+            DataVector::U32(data) => write_be_values(out_stream, &data[start..end]),
+            DataVector::I64(data) => write_be_values(out_stream, &data[start..end]),
+            DataVector::U64(data) => write_be_values(out_stream, &data[start..end]),
+        };
+    }
 
     impl_write_typed_chunk_nc_fill!(write_chunk_nc_fill_i8, i8, NC_FILL_I8);
     impl_write_typed_chunk_nc_fill!(write_chunk_nc_fill_u8, u8, NC_FILL_U8);
@@ -447,13 +1305,114 @@ impl<'a> FileWriter<'a> {
     impl_write_typed_chunk_nc_fill!(write_chunk_nc_fill_i32, i32, NC_FILL_I32);
     impl_write_typed_chunk_nc_fill!(write_chunk_nc_fill_f32, f32, NC_FILL_F32);
     impl_write_typed_chunk_nc_fill!(write_chunk_nc_fill_f64, f64, NC_FILL_F64);
+    impl_write_typed_chunk_nc_fill!(write_chunk_nc_fill_u16, u16, NC_FILL_U16);
+    impl_write_typed_chunk_nc_fill!(write_chunk_nc_fill_u32, u32, NC_FILL_U32);
+    impl_write_typed_chunk_nc_fill!(write_chunk_nc_fill_i64, i64, NC_FILL_I64);
+    impl_write_typed_chunk_nc_fill!(write_chunk_nc_fill_u64, u64, NC_FILL_U64);
+
+    /// Fill the output stream with the default value of the `data_type`.
+    fn write_chunk_nc_fill<T: Write>(out_stream: &mut T, data_type: DataType, num_values: usize) -> Result<usize, std::io::Error>
+    {
+        return match data_type {
+            DataType::I8 => FileWriter::write_chunk_nc_fill_i8(out_stream, num_values),
+            DataType::U8 => FileWriter::write_chunk_nc_fill_u8(out_stream, num_values),
+            DataType::I16 => FileWriter::write_chunk_nc_fill_i16(out_stream, num_values),
+            DataType::I32 => FileWriter::write_chunk_nc_fill_i32(out_stream, num_values),
+            DataType::F32 => FileWriter::write_chunk_nc_fill_f32(out_stream, num_values),
+            DataType::F64 => FileWriter::write_chunk_nc_fill_f64(out_stream, num_values),
+            DataType::U16 => FileWriter::write_chunk_nc_fill_u16(out_stream, num_values),
+            DataType::U32 => FileWriter::write_chunk_nc_fill_u32(out_stream, num_values),
+            DataType::I64 => FileWriter::write_chunk_nc_fill_i64(out_stream, num_values),
+            DataType::U64 => FileWriter::write_chunk_nc_fill_u64(out_stream, num_values),
+        };
+    }
+
+    /// Copies the raw bytes of all the chunks of the variable from the `reader`, the data types and the shapes must be the same.
+    pub(crate) fn copy_var_bytes(&mut self, reader: &mut FileReader, var_name: &str) -> Result<(), WriteError>
+    {
+        let header_def: &HeaderDefinition = self.header_def.as_ref().ok_or(WriteError::HeaderNotDefined)?;
+        let var: &'a Variable = header_def.data_set.get_var(var_name).ok_or_else(|| WriteError::VariableNotDefined(var_name.to_owned()))?;
+        let begin_offset: u64 = i64::from(header_def.get_var_metadata(var)?.begin_offset.clone()) as u64;
+        let record_size: usize = header_def.data_set.record_size().unwrap_or(0);
+        let num_chunks: usize = var.num_chunks();
+        self.output.flush_records()?;
+        for i in 0..num_chunks {
+            let mut chunk_bytes = reader.chunk_bytes(var_name, i)?;
+            self.output.storage.seek(SeekFrom::Start(begin_offset + ((i * record_size) as u64)))?;
+            let num_bytes: u64 = std::io::copy(&mut chunk_bytes, &mut self.output.storage)?;
+            if num_bytes != var.chunk_size() as u64 {
+                return Err(WriteError::IOErrorKind(std::io::ErrorKind::UnexpectedEof));
+            }
+        }
+        self.update_written_records(var, &(0..num_chunks).collect::<Vec<usize>>())?;
+        Ok(())
+    }
+
+    /// Returns `true` if the record of the variable has already been written.
+    fn record_is_written(&self, var: &'a Variable, record_index: usize) -> bool
+    {
+        return self.written_records.iter()
+            .find(|(var_2, _written_records): &&(&'a Variable, BTreeSet<usize>)| var.name == var_2.name)
+            .map(|(_var_2, written_records): &(&'a Variable, BTreeSet<usize>)| written_records.contains(&record_index))
+            .unwrap_or(false);
+    }
+
+    /// Returns the index following the last written record of the variable.
+    fn next_record_index(&self, var: &'a Variable) -> usize
+    {
+        return self.written_records.iter()
+            .find(|(var_2, _written_records): &&(&'a Variable, BTreeSet<usize>)| var.name == var_2.name)
+            .and_then(|(_var_2, written_records): &(&'a Variable, BTreeSet<usize>)| written_records.iter().next_back())
+            .map(|last_record: &usize| last_record + 1)
+            .unwrap_or(0);
+    }
+
+    /// Appends one record to the *unlimited-size* dimension of the data set, and updates the number of records in the header.
+    fn push_record(&mut self) -> Result<(), WriteError>
+    {
+        let header_def: &HeaderDefinition = self.header_def.as_ref().ok_or(WriteError::HeaderNotDefined)?;
+        let num_records: usize = header_def.data_set.num_records().ok_or(WriteError::Unexpected)?;
+        if header_def.version != Version::Cdf5 && i32::try_from(num_records + 1).is_err() {
+            return Err(WriteError::RecordIndexExceeded{index: num_records, num_records: num_records});
+        }
+        let _num_records: usize = header_def.data_set.push_record().ok_or(WriteError::Unexpected)?;
+        if !self.streaming {
+            self.update_num_records()?;
+        }
+        Ok(())
+    }
+
+    /// Updates the number of records in the header, it is written as *indeterminate* in the streaming mode.
+    fn update_num_records(&mut self) -> Result<(), WriteError>
+    {
+        let header_def: &HeaderDefinition = self.header_def.as_ref().ok_or(WriteError::HeaderNotDefined)?;
+        let num_records: Option<usize> = match self.streaming {
+            false => Some(header_def.data_set.num_records().unwrap_or(0)),
+            true => None,
+        };
+        // The number of records follows the magic word and the version number
+        self.output.storage.seek(SeekFrom::Start(4))?;
+        FileWriter::write_num_records(&mut self.output.storage, num_records, &header_def.version)?;
+        Ok(())
+    }
+
+    /// Fills the record located at `record_offset` with the default values if it has not been written yet, and marks it as written.
+    fn fill_record_if_not_written(&mut self, var: &'a Variable, record_index: usize, record_offset: u64) -> Result<(), WriteError>
+    {
+        if !self.record_is_written(var, record_index) {
+            self.output.storage.seek(SeekFrom::Start(record_offset))?;
+            let _chunk_size: usize = FileWriter::write_chunk_nc_fill(&mut self.output.storage, var.data_type(), var.chunk_len())?;
+            self.update_written_records(var, &[record_index][..])?;
+        }
+        Ok(())
+    }
 
     fn update_written_records(&mut self, var: &'a Variable, records: &[usize]) -> Result<(), WriteError>
     {
         let mut records_set: BTreeSet<usize> = records.iter().map(|index: &usize| index.clone()).collect();
         // Get already written records for the variable
         let ref mut written_records: Option<&mut BTreeSet<usize>> = self.written_records.iter_mut()
-            .find(|(var_2, _written_records): &&mut (&'a Variable, BTreeSet<usize>)| var == *var_2)
+            .find(|(var_2, _written_records): &&mut (&'a Variable, BTreeSet<usize>)| var.name == var_2.name)
             .map(|(_var_2, written_records): &mut (&'a Variable, BTreeSet<usize>)| written_records);
         // If at least one record has alredy been written
         if let Some(ref mut already_written_records_set) = written_records {
@@ -466,51 +1425,78 @@ impl<'a> FileWriter<'a> {
 
     fn write_header(&mut self) -> Result<usize, WriteError>{
         let header_def: &HeaderDefinition = self.header_def.as_ref().ok_or(WriteError::HeaderNotDefined)?;
-        self.output_file.seek(SeekFrom::Start(0))?;
+        #[cfg(feature = "mmap")]
+        if let Storage::Mapped(mapped_file) = &mut self.output.storage {
+            mapped_file.reserve(header_def.file_size() as u64)?;
+        }
+        // The header is encoded into the buffer, then written at once
+        let out_stream: &mut Vec<u8> = &mut self.header_buffer;
+        out_stream.clear();
         let mut num_bytes = 0;
         // the magic word
-        num_bytes += self.output_file.write("CDF".as_bytes())?;
+        num_bytes += out_stream.write("CDF".as_bytes())?;
         //the version number
-        num_bytes += self.output_file.write(&[header_def.version.clone() as u8])?;
+        num_bytes += out_stream.write(&[header_def.version.clone() as u8])?;
         // the size of the *unlimited-size* dimension
-        let num_records: u32 = match header_def.data_set.unlimited_dim.as_ref() {
-            None => 0,  // No unlimited-size dim is defined
-            Some(unlim_dim) => {
-                let num_records: usize = unlim_dim.size();
-                if num_records <= (std::i32::MAX as usize) {
-                    num_records as u32
-                } else {
-                    std::u32::MAX  // indeterminate numbe of records records
-                }
-            }
+        let num_records: Option<usize> = match self.streaming {
+            false => Some(header_def.data_set.num_records().unwrap_or(0)),  // 0 if no unlimited-size dim is defined
+            true => None,
         };
-        let bytes: [u8; 4] = num_records.to_be_bytes();
-        num_bytes += self.output_file.write(&bytes)?;
+        num_bytes += FileWriter::write_num_records(out_stream, num_records, &header_def.version)?;
         // the list of the dimensions
-        num_bytes += FileWriter::write_dims_list(&mut self.output_file, &header_def.data_set.dims)?;
+        num_bytes += FileWriter::write_dims_list(out_stream, &header_def.data_set.dims, &header_def.version)?;
         // the list of the global attributes
-        num_bytes += FileWriter::write_attrs_list(&mut self.output_file, &header_def.data_set.attrs)?;
+        num_bytes += FileWriter::write_attrs_list(out_stream, &header_def.data_set.attrs, &header_def.version)?;
 
         // the list of the variables
         // -------------------------
         // compute the number of bytes *begin-offset* for each variable of the dataset
         let data_set_metadata: &ComputedDataSetMetadata = &header_def.data_set_metadata;
-        num_bytes += FileWriter::write_vars_list(&mut self.output_file, &data_set_metadata.vars_metadata)?;
-        let zero_padding_size: &usize = &data_set_metadata.header_zero_padding_size;
-        for _ in 0..*zero_padding_size {
-            num_bytes +=  self.output_file.write(&[0_u8])?;
-        }
+        num_bytes += FileWriter::write_vars_list(out_stream, &data_set_metadata.vars_metadata, &header_def.version)?;
+        let zero_padding_size: usize = data_set_metadata.header_zero_padding_size;
+        out_stream.resize(out_stream.len() + zero_padding_size, 0_u8);
+        num_bytes += zero_padding_size;
+
+        self.output.storage.seek(SeekFrom::Start(0))?;
+        self.output.storage.write_all(&self.header_buffer)?;
         Ok(num_bytes)
     }
 
-    fn write_name_string<T: Write>(out_stream: &mut T, name: &str) -> Result<usize, std::io::Error> {
+    /// Writes a non-negative integer, as a `i32` word or as a `i64` word for the CDF-5 version.
+    fn write_non_neg<T: Write>(out_stream: &mut T, value: usize, version: &Version) -> Result<usize, std::io::Error> {
+        return match version {
+            Version::Classic | Version::Offset64Bit => out_stream.write(&(value as i32).to_be_bytes()),
+            Version::Cdf5 => out_stream.write(&(value as i64).to_be_bytes()),
+        };
+    }
+
+    /// Writes the number of records, or the *indeterminate* value if it is `None` or if it cannot be represented with the version.
+    fn write_num_records<T: Write>(out_stream: &mut T, num_records: Option<usize>, version: &Version) -> Result<usize, std::io::Error> {
+        return match version {
+            Version::Classic | Version::Offset64Bit => {
+                let num_records: u32 = match num_records {
+                    Some(num_records) if num_records <= (std::i32::MAX as usize) => num_records as u32,
+                    _ => std::u32::MAX,  // indeterminate number of records
+                };
+                out_stream.write(&num_records.to_be_bytes())
+            },
+            Version::Cdf5 => {
+                let num_records: u64 = match num_records {
+                    Some(num_records) => num_records as u64,
+                    None => std::u64::MAX,  // indeterminate number of records
+                };
+                out_stream.write(&num_records.to_be_bytes())
+            },
+        };
+    }
+
+    fn write_name_string<T: Write>(out_stream: &mut T, name: &str, version: &Version) -> Result<usize, std::io::Error> {
         let name_bytes: &[u8] = name.as_bytes();
         let zero_padding_size = compute_padding_size(name_bytes.len());
         let mut num_bytes = 0;
 
         // Write the number of useful bytes
-        let bytes: [u8; 4] = (name_bytes.len() as i32).to_be_bytes();
-        num_bytes += out_stream.write(&bytes)?;
+        num_bytes += FileWriter::write_non_neg(out_stream, name_bytes.len(), version)?;
         // Write the name
         num_bytes += out_stream.write(name_bytes)?;
         // Write the zero padding bytes
@@ -527,61 +1513,54 @@ impl<'a> FileWriter<'a> {
         Ok(num_bytes)
     }
 
-    fn write_dims_list<T: Write>(out_stream: &mut T, dims_list: &[Rc<Dimension>]) -> Result<usize, std::io::Error> {
-        fn write_dim<T: Write>(out_stream: &mut T, dim: &Rc<Dimension>) -> Result<usize, std::io::Error> {
+    fn write_dims_list<T: Write>(out_stream: &mut T, dims_list: &[Rc<Dimension>], version: &Version) -> Result<usize, std::io::Error> {
+        fn write_dim<T: Write>(out_stream: &mut T, dim: &Rc<Dimension>, version: &Version) -> Result<usize, std::io::Error> {
             // First write the dimension name
-            let mut num_bytes = FileWriter::write_name_string(out_stream, dim.name().as_ref())?;
+            let mut num_bytes = FileWriter::write_name_string(out_stream, dim.name().as_ref(), version)?;
             // Then write the dimension size
             let dim_size: usize = match dim.size {
                 DimensionSize::Unlimited(_) => 0,  // the unlimited-size is recorded as 0
                 DimensionSize::Fixed(fixed_size) => fixed_size,
             };
-            let bytes: [u8; 4] = (dim_size as i32).to_be_bytes();
-            num_bytes += out_stream.write(&bytes)?;
+            num_bytes += FileWriter::write_non_neg(out_stream, dim_size, version)?;
 
             Ok(num_bytes)
         }
         let mut num_bytes: usize = 0;
         if dims_list.is_empty() {
             // Write the ABSENT_TAG
-            num_bytes += out_stream.write(&ABSENT_TAG)?;
+            num_bytes += out_stream.write(absent_tag(version))?;
         }
         else {
             // Write the DIENSION_TAG
             num_bytes += out_stream.write(&DIMENSION_TAG)?;
 
             // Write the number of dimensions
-            let num_dims: usize = dims_list.len();
-            let bytes: [u8; 4] = (num_dims as i32).to_be_bytes();
-            num_bytes += out_stream.write(&bytes)?;
+            num_bytes += FileWriter::write_non_neg(out_stream, dims_list.len(), version)?;
 
             // Write each dimension of the list
             for dim in dims_list {
-                num_bytes += write_dim(out_stream, dim)?;
+                num_bytes += write_dim(out_stream, dim, version)?;
             }
         }
         Ok(num_bytes)
     }
 
-    fn write_attrs_list<T: Write>(out_stream: &mut T, attrs_list: &[Attribute]) -> Result<usize, std::io::Error> {
-        fn write_attr<T: Write>(out_stream: &mut T, attr: &Attribute) -> Result<usize, std::io::Error> {
+    fn write_attrs_list<T: Write>(out_stream: &mut T, attrs_list: &[Attribute], version: &Version) -> Result<usize, std::io::Error> {
+        fn write_attr<T: Write>(out_stream: &mut T, attr: &Attribute, version: &Version) -> Result<usize, std::io::Error> {
             // The name of the attribute
-            let mut num_bytes = FileWriter::write_name_string(out_stream, &attr.name)?;
+            let mut num_bytes = FileWriter::write_name_string(out_stream, &attr.name, version)?;
             // The data type of the attribute
             num_bytes += FileWriter::write_data_type(out_stream, attr.data_type())?;
             // The number of elements
-            let num_elements: usize = attr.len();
-            let bytes: [u8; 4] = (num_elements as i32).to_be_bytes();
-            num_bytes += out_stream.write(&bytes)?;
-            // The data of the attribute
-            num_bytes += match &attr.data {
-                DataVector::I8(slice) => FileWriter::write_chunk_i8(out_stream, slice)?,
-                DataVector::U8(slice) => FileWriter::write_chunk_u8(out_stream, slice)?,
-                DataVector::I16(slice) => FileWriter::write_chunk_i16(out_stream, slice)?,
-                DataVector::I32(slice) => FileWriter::write_chunk_i32(out_stream, slice)?,
-                DataVector::F32(slice) => FileWriter::write_chunk_f32(out_stream, slice)?,
-                DataVector::F64(slice) => FileWriter::write_chunk_f64(out_stream, slice)?,
-            };
+            num_bytes += FileWriter::write_non_neg(out_stream, attr.len(), version)?;
+            // The data of the attribute, followed by zero padding bytes
+            let num_useful_bytes: usize = FileWriter::write_data_vector_range(out_stream, &attr.data, 0, attr.len())?;
+            let zero_padding_size: usize = compute_padding_size(num_useful_bytes);
+            if zero_padding_size > 0 {
+                out_stream.write_all(&vec![0_u8; zero_padding_size])?;
+            }
+            num_bytes += num_useful_bytes + zero_padding_size;
 
             Ok(num_bytes)
         }
@@ -590,50 +1569,48 @@ impl<'a> FileWriter<'a> {
 
         if attrs_list.is_empty() {
             // Write the ABSENT_TAG
-            num_bytes += out_stream.write(&ABSENT_TAG)?;
+            num_bytes += out_stream.write(absent_tag(version))?;
         }
         else {
             // Write the ATTRIBUTE_TAG
             num_bytes += out_stream.write(&ATTRIBUTE_TAG)?;
             // Write the number of attributes
-            let num_attrs: usize = attrs_list.len();
-            let bytes: [u8; 4] = (num_attrs as i32).to_be_bytes();
-            num_bytes += out_stream.write(&bytes)?;
+            num_bytes += FileWriter::write_non_neg(out_stream, attrs_list.len(), version)?;
 
             // Write for each attribute:  its name, data type and data
             for attr in attrs_list {
-                num_bytes += write_attr(out_stream, attr)?;
+                num_bytes += write_attr(out_stream, attr, version)?;
             }
         }
         Ok(num_bytes)
     }
 
-    fn write_vars_list<T: Write>(out_stream: &mut T, vars_metadata_list: &[(&Variable, ComputedVariableMetadata)]) -> Result<usize, WriteError> {
-        fn write_var<T: Write>(out_stream: &mut T, var: &Variable, var_metadata: &ComputedVariableMetadata) -> Result<usize, WriteError> {
+    fn write_vars_list<T: Write>(out_stream: &mut T, vars_metadata_list: &[(&Variable, ComputedVariableMetadata)], version: &Version) -> Result<usize, WriteError> {
+        fn write_var<T: Write>(out_stream: &mut T, var: &Variable, var_metadata: &ComputedVariableMetadata, version: &Version) -> Result<usize, WriteError> {
             // Write the name of the variable
-            let mut num_bytes: usize = FileWriter::write_name_string(out_stream, &var.name)?;
+            let mut num_bytes: usize = FileWriter::write_name_string(out_stream, &var.name, version)?;
             // Write the number of dimensions
-            let num_dims = var.num_dims();
-            let mut bytes: [u8; 4] = (num_dims as i32).to_be_bytes();
-            num_bytes += out_stream.write(&bytes)?;
+            num_bytes += FileWriter::write_non_neg(out_stream, var.num_dims(), version)?;
             // Write each variable dimension ID
             for dim_id in var_metadata.dim_ids.iter() {
-                bytes = (*dim_id as i32).to_be_bytes();
-                num_bytes += out_stream.write(&bytes)?;
+                num_bytes += FileWriter::write_non_neg(out_stream, *dim_id, version)?;
             }
             // Write variable attributes
-            num_bytes += FileWriter::write_attrs_list(out_stream, &var.attrs)?;
+            num_bytes += FileWriter::write_attrs_list(out_stream, &var.attrs, version)?;
             // Write the variable data type
             num_bytes += FileWriter::write_data_type(out_stream, var.data_type.clone())?;
             // Write the `var_size` the number of bytes used per chunk (including the zero padding bytes)
-            bytes = {
-                let mut chunk_size: usize = var_metadata.chunk_size;
-                if chunk_size > (std::i32::MAX as usize) {
-                    chunk_size = std::u32::MAX as usize;
-                }
-                (chunk_size as u32).to_be_bytes()
+            num_bytes += match version {
+                Version::Classic | Version::Offset64Bit => {
+                    // The chunk sizes greater than `2^32 - 4` are written as *indeterminate* (`2^32 - 1`)
+                    let chunk_size: u32 = match var_metadata.chunk_size <= ComputedDataSetMetadata::OFFSET_64_BIT_MAX_CHUNK_SIZE {
+                        true => var_metadata.chunk_size as u32,
+                        false => std::u32::MAX,
+                    };
+                    out_stream.write(&chunk_size.to_be_bytes())?
+                },
+                Version::Cdf5 => FileWriter::write_non_neg(out_stream, var_metadata.chunk_size, version)?,
             };
-            num_bytes += out_stream.write(&bytes)?;
             // Write the `begin_offset`
             match var_metadata.begin_offset {
                 Offset::I32(begin_offset) => {
@@ -651,26 +1628,375 @@ impl<'a> FileWriter<'a> {
         let mut num_bytes: usize = 0;
         if vars_metadata_list.is_empty() {
             // Write the ABSENT_TAG
-            num_bytes += out_stream.write(&ABSENT_TAG)?;
+            num_bytes += out_stream.write(absent_tag(version))?;
         }
         else {
             // Write the VARIABLE_TAG
             num_bytes += out_stream.write(&VARIABLE_TAG)?;
 
             // Write the number of variables
-            let num_vars: usize = vars_metadata_list.len();
-            let bytes: [u8; 4] = (num_vars as i32).to_be_bytes();
-            num_bytes += out_stream.write(&bytes)?;
+            num_bytes += FileWriter::write_non_neg(out_stream, vars_metadata_list.len(), version)?;
 
             // Write for each variable :  its name, data type, ...
             for (var, var_metadata) in vars_metadata_list.iter() {
-                num_bytes += write_var(out_stream, var, var_metadata)?;
+                num_bytes += write_var(out_stream, var, var_metadata, version)?;
             }
         }
         Ok(num_bytes)
     }
 }
 
+impl DataSet {
+
+    /// Returns the number of bytes of the header written by a [`FileWriter`](struct.FileWriter.html) (without `header_min_size`), including the zero padding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, Version};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// assert_eq!(32,                      data_set.header_size(Version::Classic));
+    ///
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    /// assert_eq!(88,                      data_set.header_size(Version::Classic));
+    /// assert_eq!(136,                     data_set.header_size(Version::Cdf5));
+    /// ```
+    pub fn header_size(&self, version: Version) -> usize {
+        let header_size: usize = ComputedDataSetMetadata::compute_header_required_size(self, version);
+        return header_size + compute_padding_size(header_size);
+    }
+
+    /// Returns the size (number of bytes) of the file written by a [`FileWriter`](struct.FileWriter.html) (without `header_min_size`),
+    /// with the current number of records.
+    ///
+    /// Returns an error if the data set cannot be written with the `version`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, Version, error::WriteError};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.set_unlimited_dim("time", 10).unwrap();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    /// data_set.add_var_f64("sst", &["time", "latitude"]).unwrap();
+    ///
+    /// let header_size: usize = data_set.header_size(Version::Classic);
+    /// assert_eq!(Ok(header_size + 12 + 10 * 24),  data_set.file_size_estimate(Version::Classic));
+    ///
+    /// data_set.add_var_u64("count", &["latitude"]).unwrap();
+    /// assert!(matches!(data_set.file_size_estimate(Version::Classic), Err(WriteError::DataTypeNotSupported{..})));
+    /// ```
+    pub fn file_size_estimate(&self, version: Version) -> Result<usize, WriteError> {
+        // Check that the data set can be written
+        let header_def: HeaderDefinition = HeaderDefinition::new(self, version.clone(), 0)?;
+        let fixed_size_vars_size: usize = header_def.data_set_metadata.vars_metadata.iter()
+            .filter(|(var, _var_metadata): &&(&Variable, ComputedVariableMetadata)| !var.is_record_var())
+            .map(|(_var, var_metadata): &(&Variable, ComputedVariableMetadata)| var_metadata.chunk_size)
+            .sum();
+        let records_size: usize = self.num_records().unwrap_or(0) * self.record_size().unwrap_or(0);
+        return Ok(self.header_size(version) + fixed_size_vars_size + records_size);
+    }
+
+    /// Checks all the NetCDF-3 rules of the data set for the `version` at once, and returns all the broken rules.
+    ///
+    /// The names, the sizes of the dimensions and the dimensions of the variables are checked again (with the implementation
+    /// limits of the data set, see the method [`DataSet::with_limits`](struct.DataSet.html#method.with_limits)), as well as
+    /// the rules depending on the version :
+    ///
+    /// - the data types of the variables and of the attributes,
+    /// - the chunk sizes of the variables (except the last *fixed-size* and the last *record* variables),
+    /// - the begin offsets of the variables for the *classic* version.
+    ///
+    /// A data set which is valid can be defined by [`FileWriter::set_def`](struct.FileWriter.html#method.set_def) with the same `version`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, DataType, Version, InvalidDataSet};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_u64("count", &["latitude"]).unwrap();
+    /// data_set.add_global_attr_u16("version", vec![1]).unwrap();
+    ///
+    /// assert_eq!(Ok(()),                  data_set.validate(Version::Cdf5));
+    /// assert_eq!(
+    ///     Err(vec![
+    ///         InvalidDataSet::GlobalAttributeDataTypeNotSupported{
+    ///             attr_name: String::from("version"),
+    ///             data_type: DataType::U16,
+    ///             version: Version::Classic,
+    ///         },
+    ///         InvalidDataSet::VariableDataTypeNotSupported{
+    ///             var_name: String::from("count"),
+    ///             data_type: DataType::U64,
+    ///             version: Version::Classic,
+    ///         },
+    ///     ]),
+    ///     data_set.validate(Version::Classic)
+    /// );
+    /// ```
+    pub fn validate(&self, version: Version) -> Result<(), Vec<InvalidDataSet>> {
+        let mut errors: Vec<InvalidDataSet> = vec![];
+
+        // Check the dimensions
+        for dim in self.dims.iter() {
+            let dim_name: String = dim.name();
+            if Dimension::check_dim_name(&dim_name).is_err() || !self.check_name_size(&dim_name) {
+                errors.push(InvalidDataSet::DimensionNameNotValid(dim_name.clone()));
+            }
+            if dim.is_fixed() {
+                let dim_size: usize = dim.size();
+                if dim_size == 0 {
+                    errors.push(InvalidDataSet::FixedDimensionWithZeroSize(dim_name));
+                } else if dim_size > std::cmp::min(NC_MAX_DIM_SIZE, self.limits().max_dim_size) {
+                    errors.push(InvalidDataSet::MaximumFixedDimensionSizeExceeded{dim_name: dim_name, get: dim_size});
+                }
+            }
+        }
+
+        // Check the global attributes
+        for attr in self.attrs.iter() {
+            if Attribute::check_attr_name(&attr.name).is_err() || !self.check_name_size(&attr.name) {
+                errors.push(InvalidDataSet::GlobalAttributeNameNotValid(attr.name.clone()));
+            }
+            if !version.supports_data_type(&attr.data_type()) {
+                errors.push(InvalidDataSet::GlobalAttributeDataTypeNotSupported{
+                    attr_name: attr.name.clone(),
+                    data_type: attr.data_type(),
+                    version: version.clone(),
+                });
+            }
+        }
+
+        // Check the variables and their attributes
+        for var in self.vars.iter() {
+            if Variable::check_var_name(&var.name).is_err() || !self.check_name_size(&var.name) {
+                errors.push(InvalidDataSet::VariableNameNotValid(var.name.clone()));
+            }
+            let undef_dim_names: Vec<String> = var.dims.iter()
+                .filter(|dim: &&Rc<Dimension>| !self.dims.iter().any(|dim_2: &Rc<Dimension>| Rc::ptr_eq(dim, dim_2)))
+                .map(|dim: &Rc<Dimension>| dim.name())
+                .collect();
+            if !undef_dim_names.is_empty() {
+                errors.push(InvalidDataSet::DimensionsNotDefined{var_name: var.name.clone(), undef_dim_names: undef_dim_names});
+            }
+            if let Err(err) = Variable::check_dims_validity(&var.name, &var.dims) {
+                errors.push(err);
+            } else if var.dims.len() > self.limits().max_var_dims {
+                errors.push(InvalidDataSet::MaximumDimensionsPerVariableExceeded{var_name: var.name.clone(), num_dims: var.dims.len()});
+            }
+            if !version.supports_data_type(&var.data_type()) {
+                errors.push(InvalidDataSet::VariableDataTypeNotSupported{
+                    var_name: var.name.clone(),
+                    data_type: var.data_type(),
+                    version: version.clone(),
+                });
+            }
+            for attr in var.attrs.iter() {
+                if Attribute::check_attr_name(&attr.name).is_err() || !self.check_name_size(&attr.name) {
+                    errors.push(InvalidDataSet::VariableAttributeNameNotValid{var_name: var.name.clone(), attr_name: attr.name.clone()});
+                }
+                if !version.supports_data_type(&attr.data_type()) {
+                    errors.push(InvalidDataSet::VariableAttributeDataTypeNotSupported{
+                        var_name: var.name.clone(),
+                        attr_name: attr.name.clone(),
+                        data_type: attr.data_type(),
+                        version: version.clone(),
+                    });
+                }
+            }
+        }
+
+        // Check the chunk sizes and the begin offsets, the *fixed-size* variables are written before the *record* variables
+        let (record_vars, fixed_size_vars): (Vec<&Variable>, Vec<&Variable>) = self.vars.iter().partition(|var: &&Variable| var.is_record_var());
+        let max_chunk_size: Option<usize> = match version {
+            Version::Classic => Some(ComputedDataSetMetadata::CLASSIC_MAX_CHUNK_SIZE),
+            Version::Offset64Bit => Some(ComputedDataSetMetadata::OFFSET_64_BIT_MAX_CHUNK_SIZE),
+            Version::Cdf5 => None,
+        };
+        let mut begin_offset: usize = self.header_size(version.clone());
+        // Only the last *record* variable, and the last *fixed-size* variable if there is no *record* variable, can exceed the maximum chunk size
+        let no_record_vars: bool = record_vars.is_empty();
+        for (vars, can_exceed) in [(fixed_size_vars, no_record_vars), (record_vars, true)].iter() {
+            for (i, var) in vars.iter().enumerate() {
+                let chunk_size: usize = var.chunk_size();
+                let is_last_var: bool = *can_exceed && i + 1 == vars.len();
+                if let Some(max_chunk_size) = max_chunk_size {
+                    if !is_last_var && chunk_size > max_chunk_size {
+                        errors.push(InvalidDataSet::VariableChunkSizeExceeded{
+                            var_name: var.name.clone(),
+                            chunk_size: chunk_size,
+                            version: version.clone(),
+                        });
+                    }
+                }
+                if version == Version::Classic && i32::try_from(begin_offset).is_err() {
+                    errors.push(InvalidDataSet::VariableBeginOffsetExceeded{
+                        var_name: var.name.clone(),
+                        begin_offset: begin_offset,
+                        version: version.clone(),
+                    });
+                }
+                begin_offset += chunk_size;
+            }
+        }
+
+        return match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        };
+    }
+}
+
+impl InMemoryDataSet {
+
+    /// Writes the data set and the data of its variables into a new NetCDF-3 file (an existing file is overwritten).
+    ///
+    /// The variables without data are filled with their fill values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, FileReader, InMemoryDataSet, Version};
+    /// # use tempdir::TempDir;
+    /// # let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    /// # let file_path = tmp_dir.path().join("in_memory.nc");
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    ///
+    /// let mut in_memory: InMemoryDataSet = InMemoryDataSet::new(data_set);
+    /// in_memory.set_var("latitude", vec![0.0_f32, 0.5, 1.0]).unwrap();
+    /// in_memory.write_file(&file_path, Version::Classic).unwrap();
+    ///
+    /// let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    /// assert_eq!(in_memory,                   file_reader.read_in_memory().unwrap());
+    /// ```
+    pub fn write_file<P: AsRef<Path>>(&self, output_file_path: P, version: Version) -> Result<(), WriteError> {
+        let mut writer: FileWriter = FileWriter::open(output_file_path)?;
+        self.write_into(&mut writer, version)?;
+        writer.close()?;
+        return Ok(());
+    }
+
+    /// Writes the data set and the data of its variables into the bytes of a NetCDF-3 file stored in memory.
+    ///
+    /// The variables without data are filled with their fill values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::{DataSet, FileReader, InMemoryDataSet, Version};
+    ///
+    /// let mut data_set: DataSet = DataSet::new();
+    /// data_set.add_fixed_dim("latitude", 3).unwrap();
+    /// data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    ///
+    /// let mut in_memory: InMemoryDataSet = InMemoryDataSet::new(data_set);
+    /// in_memory.set_var("latitude", vec![0.0_f32, 0.5, 1.0]).unwrap();
+    /// let bytes: Vec<u8> = in_memory.to_bytes(Version::Classic).unwrap();
+    ///
+    /// let mut file_reader: FileReader = FileReader::from_bytes(bytes).unwrap();
+    /// assert_eq!(in_memory,                   file_reader.read_in_memory().unwrap());
+    /// ```
+    pub fn to_bytes(&self, version: Version) -> Result<Vec<u8>, WriteError> {
+        let mut writer: FileWriter = FileWriter::in_memory();
+        self.write_into(&mut writer, version)?;
+        return writer.close_into_bytes();
+    }
+
+    fn write_into<'a>(&'a self, writer: &mut FileWriter<'a>, version: Version) -> Result<(), WriteError> {
+        writer.set_def(&self.data_set, version, 0)?;
+        for var in self.data_set.vars.iter() {
+            if let Some(var_data) = self.data.get(var.name()) {
+                let shape: Vec<usize> = var.shape();
+                writer.write_var_slice(var.name(), &vec![0; shape.len()], &shape, var_data)?;
+            }
+        }
+        return Ok(());
+    }
+}
+
+/// Output of a `FileWriter`, the pending records being written when it is dropped (e.g. the writer dropped without being closed).
+#[derive(Debug)]
+struct Output {
+    storage: Storage,
+    record_buffer: RecordBuffer,
+}
+
+impl Output {
+    fn new(storage: Storage) -> Self {
+        return Output{
+            storage: storage,
+            record_buffer: RecordBuffer::default(),
+        };
+    }
+
+    /// Returns the buffer to append the bytes of the record located at the `position` (see `RecordBuffer::bytes_at`).
+    fn record_bytes_at(&mut self, position: u64) -> std::io::Result<&mut Vec<u8>> {
+        return self.record_buffer.bytes_at(&mut self.storage, position);
+    }
+
+    /// Writes the pending records.
+    fn flush_records(&mut self) -> std::io::Result<()> {
+        return self.record_buffer.flush(&mut self.storage);
+    }
+}
+
+impl Drop for Output {
+    fn drop(&mut self) {
+        let _ = self.flush_records();
+    }
+}
+
+/// Bytes of the records written contiguously, merged into one write (see `FileWriter::flush`).
+#[derive(Debug, Default)]
+struct RecordBuffer {
+    /// Position of the pending bytes in the file
+    position: u64,
+    bytes: Vec<u8>,
+    /// Record the durations of the writes of the pending bytes into `flush_duration`
+    instrumented: bool,
+    flush_duration: Duration,
+}
+
+impl RecordBuffer {
+    /// Number of pending bytes from which they are written before appending the next record
+    const MAX_SIZE: usize = 4 * 1024 * 1024;
+
+    /// Returns the buffer to append the bytes located at the `position`, the pending bytes being written first if they are not contiguous.
+    fn bytes_at(&mut self, output: &mut Storage, position: u64) -> std::io::Result<&mut Vec<u8>> {
+        if position != self.position + self.bytes.len() as u64 || self.bytes.len() >= RecordBuffer::MAX_SIZE {
+            self.flush(output)?;
+        }
+        if self.bytes.is_empty() {
+            self.position = position;
+        }
+        return Ok(&mut self.bytes);
+    }
+
+    /// Writes the pending bytes.
+    fn flush(&mut self, output: &mut Storage) -> std::io::Result<()> {
+        if self.bytes.is_empty() {
+            return Ok(());
+        }
+        let stopwatch: Stopwatch = Stopwatch::start(self.instrumented);
+        output.seek(SeekFrom::Start(self.position))?;
+        output.write_all(&self.bytes)?;
+        self.flush_duration += stopwatch.elapsed();
+        self.bytes.clear();
+        // Release the buffers grown by the very large records
+        self.bytes.shrink_to(RecordBuffer::MAX_SIZE);
+        return Ok(());
+    }
+}
+
 #[derive(Debug)]
 struct HeaderDefinition<'a> {
     /// A reference to the written data set
@@ -685,6 +2011,16 @@ struct HeaderDefinition<'a> {
 
 impl <'a> HeaderDefinition<'a> {
     fn new(data_set: &'a DataSet, version: Version, header_min_size: usize) -> Result<HeaderDefinition, WriteError> {
+        // Check that the data types of the variables and of the attributes are supported by the version
+        let data_types = data_set.attrs.iter().map(|attr: &Attribute| attr.data_type())
+            .chain(data_set.vars.iter().flat_map(|var: &Variable| {
+                std::iter::once(var.data_type()).chain(var.attrs.iter().map(|attr: &Attribute| attr.data_type()))
+            }));
+        for data_type in data_types {
+            if !version.supports_data_type(&data_type) {
+                return Err(WriteError::DataTypeNotSupported{data_type: data_type, version: version});
+            }
+        }
         Ok(HeaderDefinition{
             data_set: data_set,
             version: version.clone(),
@@ -693,9 +2029,59 @@ impl <'a> HeaderDefinition<'a> {
         })
     }
 
+    /// Builds the header definition of an existing file, the offsets of the variables are those parsed from its header.
+    fn from_existing(data_set: &'a DataSet, file_reader: &FileReader) -> Result<HeaderDefinition<'a>, WriteError> {
+        let version: Version = file_reader.version();
+        let header_required_size: usize = ComputedDataSetMetadata::compute_header_required_size(data_set, version.clone());
+        let mut vars_metadata: Vec<(&'a Variable, ComputedVariableMetadata)> = Vec::with_capacity(data_set.vars.len());
+        for var in data_set.vars.iter() {
+            vars_metadata.push((
+                var,
+                ComputedVariableMetadata{
+                    dim_ids: data_set.get_var_dim_ids(&var.name).ok_or(WriteError::Unexpected)?,
+                    chunk_size: var.chunk_size(),
+                    begin_offset: file_reader.var_begin_offset(&var.name).ok_or(WriteError::Unexpected)?,
+                },
+            ));
+        }
+        // The header ends where the data part begins
+        let header_size: usize = vars_metadata.iter()
+            .map(|(_var, var_metadata): &(&'a Variable, ComputedVariableMetadata)| i64::from(var_metadata.begin_offset.clone()) as usize)
+            .min()
+            .unwrap_or(header_required_size);
+        Ok(HeaderDefinition{
+            data_set: data_set,
+            version: version,
+            header_min_size: header_size,
+            data_set_metadata: ComputedDataSetMetadata{
+                header_required_size: header_required_size,
+                header_zero_padding_size: header_size.saturating_sub(header_required_size),
+                vars_metadata: vars_metadata,
+            },
+        })
+    }
+
+    /// Returns the size of the file once all the data are written.
+    #[cfg(feature = "mmap")]
+    fn file_size(&self) -> usize {
+        let num_records: usize = self.data_set.num_records().unwrap_or(0);
+        let record_size: usize = self.data_set.record_size().unwrap_or(0);
+        let header_size: usize = self.data_set_metadata.header_required_size + self.data_set_metadata.header_zero_padding_size;
+        return self.data_set_metadata.vars_metadata.iter()
+            .map(|(var, var_metadata): &(&Variable, ComputedVariableMetadata)| {
+                let begin_offset: usize = i64::from(var_metadata.begin_offset.clone()) as usize;
+                match var.is_record_var() {
+                    true if num_records == 0 => begin_offset,
+                    true => begin_offset + (num_records - 1) * record_size + var_metadata.chunk_size,
+                    false => begin_offset + var_metadata.chunk_size,
+                }
+            })
+            .fold(header_size, std::cmp::max);
+    }
+
     fn get_var_metadata(&self, var: &'a Variable) -> Result<&ComputedVariableMetadata, WriteError> {
         self.data_set_metadata.vars_metadata.iter()
-            .find(|(var_2, _var_metadata): &&(&Variable,  ComputedVariableMetadata)| var == *var_2)
+            .find(|(var_2, _var_metadata): &&(&Variable,  ComputedVariableMetadata)| var.name == var_2.name)
             .map(|(_var, var_metadata): &(&Variable,  ComputedVariableMetadata)| var_metadata)
             .ok_or(WriteError::Unexpected)
     }
@@ -723,6 +2109,11 @@ struct ComputedVariableMetadata {
 
 impl<'a> ComputedDataSetMetadata<'a> {
 
+    /// Maximum chunk size of the variables (except the last ones) for the *classic* version
+    const CLASSIC_MAX_CHUNK_SIZE: usize = (std::i32::MAX as usize) - 3;
+    /// Maximum chunk size of the variables (except the last ones) for the *64-bit offset* version
+    const OFFSET_64_BIT_MAX_CHUNK_SIZE: usize = (std::u32::MAX as usize) - 3;
+
     /// Computes and returns all metadata required for each variable, namely :
     ///
     /// 0. The position of the variables stored in the *data part* (a `usize` instance).
@@ -749,11 +2140,23 @@ impl<'a> ComputedDataSetMetadata<'a> {
             header_size
         };
 
+        // Only the last *record* variable, and the last *fixed-size* variable if there is no *record* variable, can exceed the maximum chunk size of the version
+        let last_fixed_size_var_pos: Option<usize> = partitioned_vars.iter().rev().find(|(_var_pos, var): &&(usize, &Variable)| !var.is_record_var()).map(|(var_pos, _var)| *var_pos);
+        let last_record_var_pos: Option<usize> = partitioned_vars.iter().rev().find(|(_var_pos, var): &&(usize, &Variable)| var.is_record_var()).map(|(var_pos, _var)| *var_pos);
+
         // Compute the metadata for each variable
         let mut begin_offset: usize = header_size;
         let mut vars_metadata: Vec<(usize, (&Variable, ComputedVariableMetadata))> = vec![];
         for (header_part_pos, var) in partitioned_vars.into_iter() {
             let chunk_size: usize = var.chunk_size();
+            let is_last_var: bool = (last_record_var_pos.is_none() && Some(header_part_pos) == last_fixed_size_var_pos) || Some(header_part_pos) == last_record_var_pos;
+            if !is_last_var {
+                match version {
+                    Version::Classic if chunk_size > ComputedDataSetMetadata::CLASSIC_MAX_CHUNK_SIZE => return Err(WriteError::ClassicVersionNotPossible),
+                    Version::Offset64Bit if chunk_size > ComputedDataSetMetadata::OFFSET_64_BIT_MAX_CHUNK_SIZE => return Err(WriteError::Offset64BitVersionNotPossible),
+                    _ => {},
+                }
+            }
             vars_metadata.push((
                 header_part_pos,
                 (
@@ -766,7 +2169,7 @@ impl<'a> ComputedDataSetMetadata<'a> {
                                 let offset: i32 = i32::try_from(begin_offset).map_err(|_err| WriteError::ClassicVersionNotPossible)?;
                                 Offset::I32(offset)
                             },
-                            Version::Offset64Bit => {
+                            Version::Offset64Bit | Version::Cdf5 => {
                                 Offset::I64(begin_offset as i64)
                             }
                         },
@@ -792,10 +2195,12 @@ impl<'a> ComputedDataSetMetadata<'a> {
     /// Computes and returns the size (number of bytes) needed to write the file header.
     fn compute_header_required_size(data_set: &'a DataSet, version: Version) -> usize
     {
-        fn compute_name_string_size(name: &str) -> usize {
+        // the size of the non-negative integers (sizes, counts, dimension IDs, ...)
+        let non_neg_size: usize = version.non_neg_size();
+        let compute_name_string_size = |name: &str| -> usize {
             let mut num_bytes: usize = 0;
             // the number bytes for the name
-            num_bytes += std::mem::size_of::<i32>();
+            num_bytes += non_neg_size;
             // the bytes of the name
             let num_bytes_name = name.as_bytes().len();
             num_bytes += num_bytes_name;
@@ -803,25 +2208,25 @@ impl<'a> ComputedDataSetMetadata<'a> {
             num_bytes += compute_padding_size(num_bytes_name);
 
             return num_bytes;
-        }
-        fn compute_attrs_list_size(attrs_list: &[Attribute]) -> usize {
+        };
+        let compute_attrs_list_size = |attrs_list: &[Attribute]| -> usize {
             let mut num_bytes: usize = 0;
             // the global attributes
             if attrs_list.is_empty() {
-                num_bytes += ABSENT_TAG.len();
+                num_bytes += absent_tag(&version).len();
             }
             else {
                 // the tag `ATTRIBUTE_TAG`
                 num_bytes += ATTRIBUTE_TAG.len();
                 // the number of attributes
-                num_bytes += std::mem::size_of::<i32>();
+                num_bytes += non_neg_size;
                 for attr in attrs_list.iter() {
                     // the name of the attributes
                     num_bytes += compute_name_string_size(&attr.name);
                     // the attribute data type
                     num_bytes += std::mem::size_of::<i32>();
                     // the number of elements
-                    num_bytes += std::mem::size_of::<i32>();
+                    num_bytes += non_neg_size;
                     // the ttribute data
                     let num_useful_bytes = attr.len() * attr.data_type().size_of();
                     num_bytes += num_useful_bytes;
@@ -830,61 +2235,58 @@ impl<'a> ComputedDataSetMetadata<'a> {
                 }
             }
             return num_bytes;
-        }
+        };
         let mut num_bytes = 0;
         // the magic word `"CDF"`
         num_bytes += 3;
         // the version number
         num_bytes += std::mem::size_of::<u8>();
         // the length of the *unlimited-size* dimension
-        num_bytes += std::mem::size_of::<i32>();
+        num_bytes += non_neg_size;
         // the dimensions list
         if data_set.dims.is_empty() {
             // the tag `ABSENT_TAG`
-            num_bytes += ABSENT_TAG.len();
+            num_bytes += absent_tag(&version).len();
         }
         else {
             // the tag `DIMENSION_TAG`
             num_bytes += DIMENSION_TAG.len();
             // the number of dimensions
-            num_bytes += std::mem::size_of::<i32>();
+            num_bytes += non_neg_size;
             for dim in data_set.dims.iter() {
                 // the name of the dimension
                 num_bytes += compute_name_string_size(&dim.name.borrow());
                 // the size og the dimension
-                num_bytes += std::mem::size_of::<i32>();
+                num_bytes += non_neg_size;
             }
         }
         // the global attributes
         num_bytes += compute_attrs_list_size(&data_set.attrs);
         // the variables list
         if data_set.vars.is_empty() {
-            num_bytes += ABSENT_TAG.len();
+            num_bytes += absent_tag(&version).len();
         }
         else {
             num_bytes += VARIABLE_TAG.len();
             // the number of variables
-            num_bytes += std::mem::size_of::<i32>();
+            num_bytes += non_neg_size;
             for var in data_set.vars.iter() {
                 // the variable name
                 num_bytes += compute_name_string_size(&var.name);
                 // the number of dimensions
-                num_bytes += std::mem::size_of::<i32>();
+                num_bytes += non_neg_size;
                 // the ID of each dimension of the variable
-                num_bytes += var.num_dims() * std::mem::size_of::<i32>();
+                num_bytes += var.num_dims() * non_neg_size;
                 // the list of variable attributes
                 num_bytes += compute_attrs_list_size(&var.attrs);
                 // the variables data type
                 num_bytes += std::mem::size_of::<i32>();
                 // the number of bytes required each chunck
-                num_bytes += std::mem::size_of::<i32>();
+                num_bytes += non_neg_size;
                 // the begin offset depends of the NetCDF-3 version
-                num_bytes += match version {
-                    Version::Classic => std::mem::size_of::<i32>(),
-                    Version::Offset64Bit => std::mem::size_of::<i64>(),
-                }
+                num_bytes += version.offset_size();
             }
         }
         return num_bytes;
     }
-}
\ No newline at end of file
+}
@@ -5,6 +5,8 @@ use crate::{
     FileReader,
 };
 
+use crate::error::WriteError;
+
 use super::ComputedDataSetMetadata;
 
 use copy_to_tmp_file::{
@@ -28,3 +30,47 @@ fn test_compute_header_required_size() {
     let header_size: usize = ComputedDataSetMetadata::compute_header_required_size(&data_set, version);
     assert_eq!(EXPECTED_HEADER_SIZE,        header_size);
 }
+
+#[test]
+fn test_computed_data_set_metadata_version_limits() {
+    // 3 GiB per variable
+    const DIM_SIZE: usize = 3 * 1024 * 1024 * 1024 / 8;
+
+    // Only one large variable : valid for all the versions
+    let mut data_set: DataSet = DataSet::new();
+    data_set.add_fixed_dim("dim_1", DIM_SIZE).unwrap();
+    data_set.add_var_f64("var_1", &["dim_1"]).unwrap();
+    assert!(ComputedDataSetMetadata::new(&data_set, Version::Classic, 0).is_ok());
+    assert!(ComputedDataSetMetadata::new(&data_set, Version::Offset64Bit, 0).is_ok());
+    assert!(ComputedDataSetMetadata::new(&data_set, Version::Cdf5, 0).is_ok());
+
+    // Two large variables : the begin offset of the second one exceeds the classic limit
+    data_set.add_var_f64("var_2", &["dim_1"]).unwrap();
+    assert_eq!(WriteError::ClassicVersionNotPossible,       ComputedDataSetMetadata::new(&data_set, Version::Classic, 0).unwrap_err());
+    assert!(ComputedDataSetMetadata::new(&data_set, Version::Offset64Bit, 0).is_ok());
+    assert!(ComputedDataSetMetadata::new(&data_set, Version::Cdf5, 0).is_ok());
+
+    // A 6 GiB variable followed by another variable : only valid for the CDF-5 version
+    data_set.add_fixed_dim("dim_2", 2).unwrap();
+    data_set.add_var_f64("var_3", &["dim_2", "dim_1"]).unwrap();
+    data_set.add_var_f64("var_4", &["dim_2"]).unwrap();
+    assert_eq!(WriteError::ClassicVersionNotPossible,       ComputedDataSetMetadata::new(&data_set, Version::Classic, 0).unwrap_err());
+    assert_eq!(WriteError::Offset64BitVersionNotPossible,   ComputedDataSetMetadata::new(&data_set, Version::Offset64Bit, 0).unwrap_err());
+    assert!(ComputedDataSetMetadata::new(&data_set, Version::Cdf5, 0).is_ok());
+}
+
+#[test]
+fn test_computed_data_set_metadata_last_fixed_size_var_limits() {
+    // 3 GiB
+    const DIM_SIZE: usize = 3 * 1024 * 1024 * 1024 / 8;
+
+    // The last *fixed-size* variable can exceed the classic limit only if there is no *record* variable
+    let mut data_set: DataSet = DataSet::new();
+    data_set.add_fixed_dim("dim_1", DIM_SIZE).unwrap();
+    data_set.add_var_f64("var_1", &["dim_1"]).unwrap();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_var_f64("time", &["time"]).unwrap();
+    assert_eq!(WriteError::ClassicVersionNotPossible,       ComputedDataSetMetadata::new(&data_set, Version::Classic, 0).unwrap_err());
+    assert!(ComputedDataSetMetadata::new(&data_set, Version::Offset64Bit, 0).is_ok());
+    assert!(ComputedDataSetMetadata::new(&data_set, Version::Cdf5, 0).is_ok());
+}
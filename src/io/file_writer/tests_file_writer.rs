@@ -2,6 +2,7 @@
 use std::rc::Rc;
 use std::io::{Read, Cursor};
 use std::path::PathBuf;
+use std::time::Duration;
 
 use tempdir::TempDir;
 
@@ -11,17 +12,24 @@ use crate::Dimension;
 use crate::FileReader;
 use crate::Variable;
 use crate::DataType;
-use crate::error::WriteError;
+use crate::DataVector;
+use crate::error::{ReadError, WriteError, InvalidDataSet};
+use crate::NC_MAX_DIM_SIZE;
+use crate::Limits;
+use crate::InMemoryDataSet;
+use crate::{Timings, VarTimings};
+use crate::io::ABSENT_TAG;
 use crate::NC_FILL_I8;
 use crate::NC_FILL_U8;
 use crate::NC_FILL_I16;
 use crate::NC_FILL_I32;
 use crate::NC_FILL_F32;
 use crate::NC_FILL_F64;
+use crate::NC_FILL_U64;
 
 use super::{
     FileWriter, DataSet, Version,
-    ABSENT_TAG, DIMENSION_TAG,
+    DIMENSION_TAG,
 };
 
 const TMP_DIR_PREFIX: &str = "netcdf3_tests_";
@@ -1160,7 +1168,7 @@ fn test_write_dims_list() {
     {
         let bytes: Vec<u8> = {
             let mut bytes: Vec<u8> = vec![];
-            let _ = FileWriter::write_dims_list(&mut bytes, &[]).unwrap();
+            let _ = FileWriter::write_dims_list(&mut bytes, &[], &Version::Classic).unwrap();
             bytes
         };
 
@@ -1176,7 +1184,7 @@ fn test_write_dims_list() {
             let dim_1 = Rc::new(Dimension::new_fixed_size(DIM_NAME, DIM_SIZE).unwrap());
 
             let mut bytes: Vec<u8> = vec![];
-            let _ = FileWriter::write_dims_list(&mut bytes, &[dim_1]).unwrap();
+            let _ = FileWriter::write_dims_list(&mut bytes, &[dim_1], &Version::Classic).unwrap();
             Cursor::new(bytes)
         };
 
@@ -1211,7 +1219,7 @@ fn test_write_dims_list() {
             let dim_1 = Rc::new(Dimension::new_unlimited_size(DIM_NAME, DIM_SIZE).unwrap());
 
             let mut bytes: Vec<u8> = vec![];
-            let _ = FileWriter::write_dims_list(&mut bytes, &[dim_1]).unwrap();
+            let _ = FileWriter::write_dims_list(&mut bytes, &[dim_1], &Version::Classic).unwrap();
             Cursor::new(bytes)
         };
 
@@ -1246,7 +1254,7 @@ fn test_write_name_string() {
     {
         let mut cursor: Cursor<Vec<u8>> = {
             let mut bytes: Vec<u8> = vec![];
-            FileWriter::write_name_string(&mut bytes, "a").unwrap();
+            FileWriter::write_name_string(&mut bytes, "a", &Version::Classic).unwrap();
             Cursor::new(bytes)
         };
 
@@ -1262,7 +1270,7 @@ fn test_write_name_string() {
     {
         let mut cursor: Cursor<Vec<u8>> = {
             let mut bytes: Vec<u8> = vec![];
-            FileWriter::write_name_string(&mut bytes, "abcd").unwrap();
+            FileWriter::write_name_string(&mut bytes, "abcd", &Version::Classic).unwrap();
             Cursor::new(bytes)
         };
 
@@ -1278,7 +1286,7 @@ fn test_write_name_string() {
     {
         let mut cursor: Cursor<Vec<u8>> = {
             let mut bytes: Vec<u8> = vec![];
-            FileWriter::write_name_string(&mut bytes, "abcde").unwrap();
+            FileWriter::write_name_string(&mut bytes, "abcde", &Version::Classic).unwrap();
             Cursor::new(bytes)
         };
 
@@ -1298,7 +1306,7 @@ fn test_write_name_string() {
     {
         let mut cursor: Cursor<Vec<u8>> = {
             let mut bytes: Vec<u8> = vec![];
-            FileWriter::write_name_string(&mut bytes, "café").unwrap();
+            FileWriter::write_name_string(&mut bytes, "café", &Version::Classic).unwrap();
             Cursor::new(bytes)
         };
 
@@ -2081,4 +2089,1329 @@ fn test_write_var_f64_errors() {
     }
 
     tmp_dir.close().unwrap();
-}
\ No newline at end of file
+}
+#[test]
+fn test_write_element_i8() {
+    const TEST_FILE_NAME: &str = "test_write_element_i8.nc";
+
+    const VAR_I8_NAME: &str = "var_i8";
+    const RECORD_1_I8_DATA: [i8; FIXED_DIM_SIZE] = [1, 2, 3, 4];
+
+    const UNLIM_DIM_NAME: &str = "unlimited_dim";
+    const UNLIM_DIM_SIZE: usize = 3;
+    const FIXED_DIM_NAME: &str = "fixed_dim";
+    const FIXED_DIM_SIZE: usize = 4;
+
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let test_file_path: PathBuf = tmp_dir.path().join(TEST_FILE_NAME);
+    assert_eq!(false,               test_file_path.exists());
+
+    // First create and write a new NetCDF-3 file
+    {
+        let mut file_writer: FileWriter = FileWriter::create_new(&test_file_path).unwrap();
+        let mut data_set = DataSet::new();
+        data_set.set_unlimited_dim(UNLIM_DIM_NAME, UNLIM_DIM_SIZE).unwrap();
+        data_set.add_fixed_dim(FIXED_DIM_NAME, FIXED_DIM_SIZE).unwrap();
+        data_set.add_var_i8(VAR_I8_NAME, &[UNLIM_DIM_NAME, FIXED_DIM_NAME]).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        // Patch an element of the written record #1
+        file_writer.write_record_i8(VAR_I8_NAME, 1, &RECORD_1_I8_DATA).unwrap();
+        file_writer.write_element_i8(VAR_I8_NAME, &[1, 2], 42).unwrap();
+        // Write an element in the unwritten record #2
+        file_writer.write_element_i8(VAR_I8_NAME, &[2, 0], 43).unwrap();
+        file_writer.close().unwrap();
+    }
+    assert_eq!(true,                        test_file_path.exists());
+
+    // Then read the outlet file
+    {
+        let mut file_reader: FileReader = FileReader::open(test_file_path).unwrap();
+        assert_eq!(Ok(vec![NC_FILL_I8; FIXED_DIM_SIZE]),            file_reader.read_record_i8(VAR_I8_NAME, 0));
+        assert_eq!(Ok(vec![1, 2, 42, 4]),                           file_reader.read_record_i8(VAR_I8_NAME, 1));
+        assert_eq!(Ok(vec![43, NC_FILL_I8, NC_FILL_I8, NC_FILL_I8]), file_reader.read_record_i8(VAR_I8_NAME, 2));
+        file_reader.close();
+    }
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_write_element_all_types() {
+    const TEST_FILE_NAME: &str = "test_write_element_all_types.nc";
+
+    const VAR_I8_NAME: &str = "var_i8";
+    const VAR_U8_NAME: &str = "var_u8";
+    const VAR_I16_NAME: &str = "var_i16";
+    const VAR_I32_NAME: &str = "var_i32";
+    const VAR_F32_NAME: &str = "var_f32";
+    const VAR_F64_NAME: &str = "var_f64";
+
+    const FIXED_DIM_NAME_1: &str = "fixed_dim_1";
+    const FIXED_DIM_SIZE_1: usize = 2;
+    const FIXED_DIM_NAME_2: &str = "fixed_dim_2";
+    const FIXED_DIM_SIZE_2: usize = 3;
+
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let test_file_path: PathBuf = tmp_dir.path().join(TEST_FILE_NAME);
+
+    // Write only the element `[1, 2]` of each *fixed-size* variable
+    {
+        let mut file_writer: FileWriter = FileWriter::create_new(&test_file_path).unwrap();
+        let mut data_set = DataSet::new();
+        data_set.add_fixed_dim(FIXED_DIM_NAME_1, FIXED_DIM_SIZE_1).unwrap();
+        data_set.add_fixed_dim(FIXED_DIM_NAME_2, FIXED_DIM_SIZE_2).unwrap();
+        data_set.add_var_i8(VAR_I8_NAME, &[FIXED_DIM_NAME_1, FIXED_DIM_NAME_2]).unwrap();
+        data_set.add_var_u8(VAR_U8_NAME, &[FIXED_DIM_NAME_1, FIXED_DIM_NAME_2]).unwrap();
+        data_set.add_var_i16(VAR_I16_NAME, &[FIXED_DIM_NAME_1, FIXED_DIM_NAME_2]).unwrap();
+        data_set.add_var_i32(VAR_I32_NAME, &[FIXED_DIM_NAME_1, FIXED_DIM_NAME_2]).unwrap();
+        data_set.add_var_f32(VAR_F32_NAME, &[FIXED_DIM_NAME_1, FIXED_DIM_NAME_2]).unwrap();
+        data_set.add_var_f64(VAR_F64_NAME, &[FIXED_DIM_NAME_1, FIXED_DIM_NAME_2]).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        file_writer.write_element_i8(VAR_I8_NAME, &[1, 2], 1).unwrap();
+        file_writer.write_element_u8(VAR_U8_NAME, &[1, 2], 2).unwrap();
+        file_writer.write_element_i16(VAR_I16_NAME, &[1, 2], 3).unwrap();
+        file_writer.write_element_i32(VAR_I32_NAME, &[1, 2], 4).unwrap();
+        file_writer.write_element_f32(VAR_F32_NAME, &[1, 2], 5.0).unwrap();
+        file_writer.write_element_f64(VAR_F64_NAME, &[1, 2], 6.0).unwrap();
+        file_writer.close().unwrap();
+    }
+
+    // Then read the outlet file
+    {
+        let mut file_reader: FileReader = FileReader::open(test_file_path).unwrap();
+        assert_eq!(Ok(vec![NC_FILL_I8, NC_FILL_I8, NC_FILL_I8, NC_FILL_I8, NC_FILL_I8, 1]),              file_reader.read_var_i8(VAR_I8_NAME));
+        assert_eq!(Ok(vec![NC_FILL_U8, NC_FILL_U8, NC_FILL_U8, NC_FILL_U8, NC_FILL_U8, 2]),              file_reader.read_var_u8(VAR_U8_NAME));
+        assert_eq!(Ok(vec![NC_FILL_I16, NC_FILL_I16, NC_FILL_I16, NC_FILL_I16, NC_FILL_I16, 3]),         file_reader.read_var_i16(VAR_I16_NAME));
+        assert_eq!(Ok(vec![NC_FILL_I32, NC_FILL_I32, NC_FILL_I32, NC_FILL_I32, NC_FILL_I32, 4]),         file_reader.read_var_i32(VAR_I32_NAME));
+        assert_eq!(Ok(vec![NC_FILL_F32, NC_FILL_F32, NC_FILL_F32, NC_FILL_F32, NC_FILL_F32, 5.0]),       file_reader.read_var_f32(VAR_F32_NAME));
+        assert_eq!(Ok(vec![NC_FILL_F64, NC_FILL_F64, NC_FILL_F64, NC_FILL_F64, NC_FILL_F64, 6.0]),       file_reader.read_var_f64(VAR_F64_NAME));
+        file_reader.close();
+    }
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_write_element_errors() {
+    const TEST_FILE_NAME: &str = "test_write_element_errors.nc";
+
+    const VAR_I8_NAME: &str = "var_i8";
+
+    const UNLIM_DIM_NAME: &str = "unlimited_dim";
+    const UNLIM_DIM_SIZE: usize = 3;
+    const FIXED_DIM_NAME: &str = "fixed_dim";
+    const FIXED_DIM_SIZE: usize = 4;
+
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let test_file_path: PathBuf = tmp_dir.path().join(TEST_FILE_NAME);
+
+    let mut data_set = DataSet::new();
+    data_set.set_unlimited_dim(UNLIM_DIM_NAME, UNLIM_DIM_SIZE).unwrap();
+    data_set.add_fixed_dim(FIXED_DIM_NAME, FIXED_DIM_SIZE).unwrap();
+    data_set.add_var_i8(VAR_I8_NAME, &[UNLIM_DIM_NAME, FIXED_DIM_NAME]).unwrap();
+
+    let mut file_writer: FileWriter = FileWriter::create_new(&test_file_path).unwrap();
+    assert_eq!(
+        WriteError::HeaderNotDefined,
+        file_writer.write_element_i8(VAR_I8_NAME, &[0, 0], 0).unwrap_err()
+    );
+    file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    assert_eq!(
+        WriteError::VariableNotDefined(String::from("undef_var")),
+        file_writer.write_element_i8("undef_var", &[0, 0], 0).unwrap_err()
+    );
+    assert_eq!(
+        WriteError::VariableMismatchDataType{var_name: String::from(VAR_I8_NAME), req: DataType::I8, get: DataType::U8},
+        file_writer.write_element_u8(VAR_I8_NAME, &[0, 0], 0).unwrap_err()
+    );
+    assert_eq!(
+        WriteError::ElementIndexNotValid{var_name: String::from(VAR_I8_NAME), index: vec![3, 0], shape: vec![UNLIM_DIM_SIZE, FIXED_DIM_SIZE]},
+        file_writer.write_element_i8(VAR_I8_NAME, &[3, 0], 0).unwrap_err()
+    );
+    assert_eq!(
+        WriteError::ElementIndexNotValid{var_name: String::from(VAR_I8_NAME), index: vec![0], shape: vec![UNLIM_DIM_SIZE, FIXED_DIM_SIZE]},
+        file_writer.write_element_i8(VAR_I8_NAME, &[0], 0).unwrap_err()
+    );
+    file_writer.close().unwrap();
+
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_open_existing() {
+    const TEST_FILE_NAME: &str = "test_open_existing.nc";
+
+    const VAR_I16_NAME: &str = "var_i16";
+    const VAR_I16_DATA: [i16; 6] = [1, 2, 3, 4, 5, 6];
+
+    const UNLIM_DIM_NAME: &str = "unlimited_dim";
+    const UNLIM_DIM_SIZE: usize = 2;
+    const FIXED_DIM_NAME: &str = "fixed_dim";
+    const FIXED_DIM_SIZE: usize = 3;
+
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let test_file_path: PathBuf = tmp_dir.path().join(TEST_FILE_NAME);
+
+    // First create and write a new NetCDF-3 file, with a large header
+    let mut data_set = DataSet::new();
+    data_set.set_unlimited_dim(UNLIM_DIM_NAME, UNLIM_DIM_SIZE).unwrap();
+    data_set.add_fixed_dim(FIXED_DIM_NAME, FIXED_DIM_SIZE).unwrap();
+    data_set.add_var_i16(VAR_I16_NAME, &[UNLIM_DIM_NAME, FIXED_DIM_NAME]).unwrap();
+    {
+        let mut file_writer: FileWriter = FileWriter::create_new(&test_file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Offset64Bit, 1024).unwrap();
+        file_writer.write_var_i16(VAR_I16_NAME, &VAR_I16_DATA).unwrap();
+        file_writer.close().unwrap();
+    }
+    let file_size: u64 = std::fs::metadata(&test_file_path).unwrap().len();
+
+    // Patch one element of the existing file
+    let (data_set_2, version): (DataSet, Version) = FileReader::open(&test_file_path).unwrap().close();
+    assert_eq!(Version::Offset64Bit,                version);
+    {
+        let mut file_writer: FileWriter = FileWriter::open_existing(&test_file_path, &data_set_2).unwrap();
+        assert_eq!(Some(Version::Offset64Bit),      file_writer.version());
+        assert_eq!(Some(1024),                      file_writer.header_min_size());
+        file_writer.write_element_i16(VAR_I16_NAME, &[1, 1], 42).unwrap();
+        file_writer.close().unwrap();
+    }
+    assert_eq!(file_size,                           std::fs::metadata(&test_file_path).unwrap().len());
+
+    // Check the data
+    {
+        let mut file_reader: FileReader = FileReader::open(&test_file_path).unwrap();
+        assert_eq!(&data_set,                       file_reader.data_set());
+        assert_eq!(Ok(vec![1, 2, 3, 4, 42, 6]),     file_reader.read_var_i16(VAR_I16_NAME));
+        file_reader.close();
+    }
+
+    // The implementation limits of the data set are not compared
+    {
+        let mut data_set_4 = DataSet::with_limits(Limits{max_name_size: 64, ..Limits::default()});
+        data_set_4.set_unlimited_dim(UNLIM_DIM_NAME, UNLIM_DIM_SIZE).unwrap();
+        data_set_4.add_fixed_dim(FIXED_DIM_NAME, FIXED_DIM_SIZE).unwrap();
+        data_set_4.add_var_i16(VAR_I16_NAME, &[UNLIM_DIM_NAME, FIXED_DIM_NAME]).unwrap();
+        let file_writer: FileWriter = FileWriter::open_existing(&test_file_path, &data_set_4).unwrap();
+        assert_eq!(Some(Version::Offset64Bit),      file_writer.version());
+        file_writer.close().unwrap();
+    }
+
+    // The data set must match the header of the file
+    {
+        let mut data_set_3 = DataSet::new();
+        data_set_3.set_unlimited_dim(UNLIM_DIM_NAME, UNLIM_DIM_SIZE).unwrap();
+        assert_eq!(
+            WriteError::DataSetMismatch,
+            FileWriter::open_existing(&test_file_path, &data_set_3).unwrap_err()
+        );
+    }
+
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_write_var_slice() {
+    const TEST_FILE_NAME: &str = "test_write_var_slice.nc";
+
+    const VAR_I32_NAME: &str = "var_i32";
+    const VAR_F64_NAME: &str = "var_f64";
+
+    const UNLIM_DIM_NAME: &str = "unlimited_dim";
+    const UNLIM_DIM_SIZE: usize = 3;
+    const FIXED_DIM_NAME: &str = "fixed_dim";
+    const FIXED_DIM_SIZE: usize = 4;
+
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let test_file_path: PathBuf = tmp_dir.path().join(TEST_FILE_NAME);
+
+    let mut data_set = DataSet::new();
+    data_set.set_unlimited_dim(UNLIM_DIM_NAME, UNLIM_DIM_SIZE).unwrap();
+    data_set.add_fixed_dim(FIXED_DIM_NAME, FIXED_DIM_SIZE).unwrap();
+    data_set.add_var_i32(VAR_I32_NAME, &[UNLIM_DIM_NAME, FIXED_DIM_NAME]).unwrap();
+    data_set.add_var_f64(VAR_F64_NAME, &[FIXED_DIM_NAME]).unwrap();
+
+    // First create and write a new NetCDF-3 file
+    {
+        let mut file_writer: FileWriter = FileWriter::create_new(&test_file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        // Write the columns `1..3` of the records `1..3`
+        file_writer.write_var_slice(VAR_I32_NAME, &[1, 1], &[2, 2], &DataVector::I32(vec![1, 2, 3, 4])).unwrap();
+        file_writer.write_var_slice(VAR_F64_NAME, &[2], &[2], &DataVector::F64(vec![1.0, 2.0])).unwrap();
+
+        // Errors
+        assert_eq!(
+            WriteError::VariableMismatchDataType{var_name: String::from(VAR_I32_NAME), req: DataType::I32, get: DataType::F64},
+            file_writer.write_var_slice(VAR_I32_NAME, &[0, 0], &[1, 1], &DataVector::F64(vec![1.0])).unwrap_err()
+        );
+        assert_eq!(
+            WriteError::VariableMismatchDataLength{var_name: String::from(VAR_I32_NAME), req: 2, get: 1},
+            file_writer.write_var_slice(VAR_I32_NAME, &[0, 0], &[1, 2], &DataVector::I32(vec![1])).unwrap_err()
+        );
+        assert_eq!(
+            WriteError::SliceNotValid{var_name: String::from(VAR_I32_NAME), start: vec![0, 3], count: vec![1, 2], shape: vec![UNLIM_DIM_SIZE, FIXED_DIM_SIZE]},
+            file_writer.write_var_slice(VAR_I32_NAME, &[0, 3], &[1, 2], &DataVector::I32(vec![1, 2])).unwrap_err()
+        );
+        assert_eq!(
+            WriteError::VariableNotDefined(String::from("undef_var")),
+            file_writer.write_var_slice("undef_var", &[0], &[1], &DataVector::I32(vec![1])).unwrap_err()
+        );
+        file_writer.close().unwrap();
+    }
+
+    // Then read the outlet file
+    {
+        let mut file_reader: FileReader = FileReader::open(&test_file_path).unwrap();
+        assert_eq!(Ok(vec![NC_FILL_I32; FIXED_DIM_SIZE]),                   file_reader.read_record_i32(VAR_I32_NAME, 0));
+        assert_eq!(Ok(vec![NC_FILL_I32, 1, 2, NC_FILL_I32]),                file_reader.read_record_i32(VAR_I32_NAME, 1));
+        assert_eq!(Ok(vec![NC_FILL_I32, 3, 4, NC_FILL_I32]),                file_reader.read_record_i32(VAR_I32_NAME, 2));
+        assert_eq!(Ok(vec![NC_FILL_F64, NC_FILL_F64, 1.0, 2.0]),            file_reader.read_var_f64(VAR_F64_NAME));
+        file_reader.close();
+    }
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_push_record() {
+    const TEST_FILE_NAME: &str = "test_push_record.nc";
+
+    const VAR_I8_NAME: &str = "var_i8";
+    const VAR_F64_NAME: &str = "var_f64";
+    const FIXED_VAR_NAME: &str = "fixed_var";
+
+    const UNLIM_DIM_NAME: &str = "unlimited_dim";
+    const FIXED_DIM_NAME: &str = "fixed_dim";
+    const FIXED_DIM_SIZE: usize = 2;
+
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let test_file_path: PathBuf = tmp_dir.path().join(TEST_FILE_NAME);
+
+    // The *unlimited-size* dimension is initially empty
+    let mut data_set = DataSet::new();
+    data_set.set_unlimited_dim(UNLIM_DIM_NAME, 0).unwrap();
+    data_set.add_fixed_dim(FIXED_DIM_NAME, FIXED_DIM_SIZE).unwrap();
+    data_set.add_var_i8(VAR_I8_NAME, &[UNLIM_DIM_NAME, FIXED_DIM_NAME]).unwrap();
+    data_set.add_var_f64(VAR_F64_NAME, &[UNLIM_DIM_NAME]).unwrap();
+    data_set.add_var_i8(FIXED_VAR_NAME, &[FIXED_DIM_NAME]).unwrap();
+    {
+        let mut file_writer: FileWriter = FileWriter::create_new(&test_file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        assert_eq!(Some(0),                 data_set.num_records());
+
+        assert_eq!(Ok(0),                   file_writer.push_record_i8(VAR_I8_NAME, &[1, 2]));
+        assert_eq!(Ok(1),                   file_writer.push_record_i8(VAR_I8_NAME, &[3, 4]));
+        assert_eq!(Some(2),                 data_set.num_records());
+        // The other record variables do not grow the *unlimited-size* dimension while they are behind
+        assert_eq!(Ok(0),                   file_writer.push_record_f64(VAR_F64_NAME, &[1.0]));
+        assert_eq!(Some(2),                 data_set.num_records());
+        // The written records are taken into account
+        file_writer.write_record_f64(VAR_F64_NAME, 1, &[2.0]).unwrap();
+        assert_eq!(Ok(2),                   file_writer.push_record_f64(VAR_F64_NAME, &[3.0]));
+        assert_eq!(Some(3),                 data_set.num_records());
+
+        // Errors
+        assert_eq!(
+            WriteError::RecordMismatchDataLength{var_name: String::from(VAR_I8_NAME), req: FIXED_DIM_SIZE, get: 3},
+            file_writer.push_record_i8(VAR_I8_NAME, &[1, 2, 3]).unwrap_err()
+        );
+        assert_eq!(
+            WriteError::VariableMismatchDataType{var_name: String::from(VAR_I8_NAME), req: DataType::I8, get: DataType::U8},
+            file_writer.push_record_u8(VAR_I8_NAME, &[1, 2]).unwrap_err()
+        );
+        assert_eq!(
+            WriteError::NotRecordVariable(String::from(FIXED_VAR_NAME)),
+            file_writer.push_record_i8(FIXED_VAR_NAME, &[1, 2]).unwrap_err()
+        );
+        assert_eq!(
+            WriteError::VariableNotDefined(String::from("undef_var")),
+            file_writer.push_record_i8("undef_var", &[1, 2]).unwrap_err()
+        );
+        assert_eq!(Some(3),                 data_set.num_records());
+        file_writer.close().unwrap();
+    }
+
+    // Then read the outlet file
+    {
+        let mut file_reader: FileReader = FileReader::open(&test_file_path).unwrap();
+        assert_eq!(Some(3),                                             file_reader.data_set().num_records());
+        assert_eq!(Ok(vec![1, 2, 3, 4, NC_FILL_I8, NC_FILL_I8]),        file_reader.read_var_i8(VAR_I8_NAME));
+        assert_eq!(Ok(vec![1.0, 2.0, 3.0]),                             file_reader.read_var_f64(VAR_F64_NAME));
+        assert_eq!(Ok(vec![NC_FILL_I8, NC_FILL_I8]),                    file_reader.read_var_i8(FIXED_VAR_NAME));
+        file_reader.close();
+    }
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_write_cdf5() {
+    const TEST_FILE_NAME: &str = "test_write_cdf5.nc";
+
+    const VAR_U16_NAME: &str = "var_u16";
+    const VAR_U32_NAME: &str = "var_u32";
+    const VAR_I64_NAME: &str = "var_i64";
+    const VAR_U64_NAME: &str = "var_u64";
+    const VAR_F32_NAME: &str = "var_f32";
+
+    const UNLIM_DIM_NAME: &str = "unlimited_dim";
+    const FIXED_DIM_NAME: &str = "fixed_dim";
+    const FIXED_DIM_SIZE: usize = 3;
+
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let test_file_path: PathBuf = tmp_dir.path().join(TEST_FILE_NAME);
+
+    let mut data_set = DataSet::new();
+    data_set.set_unlimited_dim(UNLIM_DIM_NAME, 0).unwrap();
+    data_set.add_fixed_dim(FIXED_DIM_NAME, FIXED_DIM_SIZE).unwrap();
+    data_set.add_var_u16(VAR_U16_NAME, &[FIXED_DIM_NAME]).unwrap();
+    data_set.add_var_u32(VAR_U32_NAME, &[FIXED_DIM_NAME]).unwrap();
+    data_set.add_var_i64(VAR_I64_NAME, &[UNLIM_DIM_NAME, FIXED_DIM_NAME]).unwrap();
+    data_set.add_var_u64(VAR_U64_NAME, &[FIXED_DIM_NAME]).unwrap();
+    data_set.add_var_f32(VAR_F32_NAME, &[UNLIM_DIM_NAME]).unwrap();
+    data_set.add_var_attr_u16(VAR_U16_NAME, "attr_u16", vec![1, 2, 3]).unwrap();
+    data_set.add_global_attr_u64("attr_u64", vec![u64::MAX]).unwrap();
+    {
+        let mut file_writer: FileWriter = FileWriter::create_new(&test_file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Cdf5, 0).unwrap();
+        file_writer.write_var_u16(VAR_U16_NAME, &[1, 2, u16::MAX]).unwrap();
+        file_writer.write_var_u32(VAR_U32_NAME, &[1, 2, u32::MAX]).unwrap();
+        file_writer.push_record_i64(VAR_I64_NAME, &[i64::MIN, 0, i64::MAX]).unwrap();
+        file_writer.push_record_i64(VAR_I64_NAME, &[1, 2, 3]).unwrap();
+        file_writer.write_record_f32(VAR_F32_NAME, 0, &[1.0]).unwrap();
+        file_writer.close().unwrap();
+    }
+
+    // Check the magic word, the version number and the 8-byte number of records
+    {
+        let mut cursor: Cursor<Vec<u8>> = Cursor::new(std::fs::read(&test_file_path).unwrap());
+        let mut buffer: Vec<u8> = vec![0_u8; 4];
+        cursor.read_exact(&mut buffer).unwrap();
+        assert_eq!(&b"CDF\x05"[..],             &buffer[..]);
+        assert_eq!(2,                           cursor.read_u64::<BigEndian>().unwrap());
+    }
+
+    // Then read the outlet file
+    {
+        let mut file_reader: FileReader = FileReader::open(&test_file_path).unwrap();
+        assert_eq!(Version::Cdf5,                                       file_reader.version());
+        assert_eq!(&data_set,                                           file_reader.data_set());
+        assert_eq!(Some(&[1_u16, 2, 3][..]),                            file_reader.data_set().get_var_attr_u16(VAR_U16_NAME, "attr_u16"));
+        assert_eq!(Some(&[u64::MAX][..]),                          file_reader.data_set().get_global_attr_u64("attr_u64"));
+        assert_eq!(Ok(vec![1, 2, u16::MAX]),                       file_reader.read_var_u16(VAR_U16_NAME));
+        assert_eq!(Ok(vec![1, 2, u32::MAX]),                       file_reader.read_var_u32(VAR_U32_NAME));
+        assert_eq!(Ok(vec![i64::MIN, 0, i64::MAX, 1, 2, 3]),  file_reader.read_var_i64(VAR_I64_NAME));
+        assert_eq!(Ok(vec![NC_FILL_U64; FIXED_DIM_SIZE]),               file_reader.read_var_u64(VAR_U64_NAME));
+        assert_eq!(Ok(vec![1.0, NC_FILL_F32]),                          file_reader.read_var_f32(VAR_F32_NAME));
+        file_reader.close();
+    }
+
+    // An empty data set
+    {
+        let test_file_path: PathBuf = tmp_dir.path().join("test_write_cdf5_empty.nc");
+        let empty_data_set = DataSet::new();
+        let mut file_writer: FileWriter = FileWriter::create_new(&test_file_path).unwrap();
+        file_writer.set_def(&empty_data_set, Version::Cdf5, 0).unwrap();
+        file_writer.close().unwrap();
+        // magic word + 8-byte number of records + 3 `ABSENT` tags of 12 bytes
+        assert_eq!(48,                          std::fs::metadata(&test_file_path).unwrap().len());
+        let (data_set_2, version): (DataSet, Version) = FileReader::open(&test_file_path).unwrap().close();
+        assert_eq!(Version::Cdf5,               version);
+        assert_eq!(empty_data_set,              data_set_2);
+    }
+
+    // The CDF-5 data types are not supported by the other versions
+    {
+        let test_file_path: PathBuf = tmp_dir.path().join("test_write_cdf5_classic.nc");
+        let mut file_writer: FileWriter = FileWriter::create_new(&test_file_path).unwrap();
+        assert_eq!(
+            WriteError::DataTypeNotSupported{data_type: DataType::U64, version: Version::Classic},
+            file_writer.set_def(&data_set, Version::Classic, 0).unwrap_err()
+        );
+        assert_eq!(
+            WriteError::DataTypeNotSupported{data_type: DataType::U64, version: Version::Offset64Bit},
+            file_writer.set_def(&data_set, Version::Offset64Bit, 0).unwrap_err()
+        );
+        assert_eq!(false,                       file_writer.header_is_defined());
+    }
+
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_set_def_auto() {
+    // 3 GiB per variable
+    const DIM_SIZE: usize = 3 * 1024 * 1024 * 1024 / 8;
+
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+
+    // A small data set is written with the classic version
+    let mut data_set: DataSet = DataSet::new();
+    data_set.add_fixed_dim("dim_1", DIM_SIZE).unwrap();
+    data_set.add_var_f64("var_1", &["dim_1"]).unwrap();
+    {
+        let mut file_writer: FileWriter = FileWriter::create_new(tmp_dir.path().join("test_set_def_auto_1.nc")).unwrap();
+        assert_eq!(Ok(Version::Classic),                        file_writer.set_def_auto(&data_set, Version::Cdf5, 0));
+        assert_eq!(Some(Version::Classic),                      file_writer.version());
+        assert_eq!(WriteError::HeaderAlreadyDefined,            file_writer.set_def_auto(&data_set, Version::Cdf5, 0).unwrap_err());
+    }
+
+    // The begin offsets exceed the limit of the classic version
+    data_set.add_var_f64("var_2", &["dim_1"]).unwrap();
+    {
+        let mut file_writer: FileWriter = FileWriter::create_new(tmp_dir.path().join("test_set_def_auto_2.nc")).unwrap();
+        assert_eq!(WriteError::ClassicVersionNotPossible,       file_writer.set_def_auto(&data_set, Version::Classic, 0).unwrap_err());
+        assert_eq!(WriteError::ClassicVersionNotPossible,       file_writer.set_def(&data_set, Version::Classic, 0).unwrap_err());
+        assert_eq!(false,                                       file_writer.header_is_defined());
+        assert_eq!(Ok(Version::Offset64Bit),                    file_writer.set_def_auto(&data_set, Version::Cdf5, 0));
+    }
+
+    // The chunk size of a variable exceeds the limit of the 64-bit offset version
+    data_set.add_fixed_dim("dim_2", 2).unwrap();
+    data_set.add_var_f64("var_3", &["dim_2", "dim_1"]).unwrap();
+    data_set.add_var_f64("var_4", &["dim_2"]).unwrap();
+    {
+        let mut file_writer: FileWriter = FileWriter::create_new(tmp_dir.path().join("test_set_def_auto_3.nc")).unwrap();
+        assert_eq!(WriteError::Offset64BitVersionNotPossible,   file_writer.set_def_auto(&data_set, Version::Offset64Bit, 0).unwrap_err());
+        assert_eq!(Ok(Version::Cdf5),                           file_writer.set_def_auto(&data_set, Version::Cdf5, 0));
+    }
+
+    // The last *fixed-size* variable exceeds the limit of the classic version, and is followed by a *record* variable
+    let mut data_set: DataSet = DataSet::new();
+    data_set.add_fixed_dim("dim_1", DIM_SIZE).unwrap();
+    data_set.add_var_f64("var_1", &["dim_1"]).unwrap();
+    data_set.set_unlimited_dim("time", 1).unwrap();
+    data_set.add_var_f64("time", &["time"]).unwrap();
+    {
+        let mut file_writer: FileWriter = FileWriter::create_new(tmp_dir.path().join("test_set_def_auto_4.nc")).unwrap();
+        assert_eq!(WriteError::ClassicVersionNotPossible,       file_writer.set_def(&data_set, Version::Classic, 0).unwrap_err());
+        assert_eq!(Ok(Version::Offset64Bit),                    file_writer.set_def_auto(&data_set, Version::Cdf5, 0));
+    }
+
+    // The header is not kept if it cannot be written
+    #[cfg(target_os = "linux")]
+    {
+        let mut file_writer: FileWriter = FileWriter::open("/dev/full").unwrap();
+        assert!(matches!(file_writer.set_def_auto(&data_set, Version::Cdf5, 0),  Err(WriteError::IOErrorKind(_))));
+        assert_eq!(false,                                       file_writer.header_is_defined());
+        assert_eq!(None,                                        file_writer.version());
+        assert!(matches!(file_writer.set_def_auto(&data_set, Version::Cdf5, 0),  Err(WriteError::IOErrorKind(_))));
+    }
+
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_set_streaming() {
+    const UNLIM_DIM_NAME: &str = "unlimited_dim";
+    const VAR_I32_NAME: &str = "var_i32";
+
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+
+    let mut data_set = DataSet::new();
+    data_set.set_unlimited_dim(UNLIM_DIM_NAME, 0).unwrap();
+    data_set.add_var_i32(VAR_I32_NAME, &[UNLIM_DIM_NAME]).unwrap();
+
+    // The streaming mode is enabled after the definition of the header
+    {
+        let test_file_path: PathBuf = tmp_dir.path().join("test_set_streaming_cdf5.nc");
+        let mut file_writer: FileWriter = FileWriter::create_new(&test_file_path).unwrap();
+        assert_eq!(false,                                   file_writer.is_streaming());
+        file_writer.set_def(&data_set, Version::Cdf5, 0).unwrap();
+        file_writer.push_record_i32(VAR_I32_NAME, &[1]).unwrap();
+        assert_eq!(&[0, 0, 0, 0, 0, 0, 0, 1],               &std::fs::read(&test_file_path).unwrap()[4..12]);
+
+        file_writer.set_streaming(true).unwrap();
+        assert_eq!(true,                                    file_writer.is_streaming());
+        assert_eq!(&[0xff; 8],                              &std::fs::read(&test_file_path).unwrap()[4..12]);
+        file_writer.push_record_i32(VAR_I32_NAME, &[2]).unwrap();
+        file_writer.push_record_i32(VAR_I32_NAME, &[3]).unwrap();
+        assert_eq!(&[0xff; 8],                              &std::fs::read(&test_file_path).unwrap()[4..12]);
+        {
+            let mut file_reader: FileReader = FileReader::open(&test_file_path).unwrap();
+            assert_eq!(Some(3),                             file_reader.data_set().num_records());
+            assert_eq!(Ok(vec![1, 2, 3]),                   file_reader.read_var_i32(VAR_I32_NAME));
+        }
+
+        // Disable the streaming mode
+        file_writer.set_streaming(false).unwrap();
+        assert_eq!(&[0, 0, 0, 0, 0, 0, 0, 3],               &std::fs::read(&test_file_path).unwrap()[4..12]);
+        file_writer.close().unwrap();
+    }
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_write_var_strings() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let test_file_path: PathBuf = tmp_dir.path().join("test_write_var_strings.nc");
+
+    let mut data_set = DataSet::new();
+    data_set.set_unlimited_dim("time", 1).unwrap();
+    data_set.add_fixed_dim("station", 3).unwrap();
+    data_set.add_string_var("station_name", &["station"], 4).unwrap();
+    data_set.add_string_var("comment", &["time"], 5).unwrap();
+    data_set.add_var_i8("station_id", &["station"]).unwrap();
+
+    let mut file_writer: FileWriter = FileWriter::open(&test_file_path).unwrap();
+    file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    // Padded and truncated strings, the 2-byte character `é` is not split
+    file_writer.write_var_strings("station_name", &["ab", "abcdef", "abcé"]).unwrap();
+    file_writer.write_var_strings("comment", &["été"]).unwrap();
+
+    // Errors
+    assert_eq!(
+        WriteError::VariableMismatchDataLength{var_name: String::from("station_name"), req: 3, get: 2},
+        file_writer.write_var_strings("station_name", &["a", "b"]).unwrap_err()
+    );
+    assert_eq!(
+        WriteError::VariableMismatchDataType{var_name: String::from("station_id"), req: DataType::I8, get: DataType::U8},
+        file_writer.write_var_strings("station_id", &["a", "b", "c"]).unwrap_err()
+    );
+    assert_eq!(
+        WriteError::VariableNotDefined(String::from("undef_var")),
+        file_writer.write_var_strings("undef_var", &[]).unwrap_err()
+    );
+    file_writer.close().unwrap();
+
+    let mut file_reader: FileReader = FileReader::open(&test_file_path).unwrap();
+    assert_eq!(Ok(b"ab\0\0abcdabc\0".to_vec()),                 file_reader.read_var_u8("station_name"));
+    assert_eq!(Ok("été".as_bytes().to_vec()),                   file_reader.read_var_u8("comment"));
+    assert_eq!(Ok(vec![String::from("ab"), String::from("abcd"), String::from("abc")]), file_reader.read_var_strings("station_name"));
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_header_and_file_size_estimate() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+
+    let mut data_set = DataSet::new();
+    data_set.set_unlimited_dim("time", 3).unwrap();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_fixed_dim("name_strlen", 5).unwrap();
+    data_set.add_global_attr_string("title", "Header and file sizes").unwrap();
+    data_set.add_var_i8("mask", &["latitude"]).unwrap();
+    data_set.add_var_attr_i16("mask", "valid_range", vec![0, 1, 2]).unwrap();
+    data_set.add_var_u8("name", &["name_strlen"]).unwrap();
+    data_set.add_var_f64("sst", &["time", "latitude"]).unwrap();
+    data_set.add_var_i16("flag", &["time"]).unwrap();
+
+    for (i, version) in [Version::Classic, Version::Offset64Bit, Version::Cdf5].iter().enumerate() {
+        let test_file_path: PathBuf = tmp_dir.path().join(format!("test_header_and_file_size_estimate_{}.nc", i));
+        let mut file_writer: FileWriter = FileWriter::open(&test_file_path).unwrap();
+        file_writer.set_def(&data_set, version.clone(), 0).unwrap();
+        file_writer.close().unwrap();
+
+        let file_size: usize = std::fs::metadata(&test_file_path).unwrap().len() as usize;
+        assert_eq!(Ok(file_size),                       data_set.file_size_estimate(version.clone()));
+
+        // The first variable begins at the end of the header
+        let file_reader: FileReader = FileReader::open(&test_file_path).unwrap();
+        let begin_offset: i64 = file_reader.var_begin_offset("mask").unwrap().into();
+        assert_eq!(begin_offset as usize,               data_set.header_size(version.clone()));
+    }
+
+    // The `u64` data type is only supported by the CDF-5 version
+    data_set.add_var_u64("count", &["latitude"]).unwrap();
+    assert_eq!(
+        Err(WriteError::DataTypeNotSupported{data_type: DataType::U64, version: Version::Classic}),
+        data_set.file_size_estimate(Version::Classic)
+    );
+    assert_eq!(true,                                    data_set.file_size_estimate(Version::Cdf5).is_ok());
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_validate() {
+    // Empty data set
+    {
+        let data_set: DataSet = DataSet::new();
+        assert_eq!(Ok(()),                      data_set.validate(Version::Classic));
+        assert_eq!(Ok(()),                      data_set.validate(Version::Offset64Bit));
+        assert_eq!(Ok(()),                      data_set.validate(Version::Cdf5));
+    }
+
+    // Data types not supported by the version
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.add_fixed_dim("latitude", 3).unwrap();
+        data_set.add_var_i64("count", &["latitude"]).unwrap();
+        data_set.add_var_attr_u32("count", "valid_max", vec![10]).unwrap();
+
+        assert_eq!(Ok(()),                      data_set.validate(Version::Cdf5));
+        assert_eq!(
+            Err(vec![
+                InvalidDataSet::VariableDataTypeNotSupported{
+                    var_name: String::from("count"),
+                    data_type: DataType::I64,
+                    version: Version::Offset64Bit,
+                },
+                InvalidDataSet::VariableAttributeDataTypeNotSupported{
+                    var_name: String::from("count"),
+                    attr_name: String::from("valid_max"),
+                    data_type: DataType::U32,
+                    version: Version::Offset64Bit,
+                },
+            ]),
+            data_set.validate(Version::Offset64Bit)
+        );
+    }
+
+    // Chunk sizes and begin offsets
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.add_fixed_dim("x", NC_MAX_DIM_SIZE).unwrap();
+        data_set.add_var_f64("var_1", &["x"]).unwrap();
+        data_set.add_var_f64("var_2", &["x"]).unwrap();
+        let header_size: usize = data_set.header_size(Version::Classic);
+
+        assert_eq!(Ok(()),                      data_set.validate(Version::Cdf5));
+        assert_eq!(
+            Err(vec![
+                InvalidDataSet::VariableChunkSizeExceeded{
+                    var_name: String::from("var_1"),
+                    chunk_size: 8 * NC_MAX_DIM_SIZE,
+                    version: Version::Offset64Bit,
+                },
+            ]),
+            data_set.validate(Version::Offset64Bit)
+        );
+        assert_eq!(
+            Err(vec![
+                InvalidDataSet::VariableChunkSizeExceeded{
+                    var_name: String::from("var_1"),
+                    chunk_size: 8 * NC_MAX_DIM_SIZE,
+                    version: Version::Classic,
+                },
+                InvalidDataSet::VariableBeginOffsetExceeded{
+                    var_name: String::from("var_2"),
+                    begin_offset: header_size + 8 * NC_MAX_DIM_SIZE,
+                    version: Version::Classic,
+                },
+            ]),
+            data_set.validate(Version::Classic)
+        );
+
+        // The last variable can exceed the maximum chunk size
+        data_set.remove_var("var_2").unwrap();
+        assert_eq!(Ok(()),                      data_set.validate(Version::Classic));
+
+        // Except the last *fixed-size* variable followed by *record* variables
+        data_set.set_unlimited_dim("time", 1).unwrap();
+        data_set.add_var_f64("time", &["time"]).unwrap();
+        assert_eq!(
+            Err(vec![
+                InvalidDataSet::VariableChunkSizeExceeded{
+                    var_name: String::from("var_1"),
+                    chunk_size: 8 * NC_MAX_DIM_SIZE,
+                    version: Version::Offset64Bit,
+                },
+            ]),
+            data_set.validate(Version::Offset64Bit)
+        );
+    }
+
+    // Implementation limits, the variables edited directly are not checked
+    {
+        let mut data_set: DataSet = DataSet::with_limits(Limits{max_name_size: 8, max_var_dims: 1, ..Limits::default()});
+        data_set.add_fixed_dim("x", 2).unwrap();
+        data_set.add_fixed_dim("y", 3).unwrap();
+        data_set.add_var_f32("sst", &["x"]).unwrap();
+        data_set.get_var_mut("sst").unwrap().add_attr_string("long_name", "SST").unwrap();
+        data_set.vars[0].dims.push(Rc::clone(&data_set.dims[1]));
+
+        assert_eq!(
+            Err(vec![
+                InvalidDataSet::MaximumDimensionsPerVariableExceeded{var_name: String::from("sst"), num_dims: 2},
+                InvalidDataSet::VariableAttributeNameNotValid{var_name: String::from("sst"), attr_name: String::from("long_name")},
+            ]),
+            data_set.validate(Version::Classic)
+        );
+    }
+
+    // Rules checked by the methods of `DataSet`, broken here directly
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.add_fixed_dim("latitude", 3).unwrap();
+        data_set.add_var_f32("sst", &["latitude"]).unwrap();
+        data_set.add_global_attr_string("title", "Sea surface temperature").unwrap();
+        data_set.vars[0].name = String::from("!sst");
+        data_set.vars[0].dims.push(Rc::clone(&data_set.dims[0]));
+        data_set.attrs[0].name = String::from("!title");
+
+        assert_eq!(
+            Err(vec![
+                InvalidDataSet::GlobalAttributeNameNotValid(String::from("!title")),
+                InvalidDataSet::VariableNameNotValid(String::from("!sst")),
+                InvalidDataSet::DimensionsUsedMultipleTimes{
+                    var_name: String::from("!sst"),
+                    get_dim_names: vec![String::from("latitude"), String::from("latitude")],
+                },
+            ]),
+            data_set.validate(Version::Classic)
+        );
+    }
+}
+
+#[test]
+fn test_write_in_memory_data_set() {
+    const TEST_FILE_NAME: &str = "test_write_in_memory_data_set.nc";
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let test_file_path: PathBuf = tmp_dir.path().join(TEST_FILE_NAME);
+
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_var_f64("time", &["time"]).unwrap();
+    data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    data_set.add_var_i16("sst", &["time", "latitude"]).unwrap();
+    data_set.add_var_u8("flag", &[] as &[&str]).unwrap();
+
+    let mut in_memory: InMemoryDataSet = InMemoryDataSet::new(data_set);
+    in_memory.set_var("time", vec![0.0_f64, 1.0]).unwrap();
+    in_memory.set_var("sst", vec![1_i16, 2, 3, 4, 5, 6]).unwrap();
+    in_memory.set_var("flag", vec![7_u8]).unwrap();
+    in_memory.write_file(&test_file_path, Version::Offset64Bit).unwrap();
+
+    let mut file_reader: FileReader = FileReader::open(&test_file_path).unwrap();
+    assert_eq!(Version::Offset64Bit,                    file_reader.version());
+    let read_in_memory: InMemoryDataSet = file_reader.read_in_memory().unwrap();
+    assert_eq!(in_memory.data_set(),                    read_in_memory.data_set());
+    assert_eq!(in_memory.get_var_data("time"),          read_in_memory.get_var_data("time"));
+    assert_eq!(in_memory.get_var_data("sst"),           read_in_memory.get_var_data("sst"));
+    assert_eq!(in_memory.get_var_data("flag"),          read_in_memory.get_var_data("flag"));
+    // The variable without data is filled
+    assert_eq!(Some(&[NC_FILL_F32; 3][..]),             read_in_memory.get_var::<f32>("latitude"));
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_write_and_read_generic() {
+    const TEST_FILE_NAME: &str = "test_write_and_read_generic.nc";
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let test_file_path: PathBuf = tmp_dir.path().join(TEST_FILE_NAME);
+
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    data_set.add_var_i16("sst", &["time", "latitude"]).unwrap();
+    data_set.add_var_u64("count", &["time"]).unwrap();
+
+    {
+        let mut file_writer: FileWriter = FileWriter::open(&test_file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Cdf5, 0).unwrap();
+        file_writer.write_var("latitude", &[0.0_f32, 0.5, 1.0]).unwrap();
+        file_writer.write_record("sst", 0, &[1_i16, 2, 3]).unwrap();
+        file_writer.write_record("sst", 1, &[4_i16, 5, 6]).unwrap();
+        file_writer.write_var("count", &[7_u64, 8]).unwrap();
+        // The element type does not match the variable
+        assert_eq!(
+            WriteError::VariableMismatchDataType{var_name: String::from("latitude"), req: DataType::F32, get: DataType::F64},
+            file_writer.write_var("latitude", &[0.0_f64, 0.5, 1.0]).unwrap_err()
+        );
+        assert_eq!(
+            WriteError::VariableMismatchDataType{var_name: String::from("sst"), req: DataType::I16, get: DataType::I32},
+            file_writer.write_record("sst", 0, &[1_i32, 2, 3]).unwrap_err()
+        );
+        file_writer.close().unwrap();
+    }
+
+    let mut file_reader: FileReader = FileReader::open(&test_file_path).unwrap();
+    assert_eq!(vec![0.0_f32, 0.5, 1.0],                 file_reader.read_var_as::<f32>("latitude").unwrap());
+    assert_eq!(vec![1_i16, 2, 3, 4, 5, 6],              file_reader.read_var_as::<i16>("sst").unwrap());
+    assert_eq!(vec![4_i16, 5, 6],                       file_reader.read_record_as::<i16>("sst", 1).unwrap());
+    assert_eq!(vec![7_u64, 8],                          file_reader.read_var_as::<u64>("count").unwrap());
+    assert_eq!(
+        ReadError::VariableMismatchDataType{var_name: String::from("sst"), req: DataType::I16, get: DataType::U8},
+        file_reader.read_record_as::<u8>("sst", 0).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        file_reader.read_var_as::<f32>("undef_var").unwrap_err()
+    );
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_write_and_read_packed() {
+    const TEST_FILE_NAME: &str = "test_write_and_read_packed.nc";
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let test_file_path: PathBuf = tmp_dir.path().join(TEST_FILE_NAME);
+
+    let mut data_set: DataSet = DataSet::new();
+    data_set.add_fixed_dim("latitude", 4).unwrap();
+    data_set.add_var_i8("sst", &["latitude"]).unwrap();
+    data_set.add_var_attr_f64("sst", "scale_factor", vec![0.25]).unwrap();
+    data_set.add_var_attr_f64("sst", "add_offset", vec![280.0]).unwrap();
+    data_set.add_var_f32("temperature", &["latitude"]).unwrap();  // no packing attributes
+
+    {
+        let mut file_writer: FileWriter = FileWriter::open(&test_file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        file_writer.write_var_packed("sst", &[280.0, 281.1, std::f64::NAN, 270.0]).unwrap();
+        file_writer.write_var_packed("temperature", &[1.5, std::f64::NAN, 2.5, 3.5]).unwrap();
+        // The packed value `(320.0 - 280.0) / 0.25 = 160` overflows the `i8` data type
+        assert_eq!(
+            WriteError::VariableValueOutOfRange{var_name: String::from("sst"), index: 1},
+            file_writer.write_var_packed("sst", &[280.0, 320.0, 280.0, 280.0]).unwrap_err()
+        );
+        assert_eq!(
+            WriteError::VariableNotDefined(String::from("undef_var")),
+            file_writer.write_var_packed("undef_var", &[]).unwrap_err()
+        );
+        file_writer.close().unwrap();
+    }
+
+    let mut file_reader: FileReader = FileReader::open(&test_file_path).unwrap();
+    assert_eq!(vec![0_i8, 4, NC_FILL_I8, -40],         file_reader.read_var_i8("sst").unwrap());
+    let sst: Vec<f64> = file_reader.read_var_unpacked_f64("sst").unwrap();
+    assert_eq!(4,                                       sst.len());
+    assert_eq!(280.0,                                   sst[0]);
+    assert_eq!(281.0,                                   sst[1]);
+    assert_eq!(true,                                    sst[2].is_nan());
+    assert_eq!(270.0,                                   sst[3]);
+    let temperature: Vec<f64> = file_reader.read_var_unpacked_f64("temperature").unwrap();
+    assert_eq!(1.5,                                     temperature[0]);
+    assert_eq!(true,                                    temperature[1].is_nan());
+    assert_eq!(2.5,                                     temperature[2]);
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        file_reader.read_var_unpacked_f64("undef_var").unwrap_err()
+    );
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_write_and_read_valid_range() {
+    const TEST_FILE_NAME: &str = "test_write_and_read_valid_range.nc";
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let test_file_path: PathBuf = tmp_dir.path().join(TEST_FILE_NAME);
+
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_var_i16("sst", &["time", "latitude"]).unwrap();
+    data_set.add_var_attr_i16("sst", "valid_range", vec![0, 100]).unwrap();
+    data_set.add_var_attr_i16("sst", "_FillValue", vec![-1]).unwrap();
+    data_set.add_var_f64("depth", &["latitude"]).unwrap();
+    data_set.add_var_attr_f64("depth", "valid_max", vec![10.0]).unwrap();
+
+    {
+        let mut file_writer: FileWriter = FileWriter::open(&test_file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        assert_eq!(false,                               file_writer.is_rejecting_out_of_range());
+        // The out-of-range values are written by default
+        file_writer.write_var_f64("depth", &[1.0, 20.0, -5.0]).unwrap();
+
+        file_writer.set_reject_out_of_range(true);
+        assert_eq!(true,                                file_writer.is_rejecting_out_of_range());
+        assert_eq!(
+            WriteError::VariableValueOutOfRange{var_name: String::from("sst"), index: 2},
+            file_writer.write_record_i16("sst", 0, &[10, 20, 101]).unwrap_err()
+        );
+        assert_eq!(
+            WriteError::VariableValueOutOfRange{var_name: String::from("sst"), index: 0},
+            file_writer.write_var_slice("sst", &[1, 0], &[1, 1], &DataVector::I16(vec![-2])).unwrap_err()
+        );
+        assert_eq!(
+            WriteError::VariableValueOutOfRange{var_name: String::from("depth"), index: 1},
+            file_writer.write_var("depth", &[1.0_f64, 20.0, -5.0]).unwrap_err()
+        );
+        // The fill values are accepted
+        file_writer.write_record_i16("sst", 0, &[10, -1, 100]).unwrap();
+        file_writer.set_reject_out_of_range(false);
+        file_writer.write_record_i16("sst", 1, &[0, 200, -1]).unwrap();
+        file_writer.close().unwrap();
+    }
+
+    let mut file_reader: FileReader = FileReader::open(&test_file_path).unwrap();
+    assert_eq!(false,                                   file_reader.is_masking_out_of_range());
+    assert_eq!(vec![Some(0_i16), Some(200), None],      file_reader.read_record_masked::<i16>("sst", 1).unwrap());
+    assert_eq!(vec![Some(1.0_f64), Some(20.0), Some(-5.0)], file_reader.read_var_masked::<f64>("depth").unwrap());
+
+    file_reader.set_mask_out_of_range(true);
+    assert_eq!(true,                                    file_reader.is_masking_out_of_range());
+    assert_eq!(vec![Some(10_i16), None, Some(100), Some(0), None, None], file_reader.read_var_masked::<i16>("sst").unwrap());
+    assert_eq!(vec![Some(1.0_f64), None, Some(-5.0)],   file_reader.read_var_masked::<f64>("depth").unwrap());
+    let depth: Vec<f64> = file_reader.read_var_unpacked_f64("depth").unwrap();
+    assert_eq!(1.0,                                     depth[0]);
+    assert_eq!(true,                                    depth[1].is_nan());
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_write_cdf5_data_types_error_version() {
+    const TEST_FILE_NAME: &str = "test_write_cdf5_data_types_error_version.nc";
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let test_file_path: PathBuf = tmp_dir.path().join(TEST_FILE_NAME);
+
+    for data_type in [DataType::U16, DataType::U32, DataType::I64, DataType::U64].iter() {
+        // CDF-5 variable
+        let mut data_set: DataSet = DataSet::new();
+        data_set.add_fixed_dim("x", 2).unwrap();
+        data_set.add_var("var", &["x"], data_type.clone()).unwrap();
+        for version in [Version::Classic, Version::Offset64Bit].iter() {
+            let mut file_writer: FileWriter = FileWriter::open(&test_file_path).unwrap();
+            assert_eq!(
+                WriteError::DataTypeNotSupported{data_type: data_type.clone(), version: version.clone()},
+                file_writer.set_def(&data_set, version.clone(), 0).unwrap_err()
+            );
+        }
+        let mut file_writer: FileWriter = FileWriter::open(&test_file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Cdf5, 0).unwrap();
+        file_writer.close().unwrap();
+    }
+
+    // CDF-5 global attribute
+    let mut data_set: DataSet = DataSet::new();
+    data_set.add_global_attr_u64("attr", vec![1]).unwrap();
+    let mut file_writer: FileWriter = FileWriter::open(&test_file_path).unwrap();
+    assert_eq!(
+        WriteError::DataTypeNotSupported{data_type: DataType::U64, version: Version::Classic},
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap_err()
+    );
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_write_and_read_nan() {
+    const TEST_FILE_NAME: &str = "test_write_and_read_nan.nc";
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let test_file_path: PathBuf = tmp_dir.path().join(TEST_FILE_NAME);
+
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_var_f32("sst", &["time", "latitude"]).unwrap();
+    data_set.add_var_attr_f32("sst", "_FillValue", vec![-999.0]).unwrap();
+    data_set.add_var_f64("latitude", &["latitude"]).unwrap();
+    data_set.add_var_i32("count", &["latitude"]).unwrap();
+
+    {
+        let mut file_writer: FileWriter = FileWriter::open(&test_file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        file_writer.write_record_nan("sst", 0, &[280.0_f32, std::f32::NAN, 281.0]).unwrap();
+        file_writer.write_record_nan("sst", 1, &[std::f32::NAN, 282.0_f32, 283.0]).unwrap();
+        file_writer.write_var_nan("latitude", &[std::f64::NAN, 0.0, 10.0]).unwrap();
+        assert_eq!(
+            WriteError::VariableMismatchDataType{var_name: String::from("count"), req: DataType::I32, get: DataType::F64},
+            file_writer.write_var_nan("count", &[std::f64::NAN, 0.0, 10.0]).unwrap_err()
+        );
+        assert_eq!(
+            WriteError::VariableNotDefined(String::from("undef_var")),
+            file_writer.write_var_nan::<f64>("undef_var", &[]).unwrap_err()
+        );
+        file_writer.close().unwrap();
+    }
+
+    let mut file_reader: FileReader = FileReader::open(&test_file_path).unwrap();
+    assert_eq!(vec![280.0, -999.0, 281.0, -999.0, 282.0, 283.0],    file_reader.read_var_f32("sst").unwrap());
+    assert_eq!(vec![NC_FILL_F64, 0.0, 10.0],                        file_reader.read_var_f64("latitude").unwrap());
+
+    let sst: Vec<f32> = file_reader.read_record_nan::<f32>("sst", 1).unwrap();
+    assert_eq!(true,                                                sst[0].is_nan());
+    assert_eq!(vec![282.0, 283.0],                                  sst[1..].to_vec());
+    let sst: Vec<f32> = file_reader.read_var_nan::<f32>("sst").unwrap();
+    assert_eq!(vec![false, true, false, true, false, false],        sst.iter().map(|value: &f32| value.is_nan()).collect::<Vec<bool>>());
+    let latitude: Vec<f64> = file_reader.read_var_nan::<f64>("latitude").unwrap();
+    assert_eq!(true,                                                latitude[0].is_nan());
+    assert_eq!(vec![0.0, 10.0],                                     latitude[1..].to_vec());
+    assert_eq!(
+        ReadError::VariableMismatchDataType{var_name: String::from("latitude"), req: DataType::F64, get: DataType::F32},
+        file_reader.read_var_nan::<f32>("latitude").unwrap_err()
+    );
+}
+
+#[test]
+fn test_file_writer_in_memory() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let test_file_path: PathBuf = tmp_dir.path().join("in_memory.nc");
+
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_var_f32("sst", &["time", "latitude"]).unwrap();
+    data_set.add_var_i32("count", &["latitude"]).unwrap();
+
+    // Write the same file in memory and on the file system
+    let in_memory_bytes: Vec<u8> = {
+        let mut file_writer: FileWriter = FileWriter::in_memory();
+        assert_eq!(PathBuf::new(),                      file_writer.file_path());
+        file_writer.set_def(&data_set, Version::Offset64Bit, 0).unwrap();
+        file_writer.write_record_f32("sst", 1, &[1.0, 2.0, 3.0]).unwrap();
+        file_writer.write_var_i32("count", &[4, 5, 6]).unwrap();
+        file_writer.close_into_bytes().unwrap()
+    };
+    {
+        let mut file_writer: FileWriter = FileWriter::open(&test_file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Offset64Bit, 0).unwrap();
+        file_writer.write_record_f32("sst", 1, &[1.0, 2.0, 3.0]).unwrap();
+        file_writer.write_var_i32("count", &[4, 5, 6]).unwrap();
+        assert_eq!(WriteError::NotInMemory,              file_writer.close_into_bytes().unwrap_err());
+    }
+    assert_eq!(std::fs::read(&test_file_path).unwrap(), in_memory_bytes);
+
+    let mut file_reader: FileReader = FileReader::from_bytes(in_memory_bytes).unwrap();
+    assert_eq!(&data_set,                               file_reader.data_set());
+    assert_eq!(Version::Offset64Bit,                    file_reader.version());
+    assert_eq!(vec![NC_FILL_F32, NC_FILL_F32, NC_FILL_F32, 1.0, 2.0, 3.0],     file_reader.read_var_f32("sst").unwrap());
+    assert_eq!(vec![4, 5, 6],                           file_reader.read_var_i32("count").unwrap());
+    tmp_dir.close().unwrap();
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn test_open_mapped() {
+    const TMP_DIR_PREFIX: &str = "netcdf3_tests_";
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let file_path: PathBuf = tmp_dir.path().join("mapped.nc");
+
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 3).unwrap();
+    data_set.add_fixed_dim("x", 3).unwrap();
+    data_set.add_var_i16("grid", &["x"]).unwrap();
+    data_set.add_var_f64("series", &["time", "x"]).unwrap();
+    data_set.add_var_u8("flags", &["time"]).unwrap();
+    data_set.add_var_i32("unwritten", &["x"]).unwrap();
+    let write_data = |file_writer: &mut FileWriter| {
+        file_writer.write_var_i16("grid", &[1, 2, 3]).unwrap();
+        file_writer.write_record_f64("series", 2, &[7.0, 8.0, 9.0]).unwrap();
+        file_writer.write_record_f64("series", 0, &[1.0, 2.0, 3.0]).unwrap();
+        file_writer.write_var_u8("flags", &[1, 0, 1]).unwrap();
+    };
+    let mut file_writer: FileWriter = FileWriter::in_memory();
+    file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    write_data(&mut file_writer);
+    let expected_bytes: Vec<u8> = file_writer.close_into_bytes().unwrap();
+
+    let mut file_writer: FileWriter = FileWriter::open_mapped(&file_path).unwrap();
+    file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    // Sized up front
+    assert_eq!(expected_bytes.len() as u64,                 std::fs::metadata(&file_path).unwrap().len());
+    write_data(&mut file_writer);
+    file_writer.close().unwrap();
+    assert_eq!(expected_bytes,                              std::fs::read(&file_path).unwrap());
+
+    // Records appended to an empty file
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 0).unwrap();
+    data_set.add_var_i32("counts", &["time"]).unwrap();
+    let mut file_writer: FileWriter = FileWriter::open_mapped(&file_path).unwrap();
+    file_writer.set_streaming(true).unwrap();
+    file_writer.set_def(&data_set, Version::Cdf5, 0).unwrap();
+    for count in 0..2_000 {
+        file_writer.push_record_i32("counts", &[count]).unwrap();
+    }
+    file_writer.close().unwrap();
+    let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    assert_eq!(Some(2_000),                                 file_reader.data_set().num_records());
+    assert_eq!((0..2_000).collect::<Vec<i32>>(),            file_reader.read_var_i32("counts").unwrap());
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_reset() {
+    const TMP_DIR_PREFIX: &str = "netcdf3_tests_";
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let file_paths: Vec<PathBuf> = (0..3).map(|i: usize| tmp_dir.path().join(format!("file_{}.nc", i))).collect();
+
+    let mut data_set_1: DataSet = DataSet::new();
+    data_set_1.set_unlimited_dim("time", 2).unwrap();
+    data_set_1.add_var_f32("values", &["time"]).unwrap();
+    data_set_1.add_global_attr_string("title", "first").unwrap();
+    let mut data_set_2: DataSet = DataSet::new();
+    data_set_2.add_fixed_dim("x", 3).unwrap();
+    data_set_2.add_var_i8("flags", &["x"]).unwrap();
+    data_set_2.add_var_i32("unwritten", &["x"]).unwrap();
+
+    let expected_bytes = |data_set: &DataSet, version: Version, write_data: &dyn Fn(&mut FileWriter)| -> Vec<u8> {
+        let mut file_writer: FileWriter = FileWriter::in_memory();
+        file_writer.set_def(data_set, version, 0).unwrap();
+        write_data(&mut file_writer);
+        return file_writer.close_into_bytes().unwrap();
+    };
+    let write_values = |file_writer: &mut FileWriter| file_writer.write_var_f32("values", &[1.5, 2.5]).unwrap();
+    // Only the first record is written, the second one is filled at the reset
+    let write_first_value = |file_writer: &mut FileWriter| file_writer.write_record_f32("values", 0, &[1.5]).unwrap();
+    let write_flags = |file_writer: &mut FileWriter| file_writer.write_var_i8("flags", &[1, 2, 3]).unwrap();
+
+    let mut file_writer: FileWriter = FileWriter::open(&file_paths[0]).unwrap();
+    file_writer.set_def(&data_set_1, Version::Classic, 0).unwrap();
+    write_first_value(&mut file_writer);
+    file_writer.reset(&file_paths[1]).unwrap();
+    assert_eq!(file_paths[1],                           file_writer.file_path());
+    assert_eq!(false,                                   file_writer.header_is_defined());
+    file_writer.set_def(&data_set_2, Version::Cdf5, 0).unwrap();
+    write_flags(&mut file_writer);
+    file_writer.reset(&file_paths[2]).unwrap();
+    file_writer.set_def(&data_set_1, Version::Offset64Bit, 0).unwrap();
+    write_values(&mut file_writer);
+    file_writer.close().unwrap();
+
+    assert_eq!(expected_bytes(&data_set_1, Version::Classic, &write_first_value),   std::fs::read(&file_paths[0]).unwrap());
+    assert_eq!(expected_bytes(&data_set_2, Version::Cdf5, &write_flags),            std::fs::read(&file_paths[1]).unwrap());
+    assert_eq!(expected_bytes(&data_set_1, Version::Offset64Bit, &write_values),    std::fs::read(&file_paths[2]).unwrap());
+
+    // Errors
+    let mut file_writer: FileWriter = FileWriter::in_memory();
+    assert_eq!(WriteError::NotOnFileSystem,             file_writer.reset(&file_paths[0]).unwrap_err());
+    // Nothing is written before the error (the unwritten data are not filled, the number of records is not patched)
+    file_writer.set_streaming(true).unwrap();
+    file_writer.set_def(&data_set_1, Version::Classic, 0).unwrap();
+    assert_eq!(WriteError::NotOnFileSystem,             file_writer.reset(&file_paths[0]).unwrap_err());
+    assert_eq!(true,                                    file_writer.is_streaming());
+    assert_eq!(true,                                    file_writer.header_is_defined());
+    let mut file_writer: FileWriter = FileWriter::open(&file_paths[0]).unwrap();
+    assert_eq!(
+        WriteError::IOErrorKind(std::io::ErrorKind::NotFound),
+        file_writer.reset(tmp_dir.path().join("undef_dir").join("file.nc")).unwrap_err()
+    );
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_write_records_coalesced() {
+    const TMP_DIR_PREFIX: &str = "netcdf3_tests_";
+    const NUM_RECORDS: usize = 5;
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let file_path: PathBuf = tmp_dir.path().join("coalesced.nc");
+
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", NUM_RECORDS).unwrap();
+    data_set.add_fixed_dim("x", 3).unwrap();
+    data_set.add_var_f64("time", &["time"]).unwrap();
+    data_set.add_var_i16("values", &["time", "x"]).unwrap();
+    data_set.add_var_i8("flags", &["time"]).unwrap();
+    let time: Vec<f64> = (0..NUM_RECORDS).map(|i: usize| i as f64).collect();
+    let values: Vec<i16> = (0..(NUM_RECORDS * 3)).map(|i: usize| i as i16).collect();
+    let mut flags: Vec<i8> = vec![NC_FILL_I8; NUM_RECORDS];
+    flags[2] = 1;
+    flags[3] = -1;
+
+    let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+    file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    let header_size: usize = std::fs::read(&file_path).unwrap().len();
+    // All the record variables at the same index are contiguous
+    for i in 0..NUM_RECORDS {
+        file_writer.write_record_f64("time", i, &time[i..i + 1]).unwrap();
+        file_writer.write_record_i16("values", i, &values[i * 3..(i + 1) * 3]).unwrap();
+        file_writer.write_record_i8("flags", i, &[if i == 2 { 0 } else { flags[i] }]).unwrap();
+    }
+    // The records are not written yet
+    assert_eq!(header_size,                             std::fs::read(&file_path).unwrap().len());
+    file_writer.flush().unwrap();
+    assert_eq!(data_set.file_size_estimate(Version::Classic).unwrap(),  std::fs::read(&file_path).unwrap().len());
+    // Records not contiguous, and partially overwritten by an element
+    file_writer.write_record_i8("flags", 3, &[0]).unwrap();
+    file_writer.write_record_i8("flags", 1, &[flags[1]]).unwrap();
+    file_writer.write_record_i8("flags", 3, &[-1]).unwrap();
+    file_writer.write_element_i8("flags", &[2], 1).unwrap();
+    file_writer.close().unwrap();
+
+    let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    assert_eq!(time,                                    file_reader.read_var_f64("time").unwrap());
+    assert_eq!(values,                                  file_reader.read_var_i16("values").unwrap());
+    assert_eq!(flags,                                   file_reader.read_var_i8("flags").unwrap());
+
+    // Same bytes as the variables written at once
+    let mut file_writer: FileWriter = FileWriter::in_memory();
+    file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    file_writer.write_var_f64("time", &time).unwrap();
+    file_writer.write_var_i16("values", &values).unwrap();
+    file_writer.write_var_i8("flags", &flags).unwrap();
+    assert_eq!(file_writer.close_into_bytes().unwrap(), std::fs::read(&file_path).unwrap());
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_write_records_streaming_and_dropped() {
+    const TMP_DIR_PREFIX: &str = "netcdf3_tests_";
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let streaming_file_path: PathBuf = tmp_dir.path().join("streaming.nc");
+    let dropped_file_path: PathBuf = tmp_dir.path().join("dropped.nc");
+
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 0).unwrap();
+    data_set.add_var_f64("time", &["time"]).unwrap();
+    data_set.add_var_i16("values", &["time"]).unwrap();
+
+    // In the streaming mode, the records are written as soon as they are appended
+    let mut file_writer: FileWriter = FileWriter::open(&streaming_file_path).unwrap();
+    file_writer.set_streaming(true).unwrap();
+    file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    for i in 0..3 {
+        file_writer.push_record_f64("time", &[i as f64]).unwrap();
+        file_writer.write_record_i16("values", i, &[i as i16]).unwrap();
+        let mut file_reader: FileReader = FileReader::open(&streaming_file_path).unwrap();
+        assert_eq!(Some(i + 1),                         file_reader.data_set().num_records());
+        assert_eq!((0..=i).map(|j: usize| j as f64).collect::<Vec<f64>>(),  file_reader.read_var_f64("time").unwrap());
+        assert_eq!((0..=i).map(|j: usize| j as i16).collect::<Vec<i16>>(),  file_reader.read_var_i16("values").unwrap());
+    }
+    file_writer.close().unwrap();
+
+    // The pending records are written when the writer is dropped without being closed
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_var_f64("time", &["time"]).unwrap();
+    data_set.add_var_i16("values", &["time"]).unwrap();
+    {
+        let mut file_writer: FileWriter = FileWriter::open(&dropped_file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        file_writer.write_record_f64("time", 0, &[0.0]).unwrap();
+        file_writer.write_record_i16("values", 0, &[10]).unwrap();
+        file_writer.write_record_f64("time", 1, &[1.0]).unwrap();
+        file_writer.write_record_i16("values", 1, &[11]).unwrap();
+    }
+    let mut file_reader: FileReader = FileReader::open(&dropped_file_path).unwrap();
+    assert_eq!(vec![0.0, 1.0],                          file_reader.read_var_f64("time").unwrap());
+    assert_eq!(vec![10, 11],                            file_reader.read_var_i16("values").unwrap());
+    file_reader.close();
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_instrumented_writes() {
+    const NUM_RECORDS: usize = 3;
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", NUM_RECORDS).unwrap();
+    data_set.add_fixed_dim("latitude", 4).unwrap();
+    data_set.add_var_f64("time", &["time"]).unwrap();
+    data_set.add_var_f32("latitude", &["latitude"]).unwrap();
+    data_set.add_var_i16("values", &["time", "latitude"]).unwrap();
+
+    let mut file_writer: FileWriter = FileWriter::in_memory();
+    file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    assert_eq!(false,                                   file_writer.is_instrumented());
+    // Nothing recorded when disabled
+    file_writer.write_var_f32("latitude", &[0.0, 1.0, 2.0, 3.0]).unwrap();
+    assert_eq!(Timings::default(),                      file_writer.timings());
+
+    file_writer.set_instrumented(true);
+    assert_eq!(true,                                    file_writer.is_instrumented());
+    file_writer.write_var_f32("latitude", &[0.0, 1.0, 2.0, 3.0]).unwrap();
+    for i in 0..NUM_RECORDS {
+        file_writer.write_record_f64("time", i, &[i as f64]).unwrap();
+        file_writer.write_record_i16("values", i, &[0, 1, 2, 3]).unwrap();
+    }
+    file_writer.write_element_i16("values", &[1, 2], 5).unwrap();
+    file_writer.write_var_slice("values", &[2, 0], &[1, 2], &DataVector::I16(vec![6, 7])).unwrap();
+    // Invalid writes are not recorded
+    assert!(file_writer.write_var_f32("latitude", &[0.0]).is_err());
+    file_writer.flush().unwrap();
+
+    let timings: Timings = file_writer.timings();
+    assert_eq!(vec!["latitude", "time", "values"],      timings.vars.iter().map(|(name, _)| name.as_str()).collect::<Vec<&str>>());
+    let latitude: &VarTimings = timings.var("latitude").unwrap();
+    assert_eq!(1,                                       latitude.num_writes);
+    assert_eq!(16,                                      latitude.written_bytes);
+    let time: &VarTimings = timings.var("time").unwrap();
+    assert_eq!(3,                                       time.num_writes);
+    assert_eq!(24,                                      time.written_bytes);
+    let values: &VarTimings = timings.var("values").unwrap();
+    assert_eq!(5,                                       values.num_writes);
+    assert_eq!(24 + 2 + 4,                              values.written_bytes);
+    // No read recorded by the writer
+    assert_eq!(0,                                       values.num_reads);
+    assert_eq!(Duration::default(),                     timings.total_read());
+
+    file_writer.reset_timings();
+    assert_eq!(Timings::default(),                      file_writer.timings());
+    assert_eq!(true,                                    file_writer.is_instrumented());
+    file_writer.close_into_bytes().unwrap();
+}
@@ -0,0 +1,109 @@
+use crate::{FileReader, DataType, DataVector, NcType, Variable};
+use crate::error::WriteError;
+
+/// Variables sharing the same dimensions, flattened into rows with the coordinates of their dimensions.
+///
+/// Used by the tabular exports, which read the variables by blocks of rows along their first dimension.
+pub(crate) struct FlatVars {
+    pub(crate) dim_names: Vec<String>,
+    pub(crate) shape: Vec<usize>,
+    /// The *coordinate variable* of each dimension (the 1-D variable having the same name), and its values
+    pub(crate) coord_vars: Vec<Option<(Variable, DataVector)>>,
+    /// The exported variables, except the coordinate variables
+    pub(crate) value_vars: Vec<Variable>,
+}
+
+impl FlatVars {
+    /// Checks that the variables share the same dimensions, and reads their coordinate variables.
+    pub(crate) fn new<T: AsRef<str>>(reader: &mut FileReader, var_names: &[T]) -> Result<FlatVars, WriteError> {
+        let vars: Vec<Variable> = var_names.iter().map(|var_name: &T| {
+            let var_name: &str = var_name.as_ref();
+            return reader.data_set().get_var(var_name).cloned().ok_or_else(|| WriteError::VariableNotDefined(var_name.to_owned()));
+        }).collect::<Result<Vec<Variable>, WriteError>>()?;
+        let dim_names: Vec<String> = vars.first().map(|var: &Variable| var.dim_names()).unwrap_or_default();
+        if let Some(var) = vars.iter().find(|var: &&Variable| var.dim_names() != dim_names) {
+            return Err(WriteError::VariableMismatchDims{var_name: var.name().to_owned(), req: dim_names, get: var.dim_names()});
+        }
+        let shape: Vec<usize> = vars.first().map(|var: &Variable| var.shape()).unwrap_or_default();
+
+        let mut coord_vars: Vec<Option<(Variable, DataVector)>> = vec![];
+        for dim_name in dim_names.iter() {
+            let coord_var: Option<Variable> = reader.data_set().get_var(dim_name)
+                .filter(|coord_var: &&Variable| coord_var.dim_names() == [dim_name.as_str()])
+                .cloned();
+            coord_vars.push(match coord_var {
+                None => None,
+                Some(coord_var) => {
+                    let coord_data: DataVector = reader.read_var(coord_var.name())?;
+                    Some((coord_var, coord_data))
+                },
+            });
+        }
+        let value_vars: Vec<Variable> = vars.into_iter().filter(|var: &Variable| !dim_names.contains(&var.name().to_owned())).collect();
+        return Ok(FlatVars{
+            dim_names: dim_names,
+            shape: shape,
+            coord_vars: coord_vars,
+            value_vars: value_vars,
+        });
+    }
+
+    /// Returns the `(start, count)` of the blocks of rows along the first dimension, each block containing at most
+    /// `block_len` rows (or a single slice of the first dimension).
+    ///
+    /// Returns no block if no variable is exported.
+    pub(crate) fn blocks(&self, block_len: usize) -> Vec<(Vec<usize>, Vec<usize>)> {
+        if self.dim_names.is_empty() && self.value_vars.is_empty() {
+            return vec![];
+        }
+        let first_dim_size: usize = match self.shape.first() {
+            None => return vec![(vec![], vec![])],
+            Some(first_dim_size) => *first_dim_size,
+        };
+        let inner_len: usize = self.shape[1..].iter().product();
+        let block_size: usize = (block_len / inner_len.max(1)).max(1);
+        return (0..first_dim_size).step_by(block_size).map(|first_index: usize| {
+            let mut start: Vec<usize> = vec![0; self.shape.len()];
+            let mut count: Vec<usize> = self.shape.clone();
+            start[0] = first_index;
+            count[0] = block_size.min(first_dim_size - first_index);
+            return (start, count);
+        }).collect();
+    }
+
+    /// Returns the index along the dimension `dim_index` of each row of the block.
+    pub(crate) fn dim_indices(dim_index: usize, start: &[usize], count: &[usize]) -> Vec<usize> {
+        let num_rows: usize = count.iter().product();
+        let stride: usize = count[(dim_index + 1)..].iter().product();
+        return (0..num_rows).map(|row: usize| start[dim_index] + (row / stride) % count[dim_index]).collect();
+    }
+
+    /// Reads the values of the variable located in the block.
+    pub(crate) fn read_block(reader: &mut FileReader, var: &Variable, start: &[usize], count: &[usize]) -> Result<DataVector, WriteError> {
+        let data: DataVector = match start.is_empty() {
+            true => reader.read_var(var.name())?,
+            false => reader.read_var_slice(var.name(), start, count)?,
+        };
+        return Ok(data);
+    }
+}
+
+/// Returns the values located at the `indices`.
+pub(crate) fn gather(data: &DataVector, indices: &[usize]) -> DataVector {
+    fn gather_values<T: NcType>(data: &DataVector, indices: &[usize]) -> DataVector {
+        let values: &[T] = T::from_data_vector(data).unwrap_or(&[]);
+        return T::into_data_vector(indices.iter().map(|index: &usize| values[*index]).collect());
+    }
+    return match data.data_type() {
+        DataType::I8 => gather_values::<i8>(data, indices),
+        DataType::U8 => gather_values::<u8>(data, indices),
+        DataType::I16 => gather_values::<i16>(data, indices),
+        DataType::I32 => gather_values::<i32>(data, indices),
+        DataType::F32 => gather_values::<f32>(data, indices),
+        DataType::F64 => gather_values::<f64>(data, indices),
+        DataType::U16 => gather_values::<u16>(data, indices),
+        DataType::U32 => gather_values::<u32>(data, indices),
+        DataType::I64 => gather_values::<i64>(data, indices),
+        DataType::U64 => gather_values::<u64>(data, indices),
+    };
+}
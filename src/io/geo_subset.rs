@@ -0,0 +1,171 @@
+mod tests_geo_subset;
+
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::{FileReader, FileWriter, DataSet, DataVector, Dimension, Variable};
+use crate::error::{ReadError, WriteError};
+use crate::io::compute_index_range;
+
+/// Geographic bounding box, in the units of the coordinate variables (usually `degrees_north` and `degrees_east`).
+///
+/// The bounds are inclusive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundingBox {
+    pub lat_min: f64,
+    pub lat_max: f64,
+    pub lon_min: f64,
+    pub lon_max: f64,
+}
+
+/// Subset of variables restricted to a geographic [`BoundingBox`](struct.BoundingBox.html).
+///
+/// The latitude and the longitude are given by two CF coordinate variables, namely one-dimensional variables defined on *fixed-size* dimensions.
+/// The selected index ranges are the smallest ones containing all the coordinates inside the bounding box.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{FileReader, FileWriter, BoundingBox, GeoSubset, Version};
+///
+/// const TEMP_F32_VAR_NAME: &str = "temperature_f32";
+///
+/// // ...
+/// # use copy_to_tmp_file::{
+/// #     copy_bytes_to_tmp_file,
+/// #     NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+/// # };
+/// #
+/// # // Copy bytes to an temporary file
+/// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+/// # let output_file_path = tmp_dir.path().join("subset.nc");
+///
+/// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+/// assert_eq!(Ok(vec![0.0, 0.5, 1.0]),                     file_reader.read_var_f32("latitude"));
+/// assert_eq!(Ok(vec![0.0, 0.5, 1.0, 1.5, 2.0]),           file_reader.read_var_f32("longitude"));
+///
+/// // Compute the subset
+/// let bbox = BoundingBox{lat_min: 0.4, lat_max: 1.0, lon_min: 0.6, lon_max: 1.6};
+/// let subset: GeoSubset = GeoSubset::new(&mut file_reader, &[TEMP_F32_VAR_NAME, "latitude", "longitude"], "latitude", "longitude", &bbox).unwrap();
+/// assert_eq!(1..3,                                        subset.lat_range());
+/// assert_eq!(2..4,                                        subset.lon_range());
+///
+/// // Write the subset
+/// let mut file_writer: FileWriter = FileWriter::create_new(&output_file_path).unwrap();
+/// file_writer.set_def(subset.data_set(), Version::Classic, 0).unwrap();
+/// subset.extract(&mut file_reader, &mut file_writer).unwrap();
+/// file_writer.close().unwrap();
+///
+/// let mut subset_reader: FileReader = FileReader::open(&output_file_path).unwrap();
+/// assert_eq!(Ok(vec![0.5, 1.0]),                          subset_reader.read_var_f32("latitude"));
+/// assert_eq!(Ok(vec![1.0, 1.5]),                          subset_reader.read_var_f32("longitude"));
+/// assert_eq!(
+///     Ok(vec![7.0, 8.0, 12.0, 13.0, 22.0, 23.0, 27.0, 28.0]),
+///     subset_reader.read_var_f32(TEMP_F32_VAR_NAME)
+/// );
+/// # tmp_dir.close();
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct GeoSubset {
+    /// Name of the latitude dimension
+    lat_dim_name: String,
+    /// Name of the longitude dimension
+    lon_dim_name: String,
+    /// Selected indices of the latitude dimension
+    lat_range: Range<usize>,
+    /// Selected indices of the longitude dimension
+    lon_range: Range<usize>,
+    /// Names of the selected variables
+    var_names: Vec<String>,
+    /// Definition of the subset
+    data_set: DataSet,
+}
+
+impl GeoSubset {
+
+    /// Computes the index ranges of the bounding box from the coordinate variables, and the definition of the subset.
+    pub fn new<T: AsRef<str>>(reader: &mut FileReader, var_names: &[T], lat_var_name: &str, lon_var_name: &str, bbox: &BoundingBox) -> Result<GeoSubset, ReadError> {
+        let lat_dim_name: String = GeoSubset::get_coord_dim_name(reader.data_set(), lat_var_name)?;
+        let lon_dim_name: String = GeoSubset::get_coord_dim_name(reader.data_set(), lon_var_name)?;
+        if lat_dim_name == lon_dim_name {
+            return Err(ReadError::CoordinateVariableNotValid(lon_var_name.to_owned()));
+        }
+        let lat_range: Range<usize> = compute_index_range(&reader.read_var(lat_var_name)?.get_as_f64(), bbox.lat_min, bbox.lat_max);
+        let lon_range: Range<usize> = compute_index_range(&reader.read_var(lon_var_name)?.get_as_f64(), bbox.lon_min, bbox.lon_max);
+        let data_set: DataSet = reader.data_set().extract_resized_vars(var_names, &[
+            (&lat_dim_name, lat_range.len()),
+            (&lon_dim_name, lon_range.len()),
+        ])?;
+        return Ok(GeoSubset{
+            lat_dim_name: lat_dim_name,
+            lon_dim_name: lon_dim_name,
+            lat_range: lat_range,
+            lon_range: lon_range,
+            var_names: var_names.iter().map(|var_name: &T| var_name.as_ref().to_owned()).collect(),
+            data_set: data_set,
+        });
+    }
+
+    /// Returns the definition of the subset, to be set in the [`FileWriter`](struct.FileWriter.html).
+    pub fn data_set(&self) -> &DataSet {
+        return &self.data_set;
+    }
+
+    /// Returns the selected indices of the latitude dimension.
+    pub fn lat_range(&self) -> Range<usize> {
+        return self.lat_range.clone();
+    }
+
+    /// Returns the selected indices of the longitude dimension.
+    pub fn lon_range(&self) -> Range<usize> {
+        return self.lon_range.clone();
+    }
+
+    /// Copies the slabs of the selected variables from the `reader` into the `writer`, one record at a time.
+    ///
+    /// The definition of the `writer` must already be set with the [`data_set`](struct.GeoSubset.html#method.data_set) of the subset.
+    pub fn extract(&self, reader: &mut FileReader, writer: &mut FileWriter) -> Result<(), WriteError> {
+        for var_name in self.var_names.iter() {
+            let var: &Variable = self.data_set.get_var(var_name).ok_or(WriteError::Unexpected)?;
+            let dims: Vec<Rc<Dimension>> = var.get_dims();
+            // Slab of the input variable
+            let mut input_start: Vec<usize> = vec![0; dims.len()];
+            let mut count: Vec<usize> = dims.iter().map(|dim: &Rc<Dimension>| dim.size()).collect();
+            for (i, dim) in dims.iter().enumerate() {
+                let dim_name: String = dim.name();
+                if dim_name == self.lat_dim_name {
+                    input_start[i] = self.lat_range.start;
+                } else if dim_name == self.lon_dim_name {
+                    input_start[i] = self.lon_range.start;
+                }
+            }
+            let mut output_start: Vec<usize> = vec![0; dims.len()];
+            let num_chunks: usize = match var.is_record_var() {
+                true => {
+                    count[0] = 1;
+                    var.num_chunks()
+                },
+                false => 1,
+            };
+            for i in 0..num_chunks {
+                if var.is_record_var() {
+                    input_start[0] = i;
+                    output_start[0] = i;
+                }
+                let chunk: DataVector = reader.read_var_slice(var_name, &input_start, &count)?;
+                writer.write_var_slice(var_name, &output_start, &count, &chunk)?;
+            }
+        }
+        return Ok(());
+    }
+
+    /// Returns the name of the dimension of the coordinate variable.
+    fn get_coord_dim_name(data_set: &DataSet, var_name: &str) -> Result<String, ReadError> {
+        let var: &Variable = data_set.get_var(var_name).ok_or_else(|| ReadError::VariableNotDefined(var_name.to_owned()))?;
+        let dims: Vec<Rc<Dimension>> = var.get_dims();
+        if dims.len() != 1 || !dims[0].is_fixed() {
+            return Err(ReadError::CoordinateVariableNotValid(var_name.to_owned()));
+        }
+        return Ok(dims[0].name());
+    }
+}
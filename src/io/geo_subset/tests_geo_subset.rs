@@ -0,0 +1,85 @@
+#![cfg(test)]
+use std::path::PathBuf;
+
+use crate::{FileReader, FileWriter, DataSet, Version};
+use crate::error::{ReadError, InvalidDataSet};
+
+use super::{BoundingBox, GeoSubset};
+
+use copy_to_tmp_file::{
+    copy_bytes_to_tmp_file,
+    NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+};
+
+const TEMP_I16_VAR_NAME: &str = "temperature_i16";
+
+#[test]
+fn test_geo_subset() {
+    let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    let output_file_path: PathBuf = tmp_dir.path().join("test_geo_subset.nc");
+
+    let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+    let bbox = BoundingBox{lat_min: 0.0, lat_max: 0.5, lon_min: 1.5, lon_max: 10.0};
+    let subset: GeoSubset = GeoSubset::new(&mut file_reader, &["time", TEMP_I16_VAR_NAME], "latitude", "longitude", &bbox).unwrap();
+    assert_eq!(0..2,                                                subset.lat_range());
+    assert_eq!(3..5,                                                subset.lon_range());
+    {
+        let data_set: &DataSet = subset.data_set();
+        assert_eq!(vec!["time", TEMP_I16_VAR_NAME],                 data_set.get_var_names());
+        assert_eq!(Some(2),                                         data_set.dim_size("latitude"));
+        assert_eq!(Some(2),                                         data_set.dim_size("longitude"));
+        assert_eq!(Some(2),                                         data_set.num_records());
+    }
+
+    {
+        let mut file_writer: FileWriter = FileWriter::create_new(&output_file_path).unwrap();
+        file_writer.set_def(subset.data_set(), Version::Classic, 0).unwrap();
+        subset.extract(&mut file_reader, &mut file_writer).unwrap();
+        file_writer.close().unwrap();
+    }
+
+    let mut subset_reader: FileReader = FileReader::open(&output_file_path).unwrap();
+    assert_eq!(subset.data_set(),                                   subset_reader.data_set());
+    assert_eq!(file_reader.read_var_f32("time"),                    subset_reader.read_var_f32("time"));
+    assert_eq!(Ok(vec![3, 4, 8, 9, 18, 19, 23, 24]),                subset_reader.read_var_i16(TEMP_I16_VAR_NAME));
+
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_geo_subset_errors() {
+    let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+
+    let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+    let bbox = BoundingBox{lat_min: 0.0, lat_max: 0.5, lon_min: 1.5, lon_max: 10.0};
+    // Coordinate variables not valid
+    assert_eq!(
+        ReadError::CoordinateVariableNotValid(String::from(TEMP_I16_VAR_NAME)),
+        GeoSubset::new(&mut file_reader, &[TEMP_I16_VAR_NAME], TEMP_I16_VAR_NAME, "longitude", &bbox).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::CoordinateVariableNotValid(String::from("time")),
+        GeoSubset::new(&mut file_reader, &[TEMP_I16_VAR_NAME], "latitude", "time", &bbox).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::CoordinateVariableNotValid(String::from("latitude")),
+        GeoSubset::new(&mut file_reader, &[TEMP_I16_VAR_NAME], "latitude", "latitude", &bbox).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        GeoSubset::new(&mut file_reader, &[TEMP_I16_VAR_NAME], "undef_var", "longitude", &bbox).unwrap_err()
+    );
+    // No selected variables
+    assert_eq!(
+        ReadError::DataSet(InvalidDataSet::VariableNotDefined(String::from("undef_var"))),
+        GeoSubset::new(&mut file_reader, &["undef_var"], "latitude", "longitude", &bbox).unwrap_err()
+    );
+    // Empty bounding box
+    let bbox = BoundingBox{lat_min: 10.0, lat_max: 20.0, lon_min: 1.5, lon_max: 10.0};
+    assert_eq!(
+        ReadError::DataSet(InvalidDataSet::FixedDimensionWithZeroSize(String::from("latitude"))),
+        GeoSubset::new(&mut file_reader, &[TEMP_I16_VAR_NAME], "latitude", "longitude", &bbox).unwrap_err()
+    );
+
+    tmp_dir.close().unwrap();
+}
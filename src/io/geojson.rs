@@ -0,0 +1,182 @@
+mod tests_geojson;
+
+use std::io::Write;
+
+use crate::{DataSet, DataType, DataVector, FileReader, Variable};
+use crate::cf::{self, Axis};
+use crate::data_set::{json_numbers, json_string};
+use crate::error::WriteError;
+use crate::io::summary::fill_value_as_f64;
+
+/// Exports the stations (or the points) of a CF *discrete sampling geometry* as a GeoJSON `FeatureCollection`.
+///
+/// The global attribute `featureType` must be `point` or `timeSeries`. The latitudes and the longitudes (see
+/// [`cf::var_axis`](cf/fn.var_axis.html)) are found among the coordinates of the exported variables, or else among
+/// the 0-D and 1-D variables of the data set. Their dimension is the *instance* dimension (the observations of the
+/// `point`s, or the stations of the `timeSeries`), each of its indices is a `Feature` with a `Point` geometry, and :
+/// - the exported variables are the `properties` of the features, a single value if the instance dimension is their
+///   only dimension, otherwise the array of their values at the index of the feature (e.g. the time series of the
+///   station),
+/// - the `id` of the features is the value of the variable having the attribute `cf_role` (e.g. `timeseries_id`), if any.
+///
+/// The values are unpacked (see the attributes `scale_factor` and `add_offset`), the `NC_CHAR` variables are exported
+/// as strings, the fill values and the non-finite values are `null`.
+///
+/// Returns the error `WriteError::FeatureTypeNotSupported` for the other feature types, and
+/// `WriteError::CoordinateVariableNotFound` if the latitudes or the longitudes are not found.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{DataSet, FileReader, FileWriter, Version, export_geojson};
+///
+/// let mut data_set: DataSet = DataSet::new();
+/// data_set.add_global_attr_string("featureType", "timeSeries").unwrap();
+/// data_set.add_fixed_dim("station", 2).unwrap();
+/// data_set.add_fixed_dim("name_len", 5).unwrap();
+/// data_set.set_unlimited_dim("time", 2).unwrap();
+/// data_set.add_var_u8("station_name", &["station", "name_len"]).unwrap();
+/// data_set.add_var_attr_string("station_name", "cf_role", "timeseries_id").unwrap();
+/// data_set.add_var_f64("lat", &["station"]).unwrap();
+/// data_set.add_var_attr_string("lat", "units", "degrees_north").unwrap();
+/// data_set.add_var_f64("lon", &["station"]).unwrap();
+/// data_set.add_var_attr_string("lon", "units", "degrees_east").unwrap();
+/// data_set.add_var_f32("temp", &["time", "station"]).unwrap();
+/// data_set.add_var_attr_string("temp", "coordinates", "lat lon").unwrap();
+///
+/// let mut file_writer: FileWriter = FileWriter::in_memory();
+/// file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+/// file_writer.write_var_u8("station_name", b"ParisLyon\0").unwrap();
+/// file_writer.write_var_f64("lat", &[48.85, 45.75]).unwrap();
+/// file_writer.write_var_f64("lon", &[2.35, 4.85]).unwrap();
+/// file_writer.write_var_f32("temp", &[10.5, 12.0, 11.5, std::f32::NAN]).unwrap();
+/// let mut file_reader: FileReader = FileReader::from_bytes(file_writer.close_into_bytes().unwrap()).unwrap();
+///
+/// let mut geojson: Vec<u8> = vec![];
+/// export_geojson(&mut file_reader, &["temp"], &mut geojson).unwrap();
+///
+/// let geojson: String = String::from_utf8(geojson).unwrap();
+/// let mut lines = geojson.lines();
+/// assert_eq!(Some(r#"{"type": "FeatureCollection", "features": ["#),                lines.next());
+/// assert_eq!(
+///     Some(r#"{"type": "Feature", "id": "Paris", "geometry": {"type": "Point", "coordinates": [2.35, 48.85]}, "properties": {"temp": [10.5, 11.5]}},"#),
+///     lines.next()
+/// );
+/// assert_eq!(
+///     Some(r#"{"type": "Feature", "id": "Lyon", "geometry": {"type": "Point", "coordinates": [4.85, 45.75]}, "properties": {"temp": [12.0, null]}}"#),
+///     lines.next()
+/// );
+/// assert_eq!(Some("]}"),                                                              lines.next());
+/// ```
+pub fn export_geojson<T: AsRef<str>, W: Write>(reader: &mut FileReader, var_names: &[T], writer: &mut W) -> Result<(), WriteError> {
+    let data_set: DataSet = reader.data_set().clone();
+    let feature_type: String = data_set.get_global_attr_as_string("featureType").unwrap_or_default();
+    if !["point", "timeSeries"].iter().any(|supported: &&str| feature_type.trim().eq_ignore_ascii_case(supported)) {
+        return Err(WriteError::FeatureTypeNotSupported(feature_type));
+    }
+    let vars: Vec<Variable> = var_names.iter().map(|var_name: &T| {
+        let var_name: &str = var_name.as_ref();
+        return data_set.get_var(var_name).cloned().ok_or_else(|| WriteError::VariableNotDefined(var_name.to_owned()));
+    }).collect::<Result<Vec<Variable>, WriteError>>()?;
+
+    let lat_var: Variable = find_location_var(&data_set, &vars, Axis::Y).ok_or_else(|| WriteError::CoordinateVariableNotFound(String::from("latitude")))?;
+    let lon_var: Variable = find_location_var(&data_set, &vars, Axis::X).ok_or_else(|| WriteError::CoordinateVariableNotFound(String::from("longitude")))?;
+    if lon_var.dim_names() != lat_var.dim_names() {
+        return Err(WriteError::VariableMismatchDims{var_name: lon_var.name().to_owned(), req: lat_var.dim_names(), get: lon_var.dim_names()});
+    }
+    let instance_dim: Option<String> = lat_var.dim_names().into_iter().next();
+    let num_features: usize = lat_var.len();
+
+    let lats: Vec<String> = split_values(reader, &lat_var, instance_dim.as_deref(), num_features)?;
+    let lons: Vec<String> = split_values(reader, &lon_var, instance_dim.as_deref(), num_features)?;
+    let id_var: Option<&Variable> = data_set.get_vars().into_iter().find(|var: &&Variable| {
+        var.get_attr_as_string("cf_role").is_some() && instance_dim.as_deref().map(|dim_name: &str| var.use_dim(dim_name)).unwrap_or(false)
+    });
+    let ids: Option<Vec<String>> = match id_var {
+        None => None,
+        Some(id_var) => Some(split_values(reader, id_var, instance_dim.as_deref(), num_features)?),
+    };
+    let mut properties: Vec<(String, Vec<String>)> = vec![];
+    for var in vars.iter() {
+        properties.push((json_string(var.name()), split_values(reader, var, instance_dim.as_deref(), num_features)?));
+    }
+
+    writeln!(writer, "{{\"type\": \"FeatureCollection\", \"features\": [")?;
+    for feature_index in 0..num_features {
+        let mut feature: String = String::from("{\"type\": \"Feature\", ");
+        if let Some(ids) = ids.as_ref() {
+            feature.push_str(&format!("\"id\": {}, ", ids[feature_index]));
+        }
+        let (lat, lon): (&str, &str) = (&lats[feature_index], &lons[feature_index]);
+        match lat == "null" || lon == "null" {
+            true => feature.push_str("\"geometry\": null, "),
+            false => feature.push_str(&format!("\"geometry\": {{\"type\": \"Point\", \"coordinates\": [{}, {}]}}, ", lon, lat)),
+        }
+        let properties: Vec<String> = properties.iter()
+            .map(|(var_name, values): &(String, Vec<String>)| format!("{}: {}", var_name, values[feature_index]))
+            .collect();
+        feature.push_str(&format!("\"properties\": {{{}}}}}", properties.join(", ")));
+        if feature_index + 1 < num_features {
+            feature.push(',');
+        }
+        writeln!(writer, "{}", feature)?;
+    }
+    writeln!(writer, "]}}")?;
+    writer.flush()?;
+    return Ok(());
+}
+
+/// Returns the latitudes or the longitudes, among the coordinates of the exported variables or among the 0-D and 1-D variables.
+fn find_location_var(data_set: &DataSet, vars: &[Variable], axis: Axis) -> Option<Variable> {
+    return vars.iter()
+        .find_map(|var: &Variable| cf::find_axis_var(data_set, var.name(), axis))
+        .or_else(|| data_set.get_vars().into_iter().find(|var: &&Variable| var.num_dims() <= 1 && cf::var_axis(data_set, var.name()) == Some(axis)))
+        .cloned();
+}
+
+/// Reads the variable, and returns the JSON value of each feature : the value at the index of the feature along the
+/// `instance_dim`, or the array of the values if the variable has other dimensions.
+fn split_values(reader: &mut FileReader, var: &Variable, instance_dim: Option<&str>, num_features: usize) -> Result<Vec<String>, WriteError> {
+    let mut dim_names: Vec<String> = var.dim_names();
+    let mut shape: Vec<usize> = var.shape();
+    let values: Vec<String> = match var.data_type() {
+        DataType::U8 if !shape.is_empty() => {
+            dim_names.pop();
+            shape.pop();
+            reader.read_var_strings(var.name())?.iter().map(|text: &String| json_string(text)).collect()
+        },
+        _ => json_values(reader, var)?,
+    };
+    let instance_axis: Option<usize> = instance_dim.and_then(|instance_dim: &str| dim_names.iter().position(|dim_name: &String| dim_name == instance_dim));
+    let is_scalar: bool = shape.len() == instance_axis.map(|_| 1).unwrap_or(0);
+
+    let mut groups: Vec<Vec<String>> = vec![vec![]; num_features];
+    match instance_axis {
+        None => groups.iter_mut().for_each(|group: &mut Vec<String>| *group = values.clone()),
+        Some(instance_axis) => {
+            let stride: usize = shape[(instance_axis + 1)..].iter().product();
+            for (index, value) in values.into_iter().enumerate() {
+                groups[(index / stride) % shape[instance_axis]].push(value);
+            }
+        },
+    }
+    return Ok(groups.into_iter().map(|group: Vec<String>| match is_scalar {
+        true => group.into_iter().next().unwrap_or_else(|| String::from("null")),
+        false => format!("[{}]", group.join(", ")),
+    }).collect());
+}
+
+/// Reads the numeric variable, and returns the JSON numbers of its unpacked values.
+fn json_values(reader: &mut FileReader, var: &Variable) -> Result<Vec<String>, WriteError> {
+    let data: DataVector = reader.read_var(var.name())?;
+    let (scale_factor, add_offset): (f64, f64) = var.packing_attrs();
+    if scale_factor == 1.0 && add_offset == 0.0 {
+        return Ok(json_numbers(&data, Some(var)));
+    }
+    let fill_value: Option<f64> = fill_value_as_f64(var);
+    let unpacked: DataVector = DataVector::F64(data.iter_as_f64().map(|value: f64| match Some(value) == fill_value {
+        true => std::f64::NAN,
+        false => value * scale_factor + add_offset,
+    }).collect());
+    return Ok(json_numbers(&unpacked, None));
+}
@@ -0,0 +1,113 @@
+#![cfg(test)]
+use serde_json::Value;
+
+use crate::{DataSet, FileReader, FileWriter, Version, export_geojson};
+use crate::error::WriteError;
+
+fn write_to_reader(data_set: &DataSet, write_data: impl FnOnce(&mut FileWriter)) -> FileReader {
+    let mut file_writer: FileWriter = FileWriter::in_memory();
+    file_writer.set_def(data_set, Version::Classic, 0).unwrap();
+    write_data(&mut file_writer);
+    return FileReader::from_bytes(file_writer.close_into_bytes().unwrap()).unwrap();
+}
+
+fn export_to_json(file_reader: &mut FileReader, var_names: &[&str]) -> Value {
+    let mut geojson: Vec<u8> = vec![];
+    export_geojson(file_reader, var_names, &mut geojson).unwrap();
+    return serde_json::from_slice(&geojson).unwrap();
+}
+
+fn point_data_set() -> DataSet {
+    let mut data_set: DataSet = DataSet::new();
+    data_set.add_global_attr_string("featureType", "point").unwrap();
+    data_set.set_unlimited_dim("obs", 3).unwrap();
+    data_set.add_var_f32("latitude", &["obs"]).unwrap();
+    data_set.add_var_attr_string("latitude", "standard_name", "latitude").unwrap();
+    data_set.add_var_f32("longitude", &["obs"]).unwrap();
+    data_set.add_var_attr_string("longitude", "standard_name", "longitude").unwrap();
+    data_set.add_var_i16("depth", &["obs"]).unwrap();
+    data_set.add_var_attr_i16("depth", "_FillValue", vec![-1]).unwrap();
+    data_set.add_var_attr_f64("depth", "scale_factor", vec![0.5]).unwrap();
+    return data_set;
+}
+
+#[test]
+fn test_export_geojson_point() {
+    let mut file_reader: FileReader = write_to_reader(&point_data_set(), |file_writer: &mut FileWriter| {
+        file_writer.write_var_f32("latitude", &[10.5, 20.0, std::f32::NAN]).unwrap();
+        file_writer.write_var_f32("longitude", &[-1.5, 0.0, 3.0]).unwrap();
+        file_writer.write_var_i16("depth", &[4, -1, 8]).unwrap();
+    });
+    let geojson: Value = export_to_json(&mut file_reader, &["depth"]);
+
+    assert_eq!("FeatureCollection",             geojson["type"]);
+    let features: &Vec<Value> = geojson["features"].as_array().unwrap();
+    assert_eq!(3,                               features.len());
+    assert_eq!(Value::Null,                     features[0]["id"]);
+    assert_eq!(serde_json::json!([-1.5, 10.5]), features[0]["geometry"]["coordinates"]);
+    assert_eq!(serde_json::json!(2.0),          features[0]["properties"]["depth"]);
+    assert_eq!(Value::Null,                     features[1]["properties"]["depth"]);
+    // Missing latitude
+    assert_eq!(Value::Null,                     features[2]["geometry"]);
+    assert_eq!(serde_json::json!(4.0),          features[2]["properties"]["depth"]);
+}
+
+#[test]
+fn test_export_geojson_single_time_series() {
+    let mut data_set: DataSet = DataSet::new();
+    data_set.add_global_attr_string("featureType", "timeSeries").unwrap();
+    data_set.set_unlimited_dim("time", 3).unwrap();
+    data_set.add_var_f64::<&str>("lat", &[]).unwrap();
+    data_set.add_var_attr_string("lat", "axis", "Y").unwrap();
+    data_set.add_var_f64::<&str>("lon", &[]).unwrap();
+    data_set.add_var_attr_string("lon", "axis", "X").unwrap();
+    data_set.add_var_i32("time", &["time"]).unwrap();
+    data_set.add_var_attr_string("time", "units", "days since 2020-01-01").unwrap();
+    data_set.add_var_f32("temp", &["time"]).unwrap();
+    let mut file_reader: FileReader = write_to_reader(&data_set, |file_writer: &mut FileWriter| {
+        file_writer.write_var_f64("lat", &[45.0]).unwrap();
+        file_writer.write_var_f64("lon", &[5.0]).unwrap();
+        file_writer.write_var_i32("time", &[0, 1, 2]).unwrap();
+        file_writer.write_var_f32("temp", &[1.5, 2.5, 3.5]).unwrap();
+    });
+    let geojson: Value = export_to_json(&mut file_reader, &["time", "temp"]);
+
+    let features: &Vec<Value> = geojson["features"].as_array().unwrap();
+    assert_eq!(1,                                   features.len());
+    assert_eq!(serde_json::json!([5.0, 45.0]),      features[0]["geometry"]["coordinates"]);
+    assert_eq!(serde_json::json!([0, 1, 2]),        features[0]["properties"]["time"]);
+    assert_eq!(serde_json::json!([1.5, 2.5, 3.5]),  features[0]["properties"]["temp"]);
+}
+
+#[test]
+fn test_export_geojson_errors() {
+    // Feature type not supported
+    {
+        let mut data_set: DataSet = point_data_set();
+        data_set.remove_global_attr("featureType").unwrap();
+        data_set.add_global_attr_string("featureType", "trajectory").unwrap();
+        let mut file_reader: FileReader = write_to_reader(&data_set, |_file_writer: &mut FileWriter| {});
+        assert_eq!(
+            WriteError::FeatureTypeNotSupported(String::from("trajectory")),
+            export_geojson(&mut file_reader, &["depth"], &mut vec![]).unwrap_err()
+        );
+    }
+    // Longitudes not found
+    {
+        let mut data_set: DataSet = point_data_set();
+        data_set.remove_var("longitude").unwrap();
+        let mut file_reader: FileReader = write_to_reader(&data_set, |_file_writer: &mut FileWriter| {});
+        assert_eq!(
+            WriteError::CoordinateVariableNotFound(String::from("longitude")),
+            export_geojson(&mut file_reader, &["depth"], &mut vec![]).unwrap_err()
+        );
+    }
+    // Variable not defined
+    {
+        let mut file_reader: FileReader = write_to_reader(&point_data_set(), |_file_writer: &mut FileWriter| {});
+        assert_eq!(
+            WriteError::VariableNotDefined(String::from("undef_var")),
+            export_geojson(&mut file_reader, &["undef_var"], &mut vec![]).unwrap_err()
+        );
+    }
+}
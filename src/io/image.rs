@@ -0,0 +1,163 @@
+mod tests_image;
+
+use std::io::Write;
+
+use png::{BitDepth, ColorType, Encoder, EncodingError};
+
+use crate::{FileReader, DataVector, Variable};
+use crate::error::WriteError;
+use crate::io::summary::fill_value_as_f64;
+
+/// Colormap of the function [`export_png`](fn.export_png.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colormap {
+    /// From black (minimum) to white (maximum)
+    Grayscale,
+    /// Perceptually uniform colormap of matplotlib, from dark purple (minimum) to yellow (maximum)
+    Viridis,
+}
+
+impl Colormap {
+
+    /// Anchor colors of the colormap *viridis*, evenly spaced between `0.0` and `1.0`
+    const VIRIDIS: [[u8; 3]; 9] = [
+        [68, 1, 84], [71, 44, 122], [59, 81, 139], [44, 113, 142], [33, 144, 141],
+        [39, 173, 129], [92, 200, 99], [170, 220, 50], [253, 231, 37],
+    ];
+
+    /// Returns the color of the normalized value `t` (between `0.0` and `1.0`).
+    fn color(&self, t: f64) -> [u8; 3] {
+        let t: f64 = t.max(0.0).min(1.0);
+        return match self {
+            Colormap::Grayscale => {
+                let level: u8 = (t * 255.0).round() as u8;
+                [level, level, level]
+            },
+            Colormap::Viridis => {
+                let position: f64 = t * (Colormap::VIRIDIS.len() - 1) as f64;
+                let index: usize = (position.floor() as usize).min(Colormap::VIRIDIS.len() - 2);
+                let frac: f64 = position - index as f64;
+                let (low, high): ([u8; 3], [u8; 3]) = (Colormap::VIRIDIS[index], Colormap::VIRIDIS[index + 1]);
+                let mut color: [u8; 3] = [0; 3];
+                for (i, channel) in color.iter_mut().enumerate() {
+                    *channel = (f64::from(low[i]) + (f64::from(high[i]) - f64::from(low[i])) * frac).round() as u8;
+                }
+                color
+            },
+        };
+    }
+}
+
+/// Options of the function [`export_png`](fn.export_png.html).
+///
+/// By default the colormap is *viridis*, the colors are scaled between the minimum and the maximum values of the field,
+/// and the first row of the field is the top row of the image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageOptions {
+    /// Colormap of the values
+    pub colormap: Colormap,
+    /// Values mapped to the first and the last colors of the colormap, the minimum and the maximum values if `None`
+    pub range: Option<(f64, f64)>,
+    /// Put the first row of the field at the bottom of the image (e.g. for the latitudes sorted from south to north)
+    pub flip_rows: bool,
+}
+
+impl Default for ImageOptions {
+    fn default() -> Self {
+        return ImageOptions{
+            colormap: Colormap::Viridis,
+            range: None,
+            flip_rows: false,
+        };
+    }
+}
+
+/// Renders a 2-D field as a PNG image (RGBA), one pixel per value.
+///
+/// Requires the optional feature `image`.
+///
+/// The field is the 2-D variable if `record_index` is `None`, or one record of the 3-D *record* variable. The first
+/// dimension of the field is the rows of the image and the second dimension is the columns. The values are unpacked
+/// (see the attributes `scale_factor` and `add_offset`), and the fill values and the `NaN` values are transparent.
+///
+/// Returns the error `WriteError::ImageShapeNotValid` if the field does not have 2 dimensions.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{FileReader, ImageOptions, Colormap, export_png};
+/// # use copy_to_tmp_file::{copy_bytes_to_tmp_file, NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME};
+/// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+///
+/// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+/// let options = ImageOptions{colormap: Colormap::Grayscale, ..ImageOptions::default()};
+/// let mut png: Vec<u8> = vec![];
+/// // The 3 latitudes x 5 longitudes of the second record
+/// export_png(&mut file_reader, "temperature_f32", Some(1), &mut png, &options).unwrap();
+///
+/// assert_eq!(b"\x89PNG",                          &png[0..4]);
+/// # tmp_dir.close();
+/// ```
+pub fn export_png<W: Write>(reader: &mut FileReader, var_name: &str, record_index: Option<usize>, writer: W, options: &ImageOptions) -> Result<(), WriteError> {
+    let var: Variable = reader.data_set().get_var(var_name).cloned().ok_or_else(|| WriteError::VariableNotDefined(var_name.to_owned()))?;
+    let (data, shape): (DataVector, Vec<usize>) = match record_index {
+        None => (reader.read_var(var_name)?, var.shape()),
+        Some(record_index) => {
+            if !var.is_record_var() {
+                return Err(WriteError::NotRecordVariable(var_name.to_owned()));
+            }
+            (reader.read_record(var_name, record_index)?, var.shape()[1..].to_vec())
+        },
+    };
+    if shape.len() != 2 || shape.contains(&0) {
+        return Err(WriteError::ImageShapeNotValid{var_name: var_name.to_owned(), shape: shape});
+    }
+    let (num_rows, num_columns): (usize, usize) = (shape[0], shape[1]);
+
+    // Unpacked values, `NaN` for the fill values
+    let fill_value: Option<f64> = fill_value_as_f64(&var);
+    let (scale_factor, add_offset): (f64, f64) = var.packing_attrs();
+    let values: Vec<f64> = data.iter_as_f64().map(|value: f64| match Some(value) == fill_value {
+        true => std::f64::NAN,
+        false => value * scale_factor + add_offset,
+    }).collect();
+    let (min, max): (f64, f64) = match options.range {
+        Some(range) => range,
+        None => values.iter().filter(|value: &&f64| !value.is_nan()).fold(
+            (std::f64::INFINITY, std::f64::NEG_INFINITY),
+            |(min, max): (f64, f64), value: &f64| (min.min(*value), max.max(*value))
+        ),
+    };
+
+    let mut pixels: Vec<u8> = Vec::with_capacity(values.len() * 4);
+    for row in 0..num_rows {
+        let row: usize = match options.flip_rows {
+            true => num_rows - 1 - row,
+            false => row,
+        };
+        for value in values[(row * num_columns)..((row + 1) * num_columns)].iter() {
+            if value.is_nan() {
+                pixels.extend_from_slice(&[0, 0, 0, 0]);
+                continue;
+            }
+            let t: f64 = match max > min {
+                true => (value - min) / (max - min),
+                false => 0.0,
+            };
+            pixels.extend_from_slice(&options.colormap.color(t));
+            pixels.push(255);
+        }
+    }
+
+    let mut encoder: Encoder<W> = Encoder::new(writer, num_columns as u32, num_rows as u32);
+    encoder.set_color(ColorType::Rgba);
+    encoder.set_depth(BitDepth::Eight);
+    let mut png_writer = encoder.write_header().map_err(png_error)?;
+    png_writer.write_image_data(&pixels).map_err(png_error)?;
+    png_writer.finish().map_err(png_error)?;
+    return Ok(());
+}
+
+fn png_error(err: EncodingError) -> WriteError {
+    return WriteError::Png(err.to_string());
+}
@@ -0,0 +1,96 @@
+#![cfg(test)]
+use crate::{DataSet, FileReader, FileWriter, Version, export_png, ImageOptions, Colormap};
+use crate::error::WriteError;
+
+/// Returns the width, the height and the RGBA pixels of the PNG image.
+fn decode_png(png: &[u8]) -> (u32, u32, Vec<u8>) {
+    let decoder = png::Decoder::new(png);
+    let mut png_reader = decoder.read_info().unwrap();
+    let mut pixels: Vec<u8> = vec![0; png_reader.output_buffer_size()];
+    let info = png_reader.next_frame(&mut pixels).unwrap();
+    pixels.truncate(info.buffer_size());
+    return (info.width, info.height, pixels);
+}
+
+fn init_reader() -> FileReader {
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("lat", 2).unwrap();
+    data_set.add_fixed_dim("lon", 3).unwrap();
+    data_set.add_var_i16("temp", &["time", "lat", "lon"]).unwrap();
+    data_set.add_var_attr_i16("temp", "_FillValue", vec![-1]).unwrap();
+    data_set.add_var_attr_f64("temp", "scale_factor", vec![0.5]).unwrap();
+    data_set.add_var_f32("field", &["lat", "lon"]).unwrap();
+    data_set.add_var_f32("lat", &["lat"]).unwrap();
+
+    let mut file_writer: FileWriter = FileWriter::in_memory();
+    file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    file_writer.write_var_i16("temp", &[0, 1, 2, 3, 4, 5, 0, 10, 20, 30, 40, -1]).unwrap();
+    file_writer.write_var_f32("field", &[1.0, 1.0, 1.0, 1.0, 1.0, 1.0]).unwrap();
+    file_writer.write_var_f32("lat", &[0.0, 1.0]).unwrap();
+    return FileReader::from_bytes(file_writer.close_into_bytes().unwrap()).unwrap();
+}
+
+#[test]
+fn test_export_png_record() {
+    let mut file_reader: FileReader = init_reader();
+    let options = ImageOptions{colormap: Colormap::Grayscale, ..ImageOptions::default()};
+    let mut png: Vec<u8> = vec![];
+    export_png(&mut file_reader, "temp", Some(1), &mut png, &options).unwrap();
+
+    let (width, height, pixels): (u32, u32, Vec<u8>) = decode_png(&png);
+    assert_eq!((3, 2),                                      (width, height));
+    // The unpacked values 0.0, 5.0, ..., 20.0 scaled between black and white, the fill value is transparent
+    let levels: Vec<u8> = pixels.chunks(4).map(|pixel: &[u8]| pixel[0]).collect();
+    assert_eq!(vec![0, 64, 128, 191, 255, 0],                levels);
+    let alphas: Vec<u8> = pixels.chunks(4).map(|pixel: &[u8]| pixel[3]).collect();
+    assert_eq!(vec![255, 255, 255, 255, 255, 0],             alphas);
+
+    // Fixed range and flipped rows
+    let options = ImageOptions{colormap: Colormap::Grayscale, range: Some((0.0, 10.0)), flip_rows: true};
+    let mut png: Vec<u8> = vec![];
+    export_png(&mut file_reader, "temp", Some(1), &mut png, &options).unwrap();
+    let (_width, _height, pixels): (u32, u32, Vec<u8>) = decode_png(&png);
+    let levels: Vec<u8> = pixels.chunks(4).map(|pixel: &[u8]| pixel[0]).collect();
+    assert_eq!(vec![255, 255, 0, 0, 128, 255],               levels);
+}
+
+#[test]
+fn test_export_png_viridis() {
+    let mut file_reader: FileReader = init_reader();
+    let mut png: Vec<u8> = vec![];
+    export_png(&mut file_reader, "temp", Some(0), &mut png, &ImageOptions::default()).unwrap();
+    let (_width, _height, pixels): (u32, u32, Vec<u8>) = decode_png(&png);
+    assert_eq!(&[68, 1, 84, 255],                            &pixels[0..4]);
+    assert_eq!(&[253, 231, 37, 255],                         &pixels[20..24]);
+
+    // A constant field has the first color
+    let mut png: Vec<u8> = vec![];
+    export_png(&mut file_reader, "field", None, &mut png, &ImageOptions::default()).unwrap();
+    let (width, height, pixels): (u32, u32, Vec<u8>) = decode_png(&png);
+    assert_eq!((3, 2),                                      (width, height));
+    assert_eq!(true,                                        pixels.chunks(4).all(|pixel: &[u8]| pixel == [68, 1, 84, 255]));
+}
+
+#[test]
+fn test_export_png_errors() {
+    let mut file_reader: FileReader = init_reader();
+    let mut png: Vec<u8> = vec![];
+    assert_eq!(
+        WriteError::ImageShapeNotValid{var_name: String::from("temp"), shape: vec![2, 2, 3]},
+        export_png(&mut file_reader, "temp", None, &mut png, &ImageOptions::default()).unwrap_err()
+    );
+    assert_eq!(
+        WriteError::ImageShapeNotValid{var_name: String::from("lat"), shape: vec![2]},
+        export_png(&mut file_reader, "lat", None, &mut png, &ImageOptions::default()).unwrap_err()
+    );
+    assert_eq!(
+        WriteError::NotRecordVariable(String::from("field")),
+        export_png(&mut file_reader, "field", Some(0), &mut png, &ImageOptions::default()).unwrap_err()
+    );
+    assert_eq!(
+        WriteError::VariableNotDefined(String::from("undef_var")),
+        export_png(&mut file_reader, "undef_var", None, &mut png, &ImageOptions::default()).unwrap_err()
+    );
+    assert_eq!(true,                                        png.is_empty());
+}
@@ -0,0 +1,181 @@
+mod tests_labeled;
+
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use crate::{FileReader, DataSet, DataType, DateTime, SliceArg, TimeUnits, Variable, VariableView};
+use crate::error::ReadError;
+use crate::io::compute_index_range;
+
+/// Label selecting indices along a dimension of a [`LabeledDataset`](struct.LabeledDataset.html).
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{DateTime, Label, SliceArg};
+///
+/// assert_eq!(Label::Value(45.0),                                  Label::from(45.0));
+/// assert_eq!(Label::Range(45.0, 60.0),                            Label::from(45.0..=60.0));
+///
+/// let date_time: DateTime = DateTime::parse("2020-01-01").unwrap();
+/// assert_eq!(Label::Time(date_time.clone()),                      Label::from(date_time));
+/// assert_eq!(Label::Index(SliceArg::Range{start: 0, end: Some(2)}),  Label::from(SliceArg::from(0..2)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Label {
+    /// Selects the index of the nearest coordinate value, the dimension is removed from the view.
+    Value(f64),
+    /// Selects the indices of the coordinate values between the bounds (inclusive).
+    Range(f64, f64),
+    /// Selects the index of the nearest time, the dimension is removed from the view.
+    Time(DateTime),
+    /// Selects the indices of the times between the bounds (inclusive).
+    TimeRange(DateTime, DateTime),
+    /// Selects by indices, as done by [`VariableView::slice`](struct.VariableView.html#method.slice).
+    Index(SliceArg),
+}
+
+impl From<f64> for Label {
+    fn from(value: f64) -> Self {
+        return Label::Value(value);
+    }
+}
+
+impl From<RangeInclusive<f64>> for Label {
+    fn from(range: RangeInclusive<f64>) -> Self {
+        return Label::Range(*range.start(), *range.end());
+    }
+}
+
+impl From<DateTime> for Label {
+    fn from(date_time: DateTime) -> Self {
+        return Label::Time(date_time);
+    }
+}
+
+impl From<RangeInclusive<DateTime>> for Label {
+    fn from(range: RangeInclusive<DateTime>) -> Self {
+        let (start, end): (DateTime, DateTime) = range.into_inner();
+        return Label::TimeRange(start, end);
+    }
+}
+
+impl From<SliceArg> for Label {
+    fn from(arg: SliceArg) -> Self {
+        return Label::Index(arg);
+    }
+}
+
+/// Data set whose dimensions are labeled by their *coordinate variables* (the 1-D variables having the same name as
+/// their dimension), in the manner of the Python library [xarray](https://xarray.dev).
+///
+/// The coordinate values are read once by [`new`](#method.new). The selections by labels are translated into
+/// [`VariableView`](struct.VariableView.html)s, whose data are read with [`FileReader::read_view`](struct.FileReader.html#method.read_view).
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{FileReader, LabeledDataset, Label, VariableView, DataVector, DateTime};
+/// # use copy_to_tmp_file::{copy_bytes_to_tmp_file, NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME};
+/// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+///
+/// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+/// let labeled: LabeledDataset = LabeledDataset::new(&mut file_reader).unwrap();
+/// assert_eq!(Some(&[0.0, 0.5, 1.0][..]),                  labeled.coord_values("latitude"));
+///
+/// // The temperatures at the latitude `0.5`, between the longitudes `1.0` and `2.0`
+/// let view: VariableView = labeled.sel("temperature_i32", &[
+///     ("latitude", Label::from(0.5)),
+///     ("longitude", Label::from(1.0..=2.0)),
+/// ]).unwrap();
+/// assert_eq!(vec!["time", "longitude"],                   view.dim_names());
+/// assert_eq!(DataVector::I32(vec![7, 8, 9, 22, 23, 24]),  file_reader.read_view(&view).unwrap());
+/// # tmp_dir.close();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabeledDataset {
+    data_set: DataSet,
+    /// Values of the coordinate variables, indexed by the name of their dimension
+    coords: HashMap<String, Vec<f64>>,
+}
+
+impl LabeledDataset {
+
+    /// Reads the numeric coordinate variables of the data set.
+    pub fn new(reader: &mut FileReader) -> Result<LabeledDataset, ReadError> {
+        let data_set: DataSet = reader.data_set().clone();
+        let mut coords: HashMap<String, Vec<f64>> = HashMap::new();
+        for dim in data_set.get_dims() {
+            let dim_name: String = dim.name();
+            let is_coord_var: bool = data_set.get_var(&dim_name)
+                .map(|var: &Variable| var.dim_names() == [dim_name.as_str()] && var.data_type() != DataType::U8)
+                .unwrap_or(false);
+            if is_coord_var {
+                let values: Vec<f64> = reader.read_var_as_f64(&dim_name)?;
+                coords.insert(dim_name, values);
+            }
+        }
+        return Ok(LabeledDataset{
+            data_set: data_set,
+            coords: coords,
+        });
+    }
+
+    /// Returns the data set.
+    pub fn data_set(&self) -> &DataSet {
+        return &self.data_set;
+    }
+
+    /// Returns the values of the coordinate variable of the dimension (the fill values being `NaN`).
+    pub fn coord_values(&self, dim_name: &str) -> Option<&[f64]> {
+        return self.coords.get(dim_name).map(|values: &Vec<f64>| &values[..]);
+    }
+
+    /// Returns the selection of the `label` along the dimension, as done by the method [`sel`](#method.sel).
+    pub fn sel_dim(&self, dim_name: &str, label: &Label) -> Result<SliceArg, ReadError> {
+        if let Label::Index(arg) = label {
+            return Ok(arg.clone());
+        }
+        let values: &[f64] = self.coord_values(dim_name).ok_or_else(|| ReadError::CoordinateVariableNotValid(dim_name.to_owned()))?;
+        let to_value = |date_time: &DateTime| -> Result<f64, ReadError> {
+            let time_var: &Variable = self.data_set.get_var(dim_name).ok_or_else(|| ReadError::CoordinateVariableNotValid(dim_name.to_owned()))?;
+            return Ok(TimeUnits::from_var(time_var)?.to_value(date_time));
+        };
+        let arg: SliceArg = match label {
+            Label::Value(value) => SliceArg::Index(nearest_index(dim_name, values, *value)?),
+            Label::Range(min, max) => SliceArg::from(compute_index_range(values, *min, *max)),
+            Label::Time(date_time) => SliceArg::Index(nearest_index(dim_name, values, to_value(date_time)?)?),
+            Label::TimeRange(start, end) => SliceArg::from(compute_index_range(values, to_value(start)?, to_value(end)?)),
+            Label::Index(arg) => arg.clone(),
+        };
+        return Ok(arg);
+    }
+
+    /// Returns the view of the variable selected by the `labels` of some of its dimensions, the other dimensions being fully selected.
+    ///
+    /// - The values (`Label::Value` and `Label::Time`) select the nearest coordinate, and remove the dimension from the view.
+    /// - The ranges (`Label::Range` and `Label::TimeRange`) select the coordinates between their bounds, possibly none.
+    /// - The times are converted with the attribute `units` of the time coordinate variable.
+    pub fn sel(&self, var_name: &str, labels: &[(&str, Label)]) -> Result<VariableView, ReadError> {
+        let var: &Variable = self.data_set.get_var(var_name).ok_or_else(|| ReadError::VariableNotDefined(var_name.to_owned()))?;
+        let dim_names: Vec<String> = var.dim_names();
+        let mut args: Vec<SliceArg> = vec![SliceArg::from(..); dim_names.len()];
+        for (dim_name, label) in labels.iter() {
+            let dim_index: usize = dim_names.iter().position(|var_dim_name: &String| var_dim_name == dim_name)
+                .ok_or_else(|| ReadError::DimensionNotDefined{var_name: var_name.to_owned(), dim_name: (*dim_name).to_owned()})?;
+            args[dim_index] = self.sel_dim(dim_name, label)?;
+        }
+        return VariableView::new(var).slice(&args);
+    }
+}
+
+/// Returns the index of the value nearest to `value`, the `NaN` values are ignored.
+fn nearest_index(dim_name: &str, values: &[f64], value: f64) -> Result<usize, ReadError> {
+    return values.iter().enumerate()
+        .filter(|(_index, coord_value): &(usize, &f64)| !coord_value.is_nan())
+        .min_by(|(_index_1, value_1): &(usize, &f64), (_index_2, value_2): &(usize, &f64)| {
+            (*value_1 - value).abs().partial_cmp(&(*value_2 - value).abs()).unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map(|(index, _coord_value): (usize, &f64)| index)
+        .ok_or_else(|| ReadError::CoordinateVariableNotValid(dim_name.to_owned()));
+}
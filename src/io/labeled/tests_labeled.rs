@@ -0,0 +1,85 @@
+#![cfg(test)]
+use std::path::PathBuf;
+
+use tempdir::TempDir;
+
+use crate::{DataSet, DataVector, DateTime, FileReader, FileWriter, Label, LabeledDataset, SliceArg, Version, VariableView};
+use crate::error::ReadError;
+
+const TMP_DIR_PREFIX: &str = "netcdf3_tests_";
+
+fn write_test_file(file_path: &PathBuf) {
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 4).unwrap();
+    data_set.add_fixed_dim("lat", 3).unwrap();
+    data_set.add_fixed_dim("x", 2).unwrap();
+    data_set.add_var_f64("time", &["time"]).unwrap();
+    data_set.add_var_attr_string("time", "units", "days since 2020-01-01").unwrap();
+    // Decreasing latitudes
+    data_set.add_var_f32("lat", &["lat"]).unwrap();
+    data_set.add_var_i32("temp", &["time", "lat", "x"]).unwrap();
+    let mut file_writer: FileWriter = FileWriter::open(file_path).unwrap();
+    file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    file_writer.write_var_f64("time", &[0.0, 1.0, 2.0, 3.0]).unwrap();
+    file_writer.write_var_f32("lat", &[60.0, 45.0, 30.0]).unwrap();
+    file_writer.write_var_i32("temp", &(0..24).collect::<Vec<i32>>()).unwrap();
+    file_writer.close().unwrap();
+}
+
+#[test]
+fn test_labeled_dataset_sel() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let file_path: PathBuf = tmp_dir.path().join("labeled.nc");
+    write_test_file(&file_path);
+
+    let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    let labeled: LabeledDataset = LabeledDataset::new(&mut file_reader).unwrap();
+    assert_eq!(Some(&[0.0, 1.0, 2.0, 3.0][..]),     labeled.coord_values("time"));
+    assert_eq!(Some(&[60.0, 45.0, 30.0][..]),       labeled.coord_values("lat"));
+    // No coordinate variable
+    assert_eq!(None,                                labeled.coord_values("x"));
+
+    // Nearest value, and range of decreasing values
+    assert_eq!(SliceArg::Index(1),                  labeled.sel_dim("lat", &Label::from(50.0)).unwrap());
+    assert_eq!(SliceArg::from(0..2),                labeled.sel_dim("lat", &Label::from(40.0..=70.0)).unwrap());
+    assert_eq!(SliceArg::from(0..0),                labeled.sel_dim("lat", &Label::from(0.0..=10.0)).unwrap());
+
+    // Times
+    let start: DateTime = DateTime::parse("2020-01-02").unwrap();
+    let end: DateTime = DateTime::parse("2020-01-03T12:00:00").unwrap();
+    assert_eq!(SliceArg::Index(1),                  labeled.sel_dim("time", &Label::from(start.clone())).unwrap());
+    assert_eq!(SliceArg::from(1..3),                labeled.sel_dim("time", &Label::from(start.clone()..=end.clone())).unwrap());
+
+    let view: VariableView = labeled.sel("temp", &[
+        ("time", Label::from(start..=end)),
+        ("lat", Label::from(30.0)),
+    ]).unwrap();
+    assert_eq!(vec!["time", "x"],                   view.dim_names());
+    assert_eq!(DataVector::I32(vec![10, 11, 16, 17]),   file_reader.read_view(&view).unwrap());
+
+    // Selection by indices
+    let view: VariableView = labeled.sel("temp", &[
+        ("x", Label::from(SliceArg::from(1))),
+        ("time", Label::from(SliceArg::from(3..))),
+    ]).unwrap();
+    assert_eq!(DataVector::I32(vec![19, 21, 23]),   file_reader.read_view(&view).unwrap());
+
+    // Errors
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        labeled.sel("undef_var", &[]).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::DimensionNotDefined{var_name: String::from("lat"), dim_name: String::from("time")},
+        labeled.sel("lat", &[("time", Label::from(0.0))]).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::CoordinateVariableNotValid(String::from("x")),
+        labeled.sel("temp", &[("x", Label::from(0.0))]).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::TimeUnitsNotValid(String::from("lat")),
+        labeled.sel("temp", &[("lat", Label::from(DateTime::parse("2020-01-01").unwrap()))]).unwrap_err()
+    );
+    tmp_dir.close().unwrap();
+}
@@ -0,0 +1,299 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::path::Path;
+
+use netcdf::{AttributeValue, Options};
+use netcdf::types::{NcTypeDescriptor, NcVariableType, IntType, FloatType};
+
+use crate::{DataSet, DataType, DataVector, Variable, Attribute};
+use crate::error::{ReadError, WriteError};
+
+/// Character of the NetCDF-C library (`NC_CHAR`), the values of the `char` variables cannot be written as `u8`
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+struct NcChar(u8);
+
+unsafe impl NcTypeDescriptor for NcChar {
+    fn type_descriptor() -> NcVariableType {
+        return NcVariableType::Char;
+    }
+}
+
+/// Reads a NetCDF-4 file restricted to the *classic model*, and flattens it into a [`DataSet`](struct.DataSet.html) and the data of all its variables.
+///
+/// Requires the optional feature `netcdf`, which binds the NetCDF-C library through the crate [netcdf](https://crates.io/crates/netcdf).
+///
+/// All the data are loaded into memory, they can then be written into a NetCDF-3 file with a [`FileWriter`](struct.FileWriter.html).
+///
+/// Returns the error `ReadError::Netcdf4ModelNotSupported` if the file uses a feature outside of the classic model
+/// (groups, several *unlimited-size* dimensions, strings, unsigned bytes or user-defined types).
+///
+/// # Example
+///
+/// ```no_run
+/// use std::collections::HashMap;
+/// use netcdf3::{FileWriter, DataSet, DataVector, Version};
+///
+/// let (data_set, data): (DataSet, HashMap<String, DataVector>) = netcdf3::read_netcdf4("netcdf4_classic_model.nc").unwrap();
+///
+/// let mut file_writer: FileWriter = FileWriter::create_new("netcdf3.nc").unwrap();
+/// file_writer.set_def(&data_set, Version::Offset64Bit, 0).unwrap();
+/// for var in data_set.get_vars() {
+///     let shape: Vec<usize> = var.get_dims().iter().map(|dim| dim.size()).collect();
+///     file_writer.write_var_slice(var.name(), &vec![0; shape.len()], &shape, &data[var.name()]).unwrap();
+/// }
+/// file_writer.close().unwrap();
+/// ```
+pub fn read_netcdf4<P: AsRef<Path>>(input_file_path: P) -> Result<(DataSet, HashMap<String, DataVector>), ReadError> {
+    let file: netcdf::File = netcdf::open(input_file_path)?;
+    let data_set: DataSet = DataSet::try_from(&file)?;
+    let mut data: HashMap<String, DataVector> = HashMap::new();
+    for var in file.variables() {
+        data.insert(var.name(), DataVector::try_from(&var)?);
+    }
+    return Ok((data_set, data));
+}
+
+/// Writes a data set and the data of its variables into a NetCDF-4 file restricted to the *classic model*.
+///
+/// Requires the optional feature `netcdf`, which binds the NetCDF-C library through the crate [netcdf](https://crates.io/crates/netcdf).
+///
+/// The variables missing from `data` are not written (they contain their fill values).
+///
+/// # Example
+///
+/// ```no_run
+/// use std::collections::HashMap;
+/// use netcdf3::{FileReader, DataSet, DataVector};
+///
+/// let mut file_reader: FileReader = FileReader::open("netcdf3.nc").unwrap();
+/// let data: HashMap<String, DataVector> = file_reader.read_all_vars().unwrap();
+/// let data_set: DataSet = file_reader.data_set().clone();
+///
+/// netcdf3::write_netcdf4("netcdf4_classic_model.nc", &data_set, &data).unwrap();
+/// ```
+pub fn write_netcdf4<P: AsRef<Path>>(output_file_path: P, data_set: &DataSet, data: &HashMap<String, DataVector>) -> Result<(), WriteError> {
+    let mut file: netcdf::FileMut = netcdf::create_with(output_file_path, Options::NETCDF4 | Options::CLASSIC)?;
+    define_netcdf4(&mut file, data_set)?;
+    for var in data_set.get_vars() {
+        if let Some(var_data) = data.get(var.name()) {
+            write_netcdf4_var(&mut file, data_set, var.name(), var_data)?;
+        }
+    }
+    file.close()?;
+    return Ok(());
+}
+
+/// Defines the dimensions, the variables and the attributes of the data set into a file opened with the crate [netcdf](https://crates.io/crates/netcdf).
+///
+/// Requires the optional feature `netcdf`.
+///
+/// The `char` attributes are written as text, their bytes being decoded as UTF-8 (the invalid sequences are replaced).
+/// The data of the variables can then be written with [`write_netcdf4_var`](fn.write_netcdf4_var.html).
+pub fn define_netcdf4(file: &mut netcdf::FileMut, data_set: &DataSet) -> Result<(), WriteError> {
+    for dim in data_set.get_dims() {
+        match dim.is_unlimited() {
+            true => file.add_unlimited_dimension(&dim.name())?,
+            false => file.add_dimension(&dim.name(), dim.size())?,
+        };
+    }
+    for attr in data_set.attrs() {
+        file.add_attribute(attr.name(), attr_value(attr))?;
+    }
+    for var in data_set.get_vars() {
+        let dim_names: Vec<String> = var.dim_names();
+        let dim_names: Vec<&str> = dim_names.iter().map(|dim_name: &String| dim_name.as_str()).collect();
+        let mut nc_var: netcdf::VariableMut = file.add_variable_with_type(var.name(), &dim_names, &nc_var_type(var.data_type()))?;
+        for attr in var.attrs() {
+            nc_var.put_attribute(attr.name(), attr_value(attr))?;
+        }
+    }
+    return Ok(());
+}
+
+/// Writes the data of a variable of the data set into a file opened with the crate [netcdf](https://crates.io/crates/netcdf),
+/// after its definition by [`define_netcdf4`](fn.define_netcdf4.html).
+///
+/// Requires the optional feature `netcdf`.
+///
+/// The data must contain all the values of the variable (all the records for the *record* variables).
+pub fn write_netcdf4_var(file: &mut netcdf::FileMut, data_set: &DataSet, var_name: &str, data: &DataVector) -> Result<(), WriteError> {
+    let var: &Variable = data_set.get_var(var_name).ok_or_else(|| WriteError::VariableNotDefined(var_name.to_owned()))?;
+    if data.data_type() != var.data_type() {
+        return Err(WriteError::VariableMismatchDataType{var_name: var_name.to_owned(), req: var.data_type(), get: data.data_type()});
+    }
+    if data.len() != var.len() {
+        return Err(WriteError::VariableMismatchDataLength{var_name: var_name.to_owned(), req: var.len(), get: data.len()});
+    }
+    let mut nc_var: netcdf::VariableMut = file.variable_mut(var_name).ok_or_else(|| WriteError::VariableNotDefined(var_name.to_owned()))?;
+    // The number of records is given, the *unlimited-size* dimension of the file being empty
+    let shape: Vec<usize> = var.shape();
+    let extents = (vec![0; shape.len()], shape);
+    match data {
+        DataVector::I8(values) => nc_var.put_values(values, extents)?,
+        DataVector::U8(chars) => {
+            let chars: Vec<NcChar> = chars.iter().map(|c: &u8| NcChar(*c)).collect();
+            nc_var.put_values(&chars, extents)?
+        },
+        DataVector::I16(values) => nc_var.put_values(values, extents)?,
+        DataVector::I32(values) => nc_var.put_values(values, extents)?,
+        DataVector::F32(values) => nc_var.put_values(values, extents)?,
+        DataVector::F64(values) => nc_var.put_values(values, extents)?,
+        DataVector::U16(values) => nc_var.put_values(values, extents)?,
+        DataVector::U32(values) => nc_var.put_values(values, extents)?,
+        DataVector::I64(values) => nc_var.put_values(values, extents)?,
+        DataVector::U64(values) => nc_var.put_values(values, extents)?,
+    }
+    return Ok(());
+}
+
+/// Builds the data set of a NetCDF-4 file restricted to the *classic model* (without the data of its variables).
+///
+/// Requires the optional feature `netcdf`.
+///
+/// Returns the error `ReadError::Netcdf4ModelNotSupported` if the file uses a feature outside of the classic model
+/// (groups, several *unlimited-size* dimensions, strings, unsigned bytes or user-defined types).
+impl<'a> TryFrom<&'a netcdf::File> for DataSet {
+    type Error = ReadError;
+
+    fn try_from(file: &'a netcdf::File) -> Result<DataSet, ReadError> {
+        if file.groups()?.next().is_some() {
+            return Err(ReadError::Netcdf4ModelNotSupported(String::from("groups")));
+        }
+
+        let mut data_set: DataSet = DataSet::new();
+        // Dimensions
+        for dim in file.dimensions() {
+            if dim.is_unlimited() {
+                if data_set.has_unlimited_dim() {
+                    return Err(ReadError::Netcdf4ModelNotSupported(String::from("several unlimited-size dimensions")));
+                }
+                data_set.set_unlimited_dim(dim.name(), dim.len())?;
+            } else {
+                data_set.add_fixed_dim(dim.name(), dim.len())?;
+            }
+        }
+        // Global attributes
+        for attr in file.attributes() {
+            let attr_data: DataVector = DataVector::try_from(attr.value()?)?;
+            data_set.add_global_attr_data(attr.name(), attr_data)?;
+        }
+        // Variables and their attributes
+        for var in file.variables() {
+            let var_name: String = var.name();
+            let dim_names: Vec<String> = var.dimensions().iter().map(|dim: &netcdf::Dimension| dim.name()).collect();
+            data_set.add_var(&var_name, &dim_names, convert_data_type(&var.vartype())?)?;
+            for attr in var.attributes() {
+                let attr_data: DataVector = DataVector::try_from(attr.value()?)?;
+                data_set.add_var_attr_data(&var_name, attr.name(), attr_data)?;
+            }
+        }
+        return Ok(data_set);
+    }
+}
+
+/// Reads all the values of a NetCDF-4 variable.
+///
+/// Requires the optional feature `netcdf`.
+impl<'a, 'g> TryFrom<&'a netcdf::Variable<'g>> for DataVector {
+    type Error = ReadError;
+
+    fn try_from(var: &'a netcdf::Variable<'g>) -> Result<DataVector, ReadError> {
+        let var_data: DataVector = match convert_data_type(&var.vartype())? {
+            DataType::I8 => DataVector::I8(var.get_values(..)?),
+            // The NetCDF-C chars are read as raw bytes
+            DataType::U8 => DataVector::U8(var.get_raw_values(..)?),
+            DataType::I16 => DataVector::I16(var.get_values(..)?),
+            DataType::I32 => DataVector::I32(var.get_values(..)?),
+            DataType::F32 => DataVector::F32(var.get_values(..)?),
+            DataType::F64 => DataVector::F64(var.get_values(..)?),
+            DataType::U16 => DataVector::U16(var.get_values(..)?),
+            DataType::U32 => DataVector::U32(var.get_values(..)?),
+            DataType::I64 => DataVector::I64(var.get_values(..)?),
+            DataType::U64 => DataVector::U64(var.get_values(..)?),
+        };
+        return Ok(var_data);
+    }
+}
+
+/// Converts the value of a NetCDF-4 attribute, the strings are converted to bytes.
+///
+/// Requires the optional feature `netcdf`.
+impl TryFrom<AttributeValue> for DataVector {
+    type Error = ReadError;
+
+    fn try_from(attr_value: AttributeValue) -> Result<DataVector, ReadError> {
+        return match attr_value {
+            AttributeValue::Schar(value) => Ok(DataVector::I8(vec![value])),
+            AttributeValue::Schars(values) => Ok(DataVector::I8(values)),
+            AttributeValue::Str(value) => Ok(DataVector::U8(value.into_bytes())),
+            AttributeValue::Short(value) => Ok(DataVector::I16(vec![value])),
+            AttributeValue::Shorts(values) => Ok(DataVector::I16(values)),
+            AttributeValue::Int(value) => Ok(DataVector::I32(vec![value])),
+            AttributeValue::Ints(values) => Ok(DataVector::I32(values)),
+            AttributeValue::Float(value) => Ok(DataVector::F32(vec![value])),
+            AttributeValue::Floats(values) => Ok(DataVector::F32(values)),
+            AttributeValue::Double(value) => Ok(DataVector::F64(vec![value])),
+            AttributeValue::Doubles(values) => Ok(DataVector::F64(values)),
+            AttributeValue::Ushort(value) => Ok(DataVector::U16(vec![value])),
+            AttributeValue::Ushorts(values) => Ok(DataVector::U16(values)),
+            AttributeValue::Uint(value) => Ok(DataVector::U32(vec![value])),
+            AttributeValue::Uints(values) => Ok(DataVector::U32(values)),
+            AttributeValue::Longlong(value) => Ok(DataVector::I64(vec![value])),
+            AttributeValue::Longlongs(values) => Ok(DataVector::I64(values)),
+            AttributeValue::Ulonglong(value) => Ok(DataVector::U64(vec![value])),
+            AttributeValue::Ulonglongs(values) => Ok(DataVector::U64(values)),
+            AttributeValue::Uchar(_) | AttributeValue::Uchars(_) => Err(ReadError::Netcdf4ModelNotSupported(String::from("unsigned byte attributes"))),
+            AttributeValue::Strs(_) => Err(ReadError::Netcdf4ModelNotSupported(String::from("string array attributes"))),
+        };
+    }
+}
+
+/// Returns the NetCDF-4 value of an attribute, the `char` attributes being decoded as UTF-8 text.
+fn attr_value(attr: &Attribute) -> AttributeValue {
+    return match &attr.data {
+        DataVector::I8(values) => AttributeValue::Schars(values.clone()),
+        DataVector::U8(chars) => AttributeValue::Str(String::from_utf8_lossy(chars).into_owned()),
+        DataVector::I16(values) => AttributeValue::Shorts(values.clone()),
+        DataVector::I32(values) => AttributeValue::Ints(values.clone()),
+        DataVector::F32(values) => AttributeValue::Floats(values.clone()),
+        DataVector::F64(values) => AttributeValue::Doubles(values.clone()),
+        DataVector::U16(values) => AttributeValue::Ushorts(values.clone()),
+        DataVector::U32(values) => AttributeValue::Uints(values.clone()),
+        DataVector::I64(values) => AttributeValue::Longlongs(values.clone()),
+        DataVector::U64(values) => AttributeValue::Ulonglongs(values.clone()),
+    };
+}
+
+/// Returns the NetCDF-4 type of a NetCDF-3 data type.
+fn nc_var_type(data_type: DataType) -> NcVariableType {
+    return match data_type {
+        DataType::I8 => NcVariableType::Int(IntType::I8),
+        DataType::U8 => NcVariableType::Char,
+        DataType::I16 => NcVariableType::Int(IntType::I16),
+        DataType::I32 => NcVariableType::Int(IntType::I32),
+        DataType::F32 => NcVariableType::Float(FloatType::F32),
+        DataType::F64 => NcVariableType::Float(FloatType::F64),
+        DataType::U16 => NcVariableType::Int(IntType::U16),
+        DataType::U32 => NcVariableType::Int(IntType::U32),
+        DataType::I64 => NcVariableType::Int(IntType::I64),
+        DataType::U64 => NcVariableType::Int(IntType::U64),
+    };
+}
+
+/// Returns the NetCDF-3 data type of a NetCDF-4 variable.
+fn convert_data_type(var_type: &NcVariableType) -> Result<DataType, ReadError> {
+    return match var_type {
+        NcVariableType::Int(IntType::I8) => Ok(DataType::I8),
+        NcVariableType::Char => Ok(DataType::U8),
+        NcVariableType::Int(IntType::I16) => Ok(DataType::I16),
+        NcVariableType::Int(IntType::I32) => Ok(DataType::I32),
+        NcVariableType::Float(FloatType::F32) => Ok(DataType::F32),
+        NcVariableType::Float(FloatType::F64) => Ok(DataType::F64),
+        NcVariableType::Int(IntType::U16) => Ok(DataType::U16),
+        NcVariableType::Int(IntType::U32) => Ok(DataType::U32),
+        NcVariableType::Int(IntType::I64) => Ok(DataType::I64),
+        NcVariableType::Int(IntType::U64) => Ok(DataType::U64),
+        _ => Err(ReadError::Netcdf4ModelNotSupported(format!("data type {:?}", var_type))),
+    };
+}
@@ -0,0 +1,84 @@
+mod tests_parquet;
+
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, RecordBatch, UInt64Array};
+use arrow_schema::{ArrowError, DataType as ArrowDataType, Field, Schema, SchemaRef};
+use parquet::arrow::ArrowWriter;
+use parquet::errors::ParquetError;
+
+use crate::{FileReader, DataVector};
+use crate::error::WriteError;
+use crate::io::arrow::{arrow_data_type, to_arrow_array};
+use crate::io::flatten::{FlatVars, gather};
+
+/// Maximum number of rows of the row groups written by [`export_parquet`](fn.export_parquet.html)
+const PARQUET_BATCH_LEN: usize = 65_536;
+
+/// Exports variables into a Parquet file, flattened with the coordinates of their dimensions.
+///
+/// Requires the optional feature `parquet`.
+///
+/// The variables must have the same dimensions. The file has one row per element of the variables, and the columns :
+/// - one column per dimension, containing the values of its *coordinate variable* (the 1-D variable having the same
+///   name as the dimension), or the indices along the dimension,
+/// - then one column per exported variable (the coordinate variables are only exported once).
+///
+/// The fill values are null values. The variables are read and written by blocks of rows along their first dimension.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{FileReader, export_parquet};
+/// # use copy_to_tmp_file::{copy_bytes_to_tmp_file, NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME};
+/// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+/// # let output_file_path = tmp_dir.path().join("temperature.parquet");
+///
+/// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+/// // Columns : `time`, `latitude`, `longitude`, `temperature_f32` and `temperature_f64`
+/// export_parquet(&mut file_reader, &["temperature_f32", "temperature_f64"], &output_file_path).unwrap();
+/// # tmp_dir.close();
+/// ```
+pub fn export_parquet<T: AsRef<str>, P: AsRef<Path>>(reader: &mut FileReader, var_names: &[T], output_file_path: P) -> Result<(), WriteError> {
+    let flat_vars = FlatVars::new(reader, var_names)?;
+
+    let mut fields: Vec<Field> = vec![];
+    for (dim_name, coord_var) in flat_vars.dim_names.iter().zip(flat_vars.coord_vars.iter()) {
+        let data_type: ArrowDataType = match coord_var {
+            None => ArrowDataType::UInt64,
+            Some((coord_var, _coord_data)) => arrow_data_type(coord_var.data_type()),
+        };
+        fields.push(Field::new(dim_name, data_type, true));
+    }
+    for var in flat_vars.value_vars.iter() {
+        fields.push(Field::new(var.name(), arrow_data_type(var.data_type()), true));
+    }
+    let schema: SchemaRef = Arc::new(Schema::new(fields));
+
+    let output_file: File = File::create(output_file_path)?;
+    let mut writer: ArrowWriter<File> = ArrowWriter::try_new(output_file, schema.clone(), None).map_err(parquet_error)?;
+    for (start, count) in flat_vars.blocks(PARQUET_BATCH_LEN) {
+        let mut columns: Vec<ArrayRef> = vec![];
+        for (dim_index, coord_var) in flat_vars.coord_vars.iter().enumerate() {
+            let indices: Vec<usize> = FlatVars::dim_indices(dim_index, &start, &count);
+            columns.push(match coord_var {
+                None => Arc::new(indices.into_iter().map(|index: usize| index as u64).collect::<UInt64Array>()),
+                Some((coord_var, coord_data)) => to_arrow_array(coord_var, gather(coord_data, &indices)),
+            });
+        }
+        for var in flat_vars.value_vars.iter() {
+            let data: DataVector = FlatVars::read_block(reader, var, &start, &count)?;
+            columns.push(to_arrow_array(var, data));
+        }
+        let batch: RecordBatch = RecordBatch::try_new(schema.clone(), columns).map_err(|err: ArrowError| WriteError::Parquet(err.to_string()))?;
+        writer.write(&batch).map_err(parquet_error)?;
+    }
+    writer.close().map_err(parquet_error)?;
+    return Ok(());
+}
+
+fn parquet_error(err: ParquetError) -> WriteError {
+    return WriteError::Parquet(err.to_string());
+}
@@ -0,0 +1,104 @@
+#![cfg(test)]
+use std::fs::File;
+use std::path::PathBuf;
+
+use arrow_array::{Array, Float32Array, Float64Array, Int16Array, RecordBatch, UInt64Array};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use tempdir::TempDir;
+
+use crate::{DataSet, FileReader, FileWriter, Version, export_parquet};
+use crate::error::WriteError;
+
+use super::PARQUET_BATCH_LEN;
+
+const TMP_DIR_PREFIX: &str = "netcdf3_tests_";
+
+fn read_parquet_file(file_path: &PathBuf) -> Vec<RecordBatch> {
+    let file: File = File::open(file_path).unwrap();
+    let reader = ParquetRecordBatchReaderBuilder::try_new(file).unwrap().build().unwrap();
+    return reader.map(|batch| batch.unwrap()).collect();
+}
+
+#[test]
+fn test_export_parquet() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let input_file_path: PathBuf = tmp_dir.path().join("input.nc");
+    let output_file_path: PathBuf = tmp_dir.path().join("output.parquet");
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.set_unlimited_dim("time", 2).unwrap();
+        data_set.add_fixed_dim("lat", 3).unwrap();
+        data_set.add_var_f64("time", &["time"]).unwrap();
+        data_set.add_var_i16("temp", &["time", "lat"]).unwrap();
+        data_set.add_var_attr_i16("temp", "_FillValue", vec![-1]).unwrap();
+        data_set.add_var_f32("humidity", &["time", "lat"]).unwrap();
+        data_set.add_var_f32("lat_bounds", &["lat"]).unwrap();
+        let mut file_writer: FileWriter = FileWriter::open(&input_file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        file_writer.write_var_f64("time", &[100.0, 200.0]).unwrap();
+        file_writer.write_var_i16("temp", &[1, 2, 3, -1, 5, 6]).unwrap();
+        file_writer.write_var_f32("humidity", &[0.1, 0.2, 0.3, 0.4, 0.5, 0.6]).unwrap();
+        file_writer.write_var_f32("lat_bounds", &[0.0, 1.0, 2.0]).unwrap();
+        file_writer.close().unwrap();
+    }
+    let mut file_reader: FileReader = FileReader::open(&input_file_path).unwrap();
+    export_parquet(&mut file_reader, &["temp", "humidity"], &output_file_path).unwrap();
+
+    let batches: Vec<RecordBatch> = read_parquet_file(&output_file_path);
+    assert_eq!(1,                                   batches.len());
+    let batch: &RecordBatch = &batches[0];
+    assert_eq!(vec!["time", "lat", "temp", "humidity"],     batch.schema().fields().iter().map(|field| field.name().as_str()).collect::<Vec<&str>>());
+    assert_eq!(6,                                   batch.num_rows());
+    // The coordinate variable `time`, and the indices along the dimension `lat` (no coordinate variable)
+    assert_eq!(&[100.0, 100.0, 100.0, 200.0, 200.0, 200.0],  batch.column(0).as_any().downcast_ref::<Float64Array>().unwrap().values().as_ref());
+    assert_eq!(&[0, 1, 2, 0, 1, 2],                 batch.column(1).as_any().downcast_ref::<UInt64Array>().unwrap().values().as_ref());
+    let temp = batch.column(2).as_any().downcast_ref::<Int16Array>().unwrap();
+    assert_eq!(true,                                temp.is_null(3));
+    assert_eq!(6,                                   temp.value(5));
+    let humidity = batch.column(3).as_any().downcast_ref::<Float32Array>().unwrap();
+    assert_eq!(&[0.1, 0.2, 0.3, 0.4, 0.5, 0.6],     humidity.values().as_ref());
+
+    // Errors
+    assert_eq!(
+        WriteError::VariableMismatchDims{var_name: String::from("lat_bounds"), req: vec![String::from("time"), String::from("lat")], get: vec![String::from("lat")]},
+        export_parquet(&mut file_reader, &["temp", "lat_bounds"], &output_file_path).unwrap_err()
+    );
+    assert_eq!(
+        WriteError::VariableNotDefined(String::from("undef_var")),
+        export_parquet(&mut file_reader, &["undef_var"], &output_file_path).unwrap_err()
+    );
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_export_parquet_by_blocks() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let input_file_path: PathBuf = tmp_dir.path().join("input.nc");
+    let output_file_path: PathBuf = tmp_dir.path().join("output.parquet");
+    let num_rows: usize = 2 * PARQUET_BATCH_LEN + 10;
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.add_fixed_dim("x", num_rows).unwrap();
+        data_set.add_var_f64("x", &["x"]).unwrap();
+        data_set.add_var_f64("scalar", &[] as &[&str]).unwrap();
+        let mut file_writer: FileWriter = FileWriter::open(&input_file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        file_writer.write_var_f64("x", &(0..num_rows).map(|i: usize| i as f64).collect::<Vec<f64>>()).unwrap();
+        file_writer.write_var_f64("scalar", &[1.5]).unwrap();
+        file_writer.close().unwrap();
+    }
+    let mut file_reader: FileReader = FileReader::open(&input_file_path).unwrap();
+    export_parquet(&mut file_reader, &["x"], &output_file_path).unwrap();
+    let batches: Vec<RecordBatch> = read_parquet_file(&output_file_path);
+    assert_eq!(num_rows,                            batches.iter().map(|batch: &RecordBatch| batch.num_rows()).sum::<usize>());
+    let last_batch: &RecordBatch = batches.last().unwrap();
+    assert_eq!(1,                                   last_batch.num_columns());
+    let x = last_batch.column(0).as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!((num_rows - 1) as f64,               x.value(x.len() - 1));
+
+    // The scalar variables have a single row
+    export_parquet(&mut file_reader, &["scalar"], &output_file_path).unwrap();
+    let batches: Vec<RecordBatch> = read_parquet_file(&output_file_path);
+    assert_eq!(1,                                   batches[0].num_rows());
+    tmp_dir.close().unwrap();
+}
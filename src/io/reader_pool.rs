@@ -0,0 +1,151 @@
+mod tests_reader_pool;
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::FileReader;
+use crate::error::ReadError;
+use crate::io::Storage;
+use crate::io::file_reader::ParsedHeader;
+
+/// Version of a file, compared to know if its cached header is still valid.
+#[derive(Debug, Clone, PartialEq)]
+struct FileStamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+impl FileStamp {
+    fn new(file_path: &Path) -> Result<FileStamp, ReadError> {
+        let metadata: std::fs::Metadata = std::fs::metadata(file_path)?;
+        return Ok(FileStamp{
+            modified: metadata.modified().ok(),
+            len: metadata.len(),
+        });
+    }
+}
+
+/// Pool of [`FileReader`](struct.FileReader.html)s, caching the parsed headers of the files opened repeatedly.
+///
+/// The headers are cached by file path, with the modification time and the size of the file : a file modified since its
+/// header has been parsed is parsed again. So the services reading the same files over and over (e.g. a tile server) only
+/// parse their headers once.
+///
+/// The pool can also keep the readers open (see [`get`](struct.ReaderPool.html#method.get)), up to a maximum number
+/// of open readers, the least recently used reader being closed first.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{FileReader, ReaderPool};
+/// # use copy_to_tmp_file::{
+/// #     copy_bytes_to_tmp_file,
+/// #     NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+/// # };
+/// #
+/// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+///
+/// let mut reader_pool: ReaderPool = ReaderPool::new(16);
+///
+/// // The header is parsed once
+/// let mut file_reader: FileReader = reader_pool.open(&input_file_path).unwrap();
+/// assert_eq!(vec![0.0, 0.5, 1.0],                 file_reader.read_var_f32("latitude").unwrap());
+/// let mut file_reader: FileReader = reader_pool.open(&input_file_path).unwrap();
+/// assert_eq!(vec![0.0, 0.5, 1.0],                 file_reader.read_var_f32("latitude").unwrap());
+/// assert_eq!(1,                                   reader_pool.num_cached_headers());
+///
+/// // The reader is kept open by the pool
+/// assert_eq!(vec![0.0, 0.5, 1.0, 1.5, 2.0],       reader_pool.get(&input_file_path).unwrap().read_var_f32("longitude").unwrap());
+/// assert_eq!(1,                                   reader_pool.num_open_readers());
+/// # tmp_dir.close();
+/// ```
+#[derive(Debug)]
+pub struct ReaderPool {
+    max_open_readers: usize,
+    headers: HashMap<PathBuf, (FileStamp, ParsedHeader)>,
+    /// Open readers, the most recently used being the last one
+    readers: Vec<(PathBuf, FileStamp, FileReader)>,
+}
+
+impl ReaderPool {
+
+    /// Creates an empty pool, keeping at most `max_open_readers` readers open.
+    pub fn new(max_open_readers: usize) -> ReaderPool {
+        return ReaderPool{
+            max_open_readers: max_open_readers,
+            headers: HashMap::new(),
+            readers: vec![],
+        };
+    }
+
+    /// Returns the maximum number of readers kept open by the pool.
+    pub fn max_open_readers(&self) -> usize {
+        return self.max_open_readers;
+    }
+
+    /// Returns the number of cached headers.
+    pub fn num_cached_headers(&self) -> usize {
+        return self.headers.len();
+    }
+
+    /// Returns the number of readers kept open by the pool.
+    pub fn num_open_readers(&self) -> usize {
+        return self.readers.len();
+    }
+
+    /// Opens the file and returns a new reader, the header being parsed only if it is not cached or if the file has been
+    /// modified since it was cached.
+    pub fn open<P: AsRef<Path>>(&mut self, file_path: P) -> Result<FileReader, ReadError> {
+        let file_path: &Path = file_path.as_ref();
+        let stamp: FileStamp = FileStamp::new(file_path)?;
+        return self.open_stamped(file_path, stamp);
+    }
+
+    fn open_stamped(&mut self, file_path: &Path, stamp: FileStamp) -> Result<FileReader, ReadError> {
+        if let Some((cached_stamp, header)) = self.headers.get(file_path) {
+            if *cached_stamp == stamp {
+                let input_file: std::fs::File = std::fs::File::open(file_path)?;
+                return Ok(FileReader::from_parsed_header(Storage::File(input_file), file_path.to_path_buf(), stamp.len as usize, header));
+            }
+        }
+        let file_reader: FileReader = FileReader::open(file_path)?;
+        self.headers.insert(file_path.to_path_buf(), (stamp, file_reader.parsed_header()));
+        return Ok(file_reader);
+    }
+
+    /// Returns the reader of the file kept open by the pool, opening it if necessary (see [`open`](struct.ReaderPool.html#method.open)).
+    ///
+    /// The reader is opened again if the file has been modified. If the pool keeps more than the maximum number of open
+    /// readers, the least recently used readers are closed.
+    pub fn get<P: AsRef<Path>>(&mut self, file_path: P) -> Result<&mut FileReader, ReadError> {
+        let file_path: &Path = file_path.as_ref();
+        let stamp: FileStamp = FileStamp::new(file_path)?;
+        let position: Option<usize> = self.readers.iter().position(|(reader_path, _stamp, _file_reader): &(PathBuf, FileStamp, FileReader)| reader_path == file_path);
+        let entry: (PathBuf, FileStamp, FileReader) = match position.map(|position: usize| self.readers.remove(position)) {
+            Some(entry) if entry.1 == stamp => entry,
+            _ => {
+                let file_reader: FileReader = self.open_stamped(file_path, stamp.clone())?;
+                (file_path.to_path_buf(), stamp, file_reader)
+            },
+        };
+        self.readers.push(entry);
+        let num_closed_readers: usize = self.readers.len().saturating_sub(self.max_open_readers.max(1));
+        self.readers.drain(0..num_closed_readers);
+        let (_file_path, _stamp, file_reader): &mut (PathBuf, FileStamp, FileReader) = self.readers.last_mut().ok_or(ReadError::Unexpected)?;
+        return Ok(file_reader);
+    }
+
+    /// Removes the cached header of the file, and closes its reader.
+    pub fn invalidate<P: AsRef<Path>>(&mut self, file_path: P) {
+        let file_path: &Path = file_path.as_ref();
+        self.headers.remove(file_path);
+        self.readers.retain(|(reader_path, _stamp, _file_reader): &(PathBuf, FileStamp, FileReader)| reader_path != file_path);
+    }
+
+    /// Removes all the cached headers, and closes all the readers.
+    pub fn clear(&mut self) {
+        self.headers.clear();
+        self.readers.clear();
+    }
+}
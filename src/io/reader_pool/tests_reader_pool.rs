@@ -0,0 +1,76 @@
+#![cfg(test)]
+use std::path::PathBuf;
+
+use tempdir::TempDir;
+
+use copy_to_tmp_file::{NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME, NC3_64BIT_OFFSET_FILE_BYTES};
+
+use crate::{FileReader, ReaderPool, Version};
+
+const TMP_DIR_PREFIX: &str = "netcdf3_tests_";
+
+#[test]
+fn test_reader_pool_open() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let file_path: PathBuf = tmp_dir.path().join(NC3_CLASSIC_FILE_NAME);
+    std::fs::write(&file_path, NC3_CLASSIC_FILE_BYTES).unwrap();
+
+    let mut reader_pool: ReaderPool = ReaderPool::new(4);
+    assert_eq!(0,                                   reader_pool.num_cached_headers());
+    let mut parsed_reader: FileReader = reader_pool.open(&file_path).unwrap();
+    assert_eq!(1,                                   reader_pool.num_cached_headers());
+    let mut cached_reader: FileReader = reader_pool.open(&file_path).unwrap();
+    assert_eq!(1,                                   reader_pool.num_cached_headers());
+    assert_eq!(0,                                   reader_pool.num_open_readers());
+
+    assert_eq!(parsed_reader.data_set(),            cached_reader.data_set());
+    assert_eq!(parsed_reader.version(),             cached_reader.version());
+    assert_eq!(file_path.as_path(),                 cached_reader.file_path());
+    assert_eq!(parsed_reader.read_all_vars().unwrap(),  cached_reader.read_all_vars().unwrap());
+
+    // The header is parsed again once the file has been modified
+    std::fs::write(&file_path, NC3_64BIT_OFFSET_FILE_BYTES).unwrap();
+    let file_reader: FileReader = reader_pool.open(&file_path).unwrap();
+    assert_eq!(Version::Offset64Bit,                file_reader.version());
+    let file_reader: FileReader = reader_pool.open(&file_path).unwrap();
+    assert_eq!(Version::Offset64Bit,                file_reader.version());
+
+    reader_pool.invalidate(&file_path);
+    assert_eq!(0,                                   reader_pool.num_cached_headers());
+    assert_eq!(true,                                reader_pool.open(tmp_dir.path().join("undef.nc")).is_err());
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_reader_pool_get() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let file_paths: Vec<PathBuf> = (0..3).map(|i: usize| tmp_dir.path().join(format!("file_{}.nc", i))).collect();
+    for file_path in file_paths.iter() {
+        std::fs::write(file_path, NC3_CLASSIC_FILE_BYTES).unwrap();
+    }
+
+    let mut reader_pool: ReaderPool = ReaderPool::new(2);
+    assert_eq!(2,                                   reader_pool.max_open_readers());
+    for file_path in file_paths.iter() {
+        let file_reader: &mut FileReader = reader_pool.get(file_path).unwrap();
+        assert_eq!(file_path.as_path(),              file_reader.file_path());
+        assert_eq!(vec![0.0, 0.5, 1.0],             file_reader.read_var_f32("latitude").unwrap());
+    }
+    // The least recently used reader has been closed
+    assert_eq!(2,                                   reader_pool.num_open_readers());
+    assert_eq!(3,                                   reader_pool.num_cached_headers());
+
+    let file_reader: &mut FileReader = reader_pool.get(&file_paths[2]).unwrap();
+    assert_eq!(vec![0.0, 0.5, 1.0, 1.5, 2.0],       file_reader.read_var_f32("longitude").unwrap());
+    assert_eq!(2,                                   reader_pool.num_open_readers());
+
+    // A modified file is opened again
+    std::fs::write(&file_paths[2], NC3_64BIT_OFFSET_FILE_BYTES).unwrap();
+    assert_eq!(Version::Offset64Bit,                reader_pool.get(&file_paths[2]).unwrap().version());
+    assert_eq!(2,                                   reader_pool.num_open_readers());
+
+    reader_pool.clear();
+    assert_eq!(0,                                   reader_pool.num_open_readers());
+    assert_eq!(0,                                   reader_pool.num_cached_headers());
+    tmp_dir.close().unwrap();
+}
@@ -0,0 +1,394 @@
+mod tests_summary;
+
+use crate::{FileReader, DataType, DataVector, Variable};
+use crate::error::ReadError;
+
+/// Maximum number of values read at once by [`summarize_var`](fn.summarize_var.html)
+const SUMMARY_CHUNK_LEN: usize = 65_536;
+
+/// Number of markers of the P² estimator of the percentiles
+const NUM_MARKERS: usize = 5;
+
+/// Options of the [`Summarizer`](struct.Summarizer.html).
+///
+/// By default the quartiles are estimated, and no histogram is computed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SummaryOptions {
+    /// Percentiles to estimate, between `0.0` and `100.0`
+    pub percentiles: Vec<f64>,
+    /// Bins of the optional histogram
+    pub histogram: Option<HistogramBins>,
+}
+
+impl Default for SummaryOptions {
+    fn default() -> Self {
+        return SummaryOptions{
+            percentiles: vec![25.0, 50.0, 75.0],
+            histogram: None,
+        };
+    }
+}
+
+/// Fixed-width bins of a [`Histogram`](struct.Histogram.html), dividing the range `[min, max]` into `num_bins` bins.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HistogramBins {
+    pub min: f64,
+    pub max: f64,
+    pub num_bins: usize,
+}
+
+impl HistogramBins {
+
+    /// Returns the index of the bin containing the value, `max` belongs to the last bin.
+    ///
+    /// Returns `None` if the value is outside the range `[min, max]`.
+    pub fn bin_index(&self, value: f64) -> Option<usize> {
+        if self.num_bins == 0 || !(value >= self.min && value <= self.max) {
+            return None;
+        }
+        if self.max <= self.min {
+            return Some(0);
+        }
+        let index: usize = ((value - self.min) / (self.max - self.min) * (self.num_bins as f64)) as usize;
+        return Some(index.min(self.num_bins - 1));
+    }
+}
+
+/// Histogram of the valid values of a variable.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    pub bins: HistogramBins,
+    /// Number of values in each bin
+    pub counts: Vec<usize>,
+    /// Number of values lower than `bins.min`
+    pub below: usize,
+    /// Number of values greater than `bins.max`
+    pub above: usize,
+}
+
+/// Summary of the values of a variable, returned by [`Summarizer::summary`](struct.Summarizer.html#method.summary).
+///
+/// The statistics are computed on the valid values, namely the values which are neither fill values nor `NaN`.
+/// The statistics are `None` if there is no valid value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    /// Number of values, including the fill and `NaN` values
+    pub num_values: usize,
+    pub fill_count: usize,
+    pub nan_count: usize,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub mean: Option<f64>,
+    /// Population standard deviation
+    pub stddev: Option<f64>,
+    /// Requested percentiles and their estimated values
+    pub percentiles: Vec<(f64, f64)>,
+    pub histogram: Option<Histogram>,
+}
+
+impl Summary {
+
+    /// Returns the number of valid values.
+    pub fn valid_count(&self) -> usize {
+        return self.num_values - self.fill_count - self.nan_count;
+    }
+}
+
+/// Streaming summarizer, computing the [`Summary`](struct.Summary.html) of values pushed chunk by chunk.
+///
+/// The memory used does not depend on the number of values : the mean and the standard deviation are computed with the
+/// Welford's algorithm, and the percentiles are estimated with the P² algorithm (Jain and Chlamtac, 1985).
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{DataVector, HistogramBins, Summarizer, Summary, SummaryOptions};
+///
+/// let options = SummaryOptions{
+///     percentiles: vec![50.0],
+///     histogram: Some(HistogramBins{min: 0.0, max: 10.0, num_bins: 2}),
+/// };
+/// let mut summarizer = Summarizer::new(&options, Some(-999.0));
+/// summarizer.push(&DataVector::F32(vec![1.0, 2.0, -999.0]));
+/// summarizer.push(&DataVector::F32(vec![std::f32::NAN, 9.0, 12.0]));
+///
+/// let summary: Summary = summarizer.summary();
+/// assert_eq!(6,                           summary.num_values);
+/// assert_eq!(1,                           summary.fill_count);
+/// assert_eq!(1,                           summary.nan_count);
+/// assert_eq!(Some(1.0),                   summary.min);
+/// assert_eq!(Some(12.0),                  summary.max);
+/// assert_eq!(Some(6.0),                   summary.mean);
+/// assert_eq!(vec![(50.0, 5.5)],           summary.percentiles);
+///
+/// let histogram = summary.histogram.unwrap();
+/// assert_eq!(vec![2, 1],                  histogram.counts);
+/// assert_eq!(1,                           histogram.above);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Summarizer {
+    fill_value: Option<f64>,
+    num_values: usize,
+    fill_count: usize,
+    nan_count: usize,
+    valid_count: usize,
+    min: f64,
+    max: f64,
+    mean: f64,
+    /// Sum of the squared deviations from the mean
+    m2: f64,
+    percentiles: Vec<(f64, PercentileEstimator)>,
+    histogram: Option<Histogram>,
+}
+
+impl Summarizer {
+
+    /// Creates a summarizer, the values equal to the `fill_value` are not taken into account.
+    pub fn new(options: &SummaryOptions, fill_value: Option<f64>) -> Summarizer {
+        return Summarizer{
+            fill_value: fill_value,
+            num_values: 0,
+            fill_count: 0,
+            nan_count: 0,
+            valid_count: 0,
+            min: std::f64::INFINITY,
+            max: std::f64::NEG_INFINITY,
+            mean: 0.0,
+            m2: 0.0,
+            percentiles: options.percentiles.iter().map(|percentile: &f64| {
+                let percentile: f64 = percentile.clamp(0.0, 100.0);
+                (percentile, PercentileEstimator::new(percentile / 100.0))
+            }).collect(),
+            histogram: options.histogram.map(|bins: HistogramBins| Histogram{
+                bins: bins,
+                counts: vec![0; bins.num_bins],
+                below: 0,
+                above: 0,
+            }),
+        };
+    }
+
+    /// Adds the values of a chunk, converted into `f64`.
+    pub fn push(&mut self, data: &DataVector) {
+        for value in data.iter_as_f64() {
+            self.push_value(value);
+        }
+    }
+
+    /// Adds one value.
+    pub fn push_value(&mut self, value: f64) {
+        self.num_values += 1;
+        if self.fill_value == Some(value) {
+            self.fill_count += 1;
+            return;
+        }
+        if value.is_nan() {
+            self.nan_count += 1;
+            return;
+        }
+        self.valid_count += 1;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        let delta: f64 = value - self.mean;
+        self.mean += delta / (self.valid_count as f64);
+        self.m2 += delta * (value - self.mean);
+        for (_percentile, estimator) in self.percentiles.iter_mut() {
+            estimator.push(value);
+        }
+        if let Some(histogram) = self.histogram.as_mut() {
+            match histogram.bins.bin_index(value) {
+                Some(index) => histogram.counts[index] += 1,
+                None if value < histogram.bins.min => histogram.below += 1,
+                None => histogram.above += 1,
+            }
+        }
+    }
+
+    /// Returns the summary of the values pushed so far.
+    pub fn summary(&self) -> Summary {
+        let is_empty: bool = self.valid_count == 0;
+        let get_stat = |stat: f64| -> Option<f64> {
+            return if is_empty { None } else { Some(stat) };
+        };
+        return Summary{
+            num_values: self.num_values,
+            fill_count: self.fill_count,
+            nan_count: self.nan_count,
+            min: get_stat(self.min),
+            max: get_stat(self.max),
+            mean: get_stat(self.mean),
+            stddev: get_stat((self.m2 / (self.valid_count as f64)).sqrt()),
+            percentiles: match is_empty {
+                true => vec![],
+                false => self.percentiles.iter().map(|(percentile, estimator): &(f64, PercentileEstimator)| {
+                    let value: f64 = match *percentile {
+                        p if p <= 0.0 => self.min,
+                        p if p >= 100.0 => self.max,
+                        _ => estimator.value(),
+                    };
+                    (*percentile, value)
+                }).collect(),
+            },
+            histogram: self.histogram.clone(),
+        };
+    }
+}
+
+/// Reads the variable chunk by chunk and returns the summary of its values (see the struct [`Summarizer`](struct.Summarizer.html)).
+///
+/// The fill values (see [`Variable::fill_value`](struct.Variable.html#method.fill_value)) are not taken into account,
+/// and the values are not unpacked.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{FileReader, Summary, SummaryOptions, summarize_var};
+/// # use copy_to_tmp_file::{copy_bytes_to_tmp_file, NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME};
+/// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+///
+/// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+/// let summary: Summary = summarize_var(&mut file_reader, "temperature_i32", &SummaryOptions::default()).unwrap();
+/// assert_eq!(30,                          summary.num_values);
+/// assert_eq!(Some(0.0),                   summary.min);
+/// assert_eq!(Some(29.0),                  summary.max);
+/// assert_eq!(Some(14.5),                  summary.mean);
+/// # tmp_dir.close();
+/// ```
+pub fn summarize_var(reader: &mut FileReader, var_name: &str, options: &SummaryOptions) -> Result<Summary, ReadError> {
+    let var: &Variable = reader.data_set().get_var(var_name).ok_or_else(|| ReadError::VariableNotDefined(var_name.to_owned()))?;
+    let mut summarizer = Summarizer::new(options, fill_value_as_f64(var));
+    let shape: Vec<usize> = var.shape();
+    if shape.is_empty() {
+        let data: DataVector = reader.read_var(var_name)?;
+        summarizer.push(&data);
+        return Ok(summarizer.summary());
+    }
+    // The chunks are slices along the first dimension
+    let inner_len: usize = shape[1..].iter().product();
+    let chunk_size: usize = (SUMMARY_CHUNK_LEN / inner_len.max(1)).max(1);
+    let mut start: Vec<usize> = vec![0; shape.len()];
+    let mut count: Vec<usize> = shape.clone();
+    while start[0] < shape[0] {
+        count[0] = chunk_size.min(shape[0] - start[0]);
+        let data: DataVector = reader.read_var_slice(var_name, &start, &count)?;
+        summarizer.push(&data);
+        start[0] += count[0];
+    }
+    return Ok(summarizer.summary());
+}
+
+/// Returns the fill value of the variable converted into `f64`.
+pub(crate) fn fill_value_as_f64(var: &Variable) -> Option<f64> {
+    return match var.data_type() {
+        DataType::I8 => var.fill_value::<i8>().map(f64::from),
+        DataType::U8 => var.fill_value::<u8>().map(f64::from),
+        DataType::I16 => var.fill_value::<i16>().map(f64::from),
+        DataType::I32 => var.fill_value::<i32>().map(f64::from),
+        DataType::F32 => var.fill_value::<f32>().map(f64::from),
+        DataType::F64 => var.fill_value::<f64>(),
+        DataType::U16 => var.fill_value::<u16>().map(f64::from),
+        DataType::U32 => var.fill_value::<u32>().map(f64::from),
+        DataType::I64 => var.fill_value::<i64>().map(|fill_value: i64| fill_value as f64),
+        DataType::U64 => var.fill_value::<u64>().map(|fill_value: u64| fill_value as f64),
+    };
+}
+
+/// P² estimator of one percentile, the first values are kept to compute the exact percentile of the small samples.
+#[derive(Debug, Clone)]
+struct PercentileEstimator {
+    /// Percentile, between `0.0` and `1.0`
+    p: f64,
+    count: usize,
+    /// Heights of the markers
+    heights: [f64; NUM_MARKERS],
+    /// Actual positions of the markers
+    positions: [f64; NUM_MARKERS],
+    /// Desired positions of the markers
+    desired_positions: [f64; NUM_MARKERS],
+    /// Increments of the desired positions
+    increments: [f64; NUM_MARKERS],
+}
+
+impl PercentileEstimator {
+
+    fn new(p: f64) -> PercentileEstimator {
+        return PercentileEstimator{
+            p: p,
+            count: 0,
+            heights: [0.0; NUM_MARKERS],
+            positions: [0.0, 1.0, 2.0, 3.0, 4.0],
+            desired_positions: [0.0, 2.0 * p, 4.0 * p, 2.0 + 2.0 * p, 4.0],
+            increments: [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0],
+        };
+    }
+
+    fn push(&mut self, value: f64) {
+        if self.count < NUM_MARKERS {
+            self.heights[self.count] = value;
+            self.count += 1;
+            if self.count == NUM_MARKERS {
+                self.heights.sort_by(|a: &f64, b: &f64| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            }
+            return;
+        }
+        self.count += 1;
+
+        // Find the cell containing the value, and update the extreme markers
+        let k: usize = if value < self.heights[0] {
+            self.heights[0] = value;
+            0
+        } else if value >= self.heights[NUM_MARKERS - 1] {
+            self.heights[NUM_MARKERS - 1] = value;
+            NUM_MARKERS - 2
+        } else {
+            (1..NUM_MARKERS).find(|i: &usize| value < self.heights[*i]).unwrap_or(NUM_MARKERS - 1) - 1
+        };
+        for position in self.positions[(k + 1)..].iter_mut() {
+            *position += 1.0;
+        }
+        for (desired_position, increment) in self.desired_positions.iter_mut().zip(self.increments.iter()) {
+            *desired_position += increment;
+        }
+
+        // Adjust the heights of the middle markers
+        for i in 1..(NUM_MARKERS - 1) {
+            let d: f64 = self.desired_positions[i] - self.positions[i];
+            if (d >= 1.0 && self.positions[i + 1] - self.positions[i] > 1.0) || (d <= -1.0 && self.positions[i - 1] - self.positions[i] < -1.0) {
+                let d: f64 = d.signum();
+                let height: f64 = self.parabolic(i, d);
+                self.heights[i] = match self.heights[i - 1] < height && height < self.heights[i + 1] {
+                    true => height,
+                    false => self.linear(i, d),
+                };
+                self.positions[i] += d;
+            }
+        }
+    }
+
+    /// Piecewise-parabolic prediction of the height of the marker `i`, moved by `d`.
+    fn parabolic(&self, i: usize, d: f64) -> f64 {
+        let (q, n): (&[f64; NUM_MARKERS], &[f64; NUM_MARKERS]) = (&self.heights, &self.positions);
+        return q[i] + d / (n[i + 1] - n[i - 1]) * (
+            (n[i] - n[i - 1] + d) * (q[i + 1] - q[i]) / (n[i + 1] - n[i])
+            + (n[i + 1] - n[i] - d) * (q[i] - q[i - 1]) / (n[i] - n[i - 1])
+        );
+    }
+
+    /// Linear prediction of the height of the marker `i`, moved by `d`.
+    fn linear(&self, i: usize, d: f64) -> f64 {
+        let j: usize = if d > 0.0 { i + 1 } else { i - 1 };
+        return self.heights[i] + d * (self.heights[j] - self.heights[i]) / (self.positions[j] - self.positions[i]);
+    }
+
+    /// Returns the estimated percentile, or the exact percentile (linearly interpolated) of the small samples.
+    fn value(&self) -> f64 {
+        if self.count > NUM_MARKERS {
+            return self.heights[2];
+        }
+        let mut values: Vec<f64> = self.heights[0..self.count].to_vec();
+        values.sort_by(|a: &f64, b: &f64| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let rank: f64 = self.p * ((values.len() - 1) as f64);
+        let (lower, upper): (usize, usize) = (rank.floor() as usize, rank.ceil() as usize);
+        return values[lower] + (rank - (lower as f64)) * (values[upper] - values[lower]);
+    }
+}
@@ -0,0 +1,126 @@
+#![cfg(test)]
+
+use std::path::PathBuf;
+
+use tempdir::TempDir;
+
+use crate::{DataSet, DataVector, FileReader, FileWriter, Version};
+use crate::error::ReadError;
+
+use super::{HistogramBins, Summarizer, Summary, SummaryOptions, summarize_var, SUMMARY_CHUNK_LEN};
+
+#[test]
+fn test_summarizer() {
+    let options = SummaryOptions{
+        percentiles: vec![0.0, 10.0, 50.0, 90.0, 100.0],
+        histogram: Some(HistogramBins{min: 0.0, max: 1_000.0, num_bins: 4}),
+    };
+    let mut summarizer = Summarizer::new(&options, Some(-1.0));
+    // The values `0, 1, ..., 1000` pushed in a shuffled order
+    for i in 0..1_001_u64 {
+        summarizer.push_value(((i * 7_919) % 1_001) as f64);
+    }
+    summarizer.push(&DataVector::I32(vec![-1, -1, 2_000, -10]));
+    summarizer.push_value(std::f64::NAN);
+
+    let summary: Summary = summarizer.summary();
+    assert_eq!(1_006,                                   summary.num_values);
+    assert_eq!(2,                                       summary.fill_count);
+    assert_eq!(1,                                       summary.nan_count);
+    assert_eq!(1_003,                                   summary.valid_count());
+    assert_eq!(Some(-10.0),                             summary.min);
+    assert_eq!(Some(2_000.0),                           summary.max);
+    let mean: f64 = (500_500.0 + 1_990.0) / 1_003.0;
+    assert!((summary.mean.unwrap() - mean).abs() < 1e-9);
+    let variance: f64 = ((0..1_001).map(|i: i32| f64::from(i)).chain(vec![2_000.0, -10.0])
+        .map(|value: f64| (value - mean).powi(2)).sum::<f64>()) / 1_003.0;
+    assert!((summary.stddev.unwrap() - variance.sqrt()).abs() < 1e-9);
+
+    assert_eq!(5,                                       summary.percentiles.len());
+    assert_eq!((0.0, -10.0),                            summary.percentiles[0]);
+    assert_eq!((100.0, 2_000.0),                        summary.percentiles[4]);
+    for (i, expected_value) in [(1, 100.0), (2, 500.0), (3, 900.0)].iter() {
+        let (_percentile, value): (f64, f64) = summary.percentiles[*i];
+        assert!((value - expected_value).abs() < 10.0, "{} {}", value, expected_value);
+    }
+
+    let histogram = summary.histogram.unwrap();
+    assert_eq!(vec![250, 250, 250, 251],                histogram.counts);
+    assert_eq!(1,                                       histogram.below);
+    assert_eq!(1,                                       histogram.above);
+}
+
+#[test]
+fn test_summarizer_small_and_empty_samples() {
+    let options = SummaryOptions::default();
+    let mut summarizer = Summarizer::new(&options, None);
+    let summary: Summary = summarizer.summary();
+    assert_eq!(0,                                       summary.num_values);
+    assert_eq!(None,                                    summary.min);
+    assert_eq!(None,                                    summary.stddev);
+    assert_eq!(Vec::<(f64, f64)>::new(),                summary.percentiles);
+    assert_eq!(None,                                    summary.histogram);
+
+    // The exact percentiles of the small samples
+    summarizer.push(&DataVector::F64(vec![4.0, 1.0, 3.0, 2.0]));
+    let summary: Summary = summarizer.summary();
+    assert_eq!(vec![(25.0, 1.75), (50.0, 2.5), (75.0, 3.25)],  summary.percentiles);
+    assert_eq!(Some(1.25_f64.sqrt()),                   summary.stddev);
+}
+
+#[test]
+fn test_histogram_bins() {
+    let bins = HistogramBins{min: -1.0, max: 1.0, num_bins: 4};
+    assert_eq!(Some(0),                                 bins.bin_index(-1.0));
+    assert_eq!(Some(1),                                 bins.bin_index(-0.5));
+    assert_eq!(Some(2),                                 bins.bin_index(0.0));
+    assert_eq!(Some(3),                                 bins.bin_index(1.0));
+    assert_eq!(None,                                    bins.bin_index(1.5));
+    assert_eq!(None,                                    bins.bin_index(std::f64::NAN));
+    assert_eq!(None,                                    HistogramBins{min: -1.0, max: 1.0, num_bins: 0}.bin_index(0.0));
+}
+
+#[test]
+fn test_summarize_var() {
+    let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    let file_path: PathBuf = tmp_dir.path().join("summary.nc");
+    // The variable is read in several chunks
+    let num_rows: usize = 2 * SUMMARY_CHUNK_LEN / 1_000 + 3;
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.set_unlimited_dim("time", num_rows).unwrap();
+        data_set.add_fixed_dim("x", 1_000).unwrap();
+        data_set.add_var_i16("values", &["time", "x"]).unwrap();
+        data_set.add_var_attr_i16("values", "_FillValue", vec![-1]).unwrap();
+        data_set.add_var_f64("scalar", &[] as &[&str]).unwrap();
+        let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        let values: Vec<i16> = (0..(num_rows * 1_000)).map(|i: usize| match i % 10 {
+            0 => -1,
+            _ => (i % 1_000) as i16,
+        }).collect();
+        file_writer.write_var_i16("values", &values).unwrap();
+        file_writer.write_var_f64("scalar", &[1.5]).unwrap();
+        file_writer.close().unwrap();
+    }
+    let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    let summary: Summary = summarize_var(&mut file_reader, "values", &SummaryOptions::default()).unwrap();
+    assert_eq!(num_rows * 1_000,                        summary.num_values);
+    assert_eq!(num_rows * 100,                          summary.fill_count);
+    assert_eq!(0,                                       summary.nan_count);
+    assert_eq!(Some(1.0),                               summary.min);
+    assert_eq!(Some(999.0),                             summary.max);
+    assert!((summary.mean.unwrap() - 500.0).abs() < 1e-9);
+    assert!((summary.percentiles[1].1 - 500.0).abs() < 10.0);
+
+    let summary: Summary = summarize_var(&mut file_reader, "scalar", &SummaryOptions::default()).unwrap();
+    assert_eq!(1,                                       summary.num_values);
+    assert_eq!(Some(1.5),                               summary.mean);
+    assert_eq!(vec![(25.0, 1.5), (50.0, 1.5), (75.0, 1.5)],    summary.percentiles);
+
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        summarize_var(&mut file_reader, "undef_var", &SummaryOptions::default()).unwrap_err()
+    );
+    tmp_dir.close().unwrap();
+}
@@ -1,6 +1,6 @@
 #![cfg(test)]
 
-use super::compute_padding_size;
+use super::{compute_padding_size, compute_index_range, encode_be_into, write_be_values, ENCODING_BLOCK_LEN};
 
 #[test]
 fn test_compute_padding_size()
@@ -12,3 +12,46 @@ fn test_compute_padding_size()
     assert_eq!(0, compute_padding_size(4));
     assert_eq!(3, compute_padding_size(5));
 }
+
+#[test]
+fn test_compute_index_range()
+{
+    let values: Vec<f64> = vec![0.0, 0.5, 1.0, 1.5, 2.0];
+    assert_eq!(0..5, compute_index_range(&values, -1.0, 3.0));
+    assert_eq!(1..4, compute_index_range(&values, 0.5, 1.5));
+    assert_eq!(2..3, compute_index_range(&values, 0.9, 1.1));
+    assert_eq!(0..0, compute_index_range(&values, 0.1, 0.2));
+    // Decreasing values
+    let values: Vec<f64> = vec![40.0, 30.0, 20.0, 10.0];
+    assert_eq!(1..3, compute_index_range(&values, 15.0, 35.0));
+}
+
+#[test]
+fn test_encode_be_into()
+{
+    let mut bytes: [u8; 6] = [0; 6];
+    encode_be_into(&[1_i16, -2, 0x0304], &mut bytes);
+    assert_eq!([0x00, 0x01, 0xff, 0xfe, 0x03, 0x04], bytes);
+
+    let mut bytes: [u8; 8] = [0; 8];
+    encode_be_into(&[1.0_f32, -2.5], &mut bytes);
+    assert_eq!([0x3f, 0x80, 0x00, 0x00, 0xc0, 0x20, 0x00, 0x00], bytes);
+
+    let mut bytes: [u8; 0] = [];
+    encode_be_into::<u64>(&[], &mut bytes);
+}
+
+#[test]
+fn test_write_be_values()
+{
+    // Several blocks, the last one being incomplete
+    let values: Vec<u32> = (0..(2 * ENCODING_BLOCK_LEN + 3) as u32).collect();
+    let mut bytes: Vec<u8> = vec![];
+    assert_eq!(4 * values.len(), write_be_values(&mut bytes, &values).unwrap());
+    let expected_bytes: Vec<u8> = values.iter().flat_map(|value: &u32| value.to_be_bytes().to_vec()).collect();
+    assert_eq!(expected_bytes, bytes);
+
+    let mut bytes: Vec<u8> = vec![];
+    assert_eq!(0, write_be_values::<f64, _>(&mut bytes, &[]).unwrap());
+    assert_eq!(true, bytes.is_empty());
+}
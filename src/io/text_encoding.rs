@@ -0,0 +1,104 @@
+/// Decoding of the names and of the text attributes, which must be UTF-8 in the NetCDF-3 files.
+///
+/// Files written by old tools may contain Latin-1 (ISO-8859-1) bytes, which are not valid UTF-8.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::TextEncoding;
+///
+/// let latin1_bytes: &[u8] = b"caf\xe9";
+/// assert_eq!(None,                                TextEncoding::Utf8.decode(latin1_bytes));
+/// assert_eq!(Some(String::from("café")),          TextEncoding::Utf8OrLatin1.decode(latin1_bytes));
+/// assert_eq!(Some(String::from("caf\u{FFFD}")),   TextEncoding::Utf8Lossy.decode(latin1_bytes));
+///
+/// // The valid UTF-8 bytes are always decoded as UTF-8
+/// let utf8_bytes: &[u8] = "café".as_bytes();
+/// assert_eq!(Some(String::from("café")),          TextEncoding::Utf8.decode(utf8_bytes));
+/// assert_eq!(Some(String::from("café")),          TextEncoding::Utf8OrLatin1.decode(utf8_bytes));
+/// assert_eq!(Some(String::from("café")),          TextEncoding::Utf8Lossy.decode(utf8_bytes));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextEncoding {
+    /// Strict UTF-8, the invalid bytes are not decoded (default)
+    Utf8,
+    /// UTF-8, the invalid bytes are decoded as Latin-1 (ISO-8859-1)
+    Utf8OrLatin1,
+    /// UTF-8, the invalid bytes are replaced by `U+FFFD`
+    Utf8Lossy,
+}
+
+impl Default for TextEncoding {
+    fn default() -> Self {
+        return TextEncoding::Utf8;
+    }
+}
+
+impl TextEncoding {
+    /// Decodes the bytes, returns `None` if they are not valid for the strict UTF-8 encoding.
+    pub fn decode(self, bytes: &[u8]) -> Option<String> {
+        if let Ok(text) = std::str::from_utf8(bytes) {
+            return Some(String::from(text));
+        }
+        return match self {
+            TextEncoding::Utf8 => None,
+            // Each Latin-1 byte is the Unicode code point of the same value
+            TextEncoding::Utf8OrLatin1 => Some(bytes.iter().map(|byte: &u8| char::from(*byte)).collect()),
+            TextEncoding::Utf8Lossy => Some(String::from_utf8_lossy(bytes).into_owned()),
+        };
+    }
+}
+
+/// Decoding options of the strings stored in the `u8` variables (see the method
+/// [`FileReader::read_var_strings_with`](struct.FileReader.html#method.read_var_strings_with)).
+///
+/// By default, the trailing NUL and space padding characters are trimmed, and the invalid UTF-8 sequences are replaced by `U+FFFD`.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{StringOptions, TextEncoding};
+///
+/// let options = StringOptions::default();
+/// assert_eq!(Some(String::from("Lyon")),              options.decode(b"Lyon \0\0"));
+///
+/// let options = StringOptions{trim_nuls: true, trim_spaces: false, encoding: TextEncoding::Utf8OrLatin1};
+/// assert_eq!(Some(String::from("Orl\u{e9}ans ")),     options.decode(b"Orl\xe9ans \0\0"));
+///
+/// let options = StringOptions{trim_nuls: false, trim_spaces: false, encoding: TextEncoding::Utf8};
+/// assert_eq!(Some(String::from("Lyon \0\0")),         options.decode(b"Lyon \0\0"));
+/// assert_eq!(None,                                    options.decode(b"Orl\xe9ans"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StringOptions {
+    /// Trim the trailing NUL characters
+    pub trim_nuls: bool,
+    /// Trim the trailing space characters
+    pub trim_spaces: bool,
+    /// Decoding of the bytes
+    pub encoding: TextEncoding,
+}
+
+impl Default for StringOptions {
+    fn default() -> Self {
+        return StringOptions{
+            trim_nuls: true,
+            trim_spaces: true,
+            encoding: TextEncoding::Utf8Lossy,
+        };
+    }
+}
+
+impl StringOptions {
+    /// Decodes and trims the bytes of a string, returns `None` if they cannot be decoded (see the method [`TextEncoding::decode`](enum.TextEncoding.html#method.decode)).
+    pub fn decode(&self, bytes: &[u8]) -> Option<String> {
+        let text: String = self.encoding.decode(bytes)?;
+        let trimmed_text: &str = match (self.trim_nuls, self.trim_spaces) {
+            (true, true) => text.trim_end_matches(&['\0', ' '][..]),
+            (true, false) => text.trim_end_matches('\0'),
+            (false, true) => text.trim_end_matches(' '),
+            (false, false) => &text,
+        };
+        return Some(String::from(trimmed_text));
+    }
+}
@@ -0,0 +1,376 @@
+mod tests_time_subset;
+#[cfg(feature = "chrono")]
+mod chrono_impl;
+
+use std::ops::Range;
+
+use crate::{FileReader, DataSet, DataType, DataVector, Variable};
+use crate::error::ReadError;
+use crate::io::compute_index_range;
+
+const SECONDS_PER_MINUTE: f64 = 60.0;
+const SECONDS_PER_HOUR: f64 = 3_600.0;
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Calendars supported by the [`TimeSubset`](struct.TimeSubset.html), the proleptic Gregorian calendar is used for all of them.
+const SUPPORTED_CALENDARS: [&str; 3] = ["standard", "gregorian", "proleptic_gregorian"];
+
+/// Date and time (UTC) in the proleptic Gregorian calendar.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::DateTime;
+///
+/// let date_time = DateTime{year: 2020, month: 1, day: 2, hour: 12, minute: 0, second: 0};
+/// assert_eq!(Some(date_time.clone()),         DateTime::parse("2020-01-02 12:00:00"));
+/// assert_eq!(Some(date_time.clone()),         DateTime::parse("2020-01-02T12:00:00Z"));
+/// assert_eq!(Some(date_time.clone()),         DateTime::parse("2020-1-2 12:0"));
+/// assert_eq!(1_577_966_400,                   date_time.timestamp());
+///
+/// assert_eq!(None,                            DateTime::parse("2020-02-30"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateTime {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+}
+
+impl DateTime {
+
+    /// Parses a date and an optional time, formatted as `YYYY-MM-DD[ hh:mm[:ss]]`.
+    ///
+    /// The separator `T` and the UTC suffixes `Z` and `UTC` are also accepted, the other time zones are not supported.
+    pub fn parse(input: &str) -> Option<DateTime> {
+        let input: &str = input.trim();
+        let input: &str = input.strip_suffix("UTC").unwrap_or(input).trim_end();
+        let input: &str = input.strip_suffix('Z').unwrap_or(input);
+        let input: String = input.replacen('T', " ", 1);
+        let mut parts = input.split_whitespace();
+        let date: &str = parts.next()?;
+        let time: Option<&str> = parts.next();
+        if parts.next().is_some() {
+            return None;
+        }
+
+        // Parse the date
+        let date_parts: Vec<&str> = date.split('-').collect();
+        if date_parts.len() != 3 {
+            return None;
+        }
+        let year: i32 = date_parts[0].parse().ok()?;
+        let month: u32 = date_parts[1].parse().ok()?;
+        let day: u32 = date_parts[2].parse().ok()?;
+
+        // Parse the time
+        let (hour, minute, second): (u32, u32, u32) = match time {
+            None => (0, 0, 0),
+            Some(time) => {
+                let time_parts: Vec<&str> = time.split(':').collect();
+                if time_parts.len() < 2 || time_parts.len() > 3 {
+                    return None;
+                }
+                let hour: u32 = time_parts[0].parse().ok()?;
+                let minute: u32 = time_parts[1].parse().ok()?;
+                let second: f64 = match time_parts.get(2) {
+                    None => 0.0,
+                    Some(second) => second.parse().ok()?,
+                };
+                if second.fract() != 0.0 || second < 0.0 {
+                    return None;
+                }
+                (hour, minute, second as u32)
+            }
+        };
+
+        let date_time = DateTime{year, month, day, hour, minute, second};
+        if !date_time.is_valid() {
+            return None;
+        }
+        return Some(date_time);
+    }
+
+    /// Returns the number of seconds elapsed since `1970-01-01 00:00:00`.
+    pub fn timestamp(&self) -> i64 {
+        let num_days: i64 = DateTime::days_from_civil(self.year as i64, self.month as i64, self.day as i64);
+        return num_days * (SECONDS_PER_DAY as i64) + (self.hour as i64) * 3_600 + (self.minute as i64) * 60 + (self.second as i64);
+    }
+
+    /// Returns the date time located `timestamp` seconds after `1970-01-01 00:00:00`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::DateTime;
+    ///
+    /// assert_eq!(DateTime::parse("2020-01-02 12:00:00").unwrap(),     DateTime::from_timestamp(1_577_966_400));
+    /// assert_eq!("2020-01-02T12:00:00Z",                              DateTime::from_timestamp(1_577_966_400).to_string());
+    /// ```
+    pub fn from_timestamp(timestamp: i64) -> DateTime {
+        let num_days: i64 = timestamp.div_euclid(SECONDS_PER_DAY as i64);
+        let seconds_of_day: i64 = timestamp.rem_euclid(SECONDS_PER_DAY as i64);
+        let (year, month, day): (i64, i64, i64) = DateTime::civil_from_days(num_days);
+        return DateTime{
+            year: year as i32,
+            month: month as u32,
+            day: day as u32,
+            hour: (seconds_of_day / 3_600) as u32,
+            minute: ((seconds_of_day % 3_600) / 60) as u32,
+            second: (seconds_of_day % 60) as u32,
+        };
+    }
+
+    /// Returns the current date time of the system clock (UTC), truncated to the second.
+    pub fn now() -> DateTime {
+        let timestamp: i64 = match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs() as i64,
+            Err(err) => -(err.duration().as_secs() as i64),
+        };
+        return DateTime::from_timestamp(timestamp);
+    }
+
+    fn is_valid(&self) -> bool {
+        let is_leap_year: bool = (self.year % 4 == 0 && self.year % 100 != 0) || self.year % 400 == 0;
+        let num_days: u32 = match self.month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if is_leap_year => 29,
+            2 => 28,
+            _ => return false,
+        };
+        return self.day >= 1 && self.day <= num_days && self.hour < 24 && self.minute < 60 && self.second < 60;
+    }
+
+    /// Returns the number of days elapsed since `1970-01-01` in the proleptic Gregorian calendar.
+    ///
+    /// See the algorithm `days_from_civil` of [Howard Hinnant](http://howardhinnant.github.io/date_algorithms.html).
+    fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+        let year: i64 = if month <= 2 { year - 1 } else { year };
+        let era: i64 = if year >= 0 { year } else { year - 399 } / 400;
+        let year_of_era: i64 = year - era * 400;
+        let day_of_year: i64 = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let day_of_era: i64 = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        return era * 146_097 + day_of_era - 719_468;
+    }
+
+    /// Returns the year, the month and the day located `num_days` days after `1970-01-01` in the proleptic Gregorian calendar.
+    ///
+    /// See the algorithm `civil_from_days` of [Howard Hinnant](http://howardhinnant.github.io/date_algorithms.html).
+    fn civil_from_days(num_days: i64) -> (i64, i64, i64) {
+        let num_days: i64 = num_days + 719_468;
+        let era: i64 = if num_days >= 0 { num_days } else { num_days - 146_096 } / 146_097;
+        let day_of_era: i64 = num_days - era * 146_097;
+        let year_of_era: i64 = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year: i64 = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index: i64 = (5 * day_of_year + 2) / 153;
+        let day: i64 = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month: i64 = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+        let year: i64 = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        return (year, month, day);
+    }
+}
+
+/// Formats the date time as ISO 8601 (`YYYY-MM-DDThh:mm:ssZ`).
+impl std::fmt::Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", self.year, self.month, self.day, self.hour, self.minute, self.second)
+    }
+}
+
+/// CF time units, formatted as `<unit> since <reference date time>` (for example `hours since 1970-01-01 00:00:00`).
+///
+/// The supported units are the `days`, the `hours`, the `minutes` and the `seconds` (and their usual abbreviations).
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{TimeUnits, DateTime};
+///
+/// let time_units: TimeUnits = TimeUnits::parse("hours since 1970-01-01 00:00:00").unwrap();
+/// assert_eq!(3_600.0,                                     time_units.seconds_per_unit());
+/// assert_eq!(&DateTime::parse("1970-01-01").unwrap(),     time_units.reference());
+///
+/// let date_time: DateTime = DateTime::parse("2020-01-01 12:00:00").unwrap();
+/// assert_eq!(438_300.0,                                   time_units.to_value(&date_time));
+///
+/// assert_eq!(None,                                        TimeUnits::parse("months since 1970-01-01"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeUnits {
+    seconds_per_unit: f64,
+    reference: DateTime,
+}
+
+impl TimeUnits {
+
+    /// Parses the `units` attribute of a CF time coordinate variable.
+    pub fn parse(units: &str) -> Option<TimeUnits> {
+        let units: &str = units.trim();
+        let (unit, reference): (&str, &str) = units.split_once(char::is_whitespace)?;
+        let reference: &str = reference.trim_start();
+        let reference: &str = match reference.get(0..5) {
+            Some(since) if since.eq_ignore_ascii_case("since") => &reference[5..],
+            _ => return None,
+        };
+        let seconds_per_unit: f64 = match unit.to_lowercase().as_str() {
+            "days" | "day" | "d" => SECONDS_PER_DAY,
+            "hours" | "hour" | "hrs" | "hr" | "h" => SECONDS_PER_HOUR,
+            "minutes" | "minute" | "mins" | "min" => SECONDS_PER_MINUTE,
+            "seconds" | "second" | "secs" | "sec" | "s" => 1.0,
+            _ => return None,
+        };
+        let reference: DateTime = DateTime::parse(reference)?;
+        return Some(TimeUnits{
+            seconds_per_unit: seconds_per_unit,
+            reference: reference,
+        });
+    }
+
+    /// Returns the number of seconds of the unit.
+    pub fn seconds_per_unit(&self) -> f64 {
+        return self.seconds_per_unit;
+    }
+
+    /// Returns the reference date time.
+    pub fn reference(&self) -> &DateTime {
+        return &self.reference;
+    }
+
+    /// Parses the `units` attribute of the time variable, and checks that its `calendar` attribute is supported if defined.
+    pub(crate) fn from_var(time_var: &Variable) -> Result<TimeUnits, ReadError> {
+        if let Some(calendar) = time_var.get_attr_as_string("calendar") {
+            if !SUPPORTED_CALENDARS.contains(&calendar.trim().to_lowercase().as_str()) {
+                return Err(ReadError::TimeUnitsNotValid(time_var.name().to_owned()));
+            }
+        }
+        return time_var.get_attr_as_string("units").as_deref().and_then(TimeUnits::parse).ok_or_else(|| {
+            ReadError::TimeUnitsNotValid(time_var.name().to_owned())
+        });
+    }
+
+    /// Converts a date time into a value of the time coordinate variable.
+    pub fn to_value(&self, date_time: &DateTime) -> f64 {
+        let num_seconds: i64 = date_time.timestamp() - self.reference.timestamp();
+        return (num_seconds as f64) / self.seconds_per_unit;
+    }
+}
+
+/// Records of variables restricted to a range of date times.
+///
+/// The time is given by a CF coordinate variable, namely a one-dimensional variable defined on the *unlimited-size* dimension,
+/// which has a `units` attribute (see [`TimeUnits`](struct.TimeUnits.html)).
+/// The selected records are the smallest range containing all the times between `start` and `end` (inclusive).
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{FileReader, DataVector, DateTime, TimeSubset};
+///
+/// const TEMP_I32_VAR_NAME: &str = "temperature_i32";
+///
+/// // ...
+/// # use copy_to_tmp_file::{
+/// #     copy_bytes_to_tmp_file,
+/// #     NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+/// # };
+/// #
+/// # // Copy bytes to an temporary file
+/// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+///
+/// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+/// assert_eq!(Some(String::from("hours since 1970-01-01 00:00:00")),   file_reader.data_set().get_var_attr_as_string("time", "units"));
+/// assert_eq!(Ok(vec![438_300.0, 438_324.0]),                          file_reader.read_var_f32("time"));
+///
+/// // Select the records of the 2nd of January 2020
+/// let start: DateTime = DateTime::parse("2020-01-02 00:00:00").unwrap();
+/// let end: DateTime = DateTime::parse("2020-01-02 23:59:59").unwrap();
+/// let subset: TimeSubset = TimeSubset::new(&mut file_reader, &[TEMP_I32_VAR_NAME], "time", &start, &end).unwrap();
+/// assert_eq!(1..2,                                                    subset.record_range());
+///
+/// // Read only the selected records
+/// let data: DataVector = subset.read_var(&mut file_reader, TEMP_I32_VAR_NAME).unwrap();
+/// assert_eq!(Some(&(15..30).collect::<Vec<i32>>()[..]),               data.get_i32());
+/// # tmp_dir.close();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeSubset {
+    /// Selected records
+    record_range: Range<usize>,
+    /// Names of the selected variables
+    var_names: Vec<String>,
+}
+
+impl TimeSubset {
+
+    /// Computes the range of records between the date times `start` and `end` from the time coordinate variable.
+    ///
+    /// All the selected variables must be *record* variables.
+    pub fn new<T: AsRef<str>>(reader: &mut FileReader, var_names: &[T], time_var_name: &str, start: &DateTime, end: &DateTime) -> Result<TimeSubset, ReadError> {
+        let time_units: TimeUnits = {
+            let data_set: &DataSet = reader.data_set();
+            let time_var: &Variable = data_set.get_var(time_var_name).ok_or_else(|| ReadError::VariableNotDefined(time_var_name.to_owned()))?;
+            if time_var.num_dims() != 1 || !time_var.is_record_var() {
+                return Err(ReadError::CoordinateVariableNotValid(time_var_name.to_owned()));
+            }
+            TimeUnits::from_var(time_var)?
+        };
+        for var_name in var_names.iter() {
+            TimeSubset::get_record_var(reader.data_set(), var_name.as_ref())?;
+        }
+        let times: Vec<f64> = reader.read_var(time_var_name)?.get_as_f64();
+        let record_range: Range<usize> = compute_index_range(&times, time_units.to_value(start), time_units.to_value(end));
+        return Ok(TimeSubset{
+            record_range: record_range,
+            var_names: var_names.iter().map(|var_name: &T| var_name.as_ref().to_owned()).collect(),
+        });
+    }
+
+    /// Returns the selected records.
+    pub fn record_range(&self) -> Range<usize> {
+        return self.record_range.clone();
+    }
+
+    /// Returns the number of selected records.
+    pub fn num_records(&self) -> usize {
+        return self.record_range.len();
+    }
+
+    /// Returns the names of the selected variables.
+    pub fn var_names(&self) -> &[String] {
+        return &self.var_names;
+    }
+
+    /// Reads the selected records of a *record* variable.
+    pub fn read_var(&self, reader: &mut FileReader, var_name: &str) -> Result<DataVector, ReadError> {
+        let (data_type, mut count): (DataType, Vec<usize>) = {
+            let var: &Variable = TimeSubset::get_record_var(reader.data_set(), var_name)?;
+            (var.data_type(), var.shape())
+        };
+        if self.record_range.is_empty() {
+            return Ok(DataVector::new(data_type, 0));
+        }
+        let mut start: Vec<usize> = vec![0; count.len()];
+        start[0] = self.record_range.start;
+        count[0] = self.record_range.len();
+        return reader.read_var_slice(var_name, &start, &count);
+    }
+
+    /// Reads the selected records of all the selected variables.
+    pub fn read_vars(&self, reader: &mut FileReader) -> Result<Vec<(String, DataVector)>, ReadError> {
+        return self.var_names.iter().map(|var_name: &String| {
+            Ok((var_name.clone(), self.read_var(reader, var_name)?))
+        }).collect();
+    }
+
+    fn get_record_var<'a>(data_set: &'a DataSet, var_name: &str) -> Result<&'a Variable, ReadError> {
+        let var: &Variable = data_set.get_var(var_name).ok_or_else(|| ReadError::VariableNotDefined(var_name.to_owned()))?;
+        if !var.is_record_var() {
+            return Err(ReadError::NotRecordVariable(var_name.to_owned()));
+        }
+        return Ok(var);
+    }
+}
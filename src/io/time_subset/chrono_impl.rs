@@ -0,0 +1,58 @@
+use chrono::{TimeZone, Utc};
+
+use crate::{FileReader, Variable};
+use crate::error::ReadError;
+use super::TimeUnits;
+
+const NANOSECONDS_PER_SECOND: f64 = 1_000_000_000.0;
+
+impl FileReader {
+
+    /// Reads a CF time variable and converts its values into date times of the crate [chrono](https://github.com/chronotope/chrono) (optional feature `chrono`).
+    ///
+    /// The values are decoded with the `units` attribute of the variable (see [`TimeUnits`](struct.TimeUnits.html)),
+    /// and its `calendar` attribute must be `standard`, `gregorian` or `proleptic_gregorian` if defined.
+    ///
+    /// Returns the error `TimeValueNotValid` if a value is a fill value or is out of the range of `chrono::DateTime`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeZone, Utc};
+    /// use netcdf3::FileReader;
+    ///
+    /// # use copy_to_tmp_file::{
+    /// #     copy_bytes_to_tmp_file,
+    /// #     NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+    /// # };
+    /// #
+    /// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    /// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+    /// assert_eq!(Some(String::from("hours since 1970-01-01 00:00:00")),   file_reader.data_set().get_var_attr_as_string("time", "units"));
+    /// assert_eq!(Ok(vec![438_300.0, 438_324.0]),                          file_reader.read_var_f32("time"));
+    ///
+    /// let times: Vec<DateTime<Utc>> = file_reader.read_var_chrono("time").unwrap();
+    /// assert_eq!(Utc.with_ymd_and_hms(2020, 1, 1, 12, 0, 0).unwrap(),     times[0]);
+    /// assert_eq!(Utc.with_ymd_and_hms(2020, 1, 2, 12, 0, 0).unwrap(),     times[1]);
+    /// # tmp_dir.close();
+    /// ```
+    pub fn read_var_chrono(&mut self, var_name: &str) -> Result<Vec<chrono::DateTime<Utc>>, ReadError> {
+        let time_var: &Variable = self.data_set().get_var(var_name).ok_or_else(|| ReadError::VariableNotDefined(var_name.to_owned()))?;
+        let time_units: TimeUnits = TimeUnits::from_var(time_var)?;
+        let reference: i64 = time_units.reference().timestamp();
+        // The fill values are replaced by `NaN`
+        let values: Vec<f64> = self.read_var_unpacked_f64(var_name)?;
+        return values.into_iter().enumerate().map(|(index, value): (usize, f64)| {
+            let num_seconds: f64 = value * time_units.seconds_per_unit();
+            let date_time: Option<chrono::DateTime<Utc>> = match num_seconds.is_finite() && num_seconds.abs() < (std::i64::MAX / 2) as f64 {
+                false => None,
+                true => {
+                    let seconds: f64 = num_seconds.floor();
+                    let nanoseconds: u32 = ((num_seconds - seconds) * NANOSECONDS_PER_SECOND).round().min(NANOSECONDS_PER_SECOND - 1.0) as u32;
+                    (seconds as i64).checked_add(reference).and_then(|timestamp: i64| Utc.timestamp_opt(timestamp, nanoseconds).single())
+                },
+            };
+            date_time.ok_or_else(|| ReadError::TimeValueNotValid{var_name: var_name.to_owned(), index: index})
+        }).collect();
+    }
+}
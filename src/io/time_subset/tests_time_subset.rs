@@ -0,0 +1,224 @@
+#![cfg(test)]
+
+use crate::{FileReader, DataVector};
+use crate::error::ReadError;
+
+use super::{DateTime, TimeUnits, TimeSubset};
+
+use copy_to_tmp_file::{
+    copy_bytes_to_tmp_file,
+    NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+};
+
+const TEMP_I16_VAR_NAME: &str = "temperature_i16";
+const TEMP_F64_VAR_NAME: &str = "temperature_f64";
+
+#[test]
+fn test_parse_date_time() {
+    assert_eq!(Some(DateTime{year: 1970, month: 1, day: 1, hour: 0, minute: 0, second: 0}),      DateTime::parse("1970-01-01"));
+    assert_eq!(Some(DateTime{year: 1970, month: 1, day: 1, hour: 0, minute: 0, second: 0}),      DateTime::parse(" 1970-1-1 0:0:0.0 UTC "));
+    assert_eq!(Some(DateTime{year: 2000, month: 2, day: 29, hour: 23, minute: 59, second: 59}),  DateTime::parse("2000-02-29T23:59:59Z"));
+    assert_eq!(Some(DateTime{year: 1850, month: 12, day: 31, hour: 6, minute: 30, second: 0}),   DateTime::parse("1850-12-31 06:30"));
+
+    assert_eq!(None,    DateTime::parse(""));
+    assert_eq!(None,    DateTime::parse("1970-01"));
+    assert_eq!(None,    DateTime::parse("1970-13-01"));
+    assert_eq!(None,    DateTime::parse("1900-02-29"));
+    assert_eq!(None,    DateTime::parse("1970-01-01 24:00:00"));
+    assert_eq!(None,    DateTime::parse("1970-01-01 12"));
+    assert_eq!(None,    DateTime::parse("1970-01-01 00:00:00.5"));
+    assert_eq!(None,    DateTime::parse("1970-01-01 00:00:00 +01:00"));
+}
+
+#[test]
+fn test_date_time_timestamp() {
+    assert_eq!(0,               DateTime::parse("1970-01-01").unwrap().timestamp());
+    assert_eq!(-1,              DateTime::parse("1969-12-31 23:59:59").unwrap().timestamp());
+    assert_eq!(951_782_400,     DateTime::parse("2000-02-29").unwrap().timestamp());
+    assert_eq!(1_577_880_000,   DateTime::parse("2020-01-01 12:00:00").unwrap().timestamp());
+    assert_eq!(-3_786_825_600,  DateTime::parse("1850-01-01").unwrap().timestamp());
+}
+
+#[test]
+fn test_date_time_from_timestamp() {
+    for input in ["1970-01-01", "1969-12-31 23:59:59", "2000-02-29", "2020-01-01 12:00:00", "1850-01-01", "2100-03-01 00:00:01"].iter() {
+        let date_time: DateTime = DateTime::parse(input).unwrap();
+        assert_eq!(date_time,       DateTime::from_timestamp(date_time.timestamp()));
+    }
+    assert_eq!("1969-12-31T23:59:59Z",      DateTime::from_timestamp(-1).to_string());
+    assert_eq!("0001-01-01T00:00:00Z",      DateTime::from_timestamp(-62_135_596_800).to_string());
+    assert!(DateTime::now().timestamp() > 1_577_836_800);
+}
+
+#[test]
+fn test_parse_time_units() {
+    let reference: DateTime = DateTime::parse("2000-01-01").unwrap();
+    let date_time: DateTime = DateTime::parse("2000-01-02 06:00:00").unwrap();
+    {
+        let time_units: TimeUnits = TimeUnits::parse("days since 2000-01-01").unwrap();
+        assert_eq!(86_400.0,        time_units.seconds_per_unit());
+        assert_eq!(&reference,      time_units.reference());
+        assert_eq!(1.25,            time_units.to_value(&date_time));
+    }
+    {
+        let time_units: TimeUnits = TimeUnits::parse("Hours Since 2000-01-01T00:00:00Z").unwrap();
+        assert_eq!(3_600.0,         time_units.seconds_per_unit());
+        assert_eq!(30.0,            time_units.to_value(&date_time));
+    }
+    {
+        let time_units: TimeUnits = TimeUnits::parse("min since 2000-01-01 00:00").unwrap();
+        assert_eq!(60.0,            time_units.seconds_per_unit());
+        assert_eq!(1_800.0,         time_units.to_value(&date_time));
+    }
+    {
+        let time_units: TimeUnits = TimeUnits::parse("seconds  since  2000-01-02 06:00:00").unwrap();
+        assert_eq!(1.0,             time_units.seconds_per_unit());
+        assert_eq!(0.0,             time_units.to_value(&date_time));
+    }
+
+    assert_eq!(None,    TimeUnits::parse(""));
+    assert_eq!(None,    TimeUnits::parse("days"));
+    assert_eq!(None,    TimeUnits::parse("days after 2000-01-01"));
+    assert_eq!(None,    TimeUnits::parse("months since 2000-01-01"));
+    assert_eq!(None,    TimeUnits::parse("days since 2000-01"));
+}
+
+#[test]
+fn test_time_subset() {
+    let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+
+    let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+    // Times : `2020-01-01 12:00:00` and `2020-01-02 12:00:00`
+    assert_eq!(Ok(vec![438_300.0, 438_324.0]),      file_reader.read_var_f32("time"));
+    let var_names: [&str; 2] = [TEMP_I16_VAR_NAME, TEMP_F64_VAR_NAME];
+
+    // All the records
+    {
+        let start: DateTime = DateTime::parse("2020-01-01 12:00:00").unwrap();
+        let end: DateTime = DateTime::parse("2020-01-02 12:00:00").unwrap();
+        let subset: TimeSubset = TimeSubset::new(&mut file_reader, &var_names, "time", &start, &end).unwrap();
+        assert_eq!(0..2,                            subset.record_range());
+        assert_eq!(2,                               subset.num_records());
+        assert_eq!(&[String::from(TEMP_I16_VAR_NAME), String::from(TEMP_F64_VAR_NAME)][..], subset.var_names());
+        assert_eq!(
+            vec![
+                (String::from(TEMP_I16_VAR_NAME), DataVector::I16((0..30).collect())),
+                (String::from(TEMP_F64_VAR_NAME), DataVector::F64((0..30).map(|value: i32| value as f64).collect())),
+            ],
+            subset.read_vars(&mut file_reader).unwrap()
+        );
+    }
+    // The first record
+    {
+        let start: DateTime = DateTime::parse("2020-01-01").unwrap();
+        let end: DateTime = DateTime::parse("2020-01-01 23:59:59").unwrap();
+        let subset: TimeSubset = TimeSubset::new(&mut file_reader, &var_names, "time", &start, &end).unwrap();
+        assert_eq!(0..1,                            subset.record_range());
+        assert_eq!(Ok(DataVector::I16((0..15).collect())),  subset.read_var(&mut file_reader, TEMP_I16_VAR_NAME));
+    }
+    // No record
+    {
+        let start: DateTime = DateTime::parse("2020-01-03").unwrap();
+        let end: DateTime = DateTime::parse("2020-01-04").unwrap();
+        let subset: TimeSubset = TimeSubset::new(&mut file_reader, &var_names, "time", &start, &end).unwrap();
+        assert_eq!(0..0,                            subset.record_range());
+        assert_eq!(0,                               subset.num_records());
+        assert_eq!(Ok(DataVector::I16(vec![])),     subset.read_var(&mut file_reader, TEMP_I16_VAR_NAME));
+    }
+
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_time_subset_errors() {
+    let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+
+    let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+    let start: DateTime = DateTime::parse("2020-01-01").unwrap();
+    let end: DateTime = DateTime::parse("2020-01-03").unwrap();
+
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        TimeSubset::new(&mut file_reader, &[TEMP_I16_VAR_NAME], "undef_var", &start, &end).unwrap_err()
+    );
+    // Not defined on the unlimited-size dimension
+    assert_eq!(
+        ReadError::CoordinateVariableNotValid(String::from("latitude")),
+        TimeSubset::new(&mut file_reader, &[TEMP_I16_VAR_NAME], "latitude", &start, &end).unwrap_err()
+    );
+    // Not one-dimensional
+    assert_eq!(
+        ReadError::CoordinateVariableNotValid(String::from(TEMP_I16_VAR_NAME)),
+        TimeSubset::new(&mut file_reader, &[TEMP_I16_VAR_NAME], TEMP_I16_VAR_NAME, &start, &end).unwrap_err()
+    );
+    // Selected variables
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        TimeSubset::new(&mut file_reader, &["undef_var"], "time", &start, &end).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::NotRecordVariable(String::from("latitude")),
+        TimeSubset::new(&mut file_reader, &["latitude"], "time", &start, &end).unwrap_err()
+    );
+
+    let subset: TimeSubset = TimeSubset::new(&mut file_reader, &[TEMP_I16_VAR_NAME], "time", &start, &end).unwrap();
+    assert_eq!(
+        ReadError::NotRecordVariable(String::from("longitude")),
+        subset.read_var(&mut file_reader, "longitude").unwrap_err()
+    );
+
+    tmp_dir.close().unwrap();
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_read_var_chrono() {
+    use chrono::{TimeZone, Utc};
+    use tempdir::TempDir;
+    use crate::{DataSet, FileWriter, Version, NC_FILL_F64};
+
+    let tmp_dir: TempDir = TempDir::new("netcdf3_tests_").unwrap();
+    let file_path = tmp_dir.path().join("chrono.nc");
+    {
+        let mut data_set: DataSet = DataSet::new();
+        data_set.add_fixed_dim("time", 3).unwrap();
+        data_set.add_var_f64("time", &["time"]).unwrap();
+        data_set.add_var_attr_string("time", "units", "days since 2000-01-01").unwrap();
+        data_set.add_var_attr_string("time", "calendar", "gregorian").unwrap();
+        data_set.add_var_f64("time_2", &["time"]).unwrap();
+        data_set.add_var_attr_string("time_2", "units", "seconds since 1970-01-01").unwrap();
+        data_set.add_var_f64("time_360", &["time"]).unwrap();
+        data_set.add_var_attr_string("time_360", "units", "days since 2000-01-01").unwrap();
+        data_set.add_var_attr_string("time_360", "calendar", "360_day").unwrap();
+        let mut file_writer: FileWriter = FileWriter::open(&file_path).unwrap();
+        file_writer.set_def(&data_set, Version::Classic, 0).unwrap();
+        file_writer.write_var_f64("time", &[0.0, 1.5, -0.25]).unwrap();
+        file_writer.write_var_f64("time_2", &[0.0, NC_FILL_F64, 1.0]).unwrap();
+        file_writer.write_var_f64("time_360", &[0.0, 1.0, 2.0]).unwrap();
+        file_writer.close().unwrap();
+    }
+
+    let mut file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    assert_eq!(
+        vec![
+            Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2000, 1, 2, 12, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(1999, 12, 31, 18, 0, 0).unwrap(),
+        ],
+        file_reader.read_var_chrono("time").unwrap()
+    );
+    // Errors
+    assert_eq!(
+        ReadError::TimeValueNotValid{var_name: String::from("time_2"), index: 1},
+        file_reader.read_var_chrono("time_2").unwrap_err()
+    );
+    assert_eq!(
+        ReadError::TimeUnitsNotValid(String::from("time_360")),
+        file_reader.read_var_chrono("time_360").unwrap_err()
+    );
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        file_reader.read_var_chrono("undef_var").unwrap_err()
+    );
+    tmp_dir.close().unwrap();
+}
@@ -0,0 +1,124 @@
+use std::time::{Duration, Instant};
+
+/// Durations and counters of the operations of a [`FileReader`](struct.FileReader.html) or of a [`FileWriter`](struct.FileWriter.html),
+/// recorded in their instrumentation mode (see [`FileReader::set_instrumented`](struct.FileReader.html#method.set_instrumented)).
+///
+/// Allows to attribute the slowness to the I/O (the reads and the writes, including the decoding and the encoding of the
+/// big-endian values) or to the conversions of the read values (e.g. into `f64`, into masked or unpacked values, into strings).
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{FileReader, Timings, VarTimings};
+/// # use copy_to_tmp_file::{
+/// #     copy_bytes_to_tmp_file,
+/// #     NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+/// # };
+/// #
+/// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+///
+/// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+/// file_reader.set_instrumented(true);
+/// file_reader.read_var_f32("latitude").unwrap();
+/// file_reader.read_var_unpacked_f64("temperature_i16").unwrap();
+///
+/// let timings: Timings = file_reader.timings();
+/// let latitude_timings: &VarTimings = timings.var("latitude").unwrap();
+/// assert_eq!(1,                                   latitude_timings.num_reads);
+/// assert_eq!(12,                                  latitude_timings.read_bytes);
+/// assert_eq!(60,                                  timings.var("temperature_i16").unwrap().read_bytes);
+/// assert_eq!(None,                                timings.var("longitude"));
+///
+/// // Time spent reading the values vs. converting them
+/// println!("read: {:?}, convert: {:?}", timings.total_read(), timings.total_convert());
+/// # tmp_dir.close();
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Timings {
+    /// Duration of the parsing of the header (readers only, recorded even if the instrumentation mode is disabled)
+    pub header_parse: Duration,
+    /// Duration of the writes of the merged records into the file (writers only, see [`FileWriter::flush`](struct.FileWriter.html#method.flush))
+    pub flush: Duration,
+    /// Durations and counters of the variables, in the order of their first operation
+    pub vars: Vec<(String, VarTimings)>,
+}
+
+/// Durations and counters of the operations on one variable (see [`Timings`](struct.Timings.html)).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct VarTimings {
+    /// Number of reads (of the whole variable, of records, of slices or of elements)
+    pub num_reads: usize,
+    /// Number of bytes of the values read from the file
+    pub read_bytes: u64,
+    /// Duration of the reads, including the decoding of the big-endian values
+    pub read: Duration,
+    /// Duration of the conversions of the read values (e.g. into `f64`, into masked or unpacked values, into strings)
+    pub convert: Duration,
+    /// Number of writes (of the whole variable, of records, of slices or of elements)
+    pub num_writes: usize,
+    /// Number of bytes of the values written into the file
+    pub written_bytes: u64,
+    /// Duration of the writes, including the encoding of the big-endian values
+    pub write: Duration,
+}
+
+impl Timings {
+
+    /// Returns the durations and the counters of the variable, `None` if no operation has been recorded on it.
+    pub fn var(&self, var_name: &str) -> Option<&VarTimings> {
+        return self.vars.iter()
+            .find(|(name, _var_timings): &&(String, VarTimings)| name == var_name)
+            .map(|(_name, var_timings): &(String, VarTimings)| var_timings);
+    }
+
+    /// Returns the total duration of the reads of all the variables.
+    pub fn total_read(&self) -> Duration {
+        return self.vars.iter().map(|(_name, var_timings): &(String, VarTimings)| var_timings.read).sum();
+    }
+
+    /// Returns the total duration of the conversions of all the variables.
+    pub fn total_convert(&self) -> Duration {
+        return self.vars.iter().map(|(_name, var_timings): &(String, VarTimings)| var_timings.convert).sum();
+    }
+
+    /// Returns the total duration of the writes of all the variables.
+    pub fn total_write(&self) -> Duration {
+        return self.vars.iter().map(|(_name, var_timings): &(String, VarTimings)| var_timings.write).sum();
+    }
+
+    /// Returns the timings of the variable, added if no operation has been recorded on it yet.
+    pub(crate) fn var_mut(&mut self, var_name: &str) -> &mut VarTimings {
+        let var_index: usize = match self.vars.iter().position(|(name, _var_timings): &(String, VarTimings)| name == var_name) {
+            Some(var_index) => var_index,
+            None => {
+                self.vars.push((var_name.to_owned(), VarTimings::default()));
+                self.vars.len() - 1
+            },
+        };
+        return &mut self.vars[var_index].1;
+    }
+}
+
+/// Measure of the duration of an operation, started only in the instrumentation mode.
+#[derive(Debug)]
+pub(crate) struct Stopwatch(Option<Instant>);
+
+impl Stopwatch {
+
+    pub(crate) fn start(enabled: bool) -> Stopwatch {
+        return Stopwatch(if enabled { now() } else { None });
+    }
+
+    /// Returns the elapsed duration, zero if the measure has not been started.
+    pub(crate) fn elapsed(&self) -> Duration {
+        return self.0.map(|start: Instant| start.elapsed()).unwrap_or_default();
+    }
+}
+
+/// Returns the current instant, `None` on the targets without clock (`Instant::now` panics on `wasm32-unknown-unknown`).
+fn now() -> Option<Instant> {
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    return Some(Instant::now());
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    return None;
+}
@@ -0,0 +1,250 @@
+mod tests_variable_view;
+
+use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use crate::{DataType, Variable};
+use crate::error::ReadError;
+
+/// Selection along one dimension of a [`VariableView`](struct.VariableView.html).
+///
+/// `SliceArg`s are usually built with the macro [`s!`](macro.s.html).
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::SliceArg;
+///
+/// assert_eq!(SliceArg::Index(3),                                  SliceArg::from(3));
+/// assert_eq!(SliceArg::Range{start: 10, end: Some(20)},           SliceArg::from(10..20));
+/// assert_eq!(SliceArg::Range{start: 10, end: Some(21)},           SliceArg::from(10..=20));
+/// assert_eq!(SliceArg::Range{start: 10, end: None},               SliceArg::from(10..));
+/// assert_eq!(SliceArg::Range{start: 0, end: Some(20)},            SliceArg::from(..20));
+/// assert_eq!(SliceArg::Range{start: 0, end: None},                SliceArg::from(..));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SliceArg {
+    /// Selects one index, the dimension is removed from the view.
+    Index(usize),
+    /// Selects the range `start..end`, `None` means the end of the dimension.
+    Range{start: usize, end: Option<usize>},
+}
+
+impl From<usize> for SliceArg {
+    fn from(index: usize) -> Self {
+        return SliceArg::Index(index);
+    }
+}
+
+impl From<Range<usize>> for SliceArg {
+    fn from(range: Range<usize>) -> Self {
+        return SliceArg::Range{start: range.start, end: Some(range.end)};
+    }
+}
+
+impl From<RangeInclusive<usize>> for SliceArg {
+    fn from(range: RangeInclusive<usize>) -> Self {
+        return SliceArg::Range{start: *range.start(), end: Some(*range.end() + 1)};
+    }
+}
+
+impl From<RangeFrom<usize>> for SliceArg {
+    fn from(range: RangeFrom<usize>) -> Self {
+        return SliceArg::Range{start: range.start, end: None};
+    }
+}
+
+impl From<RangeTo<usize>> for SliceArg {
+    fn from(range: RangeTo<usize>) -> Self {
+        return SliceArg::Range{start: 0, end: Some(range.end)};
+    }
+}
+
+impl From<RangeToInclusive<usize>> for SliceArg {
+    fn from(range: RangeToInclusive<usize>) -> Self {
+        return SliceArg::Range{start: 0, end: Some(range.end + 1)};
+    }
+}
+
+impl From<RangeFull> for SliceArg {
+    fn from(_range: RangeFull) -> Self {
+        return SliceArg::Range{start: 0, end: None};
+    }
+}
+
+/// Builds the list of [`SliceArg`](enum.SliceArg.html)s used by [`VariableView::slice`](struct.VariableView.html#method.slice),
+/// in the manner of the macro `s!` of the crate [ndarray](https://github.com/rust-ndarray/ndarray).
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{s, SliceArg};
+///
+/// assert_eq!(
+///     [SliceArg::Range{start: 0, end: None}, SliceArg::Index(3), SliceArg::Range{start: 10, end: Some(20)}],
+///     s![.., 3, 10..20]
+/// );
+/// ```
+#[macro_export]
+macro_rules! s {
+    ($($arg:expr),* $(,)?) => {
+        [$($crate::SliceArg::from($arg)),*]
+    };
+}
+
+/// Lazy view on a NetCDF-3 variable, obtained with [`FileReader::view`](struct.FileReader.html#method.view).
+///
+/// A view only stores the selected hyperslab, it can be sliced again without reading any data.
+/// The data are read with [`FileReader::read_view`](struct.FileReader.html#method.read_view), only the required bytes are read from the file.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{FileReader, VariableView, DataVector, s};
+///
+/// const TEMP_I32_VAR_NAME: &str = "temperature_i32";
+///
+/// // ...
+/// # use copy_to_tmp_file::{
+/// #     copy_bytes_to_tmp_file,
+/// #     NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+/// # };
+/// #
+/// # // Copy bytes to an temporary file
+/// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+///
+/// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+///
+/// let view: VariableView = file_reader.view(TEMP_I32_VAR_NAME).unwrap();
+/// assert_eq!(vec!["time", "latitude", "longitude"],       view.dim_names());
+/// assert_eq!(vec![2, 3, 5],                               view.shape());
+///
+/// // Select all the times at `latitude = 2`, and `longitude = 1..4`
+/// let view: VariableView = view.slice(&s![.., 2, 1..4]).unwrap();
+/// assert_eq!(vec!["time", "longitude"],                   view.dim_names());
+/// assert_eq!(vec![2, 3],                                  view.shape());
+/// assert_eq!(6,                                           view.len());
+///
+/// // Read the data
+/// assert_eq!(DataVector::I32(vec![11, 12, 13, 26, 27, 28]),  file_reader.read_view(&view).unwrap());
+/// # tmp_dir.close();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariableView {
+    /// Name of the viewed variable
+    var_name: String,
+    /// Data type of the viewed variable
+    data_type: DataType,
+    /// Names of the dimensions of the viewed variable
+    var_dim_names: Vec<String>,
+    /// Shape of the viewed variable
+    var_shape: Vec<usize>,
+    /// Start of the hyperslab, for each dimension of the variable
+    start: Vec<usize>,
+    /// Count of the hyperslab, for each dimension of the variable
+    count: Vec<usize>,
+    /// `false` if the dimension of the variable has been removed from the view by an index
+    kept_dims: Vec<bool>,
+}
+
+impl VariableView {
+
+    /// Creates a view on the whole variable.
+    pub(crate) fn new(var: &Variable) -> VariableView {
+        let var_shape: Vec<usize> = var.shape();
+        return VariableView{
+            var_name: var.name.clone(),
+            data_type: var.data_type(),
+            var_dim_names: var.dim_names(),
+            start: vec![0; var_shape.len()],
+            count: var_shape.clone(),
+            kept_dims: vec![true; var_shape.len()],
+            var_shape: var_shape,
+        };
+    }
+
+    /// Returns the name of the viewed variable.
+    pub fn var_name(&self) -> &str {
+        return &self.var_name;
+    }
+
+    /// Returns the data type of the viewed variable.
+    pub fn data_type(&self) -> DataType {
+        return self.data_type.clone();
+    }
+
+    /// Returns the names of the dimensions kept in the view.
+    pub fn dim_names(&self) -> Vec<String> {
+        return self.var_dim_names.iter().zip(self.kept_dims.iter())
+            .filter(|(_dim_name, kept): &(&String, &bool)| **kept)
+            .map(|(dim_name, _kept): (&String, &bool)| dim_name.clone())
+            .collect();
+    }
+
+    /// Returns the shape of the view.
+    pub fn shape(&self) -> Vec<usize> {
+        return self.count.iter().zip(self.kept_dims.iter())
+            .filter(|(_count, kept): &(&usize, &bool)| **kept)
+            .map(|(count, _kept): (&usize, &bool)| *count)
+            .collect();
+    }
+
+    /// Returns the number of dimensions of the view.
+    pub fn num_dims(&self) -> usize {
+        return self.kept_dims.iter().filter(|kept: &&bool| **kept).count();
+    }
+
+    /// Returns the number of elements of the view.
+    pub fn len(&self) -> usize {
+        return self.count.iter().product();
+    }
+
+    /// Returns `true` if the view does not contain any element.
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+
+    /// Returns the start of the viewed hyperslab, for each dimension of the variable.
+    pub fn start(&self) -> &[usize] {
+        return &self.start;
+    }
+
+    /// Returns the count of the viewed hyperslab, for each dimension of the variable.
+    pub fn count(&self) -> &[usize] {
+        return &self.count;
+    }
+
+    /// Returns a new view, sliced along each dimension of the current view.
+    ///
+    /// The `args` are relative to the current view, one argument is required for each of its dimensions.
+    pub fn slice(&self, args: &[SliceArg]) -> Result<VariableView, ReadError> {
+        let kept_dims: Vec<usize> = (0..self.kept_dims.len()).filter(|i: &usize| self.kept_dims[*i]).collect();
+        let mut view: VariableView = self.clone();
+        let mut not_valid: bool = args.len() != kept_dims.len();
+        for (dim_index, arg) in kept_dims.into_iter().zip(args.iter()) {
+            let dim_count: usize = self.count[dim_index];
+            match arg {
+                SliceArg::Index(index) => {
+                    not_valid |= *index >= dim_count;
+                    view.start[dim_index] += index;
+                    view.count[dim_index] = 1;
+                    view.kept_dims[dim_index] = false;
+                },
+                SliceArg::Range{start, end} => {
+                    let end: usize = end.unwrap_or(dim_count);
+                    not_valid |= *start > end || end > dim_count;
+                    view.start[dim_index] += start;
+                    view.count[dim_index] = end.saturating_sub(*start);
+                },
+            }
+        }
+        if not_valid {
+            return Err(ReadError::SliceNotValid{
+                var_name: self.var_name.clone(),
+                start: view.start,
+                count: view.count,
+                shape: self.var_shape.clone(),
+            });
+        }
+        return Ok(view);
+    }
+}
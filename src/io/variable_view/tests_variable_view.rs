@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+use crate::{DataSet, DataType, FileReader, DataVector, ReadError, s};
+
+use super::{VariableView, SliceArg};
+
+use copy_to_tmp_file::{
+    copy_bytes_to_tmp_file,
+    NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+};
+
+const TEMP_I8_VAR_NAME: &str = "temperature_i8";
+
+#[test]
+fn test_slice_arg_from() {
+    assert_eq!(SliceArg::Index(3),                              SliceArg::from(3));
+    assert_eq!(SliceArg::Range{start: 1, end: Some(4)},         SliceArg::from(1..4));
+    assert_eq!(SliceArg::Range{start: 1, end: Some(5)},         SliceArg::from(1..=4));
+    assert_eq!(SliceArg::Range{start: 1, end: None},            SliceArg::from(1..));
+    assert_eq!(SliceArg::Range{start: 0, end: Some(4)},         SliceArg::from(..4));
+    assert_eq!(SliceArg::Range{start: 0, end: Some(5)},         SliceArg::from(..=4));
+    assert_eq!(SliceArg::Range{start: 0, end: None},            SliceArg::from(..));
+
+    assert_eq!([SliceArg::Index(1), SliceArg::Range{start: 0, end: None}],     s![1, ..]);
+    assert_eq!([] as [SliceArg; 0],                                            s![]);
+}
+
+#[test]
+fn test_view_slice() {
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("latitude", 3).unwrap();
+    data_set.add_fixed_dim("longitude", 5).unwrap();
+    data_set.add_var_i8(TEMP_I8_VAR_NAME, &["time", "latitude", "longitude"]).unwrap();
+
+    let view: VariableView = VariableView::new(data_set.get_var(TEMP_I8_VAR_NAME).unwrap());
+    assert_eq!(TEMP_I8_VAR_NAME,                            view.var_name());
+    assert_eq!(DataType::I8,                                view.data_type());
+    assert_eq!(vec!["time", "latitude", "longitude"],       view.dim_names());
+    assert_eq!(vec![2, 3, 5],                               view.shape());
+    assert_eq!(3,                                           view.num_dims());
+    assert_eq!(30,                                          view.len());
+    assert_eq!(false,                                       view.is_empty());
+    assert_eq!(&[0, 0, 0],                                  view.start());
+    assert_eq!(&[2, 3, 5],                                  view.count());
+
+    // Slice the view twice
+    let view: VariableView = view.slice(&s![1, 1.., 1..4]).unwrap();
+    assert_eq!(vec!["latitude", "longitude"],               view.dim_names());
+    assert_eq!(vec![2, 3],                                  view.shape());
+    assert_eq!(2,                                           view.num_dims());
+    assert_eq!(6,                                           view.len());
+    assert_eq!(&[1, 1, 1],                                  view.start());
+    assert_eq!(&[1, 2, 3],                                  view.count());
+
+    let view_2: VariableView = view.slice(&s![..1, 2]).unwrap();
+    assert_eq!(vec!["latitude"],                            view_2.dim_names());
+    assert_eq!(vec![1],                                     view_2.shape());
+    assert_eq!(&[1, 1, 3],                                  view_2.start());
+    assert_eq!(&[1, 1, 1],                                  view_2.count());
+
+    let view_3: VariableView = view.slice(&s![1..1, ..]).unwrap();
+    assert_eq!(vec![0, 3],                                  view_3.shape());
+    assert_eq!(true,                                        view_3.is_empty());
+
+    // Not valid slices
+    assert_eq!(
+        ReadError::SliceNotValid{var_name: String::from(TEMP_I8_VAR_NAME), start: vec![1, 1, 1], count: vec![1, 3, 3], shape: vec![2, 3, 5]},
+        view.slice(&s![0..3, ..]).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::SliceNotValid{var_name: String::from(TEMP_I8_VAR_NAME), start: vec![1, 1, 4], count: vec![1, 2, 1], shape: vec![2, 3, 5]},
+        view.slice(&s![.., 3]).unwrap_err()
+    );
+    assert_eq!(
+        ReadError::SliceNotValid{var_name: String::from(TEMP_I8_VAR_NAME), start: vec![1, 1, 1], count: vec![1, 2, 3], shape: vec![2, 3, 5]},
+        view.slice(&s![..]).unwrap_err()
+    );
+}
+
+#[test]
+fn test_read_view() {
+    let (tmp_dir, input_data_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+    let mut file_reader = FileReader::open(input_data_file_path).unwrap();
+
+    let view: VariableView = file_reader.view(TEMP_I8_VAR_NAME).unwrap();
+    assert_eq!(Ok(DataVector::I8((0..30).collect())),       file_reader.read_view(&view));
+    let view: VariableView = view.slice(&s![.., 1..=2, 4]).unwrap();
+    assert_eq!(Ok(DataVector::I8(vec![9, 14, 24, 29])),     file_reader.read_view(&view));
+    let view: VariableView = view.slice(&s![1, ..]).unwrap();
+    assert_eq!(Ok(DataVector::I8(vec![24, 29])),            file_reader.read_view(&view));
+
+    assert_eq!(
+        ReadError::VariableNotDefined(String::from("undef_var")),
+        file_reader.view("undef_var").unwrap_err()
+    );
+
+    let _ = file_reader.close();
+    tmp_dir.close().unwrap();
+}
@@ -0,0 +1,236 @@
+mod tests_wasm;
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use wasm_bindgen::prelude::*;
+
+use crate::{CastOverflow, DataSet, DataType, DataVector, FileReader, InMemoryDataSet, Variable, Version};
+
+/// Data types in the order of their NetCDF-3 codes, selected from JavaScript by their name in the C API (e.g. `"NC_FLOAT"`).
+const DATA_TYPES: [DataType; 10] = [
+    DataType::I8, DataType::U8, DataType::I16, DataType::I32, DataType::F32, DataType::F64,
+    DataType::U16, DataType::U32, DataType::I64, DataType::U64,
+];
+
+/// Converts the errors of the crate into JavaScript errors, thrown by the wrapped methods.
+fn js_error<E: std::fmt::Debug>(err: E) -> JsValue {
+    return JsValue::from_str(&format!("{:?}", err));
+}
+
+fn parse_data_type(data_type_name: &str) -> Result<DataType, JsValue> {
+    return DATA_TYPES.iter()
+        .find(|data_type: &&DataType| data_type.c_api_name() == data_type_name)
+        .cloned()
+        .ok_or_else(|| JsValue::from_str(&format!("DataTypeNotValid({:?})", data_type_name)));
+}
+
+/// Reads the NetCDF-3 bytes given by a JavaScript application (e.g. a file selected in a web browser).
+///
+/// Requires the optional feature `wasm`, the methods are exported with camel case names (e.g. `varNames`) and the
+/// errors are thrown as strings.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::WasmReader;
+/// use copy_to_tmp_file::NC3_CLASSIC_FILE_BYTES;
+///
+/// let mut reader: WasmReader = WasmReader::new(NC3_CLASSIC_FILE_BYTES.to_vec()).unwrap();
+/// assert_eq!(1,                                   reader.version());
+/// assert_eq!(vec![3, 5],                          reader.var_shape("temperature_f32").unwrap()[1..].to_vec());
+/// assert_eq!("NC_FLOAT",                          reader.var_data_type("latitude").unwrap());
+/// assert_eq!(vec![0.0, 0.5, 1.0],                 reader.read_var_f64("latitude").unwrap());
+/// ```
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct WasmReader {
+    reader: FileReader,
+}
+
+#[wasm_bindgen]
+impl WasmReader {
+
+    /// Parses the header of the NetCDF-3 bytes (see [`FileReader::from_bytes`](struct.FileReader.html#method.from_bytes)).
+    #[wasm_bindgen(constructor)]
+    pub fn new(bytes: Vec<u8>) -> Result<WasmReader, JsValue> {
+        let reader: FileReader = FileReader::from_bytes(bytes).map_err(js_error)?;
+        return Ok(WasmReader{reader: reader});
+    }
+
+    /// Returns the version number (`1`, `2` or `5`).
+    pub fn version(&self) -> u8 {
+        return self.reader.version() as u8;
+    }
+
+    /// Returns the names of the dimensions.
+    #[wasm_bindgen(js_name = dimNames)]
+    pub fn dim_names(&self) -> Vec<String> {
+        return self.reader.data_set().dim_names();
+    }
+
+    /// Returns the names of the variables.
+    #[wasm_bindgen(js_name = varNames)]
+    pub fn var_names(&self) -> Vec<String> {
+        return self.reader.data_set().get_var_names();
+    }
+
+    /// Returns the names of the dimensions of the variable.
+    #[wasm_bindgen(js_name = varDims)]
+    pub fn var_dims(&self, var_name: &str) -> Result<Vec<String>, JsValue> {
+        return Ok(self.get_var(var_name)?.dim_names());
+    }
+
+    /// Returns the shape of the variable.
+    #[wasm_bindgen(js_name = varShape)]
+    pub fn var_shape(&self, var_name: &str) -> Result<Vec<u32>, JsValue> {
+        return Ok(self.get_var(var_name)?.shape().into_iter().map(|dim_size: usize| dim_size as u32).collect());
+    }
+
+    /// Returns the name of the data type of the variable in the C API (e.g. `"NC_FLOAT"`).
+    #[wasm_bindgen(js_name = varDataType)]
+    pub fn var_data_type(&self, var_name: &str) -> Result<String, JsValue> {
+        return Ok(self.get_var(var_name)?.data_type().c_api_name().to_owned());
+    }
+
+    /// Returns the header as CDL text (see [`DataSet::to_cdl`](struct.DataSet.html#method.to_cdl)).
+    pub fn cdl(&self, data_set_name: &str) -> String {
+        return self.reader.data_set().to_cdl(data_set_name);
+    }
+
+    /// Returns the header as JSON text (see [`DataSet::to_json`](struct.DataSet.html#method.to_json)).
+    pub fn json(&self) -> String {
+        return self.reader.data_set().to_json();
+    }
+
+    /// Reads the variable, the values of all the numeric data types are converted into `f64`.
+    #[wasm_bindgen(js_name = readVarF64)]
+    pub fn read_var_f64(&mut self, var_name: &str) -> Result<Vec<f64>, JsValue> {
+        return self.reader.read_var_as_f64(var_name).map_err(js_error);
+    }
+
+    /// Reads one record of the variable, the values are converted into `f64`.
+    #[wasm_bindgen(js_name = readRecordF64)]
+    pub fn read_record_f64(&mut self, var_name: &str, record_index: usize) -> Result<Vec<f64>, JsValue> {
+        return self.reader.read_record_as_f64(var_name, record_index).map_err(js_error);
+    }
+
+    /// Reads the strings of a `NC_CHAR` variable (see [`FileReader::read_var_strings`](struct.FileReader.html#method.read_var_strings)).
+    #[wasm_bindgen(js_name = readVarStrings)]
+    pub fn read_var_strings(&mut self, var_name: &str) -> Result<Vec<String>, JsValue> {
+        return self.reader.read_var_strings(var_name).map_err(js_error);
+    }
+
+    fn get_var(&self, var_name: &str) -> Result<&Variable, JsValue> {
+        return self.reader.data_set().get_var(var_name).ok_or_else(|| JsValue::from_str(&format!("VariableNotDefined({:?})", var_name)));
+    }
+}
+
+/// Defines a data set and the data of its variables from a JavaScript application, and writes them as NetCDF-3 bytes.
+///
+/// Requires the optional feature `wasm`. The data types are selected by their name in the C API (e.g. `"NC_FLOAT"`),
+/// and the data are given as `f64` values converted into the data type of the variables.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{WasmReader, WasmWriter};
+///
+/// let mut writer: WasmWriter = WasmWriter::new();
+/// writer.add_fixed_dim("latitude", 3).unwrap();
+/// writer.add_var("latitude", vec![String::from("latitude")], "NC_SHORT").unwrap();
+/// writer.add_var_attr_string("latitude", "units", "degrees_north").unwrap();
+/// writer.set_var_f64("latitude", vec![-10.0, 0.0, 10.0]).unwrap();
+/// let bytes: Vec<u8> = writer.to_bytes(1).unwrap();
+///
+/// let mut reader: WasmReader = WasmReader::new(bytes).unwrap();
+/// assert_eq!("NC_SHORT",                          reader.var_data_type("latitude").unwrap());
+/// assert_eq!(vec![-10.0, 0.0, 10.0],              reader.read_var_f64("latitude").unwrap());
+/// ```
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct WasmWriter {
+    data_set: DataSet,
+    data: HashMap<String, DataVector>,
+}
+
+#[wasm_bindgen]
+impl WasmWriter {
+
+    /// Creates an empty data set.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmWriter {
+        return WasmWriter{
+            data_set: DataSet::new(),
+            data: HashMap::new(),
+        };
+    }
+
+    /// Appends a new *fixed-size* dimension.
+    #[wasm_bindgen(js_name = addFixedDim)]
+    pub fn add_fixed_dim(&mut self, dim_name: &str, dim_size: usize) -> Result<(), JsValue> {
+        return self.data_set.add_fixed_dim(dim_name, dim_size).map_err(js_error);
+    }
+
+    /// Sets the *unlimited-size* dimension and its number of records.
+    #[wasm_bindgen(js_name = setUnlimitedDim)]
+    pub fn set_unlimited_dim(&mut self, dim_name: &str, dim_size: usize) -> Result<(), JsValue> {
+        return self.data_set.set_unlimited_dim(dim_name, dim_size).map_err(js_error);
+    }
+
+    /// Appends a new variable, its data type is given by its name in the C API (e.g. `"NC_FLOAT"`).
+    #[wasm_bindgen(js_name = addVar)]
+    pub fn add_var(&mut self, var_name: &str, dim_names: Vec<String>, data_type_name: &str) -> Result<(), JsValue> {
+        let data_type: DataType = parse_data_type(data_type_name)?;
+        return self.data_set.add_var(var_name, &dim_names, data_type).map_err(js_error);
+    }
+
+    /// Appends a new global text attribute.
+    #[wasm_bindgen(js_name = addGlobalAttrString)]
+    pub fn add_global_attr_string(&mut self, attr_name: &str, attr_value: &str) -> Result<(), JsValue> {
+        return self.data_set.add_global_attr_string(attr_name, attr_value).map_err(js_error);
+    }
+
+    /// Appends a new global `NC_DOUBLE` attribute.
+    #[wasm_bindgen(js_name = addGlobalAttrF64)]
+    pub fn add_global_attr_f64(&mut self, attr_name: &str, attr_values: Vec<f64>) -> Result<(), JsValue> {
+        return self.data_set.add_global_attr_f64(attr_name, attr_values).map_err(js_error);
+    }
+
+    /// Appends a new text attribute to the variable.
+    #[wasm_bindgen(js_name = addVarAttrString)]
+    pub fn add_var_attr_string(&mut self, var_name: &str, attr_name: &str, attr_value: &str) -> Result<(), JsValue> {
+        return self.data_set.add_var_attr_string(var_name, attr_name, attr_value).map_err(js_error);
+    }
+
+    /// Appends a new `NC_DOUBLE` attribute to the variable.
+    #[wasm_bindgen(js_name = addVarAttrF64)]
+    pub fn add_var_attr_f64(&mut self, var_name: &str, attr_name: &str, attr_values: Vec<f64>) -> Result<(), JsValue> {
+        return self.data_set.add_var_attr_f64(var_name, attr_name, attr_values).map_err(js_error);
+    }
+
+    /// Sets the data of the variable, the values are converted into its data type (an error is thrown if a value is out of its range).
+    ///
+    /// The variables without data are written with their fill values.
+    #[wasm_bindgen(js_name = setVarF64)]
+    pub fn set_var_f64(&mut self, var_name: &str, values: Vec<f64>) -> Result<(), JsValue> {
+        let var: &Variable = self.data_set.get_var(var_name).ok_or_else(|| JsValue::from_str(&format!("VariableNotDefined({:?})", var_name)))?;
+        let var_data: DataVector = DataVector::F64(values).cast_to(var.data_type(), CastOverflow::Error).map_err(js_error)?;
+        self.data.insert(var_name.to_owned(), var_data);
+        return Ok(());
+    }
+
+    /// Writes the data set and the data of its variables as the bytes of a NetCDF-3 file (version `1`, `2` or `5`).
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self, version: u8) -> Result<Vec<u8>, JsValue> {
+        let version: Version = Version::try_from(version).map_err(js_error)?;
+        let in_memory: InMemoryDataSet = InMemoryDataSet::from_parts(self.data_set.clone(), self.data.clone()).map_err(js_error)?;
+        return in_memory.to_bytes(version).map_err(js_error);
+    }
+}
+
+impl Default for WasmWriter {
+    fn default() -> WasmWriter {
+        return WasmWriter::new();
+    }
+}
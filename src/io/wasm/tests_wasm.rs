@@ -0,0 +1,41 @@
+#![cfg(test)]
+use copy_to_tmp_file::NC3_CLASSIC_FILE_BYTES;
+
+use crate::{WasmReader, WasmWriter};
+
+#[test]
+fn test_wasm_reader() {
+    let mut reader: WasmReader = WasmReader::new(NC3_CLASSIC_FILE_BYTES.to_vec()).unwrap();
+    assert_eq!(1,                                           reader.version());
+    assert_eq!(vec!["latitude", "longitude", "time"],       reader.dim_names());
+    assert_eq!(vec!["time", "latitude", "longitude"],       reader.var_dims("temperature_i8").unwrap());
+    assert_eq!(vec![2, 3, 5],                               reader.var_shape("temperature_i8").unwrap());
+    assert_eq!("NC_BYTE",                                   reader.var_data_type("temperature_i8").unwrap());
+
+    let temperatures: Vec<f64> = reader.read_var_f64("temperature_i8").unwrap();
+    assert_eq!((0..30).map(|value: i32| value as f64).collect::<Vec<f64>>(),   temperatures);
+    assert_eq!(temperatures[15..].to_vec(),                 reader.read_record_f64("temperature_i8", 1).unwrap());
+}
+
+#[test]
+fn test_wasm_writer() {
+    let mut writer: WasmWriter = WasmWriter::new();
+    writer.set_unlimited_dim("time", 2).unwrap();
+    writer.add_fixed_dim("station", 3).unwrap();
+    writer.add_var("time", vec![String::from("time")], "NC_DOUBLE").unwrap();
+    writer.add_var("count", vec![String::from("time"), String::from("station")], "NC_INT").unwrap();
+    writer.add_global_attr_string("title", "stations").unwrap();
+    writer.add_global_attr_f64("version", vec![1.5]).unwrap();
+    writer.add_var_attr_f64("count", "scale_factor", vec![0.5]).unwrap();
+    writer.set_var_f64("count", vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+
+    let bytes: Vec<u8> = writer.to_bytes(2).unwrap();
+    let mut reader: WasmReader = WasmReader::new(bytes).unwrap();
+    assert_eq!(2,                                           reader.version());
+    assert_eq!(vec!["time", "count"],                       reader.var_names());
+    assert_eq!("NC_INT",                                    reader.var_data_type("count").unwrap());
+    assert_eq!(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],          reader.read_var_f64("count").unwrap());
+    // The variables without data are filled
+    assert!(reader.read_var_f64("time").unwrap().iter().all(|value: &f64| *value == crate::NC_FILL_F64));
+    assert!(reader.cdl("stations").contains(":title = \"stations\" ;"));
+}
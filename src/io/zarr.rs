@@ -0,0 +1,419 @@
+mod tests_zarr;
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+
+use crate::{DataSet, DataType, DataVector, Variable, Attribute, FileReader, FileWriter};
+use crate::{NC_FILL_I8, NC_FILL_U8, NC_FILL_I16, NC_FILL_I32, NC_FILL_F32, NC_FILL_F64, NC_FILL_U16, NC_FILL_U32, NC_FILL_I64, NC_FILL_U64};
+use crate::error::{ReadError, WriteError};
+
+/// Layout of a Zarr store
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZarrStore {
+    /// One file per key, inside a directory
+    Directory,
+    /// One entry per key, inside an uncompressed ZIP archive
+    Zip,
+}
+
+/// Exports a data set and the data of its variables into a Zarr v2 store.
+///
+/// Requires the optional feature `zarr`.
+///
+/// The data set is mapped to the Zarr metadata with the conventions of [xarray](https://xarray.dev) :
+/// - the root group contains the global attributes,
+/// - each variable is an array with its attributes, and the names of its dimensions in the attribute `_ARRAY_DIMENSIONS`,
+/// - the `fill_value` is the attribute `_FillValue`, or the default NetCDF-3 fill value,
+/// - the chunks are the NetCDF-3 chunks, namely one chunk per record for the *record* variables and a single chunk for the *fixed-size* variables,
+/// - the chunks are not compressed and keep the big-endian byte order (`dtype` `>i2`, `>f4`, ...), the `u8` variables are stored as characters (`|S1`).
+///
+/// The variables missing from `vars_data` are only described, their values are the fill value.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use netcdf3::{FileReader, DataVector, ZarrStore};
+///
+/// # use copy_to_tmp_file::{
+/// #     copy_bytes_to_tmp_file,
+/// #     NC3_CLASSIC_FILE_NAME, NC3_CLASSIC_FILE_BYTES,
+/// # };
+/// #
+/// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+/// # let zarr_path = tmp_dir.path().join("data.zarr");
+/// let mut file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+/// let vars_data: HashMap<String, DataVector> = file_reader.read_all_vars().unwrap();
+/// netcdf3::export_zarr(file_reader.data_set(), &vars_data, &zarr_path, ZarrStore::Directory).unwrap();
+///
+/// assert_eq!(true,                                zarr_path.join(".zgroup").exists());
+/// assert_eq!(true,                                zarr_path.join("latitude").join(".zarray").exists());
+/// # tmp_dir.close();
+/// ```
+pub fn export_zarr<P: AsRef<Path>>(data_set: &DataSet, vars_data: &HashMap<String, DataVector>, output_path: P, store: ZarrStore) -> Result<(), WriteError> {
+    let mut writer: StoreWriter = match store {
+        ZarrStore::Directory => {
+            std::fs::create_dir(output_path.as_ref())?;
+            StoreWriter::Directory(output_path.as_ref().to_path_buf())
+        },
+        ZarrStore::Zip => StoreWriter::Zip(ZipWriter::new(File::create(output_path.as_ref())?)),
+    };
+    writer.put(".zgroup", b"{\"zarr_format\": 2}")?;
+    writer.put(".zattrs", attrs_to_json(&data_set.get_global_attrs(), None).as_bytes())?;
+    for var in data_set.get_vars() {
+        let var_data: Option<&DataVector> = vars_data.get(var.name());
+        if let Some(var_data) = var_data {
+            if var_data.data_type() != var.data_type() {
+                return Err(WriteError::VariableMismatchDataType{var_name: var.name().to_owned(), req: var.data_type(), get: var_data.data_type()});
+            }
+            if var_data.len() != var.len() {
+                return Err(WriteError::VariableMismatchDataLength{var_name: var.name().to_owned(), req: var.len(), get: var_data.len()});
+            }
+        }
+        writer.put(&format!("{}/.zarray", var.name()), zarray_json(var).as_bytes())?;
+        writer.put(&format!("{}/.zattrs", var.name()), attrs_to_json(&var.get_attrs(), Some(&var.dim_names())).as_bytes())?;
+        if let Some(var_data) = var_data {
+            let chunk_len: usize = var.chunk_len();
+            for chunk_index in 0..var.num_chunks() {
+                let start: usize = chunk_index * chunk_len;
+                let mut chunk_bytes: Vec<u8> = vec![];
+                FileWriter::write_data_vector_range(&mut chunk_bytes, var_data, start, start + chunk_len)?;
+                writer.put(&format!("{}/{}", var.name(), chunk_key(var, chunk_index)), &chunk_bytes)?;
+            }
+        }
+    }
+    return writer.finish();
+}
+
+/// Returns the [kerchunk](https://fsspec.github.io/kerchunk/spec.html) references (version 1) of the NetCDF-3 file, as a JSON text.
+///
+/// Requires the optional feature `zarr`.
+///
+/// The references describe the file as a Zarr v2 store (with the same metadata as [`export_zarr`](fn.export_zarr.html)),
+/// where each chunk is a byte range `[url, offset, length]` of the file located at `url`. The file can then be read by the
+/// reference file systems (e.g. `fsspec` and `xarray`) without this library, only the data bytes are fetched.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{FileReader, kerchunk_refs};
+/// # use copy_to_tmp_file::{copy_bytes_to_tmp_file, NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME};
+/// # let (tmp_dir, input_file_path) = copy_bytes_to_tmp_file(NC3_CLASSIC_FILE_BYTES, NC3_CLASSIC_FILE_NAME);
+///
+/// let file_reader: FileReader = FileReader::open(input_file_path).unwrap();
+/// let refs: String = kerchunk_refs(&file_reader, "s3://bucket/data.nc").unwrap();
+///
+/// assert_eq!(true,                                refs.starts_with("{\"version\": 1, \"refs\": {"));
+/// // The 3 latitudes of `f32`
+/// assert_eq!(true,                                refs.contains("\"latitude/0\": [\"s3://bucket/data.nc\", 1684, 12]"));
+/// # tmp_dir.close();
+/// ```
+pub fn kerchunk_refs(reader: &FileReader, url: &str) -> Result<String, ReadError> {
+    let data_set: &DataSet = reader.data_set();
+    let record_size: usize = data_set.record_size().unwrap_or(0);
+    let url: String = string_to_json(url);
+    let mut refs: Vec<String> = vec![
+        format!("{}: {}", string_to_json(".zgroup"), string_to_json("{\"zarr_format\": 2}")),
+        format!("{}: {}", string_to_json(".zattrs"), string_to_json(&attrs_to_json(&data_set.get_global_attrs(), None))),
+    ];
+    for var in data_set.get_vars() {
+        let begin_offset: usize = i64::from(reader.var_begin_offset(var.name()).ok_or(ReadError::Unexpected)?) as usize;
+        refs.push(format!("{}: {}", string_to_json(&format!("{}/.zarray", var.name())), string_to_json(&zarray_json(var))));
+        refs.push(format!("{}: {}", string_to_json(&format!("{}/.zattrs", var.name())), string_to_json(&attrs_to_json(&var.get_attrs(), Some(&var.dim_names())))));
+        // The padding bytes are not part of the chunks
+        let chunk_len: usize = var.chunk_len() * var.data_type().size_of();
+        for chunk_index in 0..var.num_chunks() {
+            let offset: usize = begin_offset + chunk_index * record_size;
+            refs.push(format!("{}: [{}, {}, {}]", string_to_json(&format!("{}/{}", var.name(), chunk_key(var, chunk_index))), url, offset, chunk_len));
+        }
+    }
+    return Ok(format!("{{\"version\": 1, \"refs\": {{{}}}}}", refs.join(", ")));
+}
+
+/// Returns the key of a chunk, the *record* variables have one chunk per record.
+fn chunk_key(var: &Variable, chunk_index: usize) -> String {
+    if var.num_dims() == 0 {
+        return String::from("0");
+    }
+    let mut indices: Vec<String> = vec![String::from("0"); var.num_dims()];
+    indices[0] = chunk_index.to_string();
+    return indices.join(".");
+}
+
+/// Returns the metadata (`.zarray`) of a variable.
+fn zarray_json(var: &Variable) -> String {
+    let shape: Vec<usize> = var.get_dims().iter().map(|dim| dim.size()).collect();
+    let mut chunks: Vec<usize> = shape.clone();
+    if var.is_record_var() {
+        chunks[0] = 1;
+    }
+    let dtype: &str = match var.data_type() {
+        DataType::I8 => "|i1",
+        DataType::U8 => "|S1",
+        DataType::I16 => ">i2",
+        DataType::I32 => ">i4",
+        DataType::F32 => ">f4",
+        DataType::F64 => ">f8",
+        DataType::U16 => ">u2",
+        DataType::U32 => ">u4",
+        DataType::I64 => ">i8",
+        DataType::U64 => ">u8",
+    };
+    let fill_value: String = match var.get_attr("_FillValue").filter(|attr: &&Attribute| attr.data_type() == var.data_type() && attr.len() == 1) {
+        Some(attr) => fill_value_json(&attr.data),
+        None => fill_value_json(&default_fill_value(var.data_type())),
+    };
+    return format!(
+        "{{\"zarr_format\": 2, \"shape\": {}, \"chunks\": {}, \"dtype\": \"{}\", \"compressor\": null, \"fill_value\": {}, \"order\": \"C\", \"filters\": null}}",
+        usizes_to_json(&shape), usizes_to_json(&chunks), dtype, fill_value,
+    );
+}
+
+fn default_fill_value(data_type: DataType) -> DataVector {
+    return match data_type {
+        DataType::I8 => DataVector::I8(vec![NC_FILL_I8]),
+        DataType::U8 => DataVector::U8(vec![NC_FILL_U8]),
+        DataType::I16 => DataVector::I16(vec![NC_FILL_I16]),
+        DataType::I32 => DataVector::I32(vec![NC_FILL_I32]),
+        DataType::F32 => DataVector::F32(vec![NC_FILL_F32]),
+        DataType::F64 => DataVector::F64(vec![NC_FILL_F64]),
+        DataType::U16 => DataVector::U16(vec![NC_FILL_U16]),
+        DataType::U32 => DataVector::U32(vec![NC_FILL_U32]),
+        DataType::I64 => DataVector::I64(vec![NC_FILL_I64]),
+        DataType::U64 => DataVector::U64(vec![NC_FILL_U64]),
+    };
+}
+
+/// Returns the fill value of the `.zarray`, the characters are encoded in base64.
+fn fill_value_json(fill_value: &DataVector) -> String {
+    const BASE64_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    return match fill_value {
+        DataVector::U8(data) => {
+            let byte: u8 = data[0];
+            format!("\"{}{}==\"", BASE64_CHARS[(byte >> 2) as usize] as char, BASE64_CHARS[((byte & 0x03) << 4) as usize] as char)
+        },
+        _ => values_to_json(fill_value)[0].clone(),
+    };
+}
+
+/// Returns the attributes as a JSON object, the `u8` attributes are converted to strings.
+fn attrs_to_json(attrs: &[&Attribute], dim_names: Option<&[String]>) -> String {
+    let mut members: Vec<String> = attrs.iter().map(|attr: &&Attribute| {
+        let value: String = match &attr.data {
+            DataVector::U8(data) => string_to_json(String::from_utf8_lossy(data).trim_end_matches('\0')),
+            data => {
+                let values: Vec<String> = values_to_json(data);
+                match values.len() {
+                    1 => values[0].clone(),
+                    _ => format!("[{}]", values.join(", ")),
+                }
+            },
+        };
+        format!("{}: {}", string_to_json(attr.name()), value)
+    }).collect();
+    if let Some(dim_names) = dim_names {
+        let dim_names: Vec<String> = dim_names.iter().map(|dim_name: &String| string_to_json(dim_name)).collect();
+        members.push(format!("\"_ARRAY_DIMENSIONS\": [{}]", dim_names.join(", ")));
+    }
+    return format!("{{{}}}", members.join(", "));
+}
+
+fn usizes_to_json(values: &[usize]) -> String {
+    let values: Vec<String> = values.iter().map(|value: &usize| value.to_string()).collect();
+    return format!("[{}]", values.join(", "));
+}
+
+/// Returns the JSON numbers, the non-finite floats are written as the strings `"NaN"`, `"Infinity"` and `"-Infinity"`.
+fn values_to_json(data: &DataVector) -> Vec<String> {
+    fn float_to_json(value: f64, repr: String) -> String {
+        return match value {
+            _ if value.is_nan() => String::from("\"NaN\""),
+            _ if value == std::f64::INFINITY => String::from("\"Infinity\""),
+            _ if value == std::f64::NEG_INFINITY => String::from("\"-Infinity\""),
+            _ => repr,
+        };
+    }
+    return match data {
+        DataVector::I8(data) => data.iter().map(|value: &i8| value.to_string()).collect(),
+        DataVector::U8(data) => data.iter().map(|value: &u8| value.to_string()).collect(),
+        DataVector::I16(data) => data.iter().map(|value: &i16| value.to_string()).collect(),
+        DataVector::I32(data) => data.iter().map(|value: &i32| value.to_string()).collect(),
+        DataVector::F32(data) => data.iter().map(|value: &f32| float_to_json(f64::from(*value), format!("{:?}", value))).collect(),
+        DataVector::F64(data) => data.iter().map(|value: &f64| float_to_json(*value, format!("{:?}", value))).collect(),
+        DataVector::U16(data) => data.iter().map(|value: &u16| value.to_string()).collect(),
+        DataVector::U32(data) => data.iter().map(|value: &u32| value.to_string()).collect(),
+        DataVector::I64(data) => data.iter().map(|value: &i64| value.to_string()).collect(),
+        DataVector::U64(data) => data.iter().map(|value: &u64| value.to_string()).collect(),
+    };
+}
+
+fn string_to_json(value: &str) -> String {
+    let mut json: String = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    return json;
+}
+
+/// Destination of the keys of the store
+enum StoreWriter {
+    Directory(PathBuf),
+    Zip(ZipWriter),
+}
+
+impl StoreWriter {
+
+    fn put(&mut self, key: &str, value: &[u8]) -> Result<(), WriteError> {
+        match self {
+            StoreWriter::Directory(root_path) => {
+                let file_path: PathBuf = root_path.join(key);
+                if let Some(parent_path) = file_path.parent() {
+                    std::fs::create_dir_all(parent_path)?;
+                }
+                std::fs::write(file_path, value)?;
+            },
+            StoreWriter::Zip(zip_writer) => zip_writer.put(key, value)?,
+        }
+        return Ok(());
+    }
+
+    fn finish(self) -> Result<(), WriteError> {
+        return match self {
+            StoreWriter::Directory(_) => Ok(()),
+            StoreWriter::Zip(zip_writer) => zip_writer.finish(),
+        };
+    }
+}
+
+/// Minimal writer of ZIP archives, the entries are stored without compression.
+///
+/// The ZIP64 extensions are not supported.
+struct ZipWriter {
+    output: BufWriter<File>,
+    /// Number of bytes already written
+    offset: u64,
+    /// Name, CRC-32, size and offset of the local header of each entry
+    entries: Vec<(String, u32, u32, u32)>,
+}
+
+impl ZipWriter {
+
+    const LOCAL_HEADER_SIGNATURE: u32 = 0x04034b50;
+    const CENTRAL_HEADER_SIGNATURE: u32 = 0x02014b50;
+    const END_OF_CENTRAL_DIR_SIGNATURE: u32 = 0x06054b50;
+    const VERSION: u16 = 20;
+    /// 1980-01-01, the first date of the MS-DOS format
+    const DOS_DATE: u16 = 0x21;
+
+    fn new(output: File) -> ZipWriter {
+        return ZipWriter{
+            output: BufWriter::new(output),
+            offset: 0,
+            entries: vec![],
+        };
+    }
+
+    fn check_size(size: u64) -> Result<u32, WriteError> {
+        return match size <= u64::from(std::u32::MAX) {
+            true => Ok(size as u32),
+            false => Err(WriteError::ZipSizeExceeded),
+        };
+    }
+
+    fn put(&mut self, name: &str, data: &[u8]) -> Result<(), WriteError> {
+        let crc: u32 = crc32(data);
+        let size: u32 = ZipWriter::check_size(data.len() as u64)?;
+        let header_offset: u32 = ZipWriter::check_size(self.offset)?;
+        let out: &mut BufWriter<File> = &mut self.output;
+        out.write_u32::<LittleEndian>(ZipWriter::LOCAL_HEADER_SIGNATURE)?;
+        out.write_u16::<LittleEndian>(ZipWriter::VERSION)?;
+        out.write_u16::<LittleEndian>(0)?;  // flags
+        out.write_u16::<LittleEndian>(0)?;  // stored
+        out.write_u16::<LittleEndian>(0)?;  // time
+        out.write_u16::<LittleEndian>(ZipWriter::DOS_DATE)?;
+        out.write_u32::<LittleEndian>(crc)?;
+        out.write_u32::<LittleEndian>(size)?;  // compressed size
+        out.write_u32::<LittleEndian>(size)?;  // uncompressed size
+        out.write_u16::<LittleEndian>(name.len() as u16)?;
+        out.write_u16::<LittleEndian>(0)?;  // extra field length
+        out.write_all(name.as_bytes())?;
+        out.write_all(data)?;
+        self.offset += (30 + name.len() + data.len()) as u64;
+        self.entries.push((name.to_owned(), crc, size, header_offset));
+        return Ok(());
+    }
+
+    fn finish(mut self) -> Result<(), WriteError> {
+        if self.entries.len() > usize::from(std::u16::MAX) {
+            return Err(WriteError::ZipSizeExceeded);
+        }
+        let central_dir_offset: u32 = ZipWriter::check_size(self.offset)?;
+        let mut central_dir_size: u64 = 0;
+        let out: &mut BufWriter<File> = &mut self.output;
+        for (name, crc, size, header_offset) in self.entries.iter() {
+            out.write_u32::<LittleEndian>(ZipWriter::CENTRAL_HEADER_SIGNATURE)?;
+            out.write_u16::<LittleEndian>(ZipWriter::VERSION)?;  // version made by
+            out.write_u16::<LittleEndian>(ZipWriter::VERSION)?;  // version needed
+            out.write_u16::<LittleEndian>(0)?;  // flags
+            out.write_u16::<LittleEndian>(0)?;  // stored
+            out.write_u16::<LittleEndian>(0)?;  // time
+            out.write_u16::<LittleEndian>(ZipWriter::DOS_DATE)?;
+            out.write_u32::<LittleEndian>(*crc)?;
+            out.write_u32::<LittleEndian>(*size)?;
+            out.write_u32::<LittleEndian>(*size)?;
+            out.write_u16::<LittleEndian>(name.len() as u16)?;
+            out.write_u16::<LittleEndian>(0)?;  // extra field length
+            out.write_u16::<LittleEndian>(0)?;  // comment length
+            out.write_u16::<LittleEndian>(0)?;  // disk number
+            out.write_u16::<LittleEndian>(0)?;  // internal attributes
+            out.write_u32::<LittleEndian>(0)?;  // external attributes
+            out.write_u32::<LittleEndian>(*header_offset)?;
+            out.write_all(name.as_bytes())?;
+            central_dir_size += (46 + name.len()) as u64;
+        }
+        out.write_u32::<LittleEndian>(ZipWriter::END_OF_CENTRAL_DIR_SIGNATURE)?;
+        out.write_u16::<LittleEndian>(0)?;  // disk number
+        out.write_u16::<LittleEndian>(0)?;  // disk of the central directory
+        out.write_u16::<LittleEndian>(self.entries.len() as u16)?;
+        out.write_u16::<LittleEndian>(self.entries.len() as u16)?;
+        out.write_u32::<LittleEndian>(ZipWriter::check_size(central_dir_size)?)?;
+        out.write_u32::<LittleEndian>(central_dir_offset)?;
+        out.write_u16::<LittleEndian>(0)?;  // comment length
+        out.flush()?;
+        return Ok(());
+    }
+}
+
+/// Computes the CRC-32 (IEEE) checksum used by the ZIP archives.
+fn crc32(data: &[u8]) -> u32 {
+    let mut table: [u32; 256] = [0; 256];
+    for (i, entry) in table.iter_mut().enumerate() {
+        let mut value: u32 = i as u32;
+        for _ in 0..8 {
+            value = match value & 1 {
+                1 => 0xEDB88320 ^ (value >> 1),
+                _ => value >> 1,
+            };
+        }
+        *entry = value;
+    }
+    let mut crc: u32 = 0xFFFFFFFF;
+    for byte in data.iter() {
+        crc = table[((crc ^ u32::from(*byte)) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    return !crc;
+}
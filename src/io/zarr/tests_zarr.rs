@@ -0,0 +1,162 @@
+#![cfg(test)]
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use byteorder::{ByteOrder, LittleEndian};
+use tempdir::TempDir;
+
+use crate::{DataSet, DataVector, FileReader, InMemoryDataSet, Version, ZarrStore, export_zarr, kerchunk_refs};
+use crate::error::WriteError;
+
+use super::crc32;
+
+const TMP_DIR_PREFIX: &str = "netcdf3_tests_";
+
+fn init_data_set() -> (DataSet, HashMap<String, DataVector>) {
+    let mut data_set: DataSet = DataSet::new();
+    data_set.set_unlimited_dim("time", 2).unwrap();
+    data_set.add_fixed_dim("lat", 3).unwrap();
+    data_set.add_global_attr_string("title", "Test \"zarr\"").unwrap();
+    data_set.add_var_i16("temp", &["time", "lat"]).unwrap();
+    data_set.add_var_attr_string("temp", "units", "K").unwrap();
+    data_set.add_var_attr_i16("temp", "_FillValue", vec![-1]).unwrap();
+    data_set.add_var_attr_i16("temp", "valid_range", vec![0, 50]).unwrap();
+    data_set.add_var_f32("lat", &["lat"]).unwrap();
+    data_set.add_var_f64("scalar", &[] as &[&str]).unwrap();
+    data_set.add_var_u8("flag", &["lat"]).unwrap();
+
+    let mut vars_data: HashMap<String, DataVector> = HashMap::new();
+    vars_data.insert(String::from("temp"), DataVector::I16(vec![1, 2, 3, 4, 5, 6]));
+    vars_data.insert(String::from("lat"), DataVector::F32(vec![0.5, 1.5, 2.5]));
+    vars_data.insert(String::from("scalar"), DataVector::F64(vec![std::f64::NAN]));
+    return (data_set, vars_data);
+}
+
+#[test]
+fn test_export_zarr_directory() {
+    let (data_set, vars_data): (DataSet, HashMap<String, DataVector>) = init_data_set();
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let zarr_path: PathBuf = tmp_dir.path().join("test.zarr");
+    export_zarr(&data_set, &vars_data, &zarr_path, ZarrStore::Directory).unwrap();
+
+    let read_string = |key: &str| -> String { std::fs::read_to_string(zarr_path.join(key)).unwrap() };
+    assert_eq!("{\"zarr_format\": 2}",                  read_string(".zgroup"));
+    assert_eq!("{\"title\": \"Test \\\"zarr\\\"\"}",     read_string(".zattrs"));
+    assert_eq!(
+        "{\"zarr_format\": 2, \"shape\": [2, 3], \"chunks\": [1, 3], \"dtype\": \">i2\", \"compressor\": null, \"fill_value\": -1, \"order\": \"C\", \"filters\": null}",
+        read_string("temp/.zarray")
+    );
+    assert_eq!(
+        "{\"units\": \"K\", \"_FillValue\": -1, \"valid_range\": [0, 50], \"_ARRAY_DIMENSIONS\": [\"time\", \"lat\"]}",
+        read_string("temp/.zattrs")
+    );
+    assert_eq!(
+        "{\"zarr_format\": 2, \"shape\": [], \"chunks\": [], \"dtype\": \">f8\", \"compressor\": null, \"fill_value\": 9.969209968386869e36, \"order\": \"C\", \"filters\": null}",
+        read_string("scalar/.zarray")
+    );
+    assert_eq!(true,                                    read_string("flag/.zarray").contains("\"dtype\": \"|S1\", \"compressor\": null, \"fill_value\": \"AA==\""));
+
+    // One chunk per record, big-endian values
+    assert_eq!(vec![0, 1, 0, 2, 0, 3],                  std::fs::read(zarr_path.join("temp/0.0")).unwrap());
+    assert_eq!(vec![0, 4, 0, 5, 0, 6],                  std::fs::read(zarr_path.join("temp/1.0")).unwrap());
+    assert_eq!(0.5_f32.to_be_bytes().to_vec(),          std::fs::read(zarr_path.join("lat/0")).unwrap()[0..4].to_vec());
+    assert_eq!(std::f64::NAN.to_be_bytes().to_vec(),    std::fs::read(zarr_path.join("scalar/0")).unwrap());
+    // The variables without data are only described
+    assert_eq!(false,                                   zarr_path.join("flag/0").exists());
+
+    // The store already exists
+    assert_eq!(true,                                    export_zarr(&data_set, &vars_data, &zarr_path, ZarrStore::Directory).is_err());
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_export_zarr_zip() {
+    let (data_set, vars_data): (DataSet, HashMap<String, DataVector>) = init_data_set();
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let zip_path: PathBuf = tmp_dir.path().join("test.zarr.zip");
+    export_zarr(&data_set, &vars_data, &zip_path, ZarrStore::Zip).unwrap();
+
+    let bytes: Vec<u8> = std::fs::read(&zip_path).unwrap();
+    assert_eq!(0x04034b50,                              LittleEndian::read_u32(&bytes[0..4]));
+    // End of the central directory
+    let eocd: &[u8] = &bytes[(bytes.len() - 22)..];
+    assert_eq!(0x06054b50,                              LittleEndian::read_u32(&eocd[0..4]));
+    // .zgroup + .zattrs + 4 x (.zarray + .zattrs) + 2 chunks (temp) + 1 chunk (lat) + 1 chunk (scalar)
+    let num_entries: u16 = 2 + 4 * 2 + 2 + 1 + 1;
+    assert_eq!(num_entries,                             LittleEndian::read_u16(&eocd[10..12]));
+    let central_dir_size: usize = LittleEndian::read_u32(&eocd[12..16]) as usize;
+    let central_dir_offset: usize = LittleEndian::read_u32(&eocd[16..20]) as usize;
+    assert_eq!(bytes.len() - 22,                        central_dir_offset + central_dir_size);
+
+    // First entry
+    let name_len: usize = LittleEndian::read_u16(&bytes[26..28]) as usize;
+    let data_len: usize = LittleEndian::read_u32(&bytes[22..26]) as usize;
+    assert_eq!(b".zgroup" as &[u8],                     &bytes[30..(30 + name_len)]);
+    let data: &[u8] = &bytes[(30 + name_len)..(30 + name_len + data_len)];
+    assert_eq!(b"{\"zarr_format\": 2}" as &[u8],        data);
+    assert_eq!(crc32(data),                             LittleEndian::read_u32(&bytes[14..18]));
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_export_zarr_errors() {
+    let (data_set, mut vars_data): (DataSet, HashMap<String, DataVector>) = init_data_set();
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+
+    vars_data.insert(String::from("lat"), DataVector::F32(vec![0.5]));
+    assert_eq!(
+        WriteError::VariableMismatchDataLength{var_name: String::from("lat"), req: 3, get: 1},
+        export_zarr(&data_set, &vars_data, tmp_dir.path().join("length.zarr"), ZarrStore::Directory).unwrap_err()
+    );
+    vars_data.insert(String::from("lat"), DataVector::F64(vec![0.5, 1.5, 2.5]));
+    assert_eq!(
+        WriteError::VariableMismatchDataType{var_name: String::from("lat"), req: crate::DataType::F32, get: crate::DataType::F64},
+        export_zarr(&data_set, &vars_data, tmp_dir.path().join("type.zarr"), ZarrStore::Zip).unwrap_err()
+    );
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn test_crc32() {
+    assert_eq!(0x00000000,                              crc32(b""));
+    assert_eq!(0xCBF43926,                              crc32(b"123456789"));
+}
+
+#[test]
+fn test_kerchunk_refs() {
+    let tmp_dir: TempDir = TempDir::new(TMP_DIR_PREFIX).unwrap();
+    let file_path: PathBuf = tmp_dir.path().join("kerchunk.nc");
+    let (data_set, vars_data): (DataSet, HashMap<String, DataVector>) = init_data_set();
+    InMemoryDataSet::from_parts(data_set, vars_data).unwrap().write_file(&file_path, Version::Classic).unwrap();
+    let file_bytes: Vec<u8> = std::fs::read(&file_path).unwrap();
+
+    let file_reader: FileReader = FileReader::open(&file_path).unwrap();
+    let refs: serde_json::Value = serde_json::from_str(&kerchunk_refs(&file_reader, "data/\"kerchunk\".nc").unwrap()).unwrap();
+    assert_eq!(1,                                               refs["version"]);
+    let refs: &serde_json::Map<String, serde_json::Value> = refs["refs"].as_object().unwrap();
+
+    // Metadata of the Zarr store
+    assert_eq!("{\"zarr_format\": 2}",                          refs[".zgroup"]);
+    let zattrs: serde_json::Value = serde_json::from_str(refs[".zattrs"].as_str().unwrap()).unwrap();
+    assert_eq!("Test \"zarr\"",                                 zattrs["title"]);
+    let zarray: serde_json::Value = serde_json::from_str(refs["temp/.zarray"].as_str().unwrap()).unwrap();
+    assert_eq!(serde_json::json!([1, 3]),                       zarray["chunks"]);
+    let zattrs: serde_json::Value = serde_json::from_str(refs["temp/.zattrs"].as_str().unwrap()).unwrap();
+    assert_eq!(serde_json::json!(["time", "lat"]),              zattrs["_ARRAY_DIMENSIONS"]);
+
+    // One reference per chunk, without the padding bytes
+    let chunk_bytes = |key: &str| -> &[u8] {
+        let chunk_ref: &Vec<serde_json::Value> = refs[key].as_array().unwrap();
+        assert_eq!("data/\"kerchunk\".nc",                      chunk_ref[0]);
+        let offset: usize = chunk_ref[1].as_u64().unwrap() as usize;
+        let len: usize = chunk_ref[2].as_u64().unwrap() as usize;
+        return &file_bytes[offset..offset + len];
+    };
+    assert_eq!(&[0, 1, 0, 2, 0, 3],                             chunk_bytes("temp/0.0"));
+    assert_eq!(&[0, 4, 0, 5, 0, 6],                             chunk_bytes("temp/1.0"));
+    assert_eq!(DataVector::F32(vec![0.5, 1.5, 2.5]).as_be_bytes(),  chunk_bytes("lat/0"));
+    assert_eq!(8,                                               chunk_bytes("scalar/0").len());
+    assert_eq!(3,                                               chunk_bytes("flag/0").len());
+    assert_eq!(false,                                           refs.contains_key("temp/2.0"));
+    tmp_dir.close().unwrap();
+}
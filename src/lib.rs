@@ -35,21 +35,57 @@ mod data_type;
 pub use data_type::DataType;
 
 mod data_vector;
-pub use data_vector::DataVector;
+pub use data_vector::{DataVector, CastOverflow};
+
+mod nc_type;
+pub use nc_type::{NcType, NcFloat};
 
 mod data_set;
 pub use data_set::{Attribute, DataSet, Dimension, DimensionType, Variable};
+pub use data_set::{DataSetDiff, DimensionDiff, VariableDiff, AttributeDiff, DataDiff, Tolerance, compare_vars, MergePolicy, MergeReport};
+pub use data_set::{DataSetBuilder, VariableBuilder};
+pub use data_set::InMemoryDataSet;
 pub use data_set::NC_FILL_I8;
 pub use data_set::NC_FILL_U8;
 pub use data_set::NC_FILL_I16;
 pub use data_set::NC_FILL_I32;
 pub use data_set::NC_FILL_F32;
 pub use data_set::NC_FILL_F64;
+pub use data_set::NC_FILL_U16;
+pub use data_set::NC_FILL_U32;
+pub use data_set::NC_FILL_I64;
+pub use data_set::NC_FILL_U64;
 pub use data_set::NC_MAX_DIM_SIZE;
 pub use data_set::NC_MAX_VAR_DIMS;
+pub use data_set::Limits;
 
 mod io;
-pub use io::{FileReader, FileWriter};
+pub use io::{FileReader, FileWriter, VariableView, SliceArg, extract, convert, BoundingBox, GeoSubset, DateTime, TimeUnits, TimeSubset, TextEncoding, StringOptions};
+pub use io::{Summarizer, Summary, SummaryOptions, Histogram, HistogramBins, summarize_var};
+pub use io::{export_csv, import_csv, CsvOptions, CsvImportOptions};
+pub use io::export_geojson;
+pub use io::{compare_files, CompareOptions, FileDiff};
+pub use io::ReaderPool;
+pub use io::{Timings, VarTimings};
+pub use io::{LabeledDataset, Label};
+#[cfg(feature = "netcdf")]
+pub use io::{read_netcdf4, write_netcdf4, define_netcdf4, write_netcdf4_var};
+#[cfg(feature = "dap2")]
+pub use io::Dap2Reader;
+#[cfg(feature = "zarr")]
+pub use io::{export_zarr, kerchunk_refs, ZarrStore};
+#[cfg(feature = "arrow")]
+pub use io::to_arrow;
+#[cfg(feature = "parquet")]
+pub use io::export_parquet;
+#[cfg(feature = "wasm")]
+pub use io::{WasmReader, WasmWriter};
+#[cfg(feature = "image")]
+pub use io::{export_png, ImageOptions, Colormap};
+#[cfg(feature = "archive")]
+pub use io::archive_members;
 
 mod version;
-pub use version::Version;
\ No newline at end of file
+pub use version::Version;
+
+pub mod cf;
\ No newline at end of file
@@ -0,0 +1,141 @@
+use crate::{DataType, DataVector, FileWriter, WriteError};
+use crate::{NC_FILL_I8, NC_FILL_U8, NC_FILL_I16, NC_FILL_I32, NC_FILL_F32, NC_FILL_F64, NC_FILL_U16, NC_FILL_U32, NC_FILL_I64, NC_FILL_U64};
+
+mod sealed {
+    use crate::{FileWriter, WriteError};
+
+    /// Prevents the implementation of `NcType` outside of the crate, and dispatches the generic writes to the typed methods of `FileWriter`.
+    pub trait Sealed: Sized {
+        fn write_var(file_writer: &mut FileWriter, var_name: &str, data: &[Self]) -> Result<(), WriteError>;
+        fn write_record(file_writer: &mut FileWriter, var_name: &str, record_index: usize, record: &[Self]) -> Result<(), WriteError>;
+        fn to_f64(self) -> f64;
+        fn copy_be_bytes(self, bytes: &mut [u8]);
+    }
+}
+
+/// Primitive type of the NetCDF elements (`i8`, `u8`, `i16`, `i32`, `f32`, `f64`, `u16`, `u32`, `i64` and `u64`).
+///
+/// Used by the generic methods of [`DataSet`](struct.DataSet.html), like
+/// [`add_global_attr`](struct.DataSet.html#method.add_global_attr) and [`add_var_attr`](struct.DataSet.html#method.add_var_attr),
+/// and by the generic methods of [`FileReader`](struct.FileReader.html) and [`FileWriter`](struct.FileWriter.html), like
+/// [`read_var_as`](struct.FileReader.html#method.read_var_as) and [`write_var`](struct.FileWriter.html#method.write_var).
+///
+/// The trait is sealed, it is only implemented for the NetCDF primitive types.
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::{DataType, DataVector, NcType};
+///
+/// assert_eq!(DataType::I16,                   i16::DATA_TYPE);
+/// assert_eq!(netcdf3::NC_FILL_I16,            i16::FILL_VALUE);
+///
+/// let data: DataVector = i16::into_data_vector(vec![0, 100]);
+/// assert_eq!(DataVector::I16(vec![0, 100]),   data);
+/// assert_eq!(Some(&[0_i16, 100][..]),         i16::from_data_vector(&data));
+/// assert_eq!(None,                            i32::from_data_vector(&data));
+/// ```
+pub trait NcType: sealed::Sealed + Copy + Sized + PartialEq {
+    /// NetCDF data type of the elements
+    const DATA_TYPE: DataType;
+
+    /// Default fill value of the data type (`NC_FILL_I8`, `NC_FILL_U8`, ...)
+    const FILL_VALUE: Self;
+
+    /// Wraps the elements into a `DataVector`.
+    fn into_data_vector(data: Vec<Self>) -> DataVector;
+
+    /// Returns the elements of the `DataVector`, or `None` if its data type is not `DATA_TYPE`.
+    fn from_data_vector(data: &DataVector) -> Option<&[Self]>;
+
+    /// Returns the elements of the `DataVector` without copying them, or the `DataVector` itself if its data type is not `DATA_TYPE`.
+    fn from_data_vector_into(data: DataVector) -> Result<Vec<Self>, DataVector>;
+}
+
+macro_rules! impl_nc_type {
+    ($prim_type:ty, $data_type:path, $fill_value:path, $data_vector:path, $get_func_name:ident, $get_into_func_name:ident, $write_var_func_name:ident, $write_record_func_name:ident) => {
+        impl sealed::Sealed for $prim_type {
+            fn write_var(file_writer: &mut FileWriter, var_name: &str, data: &[Self]) -> Result<(), WriteError> {
+                return file_writer.$write_var_func_name(var_name, data);
+            }
+
+            fn write_record(file_writer: &mut FileWriter, var_name: &str, record_index: usize, record: &[Self]) -> Result<(), WriteError> {
+                return file_writer.$write_record_func_name(var_name, record_index, record);
+            }
+
+            fn to_f64(self) -> f64 {
+                return self as f64;
+            }
+
+            #[inline]
+            fn copy_be_bytes(self, bytes: &mut [u8]) {
+                bytes.copy_from_slice(&self.to_be_bytes());
+            }
+        }
+
+        impl NcType for $prim_type {
+            const DATA_TYPE: DataType = $data_type;
+            const FILL_VALUE: Self = $fill_value;
+
+            fn into_data_vector(data: Vec<Self>) -> DataVector {
+                return $data_vector(data);
+            }
+
+            fn from_data_vector(data: &DataVector) -> Option<&[Self]> {
+                return data.$get_func_name();
+            }
+
+            fn from_data_vector_into(data: DataVector) -> Result<Vec<Self>, DataVector> {
+                return data.$get_into_func_name();
+            }
+        }
+    }
+}
+
+impl_nc_type!(i8, DataType::I8, NC_FILL_I8, DataVector::I8, get_i8, get_i8_into, write_var_i8, write_record_i8);
+impl_nc_type!(u8, DataType::U8, NC_FILL_U8, DataVector::U8, get_u8, get_u8_into, write_var_u8, write_record_u8);
+impl_nc_type!(i16, DataType::I16, NC_FILL_I16, DataVector::I16, get_i16, get_i16_into, write_var_i16, write_record_i16);
+impl_nc_type!(i32, DataType::I32, NC_FILL_I32, DataVector::I32, get_i32, get_i32_into, write_var_i32, write_record_i32);
+impl_nc_type!(f32, DataType::F32, NC_FILL_F32, DataVector::F32, get_f32, get_f32_into, write_var_f32, write_record_f32);
+impl_nc_type!(f64, DataType::F64, NC_FILL_F64, DataVector::F64, get_f64, get_f64_into, write_var_f64, write_record_f64);
+impl_nc_type!(u16, DataType::U16, NC_FILL_U16, DataVector::U16, get_u16, get_u16_into, write_var_u16, write_record_u16);
+impl_nc_type!(u32, DataType::U32, NC_FILL_U32, DataVector::U32, get_u32, get_u32_into, write_var_u32, write_record_u32);
+impl_nc_type!(i64, DataType::I64, NC_FILL_I64, DataVector::I64, get_i64, get_i64_into, write_var_i64, write_record_i64);
+impl_nc_type!(u64, DataType::U64, NC_FILL_U64, DataVector::U64, get_u64, get_u64_into, write_var_u64, write_record_u64);
+
+/// Floating-point NetCDF types (`f32` and `f64`), whose fill values can be replaced by `NaN`.
+///
+/// Used by the methods [`FileReader::read_var_nan`](struct.FileReader.html#method.read_var_nan) and
+/// [`FileWriter::write_var_nan`](struct.FileWriter.html#method.write_var_nan).
+///
+/// # Example
+///
+/// ```
+/// use netcdf3::NcFloat;
+///
+/// assert_eq!(true,                            f32::NAN.is_nan());
+/// assert_eq!(true,                            <f64 as NcFloat>::is_nan(f64::NAN));
+/// ```
+pub trait NcFloat: NcType + PartialOrd {
+    /// Not a number
+    const NAN: Self;
+
+    /// Returns `true` if the value is `NaN`.
+    fn is_nan(self) -> bool;
+}
+
+impl NcFloat for f32 {
+    const NAN: Self = std::f32::NAN;
+
+    fn is_nan(self) -> bool {
+        return f32::is_nan(self);
+    }
+}
+
+impl NcFloat for f64 {
+    const NAN: Self = std::f64::NAN;
+
+    fn is_nan(self) -> bool {
+        return f64::is_nan(self);
+    }
+}
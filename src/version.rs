@@ -1,14 +1,98 @@
+use std::io::Read;
+use std::convert::TryFrom;
+
+use crate::DataType;
+use crate::error::ReadError;
+
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq)]
-/// NetCDF-3 file versions (classic or 64-bit offset)
+/// NetCDF-3 file versions (classic, 64-bit offset or CDF-5)
 pub enum Version {
     /// Classic format (use `i32` for the begin offsets)
     Classic = 1,
     /// 64-bit offset format (use `i64` for the begin offsets)
     Offset64Bit = 2,
+    /// CDF-5 format, a.k.a. 64-bit data format (use `i64` for the begin offsets and for all the sizes,
+    /// and supports the data types `U16`, `U32`, `I64` and `U64`)
+    Cdf5 = 5,
+}
+
+impl Version {
+
+    /// Magic word of the NetCDF-3 files
+    const MAGIC_WORD: &'static [u8] = b"CDF";
+    /// Signature of the HDF5 files (and so of the NetCDF-4 files)
+    const HDF5_SIGNATURE: &'static [u8] = b"\x89HDF\r\n\x1a\n";
+
+    /// Detects the version from the first bytes of a file, without parsing its header.
+    ///
+    /// Only the 4 first bytes are required for a NetCDF-3 file, and 8 bytes are required to recognize an HDF5 file.
+    ///
+    /// Returns the error :
+    /// - `ReadError::Hdf5FormatNotSupported` if the bytes begin with the signature of the HDF5 files (NetCDF-4 files are HDF5 files).
+    /// - `ReadError::MagicBytesNotValid` if the bytes are not the magic bytes of any NetCDF file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use netcdf3::Version;
+    /// use netcdf3::error::ReadError;
+    ///
+    /// assert_eq!(Ok(Version::Classic),                    Version::detect(b"CDF\x01"));
+    /// assert_eq!(Ok(Version::Offset64Bit),                Version::detect(b"CDF\x02\x00\x00"));
+    /// assert_eq!(Ok(Version::Cdf5),                       Version::detect(b"CDF\x05"));
+    /// assert_eq!(Err(ReadError::Hdf5FormatNotSupported),  Version::detect(b"\x89HDF\r\n\x1a\n"));
+    /// assert_eq!(
+    ///     Err(ReadError::MagicBytesNotValid(b"CDF\x03".to_vec())),
+    ///     Version::detect(b"CDF\x03")
+    /// );
+    /// ```
+    pub fn detect(bytes: &[u8]) -> Result<Version, ReadError> {
+        if bytes.starts_with(Version::HDF5_SIGNATURE) {
+            return Err(ReadError::Hdf5FormatNotSupported);
+        }
+        if bytes.len() >= 4 && bytes.starts_with(Version::MAGIC_WORD) {
+            if let Ok(version) = Version::try_from(bytes[3]) {
+                return Ok(version);
+            }
+        }
+        let num_bytes: usize = std::cmp::min(bytes.len(), Version::HDF5_SIGNATURE.len());
+        return Err(ReadError::MagicBytesNotValid(bytes[0..num_bytes].to_vec()));
+    }
+
+    /// Reads the first bytes of the `input` (at most 8 bytes), and detects the version (see [`detect`](enum.Version.html#method.detect)).
+    pub fn detect_from_reader<R: Read>(input: &mut R) -> Result<Version, ReadError> {
+        let mut bytes: Vec<u8> = Vec::with_capacity(Version::HDF5_SIGNATURE.len());
+        input.take(Version::HDF5_SIGNATURE.len() as u64).read_to_end(&mut bytes)?;
+        return Version::detect(&bytes);
+    }
+
+    /// Returns the size (number of bytes) of the non-negative integers (sizes, counts, ...) of the header.
+    pub(crate) fn non_neg_size(&self) -> usize {
+        return match self {
+            Version::Classic | Version::Offset64Bit => std::mem::size_of::<i32>(),
+            Version::Cdf5 => std::mem::size_of::<i64>(),
+        };
+    }
+
+    /// Returns the size (number of bytes) of the begin offsets of the variables.
+    pub(crate) fn offset_size(&self) -> usize {
+        return match self {
+            Version::Classic => std::mem::size_of::<i32>(),
+            Version::Offset64Bit | Version::Cdf5 => std::mem::size_of::<i64>(),
+        };
+    }
+
+    /// Returns `true` if the data type can be stored in this version.
+    pub fn supports_data_type(&self, data_type: &DataType) -> bool {
+        return match self {
+            Version::Classic | Version::Offset64Bit => !data_type.is_cdf5_only(),
+            Version::Cdf5 => true,
+        };
+    }
 }
 
-impl std::convert::TryFrom<u8> for Version {
+impl TryFrom<u8> for Version {
 
     type Error = &'static str;
 
@@ -16,6 +100,7 @@ impl std::convert::TryFrom<u8> for Version {
         match value {
             1_u8 => Ok(Version::Classic),
             2_u8 => Ok(Version::Offset64Bit),
+            5_u8 => Ok(Version::Cdf5),
             _ => Err("Invalid value for a NetCDF-3 version."),
         }
     }
@@ -26,6 +111,8 @@ mod tests
 {
     use std::convert::TryFrom;
     use super::Version;
+    use crate::DataType;
+    use crate::error::ReadError;
 
     #[test]
     fn test_version_try_from_u8() {
@@ -33,5 +120,42 @@ mod tests
         assert_eq!(Ok(Version::Classic),                            Version::try_from(1_u8));
         assert_eq!(Ok(Version::Offset64Bit),                        Version::try_from(2_u8));
         assert_eq!(Err("Invalid value for a NetCDF-3 version."),    Version::try_from(3_u8));
+        assert_eq!(Err("Invalid value for a NetCDF-3 version."),    Version::try_from(4_u8));
+        assert_eq!(Ok(Version::Cdf5),                               Version::try_from(5_u8));
+        assert_eq!(Err("Invalid value for a NetCDF-3 version."),    Version::try_from(6_u8));
+    }
+
+    #[test]
+    fn test_version_supports_data_type() {
+        assert_eq!(true,        Version::Classic.supports_data_type(&DataType::F64));
+        assert_eq!(false,       Version::Classic.supports_data_type(&DataType::U16));
+        assert_eq!(false,       Version::Offset64Bit.supports_data_type(&DataType::I64));
+        assert_eq!(true,        Version::Cdf5.supports_data_type(&DataType::I8));
+        assert_eq!(true,        Version::Cdf5.supports_data_type(&DataType::U64));
+    }
+
+    #[test]
+    fn test_version_detect() {
+        assert_eq!(Ok(Version::Classic),                                Version::detect(b"CDF\x01\x00\x00\x00\x00"));
+        assert_eq!(Ok(Version::Offset64Bit),                            Version::detect(b"CDF\x02"));
+        assert_eq!(Ok(Version::Cdf5),                                   Version::detect(b"CDF\x05"));
+        assert_eq!(Err(ReadError::Hdf5FormatNotSupported),              Version::detect(b"\x89HDF\r\n\x1a\n\x00\x00"));
+        // Truncated or not valid bytes
+        assert_eq!(Err(ReadError::MagicBytesNotValid(vec![])),          Version::detect(b""));
+        assert_eq!(Err(ReadError::MagicBytesNotValid(b"CDF".to_vec())), Version::detect(b"CDF"));
+        assert_eq!(Err(ReadError::MagicBytesNotValid(b"\x89HDF".to_vec())), Version::detect(b"\x89HDF"));
+        assert_eq!(Err(ReadError::MagicBytesNotValid(b"CDF\x04".to_vec())), Version::detect(b"CDF\x04"));
+        assert_eq!(Err(ReadError::MagicBytesNotValid(b"cdf\x01".to_vec())), Version::detect(b"cdf\x01"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_version_detect_from_reader() {
+        let mut input: &[u8] = b"CDF\x02\x00\x00\x00\x00\x00\x00\x00\x00";
+        assert_eq!(Ok(Version::Offset64Bit),                            Version::detect_from_reader(&mut input));
+        // Only the first bytes have been read
+        assert_eq!(4,                                                   input.len());
+
+        let mut input: &[u8] = b"\x89HDF\r\n\x1a\n";
+        assert_eq!(Err(ReadError::Hdf5FormatNotSupported),              Version::detect_from_reader(&mut input));
+    }
+}